@@ -0,0 +1,125 @@
+//! `#[derive(CacheBind)]`, implementing json_data_cache::bind::CacheBind (see its documentation for the attributes)
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{Attribute, Data, DeriveInput, Fields, LitStr, Type, parse_macro_input};
+
+/// Attributes of a field: #[cache(path = "..", default, skip, nested)]
+#[derive(Default)]
+struct FieldOptions {
+    path: Option<String>,
+    default: bool, // Missing values load Default::default()
+    skip: bool, // Neither loaded (Default::default()) nor stored
+    nested: bool, // The field is itself CacheBind, loaded & stored below the path
+}
+
+fn field_options(attrs: &[Attribute]) -> syn::Result<FieldOptions> {
+    let mut options = FieldOptions::default();
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("cache")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("path") {
+                options.path = Some(meta.value()?.parse::<LitStr>()?.value());
+            } else if meta.path.is_ident("default") {
+                options.default = true;
+            } else if meta.path.is_ident("skip") {
+                options.skip = true;
+            } else if meta.path.is_ident("nested") {
+                options.nested = true;
+            } else {
+                return Err(meta.error("unknown cache attribute, expected path, default, skip or nested"));
+            }
+            Ok(())
+        })?;
+    }
+    Ok(options)
+}
+
+/// Prefix of the struct: #[cache(prefix = "..")]
+fn struct_prefix(attrs: &[Attribute]) -> syn::Result<String> {
+    let mut prefix = String::new();
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("cache")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("prefix") {
+                prefix = meta.value()?.parse::<LitStr>()?.value();
+                Ok(())
+            } else {
+                Err(meta.error("unknown cache attribute, expected prefix"))
+            }
+        })?;
+    }
+    Ok(prefix)
+}
+
+fn is_option(ty: &Type) -> bool {
+    matches!(ty, Type::Path(type_path) if type_path.qself.is_none()
+        && type_path.path.segments.last().is_some_and(|segment| segment.ident == "Option"))
+}
+
+fn expand(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new_spanned(input, "CacheBind can only be derived for structs"));
+    };
+    let Fields::Named(fields) = &data.fields else {
+        return Err(syn::Error::new_spanned(input, "CacheBind can only be derived for structs with named fields"));
+    };
+    let prefix = struct_prefix(&input.attrs)?;
+    let mut loads = Vec::new();
+    let mut stores = Vec::new();
+    for field in &fields.named {
+        let ident = field.ident.as_ref().unwrap();
+        let options = field_options(&field.attrs)?;
+        if options.skip {
+            loads.push(quote! { #ident: ::core::default::Default::default() });
+            continue;
+        }
+        let path = options.path.unwrap_or_else(|| ident.to_string().trim_start_matches("r#").to_string());
+        let load = if options.nested {
+            quote! { ::json_data_cache::bind::CacheBind::load_from_cache_at(data_cache, &::json_data_cache::bind::join(prefix, #path))? }
+        } else if is_option(&field.ty) {
+            quote! { ::json_data_cache::bind::load_optional(data_cache, prefix, #path)? }
+        } else if options.default {
+            quote! { ::json_data_cache::bind::load_optional(data_cache, prefix, #path)?.unwrap_or_default() }
+        } else {
+            quote! { ::json_data_cache::bind::load_required(data_cache, prefix, #path)? }
+        };
+        loads.push(quote! { #ident: #load });
+        stores.push(if options.nested {
+            quote! { ::json_data_cache::bind::CacheBind::store_to_cache(&self.#ident, data_cache, &::json_data_cache::bind::join(prefix, #path))?; }
+        } else {
+            quote! { ::json_data_cache::bind::store(data_cache, prefix, #path, &self.#ident)?; }
+        });
+    }
+
+    let name = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::json_data_cache::bind::CacheBind for #name #type_generics #where_clause {
+            const PREFIX: &'static str = #prefix;
+
+            fn load_from_cache_at(
+                data_cache: &::json_data_cache::DataCache,
+                prefix: &str
+            ) -> ::core::result::Result<Self, ::json_data_cache::error::JsonDataCacheError> {
+                ::core::result::Result::Ok(Self {
+                    #(#loads,)*
+                })
+            }
+
+            fn store_to_cache(
+                &self,
+                data_cache: &mut ::json_data_cache::DataCache,
+                prefix: &str
+            ) -> ::core::result::Result<(), ::json_data_cache::error::JsonDataCacheError> {
+                #(#stores)*
+                ::core::result::Result::Ok(())
+            }
+        }
+    })
+}
+
+#[proc_macro_derive(CacheBind, attributes(cache))]
+pub fn derive_cache_bind(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(&input).unwrap_or_else(syn::Error::into_compile_error).into()
+}
@@ -0,0 +1,4 @@
+//! Shared library exporting the C ABI of json-data-cache (see json-data-cache/include/json_data_cache.h), built apart
+//! so that Rust users of json-data-cache only compile its rlib: cargo build --release -p json-data-cache-ffi
+
+pub use json_data_cache::ffi::*;
@@ -0,0 +1,48 @@
+use std::{env, io, path::PathBuf, process::Command};
+
+/// Exercises the ctypes binding against the cdylib built along with the tests
+const SMOKE_TEST: &str = r#"
+import json_data_cache as jdc
+
+with jdc.DataCache() as cache:
+    cache.insert("content", {"subject": "Hello", "tags": ["a"]})
+    cache.merge({"content": {"author": "Taro"}})
+    assert cache.get("content.author") == "Taro"
+    assert cache.get("content.missing", "default") == "default"
+    assert cache.replace("<h1>{$content.subject}</h1>{$content.tags}") == '<h1>Hello</h1>["a"]'
+    assert cache.replace(b"{$content.author}") == b"Taro"
+    try:
+        cache.insert("a." * 300 + "b", 1)
+        raise AssertionError("deep path accepted")
+    except jdc.JsonDataCacheError as e:
+        assert e.code == jdc.ERR_REJECTED
+print("ok")
+"#;
+
+/// The cdylib, next to the deps directory of this test executable
+fn library_path() -> PathBuf {
+    let exe = env::current_exe().unwrap();
+    let dir = exe.parent().and_then(|deps| deps.parent()).unwrap();
+    dir.join(format!("{}json_data_cache_ffi{}", env::consts::DLL_PREFIX, env::consts::DLL_SUFFIX))
+}
+
+#[test]
+fn python_binding_test() {
+    let bindings = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../json-data-cache/bindings/python");
+    let output = Command::new("python3")
+        .arg("-c")
+        .arg(SMOKE_TEST)
+        .env("PYTHONPATH", &bindings)
+        .env("JSON_DATA_CACHE_LIB", library_path())
+        .output();
+    let output = match output {
+        Ok(output) => output,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {
+            eprintln!("python3 not found, skipping the binding test");
+            return;
+        },
+        Err(e) => panic!("{e}"),
+    };
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert_eq!(String::from_utf8_lossy(&output.stdout).trim(), "ok");
+}
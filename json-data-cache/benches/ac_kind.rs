@@ -0,0 +1,73 @@
+//! Compares automaton kinds on a catalog-like cache, for several placeholder densities of the replaced document
+//! Run with `cargo bench --bench ac_kind`
+
+use std::time::{Duration, Instant};
+
+use json_data_cache::{AhoCorasickKind, DataCache, DataCacheOptions};
+use serde_json::json;
+
+const ITEMS: usize = 2_000;
+const DOCUMENT_BYTES: usize = 1 << 20;
+const ITERATIONS: u32 = 5;
+
+fn build_cache(ac_kind: Option<AhoCorasickKind>, ac_prefilter: Option<bool>) -> DataCache {
+    let mut data_cache = DataCache::new(DataCacheOptions {
+        ac_kind,
+        ac_prefilter,
+        ..Default::default()
+    });
+    let items: Vec<_> = (0..ITEMS).map(|idx| json!({
+        "id": idx,
+        "subject": format!("Article number {idx}"),
+        "tags": ["news", "edge", idx.to_string()]
+    })).collect();
+    data_cache.insert("content.list", json!(items));
+    data_cache
+}
+
+/// Builds a document with one placeholder every `spacing` bytes of filler text
+fn build_document(spacing: usize) -> Vec<u8> {
+    let filler = "lorem ipsum dolor sit amet ".repeat(spacing / 27 + 1);
+    let mut document = String::with_capacity(DOCUMENT_BYTES);
+    let mut idx = 0;
+    while document.len() < DOCUMENT_BYTES {
+        document.push_str(&filler[..spacing]);
+        document.push_str(&format!("{{$content.list.{}.subject}}", idx % ITEMS));
+        idx += 1;
+    }
+    document.into_bytes()
+}
+
+fn main() {
+    for (name, ac_kind, ac_prefilter) in [
+        ("auto", None, None),
+        ("noncontiguous NFA", Some(AhoCorasickKind::NoncontiguousNFA), None),
+        ("contiguous NFA", Some(AhoCorasickKind::ContiguousNFA), None),
+        ("DFA", Some(AhoCorasickKind::DFA), None),
+        ("DFA, no prefilter", Some(AhoCorasickKind::DFA), Some(false)),
+    ] {
+        let mut data_cache = build_cache(ac_kind, ac_prefilter);
+        data_cache.replace_with_data_cache("".as_bytes(), Vec::new()).unwrap();
+        let stats = data_cache.automaton_stats().unwrap().clone();
+        println!(
+            "{name}: {} patterns, {} KiB, built in {:?}",
+            stats.pattern_count,
+            stats.heap_bytes / 1024,
+            stats.build_time
+        );
+
+        for spacing in [16, 256, 4096] {
+            let document = build_document(spacing);
+            let mut total = Duration::ZERO;
+            for _ in 0..ITERATIONS {
+                let mut output = Vec::with_capacity(document.len());
+                let start = Instant::now();
+                data_cache.replace_with_data_cache(document.as_slice(), &mut output).unwrap();
+                total += start.elapsed();
+            }
+            let average = total / ITERATIONS;
+            let throughput = document.len() as f64 / average.as_secs_f64() / (1024.0 * 1024.0);
+            println!("  placeholder every {spacing} bytes: {average:?} per MiB ({throughput:.0} MiB/s)");
+        }
+    }
+}
@@ -0,0 +1,133 @@
+"""Python binding of json-data-cache, over the C ABI of the json-data-cache-ffi cdylib.
+
+    cargo build --release -p json-data-cache-ffi
+
+The library is looked up in JSON_DATA_CACHE_LIB if set, otherwise in the cargo release target directory.
+Replacements use the exact same engine as the Rust workers, so templates can be validated offline:
+
+    from json_data_cache import DataCache
+    cache = DataCache()
+    cache.insert("content.subject", "Hello")
+    assert cache.replace("<h1>{$content.subject}</h1>") == "<h1>Hello</h1>"
+"""
+
+import ctypes
+import json
+import os
+import sys
+from pathlib import Path
+
+OK = 0
+ERR_NULL = -1
+ERR_UTF8 = -2
+ERR_JSON = -3
+ERR_NOT_FOUND = -4
+ERR_REPLACE = -5
+ERR_REJECTED = -6
+
+
+class JsonDataCacheError(Exception):
+    def __init__(self, code):
+        super().__init__(f"json-data-cache error {code}")
+        self.code = code
+
+
+class _Buffer(ctypes.Structure):
+    _fields_ = [("data", ctypes.POINTER(ctypes.c_uint8)), ("len", ctypes.c_size_t)]
+
+
+def _default_library_path():
+    if sys.platform == "darwin":
+        name = "libjson_data_cache_ffi.dylib"
+    elif sys.platform == "win32":
+        name = "json_data_cache_ffi.dll"
+    else:
+        name = "libjson_data_cache_ffi.so"
+    return Path(__file__).resolve().parents[3] / "target" / "release" / name
+
+
+def _load(path=None):
+    lib = ctypes.CDLL(str(path or os.environ.get("JSON_DATA_CACHE_LIB") or _default_library_path()))
+    bytes_arg = [ctypes.c_char_p, ctypes.c_size_t]
+    lib.json_data_cache_new.restype = ctypes.c_void_p
+    lib.json_data_cache_new.argtypes = []
+    lib.json_data_cache_free.restype = None
+    lib.json_data_cache_free.argtypes = [ctypes.c_void_p]
+    lib.json_data_cache_buffer_free.restype = None
+    lib.json_data_cache_buffer_free.argtypes = [ctypes.POINTER(_Buffer)]
+    lib.json_data_cache_insert.restype = ctypes.c_int32
+    lib.json_data_cache_insert.argtypes = [ctypes.c_void_p, *bytes_arg, *bytes_arg]
+    lib.json_data_cache_merge.restype = ctypes.c_int32
+    lib.json_data_cache_merge.argtypes = [ctypes.c_void_p, *bytes_arg]
+    lib.json_data_cache_get.restype = ctypes.c_int32
+    lib.json_data_cache_get.argtypes = [ctypes.c_void_p, *bytes_arg, ctypes.POINTER(_Buffer)]
+    lib.json_data_cache_replace.restype = ctypes.c_int32
+    lib.json_data_cache_replace.argtypes = [ctypes.c_void_p, *bytes_arg, ctypes.POINTER(_Buffer)]
+    return lib
+
+
+_lib = None
+
+
+def _library():
+    global _lib
+    if _lib is None:
+        _lib = _load()
+    return _lib
+
+
+def _check(code):
+    if code != OK:
+        raise JsonDataCacheError(code)
+
+
+class DataCache:
+    def __init__(self):
+        self._lib = _library()
+        self._handle = self._lib.json_data_cache_new()
+
+    def close(self):
+        if self._handle:
+            self._lib.json_data_cache_free(self._handle)
+            self._handle = None
+
+    def __del__(self):
+        self.close()
+
+    def __enter__(self):
+        return self
+
+    def __exit__(self, *_):
+        self.close()
+
+    def _take(self, buffer):
+        try:
+            return ctypes.string_at(buffer.data, buffer.len) if buffer.len else b""
+        finally:
+            self._lib.json_data_cache_buffer_free(ctypes.byref(buffer))
+
+    def insert(self, path, value):
+        path = path.encode()
+        value = json.dumps(value).encode()
+        _check(self._lib.json_data_cache_insert(self._handle, path, len(path), value, len(value)))
+
+    def merge(self, value):
+        value = json.dumps(value).encode()
+        _check(self._lib.json_data_cache_merge(self._handle, value, len(value)))
+
+    def get(self, path, default=None):
+        path = path.encode()
+        buffer = _Buffer()
+        code = self._lib.json_data_cache_get(self._handle, path, len(path), ctypes.byref(buffer))
+        if code == ERR_NOT_FOUND:
+            return default
+        _check(code)
+        return json.loads(self._take(buffer))
+
+    def replace(self, template):
+        """Replaces {$key} / {$$key} placeholders. Returns the same type as given (str or bytes)"""
+        data = template.encode() if isinstance(template, str) else bytes(template)
+        buffer = _Buffer()
+        _check(self._lib.json_data_cache_replace(self._handle, data, len(data), ctypes.byref(buffer)))
+        output = self._take(buffer)
+        return output.decode() if isinstance(template, str) else output
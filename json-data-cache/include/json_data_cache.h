@@ -0,0 +1,35 @@
+/* C bindings of json-data-cache, exported by the json-data-cache-ffi cdylib: cargo build --release -p json-data-cache-ffi */
+/* Strings are pointer + length pairs (UTF-8, no terminating NUL required) */
+/* Buffers written by the library must be released with json_data_cache_buffer_free */
+
+#ifndef JSON_DATA_CACHE_H
+#define JSON_DATA_CACHE_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#define JSON_DATA_CACHE_OK 0
+#define JSON_DATA_CACHE_ERR_NULL -1
+#define JSON_DATA_CACHE_ERR_UTF8 -2
+#define JSON_DATA_CACHE_ERR_JSON -3
+#define JSON_DATA_CACHE_ERR_NOT_FOUND -4
+#define JSON_DATA_CACHE_ERR_REPLACE -5
+#define JSON_DATA_CACHE_ERR_REJECTED -6 /* Value refused by the cache, for example nested deeper than its maximum depth */
+
+typedef struct DataCache DataCache;
+
+typedef struct JsonDataCacheBuffer {
+    uint8_t *data;
+    size_t len;
+} JsonDataCacheBuffer;
+
+DataCache *json_data_cache_new(void);
+void json_data_cache_free(DataCache *cache);
+void json_data_cache_buffer_free(JsonDataCacheBuffer *buffer);
+
+int32_t json_data_cache_insert(DataCache *cache, const uint8_t *path, size_t path_len, const uint8_t *json, size_t json_len);
+int32_t json_data_cache_merge(DataCache *cache, const uint8_t *json, size_t json_len);
+int32_t json_data_cache_get(const DataCache *cache, const uint8_t *path, size_t path_len, JsonDataCacheBuffer *out);
+int32_t json_data_cache_replace(DataCache *cache, const uint8_t *input, size_t input_len, JsonDataCacheBuffer *out);
+
+#endif
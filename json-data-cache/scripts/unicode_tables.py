@@ -0,0 +1,103 @@
+"""Generates src/unicode_tables.rs from the Unicode Character Database of Python's unicodedata.
+
+Usage: python3 scripts/unicode_tables.py > src/unicode_tables.rs
+The Unicode version of the tables is the one of the Python running the script.
+"""
+
+import unicodedata as ucd
+
+HANGUL_SYLLABLES = range(0xAC00, 0xD7A4)  # Decomposed algorithmically
+PREPEND = (0x600, 0x601, 0x602, 0x603, 0x604, 0x605, 0x6DD, 0x70F, 0x8E2, 0x110BD, 0x110CD)
+
+
+def ranges(classify, skip=()):
+    """Ranges of consecutive code points of the same class, None being left out"""
+    found = []
+    for cp in range(0x110000):
+        if cp in skip:
+            continue
+        k = classify(cp)
+        if k is None:
+            continue
+        if found and found[-1][1] == cp - 1 and found[-1][2] == k:
+            found[-1][1] = cp
+        else:
+            found.append([cp, cp, k])
+    return found
+
+
+def grapheme_class(cp):
+    """Grapheme cluster break class approximated from the general category, None for Other"""
+    cat = ucd.category(chr(cp))
+    if cp == 0x200D:
+        return "Zwj"
+    if cp == 0x200C or 0xFF9E <= cp <= 0xFF9F or 0x1F3FB <= cp <= 0x1F3FF or 0xE0020 <= cp <= 0xE007F:
+        return "Extend"
+    if cat in ("Mn", "Me"):
+        return "Extend"
+    if cat == "Mc":
+        return "SpacingMark"
+    if cp in PREPEND:
+        return "Prepend"
+    if cat in ("Cc", "Zl", "Zp", "Cf"):
+        return "Control"
+    return None
+
+
+def table(doc, name, item_type, items, per_line):
+    lines = [doc, "pub(crate) const %s: &[%s] = &[" % (name, item_type)]
+    for i in range(0, len(items), per_line):
+        lines.append("    " + " ".join(item + "," for item in items[i:i + per_line]))
+    lines.append("];")
+    return "\n".join(lines)
+
+
+def main():
+    combining = ranges(lambda cp: ucd.combining(chr(cp)) or None)
+    decompositions = []
+    compositions = []
+    for cp in range(0x110000):
+        if cp in HANGUL_SYLLABLES:
+            continue
+        decomposition = ucd.decomposition(chr(cp)).split()
+        if not decomposition:
+            continue
+        compat = decomposition[0].startswith("<")
+        parts = [int(part, 16) for part in decomposition[1 if compat else 0:]]
+        decompositions.append((cp, compat, parts))
+        # Composition exclusions do not compose back
+        if not compat and len(parts) == 2 and ucd.normalize("NFC", "".join(map(chr, parts))) == chr(cp):
+            compositions.append((parts[0], parts[1], cp))
+    compositions.sort()
+    graphemes = ranges(grapheme_class, skip=(0xD, 0xA))
+
+    print("// Generated by scripts/unicode_tables.py from the Unicode Character Database %s (Python unicodedata), do not edit"
+          % ucd.unidata_version)
+    print()
+    print("use crate::unicode::GraphemeClass;")
+    print()
+    print(table(
+        "/// Canonical combining classes other than 0, by ranges of code points",
+        "COMBINING_CLASSES", "(u32, u32, u8)",
+        ["(0x%x, 0x%x, %d)" % tuple(r) for r in combining], 6))
+    print()
+    print(table(
+        "/// Decomposition of each character (one level, Hangul syllables excepted), compatibility ones flagged",
+        "DECOMPOSITIONS", "(u32, bool, &[u32])",
+        ["(0x%x, %s, &[%s])" % (cp, "true" if compat else "false", ", ".join("0x%x" % p for p in parts))
+         for cp, compat, parts in decompositions], 4))
+    print()
+    print(table(
+        "/// Canonical compositions of pairs of characters, composition exclusions left out, sorted by pair",
+        "COMPOSITIONS", "(u32, u32, u32)",
+        ["(0x%x, 0x%x, 0x%x)" % c for c in compositions], 5))
+    print()
+    print(table(
+        "/// Grapheme cluster break classes of the characters other than CR, LF, Hangul jamos & emojis, approximated from their\n"
+        "/// general categories, by ranges of code points",
+        "GRAPHEME_CLASSES", "(u32, u32, GraphemeClass)",
+        ["(0x%x, 0x%x, GraphemeClass::%s)" % tuple(r) for r in graphemes], 4))
+
+
+if __name__ == "__main__":
+    main()
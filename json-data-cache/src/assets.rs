@@ -0,0 +1,61 @@
+//! Content hashed asset URLs: the asset manifest written by the front end build maps logical names (main.css) to the
+//! files named after their hash (main.3f2a1b.css). Each URL is kept as the raw value (see insert_bytes) of the key
+//! asset:<name>, so that templates reference {$asset:main.css} and get the URL of the deployed build, cache busting
+//! included. Names may contain dots, unlike the keys of the JSON tree.
+
+use serde_json::Value;
+
+use crate::{DataCache, error::JsonDataCacheError};
+
+/// Prefix of the keys of asset URLs, {$asset:main.css} being replaced with the URL of main.css
+pub const ASSET_KEY_PREFIX: &str = "asset:";
+
+/// URL of a file of the manifest below base_url, unless it is absolute or base_url empty
+fn asset_url_of(file: &str, base_url: &str) -> String {
+    if base_url.is_empty() || file.contains("://") || file.starts_with("//") {
+        return file.to_string();
+    }
+    format!("{}/{}", base_url.trim_end_matches('/'), file.trim_start_matches('/'))
+}
+
+impl DataCache {
+    /// Replaces the asset URLs with the ones of the manifest, returning their number. Manifests map names to files, either
+    /// directly like {"main.css": "main.3f2a1b.css"} or through the file of an object like Vite ones, {"src/main.ts":
+    /// {"file": "assets/main.4889e940.js"}}. Files are prefixed with base_url, like "/static" or "https://cdn.example.com"
+    pub fn load_asset_manifest(&mut self, manifest: &Value, base_url: &str) -> Result<usize, JsonDataCacheError> {
+        let Value::Object(entries) = manifest else {
+            return Err("Asset manifest must be a JSON object".into());
+        };
+        let mut urls = Vec::with_capacity(entries.len());
+        for (name, entry) in entries {
+            let file = match entry {
+                Value::String(file) => file,
+                Value::Object(o) => match o.get("file") {
+                    Some(Value::String(file)) => file,
+                    _ => return Err(format!("Asset {name} has no file").into()),
+                },
+                _ => return Err(format!("Invalid asset {name}").into()),
+            };
+            urls.push((format!("{ASSET_KEY_PREFIX}{name}"), asset_url_of(file, base_url)));
+        }
+        let outdated: Vec<String> = self.raw_values.keys()
+            .filter(|key| key.starts_with(ASSET_KEY_PREFIX) && !urls.iter().any(|(asset_key, _)| asset_key == *key))
+            .cloned()
+            .collect();
+        for key in outdated {
+            self.remove_bytes(&key);
+        }
+        let count = urls.len();
+        for (key, url) in urls {
+            if self.get_bytes(&key) != Some(url.as_bytes()) {
+                self.insert_bytes(&key, url.into_bytes());
+            }
+        }
+        Ok(count)
+    }
+
+    /// URL of an asset of the loaded manifest, like "/static/main.3f2a1b.css" for "main.css"
+    pub fn asset_url(&self, name: &str) -> Option<&str> {
+        self.get_bytes(&format!("{ASSET_KEY_PREFIX}{name}")).and_then(|url| str::from_utf8(url).ok())
+    }
+}
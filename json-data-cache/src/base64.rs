@@ -0,0 +1,46 @@
+/// Unpadded base64url (RFC 4648 §5), safe in cookie values, URLs & file names
+const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+/// Standard base64 (RFC 4648 §4), for HTTP header values like digests
+const STANDARD_ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+fn encode_with(data: &[u8], alphabet: &[u8; 64], is_padded: bool) -> String {
+    let mut encoded = String::with_capacity(data.len().div_ceil(3) * 4);
+    for chunk in data.chunks(3) {
+        let bits = chunk.iter().enumerate().fold(0u32, |bits, (i, byte)| bits | (*byte as u32) << (16 - 8 * i));
+        for i in 0..=chunk.len() {
+            encoded.push(alphabet[(bits >> (18 - 6 * i) & 0x3f) as usize] as char);
+        }
+        if is_padded {
+            encoded.extend(std::iter::repeat_n('=', 3 - chunk.len()));
+        }
+    }
+    encoded
+}
+
+pub(crate) fn encode_url_safe(data: &[u8]) -> String {
+    encode_with(data, ALPHABET, false)
+}
+
+/// Padded standard base64
+pub(crate) fn encode(data: &[u8]) -> String {
+    encode_with(data, STANDARD_ALPHABET, true)
+}
+
+/// None if the input contains characters out of the alphabet (padding included) or has an impossible length
+pub(crate) fn decode_url_safe(encoded: &str) -> Option<Vec<u8>> {
+    if encoded.len() % 4 == 1 {
+        return None;
+    }
+    let mut decoded = Vec::with_capacity(encoded.len() * 3 / 4);
+    for chunk in encoded.as_bytes().chunks(4) {
+        let mut bits = 0u32;
+        for (i, c) in chunk.iter().enumerate() {
+            let value = ALPHABET.iter().position(|a| a == c)? as u32;
+            bits |= value << (18 - 6 * i);
+        }
+        for i in 0..chunk.len() - 1 {
+            decoded.push((bits >> (16 - 8 * i)) as u8);
+        }
+    }
+    Some(decoded)
+}
@@ -0,0 +1,49 @@
+//! Typed structs mapped to paths of the cache. `#[derive(CacheBind)]` (feature derive) implements CacheBind for structs
+//! with named fields whose types are FromCacheValue & IntoCacheValue:
+//! - `#[cache(prefix = "request")]` on the struct: prefix of load_from_cache (none by default)
+//! - `#[cache(path = "user.name")]` on a field: its path below the prefix (its name by default)
+//! - `#[cache(default)]`: a missing value loads Default::default() instead of failing. Option fields load None
+//! - `#[cache(nested)]`: the field is itself CacheBind, loaded & stored below its path
+//! - `#[cache(skip)]`: neither loaded (Default::default()) nor stored
+//!
+//! Values are read with get_as, so the schemas registered for their paths apply.
+
+use crate::{DataCache, coercion::{FromCacheValue, IntoCacheValue}, error::JsonDataCacheError};
+
+#[cfg(feature = "derive")]
+pub use json_data_cache_derive::CacheBind;
+
+pub trait CacheBind: Sized {
+    /// Prefix of load_from_cache
+    const PREFIX: &'static str = "";
+
+    /// Loads the fields from their paths below prefix
+    fn load_from_cache_at(data_cache: &DataCache, prefix: &str) -> Result<Self, JsonDataCacheError>;
+
+    /// Stores the fields at their paths below prefix, replacing the existing values
+    fn store_to_cache(&self, data_cache: &mut DataCache, prefix: &str) -> Result<(), JsonDataCacheError>;
+
+    fn load_from_cache(data_cache: &DataCache) -> Result<Self, JsonDataCacheError> {
+        Self::load_from_cache_at(data_cache, Self::PREFIX)
+    }
+}
+
+/// Path of a field below prefix
+pub fn join(prefix: &str, path: &str) -> String {
+    if prefix.is_empty() { path.to_string() } else { format!("{prefix}.{path}") }
+}
+
+/// Value of a field, missing & null values failing
+pub fn load_required<T: FromCacheValue>(data_cache: &DataCache, prefix: &str, path: &str) -> Result<T, JsonDataCacheError> {
+    let path = join(prefix, path);
+    data_cache.get_as(&path)?.ok_or_else(|| format!("Missing value at {path}").into())
+}
+
+pub fn load_optional<T: FromCacheValue>(data_cache: &DataCache, prefix: &str, path: &str) -> Result<Option<T>, JsonDataCacheError> {
+    data_cache.get_as(&join(prefix, path))
+}
+
+pub fn store<T: IntoCacheValue>(data_cache: &mut DataCache, prefix: &str, path: &str, value: &T) -> Result<(), JsonDataCacheError> {
+    let path = join(prefix, path);
+    data_cache.try_replace(&path, value.to_cache_value())
+}
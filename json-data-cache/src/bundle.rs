@@ -0,0 +1,386 @@
+//! Deployment bundles: a single .kebundle artifact holding everything an edge deployment renders with. It is a tar archive
+//! (ustar, uncompressed) whose manifest.json lists the other files by kind, keyed by template id, locale or cache path, like
+//! {"version": 1, "templates": {"page": "templates/page.html"}, "fragments": {"nav": "fragments/nav.html"},
+//! "catalogs": {"ja": "i18n/ja.json"}, "schemas": {"articles": "schemas/articles.json"}, "data": {"site": "data/site.json"}}
+//! Bundle::load registers the fragments (see register_fragment) & schemas (see register_schema) in a new cache, inserts the
+//! seed data at its paths ("" merging it at the root) and each i18n catalog at catalog_path.<locale> ("i18n" by default, for
+//! {$i18n.ja.greeting} placeholders), templates being kept in a TemplateRegistry. An "assets" file is loaded as the asset
+//! manifest, below "asset_base_url" (see load_asset_manifest). BundleBuilder writes bundles.
+//! BundleManager hot-reloads them: a new version is loaded & prepared in steps between requests, then swapped in at once.
+//! Reloading is cooperative & single threaded, there is no background loading: the caller drives build_step between requests.
+
+use std::{collections::{BTreeMap, HashMap}, io, rc::Rc, time::Duration};
+
+use serde_json::{Map, Value, json};
+
+use crate::{BuildProgress, DataCache, DataCacheOptions, error::JsonDataCacheError};
+
+/// Version of the manifest format written by BundleBuilder, the newest Bundle::load reads
+pub const BUNDLE_FORMAT_VERSION: u64 = 1;
+
+/// Path of the manifest in bundles
+pub const MANIFEST_PATH: &str = "manifest.json";
+
+/// Cache path of the i18n catalogs when the manifest has no catalog_path
+pub const DEFAULT_CATALOG_PATH: &str = "i18n";
+
+pub(crate) const BLOCK_LEN: usize = 512;
+
+/// Value of a NUL or space terminated field of a tar header
+fn header_field(header: &[u8], range: std::ops::Range<usize>) -> &[u8] {
+    let field = &header[range];
+    let end = field.iter().position(|b| *b == 0).unwrap_or(field.len());
+    field[..end].trim_ascii()
+}
+
+fn parse_octal(field: &[u8]) -> Result<usize, JsonDataCacheError> {
+    let digits = str::from_utf8(field).ok().filter(|digits| !digits.is_empty()).ok_or("Invalid tar header")?;
+    usize::from_str_radix(digits, 8).map_err(|_| "Invalid tar header".into())
+}
+
+/// Regular files of a tar archive by path, other entries (directories, links, extended headers) being skipped
+fn read_tar(bytes: &[u8]) -> Result<HashMap<String, &[u8]>, JsonDataCacheError> {
+    let mut files = HashMap::new();
+    let mut pos = 0;
+    while pos + BLOCK_LEN <= bytes.len() {
+        let header = &bytes[pos..pos + BLOCK_LEN];
+        if header.iter().all(|b| *b == 0) {
+            break;
+        }
+        let checksum = parse_octal(header_field(header, 148..156))?;
+        let sum: usize = header.iter().enumerate().map(|(idx, b)| if (148..156).contains(&idx) { b' ' as usize } else { *b as usize }).sum();
+        if checksum != sum {
+            return Err("Invalid tar header checksum".into());
+        }
+        let size = parse_octal(header_field(header, 124..136))?;
+        let data_start = pos + BLOCK_LEN;
+        let data = bytes.get(data_start..data_start + size).ok_or("Truncated tar archive")?;
+        if matches!(header[156], b'0' | 0) {
+            let mut name = String::from_utf8_lossy(header_field(header, 0..100)).into_owned();
+            let prefix = header_field(header, 345..500);
+            if &header[257..262] == b"ustar" && !prefix.is_empty() {
+                name = format!("{}/{name}", String::from_utf8_lossy(prefix));
+            }
+            files.insert(name.trim_start_matches("./").to_string(), data);
+        }
+        pos = data_start + size.div_ceil(BLOCK_LEN) * BLOCK_LEN;
+    }
+    Ok(files)
+}
+
+/// Appends a regular file to a tar archive
+pub(crate) fn write_tar_entry(archive: &mut Vec<u8>, path: &str, data: &[u8]) -> Result<(), JsonDataCacheError> {
+    if path.len() > 100 {
+        return Err(format!("Path too long for a bundle: {path}").into());
+    }
+    let mut header = [0u8; BLOCK_LEN];
+    header[..path.len()].copy_from_slice(path.as_bytes());
+    header[100..107].copy_from_slice(b"0000644");
+    header[108..115].copy_from_slice(b"0000000");
+    header[116..123].copy_from_slice(b"0000000");
+    header[124..135].copy_from_slice(format!("{:011o}", data.len()).as_bytes());
+    header[136..147].copy_from_slice(b"00000000000");
+    header[156] = b'0';
+    header[257..263].copy_from_slice(b"ustar\0");
+    header[263..265].copy_from_slice(b"00");
+    header[148..156].fill(b' ');
+    let checksum: usize = header.iter().map(|b| *b as usize).sum();
+    header[148..155].copy_from_slice(format!("{checksum:06o}\0").as_bytes());
+    archive.extend_from_slice(&header);
+    archive.extend_from_slice(data);
+    archive.resize(archive.len().div_ceil(BLOCK_LEN) * BLOCK_LEN, 0);
+    Ok(())
+}
+
+/// Templates of a bundle by id
+#[derive(Debug, Clone, Default)]
+pub struct TemplateRegistry {
+    templates: BTreeMap<String, Vec<u8>>,
+}
+
+impl TemplateRegistry {
+    /// Registers a template, replacing any previous one of the same id
+    pub fn insert(&mut self, template_id: &str, template: Vec<u8>) {
+        self.templates.insert(template_id.to_string(), template);
+    }
+
+    pub fn get(&self, template_id: &str) -> Option<&[u8]> {
+        self.templates.get(template_id).map(Vec::as_slice)
+    }
+
+    /// Ids of the templates, sorted
+    pub fn ids(&self) -> impl Iterator<Item = &str> {
+        self.templates.keys().map(String::as_str)
+    }
+
+    pub fn len(&self) -> usize {
+        self.templates.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.templates.is_empty()
+    }
+
+    /// Replaces the template against the cache (see replace_with_data_cache)
+    pub fn render<W: io::Write>(&self, template_id: &str, data_cache: &mut DataCache, writer: W) -> Result<(), JsonDataCacheError> {
+        let template = self.get(template_id).ok_or_else(|| format!("Unknown template {template_id}"))?;
+        data_cache.replace_with_data_cache(template, writer)
+    }
+}
+
+/// A loaded bundle: its manifest, the cache it is wired into and its templates
+#[derive(Debug)]
+pub struct Bundle {
+    pub manifest: Value,
+    pub data_cache: DataCache,
+    pub templates: TemplateRegistry,
+}
+
+impl Bundle {
+    /// Loads a bundle into a cache with the default options
+    pub fn load(bytes: &[u8]) -> Result<Self, JsonDataCacheError> {
+        Self::load_with_options(bytes, DataCacheOptions::default())
+    }
+
+    /// Loads a bundle into a new cache. Fails on any invalid or missing file listed by the manifest
+    pub fn load_with_options(bytes: &[u8], options: DataCacheOptions) -> Result<Self, JsonDataCacheError> {
+        let files = read_tar(bytes)?;
+        let manifest = json_file(&files, MANIFEST_PATH)?;
+        match manifest.get("version").and_then(Value::as_u64) {
+            Some(version) if version <= BUNDLE_FORMAT_VERSION => {},
+            Some(version) => return Err(format!("Unsupported bundle version {version}").into()),
+            None => return Err("Bundle manifest has no version".into()),
+        }
+        let entries = |kind: &str| -> Result<Vec<(&String, &str)>, JsonDataCacheError> {
+            let Some(entries) = manifest.get(kind) else {
+                return Ok(Vec::new());
+            };
+            let entries = entries.as_object().ok_or_else(|| format!("Bundle manifest {kind} must be an object"))?;
+            entries.iter()
+                .map(|(name, file)| file.as_str().map(|file| (name, file)).ok_or_else(|| format!("Bundle manifest {kind}.{name} must be a file path").into()))
+                .collect()
+        };
+
+        let mut templates = TemplateRegistry::default();
+        for (template_id, file) in entries("templates")? {
+            templates.insert(template_id, file_bytes(&files, file)?.to_vec());
+        }
+        let mut data_cache = DataCache::new(options);
+        for (template_id, file) in entries("fragments")? {
+            data_cache.register_fragment(template_id, file_bytes(&files, file)?.to_vec());
+        }
+        for (path, file) in entries("schemas")? {
+            data_cache.register_schema(path, json_file(&files, file)?);
+        }
+        for (path, file) in entries("data")? {
+            let value = json_file(&files, file)?;
+            if path.is_empty() {
+                data_cache.try_merge(value)?;
+            } else {
+                data_cache.try_insert(path, value)?;
+            }
+        }
+        let catalog_path = manifest.get("catalog_path").and_then(Value::as_str).unwrap_or(DEFAULT_CATALOG_PATH);
+        for (locale, file) in entries("catalogs")? {
+            data_cache.try_insert(&format!("{catalog_path}.{locale}"), json_file(&files, file)?)?;
+        }
+        if let Some(file) = manifest.get("assets") {
+            let file = file.as_str().ok_or("Bundle manifest assets must be a file path")?;
+            let base_url = manifest.get("asset_base_url").and_then(Value::as_str).unwrap_or_default();
+            data_cache.load_asset_manifest(&json_file(&files, file)?, base_url)?;
+        }
+        Ok(Self { manifest, data_cache, templates })
+    }
+
+    /// Renders a template of the bundle without modifying it, which must be prepared (see DataCache::replace_with_caches)
+    pub fn render<W: io::Write>(&self, template_id: &str, writer: W) -> Result<(), JsonDataCacheError> {
+        let template = self.templates.get(template_id).ok_or_else(|| format!("Unknown template {template_id}"))?;
+        DataCache::replace_with_caches(&[&self.data_cache], template, writer)
+    }
+
+    /// Same as render, the values of the request cache (like the article of the route) taking precedence over the ones of
+    /// the bundle. Prepares the request cache, the bundle being left as it is
+    pub fn render_with<W: io::Write>(&self, template_id: &str, request_cache: &mut DataCache, writer: W) -> Result<(), JsonDataCacheError> {
+        let template = self.templates.get(template_id).ok_or_else(|| format!("Unknown template {template_id}"))?;
+        request_cache.prepare()?;
+        DataCache::replace_with_caches(&[request_cache, &self.data_cache], template, writer)
+    }
+}
+
+/// Serves the current version of a bundle while the next one is prepared. current hands out the prepared bundle, kept alive
+/// by the renders holding it: once the next version is swapped in, they finish on the version they started with, and
+/// the following ones get the new version
+/// Bundles are shared as Rc<Bundle>, so the manager & its bundles stay on one thread: nothing is loaded in the background,
+/// the staged version only advances when the caller runs build_step, typically with a small budget between requests (or
+/// after responding, like in waitUntil on wasm runtimes)
+#[derive(Debug, Default)]
+pub struct BundleManager {
+    current: Option<Rc<Bundle>>,
+    pending: Option<Bundle>,
+    generation: u64, // Number of swapped in versions
+}
+
+impl BundleManager {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The bundle of the last swapped in version, prepared, if any
+    pub fn current(&self) -> Option<Rc<Bundle>> {
+        self.current.clone()
+    }
+
+    /// Number of versions swapped in so far, for logs & cache keys
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    /// True while a staged version is not swapped in yet
+    pub fn is_pending(&self) -> bool {
+        self.pending.is_some()
+    }
+
+    /// Loads the next version with the default options, see stage_with_options
+    pub fn stage(&mut self, bytes: &[u8]) -> Result<(), JsonDataCacheError> {
+        self.stage_with_options(bytes, DataCacheOptions::default())
+    }
+
+    /// Loads the next version, replacing any staged one. It is swapped in by build_step once prepared: until then the
+    /// current version keeps being served. A bundle failing to load leaves the current & staged versions as they are
+    pub fn stage_with_options(&mut self, bytes: &[u8], options: DataCacheOptions) -> Result<(), JsonDataCacheError> {
+        self.pending = Some(Bundle::load_with_options(bytes, options)?);
+        Ok(())
+    }
+
+    /// Prepares the staged version (see DataCache::build_step) until budget is spent, swapping it in once done
+    /// Done is returned when nothing is staged anymore. A failing preparation drops the staged version
+    pub fn build_step(&mut self, budget: Duration) -> Result<BuildProgress, JsonDataCacheError> {
+        let Some(pending) = self.pending.as_mut() else {
+            return Ok(BuildProgress::Done);
+        };
+        match pending.data_cache.build_step(budget) {
+            Ok(BuildProgress::Done) => {
+                self.current = self.pending.take().map(Rc::new);
+                self.generation += 1;
+                Ok(BuildProgress::Done)
+            },
+            Ok(progress) => Ok(progress),
+            Err(e) => {
+                self.pending = None;
+                Err(e)
+            },
+        }
+    }
+
+    /// Stages, prepares & swaps in a bundle at once, returning it
+    pub fn load(&mut self, bytes: &[u8], options: DataCacheOptions) -> Result<Rc<Bundle>, JsonDataCacheError> {
+        self.stage_with_options(bytes, options)?;
+        while self.build_step(Duration::MAX)? != BuildProgress::Done {}
+        Ok(self.current().unwrap())
+    }
+}
+
+fn file_bytes<'a>(files: &HashMap<String, &'a [u8]>, path: &str) -> Result<&'a [u8], JsonDataCacheError> {
+    files.get(path.trim_start_matches("./")).copied().ok_or_else(|| format!("Missing {path} in bundle").into())
+}
+
+fn json_file(files: &HashMap<String, &[u8]>, path: &str) -> Result<Value, JsonDataCacheError> {
+    serde_json::from_slice(file_bytes(files, path)?).map_err(|e| format!("Invalid JSON in {path} : {e}").into())
+}
+
+/// Writes bundles, files being named after their kind & name, like templates/page.html
+#[derive(Debug, Clone, Default)]
+pub struct BundleBuilder {
+    templates: BTreeMap<String, Vec<u8>>,
+    fragments: BTreeMap<String, Vec<u8>>,
+    catalogs: BTreeMap<String, Value>,
+    schemas: BTreeMap<String, Value>,
+    data: BTreeMap<String, Value>,
+    catalog_path: Option<String>,
+    assets: Option<(Value, String)>, // Asset manifest & base URL
+}
+
+impl BundleBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn template(mut self, template_id: &str, template: &[u8]) -> Self {
+        self.templates.insert(template_id.to_string(), template.to_vec());
+        self
+    }
+
+    pub fn fragment(mut self, template_id: &str, template: &[u8]) -> Self {
+        self.fragments.insert(template_id.to_string(), template.to_vec());
+        self
+    }
+
+    pub fn catalog(mut self, locale: &str, catalog: Value) -> Self {
+        self.catalogs.insert(locale.to_string(), catalog);
+        self
+    }
+
+    pub fn schema(mut self, path: &str, schema: Value) -> Self {
+        self.schemas.insert(path.to_string(), schema);
+        self
+    }
+
+    /// Seed data inserted at path, "" merging it at the root
+    pub fn data(mut self, path: &str, value: Value) -> Self {
+        self.data.insert(path.to_string(), value);
+        self
+    }
+
+    /// Cache path of the catalogs (DEFAULT_CATALOG_PATH by default)
+    pub fn catalog_path(mut self, catalog_path: &str) -> Self {
+        self.catalog_path = Some(catalog_path.to_string());
+        self
+    }
+
+    /// Asset manifest of the front end build, its files being served below base_url (see load_asset_manifest)
+    pub fn assets(mut self, manifest: Value, base_url: &str) -> Self {
+        self.assets = Some((manifest, base_url.to_string()));
+        self
+    }
+
+    /// The tar archive of the bundle
+    pub fn to_bytes(&self) -> Result<Vec<u8>, JsonDataCacheError> {
+        let mut manifest = json!({"version": BUNDLE_FORMAT_VERSION});
+        if let Some(catalog_path) = &self.catalog_path {
+            manifest["catalog_path"] = json!(catalog_path);
+        }
+        let mut files: Vec<(String, Vec<u8>)> = Vec::new();
+        let mut add_files = |kind: &str, entries: Vec<(String, Vec<u8>)>, extension: &str| {
+            if entries.is_empty() {
+                return;
+            }
+            let mut listed = Map::new();
+            for (idx, (name, bytes)) in entries.into_iter().enumerate() {
+                // Indexes keep the file names unique & valid whatever the names (like "" for the root data)
+                let file = format!("{kind}/{idx}{extension}");
+                listed.insert(name, json!(file));
+                files.push((file, bytes));
+            }
+            manifest[kind] = Value::Object(listed);
+        };
+        let to_json = |values: &BTreeMap<String, Value>| values.iter().map(|(name, value)| (name.clone(), value.to_string().into_bytes())).collect();
+        add_files("templates", self.templates.clone().into_iter().collect(), ".html");
+        add_files("fragments", self.fragments.clone().into_iter().collect(), ".html");
+        add_files("catalogs", to_json(&self.catalogs), ".json");
+        add_files("schemas", to_json(&self.schemas), ".json");
+        add_files("data", to_json(&self.data), ".json");
+        if let Some((assets, base_url)) = &self.assets {
+            manifest["assets"] = json!("assets.json");
+            manifest["asset_base_url"] = json!(base_url);
+            files.push((String::from("assets.json"), assets.to_string().into_bytes()));
+        }
+
+        let mut archive = Vec::new();
+        write_tar_entry(&mut archive, MANIFEST_PATH, manifest.to_string().as_bytes())?;
+        for (path, bytes) in &files {
+            write_tar_entry(&mut archive, path, bytes)?;
+        }
+        archive.resize(archive.len() + 2 * BLOCK_LEN, 0);
+        Ok(archive)
+    }
+}
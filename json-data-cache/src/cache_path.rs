@@ -0,0 +1,156 @@
+use std::{fmt, ops::Deref, str::FromStr};
+
+use crate::error::JsonDataCacheError;
+
+/// A dotted path of the tree, built once and reused. It dereferences to its dotted form, so it is accepted by every
+/// API taking a path: `data_cache.get(&path)`
+/// Example: CachePath::root().join("content").join("list").join(0) is "content.list.0", as is path!("content"."list"[0])
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CachePath {
+    path: String,
+}
+
+/// A key or array index, joined to a CachePath
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PathSegment {
+    Key(String),
+    Index(usize),
+}
+
+impl From<&str> for PathSegment {
+    fn from(key: &str) -> Self {
+        PathSegment::Key(key.to_string())
+    }
+}
+
+impl From<String> for PathSegment {
+    fn from(key: String) -> Self {
+        PathSegment::Key(key)
+    }
+}
+
+impl From<usize> for PathSegment {
+    fn from(idx: usize) -> Self {
+        PathSegment::Index(idx)
+    }
+}
+
+impl CachePath {
+    /// The empty path, of the whole tree
+    pub fn root() -> Self {
+        Self::default()
+    }
+
+    /// Parses a dotted path, failing on empty segments (a trailing dot, which appends to arrays on insert, is kept)
+    pub fn parse(path: &str) -> Result<Self, JsonDataCacheError> {
+        let segments = path.strip_suffix('.').unwrap_or(path);
+        if !path.is_empty() && segments.split('.').any(str::is_empty) {
+            return Err(format!("Invalid path {path} : empty segment").into());
+        }
+        Ok(Self { path: path.to_string() })
+    }
+
+    /// This path followed by the segment, panicking on keys paths can not address (see try_join)
+    pub fn join(&self, segment: impl Into<PathSegment>) -> Self {
+        self.try_join(segment).unwrap_or_else(|e| panic!("{}", e.msg))
+    }
+
+    /// Same as join, failing on empty keys & keys containing dots, which would be split into several segments
+    pub fn try_join(&self, segment: impl Into<PathSegment>) -> Result<Self, JsonDataCacheError> {
+        let mut path = self.path.trim_end_matches('.').to_string();
+        if !path.is_empty() {
+            path.push('.');
+        }
+        match segment.into() {
+            PathSegment::Key(key) if key.is_empty() || key.contains('.') => {
+                return Err(format!("Invalid key {key:?} joined to {}: keys must not be empty nor contain '.'", self.path).into());
+            },
+            PathSegment::Key(key) => path.push_str(&key),
+            PathSegment::Index(idx) => path.push_str(&idx.to_string()),
+        }
+        Ok(Self { path })
+    }
+
+    /// Same path with a trailing dot, appending to the array at this path on insert
+    pub fn append(&self) -> Self {
+        Self { path: format!("{}.", self.path.trim_end_matches('.')) }
+    }
+
+    pub fn parent(&self) -> Option<Self> {
+        let path = self.path.trim_end_matches('.');
+        if path.is_empty() {
+            return None;
+        }
+        Some(Self { path: path.rfind('.').map(|end| path[..end].to_string()).unwrap_or_default() })
+    }
+
+    pub fn segments(&self) -> impl Iterator<Item = &str> {
+        self.path.trim_end_matches('.').split('.').filter(|segment| !segment.is_empty())
+    }
+
+    pub fn last(&self) -> Option<&str> {
+        self.segments().last()
+    }
+
+    pub fn is_root(&self) -> bool {
+        self.path.is_empty()
+    }
+
+    pub fn starts_with(&self, ancestor: &CachePath) -> bool {
+        ancestor.is_root() || self.path == ancestor.path || self.path.starts_with(&format!("{}.", ancestor.path))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.path
+    }
+}
+
+impl Deref for CachePath {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.path
+    }
+}
+
+impl AsRef<str> for CachePath {
+    fn as_ref(&self) -> &str {
+        &self.path
+    }
+}
+
+impl fmt::Display for CachePath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.path)
+    }
+}
+
+impl FromStr for CachePath {
+    type Err = JsonDataCacheError;
+
+    fn from_str(path: &str) -> Result<Self, Self::Err> {
+        Self::parse(path)
+    }
+}
+
+impl From<CachePath> for String {
+    fn from(path: CachePath) -> Self {
+        path.path
+    }
+}
+
+/// Builds a CachePath from string literal keys separated by dots & bracketed array indexes
+/// Example: path!("content"."list"[0]."title") is "content.list.0.title"
+#[macro_export]
+macro_rules! path {
+    (@segments ($path:expr)) => { $path };
+    (@segments ($path:expr) . $key:literal $($rest:tt)*) => {
+        $crate::path!(@segments ($path.join($key)) $($rest)*)
+    };
+    (@segments ($path:expr) [$idx:expr] $($rest:tt)*) => {
+        $crate::path!(@segments ($path.join($idx as usize)) $($rest)*)
+    };
+    ($first:literal $($rest:tt)*) => {
+        $crate::path!(@segments ($crate::cache_path::CachePath::root().join($first)) $($rest)*)
+    };
+}
@@ -0,0 +1,266 @@
+//! Typed reads with get_as. Values are converted strictly (a bool must be a JSON boolean), unless the schema registered for
+//! their path (a subset of JSON Schema: `type`, `format`, `properties` & `items`) declares their type: the representations
+//! integrations commonly send are then accepted, like "1" / "0" for booleans or "2023-01-02" for dates.
+//! Conversions of a type can be replaced with set_coercion.
+
+use std::{any::{Any, TypeId, type_name}, collections::HashMap, fmt, rc::Rc};
+
+use serde_json::Value;
+
+use crate::{DataCache, error::JsonDataCacheError, mock::civil_from_days};
+
+/// Types readable by get_as
+pub trait FromCacheValue: Sized + 'static {
+    /// Strict conversion, without schema
+    fn from_value(value: &Value) -> Option<Self>;
+
+    /// Conversion of a value whose path has a schema
+    fn coerce(value: &Value, _schema: &Value) -> Option<Self> {
+        Self::from_value(value)
+    }
+}
+
+fn schema_type(schema: &Value) -> Option<&str> {
+    schema.get("type").and_then(Value::as_str)
+}
+
+impl FromCacheValue for bool {
+    fn from_value(value: &Value) -> Option<Self> {
+        value.as_bool()
+    }
+
+    fn coerce(value: &Value, schema: &Value) -> Option<Self> {
+        if schema_type(schema) != Some("boolean") {
+            return Self::from_value(value);
+        }
+        match value {
+            Value::Bool(b) => Some(*b),
+            Value::Number(n) => match n.as_i64() {
+                Some(1) => Some(true),
+                Some(0) => Some(false),
+                _ => None,
+            },
+            Value::String(s) => match s.trim().to_ascii_lowercase().as_str() {
+                "1" | "true" | "yes" | "on" => Some(true),
+                "0" | "false" | "no" | "off" | "" => Some(false),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
+impl FromCacheValue for i64 {
+    fn from_value(value: &Value) -> Option<Self> {
+        value.as_i64()
+    }
+
+    fn coerce(value: &Value, schema: &Value) -> Option<Self> {
+        match (value, schema_type(schema)) {
+            (Value::String(s), Some("integer" | "number")) => s.trim().parse().ok(),
+            (Value::Number(n), Some("integer" | "number")) => n.as_i64().or_else(|| n.as_f64().filter(|f| f.fract() == 0.0).map(|f| f as i64)),
+            _ => Self::from_value(value),
+        }
+    }
+}
+
+impl FromCacheValue for u64 {
+    fn from_value(value: &Value) -> Option<Self> {
+        value.as_u64()
+    }
+
+    fn coerce(value: &Value, schema: &Value) -> Option<Self> {
+        i64::coerce(value, schema).and_then(|i| u64::try_from(i).ok()).or_else(|| Self::from_value(value))
+    }
+}
+
+impl FromCacheValue for f64 {
+    fn from_value(value: &Value) -> Option<Self> {
+        value.as_f64()
+    }
+
+    fn coerce(value: &Value, schema: &Value) -> Option<Self> {
+        match (value, schema_type(schema)) {
+            (Value::String(s), Some("integer" | "number")) => s.trim().parse().ok().filter(|f: &f64| f.is_finite()),
+            _ => Self::from_value(value),
+        }
+    }
+}
+
+impl FromCacheValue for String {
+    fn from_value(value: &Value) -> Option<Self> {
+        value.as_str().map(str::to_string)
+    }
+
+    fn coerce(value: &Value, schema: &Value) -> Option<Self> {
+        match (value, schema_type(schema)) {
+            (Value::Number(n), Some("string")) => Some(n.to_string()),
+            (Value::Bool(b), Some("string")) => Some(b.to_string()),
+            _ => Self::from_value(value),
+        }
+    }
+}
+
+impl FromCacheValue for Value {
+    fn from_value(value: &Value) -> Option<Self> {
+        Some(value.clone())
+    }
+}
+
+/// Calendar date, read from "YYYY-MM-DD" strings
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Date {
+    pub year: i64,
+    pub month: u32,
+    pub day: u32,
+}
+
+impl Date {
+    pub fn parse(s: &str) -> Option<Self> {
+        let bytes = s.as_bytes();
+        if bytes.len() != 10 || bytes[4] != b'-' || bytes[7] != b'-' {
+            return None;
+        }
+        let number = |range: std::ops::Range<usize>| s.get(range).filter(|part| part.bytes().all(|b| b.is_ascii_digit()))?.parse().ok();
+        let date = Date { year: number(0..4)? as i64, month: number(5..7)?, day: number(8..10)? };
+        let is_leap_year = date.year % 4 == 0 && (date.year % 100 != 0 || date.year % 400 == 0);
+        let month_days = match date.month {
+            1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+            4 | 6 | 9 | 11 => 30,
+            2 if is_leap_year => 29,
+            2 => 28,
+            _ => return None,
+        };
+        (1..=month_days).contains(&date.day).then_some(date)
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+impl FromCacheValue for Date {
+    fn from_value(value: &Value) -> Option<Self> {
+        value.as_str().and_then(Date::parse)
+    }
+
+    /// With format date or date-time, the date of date-time strings and of Unix timestamps (in seconds, UTC) is accepted too
+    fn coerce(value: &Value, schema: &Value) -> Option<Self> {
+        if !matches!(schema.get("format").and_then(Value::as_str), Some("date" | "date-time")) {
+            return Self::from_value(value);
+        }
+        match value {
+            Value::String(s) => s.get(..10).and_then(Date::parse),
+            Value::Number(n) => {
+                let (year, month, day) = civil_from_days(n.as_i64()?.div_euclid(86_400));
+                Some(Date { year, month, day })
+            },
+            _ => None,
+        }
+    }
+}
+
+/// Types stored by CacheBind::store_to_cache, the reverse of FromCacheValue
+pub trait IntoCacheValue {
+    fn to_cache_value(&self) -> Value;
+}
+
+macro_rules! into_cache_value {
+    ($($ty:ty),*) => {
+        $(impl IntoCacheValue for $ty {
+            fn to_cache_value(&self) -> Value {
+                Value::from(self.clone())
+            }
+        })*
+    };
+}
+
+into_cache_value!(bool, i64, u64, f64, String, Value);
+
+impl IntoCacheValue for Date {
+    fn to_cache_value(&self) -> Value {
+        Value::String(self.to_string())
+    }
+}
+
+impl<T: IntoCacheValue> IntoCacheValue for Option<T> {
+    fn to_cache_value(&self) -> Value {
+        self.as_ref().map_or(Value::Null, T::to_cache_value)
+    }
+}
+
+type Coercion<T> = Box<dyn Fn(&Value, Option<&Value>) -> Option<T>>;
+
+/// Schemas registered by register_schema & conversions set by set_coercion
+#[derive(Clone, Default)]
+pub(crate) struct DataCacheSchemas {
+    schemas: HashMap<String, Value>,
+    coercions: HashMap<TypeId, Rc<dyn Any>>, // Coercion<T> by TypeId of T
+}
+
+impl fmt::Debug for DataCacheSchemas {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DataCacheSchemas")
+            .field("schemas", &self.schemas)
+            .field("coercions", &self.coercions.len())
+            .finish()
+    }
+}
+
+impl DataCache {
+    /// Registers the schema of the value at path, replacing any previous one. Descendants use the matching
+    /// `properties` & `items` of the schema, unless they have their own
+    pub fn register_schema(&mut self, path: &str, schema: Value) {
+        self.schemas.schemas.insert(path.to_string(), schema);
+    }
+
+    /// The schema applying to path: the registered schema of path or of its nearest ancestor, descended to path
+    pub fn schema(&self, path: &str) -> Option<&Value> {
+        if self.schemas.schemas.is_empty() {
+            return None;
+        }
+        let mut end = path.len();
+        let mut schema = loop {
+            if let Some(schema) = self.schemas.schemas.get(&path[..end]) {
+                break schema;
+            }
+            end = path[..end].rfind('.')?;
+        };
+        for segment in path[end..].split('.').filter(|segment| !segment.is_empty()) {
+            schema = match schema.get("properties").and_then(|properties| properties.get(segment)) {
+                Some(property_schema) => property_schema,
+                None if segment.parse::<usize>().is_ok() => schema.get("items")?,
+                None => return None,
+            };
+        }
+        Some(schema)
+    }
+
+    /// Replaces the conversion of T by get_as, called with the value and the schema of its path
+    pub fn set_coercion<T, F>(&mut self, coercion: F)
+    where
+        T: 'static,
+        F: Fn(&Value, Option<&Value>) -> Option<T> + 'static,
+    {
+        let coercion: Coercion<T> = Box::new(coercion);
+        self.schemas.coercions.insert(TypeId::of::<T>(), Rc::new(coercion));
+    }
+
+    /// Reads the value at path as T (see the coercion module). Missing & null values are None, values which can not be
+    /// converted an error
+    pub fn get_as<T: FromCacheValue>(&self, path: &str) -> Result<Option<T>, JsonDataCacheError> {
+        let Some(value) = self.get(path).filter(|value| !value.is_null()) else {
+            return Ok(None);
+        };
+        let schema = self.schema(path);
+        let coercion = self.schemas.coercions.get(&TypeId::of::<T>()).and_then(|coercion| coercion.downcast_ref::<Coercion<T>>());
+        let converted = match (coercion, schema) {
+            (Some(coercion), _) => coercion(value, schema),
+            (None, Some(schema)) => T::coerce(value, schema),
+            (None, None) => T::from_value(value),
+        };
+        converted.map(Some).ok_or_else(|| format!("Value {value} at {path} can not be read as {}", type_name::<T>()).into())
+    }
+}
@@ -0,0 +1,275 @@
+//! Compression of large strings: once compress_strings is set for a prefix, the string values under it longer than its
+//! threshold are kept compressed outside of the tree, which holds null in their place. get of their own path,
+//! replacements ({$key} as well as {$$parent}), persist_to, fallback chains and string_values_view decompress them,
+//! the decompressed strings being kept until the next modification of the cache. get of an ancestor returns a copy of
+//! its subtree with the strings restored, kept until the next modification as well.
+//! as_string_values_map sees the null in the values of the ancestors.
+//!
+//! The format is a byte oriented LZ77: the varint length of the original data, then sequences of a varint count of
+//! literal bytes, those bytes, and (except for the last sequence) the varint offset & varint length minus 4 of a match.
+
+use std::{cell::OnceCell, collections::HashMap};
+
+use serde_json::Value;
+
+use crate::{DataCache, error::JsonDataCacheError};
+
+const MIN_MATCH: usize = 4;
+const HASH_BITS: u32 = 14;
+const MAX_OFFSET: usize = 1 << 16;
+
+pub(crate) fn push_varint(output: &mut Vec<u8>, mut value: usize) {
+    while value >= 0x80 {
+        output.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    output.push(value as u8);
+}
+
+pub(crate) fn read_varint(data: &[u8], pos: &mut usize) -> Result<usize, JsonDataCacheError> {
+    let mut value = 0usize;
+    for shift in (0..usize::BITS).step_by(7) {
+        let byte = *data.get(*pos).ok_or("Truncated compressed data")?;
+        *pos += 1;
+        value |= usize::from(byte & 0x7f) << shift;
+        if byte & 0x80 == 0 {
+            return Ok(value);
+        }
+    }
+    Err("Invalid varint in compressed data".into())
+}
+
+fn hash(bytes: &[u8]) -> usize {
+    let word = u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+    (word.wrapping_mul(2_654_435_761) >> (32 - HASH_BITS)) as usize
+}
+
+pub fn compress(data: &[u8]) -> Vec<u8> {
+    let mut output = Vec::with_capacity(data.len() / 2 + 16);
+    push_varint(&mut output, data.len());
+    let mut table = vec![usize::MAX; 1 << HASH_BITS]; // Last position of each hashed 4 bytes
+    let (mut pos, mut literal_start) = (0, 0);
+    while pos + MIN_MATCH <= data.len() {
+        let slot = &mut table[hash(&data[pos..])];
+        let candidate = std::mem::replace(slot, pos);
+        if candidate == usize::MAX || pos - candidate > MAX_OFFSET || data[candidate..candidate + MIN_MATCH] != data[pos..pos + MIN_MATCH] {
+            pos += 1;
+            continue;
+        }
+        let len = MIN_MATCH + data[pos + MIN_MATCH..].iter().zip(&data[candidate + MIN_MATCH..]).take_while(|(a, b)| a == b).count();
+        push_varint(&mut output, pos - literal_start);
+        output.extend_from_slice(&data[literal_start..pos]);
+        push_varint(&mut output, pos - candidate);
+        push_varint(&mut output, len - MIN_MATCH);
+        pos += len;
+        literal_start = pos;
+    }
+    push_varint(&mut output, data.len() - literal_start);
+    output.extend_from_slice(&data[literal_start..]);
+    output
+}
+
+pub fn decompress(data: &[u8]) -> Result<Vec<u8>, JsonDataCacheError> {
+    let mut pos = 0;
+    let len = read_varint(data, &mut pos)?;
+    let mut output = Vec::with_capacity(len);
+    loop {
+        let literal_len = read_varint(data, &mut pos)?;
+        let literals = data.get(pos..pos + literal_len).ok_or("Truncated compressed data")?;
+        output.extend_from_slice(literals);
+        pos += literal_len;
+        if pos == data.len() {
+            break;
+        }
+        let offset = read_varint(data, &mut pos)?;
+        let match_len = read_varint(data, &mut pos)? + MIN_MATCH;
+        if offset == 0 || offset > output.len() || output.len() + match_len > len {
+            return Err("Invalid match in compressed data".into());
+        }
+        // Matches may overlap the bytes they produce
+        let start = output.len() - offset;
+        for idx in start..start + match_len {
+            output.push(output[idx]);
+        }
+    }
+    if output.len() != len {
+        return Err(format!("Compressed data decompressed to {} bytes instead of {len}", output.len()).into());
+    }
+    Ok(output)
+}
+
+/// Figures of the strings compressed by compress_strings
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CompressionStats {
+    pub count: usize,
+    pub original_bytes: usize,
+    pub compressed_bytes: usize,
+}
+
+#[derive(Debug)]
+struct CompressedString {
+    data: Vec<u8>,
+    original_len: usize,
+    decompressed: OnceCell<Value>, // Value::String, built by the first read
+}
+
+impl CompressedString {
+    fn value(&self) -> &Value {
+        self.decompressed.get_or_init(|| {
+            let bytes = decompress(&self.data).unwrap_or_default();
+            Value::String(String::from_utf8(bytes).unwrap_or_default())
+        })
+    }
+}
+
+/// Prefixes set by compress_strings & the strings compressed under them, by path
+#[derive(Debug, Default)]
+pub(crate) struct DataCacheCompression {
+    prefixes: Vec<(String, usize)>, // With the minimum length in bytes of compressed strings
+    values: HashMap<String, CompressedString>,
+    ancestors: OnceCell<HashMap<String, OnceCell<Value>>>, // Ancestors of the compressed strings, restored by their first read
+}
+
+impl DataCacheCompression {
+    pub(crate) fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    pub(crate) fn get(&self, path: &str) -> Option<&Value> {
+        self.values.get(path).map(CompressedString::value)
+    }
+
+    /// Value of an ancestor of compressed strings, with the decompressed strings in place of their null placeholders
+    pub(crate) fn get_ancestor(&self, root: &Value, path: &str) -> Option<&Value> {
+        if self.values.is_empty() {
+            return None;
+        }
+        let ancestors = self.ancestors.get_or_init(|| {
+            self.values.keys()
+                .flat_map(|key| key.match_indices('.').map(|(end, _)| (key[..end].to_string(), OnceCell::new())))
+                .collect()
+        });
+        let restored = ancestors.get(path)?;
+        Some(restored.get_or_init(|| {
+            let mut value = DataCache::lookup(root, path).cloned().unwrap_or_default();
+            self.restore(&mut value, path);
+            value
+        }))
+    }
+
+    /// Decompressed strings by path, relative to prefix (all of them for "")
+    #[cfg(feature = "serializer")]
+    pub(crate) fn values_under<'a>(&'a self, prefix: &'a str) -> impl Iterator<Item = (&'a str, &'a Value)> {
+        self.values().filter_map(move |(path, value)| if prefix.is_empty() {
+            Some((path, value))
+        } else {
+            path.strip_prefix(prefix).and_then(|rest| rest.strip_prefix('.')).map(|rest| (rest, value))
+        })
+    }
+
+    /// Decompressed strings by path
+    pub(crate) fn values(&self) -> impl Iterator<Item = (&str, &Value)> {
+        self.values.iter().map(|(path, compressed)| (path.as_str(), compressed.value()))
+    }
+
+    /// Writes the decompressed strings of path & its descendants into value, the value at path
+    pub(crate) fn restore(&self, value: &mut Value, path: &str) {
+        for (key, compressed) in &self.values {
+            let relative = match key.strip_prefix(path) {
+                Some(relative) if path.is_empty() => relative,
+                Some(relative) if relative.is_empty() || relative.starts_with('.') => relative.trim_start_matches('.'),
+                _ => continue,
+            };
+            let pointer = if relative.is_empty() { String::new() } else { DataCache::target_to_pointer(relative) };
+            if let Some(placeholder) = value.pointer_mut(&pointer) {
+                *placeholder = compressed.value().clone();
+            }
+        }
+    }
+
+    /// Forgets the strings at & below path, about to be replaced by null (other values replace the null placeholders)
+    pub(crate) fn forget(&mut self, path: &str) {
+        if !self.values.is_empty() && !path.ends_with('.') {
+            let prefix = format!("{path}.");
+            self.values.retain(|key, _| key != path && !key.starts_with(&prefix));
+            self.ancestors.take();
+        }
+    }
+
+    /// Forgets the strings whose null placeholder was replaced or removed, and releases the decompressed strings
+    pub(crate) fn on_modified(&mut self, root: &Value) {
+        self.values.retain(|path, _| DataCache::lookup(root, path).is_some_and(Value::is_null));
+        for compressed in self.values.values_mut() {
+            compressed.decompressed.take();
+        }
+        self.ancestors.take();
+    }
+}
+
+/// Compresses the strings of value (at path) of at least min_len bytes, replacing them with null
+fn compress_rec(values: &mut HashMap<String, CompressedString>, value: &mut Value, path: &mut String, min_len: usize) {
+    let original_path_len = path.len();
+    let mut child = |values: &mut HashMap<String, CompressedString>, key: &str, value: &mut Value| {
+        if !path.is_empty() {
+            path.push('.');
+        }
+        path.push_str(key);
+        compress_rec(values, value, path, min_len);
+        path.truncate(original_path_len);
+    };
+    match value {
+        Value::Object(o) => o.iter_mut().for_each(|(key, value)| child(values, key, value)),
+        Value::Array(a) => a.iter_mut().enumerate().for_each(|(idx, value)| child(values, &idx.to_string(), value)),
+        Value::String(s) if s.len() >= min_len => {
+            let data = compress(s.as_bytes());
+            values.insert(path.clone(), CompressedString { data, original_len: s.len(), decompressed: OnceCell::new() });
+            *value = Value::Null;
+        },
+        _ => {},
+    }
+}
+
+impl DataCache {
+    /// Keeps the strings under prefix (the whole tree for "") of at least min_len bytes compressed, including the ones
+    /// already inserted (see the compression module). Replaces the threshold previously set for the same prefix
+    pub fn compress_strings(&mut self, prefix: &str, min_len: usize) {
+        let prefixes = &mut self.compression.prefixes;
+        prefixes.retain(|(existing, _)| existing != prefix);
+        prefixes.push((prefix.to_string(), min_len));
+        self.compress_inserted(&[prefix]);
+        self.on_after_insert();
+    }
+
+    pub fn compression_stats(&self) -> CompressionStats {
+        self.compression.values.values().fold(CompressionStats::default(), |stats, compressed| CompressionStats {
+            count: stats.count + 1,
+            original_bytes: stats.original_bytes + compressed.original_len,
+            compressed_bytes: stats.compressed_bytes + compressed.data.len(),
+        })
+    }
+
+    /// Compresses the strings inserted at paths matching the prefixes of compress_strings
+    pub(crate) fn compress_inserted(&mut self, paths: &[&str]) {
+        if self.compression.prefixes.is_empty() {
+            return;
+        }
+        let is_within = |path: &str, prefix: &str| prefix.is_empty() || path == prefix || path.starts_with(&format!("{prefix}."));
+        for path in paths {
+            let path = path.trim_end_matches('.');
+            for (prefix, min_len) in &self.compression.prefixes {
+                // Subtree of the deepest of both
+                let mut subtree_path = if is_within(path, prefix) {
+                    path.to_string()
+                } else if is_within(prefix, path) {
+                    prefix.clone()
+                } else {
+                    continue;
+                };
+                let pointer = if subtree_path.is_empty() { String::new() } else { Self::target_to_pointer(&subtree_path) };
+                if let Some(value) = self.root.pointer_mut(&pointer) {
+                    compress_rec(&mut self.compression.values, value, &mut subtree_path, *min_len);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,365 @@
+use std::{borrow::Cow, cell::OnceCell};
+
+use serde_json::{Number, Value};
+
+use crate::{DataCache, error::JsonDataCacheError};
+
+/// A value derived from other paths of the cache. Modifying the paths it depends on only marks it outdated: it is computed
+/// again on the next get of its path, or into the tree by prepare_computed (called by prepare & string_values_view)
+/// Expressions support numbers, paths (a single `*` wildcard is allowed in function arguments), + - * / and parentheses,
+/// and the aggregate functions sum, count, min, max and avg. Example: "sum(cart.items.*.price) * 1.1"
+#[derive(Debug, Clone)]
+pub struct ComputedPath {
+    path: String,
+    expr: Expr,
+    dependencies: Vec<String>, // Referenced paths, truncated before their wildcard
+    value: OnceCell<Value>, // Computed since the last modification of the dependencies, if read
+    is_in_tree: bool, // False while the tree holds no value or an outdated one
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Expr {
+    Number(f64),
+    Path(String),
+    Neg(Box<Expr>),
+    Binary(Box<Expr>, BinaryOp, Box<Expr>),
+    Call(Function, Vec<Expr>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum BinaryOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum Function {
+    Sum,
+    Count,
+    Min,
+    Max,
+    Avg,
+}
+
+/// Recursive descent parser over the expression string
+struct Parser<'a> {
+    input: &'a str,
+    position: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn skip_whitespaces(&mut self) {
+        let trimmed = self.input[self.position..].trim_start();
+        self.position = self.input.len() - trimmed.len();
+    }
+
+    fn peek(&mut self) -> Option<char> {
+        self.skip_whitespaces();
+        self.input[self.position..].chars().next()
+    }
+
+    fn consume(&mut self, expected: char) -> bool {
+        if self.peek() == Some(expected) {
+            self.position += expected.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn take_while(&mut self, predicate: impl Fn(char) -> bool) -> &'a str {
+        let remaining = &self.input[self.position..];
+        let len = remaining.find(|c| !predicate(c)).unwrap_or(remaining.len());
+        self.position += len;
+        &remaining[..len]
+    }
+
+    fn parse_expr(&mut self) -> Result<Expr, String> {
+        let mut left = self.parse_term()?;
+        loop {
+            let op = if self.consume('+') {
+                BinaryOp::Add
+            } else if self.consume('-') {
+                BinaryOp::Sub
+            } else {
+                return Ok(left);
+            };
+            left = Expr::Binary(Box::new(left), op, Box::new(self.parse_term()?));
+        }
+    }
+
+    fn parse_term(&mut self) -> Result<Expr, String> {
+        let mut left = self.parse_factor()?;
+        loop {
+            let op = if self.consume('*') {
+                BinaryOp::Mul
+            } else if self.consume('/') {
+                BinaryOp::Div
+            } else {
+                return Ok(left);
+            };
+            left = Expr::Binary(Box::new(left), op, Box::new(self.parse_factor()?));
+        }
+    }
+
+    fn parse_factor(&mut self) -> Result<Expr, String> {
+        match self.peek() {
+            Some('-') => {
+                self.position += 1;
+                Ok(Expr::Neg(Box::new(self.parse_factor()?)))
+            },
+            Some('(') => {
+                self.position += 1;
+                let expr = self.parse_expr()?;
+                if !self.consume(')') {
+                    return Err(format!("Expected ')' at position {}", self.position));
+                }
+                Ok(expr)
+            },
+            Some(c) if c.is_ascii_digit() => {
+                let number = self.take_while(|c| c.is_ascii_digit() || c == '.');
+                number.parse().map(Expr::Number).map_err(|_| format!("Invalid number {number}"))
+            },
+            Some(c) if c.is_alphanumeric() || c == '_' => {
+                let name = self.take_while(|c| c.is_alphanumeric() || c == '_' || c == '.' || c == '*');
+                if self.consume('(') {
+                    let function = match name {
+                        "sum" => Function::Sum,
+                        "count" => Function::Count,
+                        "min" => Function::Min,
+                        "max" => Function::Max,
+                        "avg" => Function::Avg,
+                        _ => return Err(format!("Unknown function {name}")),
+                    };
+                    let mut args = Vec::new();
+                    if !self.consume(')') {
+                        loop {
+                            args.push(self.parse_expr()?);
+                            if self.consume(')') {
+                                break;
+                            }
+                            if !self.consume(',') {
+                                return Err(format!("Expected ',' or ')' at position {}", self.position));
+                            }
+                        }
+                    }
+                    Ok(Expr::Call(function, args))
+                } else {
+                    Ok(Expr::Path(name.to_string()))
+                }
+            },
+            Some(c) => Err(format!("Unexpected character '{c}' at position {}", self.position)),
+            None => Err(String::from("Unexpected end of expression")),
+        }
+    }
+}
+
+impl Expr {
+    pub(crate) fn parse(input: &str) -> Result<Self, String> {
+        let mut parser = Parser { input, position: 0 };
+        let expr = parser.parse_expr()?;
+        match parser.peek() {
+            Some(c) => Err(format!("Unexpected character '{c}' at position {}", parser.position)),
+            None => Ok(expr),
+        }
+    }
+
+    fn collect_dependencies(&self, dependencies: &mut Vec<String>) {
+        match self {
+            Expr::Number(_) => {},
+            Expr::Path(path) => {
+                let dependency = match path.find('*') {
+                    Some(wc_idx) => path[..wc_idx].trim_end_matches('.'),
+                    None => path.as_str(),
+                };
+                dependencies.push(dependency.to_string());
+            },
+            Expr::Neg(expr) => expr.collect_dependencies(dependencies),
+            Expr::Binary(left, _, right) => {
+                left.collect_dependencies(dependencies);
+                right.collect_dependencies(dependencies);
+            },
+            Expr::Call(_, args) => args.iter().for_each(|arg| arg.collect_dependencies(dependencies)),
+        }
+    }
+
+    /// Evaluates the expression as a single number. None if a path is missing or not numeric
+    pub(crate) fn eval(&self, data_cache: &DataCache) -> Option<f64> {
+        match self {
+            Expr::Number(n) => Some(*n),
+            Expr::Path(path) => data_cache.get(path).and_then(value_as_f64),
+            Expr::Neg(expr) => expr.eval(data_cache).map(|n| -n),
+            Expr::Binary(left, op, right) => {
+                let (left, right) = (left.eval(data_cache)?, right.eval(data_cache)?);
+                match op {
+                    BinaryOp::Add => Some(left + right),
+                    BinaryOp::Sub => Some(left - right),
+                    BinaryOp::Mul => Some(left * right),
+                    BinaryOp::Div if right == 0.0 => None,
+                    BinaryOp::Div => Some(left / right),
+                }
+            },
+            Expr::Call(function, args) => {
+                // Path arguments are expanded into lists (with wildcard support), others are evaluated as single numbers
+                let mut count = 0usize;
+                let mut numbers = Vec::new();
+                for arg in args {
+                    match arg {
+                        Expr::Path(path) => {
+                            let values = data_cache.get_list(path);
+                            count += values.len();
+                            numbers.extend(values.into_iter().filter_map(value_as_f64));
+                        },
+                        _ => {
+                            count += 1;
+                            numbers.extend(arg.eval(data_cache));
+                        }
+                    }
+                }
+                match function {
+                    Function::Sum => Some(numbers.iter().sum()),
+                    Function::Count => Some(count as f64),
+                    Function::Min => numbers.into_iter().reduce(f64::min),
+                    Function::Max => numbers.into_iter().reduce(f64::max),
+                    Function::Avg if numbers.is_empty() => None,
+                    Function::Avg => Some(numbers.iter().sum::<f64>() / numbers.len() as f64),
+                }
+            },
+        }
+    }
+}
+
+/// Numbers, and strings holding a number, are usable in expressions
+fn value_as_f64(value: &Value) -> Option<f64> {
+    match value {
+        Value::Number(n) => n.as_f64(),
+        Value::String(s) => s.trim().parse().ok(),
+        _ => None,
+    }
+}
+
+/// Integral results are stored as integers, so that they render without decimals
+fn f64_to_value(n: f64) -> Value {
+    if n.fract() == 0.0 && n.abs() < (1u64 << 53) as f64 {
+        Value::Number(Number::from(n as i64))
+    } else {
+        Number::from_f64(n).map(Value::Number).unwrap_or(Value::Null)
+    }
+}
+
+/// True if one path is the other, or one of its ancestors. Empty path is the root
+pub(crate) fn paths_overlap(a: &str, b: &str) -> bool {
+    let (shorter, longer) = if a.len() <= b.len() { (a, b) } else { (b, a) };
+    shorter.is_empty()
+        || longer == shorter
+        || (longer.starts_with(shorter) && longer.as_bytes()[shorter.len()] == b'.')
+}
+
+impl DataCache {
+    /// Registers a value at path computed from an expression over other paths (see ComputedPath), on first read.
+    /// Computed paths may depend on other ones, but not on themselves through them
+    pub fn define_computed(&mut self, path: &str, expr: &str) -> Result<(), JsonDataCacheError> {
+        let parsed = Expr::parse(expr).map_err(|e| format!("Invalid expression for computed path {path} : {e}"))?;
+        let mut dependencies = Vec::new();
+        parsed.collect_dependencies(&mut dependencies);
+        let dependencies: Vec<String> = dependencies.iter().map(|dependency| self.refs.resolve(dependency).into_owned()).collect();
+        // Dependencies through other computed paths
+        let mut transitive = dependencies.clone();
+        let mut idx = 0;
+        while let Some(dependency) = transitive.get(idx) {
+            if paths_overlap(dependency, path) {
+                return Err(format!("Computed path {path} can not depend on itself").into());
+            }
+            let indirect: Vec<String> = self.computed.iter()
+                .filter(|computed| computed.path != path && paths_overlap(&computed.path, dependency))
+                .flat_map(|computed| computed.dependencies.iter().filter(|indirect| !transitive.contains(indirect)).cloned())
+                .collect();
+            transitive.extend(indirect);
+            idx += 1;
+        }
+        self.computed.retain(|computed| computed.path != path);
+        self.computed.push(ComputedPath {
+            path: path.to_string(),
+            expr: parsed,
+            dependencies,
+            value: OnceCell::new(),
+            is_in_tree: false,
+        });
+        self.update_computed(&[path]);
+        self.on_after_insert();
+        Ok(())
+    }
+
+    /// Marks the computed paths depending on any of the modified paths as outdated, along with the ones depending on them
+    pub(crate) fn update_computed(&mut self, modified_paths: &[&str]) {
+        if self.computed.is_empty() {
+            return;
+        }
+        let mut modified_paths: Vec<String> = modified_paths.iter().map(|p| p.to_string()).collect();
+        let mut is_modified = true;
+        while is_modified {
+            is_modified = false;
+            for computed in &mut self.computed {
+                let is_outdated = modified_paths.iter().any(|modified| {
+                    modified == &computed.path
+                        || computed.dependencies.iter().any(|dependency| paths_overlap(dependency, modified))
+                });
+                if is_outdated && !modified_paths.contains(&computed.path) {
+                    modified_paths.push(computed.path.clone()); // Computed paths may depend on this one
+                    is_modified = true;
+                }
+                if is_outdated {
+                    computed.value = OnceCell::new();
+                    computed.is_in_tree = false;
+                }
+            }
+        }
+    }
+
+    /// Value of the computed path if it is outdated in the tree, computed on first call since the last modification of
+    /// its dependencies (see get)
+    pub(crate) fn computed_value(&self, path: &str) -> Option<&Value> {
+        let computed = self.computed.iter().find(|computed| !computed.is_in_tree && computed.path == path)?;
+        Some(computed.value.get_or_init(|| computed.expr.eval(self).map(f64_to_value).unwrap_or(Value::Null)))
+    }
+
+    /// Outdated computed paths & their values (see computed_value), for the getters other than get
+    pub(crate) fn outdated_computed(&self) -> impl Iterator<Item = (&str, &Value)> {
+        self.computed.iter()
+            .filter(|computed| !computed.is_in_tree)
+            .filter_map(|computed| Some((computed.path.as_str(), self.computed_value(&computed.path)?)))
+    }
+
+    /// The tree, or a copy of it holding the values of the outdated computed paths if any, as prepare_computed would write them
+    pub(crate) fn root_with_computed(&self) -> Cow<'_, Value> {
+        if self.computed.iter().all(|computed| computed.is_in_tree) {
+            return Cow::Borrowed(&self.root);
+        }
+        let mut root = self.root.clone();
+        for (path, value) in self.outdated_computed() {
+            Self::insert_rec(&mut root, path, value.clone());
+        }
+        Cow::Owned(root)
+    }
+
+    /// Writes the outdated computed paths into the tree, so that the views of the tree (serialized data, string values...)
+    /// include them. Called by prepare & string_values_view
+    pub fn prepare_computed(&mut self) {
+        if self.computed.iter().all(|computed| computed.is_in_tree) {
+            return;
+        }
+        for idx in 0..self.computed.len() {
+            if self.computed[idx].is_in_tree {
+                continue;
+            }
+            let path = self.computed[idx].path.clone();
+            let value = self.computed_value(&path).cloned().unwrap_or(Value::Null);
+            Self::insert_rec(&mut self.root, &path, value);
+            self.mark_dirty(&path);
+            self.computed[idx].is_in_tree = true;
+        }
+        self.on_after_data_insert();
+    }
+}
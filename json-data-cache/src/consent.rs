@@ -0,0 +1,96 @@
+//! Consent gating: the keys of the paths requiring a purpose (analytics ids, personalization data...) are hidden from the
+//! tree, replacements and debug output unless the consent flags of the visitor grant it (see the visibility module).
+//! Flags are parsed from the cookie of the consent management platform into the consent path ("consent" by default), like
+//! {"analytics": true, "personalization": false}. Absent flags grant nothing.
+
+use serde_json::{Map, Value};
+
+use crate::{DataCache, visibility::Visibility};
+
+/// Default path of the consent flags, see set_consent_path
+pub const DEFAULT_CONSENT_PATH: &str = "consent";
+
+fn percent_decode(value: &str) -> String {
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let hex = bytes.get(i + 1..i + 3).and_then(|hex| std::str::from_utf8(hex).ok()).and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        match (bytes[i], hex) {
+            (b'%', Some(byte)) => {
+                decoded.push(byte);
+                i += 3;
+            },
+            (b'+', _) => {
+                decoded.push(b' ');
+                i += 1;
+            },
+            (byte, _) => {
+                decoded.push(byte);
+                i += 1;
+            },
+        }
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+/// Parses the value of a consent cookie into flags by purpose. The value may be percent-encoded, and be either pairs like
+/// `analytics:1,ads:0`, `{necessary:true,statistics:false}` (JSON or Cookiebot like) or the list of the granted purposes
+/// like `analytics|personalization`. Pairs of other values (timestamps, ids...) are left out
+pub fn parse_consent(cookie_value: &str) -> Value {
+    let decoded = percent_decode(cookie_value);
+    let trimmed = decoded.trim().trim_start_matches('{').trim_end_matches('}');
+    let mut flags = Map::new();
+    for entry in trimmed.split([',', '|', '&']) {
+        let unquote = |part: &str| part.trim().trim_matches(['"', '\'']).trim().to_string();
+        let (purpose, granted) = match entry.split_once([':', '=']) {
+            Some((purpose, value)) => match unquote(value).to_ascii_lowercase().as_str() {
+                "true" | "1" | "yes" | "granted" => (unquote(purpose), true),
+                "false" | "0" | "no" | "denied" => (unquote(purpose), false),
+                _ => continue,
+            },
+            None => (unquote(entry), true),
+        };
+        if !purpose.is_empty() {
+            flags.insert(purpose, Value::Bool(granted));
+        }
+    }
+    Value::Object(flags)
+}
+
+impl DataCache {
+    /// Hides the keys matching pattern unless the consent flags grant purpose, replacing any rule previously set for the pattern
+    /// Example: require_consent("tracking.ga_id", "analytics")
+    pub fn require_consent(&mut self, pattern: &str, purpose: &str) {
+        self.set_visibility(pattern, Visibility::Consent(purpose.to_string()));
+    }
+
+    /// Sets the path of the consent flags (DEFAULT_CONSENT_PATH by default)
+    pub fn set_consent_path(&mut self, consent_path: &str) {
+        self.visibility.consent_path = consent_path.to_string();
+        self.on_after_insert();
+    }
+
+    /// Replaces the consent flags with the ones of the cookie_name cookie of the Cookie header (none without the cookie),
+    /// and returns them
+    pub fn load_consent(&mut self, cookie_header: &str, cookie_name: &str) -> Value {
+        let flags = cookie_header.split(';')
+            .filter_map(|cookie| cookie.trim().split_once('='))
+            .find(|(name, _)| *name == cookie_name)
+            .map(|(_, value)| parse_consent(value))
+            .unwrap_or_else(|| Value::Object(Map::new()));
+        let consent_path = self.visibility.consent_path.clone();
+        if let Err(e) = self.try_replace(&consent_path, flags.clone()) {
+            log::info!("[WARN] DataCache consent : {}", e);
+        }
+        flags
+    }
+
+    /// Whether the consent flags grant purpose
+    pub fn has_consent(&self, purpose: &str) -> bool {
+        self.get(&self.visibility.consent_path)
+            .and_then(|consent| consent.get(purpose))
+            .and_then(Value::as_bool)
+            .unwrap_or(false)
+    }
+}
@@ -0,0 +1,41 @@
+/// CRC-32C (Castagnoli, RFC 3720), a checksum of bodies cheaper than SHA-256 when integrity against tampering is not needed
+const POLYNOMIAL: u32 = 0x82f63b78; // Reversed
+
+const TABLE: [u32; 256] = {
+    let mut table = [0u32; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 == 1 { crc >> 1 ^ POLYNOMIAL } else { crc >> 1 };
+            bit += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+};
+
+#[derive(Debug, Clone)]
+pub(crate) struct Crc32c {
+    state: u32,
+}
+
+impl Default for Crc32c {
+    fn default() -> Self {
+        Self { state: !0 }
+    }
+}
+
+impl Crc32c {
+    pub(crate) fn update(&mut self, data: &[u8]) {
+        for byte in data {
+            self.state = self.state >> 8 ^ TABLE[((self.state ^ *byte as u32) & 0xff) as usize];
+        }
+    }
+
+    pub(crate) fn finalize(&self) -> u32 {
+        !self.state
+    }
+}
@@ -0,0 +1,222 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+use serde_json::{Map, Value, json};
+
+use crate::{DataCache, error::JsonDataCacheError};
+
+/// Last-writer-wins register. Concurrent writes with the same timestamp are ordered by node id
+#[derive(Debug, Clone, PartialEq)]
+pub struct LwwRegister {
+    pub value: Value,
+    pub timestamp: u64, // Typically milliseconds since the Unix epoch, as set by the writing node
+    pub node_id: String
+}
+
+impl LwwRegister {
+    pub fn new(value: Value, timestamp: u64, node_id: &str) -> Self {
+        Self { value, timestamp, node_id: node_id.to_string() }
+    }
+
+    fn merge(&mut self, other: &Self) {
+        if (other.timestamp, &other.node_id) > (self.timestamp, &self.node_id) {
+            *self = other.clone();
+        }
+    }
+}
+
+/// Grow-only counter, holding the count of each node
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct GCounter {
+    counts: BTreeMap<String, u64>,
+}
+
+impl GCounter {
+    pub fn increment(&mut self, node_id: &str, by: u64) {
+        *self.counts.entry(node_id.to_string()).or_default() += by;
+    }
+
+    /// Sum of the counts of all nodes
+    pub fn count(&self) -> u64 {
+        self.counts.values().sum()
+    }
+
+    fn merge(&mut self, other: &Self) {
+        for (node_id, count) in &other.counts {
+            let current = self.counts.entry(node_id.clone()).or_default();
+            *current = (*current).max(*count);
+        }
+    }
+}
+
+/// Observed-remove set: a removal only discards the additions it has observed, so a concurrent addition wins
+/// Each addition is identified by a tag that must be unique across nodes (node id & sequence number for example)
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct OrSet {
+    additions: BTreeMap<String, BTreeSet<String>>, // Tags of each element, keyed by its serialized JSON
+    removals: BTreeSet<String> // Tags of removed additions
+}
+
+impl OrSet {
+    pub fn add(&mut self, element: &Value, tag: &str) {
+        self.additions.entry(element.to_string()).or_default().insert(tag.to_string());
+    }
+
+    pub fn remove(&mut self, element: &Value) {
+        if let Some(tags) = self.additions.get(&element.to_string()) {
+            self.removals.extend(tags.iter().cloned());
+        }
+    }
+
+    pub fn contains(&self, element: &Value) -> bool {
+        self.additions.get(&element.to_string()).is_some_and(|tags| self.has_live_tag(tags))
+    }
+
+    /// Elements present in the set, ordered by their serialized JSON
+    pub fn elements(&self) -> Vec<Value> {
+        self.additions.iter()
+            .filter(|(_, tags)| self.has_live_tag(tags))
+            .filter_map(|(element, _)| serde_json::from_str(element).ok())
+            .collect()
+    }
+
+    fn has_live_tag(&self, tags: &BTreeSet<String>) -> bool {
+        tags.iter().any(|tag| !self.removals.contains(tag))
+    }
+
+    fn merge(&mut self, other: &Self) {
+        for (element, tags) in &other.additions {
+            self.additions.entry(element.clone()).or_default().extend(tags.iter().cloned());
+        }
+        self.removals.extend(other.removals.iter().cloned());
+    }
+}
+
+/// Replicated state stored at a path by merge_crdt. Merging is commutative, associative and idempotent,
+/// so that every node converges to the same value whatever the order in which states are exchanged
+#[derive(Debug, Clone, PartialEq)]
+pub enum Crdt {
+    LwwRegister(LwwRegister),
+    GCounter(GCounter),
+    OrSet(OrSet),
+}
+
+impl Crdt {
+    fn kind(&self) -> &'static str {
+        match self {
+            Crdt::LwwRegister(_) => "lww-register",
+            Crdt::GCounter(_) => "g-counter",
+            Crdt::OrSet(_) => "or-set",
+        }
+    }
+
+    /// Merges other into self. Both must be of the same type
+    pub fn merge(&mut self, other: &Crdt) -> Result<(), JsonDataCacheError> {
+        match (self, other) {
+            (Crdt::LwwRegister(register), Crdt::LwwRegister(other)) => register.merge(other),
+            (Crdt::GCounter(counter), Crdt::GCounter(other)) => counter.merge(other),
+            (Crdt::OrSet(set), Crdt::OrSet(other)) => set.merge(other),
+            (current, other) => return Err(format!("Cannot merge a {} into a {}", other.kind(), current.kind()).into()),
+        }
+        Ok(())
+    }
+
+    /// Value seen by readers of the path : the register value, the counter total or the array of set elements
+    pub fn value(&self) -> Value {
+        match self {
+            Crdt::LwwRegister(register) => register.value.clone(),
+            Crdt::GCounter(counter) => json!(counter.count()),
+            Crdt::OrSet(set) => Value::Array(set.elements()),
+        }
+    }
+
+    /// JSON encoding of the whole state, to be sent to other nodes
+    pub fn to_state(&self) -> Value {
+        match self {
+            Crdt::LwwRegister(register) => json!({
+                "type": self.kind(),
+                "value": register.value,
+                "timestamp": register.timestamp,
+                "node_id": register.node_id
+            }),
+            Crdt::GCounter(counter) => json!({
+                "type": self.kind(),
+                "counts": counter.counts
+            }),
+            Crdt::OrSet(set) => json!({
+                "type": self.kind(),
+                "additions": set.additions,
+                "removals": set.removals
+            }),
+        }
+    }
+
+    /// Decodes a state encoded by to_state
+    pub fn from_state(state: &Value) -> Result<Crdt, JsonDataCacheError> {
+        let invalid = |field: &str| -> JsonDataCacheError { format!("Invalid CRDT state : bad {field}").into() };
+        let strings = |value: &Value, field: &str| -> Result<BTreeSet<String>, JsonDataCacheError> {
+            value.as_array().ok_or(invalid(field))?.iter()
+                .map(|tag| tag.as_str().map(str::to_string).ok_or(invalid(field)))
+                .collect()
+        };
+        let object = |field: &str| -> Result<&Map<String, Value>, JsonDataCacheError> {
+            state.get(field).and_then(Value::as_object).ok_or(invalid(field))
+        };
+        match state.get("type").and_then(Value::as_str) {
+            Some("lww-register") => Ok(Crdt::LwwRegister(LwwRegister {
+                value: state.get("value").cloned().ok_or(invalid("value"))?,
+                timestamp: state.get("timestamp").and_then(Value::as_u64).ok_or(invalid("timestamp"))?,
+                node_id: state.get("node_id").and_then(Value::as_str).ok_or(invalid("node_id"))?.to_string(),
+            })),
+            Some("g-counter") => {
+                let counts = object("counts")?.iter()
+                    .map(|(node_id, count)| count.as_u64().map(|count| (node_id.clone(), count)).ok_or(invalid("counts")))
+                    .collect::<Result<_, _>>()?;
+                Ok(Crdt::GCounter(GCounter { counts }))
+            },
+            Some("or-set") => {
+                let additions = object("additions")?.iter()
+                    .map(|(element, tags)| Ok((element.clone(), strings(tags, "additions")?)))
+                    .collect::<Result<_, JsonDataCacheError>>()?;
+                let removals = strings(state.get("removals").unwrap_or(&Value::Null), "removals")?;
+                Ok(Crdt::OrSet(OrSet { additions, removals }))
+            },
+            _ => Err(invalid("type")),
+        }
+    }
+}
+
+/// States of the paths holding CRDTs
+#[derive(Debug, Default)]
+pub(crate) struct DataCacheCrdts(HashMap<String, Crdt>);
+
+impl DataCacheCrdts {
+    /// Drops the states of path and its descendants
+    pub(crate) fn remove_subtree(&mut self, path: &str) {
+        let nested_prefix = format!("{path}.");
+        self.0.retain(|crdt_path, _| !path.is_empty() && crdt_path != path && !crdt_path.starts_with(&nested_prefix));
+    }
+}
+
+impl DataCache {
+    /// Merges the state into the one held at path (a path without state takes it as is), then stores the resulting value
+    /// at path (see Crdt::value). Plain JSON merges cannot express counters or sets replicated across edge nodes
+    /// Inserting a plain value at path does not modify its state : the next merge overwrites it
+    pub fn merge_crdt(&mut self, path: &str, crdt: &Crdt) -> Result<(), JsonDataCacheError> {
+        let merged = match self.crdts.0.get(path) {
+            Some(current) => {
+                let mut merged = current.clone();
+                merged.merge(crdt)?;
+                merged
+            },
+            None => crdt.clone(),
+        };
+        self.try_replace(path, merged.value())?;
+        self.crdts.0.insert(path.to_string(), merged);
+        Ok(())
+    }
+
+    /// State held at path, to be modified locally before being merged back, or sent to other nodes
+    pub fn get_crdt(&self, path: &str) -> Option<&Crdt> {
+        self.crdts.0.get(path)
+    }
+}
@@ -0,0 +1,251 @@
+//! Inline critical CSS: the styles needed to render the top of a page, inlined in its head so that the first paint does
+//! not wait for the full stylesheets, which are then loaded asynchronously. The critical CSS of each route or template
+//! (extracted at build time) is held in a map of the cache, like {"/": "body{margin:0}", "article": "h1{font-size:2em}"},
+//! and injected while the HTML is streamed by CriticalCssInjector.
+
+use std::io::{self, Write};
+
+use serde_json::Value;
+
+use crate::{DataCache, error::JsonDataCacheError, preload::{escape_attribute, parse_attributes}};
+
+/// Default path of the critical CSS map
+pub const DEFAULT_CRITICAL_CSS_PATH: &str = "critical_css";
+
+/// Tags longer than this (split across chunks) are written as they are
+const MAX_PENDING_TAG_LEN: usize = 4096;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum State {
+    Head,
+    Raw(&'static [u8]), // Content of a script, style or comment of the head, until this closing sequence
+    TagEnd, // Rest of a closing tag matched by State::Raw, until its >
+    Done, // Past the head, written as is
+}
+
+/// Writer injecting a <style> block of critical CSS right after the <head> tag of the HTML (or before <body> or </head>
+/// without one), and rewriting the stylesheet links of the head to load asynchronously: as preloads turned into
+/// stylesheets once loaded, along with the original link in <noscript>. Typically wraps the output of replace_with_data_cache,
+/// its chunks possibly splitting tags. The onload handlers of the links require 'unsafe-hashes' (or 'unsafe-inline')
+/// in a Content-Security-Policy restricting scripts. Empty CSS leaves the HTML unchanged
+#[derive(Debug)]
+pub struct CriticalCssInjector<W: Write> {
+    inner: W,
+    css: String,
+    nonce: Option<String>,
+    state: State,
+    is_injected: bool,
+    pending_tag: Option<Vec<u8>>, // Start of a tag not closed in the previous chunk, after its <
+    matched: usize, // Bytes of the closing sequence of State::Raw matched so far
+}
+
+impl<W: Write> CriticalCssInjector<W> {
+    pub fn new(inner: W, css: &str) -> Self {
+        Self {
+            inner,
+            css: css.to_string(),
+            nonce: None,
+            state: if css.is_empty() { State::Done } else { State::Head },
+            is_injected: false,
+            pending_tag: None,
+            matched: 0,
+        }
+    }
+
+    /// Renders the nonce attribute on the <style> tag, for a Content-Security-Policy restricting styles (see security_headers)
+    pub fn nonce(mut self, nonce: &str) -> Self {
+        self.nonce = Some(nonce.to_string());
+        self
+    }
+
+    /// True once the <style> block is written
+    pub fn is_injected(&self) -> bool {
+        self.is_injected
+    }
+
+    /// Writes the start of a tag left unclosed by the last chunk, returning the inner writer
+    pub fn finish(mut self) -> io::Result<W> {
+        if let Some(tag) = self.pending_tag.take() {
+            self.inner.write_all(b"<")?;
+            self.inner.write_all(&tag)?;
+        }
+        Ok(self.inner)
+    }
+
+    fn write_style_block(&mut self) -> io::Result<()> {
+        let nonce = self.nonce.as_deref().map(|nonce| format!(" nonce=\"{}\"", escape_attribute(nonce))).unwrap_or_default();
+        write!(self.inner, "<style{nonce}>{}</style>", escape_css(&self.css))?;
+        self.is_injected = true;
+        Ok(())
+    }
+
+    fn process(&mut self, chunk: &[u8]) -> io::Result<()> {
+        let mut idx = 0;
+        while idx < chunk.len() {
+            match self.state {
+                State::Done => return self.inner.write_all(&chunk[idx..]),
+                State::Raw(closing) => {
+                    let start = idx;
+                    while idx < chunk.len() && self.matched < closing.len() {
+                        let byte = chunk[idx].to_ascii_lowercase();
+                        self.matched = if byte == closing[self.matched] {
+                            self.matched + 1
+                        } else if closing == b"-->" && byte == b'-' {
+                            2 // Like --->
+                        } else {
+                            usize::from(byte == closing[0])
+                        };
+                        idx += 1;
+                    }
+                    self.inner.write_all(&chunk[start..idx])?;
+                    if self.matched == closing.len() {
+                        self.matched = 0;
+                        self.state = if closing == b"-->" { State::Head } else { State::TagEnd };
+                    }
+                },
+                State::TagEnd => {
+                    let Some(offset) = chunk[idx..].iter().position(|b| *b == b'>') else {
+                        return self.inner.write_all(&chunk[idx..]);
+                    };
+                    self.inner.write_all(&chunk[idx..=idx + offset])?;
+                    idx += offset + 1;
+                    self.state = State::Head;
+                },
+                State::Head => match self.pending_tag.take() {
+                    Some(mut tag) => {
+                        let Some(tag_len) = chunk[idx..].iter().position(|b| *b == b'>') else {
+                            tag.extend_from_slice(&chunk[idx..]);
+                            if tag.len() <= MAX_PENDING_TAG_LEN {
+                                self.pending_tag = Some(tag);
+                            } else {
+                                self.inner.write_all(b"<")?;
+                                self.inner.write_all(&tag)?;
+                            }
+                            return Ok(());
+                        };
+                        tag.extend_from_slice(&chunk[idx..idx + tag_len]);
+                        idx += tag_len + 1;
+                        self.on_tag(&tag)?;
+                    },
+                    None => {
+                        let Some(offset) = chunk[idx..].iter().position(|b| *b == b'<') else {
+                            return self.inner.write_all(&chunk[idx..]);
+                        };
+                        self.inner.write_all(&chunk[idx..idx + offset])?;
+                        self.pending_tag = Some(Vec::new());
+                        idx += offset + 1;
+                    },
+                },
+            }
+        }
+        Ok(())
+    }
+
+    /// Writes a tag of the head (between < and >), rewritten if needed
+    fn on_tag(&mut self, tag: &[u8]) -> io::Result<()> {
+        let is_closing = tag.first() == Some(&b'/');
+        let name_start = usize::from(is_closing);
+        let name_len = tag[name_start..].iter().take_while(|b| b.is_ascii_alphanumeric()).count();
+        let name = tag[name_start..name_start + name_len].to_ascii_lowercase();
+        let write_tag = |inner: &mut W| -> io::Result<()> {
+            inner.write_all(b"<")?;
+            inner.write_all(tag)?;
+            inner.write_all(b">")
+        };
+        if tag.starts_with(b"!--") {
+            write_tag(&mut self.inner)?;
+            if tag.len() < 5 || !tag.ends_with(b"--") {
+                self.state = State::Raw(b"-->");
+            }
+            return Ok(());
+        }
+        match (is_closing, name.as_slice()) {
+            (false, b"head") if !self.is_injected => {
+                write_tag(&mut self.inner)?;
+                self.write_style_block()?;
+            },
+            (true, b"head") | (false, b"body") => {
+                if !self.is_injected {
+                    self.write_style_block()?;
+                }
+                write_tag(&mut self.inner)?;
+                self.state = State::Done;
+            },
+            (false, b"link") => {
+                let attributes = parse_attributes(&tag[name_len..]);
+                let attribute = |name: &str| attributes.iter().find(|(attribute, _)| attribute == name).map(|(_, value)| value.as_str());
+                let is_stylesheet = attribute("rel").is_some_and(|rel| rel.eq_ignore_ascii_case("stylesheet"));
+                match attribute("href").filter(|href| is_stylesheet && !href.is_empty() && attribute("onload").is_none()) {
+                    Some(href) => {
+                        let kept: String = attributes.iter()
+                            .filter(|(name, _)| !matches!(name.as_str(), "rel" | "href" | "as"))
+                            .map(|(name, value)| format!(" {name}=\"{}\"", value.replace('"', "&quot;")))
+                            .collect();
+                        write!(
+                            self.inner,
+                            "<link rel=\"preload\" href=\"{}\" as=\"style\"{kept} onload=\"this.onload=null;this.rel='stylesheet'\">",
+                            href.replace('"', "&quot;")
+                        )?;
+                        self.inner.write_all(b"<noscript>")?;
+                        write_tag(&mut self.inner)?;
+                        self.inner.write_all(b"</noscript>")?;
+                    },
+                    None => write_tag(&mut self.inner)?,
+                }
+            },
+            (false, b"script") if !tag.ends_with(b"/") => {
+                write_tag(&mut self.inner)?;
+                self.state = State::Raw(b"</script");
+            },
+            (false, b"style" | b"noscript") if !tag.ends_with(b"/") => {
+                write_tag(&mut self.inner)?;
+                self.state = State::Raw(if name == b"style" { b"</style" } else { b"</noscript" });
+            },
+            _ => write_tag(&mut self.inner)?,
+        }
+        Ok(())
+    }
+}
+
+impl<W: Write> Write for CriticalCssInjector<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.process(buf)?;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// CSS safe to inline in a <style> element, which would end at any </style
+fn escape_css(css: &str) -> String {
+    let mut escaped = String::with_capacity(css.len());
+    let mut rest = css;
+    while let Some(idx) = rest.to_ascii_lowercase().find("</style") {
+        escaped.push_str(&rest[..idx]);
+        escaped.push_str("<\\/");
+        rest = &rest[idx + 2..];
+    }
+    escaped.push_str(rest);
+    escaped
+}
+
+impl DataCache {
+    /// Critical CSS of the first key of the map at map_path holding one, keys being tried in order, like the route then
+    /// the template: critical_css(DEFAULT_CRITICAL_CSS_PATH, &["/news/1", "article"]). Keys may contain dots
+    pub fn critical_css(&self, map_path: &str, keys: &[&str]) -> Result<Option<&str>, JsonDataCacheError> {
+        let Some(map) = self.get(map_path) else {
+            return Ok(None);
+        };
+        let map = map.as_object().ok_or(format!("Critical CSS map {map_path} is not an object"))?;
+        for key in keys {
+            match map.get(*key) {
+                Some(Value::String(css)) => return Ok(Some(css)),
+                Some(Value::Null) | None => continue,
+                Some(_) => return Err(format!("Critical CSS of {key} in {map_path} is not a string").into()),
+            }
+        }
+        Ok(None)
+    }
+}
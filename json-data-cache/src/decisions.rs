@@ -0,0 +1,101 @@
+//! Per-request log of the decisions taken while rendering (experiment branches, redirect rules, flags, expanded placeholders),
+//! as JSON objects `{"kind": .., "name": .., "detail": .., "request_id": ..}` for analytics ingestion.
+//! Entries are either buffered until the caller drains them (after the response, see DataCache::drain_decisions) or passed
+//! to a callback. They are kept outside of the tree, so that logging does not invalidate the automaton of the next request.
+
+use std::{fmt, mem};
+
+use serde_json::{Value, json};
+
+use crate::{DataCache, error::JsonDataCacheError};
+
+/// Kind of the entries logged for expanded placeholders
+pub const PLACEHOLDER_DECISION: &str = "placeholder";
+
+/// Where the entries go
+pub enum DecisionSink {
+    Buffer(Vec<Value>), // Drained by DataCache::drain_decisions
+    Callback(Box<dyn FnMut(&Value)>)
+}
+
+impl fmt::Debug for DecisionSink {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecisionSink::Buffer(entries) => f.debug_tuple("Buffer").field(entries).finish(),
+            DecisionSink::Callback(_) => f.write_str("Callback"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct DecisionLog {
+    sink: DecisionSink,
+    request_id: Option<String>, // Added to each entry
+    log_placeholders: bool // Each replacement logs its distinct expanded placeholders with their count
+}
+
+impl DecisionLog {
+    /// Keeps the entries until drained
+    pub fn buffered() -> Self {
+        Self { sink: DecisionSink::Buffer(Vec::new()), request_id: None, log_placeholders: false }
+    }
+
+    pub fn to_callback<F>(callback: F) -> Self
+    where
+        F: FnMut(&Value) + 'static,
+    {
+        Self { sink: DecisionSink::Callback(Box::new(callback)), request_id: None, log_placeholders: false }
+    }
+
+    pub fn request_id(mut self, request_id: &str) -> Self {
+        self.request_id = Some(request_id.to_string());
+        self
+    }
+
+    pub fn log_placeholders(mut self, log_placeholders: bool) -> Self {
+        self.log_placeholders = log_placeholders;
+        self
+    }
+}
+
+impl DataCache {
+    /// Enables the decision log, replacing any previous one
+    pub fn set_decision_log(&mut self, decision_log: DecisionLog) {
+        self.decision_log = Some(decision_log);
+    }
+
+    /// Disables the decision log, returning it
+    pub fn take_decision_log(&mut self) -> Option<DecisionLog> {
+        self.decision_log.take()
+    }
+
+    /// Logs a decision (for example kind "experiment", name the experiment, detail the chosen branch). Does nothing without log
+    pub fn log_decision(&mut self, kind: &str, name: &str, detail: Value) -> Result<(), JsonDataCacheError> {
+        let Some(decision_log) = &mut self.decision_log else {
+            return Ok(());
+        };
+        let mut entry = json!({"kind": kind, "name": name, "detail": detail});
+        if let Some(request_id) = &decision_log.request_id {
+            entry["request_id"] = Value::String(request_id.clone());
+        }
+        match &mut decision_log.sink {
+            DecisionSink::Buffer(entries) => entries.push(entry),
+            DecisionSink::Callback(callback) => callback(&entry),
+        }
+        Ok(())
+    }
+
+    /// Entries buffered since the last drain, in logging order. Empty without log or with a callback sink
+    pub fn drain_decisions(&mut self) -> Vec<Value> {
+        match self.decision_log.as_mut().map(|decision_log| &mut decision_log.sink) {
+            Some(DecisionSink::Buffer(entries)) => mem::take(entries),
+            _ => Vec::new(),
+        }
+    }
+
+    /// True if replacements log their expanded placeholders
+    #[cfg(feature = "replace-engine")]
+    pub(crate) fn is_logging_placeholders(&self) -> bool {
+        self.decision_log.as_ref().is_some_and(|decision_log| decision_log.log_placeholders)
+    }
+}
@@ -0,0 +1,149 @@
+//! Graceful degradation: a DegradedMode preset says how the site renders while something it depends on is down (the
+//! origin, an API...). Once flagged by degrade, configured fallback subtrees replace their paths, disabled blocks (and
+//! optionally the paths declared dynamic, see declare_dynamic) render empty, and the status is inserted at its path for
+//! templates to show a notice, besides being available for a response header. recover restores the values as they were.
+
+use std::time::SystemTime;
+
+use serde_json::{Value, json};
+
+use crate::{DataCache, error::JsonDataCacheError, path_pattern::PathPattern};
+
+/// Default path of the status inserted while degraded, like {$degraded.reason}
+pub const DEFAULT_DEGRADED_STATUS_PATH: &str = "degraded";
+
+/// Header telling clients & CDNs that the response was rendered degraded, see DegradedStatus::header_value
+pub const DEGRADED_HEADER: &str = "X-Edge-Degraded";
+
+/// How renders degrade, see DataCache::set_degraded_mode
+#[derive(Debug, Clone)]
+pub struct DegradedMode {
+    fallbacks: Vec<(String, Value)>,
+    disabled: Vec<PathPattern>,
+    disable_dynamic: bool,
+    status_path: String,
+}
+
+impl Default for DegradedMode {
+    fn default() -> Self {
+        Self {
+            fallbacks: Vec::new(),
+            disabled: Vec::new(),
+            disable_dynamic: false,
+            status_path: DEFAULT_DEGRADED_STATUS_PATH.to_string(),
+        }
+    }
+}
+
+impl DegradedMode {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Substitutes the value at path while degraded, like a static menu for a navigation fetched from the origin
+    pub fn fallback(mut self, path: &str, value: Value) -> Self {
+        self.fallbacks.push((path.to_string(), value));
+        self
+    }
+
+    /// Renders the blocks at the paths matching pattern empty while degraded, like recommendations
+    pub fn disable(mut self, pattern: &str) -> Self {
+        self.disabled.push(PathPattern::from(pattern));
+        self
+    }
+
+    /// Also renders the paths declared dynamic empty while degraded, so that no personalized block shows stale values
+    pub fn disable_dynamic(mut self, disable_dynamic: bool) -> Self {
+        self.disable_dynamic = disable_dynamic;
+        self
+    }
+
+    /// Path of the status inserted while degraded (DEFAULT_DEGRADED_STATUS_PATH by default)
+    pub fn status_path(mut self, status_path: &str) -> Self {
+        self.status_path = status_path.to_string();
+        self
+    }
+}
+
+/// Why & since when the cache is degraded, as returned by degraded_status
+#[derive(Debug, Clone, PartialEq)]
+pub struct DegradedStatus {
+    pub reason: String, // Like "origin-down"
+    pub since: SystemTime, // Clock time of degrade
+    pub substituted: Vec<String>, // Paths of the fallbacks
+    pub disabled: Vec<String>, // Paths of the disabled blocks
+}
+
+impl DegradedStatus {
+    /// Value of the DEGRADED_HEADER header, like `origin-down; substituted=1; disabled=2`
+    pub fn header_value(&self) -> String {
+        let reason: String = self.reason.chars().filter(|c| c.is_ascii_graphic() && *c != ';').collect();
+        format!("{reason}; substituted={}; disabled={}", self.substituted.len(), self.disabled.len())
+    }
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct DataCacheDegraded {
+    mode: DegradedMode,
+    originals: Vec<(String, Option<Value>)>, // Values replaced by degrade, in replacement order
+    status: Option<DegradedStatus>,
+}
+
+impl DataCache {
+    /// Sets how renders degrade. A cache currently degraded keeps the previous preset until recover
+    pub fn set_degraded_mode(&mut self, mode: DegradedMode) {
+        self.degraded.mode = mode;
+    }
+
+    /// Flags the cache degraded: fallbacks are substituted and disabled blocks emptied until recover. Degrading a degraded
+    /// cache only updates the reason
+    pub fn degrade(&mut self, reason: &str) -> Result<(), JsonDataCacheError> {
+        if let Some(status) = self.degraded.status.as_mut() {
+            status.reason = reason.to_string();
+            let status_path = self.degraded.mode.status_path.clone();
+            return self.try_insert(&format!("{status_path}.reason"), json!(reason));
+        }
+        let mode = self.degraded.mode.clone();
+        let mut disabled_patterns = mode.disabled.clone();
+        if mode.disable_dynamic {
+            disabled_patterns.extend(self.vary.dynamic.iter().cloned());
+        }
+        let mut disabled: Vec<String> = disabled_patterns.iter().flat_map(|pattern| pattern.matching_paths(&self.root)).collect();
+        disabled.sort_unstable();
+        disabled.dedup();
+
+        let replaced = mode.fallbacks.iter().cloned()
+            .chain(disabled.iter().map(|path| (path.clone(), Value::String(String::new()))))
+            .chain([(mode.status_path.clone(), json!({"reason": reason}))]);
+        let mut originals = Vec::new();
+        for (path, value) in replaced {
+            originals.push((path.clone(), self.get(&path).cloned()));
+            self.try_replace(&path, value)?;
+        }
+        self.degraded.originals = originals;
+        self.degraded.status = Some(DegradedStatus {
+            reason: reason.to_string(),
+            since: self.clock().now(),
+            substituted: mode.fallbacks.iter().map(|(path, _)| path.clone()).collect(),
+            disabled,
+        });
+        Ok(())
+    }
+
+    /// Restores the values replaced by degrade (the last replaced first), values inserted at their paths since then being
+    /// overwritten. Does nothing unless degraded
+    pub fn recover(&mut self) -> Result<(), JsonDataCacheError> {
+        if self.degraded.status.take().is_none() {
+            return Ok(());
+        }
+        for (path, original) in std::mem::take(&mut self.degraded.originals).into_iter().rev() {
+            self.try_replace(&path, original.unwrap_or(Value::Null))?;
+        }
+        Ok(())
+    }
+
+    /// The status of the cache if degraded
+    pub fn degraded_status(&self) -> Option<&DegradedStatus> {
+        self.degraded.status.as_ref()
+    }
+}
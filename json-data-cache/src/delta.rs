@@ -0,0 +1,119 @@
+//! Deltas between rendered variants of a template, like its per-locale outputs, which share the markup of the template &
+//! differ by their values: edge storage keeps one full body & a small delta per other variant, apply_delta reconstructing
+//! a variant at request time from the full body.
+//!
+//! The format starts with DELTA_MAGIC, the varint length & CRC-32C (little endian) of the base, then of the target, then
+//! sequences of a varint count of added bytes, those bytes, and (except for the last sequence) the varint offset in the
+//! base & varint length minus MIN_COPY of bytes copied from it. The checksums make applying a delta to another base (or
+//! a corrupted delta) fail instead of producing a wrong body.
+
+use std::collections::HashMap;
+
+use crate::{compression::{push_varint, read_varint}, crc32c::Crc32c, error::JsonDataCacheError};
+
+/// First bytes of deltas
+pub const DELTA_MAGIC: &[u8; 4] = b"KED1";
+
+/// Shortest run of bytes copied from the base, shorter ones being added as is
+const MIN_COPY: usize = 8;
+
+/// Base positions kept per run of MIN_COPY bytes, bounding the time spent on repetitive bases
+const MAX_CANDIDATES: usize = 16;
+
+fn crc32c(data: &[u8]) -> u32 {
+    let mut crc = Crc32c::default();
+    crc.update(data);
+    crc.finalize()
+}
+
+fn push_header(output: &mut Vec<u8>, data: &[u8]) {
+    push_varint(output, data.len());
+    output.extend_from_slice(&crc32c(data).to_le_bytes());
+}
+
+fn read_header(delta: &[u8], pos: &mut usize) -> Result<(usize, u32), JsonDataCacheError> {
+    let len = read_varint(delta, pos).map_err(|_| "Truncated delta")?;
+    let checksum = delta.get(*pos..*pos + 4).ok_or("Truncated delta")?;
+    *pos += 4;
+    Ok((len, u32::from_le_bytes([checksum[0], checksum[1], checksum[2], checksum[3]])))
+}
+
+/// Delta turning base into target, see apply_delta
+/// Example: encode_delta(&ja_body, &en_body), once per locale other than the stored ja_body
+pub fn encode_delta(base: &[u8], target: &[u8]) -> Vec<u8> {
+    let mut output = Vec::from(*DELTA_MAGIC);
+    push_header(&mut output, base);
+    push_header(&mut output, target);
+    let mut index: HashMap<&[u8], Vec<usize>> = HashMap::new();
+    for start in 0..(base.len() + 1).saturating_sub(MIN_COPY) {
+        let positions = index.entry(&base[start..start + MIN_COPY]).or_default();
+        if positions.len() < MAX_CANDIDATES {
+            positions.push(start);
+        }
+    }
+    let match_len = |base_start: usize, target_start: usize| {
+        base[base_start..].iter().zip(&target[target_start..]).take_while(|(a, b)| a == b).count()
+    };
+    let (mut pos, mut added_start, mut next_copy) = (0, 0, 0);
+    while pos + MIN_COPY <= target.len() {
+        // The bytes following the last copy first, variants mostly differing by the values between identical markup
+        let candidates = index.get(&target[pos..pos + MIN_COPY]).map(Vec::as_slice).unwrap_or_default();
+        let best = [next_copy].iter().chain(candidates)
+            .filter(|base_start| **base_start < base.len())
+            .map(|base_start| (*base_start, match_len(*base_start, pos)))
+            .max_by_key(|(_, len)| *len)
+            .filter(|(_, len)| *len >= MIN_COPY);
+        let Some((mut base_start, mut len)) = best else {
+            pos += 1;
+            continue;
+        };
+        while pos > added_start && base_start > 0 && base[base_start - 1] == target[pos - 1] {
+            (pos, base_start, len) = (pos - 1, base_start - 1, len + 1);
+        }
+        push_varint(&mut output, pos - added_start);
+        output.extend_from_slice(&target[added_start..pos]);
+        push_varint(&mut output, base_start);
+        push_varint(&mut output, len - MIN_COPY);
+        pos += len;
+        added_start = pos;
+        next_copy = base_start + len;
+    }
+    push_varint(&mut output, target.len() - added_start);
+    output.extend_from_slice(&target[added_start..]);
+    output
+}
+
+/// Target of a delta computed by encode_delta from base. Fails if the delta is invalid or was computed from another base
+pub fn apply_delta(base: &[u8], delta: &[u8]) -> Result<Vec<u8>, JsonDataCacheError> {
+    if !delta.starts_with(DELTA_MAGIC) {
+        return Err("Not a delta".into());
+    }
+    let mut pos = DELTA_MAGIC.len();
+    let (base_len, base_checksum) = read_header(delta, &mut pos)?;
+    if base_len != base.len() || base_checksum != crc32c(base) {
+        return Err("Delta was computed from another base".into());
+    }
+    let (len, checksum) = read_header(delta, &mut pos)?;
+    // Lengths are checked as bytes are produced, so that a forged length can not allocate more than the delta can produce
+    let mut output = Vec::with_capacity(len.min(base.len() + delta.len()));
+    loop {
+        let added_len = read_varint(delta, &mut pos).map_err(|_| "Truncated delta")?;
+        let added = pos.checked_add(added_len).and_then(|end| delta.get(pos..end)).ok_or("Truncated delta")?;
+        output.extend_from_slice(added);
+        pos += added_len;
+        if pos == delta.len() {
+            break;
+        }
+        let base_start = read_varint(delta, &mut pos).map_err(|_| "Truncated delta")?;
+        let copy_len = read_varint(delta, &mut pos).map_err(|_| "Truncated delta")?.saturating_add(MIN_COPY);
+        let copied = base_start.checked_add(copy_len).and_then(|end| base.get(base_start..end)).ok_or("Invalid copy in delta")?;
+        output.extend_from_slice(copied);
+        if output.len() > len {
+            break;
+        }
+    }
+    if output.len() != len || crc32c(&output) != checksum {
+        return Err("Delta produced another body than the one it was computed for".into());
+    }
+    Ok(output)
+}
@@ -33,15 +33,38 @@ impl From<String> for JsonDataCacheError {
     }
 }
 
+#[cfg(feature = "replace-engine")]
 impl From<aho_corasick::BuildError> for JsonDataCacheError {
     fn from(value: aho_corasick::BuildError) -> Self {
-        format!("[AC] {}", value.to_string()).into()
+        format!("[AC] {}", value).into()
     }
 }
 
+#[cfg(feature = "replace-engine")]
 impl From<aho_corasick::MatchError> for JsonDataCacheError {
     fn from(value: aho_corasick::MatchError) -> Self {
-        format!("[AC] {}", value.to_string()).into()
+        format!("[AC] {}", value).into()
+    }
+}
+
+#[cfg(feature = "replace-engine")]
+impl From<regex_automata::dfa::dense::BuildError> for JsonDataCacheError {
+    fn from(value: regex_automata::dfa::dense::BuildError) -> Self {
+        format!("[DFA] {}", value).into()
+    }
+}
+
+#[cfg(feature = "replace-engine")]
+impl From<regex_automata::util::wire::DeserializeError> for JsonDataCacheError {
+    fn from(value: regex_automata::util::wire::DeserializeError) -> Self {
+        format!("[DFA] {}", value).into()
+    }
+}
+
+#[cfg(feature = "replace-engine")]
+impl From<regex_automata::MatchError> for JsonDataCacheError {
+    fn from(value: regex_automata::MatchError) -> Self {
+        format!("[DFA] {}", value).into()
     }
 }
 
@@ -53,8 +76,8 @@ impl From<std::io::Error> for JsonDataCacheError {
     }
 }   
 
-impl Into<std::io::Error> for JsonDataCacheError {
-    fn into(self) -> std::io::Error {
-        std::io::Error::new(std::io::ErrorKind::Other, self)
+impl From<JsonDataCacheError> for std::io::Error {
+    fn from(val: JsonDataCacheError) -> Self {
+        std::io::Error::other(val)
     }
 }   
@@ -0,0 +1,114 @@
+//! Static site export, for hybrid deployments pre-rendering most pages & rendering the rest at the edge: each route is
+//! rendered by Bundle::render_with, as the edge renders requests, its seed data being the request cache. Pages are written
+//! to a directory or to a tar stream, "/news/1" becoming news/1/index.html (routes whose last segment has an extension,
+//! like "/feed.xml", keeping their name). Routes are listed like
+//! [{"path": "/", "template": "home"}, {"path": "/news/1", "template": "article", "data": {"article": {"id": 1}}}]
+
+use std::{fs, io, path::Path};
+
+use serde_json::Value;
+
+use crate::{DataCache, DataCacheOptions, bundle::{BLOCK_LEN, Bundle, write_tar_entry}, error::JsonDataCacheError};
+
+/// A page to export
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExportRoute {
+    pub path: String, // Like "/news/1"
+    pub template_id: String,
+    pub seed: Value, // Merged at the root of the request cache, Null for none
+}
+
+impl ExportRoute {
+    pub fn new(path: &str, template_id: &str, seed: Value) -> Self {
+        Self { path: path.to_string(), template_id: template_id.to_string(), seed }
+    }
+
+    /// Routes of a JSON array of {"path", "template", "data"} objects, data being optional
+    pub fn from_json(routes: &Value) -> Result<Vec<Self>, JsonDataCacheError> {
+        let routes = routes.as_array().ok_or("Routes must be a JSON array")?;
+        routes.iter().enumerate().map(|(idx, route)| {
+            let field = |name: &str| route.get(name).and_then(Value::as_str).ok_or_else(|| format!("Route {idx} has no {name}"));
+            Ok(Self::new(field("path")?, field("template")?, route.get("data").cloned().unwrap_or(Value::Null)))
+        }).collect()
+    }
+}
+
+/// A page written by an export
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExportedPage {
+    pub route: String,
+    pub file: String, // Relative to the export directory or archive root, like "news/1/index.html"
+    pub len: usize,
+}
+
+/// Relative path of the file of a route: "/" is index.html, "/news/1" (or "/news/1/") news/1/index.html, "/feed.xml" feed.xml
+pub fn route_file_path(route: &str) -> Result<String, JsonDataCacheError> {
+    let Some(path) = route.strip_prefix('/') else {
+        return Err(format!("Route {route} must start with /").into());
+    };
+    if route.contains(['?', '#', '\\']) {
+        return Err(format!("Route {route} must be a path only").into());
+    }
+    let path = path.trim_end_matches('/');
+    let segments: Vec<&str> = if path.is_empty() { Vec::new() } else { path.split('/').collect() };
+    if segments.iter().any(|segment| segment.is_empty() || *segment == "." || *segment == "..") {
+        return Err(format!("Invalid route {route}").into());
+    }
+    match segments.last() {
+        Some(last) if last.contains('.') && !route.ends_with('/') => Ok(path.to_string()),
+        Some(_) => Ok(format!("{path}/index.html")),
+        None => Ok(String::from("index.html")),
+    }
+}
+
+impl Bundle {
+    /// Renders each route (see render_with, the bundle must be prepared), giving emit the file path of the page & its
+    /// bytes. Fails on the first route failing to render or to be emitted, and on routes sharing a file
+    pub fn export<F>(&self, routes: &[ExportRoute], mut emit: F) -> Result<Vec<ExportedPage>, JsonDataCacheError>
+    where
+        F: FnMut(&str, &[u8]) -> Result<(), JsonDataCacheError>,
+    {
+        let mut pages: Vec<ExportedPage> = Vec::with_capacity(routes.len());
+        for route in routes {
+            let file = route_file_path(&route.path)?;
+            if let Some(page) = pages.iter().find(|page| page.file == file) {
+                return Err(format!("Routes {} and {} are both exported to {file}", page.route, route.path).into());
+            }
+            let mut request_cache = DataCache::new(DataCacheOptions::default());
+            match &route.seed {
+                Value::Null => {},
+                seed @ Value::Object(_) => request_cache.try_merge(seed.clone())?,
+                _ => return Err(format!("Data of route {} must be a JSON object", route.path).into()),
+            }
+            let mut output = Vec::new();
+            self.render_with(&route.template_id, &mut request_cache, &mut output)
+                .map_err(|e| format!("Unable to render {}: {}", route.path, e.msg))?;
+            emit(&file, &output)?;
+            pages.push(ExportedPage { route: route.path.clone(), file, len: output.len() });
+        }
+        Ok(pages)
+    }
+
+    /// Exports the routes below dir, creating the directories needed
+    pub fn export_to_dir(&self, routes: &[ExportRoute], dir: &Path) -> Result<Vec<ExportedPage>, JsonDataCacheError> {
+        self.export(routes, |file, bytes| {
+            let path = dir.join(file);
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::write(&path, bytes).map_err(|e| format!("Unable to write {}: {e}", path.display()).into())
+        })
+    }
+
+    /// Exports the routes as a tar archive (ustar, uncompressed), written entry by entry
+    pub fn export_to_tar<W: io::Write>(&self, routes: &[ExportRoute], mut writer: W) -> Result<Vec<ExportedPage>, JsonDataCacheError> {
+        let pages = self.export(routes, |file, bytes| {
+            let mut entry = Vec::with_capacity(BLOCK_LEN + bytes.len().next_multiple_of(BLOCK_LEN));
+            write_tar_entry(&mut entry, file, bytes)?;
+            Ok(writer.write_all(&entry)?)
+        })?;
+        writer.write_all(&[0; 2 * BLOCK_LEN])?;
+        writer.flush()?;
+        Ok(pages)
+    }
+}
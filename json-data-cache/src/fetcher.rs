@@ -0,0 +1,544 @@
+//! Origin fetch-and-cache helper: data sources declare where their values come from, and Fetcher::ensure fetches them (through a
+//! user supplied HTTP client) only when they are missing or expired, inserting the parsed responses into the cache.
+//! An optional circuit breaker stops requesting unhealthy origins for a while, serving stale values or fallbacks instead.
+
+use std::{collections::HashMap, time::{Duration, SystemTime}};
+
+use serde_json::{Value, json};
+
+use crate::{DataCache, FreshnessMeta, computed::paths_overlap, error::JsonDataCacheError};
+
+/// Declares that the value at path is the JSON response of url_template
+/// url_template may contain {$key} placeholders, replaced by the (percent-encoded) values of the cache
+#[derive(Debug, Clone)]
+pub struct DataSource {
+    pub path: String,
+    pub url_template: String,
+    pub headers: Vec<(String, String)>,
+    pub ttl: Option<Duration>, // None : fetched once
+    pub fallback: Option<Value> // Inserted when the circuit of the source is open and the cache has no stale value
+}
+
+impl DataSource {
+    pub fn new(path: &str, url_template: &str) -> Self {
+        Self {
+            path: path.to_string(),
+            url_template: url_template.to_string(),
+            headers: Vec::new(),
+            ttl: None,
+            fallback: None
+        }
+    }
+
+    pub fn header(mut self, name: &str, value: &str) -> Self {
+        self.headers.push((name.to_string(), value.to_string()));
+        self
+    }
+
+    pub fn ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = Some(ttl);
+        self
+    }
+
+    pub fn fallback(mut self, fallback: Value) -> Self {
+        self.fallback = Some(fallback);
+        self
+    }
+
+    /// Keys of the placeholders of url_template
+    pub fn dependencies(&self) -> Vec<&str> {
+        let mut dependencies = Vec::new();
+        let mut remaining = self.url_template.as_str();
+        while let Some(start) = remaining.find("{$") {
+            let Some(len) = remaining[start + 2..].find('}') else {
+                break;
+            };
+            dependencies.push(&remaining[start + 2..start + 2 + len]);
+            remaining = &remaining[start + 2 + len + 1..];
+        }
+        dependencies
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FetchRequest {
+    pub url: String,
+    pub headers: Vec<(String, String)>
+}
+
+#[derive(Debug, Clone)]
+pub struct FetchResponse {
+    pub status: u16,
+    pub body: Vec<u8>
+}
+
+/// Performs HTTP requests for the fetcher. Implemented by any FnMut(&FetchRequest) -> Result<FetchResponse, JsonDataCacheError>
+pub trait HttpClient {
+    fn fetch(&mut self, request: &FetchRequest) -> Result<FetchResponse, JsonDataCacheError>;
+
+    /// Performs independent requests, in the same order. Clients able to run requests concurrently should override it
+    fn fetch_all(&mut self, requests: &[FetchRequest]) -> Vec<Result<FetchResponse, JsonDataCacheError>> {
+        requests.iter().map(|request| self.fetch(request)).collect()
+    }
+}
+
+impl<F> HttpClient for F
+where
+    F: FnMut(&FetchRequest) -> Result<FetchResponse, JsonDataCacheError>,
+{
+    fn fetch(&mut self, request: &FetchRequest) -> Result<FetchResponse, JsonDataCacheError> {
+        self(request)
+    }
+}
+
+/// Stages of sources to fetch, in order: sources of a stage only depend on sources of previous stages,
+/// so that requests of a stage can all run in parallel
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FetchPlan {
+    pub stages: Vec<Vec<String>> // Source paths
+}
+
+/// When sources stop being requested : after failure_threshold consecutive failures (errors, non 2xx statuses, invalid JSON,
+/// or responses slower than timeout), the circuit opens for cooldown, then a single trial request is allowed (half-open),
+/// closing the circuit on success and opening it again on failure
+#[derive(Debug, Clone)]
+pub struct CircuitBreakerPolicy {
+    pub failure_threshold: u32,
+    pub cooldown: Duration,
+    pub timeout: Option<Duration>,
+    pub health_path: Option<String> // Where the health of each source is inserted, by source path with dots replaced by _
+}
+
+impl CircuitBreakerPolicy {
+    pub fn new(failure_threshold: u32, cooldown: Duration) -> Self {
+        Self {
+            failure_threshold: failure_threshold.max(1),
+            cooldown,
+            timeout: None,
+            health_path: None
+        }
+    }
+
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub fn health_path(mut self, health_path: &str) -> Self {
+        self.health_path = Some(health_path.to_string());
+        self
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CircuitState {
+    Closed,
+    Open { until: SystemTime },
+    HalfOpen
+}
+
+/// Request outcomes of a source
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourceHealth {
+    pub state: CircuitState,
+    pub consecutive_failures: u32,
+    pub requests: u64,
+    pub failures: u64, // Timeouts included
+    pub timeouts: u64
+}
+
+impl Default for SourceHealth {
+    fn default() -> Self {
+        Self {
+            state: CircuitState::Closed,
+            consecutive_failures: 0,
+            requests: 0,
+            failures: 0,
+            timeouts: 0
+        }
+    }
+}
+
+impl SourceHealth {
+    pub fn failure_rate(&self) -> f64 {
+        if self.requests == 0 { 0.0 } else { self.failures as f64 / self.requests as f64 }
+    }
+
+    /// As inserted at the health path, state being closed, open or half_open
+    pub fn to_json(&self) -> Value {
+        let state = match self.state {
+            CircuitState::Closed => "closed",
+            CircuitState::Open { .. } => "open",
+            CircuitState::HalfOpen => "half_open",
+        };
+        json!({
+            "state": state,
+            "consecutive_failures": self.consecutive_failures,
+            "requests": self.requests,
+            "failures": self.failures,
+            "timeouts": self.timeouts,
+            "failure_rate": self.failure_rate()
+        })
+    }
+}
+
+/// A response kept for the sources sharing the same request
+#[derive(Debug)]
+struct FetchedResponse {
+    value: Value,
+    expires_at: Option<SystemTime>
+}
+
+/// Fetches declared data sources into a DataCache
+/// Requests are coalesced : sources rendering the same request share a single fetch, and a request is not made again
+/// until the ttl of the source which fetched it has elapsed
+#[derive(Debug)]
+pub struct Fetcher<C: HttpClient> {
+    client: C,
+    sources: Vec<DataSource>,
+    responses: HashMap<FetchRequest, FetchedResponse>,
+    inserted: HashMap<String, FetchRequest>, // Request of the last value inserted for each source path
+    circuit_breaker: Option<CircuitBreakerPolicy>,
+    health: HashMap<String, SourceHealth> // By source path, only tracked with a circuit breaker
+}
+
+impl<C: HttpClient> Fetcher<C> {
+    pub fn new(client: C) -> Self {
+        Self {
+            client,
+            sources: Vec::new(),
+            responses: HashMap::new(),
+            inserted: HashMap::new(),
+            circuit_breaker: None,
+            health: HashMap::new()
+        }
+    }
+
+    pub fn circuit_breaker(mut self, policy: CircuitBreakerPolicy) -> Self {
+        self.circuit_breaker = Some(policy);
+        self
+    }
+
+    /// Request outcomes of the source of path, if it has been requested with a circuit breaker
+    pub fn health(&self, source_path: &str) -> Option<&SourceHealth> {
+        self.health.get(source_path)
+    }
+
+    /// Declares a source, replacing any previous source of the same path
+    pub fn add_source(&mut self, source: DataSource) {
+        self.sources.retain(|existing| existing.path != source.path);
+        self.sources.push(source);
+    }
+
+    pub fn client(&self) -> &C {
+        &self.client
+    }
+
+    pub fn sources(&self) -> &[DataSource] {
+        &self.sources
+    }
+
+    /// The source providing path, or one of its ancestors or descendants
+    fn find_source(&self, path: &str) -> Option<&DataSource> {
+        self.sources.iter().find(|source| paths_overlap(&source.path, path))
+    }
+
+    /// Builds the request of a source from the current values of the cache. Fails if a placeholder has no value
+    pub fn render_request(&self, data_cache: &DataCache, source: &DataSource) -> Result<FetchRequest, JsonDataCacheError> {
+        let mut url = String::with_capacity(source.url_template.len());
+        let mut remaining = source.url_template.as_str();
+        while let Some(start) = remaining.find("{$") {
+            let Some(len) = remaining[start + 2..].find('}') else {
+                break;
+            };
+            let key = &remaining[start + 2..start + 2 + len];
+            let value = match data_cache.get(key) {
+                Some(Value::String(s)) => s.clone(),
+                Some(Value::Null) | None => return Err(format!("Source {} depends on {key}, which has no value", source.path).into()),
+                Some(value) => value.to_string(),
+            };
+            url.push_str(&remaining[..start]);
+            percent_encode(&value, &mut url);
+            remaining = &remaining[start + 2 + len + 1..];
+        }
+        url.push_str(remaining);
+        Ok(FetchRequest {
+            url,
+            headers: source.headers.clone()
+        })
+    }
+
+    /// True if the value of the source has been inserted from the request, is still fresh, and has not been invalidated since
+    fn is_up_to_date(&self, data_cache: &DataCache, source: &DataSource, request: &FetchRequest, now: SystemTime) -> bool {
+        self.inserted.get(&source.path) == Some(request) && self.is_fresh(request, now) && data_cache.get(&source.path).is_some()
+    }
+
+    /// True if the response of the request can be used, now
+    fn is_fresh(&self, request: &FetchRequest, now: SystemTime) -> bool {
+        self.responses.get(request).is_some_and(|response| response.expires_at.is_none_or(|expires_at| now < expires_at))
+    }
+
+    /// Makes sure the source providing path has been fetched and inserted, fetching it if it is missing, expired, or if its
+    /// request changed (its placeholders have new values). Returns false if the cache was already up to date.
+    /// A path without source is an error. While the circuit of the source is open, the stale value is kept (returning false)
+    /// or the fallback inserted, failing if there is neither
+    pub fn ensure(&mut self, data_cache: &mut DataCache, path: &str) -> Result<bool, JsonDataCacheError> {
+        let source = self.find_source(path).ok_or(format!("No data source for {path}"))?.clone();
+        let request = self.render_request(data_cache, &source)?;
+        let now = data_cache.clock().now();
+        if self.is_up_to_date(data_cache, &source, &request, now) {
+            return Ok(false);
+        }
+        if !self.is_fresh(&request, now) {
+            if !self.is_circuit_closed(&source.path, now) {
+                return Self::fall_back(data_cache, &source);
+            }
+            let start = data_cache.clock().monotonic();
+            let response = self.client.fetch(&request);
+            let elapsed = data_cache.clock().monotonic().saturating_sub(start);
+            let result = self.store_response(&source, &request, response, now);
+            self.record_outcome(data_cache, &source.path, result.is_ok(), elapsed, now)?;
+            result?;
+        }
+        self.insert_response(data_cache, &source, request)?;
+        Ok(true)
+    }
+
+    /// False while the circuit of the source is open. Once its cooldown has elapsed, the circuit becomes half-open for a trial
+    fn is_circuit_closed(&mut self, source_path: &str, now: SystemTime) -> bool {
+        let Some(health) = self.health.get_mut(source_path) else {
+            return true;
+        };
+        match health.state {
+            CircuitState::Open { until } if now < until => false,
+            CircuitState::Open { .. } => {
+                health.state = CircuitState::HalfOpen;
+                true
+            },
+            _ => true,
+        }
+    }
+
+    /// Keeps the stale value of an open circuit source (false), or inserts its fallback (true)
+    fn fall_back(data_cache: &mut DataCache, source: &DataSource) -> Result<bool, JsonDataCacheError> {
+        if data_cache.get(&source.path).is_some() {
+            return Ok(false);
+        }
+        let fallback = source.fallback.clone().ok_or(format!("Circuit of source {} is open, and it has no stale value nor fallback", source.path))?;
+        data_cache.try_insert(&source.path, fallback)?;
+        Ok(true)
+    }
+
+    /// Updates the health of the source with the outcome of its request, inserting it at the health path
+    fn record_outcome(
+        &mut self,
+        data_cache: &mut DataCache,
+        source_path: &str,
+        is_success: bool,
+        elapsed: Duration,
+        now: SystemTime
+    ) -> Result<(), JsonDataCacheError> {
+        let Some(policy) = &self.circuit_breaker else {
+            return Ok(());
+        };
+        let health = self.health.entry(source_path.to_string()).or_default();
+        let is_timeout = policy.timeout.is_some_and(|timeout| elapsed > timeout);
+        health.requests += 1;
+        if is_timeout {
+            health.timeouts += 1;
+        }
+        if is_success && !is_timeout {
+            health.consecutive_failures = 0;
+            health.state = CircuitState::Closed;
+        } else {
+            health.failures += 1;
+            health.consecutive_failures += 1;
+            if health.state == CircuitState::HalfOpen || health.consecutive_failures >= policy.failure_threshold {
+                log::warn!("Circuit of source {source_path} opened after {} consecutive failures", health.consecutive_failures);
+                health.state = CircuitState::Open { until: now + policy.cooldown };
+            }
+        }
+        if let Some(health_path) = &policy.health_path {
+            data_cache.try_insert(&format!("{health_path}.{}", source_path.replace('.', "_")), health.to_json())?;
+        }
+        Ok(())
+    }
+
+    /// Forgets the responses of the sources overlapping path, so that the next ensure fetches them again
+    /// To be called along with DataCache::invalidate when the origin content changed
+    pub fn invalidate(&mut self, path: &str) {
+        for source in self.sources.iter().filter(|source| paths_overlap(&source.path, path)) {
+            if let Some(request) = self.inserted.remove(&source.path) {
+                self.responses.remove(&request);
+            }
+        }
+    }
+
+    /// Sources whose path provides one of the placeholders of the source
+    fn source_dependencies(&self, source: &DataSource) -> Vec<&DataSource> {
+        let dependencies = source.dependencies();
+        self.sources.iter()
+            .filter(|other| other.path != source.path && dependencies.iter().any(|dependency| paths_overlap(&other.path, dependency)))
+            .collect()
+    }
+
+    /// Plans the fetches needed for paths: their sources, and recursively the sources their URLs depend on.
+    /// Each source is placed in the stage following its last dependency. Fails on unknown paths and dependency cycles
+    pub fn plan(&self, paths: &[&str]) -> Result<FetchPlan, JsonDataCacheError> {
+        let mut stage_of: HashMap<String, usize> = HashMap::new();
+        let mut visiting: Vec<String> = Vec::new();
+        for path in paths {
+            let source = self.find_source(path).ok_or(format!("No data source for {path}"))?;
+            self.plan_source(source, &mut stage_of, &mut visiting)?;
+        }
+        let mut stages: Vec<Vec<String>> = Vec::new();
+        // Sources order is kept within stages
+        for source in &self.sources {
+            if let Some(stage) = stage_of.get(&source.path) {
+                if stages.len() <= *stage {
+                    stages.resize_with(stage + 1, Vec::new);
+                }
+                stages[*stage].push(source.path.clone());
+            }
+        }
+        Ok(FetchPlan { stages })
+    }
+
+    /// Depth first placement of a source after its dependencies, returning its stage
+    fn plan_source(
+        &self,
+        source: &DataSource,
+        stage_of: &mut HashMap<String, usize>,
+        visiting: &mut Vec<String>
+    ) -> Result<usize, JsonDataCacheError> {
+        if let Some(stage) = stage_of.get(&source.path) {
+            return Ok(*stage);
+        }
+        if visiting.contains(&source.path) {
+            return Err(format!("Data sources dependency cycle : {} -> {}", visiting.join(" -> "), source.path).into());
+        }
+        visiting.push(source.path.clone());
+        let mut stage = 0;
+        for dependency in self.source_dependencies(source) {
+            stage = stage.max(self.plan_source(dependency, stage_of, visiting)? + 1);
+        }
+        visiting.pop();
+        stage_of.insert(source.path.clone(), stage);
+        Ok(stage)
+    }
+
+    /// Executes the plan of paths (see plan) stage by stage. Requests of a stage are sent together with HttpClient::fetch_all,
+    /// coalesced like with ensure. Stops after the first stage having a failure, returning the first error.
+    /// Returns the count of inserted sources
+    pub fn ensure_all(&mut self, data_cache: &mut DataCache, paths: &[&str]) -> Result<usize, JsonDataCacheError> {
+        let plan = self.plan(paths)?;
+        let mut inserted_count = 0;
+        for stage in plan.stages {
+            let now = data_cache.clock().now();
+            let mut to_insert: Vec<(DataSource, FetchRequest)> = Vec::new();
+            let mut requests: Vec<FetchRequest> = Vec::new();
+            let mut first_error = None;
+            for path in stage {
+                let source = self.sources.iter().find(|source| source.path == path).unwrap().clone();
+                let request = match self.render_request(data_cache, &source) {
+                    Ok(request) => request,
+                    Err(e) => {
+                        first_error.get_or_insert(e);
+                        continue;
+                    },
+                };
+                if self.is_up_to_date(data_cache, &source, &request, now) {
+                    continue;
+                }
+                if !self.is_fresh(&request, now) && !self.is_circuit_closed(&source.path, now) {
+                    match Self::fall_back(data_cache, &source) {
+                        Ok(true) => inserted_count += 1,
+                        Ok(false) => {},
+                        Err(e) => {
+                            first_error.get_or_insert(e);
+                        },
+                    }
+                    continue;
+                }
+                if !self.is_fresh(&request, now) && !requests.contains(&request) {
+                    requests.push(request.clone());
+                }
+                to_insert.push((source, request));
+            }
+            let start = data_cache.clock().monotonic();
+            let responses = if requests.is_empty() { Vec::new() } else { self.client.fetch_all(&requests) };
+            let elapsed = data_cache.clock().monotonic().saturating_sub(start);
+            let mut failed_requests = Vec::new();
+            for (request, response) in requests.iter().zip(responses) {
+                let source = to_insert.iter().find(|(_, source_request)| source_request == request).map(|(source, _)| source.clone()).unwrap();
+                let result = self.store_response(&source, request, response, now);
+                // Concurrent requests are timed together
+                for (source, _) in to_insert.iter().filter(|(_, source_request)| source_request == request) {
+                    self.record_outcome(data_cache, &source.path, result.is_ok(), elapsed, now)?;
+                }
+                if let Err(e) = result {
+                    first_error.get_or_insert(e);
+                    failed_requests.push(request);
+                }
+            }
+            for (source, request) in to_insert {
+                if !failed_requests.contains(&&request) {
+                    self.insert_response(data_cache, &source, request)?;
+                    inserted_count += 1;
+                }
+            }
+            if let Some(e) = first_error {
+                return Err(e);
+            }
+        }
+        Ok(inserted_count)
+    }
+
+    /// Parses a response and keeps it for the sources sharing the request
+    fn store_response(
+        &mut self,
+        source: &DataSource,
+        request: &FetchRequest,
+        response: Result<FetchResponse, JsonDataCacheError>,
+        now: SystemTime
+    ) -> Result<(), JsonDataCacheError> {
+        let response = response?;
+        if !(200..300).contains(&response.status) {
+            return Err(format!("Fetching {} for {} failed with status {}", request.url, source.path, response.status).into());
+        }
+        let value = serde_json::from_slice(&response.body)
+            .map_err(|e| format!("Invalid JSON response from {} for {} : {e}", request.url, source.path))?;
+        log::debug!("Fetcher fetched {} for {}", request.url, source.path);
+        self.responses.insert(request.clone(), FetchedResponse {
+            value,
+            expires_at: source.ttl.map(|ttl| now + ttl)
+        });
+        Ok(())
+    }
+
+    /// Inserts the kept response of the request at the path of the source
+    fn insert_response(&mut self, data_cache: &mut DataCache, source: &DataSource, request: FetchRequest) -> Result<(), JsonDataCacheError> {
+        let response = &self.responses[&request];
+        // Replaced rather than merged into the previous response
+        data_cache.try_replace(&source.path, response.value.clone())?;
+        if let Some(expires_at) = response.expires_at {
+            data_cache.set_freshness(&source.path, FreshnessMeta {
+                fresh_until: expires_at,
+                stale_until: expires_at
+            });
+        }
+        self.inserted.insert(source.path.clone(), request);
+        Ok(())
+    }
+}
+
+/// Percent-encodes everything but unreserved characters (RFC 3986)
+pub(crate) fn percent_encode(value: &str, output: &mut String) {
+    for byte in value.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.' | b'_' | b'~') {
+            output.push(byte as char);
+        } else {
+            output.push_str(&format!("%{byte:02X}"));
+        }
+    }
+}
@@ -0,0 +1,179 @@
+//! C ABI over DataCache, for non-Rust runtimes. See include/json_data_cache.h
+//! All strings are passed as pointer + length (UTF-8, no terminating NUL required), and every buffer returned by the
+//! library must be released with json_data_cache_buffer_free.
+
+use std::{ptr, slice, str};
+
+use serde_json::Value;
+
+use crate::{DataCache, DataCacheOptions};
+
+pub const JSON_DATA_CACHE_OK: i32 = 0;
+pub const JSON_DATA_CACHE_ERR_NULL: i32 = -1;
+pub const JSON_DATA_CACHE_ERR_UTF8: i32 = -2;
+pub const JSON_DATA_CACHE_ERR_JSON: i32 = -3;
+pub const JSON_DATA_CACHE_ERR_NOT_FOUND: i32 = -4;
+pub const JSON_DATA_CACHE_ERR_REPLACE: i32 = -5;
+pub const JSON_DATA_CACHE_ERR_REJECTED: i32 = -6; // Value refused by the cache, for example nested deeper than its maximum depth
+
+/// Bytes owned by the library
+#[repr(C)]
+pub struct JsonDataCacheBuffer {
+    pub data: *mut u8,
+    pub len: usize,
+}
+
+impl From<Vec<u8>> for JsonDataCacheBuffer {
+    fn from(bytes: Vec<u8>) -> Self {
+        let boxed = bytes.into_boxed_slice();
+        let len = boxed.len();
+        Self {
+            data: Box::into_raw(boxed) as *mut u8,
+            len,
+        }
+    }
+}
+
+/// Borrows a byte slice from a pointer + length pair, accepting a null pointer for an empty slice
+unsafe fn bytes_arg<'a>(data: *const u8, len: usize) -> Option<&'a [u8]> {
+    if data.is_null() {
+        if len == 0 { Some(&[]) } else { None }
+    } else {
+        Some(unsafe { slice::from_raw_parts(data, len) })
+    }
+}
+
+/// Creates an empty cache with default options
+#[unsafe(no_mangle)]
+pub extern "C" fn json_data_cache_new() -> *mut DataCache {
+    Box::into_raw(Box::new(DataCache::new(DataCacheOptions::default())))
+}
+
+/// Releases a cache created by json_data_cache_new
+///
+/// # Safety
+/// cache must be null or a pointer returned by json_data_cache_new, not freed yet
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn json_data_cache_free(cache: *mut DataCache) {
+    if !cache.is_null() {
+        drop(unsafe { Box::from_raw(cache) });
+    }
+}
+
+/// Releases a buffer returned by the library. Freeing an empty buffer is allowed
+///
+/// # Safety
+/// buffer must be null or point to a buffer written by the library, not freed yet
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn json_data_cache_buffer_free(buffer: *mut JsonDataCacheBuffer) {
+    if let Some(buffer) = unsafe { buffer.as_mut() }
+        && !buffer.data.is_null() {
+        drop(unsafe { Box::from_raw(ptr::slice_from_raw_parts_mut(buffer.data, buffer.len)) });
+        buffer.data = ptr::null_mut();
+        buffer.len = 0;
+    }
+}
+
+/// Same as DataCache::try_insert, value being a JSON document
+///
+/// # Safety
+/// cache must come from json_data_cache_new, path & json must point to at least path_len & json_len readable bytes
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn json_data_cache_insert(
+    cache: *mut DataCache,
+    path: *const u8,
+    path_len: usize,
+    json: *const u8,
+    json_len: usize,
+) -> i32 {
+    let (Some(cache), Some(path), Some(json)) = (unsafe { cache.as_mut() }, unsafe { bytes_arg(path, path_len) }, unsafe { bytes_arg(json, json_len) }) else {
+        return JSON_DATA_CACHE_ERR_NULL;
+    };
+    let Ok(path) = str::from_utf8(path) else {
+        return JSON_DATA_CACHE_ERR_UTF8;
+    };
+    match serde_json::from_slice::<Value>(json) {
+        Ok(value) => match cache.try_insert(path, value) {
+            Ok(()) => JSON_DATA_CACHE_OK,
+            Err(_) => JSON_DATA_CACHE_ERR_REJECTED,
+        },
+        Err(_) => JSON_DATA_CACHE_ERR_JSON,
+    }
+}
+
+/// Same as DataCache::try_merge, other being a JSON document
+///
+/// # Safety
+/// cache must come from json_data_cache_new, json must point to at least json_len readable bytes
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn json_data_cache_merge(cache: *mut DataCache, json: *const u8, json_len: usize) -> i32 {
+    let (Some(cache), Some(json)) = (unsafe { cache.as_mut() }, unsafe { bytes_arg(json, json_len) }) else {
+        return JSON_DATA_CACHE_ERR_NULL;
+    };
+    match serde_json::from_slice::<Value>(json) {
+        Ok(value) => match cache.try_merge(value) {
+            Ok(()) => JSON_DATA_CACHE_OK,
+            Err(_) => JSON_DATA_CACHE_ERR_REJECTED,
+        },
+        Err(_) => JSON_DATA_CACHE_ERR_JSON,
+    }
+}
+
+/// Same as DataCache::get, writing the found value serialized as JSON into out
+///
+/// # Safety
+/// cache must come from json_data_cache_new, path must point to at least path_len readable bytes, out must be writable
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn json_data_cache_get(
+    cache: *const DataCache,
+    path: *const u8,
+    path_len: usize,
+    out: *mut JsonDataCacheBuffer,
+) -> i32 {
+    let (Some(cache), Some(path)) = (unsafe { cache.as_ref() }, unsafe { bytes_arg(path, path_len) }) else {
+        return JSON_DATA_CACHE_ERR_NULL;
+    };
+    if out.is_null() {
+        return JSON_DATA_CACHE_ERR_NULL;
+    }
+    let Ok(path) = str::from_utf8(path) else {
+        return JSON_DATA_CACHE_ERR_UTF8;
+    };
+    match cache.get(path) {
+        Some(value) => {
+            unsafe { out.write(serde_json::to_vec(value).unwrap_or_default().into()) };
+            JSON_DATA_CACHE_OK
+        },
+        None => JSON_DATA_CACHE_ERR_NOT_FOUND,
+    }
+}
+
+/// Same as DataCache::replace_with_data_cache, writing the replaced input into out
+///
+/// # Safety
+/// cache must come from json_data_cache_new, input must point to at least input_len readable bytes, out must be writable
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn json_data_cache_replace(
+    cache: *mut DataCache,
+    input: *const u8,
+    input_len: usize,
+    out: *mut JsonDataCacheBuffer,
+) -> i32 {
+    let (Some(cache), Some(input)) = (unsafe { cache.as_mut() }, unsafe { bytes_arg(input, input_len) }) else {
+        return JSON_DATA_CACHE_ERR_NULL;
+    };
+    if out.is_null() {
+        return JSON_DATA_CACHE_ERR_NULL;
+    }
+    let mut output = Vec::with_capacity(input.len());
+    match cache.replace_with_data_cache(input, &mut output) {
+        Ok(_) => {
+            unsafe { out.write(output.into()) };
+            JSON_DATA_CACHE_OK
+        },
+        Err(e) => {
+            log::info!("[WARN] DataCache ffi replace : {}", e);
+            JSON_DATA_CACHE_ERR_REPLACE
+        },
+    }
+}
@@ -0,0 +1,70 @@
+use std::hash::{DefaultHasher, Hash, Hasher};
+
+use crate::{DataCache, computed::paths_overlap, error::JsonDataCacheError};
+
+/// A registered template & its last rendered output
+#[derive(Debug, Default)]
+pub(crate) struct Fragment {
+    template: Vec<u8>,
+    dependencies: Vec<String>, // Resolved through references, as given to the last render
+    output: Option<Vec<u8>>,
+    fingerprint: u64, // Of the dependency values used to render output
+    is_dirty: bool // A dependency has been modified since the last render
+}
+
+impl Fragment {
+    /// Flags the fragment if the modified path is one of its dependencies, or one of their ancestors or descendants
+    pub(crate) fn mark_dirty(&mut self, path: &str) {
+        if !self.is_dirty && self.dependencies.iter().any(|dependency| paths_overlap(dependency, path)) {
+            self.is_dirty = true;
+        }
+    }
+}
+
+impl DataCache {
+    /// Registers a template rendered by render_fragment_cached. Registering an existing id replaces it and drops its cached output
+    pub fn register_fragment(&mut self, template_id: &str, template: Vec<u8>) {
+        self.fragments.insert(template_id.to_string(), Fragment {
+            template,
+            ..Default::default()
+        });
+    }
+
+    /// Renders a registered template (see replace_with_data_cache), reusing the previous output until one of the dependency paths
+    /// is modified. Even then, the template is only rendered again if the fingerprint of the dependency values has changed.
+    /// Fragments like navigation menus only depend on a few paths and are identical across many requests
+    pub fn render_fragment_cached(&mut self, template_id: &str, dependency_paths: &[&str]) -> Result<&[u8], JsonDataCacheError> {
+        let dependencies: Vec<String> = dependency_paths.iter().map(|path| self.refs.resolve(path).into_owned()).collect();
+        let Some(fragment) = self.fragments.get(template_id) else {
+            return Err(format!("Unknown fragment {template_id}").into());
+        };
+        let is_reusable = fragment.output.is_some() && !fragment.is_dirty && fragment.dependencies == dependencies;
+        if !is_reusable {
+            let fingerprint = self.fragment_fingerprint(&dependencies);
+            let fragment = &self.fragments[template_id];
+            if fragment.output.is_none() || fragment.fingerprint != fingerprint {
+                let template = fragment.template.clone();
+                let mut output = Vec::with_capacity(template.len());
+                self.replace_with_data_cache(template.as_slice(), &mut output)?;
+                let fragment = self.fragments.get_mut(template_id).unwrap();
+                fragment.output = Some(output);
+                fragment.fingerprint = fingerprint;
+            }
+            let fragment = self.fragments.get_mut(template_id).unwrap();
+            fragment.dependencies = dependencies;
+            fragment.is_dirty = false;
+        }
+        Ok(self.fragments[template_id].output.as_deref().unwrap_or_default())
+    }
+
+    /// Hash of the serialized values of the paths (missing ones included)
+    fn fragment_fingerprint(&self, dependencies: &[String]) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        for dependency in dependencies {
+            dependency.hash(&mut hasher);
+            self.serialized_range(dependency).map(|(bytes, _)| bytes).hash(&mut hasher);
+            self.get_bytes(dependency).hash(&mut hasher);
+        }
+        hasher.finish()
+    }
+}
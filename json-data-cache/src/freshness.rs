@@ -0,0 +1,110 @@
+use std::{cell::RefCell, collections::{HashMap, HashSet}, fmt, time::SystemTime};
+
+use serde_json::Value;
+
+use crate::DataCache;
+
+/// Validity period of a cached subtree, for stale-while-revalidate
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FreshnessMeta {
+    pub fresh_until: SystemTime, // Served as is until then
+    pub stale_until: SystemTime // Then served while being revalidated until then, expired afterwards
+}
+
+/// Status of a value returned by get_with_freshness
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Freshness {
+    Fresh,
+    Stale,
+    Expired
+}
+
+type RevalidateHook = Box<dyn Fn(&str)>;
+
+/// Freshness metadata of paths, and the hook called when a stale path is read
+#[derive(Default)]
+pub(crate) struct DataCacheFreshness {
+    metas: HashMap<String, FreshnessMeta>,
+    revalidate_hook: Option<RevalidateHook>,
+    revalidating: RefCell<HashSet<String>> // Paths whose revalidation has been requested, until their freshness is set again
+}
+
+impl DataCacheFreshness {
+    /// Drops the metadata of path and its descendants
+    pub(crate) fn remove_subtree(&mut self, path: &str) {
+        self.metas.retain(|meta_path, _| !is_in_subtree(meta_path, path));
+        self.revalidating.borrow_mut().retain(|meta_path| !is_in_subtree(meta_path, path));
+    }
+}
+
+/// True if path is subtree_path or one of its descendants. Everything is in the subtree of the empty path
+fn is_in_subtree(path: &str, subtree_path: &str) -> bool {
+    subtree_path.is_empty() || path == subtree_path || (path.starts_with(subtree_path) && path.as_bytes()[subtree_path.len()] == b'.')
+}
+
+impl fmt::Debug for DataCacheFreshness {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DataCacheFreshness")
+            .field("metas", &self.metas)
+            .field("revalidate_hook", &self.revalidate_hook.is_some())
+            .field("revalidating", &self.revalidating)
+            .finish()
+    }
+}
+
+impl DataCache {
+    /// Sets the validity period of the subtree at path. Descendants without their own metadata share it
+    pub fn set_freshness(&mut self, path: &str, meta: FreshnessMeta) {
+        self.freshness.metas.insert(path.to_string(), meta);
+        self.freshness.revalidating.borrow_mut().remove(path);
+    }
+
+    /// Returns the metadata applying to path: its own, or the one of its nearest ancestor
+    /// Returns the path the metadata was set on along with it
+    pub fn freshness_meta<'a>(&self, path: &'a str) -> Option<(&'a str, &FreshnessMeta)> {
+        if self.freshness.metas.is_empty() {
+            return None;
+        }
+        let mut end = path.len();
+        loop {
+            if let Some(meta) = self.freshness.metas.get(&path[..end]) {
+                return Some((&path[..end], meta));
+            }
+            end = path[..end].rfind('.')?;
+        }
+    }
+
+    /// Sets the function called with the path carrying the metadata, the first time a stale value is read by get_with_freshness
+    /// It is called again for the same path only after its freshness has been set again
+    pub fn set_revalidate_hook<F>(&mut self, hook: F)
+    where
+        F: Fn(&str) + 'static,
+    {
+        self.freshness.revalidate_hook = Some(Box::new(hook));
+    }
+
+    /// Same as get, along with the freshness status of the value. Values without metadata are always fresh
+    /// Expired values are still returned, letting callers choose between serving them and waiting for the origin
+    pub fn get_with_freshness(&self, path: &str) -> Option<(&Value, Freshness)> {
+        let value = self.get(path)?;
+        let Some((meta_path, meta)) = self.freshness_meta(path) else {
+            return Some((value, Freshness::Fresh));
+        };
+        let now = self.clock().now();
+        let freshness = if now < meta.fresh_until {
+            Freshness::Fresh
+        } else if now < meta.stale_until {
+            Freshness::Stale
+        } else {
+            Freshness::Expired
+        };
+        if freshness == Freshness::Stale
+            && let Some(hook) = &self.freshness.revalidate_hook {
+            let is_first_read = self.freshness.revalidating.borrow_mut().insert(meta_path.to_string());
+            if is_first_read {
+                hook(meta_path);
+            }
+        }
+        Some((value, freshness))
+    }
+}
@@ -0,0 +1,369 @@
+//! Streaming HTML rewriting: HtmlRewriter applies RewriteRules to the tags of the HTML written to it, removing elements
+//! (with their content), renaming them (closing tags included) and setting or removing attributes, chunks possibly
+//! splitting tags. The content of script, style & textarea elements and comments is never parsed as tags, and a > in a
+//! quoted attribute value does not end its tag.
+//! URLs are rewritten by LinkRules (see the links module), image attributes added by ImageRules (see images).
+//! Tags whose attributes are rewritten are written again with double quoted values, the others as they are.
+
+use std::io::{self, Write};
+
+use crate::{images::ImageRules, links::LinkRules, preload::parse_attributes};
+
+/// Tags longer than this (split across chunks) are written as they are
+const MAX_PENDING_TAG_LEN: usize = 4096;
+
+const COMMENT_END: &[u8] = b"-->";
+
+/// Elements without content nor closing tag
+const VOID_ELEMENTS: &[&str] = &["area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "source", "track", "wbr"];
+
+/// Elements whose content is text, up to their closing tag
+const RAW_TEXT_ELEMENTS: &[&str] = &["script", "style", "textarea"];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum RewriteAction {
+    Remove,
+    Rename(String),
+    SetAttribute(String, String),
+    RemoveAttribute(String), // Name, or prefix followed by *
+    RewriteLinks(LinkRules),
+    RewriteImages(ImageRules),
+}
+
+/// Actions on the tags of an element, "*" matching every element
+/// Example: RewriteRule::new("img").when("loading", "").rename("amp-img").set_attribute("layout", "responsive")
+#[derive(Debug, Clone)]
+pub struct RewriteRule {
+    tag: String,
+    conditions: Vec<(String, String, bool)>, // Attribute names, substrings their values must contain (ignoring case) & whether they must
+    actions: Vec<RewriteAction>,
+}
+
+impl RewriteRule {
+    pub fn new(tag: &str) -> Self {
+        Self { tag: tag.to_ascii_lowercase(), conditions: Vec::new(), actions: Vec::new() }
+    }
+
+    /// Only matches tags whose attribute contains the substring (an empty substring only requires the attribute)
+    pub fn when(mut self, attribute: &str, substring: &str) -> Self {
+        self.conditions.push((attribute.to_ascii_lowercase(), substring.to_ascii_lowercase(), true));
+        self
+    }
+
+    /// Only matches tags whose attribute is missing or does not contain the substring
+    pub fn unless(mut self, attribute: &str, substring: &str) -> Self {
+        self.conditions.push((attribute.to_ascii_lowercase(), substring.to_ascii_lowercase(), false));
+        self
+    }
+
+    /// Removes the element & its content
+    pub fn remove(mut self) -> Self {
+        self.actions.push(RewriteAction::Remove);
+        self
+    }
+
+    /// Renames the element, void elements getting a closing tag (like <amp-img ...></amp-img>)
+    pub fn rename(mut self, tag: &str) -> Self {
+        self.actions.push(RewriteAction::Rename(tag.to_ascii_lowercase()));
+        self
+    }
+
+    /// Sets the attribute, an empty value being written as the attribute name only
+    pub fn set_attribute(mut self, name: &str, value: &str) -> Self {
+        self.actions.push(RewriteAction::SetAttribute(name.to_ascii_lowercase(), value.to_string()));
+        self
+    }
+
+    /// Removes the attribute, a name ending with * removing the attributes starting with the rest (like "on*")
+    pub fn remove_attribute(mut self, name: &str) -> Self {
+        self.actions.push(RewriteAction::RemoveAttribute(name.to_ascii_lowercase()));
+        self
+    }
+
+    /// Rewrites the URLs of the href & src attributes
+    pub fn rewrite_links(mut self, links: LinkRules) -> Self {
+        self.actions.push(RewriteAction::RewriteLinks(links));
+        self
+    }
+
+    /// Adds the missing image attributes of the rules (meant for <img> tags)
+    pub fn rewrite_images(mut self, images: ImageRules) -> Self {
+        self.actions.push(RewriteAction::RewriteImages(images));
+        self
+    }
+
+    fn matches(&self, tag_name: &str, attributes: &[(String, String)]) -> bool {
+        (self.tag == "*" || self.tag == tag_name) && self.conditions.iter().all(|(name, substring, must_contain)| {
+            let contains = attributes.iter().any(|(attribute, value)| attribute == name && value.to_ascii_lowercase().contains(substring.as_str()));
+            contains == *must_contain
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum State {
+    Markup,
+    RawText { closing: Vec<u8>, is_removed: bool }, // Until this closing tag start (like </script) or COMMENT_END
+    TagEnd { is_removed: bool }, // Rest of a closing tag matched by RawText, until its >
+}
+
+/// Writer applying the rules to the HTML written to it, in order. Typically wraps the output of replace_with_data_cache
+#[derive(Debug)]
+pub struct HtmlRewriter<W: Write> {
+    inner: W,
+    rules: Vec<RewriteRule>,
+    state: State,
+    pending_tag: Option<Vec<u8>>, // Start of a tag not closed in the previous chunk, after its <
+    matched: usize, // Bytes of the closing sequence of RawText matched so far
+    removed: Option<(String, usize)>, // Removed element whose content is skipped & the depth of nested elements of its name
+    renamed: Vec<(String, Option<String>)>, // Open elements having rename rules, with their new name if renamed
+}
+
+impl<W: Write> HtmlRewriter<W> {
+    pub fn new(inner: W, rules: Vec<RewriteRule>) -> Self {
+        Self { inner, rules, state: State::Markup, pending_tag: None, matched: 0, removed: None, renamed: Vec::new() }
+    }
+
+    /// Writes the start of a tag left unclosed by the last chunk, returning the inner writer
+    pub fn finish(mut self) -> io::Result<W> {
+        if let Some(tag) = self.pending_tag.take()
+            && self.removed.is_none() {
+            self.inner.write_all(b"<")?;
+            self.inner.write_all(&tag)?;
+        }
+        Ok(self.inner)
+    }
+
+    fn write_kept(&mut self, bytes: &[u8], is_removed: bool) -> io::Result<()> {
+        if is_removed { Ok(()) } else { self.inner.write_all(bytes) }
+    }
+
+    fn process(&mut self, chunk: &[u8]) -> io::Result<()> {
+        let mut idx = 0;
+        while idx < chunk.len() {
+            match &self.state {
+                State::RawText { closing, is_removed } => {
+                    let (closing, is_removed) = (closing.clone(), *is_removed);
+                    let is_comment = closing == COMMENT_END;
+                    let start = idx;
+                    while idx < chunk.len() && self.matched < closing.len() {
+                        let byte = chunk[idx].to_ascii_lowercase();
+                        self.matched = if byte == closing[self.matched] {
+                            self.matched + 1
+                        } else if is_comment && byte == b'-' {
+                            2 // Like --->
+                        } else {
+                            usize::from(byte == closing[0])
+                        };
+                        idx += 1;
+                    }
+                    self.write_kept(&chunk[start..idx], is_removed)?;
+                    if self.matched == closing.len() {
+                        self.matched = 0;
+                        self.state = if is_comment { State::Markup } else { State::TagEnd { is_removed } };
+                    }
+                },
+                State::TagEnd { is_removed } => {
+                    let is_removed = *is_removed;
+                    let Some(offset) = chunk[idx..].iter().position(|b| *b == b'>') else {
+                        return self.write_kept(&chunk[idx..], is_removed);
+                    };
+                    self.write_kept(&chunk[idx..=idx + offset], is_removed)?;
+                    idx += offset + 1;
+                    self.state = State::Markup;
+                },
+                State::Markup => match self.pending_tag.take() {
+                    Some(mut tag) => {
+                        let Some(tag_len) = tag_end(&tag, &chunk[idx..]) else {
+                            tag.extend_from_slice(&chunk[idx..]);
+                            if tag.len() <= MAX_PENDING_TAG_LEN {
+                                self.pending_tag = Some(tag);
+                            } else if self.removed.is_none() {
+                                self.inner.write_all(b"<")?;
+                                self.inner.write_all(&tag)?;
+                            }
+                            return Ok(());
+                        };
+                        tag.extend_from_slice(&chunk[idx..idx + tag_len]);
+                        idx += tag_len + 1;
+                        self.on_tag(&tag)?;
+                    },
+                    None => {
+                        let is_removed = self.removed.is_some();
+                        let Some(offset) = chunk[idx..].iter().position(|b| *b == b'<') else {
+                            return self.write_kept(&chunk[idx..], is_removed);
+                        };
+                        self.write_kept(&chunk[idx..idx + offset], is_removed)?;
+                        self.pending_tag = Some(Vec::new());
+                        idx += offset + 1;
+                    },
+                },
+            }
+        }
+        Ok(())
+    }
+
+    /// Handles a tag (between < and >)
+    fn on_tag(&mut self, tag: &[u8]) -> io::Result<()> {
+        let is_removed = self.removed.is_some();
+        if tag.starts_with(b"!--") {
+            self.write_kept(b"<", is_removed)?;
+            self.write_kept(tag, is_removed)?;
+            self.write_kept(b">", is_removed)?;
+            if tag.len() < 5 || !tag.ends_with(b"--") {
+                self.state = State::RawText { closing: COMMENT_END.to_vec(), is_removed };
+            }
+            return Ok(());
+        }
+        let is_closing = tag.first() == Some(&b'/');
+        let name_start = usize::from(is_closing);
+        let name_len = tag[name_start..].iter().take_while(|b| b.is_ascii_alphanumeric() || **b == b'-').count();
+        let name = String::from_utf8_lossy(&tag[name_start..name_start + name_len]).to_ascii_lowercase();
+        let is_void = VOID_ELEMENTS.contains(&name.as_str()) || tag.ends_with(b"/");
+        let raw_closing = (!is_closing && !is_void && RAW_TEXT_ELEMENTS.contains(&name.as_str())).then(|| format!("</{name}").into_bytes());
+
+        if let Some((removed_name, depth)) = &mut self.removed {
+            if *removed_name == name && !is_void {
+                if is_closing {
+                    *depth -= 1;
+                } else {
+                    *depth += 1;
+                }
+            }
+            if *depth == 0 {
+                self.removed = None;
+            }
+            if let Some(closing) = raw_closing {
+                self.state = State::RawText { closing, is_removed: true };
+            }
+            return Ok(());
+        }
+        if name.is_empty() {
+            return self.write_tag(tag);
+        }
+        if is_closing {
+            if let Some(idx) = self.renamed.iter().rposition(|(original, _)| *original == name) {
+                let (_, renamed) = self.renamed.remove(idx);
+                if let Some(renamed) = renamed {
+                    return write!(self.inner, "</{renamed}{}>", String::from_utf8_lossy(&tag[name_start + name_len..]));
+                }
+            }
+            return self.write_tag(tag);
+        }
+
+        let attributes_bytes = &tag[name_len..];
+        let has_rules = self.rules.iter().any(|rule| rule.tag == "*" || rule.tag == name);
+        let mut attributes = if has_rules { parse_attributes(attributes_bytes) } else { Vec::new() };
+        let mut rename = None;
+        let mut is_rewritten = false;
+        let is_renamable = self.rules.iter().any(|rule| rule.tag == name && rule.actions.iter().any(|action| matches!(action, RewriteAction::Rename(_))));
+        for rule in &self.rules {
+            if !rule.matches(&name, &attributes) {
+                continue;
+            }
+            for action in &rule.actions {
+                match action {
+                    RewriteAction::Remove => {
+                        if !is_void {
+                            match raw_closing {
+                                Some(closing) => self.state = State::RawText { closing, is_removed: true },
+                                None => self.removed = Some((name, 1)),
+                            }
+                        }
+                        return Ok(());
+                    },
+                    RewriteAction::Rename(renamed) => rename = Some(renamed.clone()),
+                    RewriteAction::SetAttribute(attribute, value) => {
+                        match attributes.iter_mut().find(|(name, _)| name == attribute) {
+                            Some((_, previous)) => *previous = value.clone(),
+                            None => attributes.push((attribute.clone(), value.clone())),
+                        }
+                        is_rewritten = true;
+                    },
+                    RewriteAction::RemoveAttribute(attribute) => {
+                        let len = attributes.len();
+                        match attribute.strip_suffix('*') {
+                            Some(prefix) => attributes.retain(|(name, _)| !name.starts_with(prefix)),
+                            None => attributes.retain(|(name, _)| name != attribute),
+                        }
+                        is_rewritten |= attributes.len() != len;
+                    },
+                    RewriteAction::RewriteLinks(links) => {
+                        let is_link = matches!(name.as_str(), "a" | "area");
+                        for (attribute, value) in attributes.iter_mut().filter(|(attribute, _)| attribute == "href" || attribute == "src") {
+                            if let Some(rewritten) = links.rewrite(value, is_link && attribute == "href") {
+                                *value = rewritten;
+                                is_rewritten = true;
+                            }
+                        }
+                    },
+                    RewriteAction::RewriteImages(images) => is_rewritten |= images.apply(&mut attributes),
+                }
+            }
+        }
+        if is_renamable && !is_void {
+            self.renamed.push((name.clone(), rename.clone()));
+        }
+        if let Some(closing) = raw_closing {
+            self.state = State::RawText { closing, is_removed: false };
+        }
+        if rename.is_none() && !is_rewritten {
+            return self.write_tag(tag);
+        }
+        let tag_name = rename.as_deref().unwrap_or(&name);
+        write!(self.inner, "<{tag_name}")?;
+        for (attribute, value) in &attributes {
+            match value.is_empty() {
+                true => write!(self.inner, " {attribute}")?,
+                false => write!(self.inner, " {attribute}=\"{}\"", value.replace('"', "&quot;"))?,
+            }
+        }
+        self.inner.write_all(b">")?;
+        if is_void && rename.is_some() {
+            write!(self.inner, "</{tag_name}>")?;
+        }
+        Ok(())
+    }
+
+    fn write_tag(&mut self, tag: &[u8]) -> io::Result<()> {
+        self.inner.write_all(b"<")?;
+        self.inner.write_all(tag)?;
+        self.inner.write_all(b">")
+    }
+}
+
+/// Offset in chunk of the > ending the tag whose start is pending, ignoring the ones of quoted attribute values (comments
+/// having no attributes)
+fn tag_end(pending: &[u8], chunk: &[u8]) -> Option<usize> {
+    if pending.first().or(chunk.first()) == Some(&b'!') {
+        return chunk.iter().position(|b| *b == b'>');
+    }
+    let mut quote = None;
+    let mut is_value_start = false; // After an =, where a quote starts a quoted value
+    for (idx, byte) in pending.iter().chain(chunk).copied().enumerate() {
+        match quote {
+            Some(end) => if byte == end {
+                quote = None;
+                is_value_start = false;
+            },
+            None => match byte {
+                b'>' => return Some(idx - pending.len()), // The pending start of the tag has none outside of quotes
+                b'"' | b'\'' if is_value_start => quote = Some(byte),
+                b'=' => is_value_start = true,
+                _ => is_value_start &= byte.is_ascii_whitespace(),
+            },
+        }
+    }
+    None
+}
+
+impl<W: Write> Write for HtmlRewriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.process(buf)?;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
@@ -0,0 +1,314 @@
+//! Partial hydration patches: the difference between two renders of a template (a cacheable shell, for example anonymous,
+//! and a personalized render), as a short list of element changes the client applies to the shell.
+
+use std::ops::Range;
+
+use serde_json::{Value, json};
+
+#[cfg(feature = "replace-engine")]
+use crate::DataCache;
+use crate::error::JsonDataCacheError;
+
+/// Elements without content nor end tag
+const VOID_ELEMENTS: &[&str] = &["area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "source", "track", "wbr"];
+
+/// Elements whose content is not parsed as HTML
+const RAW_TEXT_ELEMENTS: &[&str] = &["script", "style", "textarea", "title"];
+
+/// A change of an element, selected by a CSS selector valid in the shell. Selectors of top level elements other than html start with
+/// :scope, for fragments to be patched through container.querySelector
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PatchOp {
+    SetAttribute { selector: String, name: String, value: String },
+    RemoveAttribute { selector: String, name: String },
+    /// Replaces the content of an element holding only text
+    SetText { selector: String, text: String },
+    /// Replaces the content of an element whose children differ otherwise
+    SetHtml { selector: String, html: String },
+}
+
+impl PatchOp {
+    /// Compact JSON encoding : ["attr", selector, name, value], ["rmattr", selector, name], ["text", selector, text] & ["html", selector, html]
+    pub fn to_json(&self) -> Value {
+        match self {
+            PatchOp::SetAttribute { selector, name, value } => json!(["attr", selector, name, value]),
+            PatchOp::RemoveAttribute { selector, name } => json!(["rmattr", selector, name]),
+            PatchOp::SetText { selector, text } => json!(["text", selector, text]),
+            PatchOp::SetHtml { selector, html } => json!(["html", selector, html]),
+        }
+    }
+}
+
+/// JSON array of the encoded operations (see PatchOp::to_json)
+pub fn patch_to_json(patch: &[PatchOp]) -> Value {
+    Value::Array(patch.iter().map(PatchOp::to_json).collect())
+}
+
+#[derive(Debug)]
+enum Node {
+    Element {
+        name: String, // Lowercase
+        attributes: Vec<(String, String)>,
+        children: Vec<Node>,
+        content: Range<usize> // Between the start & end tags in the source
+    },
+    Text(Range<usize>),
+    Other(Range<usize>) // Comments & doctypes
+}
+
+/// Lenient tree builder : an end tag other than the one of the current element closes it, top level end tags are ignored
+struct HtmlParser<'a> {
+    html: &'a [u8],
+    idx: usize
+}
+
+impl HtmlParser<'_> {
+    fn parse(html: &[u8]) -> Vec<Node> {
+        let mut parser = HtmlParser { html, idx: 0 };
+        let (nodes, _) = parser.parse_children(None);
+        nodes
+    }
+
+    /// Parses nodes until the end tag of parent (or of one of its ancestors, left unconsumed), returning them along with their end
+    fn parse_children(&mut self, parent: Option<&str>) -> (Vec<Node>, usize) {
+        let mut nodes = Vec::new();
+        while self.idx < self.html.len() {
+            let start = self.idx;
+            if self.html[start] != b'<' {
+                let end = self.find(b"<", start).unwrap_or(self.html.len());
+                nodes.push(Node::Text(start..end));
+                self.idx = end;
+                continue;
+            }
+            let rest = &self.html[start + 1..];
+            if rest.starts_with(b"!--") {
+                let end = self.find(b"-->", start).map(|end| end + 3).unwrap_or(self.html.len());
+                nodes.push(Node::Other(start..end));
+                self.idx = end;
+            } else if rest.starts_with(b"!") || rest.starts_with(b"?") {
+                let end = self.find(b">", start).map(|end| end + 1).unwrap_or(self.html.len());
+                nodes.push(Node::Other(start..end));
+                self.idx = end;
+            } else if rest.starts_with(b"/") {
+                let name_len = rest[1..].iter().take_while(|b| b.is_ascii_alphanumeric()).count();
+                let name = String::from_utf8_lossy(&rest[1..1 + name_len]).to_ascii_lowercase();
+                let end = self.find(b">", start).map(|end| end + 1).unwrap_or(self.html.len());
+                if parent == Some(name.as_str()) {
+                    self.idx = end;
+                    return (nodes, start);
+                }
+                // Most likely the end tag of an ancestor, left for it
+                if parent.is_some() {
+                    return (nodes, start);
+                }
+                self.idx = end;
+            } else if rest.first().is_some_and(u8::is_ascii_alphabetic) {
+                nodes.push(self.parse_element(start));
+            } else {
+                let end = self.find(b"<", start + 1).unwrap_or(self.html.len());
+                nodes.push(Node::Text(start..end));
+                self.idx = end;
+            }
+        }
+        (nodes, self.html.len())
+    }
+
+    fn parse_element(&mut self, start: usize) -> Node {
+        let name_len = self.html[start + 1..].iter().take_while(|b| b.is_ascii_alphanumeric() || **b == b'-').count();
+        let name = String::from_utf8_lossy(&self.html[start + 1..start + 1 + name_len]).to_ascii_lowercase();
+        let (attributes, tag_end) = parse_attributes(self.html, start + 1 + name_len);
+        self.idx = tag_end;
+        let is_self_closing = self.html[..tag_end].ends_with(b"/>");
+        if is_self_closing || VOID_ELEMENTS.contains(&name.as_str()) {
+            return Node::Element { name, attributes, children: Vec::new(), content: tag_end..tag_end };
+        }
+        if RAW_TEXT_ELEMENTS.contains(&name.as_str()) {
+            let end_tag = format!("</{name}");
+            let content_end = self.find_ignore_case(end_tag.as_bytes(), tag_end).unwrap_or(self.html.len());
+            self.idx = self.find(b">", content_end).map(|end| end + 1).unwrap_or(self.html.len());
+            let children = if content_end > tag_end { Vec::from([Node::Text(tag_end..content_end)]) } else { Vec::new() };
+            return Node::Element { name, attributes, children, content: tag_end..content_end };
+        }
+        let (children, content_end) = self.parse_children(Some(&name));
+        Node::Element { name, attributes, children, content: tag_end..content_end }
+    }
+
+    fn find(&self, needle: &[u8], from: usize) -> Option<usize> {
+        self.html[from..].windows(needle.len()).position(|w| w == needle).map(|pos| from + pos)
+    }
+
+    fn find_ignore_case(&self, needle: &[u8], from: usize) -> Option<usize> {
+        self.html[from..].windows(needle.len()).position(|w| w.eq_ignore_ascii_case(needle)).map(|pos| from + pos)
+    }
+}
+
+/// Attributes of a tag starting at idx (after its name), along with the index after the tag
+fn parse_attributes(html: &[u8], mut idx: usize) -> (Vec<(String, String)>, usize) {
+    let mut attributes = Vec::new();
+    loop {
+        while idx < html.len() && (html[idx].is_ascii_whitespace() || html[idx] == b'/') {
+            idx += 1;
+        }
+        if idx >= html.len() {
+            return (attributes, idx);
+        }
+        if html[idx] == b'>' {
+            return (attributes, idx + 1);
+        }
+        let name_start = idx;
+        while idx < html.len() && !html[idx].is_ascii_whitespace() && !matches!(html[idx], b'=' | b'>' | b'/') {
+            idx += 1;
+        }
+        let name = String::from_utf8_lossy(&html[name_start..idx]).to_ascii_lowercase();
+        let mut value = String::new();
+        if idx < html.len() && html[idx] == b'=' {
+            idx += 1;
+            let value_range = match html.get(idx) {
+                Some(quote @ (b'"' | b'\'')) => {
+                    let value_len = html[idx + 1..].iter().position(|b| b == quote).unwrap_or(html.len() - idx - 1);
+                    let range = idx + 1..idx + 1 + value_len;
+                    idx = (range.end + 1).min(html.len());
+                    range
+                },
+                _ => {
+                    let value_len = html[idx..].iter().take_while(|b| !b.is_ascii_whitespace() && **b != b'>').count();
+                    let range = idx..idx + value_len;
+                    idx = range.end;
+                    range
+                },
+            };
+            value = String::from_utf8_lossy(&html[value_range]).into_owned();
+        }
+        if !name.is_empty() {
+            attributes.push((name, value));
+        }
+    }
+}
+
+/// Compares the shell with the personalized HTML, returning the operations turning the first into the second
+/// Elements are matched by position : differing element names or counts of children replace the content of their parent
+/// Attribute values are kept as written in the source (escaped)
+pub fn diff_html(shell: &[u8], personalized: &[u8]) -> Result<Vec<PatchOp>, JsonDataCacheError> {
+    let shell_nodes = HtmlParser::parse(shell);
+    let personalized_nodes = HtmlParser::parse(personalized);
+    let mut patch = Vec::new();
+    let mut differ = HtmlDiffer { shell, personalized, patch: &mut patch };
+    if !differ.children_match(&shell_nodes, &personalized_nodes) {
+        return Err("The top level elements of the two renders differ".into());
+    }
+    differ.diff_children("", &shell_nodes, &personalized_nodes);
+    Ok(patch)
+}
+
+struct HtmlDiffer<'a> {
+    shell: &'a [u8],
+    personalized: &'a [u8],
+    patch: &'a mut Vec<PatchOp>
+}
+
+impl HtmlDiffer<'_> {
+    /// Same kinds of nodes, elements having the same names
+    fn children_match(&self, shell_nodes: &[Node], personalized_nodes: &[Node]) -> bool {
+        shell_nodes.len() == personalized_nodes.len() && shell_nodes.iter().zip(personalized_nodes).all(|pair| match pair {
+            (Node::Element { name, .. }, Node::Element { name: personalized_name, .. }) => name == personalized_name,
+            (Node::Text(_), Node::Text(_)) => true,
+            (Node::Other(shell_range), Node::Other(personalized_range)) => {
+                self.shell[shell_range.clone()] == self.personalized[personalized_range.clone()]
+            },
+            _ => false,
+        })
+    }
+
+    fn diff_children(&mut self, parent_selector: &str, shell_nodes: &[Node], personalized_nodes: &[Node]) {
+        let mut element_idx = 0;
+        for (shell_node, personalized_node) in shell_nodes.iter().zip(personalized_nodes) {
+            let (
+                Node::Element { name, attributes, children, content },
+                Node::Element { attributes: personalized_attributes, children: personalized_children, content: personalized_content, .. }
+            ) = (shell_node, personalized_node) else {
+                continue; // Text differences are handled with their parent
+            };
+            element_idx += 1;
+            let selector = match attributes.iter().find(|(attribute, value)| attribute == "id" && is_simple_id(value)) {
+                Some((_, id)) => format!("#{id}"),
+                None if parent_selector.is_empty() && name == "html" => name.clone(),
+                None if parent_selector.is_empty() => format!(":scope > {name}:nth-child({element_idx})"),
+                None => format!("{parent_selector} > {name}:nth-child({element_idx})"),
+            };
+            self.diff_attributes(&selector, attributes, personalized_attributes);
+
+            let shell_content = &self.shell[content.clone()];
+            let personalized_content_bytes = &self.personalized[personalized_content.clone()];
+            if shell_content == personalized_content_bytes {
+                continue;
+            }
+            let is_text_only = |nodes: &[Node]| nodes.iter().all(|node| matches!(node, Node::Text(_)));
+            if is_text_only(children) && is_text_only(personalized_children) {
+                self.patch.push(PatchOp::SetText {
+                    selector,
+                    text: String::from_utf8_lossy(personalized_content_bytes).into_owned()
+                });
+            } else if !self.children_match(children, personalized_children) || self.texts_differ(children, personalized_children) {
+                self.patch.push(PatchOp::SetHtml {
+                    selector,
+                    html: String::from_utf8_lossy(personalized_content_bytes).into_owned()
+                });
+            } else {
+                self.diff_children(&selector, children, personalized_children);
+            }
+        }
+    }
+
+    /// Text nodes mixed with elements cannot be selected, so that their changes replace the whole content
+    fn texts_differ(&self, shell_nodes: &[Node], personalized_nodes: &[Node]) -> bool {
+        shell_nodes.iter().zip(personalized_nodes).any(|pair| match pair {
+            (Node::Text(shell_range), Node::Text(personalized_range)) => {
+                self.shell[shell_range.clone()] != self.personalized[personalized_range.clone()]
+            },
+            _ => false,
+        })
+    }
+
+    fn diff_attributes(&mut self, selector: &str, attributes: &[(String, String)], personalized_attributes: &[(String, String)]) {
+        for (name, value) in personalized_attributes {
+            if !attributes.iter().any(|(shell_name, shell_value)| shell_name == name && shell_value == value) {
+                self.patch.push(PatchOp::SetAttribute {
+                    selector: selector.to_string(),
+                    name: name.clone(),
+                    value: value.clone()
+                });
+            }
+        }
+        for (name, _) in attributes {
+            if !personalized_attributes.iter().any(|(personalized_name, _)| personalized_name == name) {
+                self.patch.push(PatchOp::RemoveAttribute {
+                    selector: selector.to_string(),
+                    name: name.clone()
+                });
+            }
+        }
+    }
+}
+
+/// Ids usable as #id selectors without escaping
+fn is_simple_id(id: &str) -> bool {
+    id.chars().next().is_some_and(|c| c.is_ascii_alphabetic()) && id.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
+
+/// Shell render & the patch personalizing it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HydrationPatch {
+    pub shell: Vec<u8>,
+    pub patch: Vec<PatchOp>
+}
+
+/// Renders the template (see replace_with_data_cache) with the shell cache & the personalized cache, and compares the results
+#[cfg(feature = "replace-engine")]
+pub fn render_patch(template: &[u8], shell_cache: &mut DataCache, personalized_cache: &mut DataCache) -> Result<HydrationPatch, JsonDataCacheError> {
+    let mut shell = Vec::with_capacity(template.len());
+    shell_cache.replace_with_data_cache(template, &mut shell)?;
+    let mut personalized = Vec::with_capacity(template.len());
+    personalized_cache.replace_with_data_cache(template, &mut personalized)?;
+    let patch = diff_html(&shell, &personalized)?;
+    Ok(HydrationPatch { shell, patch })
+}
@@ -0,0 +1,93 @@
+//! Image attributes: ImageRules add loading="lazy", decoding="async" & the intrinsic dimensions of images to their <img>
+//! tags while the HTML streams through an HtmlRewriter, so that pages reserve the space of images (no layout shift) and
+//! defer offscreen ones without changing the origin's templates. Dimensions come from an image metadata map of the cache
+//! keyed by URL, like {"/img/top.jpg": {"width": 1200, "height": 630}} (see DataCache::image_rules).
+
+use std::collections::HashMap;
+
+use serde_json::Value;
+
+use crate::{DataCache, error::JsonDataCacheError, html_rewriter::RewriteRule};
+
+/// Default path of the image metadata map
+pub const DEFAULT_IMAGE_METADATA_PATH: &str = "image_metadata";
+
+/// Attributes added to <img> tags, never replacing the ones they already have
+/// Example: data_cache.image_rules(DEFAULT_IMAGE_METADATA_PATH)?.lazy_loading().async_decoding().rewrite_rule()
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ImageRules {
+    is_lazy_loading: bool,
+    is_async_decoding: bool,
+    dimensions: HashMap<String, (u64, u64)>, // Width & height by URL
+}
+
+impl ImageRules {
+    /// Adds loading="lazy", except to images with fetchpriority="high" (like the largest one of the first screen)
+    pub fn lazy_loading(mut self) -> Self {
+        self.is_lazy_loading = true;
+        self
+    }
+
+    /// Adds decoding="async"
+    pub fn async_decoding(mut self) -> Self {
+        self.is_async_decoding = true;
+        self
+    }
+
+    /// Sets the dimensions of the image at url, added to the tags having neither width nor height
+    pub fn dimension(mut self, url: &str, width: u64, height: u64) -> Self {
+        self.dimensions.insert(url.to_string(), (width, height));
+        self
+    }
+
+    /// The rewrite rule applying these rules to <img> tags, for an HtmlRewriter or a TransformProfile
+    pub fn rewrite_rule(&self) -> RewriteRule {
+        RewriteRule::new("img").rewrite_images(self.clone())
+    }
+
+    /// Dimensions of the image at url, looked up without its query & fragment if not found as is
+    fn dimensions_of(&self, url: &str) -> Option<(u64, u64)> {
+        let url = url.trim();
+        self.dimensions.get(url).or_else(|| self.dimensions.get(url.split(['?', '#']).next()?)).copied()
+    }
+
+    /// Adds the missing attributes of an <img> tag, returning true if any was
+    pub(crate) fn apply(&self, attributes: &mut Vec<(String, String)>) -> bool {
+        let attribute = |name: &str| attributes.iter().find(|(attribute, _)| attribute == name).map(|(_, value)| value.as_str());
+        let mut added = Vec::new();
+        if self.is_lazy_loading && attribute("loading").is_none() && !attribute("fetchpriority").is_some_and(|p| p.eq_ignore_ascii_case("high")) {
+            added.push(("loading".to_string(), "lazy".to_string()));
+        }
+        if self.is_async_decoding && attribute("decoding").is_none() {
+            added.push(("decoding".to_string(), "async".to_string()));
+        }
+        if attribute("width").is_none() && attribute("height").is_none()
+            && let Some((width, height)) = attribute("src").and_then(|src| self.dimensions_of(src)) {
+            added.push(("width".to_string(), width.to_string()));
+            added.push(("height".to_string(), height.to_string()));
+        }
+        let is_added = !added.is_empty();
+        attributes.append(&mut added);
+        is_added
+    }
+}
+
+impl DataCache {
+    /// Rules holding the dimensions of the metadata map at map_path, an object of {"width", "height"} objects keyed by URL
+    /// (which may contain dots). Without map, the rules have no dimensions
+    pub fn image_rules(&self, map_path: &str) -> Result<ImageRules, JsonDataCacheError> {
+        let Some(map) = self.get(map_path) else {
+            return Ok(ImageRules::default());
+        };
+        let map = map.as_object().ok_or(format!("Image metadata map {map_path} is not an object"))?;
+        let mut rules = ImageRules::default();
+        for (url, metadata) in map {
+            let dimension = |name: &str| metadata.get(name).and_then(Value::as_u64);
+            match (dimension("width"), dimension("height")) {
+                (Some(width), Some(height)) => rules = rules.dimension(url, width, height),
+                _ => return Err(format!("Image metadata of {url} in {map_path} has no width & height").into()),
+            }
+        }
+        Ok(rules)
+    }
+}
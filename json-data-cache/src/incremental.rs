@@ -0,0 +1,155 @@
+//! Incremental re-renders for long lived connections (SSE dashboards) sending a page again on each update: a template compiled
+//! once into literal & placeholder regions is rendered along with the versions of the keys of its placeholders (see version),
+//! so that rerender_dirty only renders again the placeholders whose keys have been modified since, as patches of the previous output.
+//! Regions are rendered separately, which gives the output of replace_with_data_cache as long as no key contains '{' or '}'.
+//! Renderer & option changes do not bump versions: call render_compiled again after them.
+
+use std::ops::Range;
+
+use crate::{DataCache, error::JsonDataCacheError};
+
+/// A literal part of the template, or a placeholder & its key
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct TemplateRegion {
+    template: Range<usize>, // The placeholder includes a preceding backslash, see DataCacheOptions::escape_placeholders
+    key: Option<String>
+}
+
+/// A template split into regions by compile, for render_compiled & rerender_dirty
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompiledTemplate {
+    template: Vec<u8>,
+    regions: Vec<TemplateRegion>
+}
+
+impl CompiledTemplate {
+    /// Splits the template on its {$key} & {$$key} placeholders, whether their keys have a value or not yet
+    pub fn compile(template: Vec<u8>) -> Self {
+        let mut regions = Vec::new();
+        let mut literal_start = 0;
+        let mut pos = 0;
+        while let Some(offset) = template[pos..].windows(2).position(|window| window == b"{$") {
+            let start = pos + offset;
+            let key_start = if template.get(start + 2) == Some(&b'$') { start + 3 } else { start + 2 };
+            let Some(key_len) = template[key_start..].iter().position(|byte| *byte == b'}') else {
+                break;
+            };
+            let key = &template[key_start..key_start + key_len];
+            if key.is_empty() || key.contains(&b'{') {
+                pos = start + 1;
+                continue;
+            }
+            let region_start = if start > literal_start && template[start - 1] == b'\\' { start - 1 } else { start };
+            if region_start > literal_start {
+                regions.push(TemplateRegion { template: literal_start..region_start, key: None });
+            }
+            pos = key_start + key_len + 1;
+            regions.push(TemplateRegion { template: region_start..pos, key: Some(String::from_utf8_lossy(key).into_owned()) });
+            literal_start = pos;
+        }
+        if literal_start < template.len() {
+            regions.push(TemplateRegion { template: literal_start..template.len(), key: None });
+        }
+        Self { template, regions }
+    }
+
+    /// Keys of the placeholders, in template order
+    pub fn keys(&self) -> impl Iterator<Item = &str> {
+        self.regions.iter().filter_map(|region| region.key.as_deref())
+    }
+}
+
+/// Output of a compiled template, with the output range of each region & the versions of the keys it was rendered with
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RenderedTemplate {
+    output: Vec<u8>,
+    regions: Vec<Range<usize>>,
+    versions: Vec<u64> // 0 for literal regions
+}
+
+impl RenderedTemplate {
+    pub fn output(&self) -> &[u8] {
+        &self.output
+    }
+
+    pub fn into_output(self) -> Vec<u8> {
+        self.output
+    }
+}
+
+/// Replacement of a range of the previous output
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutputPatch {
+    pub range: Range<usize>,
+    pub bytes: Vec<u8>
+}
+
+impl DataCache {
+    fn region_version(&self, region: &TemplateRegion) -> u64 {
+        region.key.as_deref().map(|key| self.version(&self.refs.resolve(key))).unwrap_or_default()
+    }
+
+    /// Renders the whole compiled template, like replace_with_data_cache
+    pub fn render_compiled(&mut self, compiled: &CompiledTemplate) -> Result<RenderedTemplate, JsonDataCacheError> {
+        self.prepare()?;
+        let mut output = Vec::with_capacity(compiled.template.len());
+        let mut regions = Vec::with_capacity(compiled.regions.len());
+        let mut versions = Vec::with_capacity(compiled.regions.len());
+        for region in &compiled.regions {
+            let start = output.len();
+            output.extend(self.replace_prepared(&compiled.template[region.template.clone()])?);
+            regions.push(start..output.len());
+            versions.push(self.region_version(region));
+        }
+        Ok(RenderedTemplate { output, regions, versions })
+    }
+
+    /// Renders again the placeholders whose keys have been modified since previous was rendered (see version), updating it.
+    /// Returns the patches turning the previous output into the new one, in output order, with ranges of the previous output
+    /// (apply them from the last one, or shift the following ranges by the length difference). Unchanged values give no patch
+    pub fn rerender_dirty(&mut self, compiled: &CompiledTemplate, previous: &mut RenderedTemplate) -> Result<Vec<OutputPatch>, JsonDataCacheError> {
+        if previous.regions.len() != compiled.regions.len() {
+            return Err("Rendered template does not come from this compiled template".into());
+        }
+        self.prepare()?;
+        let mut patches = Vec::new();
+        let mut patched_regions = Vec::new();
+        for (region_idx, region) in compiled.regions.iter().enumerate() {
+            let current_version = self.region_version(region);
+            if current_version == previous.versions[region_idx] {
+                continue;
+            }
+            previous.versions[region_idx] = current_version;
+            let bytes = self.replace_prepared(&compiled.template[region.template.clone()])?;
+            let range = previous.regions[region_idx].clone();
+            if bytes != previous.output[range.clone()] {
+                patches.push(OutputPatch { range, bytes });
+                patched_regions.push(region_idx);
+            }
+        }
+        if patches.is_empty() {
+            return Ok(patches);
+        }
+
+        // Applies the patches to the previous output, shifting the ranges of the regions following each of them
+        let mut output = Vec::with_capacity(previous.output.len());
+        let mut pos = 0;
+        for patch in &patches {
+            output.extend_from_slice(&previous.output[pos..patch.range.start]);
+            output.extend_from_slice(&patch.bytes);
+            pos = patch.range.end;
+        }
+        output.extend_from_slice(&previous.output[pos..]);
+        let mut shift: isize = 0;
+        let mut patched = patched_regions.iter().zip(&patches).peekable();
+        for (region_idx, range) in previous.regions.iter_mut().enumerate() {
+            let start = range.start.wrapping_add_signed(shift);
+            if let Some((_, patch)) = patched.next_if(|(patched_idx, _)| **patched_idx == region_idx) {
+                shift += patch.bytes.len() as isize - patch.range.len() as isize;
+            }
+            *range = start..range.end.wrapping_add_signed(shift);
+        }
+        previous.output = output;
+        Ok(patches)
+    }
+}
@@ -0,0 +1,94 @@
+use std::fmt;
+
+use serde_json::Value;
+
+use crate::DataCache;
+
+/// Notified of each invalidated path, for example to fetch it again or to propagate the eviction to a shared store
+pub trait InvalidationListener {
+    fn on_invalidate(&mut self, path: &str);
+}
+
+impl<F> InvalidationListener for F
+where
+    F: FnMut(&str),
+{
+    fn on_invalidate(&mut self, path: &str) {
+        self(path)
+    }
+}
+
+/// Listeners added by add_invalidation_listener
+#[derive(Default)]
+pub(crate) struct InvalidationListeners(Vec<Box<dyn InvalidationListener>>);
+
+impl fmt::Debug for InvalidationListeners {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "InvalidationListeners({})", self.0.len())
+    }
+}
+
+impl DataCache {
+    pub fn add_invalidation_listener<L>(&mut self, listener: L)
+    where
+        L: InvalidationListener + 'static,
+    {
+        self.invalidation_listeners.0.push(Box::new(listener));
+    }
+
+    /// Evicts the subtree at path (everything for an empty path) along with its freshness metadata, CRDT states and raw values,
+    /// so that a content update only discards the affected data. Serialized data and fragments depending on the path
+    /// are rebuilt on next use, then listeners are notified. Returns false if there was nothing to evict
+    pub fn invalidate(&mut self, path: &str) -> bool {
+        let removed = if path.is_empty() {
+            let was_empty = self.root.as_object().is_some_and(|root| root.is_empty());
+            self.root = Value::Object(serde_json::Map::new());
+            #[cfg(feature = "serializer")]
+            {
+                self.string_values.is_built = false;
+            }
+            !was_empty
+        } else {
+            self.remove_value(path).is_some()
+        };
+        #[cfg(feature = "replace-engine")]
+        let removed = {
+            let raw_count = self.raw_values.len();
+            let nested_prefix = format!("{path}.");
+            self.raw_values.retain(|key, _| !path.is_empty() && key != path && !key.starts_with(&nested_prefix));
+            removed || raw_count != self.raw_values.len()
+        };
+        self.freshness.remove_subtree(path);
+        self.crdts.remove_subtree(path);
+        if !removed {
+            return false;
+        }
+        log::debug!("DataCache invalidated {path}");
+        self.mark_dirty(path);
+        self.update_computed(&[path]);
+        self.on_after_insert();
+
+        for listener in self.invalidation_listeners.0.iter_mut() {
+            listener.on_invalidate(path);
+        }
+        true
+    }
+
+    /// Detaches the value at path from its parent object or array
+    pub(crate) fn remove_value(&mut self, path: &str) -> Option<Value> {
+        let (parent_path, key) = path.rsplit_once('.').unwrap_or(("", path));
+        let parent = if parent_path.is_empty() {
+            &mut self.root
+        } else {
+            self.root.pointer_mut(&Self::target_to_pointer(parent_path))?
+        };
+        match parent {
+            Value::Object(parent_object) => parent_object.shift_remove(key),
+            Value::Array(parent_array) => match key.parse::<usize>() {
+                Ok(idx) if idx < parent_array.len() => Some(parent_array.remove(idx)),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
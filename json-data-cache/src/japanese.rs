@@ -0,0 +1,126 @@
+//! Japanese text utilities, on characters rather than bytes so that multibyte characters are never cut in half.
+//! Combine them with the text renderer to apply them in replacements, like set_renderer("news.*.title", text(to_katakana)),
+//! or use the truncated renderer, cutting strings as truncate_chars does.
+
+use crate::unicode::{graphemes, truncate};
+
+/// Full-width katakana of the half-width ones from U+FF61 (｡) to U+FF9D (ﾝ)
+const HALF_WIDTH_KATAKANA: [char; 61] = [
+    '。', '「', '」', '、', '・', 'ヲ', 'ァ', 'ィ', 'ゥ', 'ェ', 'ォ', 'ャ', 'ュ', 'ョ', 'ッ', 'ー',
+    'ア', 'イ', 'ウ', 'エ', 'オ', 'カ', 'キ', 'ク', 'ケ', 'コ', 'サ', 'シ', 'ス', 'セ', 'ソ',
+    'タ', 'チ', 'ツ', 'テ', 'ト', 'ナ', 'ニ', 'ヌ', 'ネ', 'ノ', 'ハ', 'ヒ', 'フ', 'ヘ', 'ホ',
+    'マ', 'ミ', 'ム', 'メ', 'モ', 'ヤ', 'ユ', 'ヨ', 'ラ', 'リ', 'ル', 'レ', 'ロ', 'ワ', 'ン',
+];
+
+/// The voiced (dakuten) or semi-voiced (handakuten) katakana of a full-width one, if any
+fn voiced(c: char, semi: bool) -> Option<char> {
+    let offset = match c {
+        'ウ' if !semi => return Some('ヴ'),
+        'カ' | 'キ' | 'ク' | 'ケ' | 'コ' | 'サ' | 'シ' | 'ス' | 'セ' | 'ソ' | 'タ' | 'チ' | 'ツ' | 'テ' | 'ト' if !semi => 1,
+        'ハ' | 'ヒ' | 'フ' | 'ヘ' | 'ホ' => if semi { 2 } else { 1 },
+        _ => return None,
+    };
+    char::from_u32(c as u32 + offset)
+}
+
+/// Full-width ASCII (letters, digits, symbols & the ideographic space) to ASCII, and half-width katakana to full-width,
+/// combining their voicing marks. Like NFKC for the characters of Japanese input, keeping the rest as it is
+/// Example: "ＡＢＣ１２３　ｶﾞｷﾞ" is normalized to "ABC123 ガギ"
+pub fn normalize_width(text: &str) -> String {
+    let mut normalized = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\u{ff01}'..='\u{ff5e}' => normalized.push(char::from_u32(c as u32 - 0xfee0).unwrap()),
+            '\u{3000}' => normalized.push(' '),
+            '\u{ff61}'..='\u{ff9d}' => {
+                let kana = HALF_WIDTH_KATAKANA[(c as u32 - 0xff61) as usize];
+                let combined = match chars.peek() {
+                    Some('\u{ff9e}') => voiced(kana, false),
+                    Some('\u{ff9f}') => voiced(kana, true),
+                    _ => None,
+                };
+                if combined.is_some() {
+                    chars.next();
+                }
+                normalized.push(combined.unwrap_or(kana));
+            },
+            '\u{ff9e}' => normalized.push('゛'),
+            '\u{ff9f}' => normalized.push('゜'),
+            _ => normalized.push(c),
+        }
+    }
+    normalized
+}
+
+/// Hiragana to katakana, other characters being kept. Example: "ひらがな" to "ヒラガナ"
+pub fn to_katakana(text: &str) -> String {
+    text.chars().map(|c| match c {
+        'ぁ'..='ゖ' | 'ゝ' | 'ゞ' => char::from_u32(c as u32 + 0x60).unwrap(),
+        _ => c,
+    }).collect()
+}
+
+/// Katakana to hiragana, other characters (like ー or the katakana without hiragana) being kept. Example: "カタカナ" to "かたかな"
+pub fn to_hiragana(text: &str) -> String {
+    text.chars().map(|c| match c {
+        'ァ'..='ヶ' | 'ヽ' | 'ヾ' => char::from_u32(c as u32 - 0x60).unwrap(),
+        _ => c,
+    }).collect()
+}
+
+/// Number of characters of text as perceived by readers (grapheme clusters, see the unicode module), so that voicing
+/// marks combining with a kana do not count
+pub fn char_count(text: &str) -> usize {
+    graphemes(text).count()
+}
+
+/// Text cut to at most max_chars characters (see char_count), ellipsis included, when longer.
+/// Example: truncate_chars("東京都の天気予報", 5, "…") is "東京都の…"
+pub fn truncate_chars(text: &str, max_chars: usize, ellipsis: &str) -> String {
+    truncate(text, max_chars, ellipsis)
+}
+
+/// Lowercase name of the tag starting text, like "rt" for "</RT>"
+fn tag_name(tag: &str) -> Option<String> {
+    let name: String = tag.trim_start_matches('<').trim_start_matches('/').chars().take_while(char::is_ascii_alphanumeric).collect();
+    Some(name.to_ascii_lowercase()).filter(|name| !name.is_empty())
+}
+
+/// Text without its furigana: the readings of HTML ruby (<rt> & <rp> elements, the <ruby> & <rb> tags being removed around
+/// their base text) and of the 《》 notation, along with its ｜ base text marker.
+/// Example: "<ruby>漢字<rt>かんじ</rt></ruby>と｜東京《とうきょう》" is stripped to "漢字と東京"
+pub fn strip_furigana(text: &str) -> String {
+    let mut stripped = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find(['<', '《', '｜']) {
+        stripped.push_str(&rest[..start]);
+        rest = &rest[start..];
+        // Length of the furigana (or ruby tag) starting rest
+        let skipped = if let Some(tail) = rest.strip_prefix('《') {
+            tail.find('》').map(|end| '《'.len_utf8() + end + '》'.len_utf8())
+        } else if rest.starts_with('｜') {
+            Some('｜'.len_utf8())
+        } else {
+            match (tag_name(rest).as_deref(), rest.find('>')) {
+                (Some(name @ ("rt" | "rp")), Some(_)) if !rest.starts_with("</") => {
+                    let closing = format!("</{name}>");
+                    Some(rest.to_ascii_lowercase().find(&closing).map_or(rest.len(), |end| end + closing.len()))
+                },
+                (Some("ruby" | "rb" | "rt" | "rp"), Some(end)) => Some(end + 1),
+                _ => None,
+            }
+        };
+        match skipped {
+            Some(len) => rest = &rest[len..],
+            None => {
+                // Not furigana, like another tag or an unclosed 《
+                let c = rest.chars().next().unwrap();
+                stripped.push(c);
+                rest = &rest[c.len_utf8()..];
+            },
+        }
+    }
+    stripped.push_str(rest);
+    stripped
+}
@@ -2,12 +2,81 @@ use std::collections::HashMap;
 
 use serde_json::Value;
 
-use crate::json_serializer::{json_length::JsonLength, key_value_range::Range, serialized_data::SerializedDataLegacy};
+use crate::{error::JsonDataCacheError, exceeds_depth, json_serializer::{key_value_range::Range, serialized_data::SerializedDataLegacy}};
 
-mod json_length;
-mod key_value_range;
+pub(crate) mod key_value_range;
 pub(crate) mod serialized_data;
 
+/// Options of JsonSerializer::serialize_with
+#[derive(Debug, Clone, Default)]
+pub struct SerializerOptions {
+    /// Also provides the doubly serialized data (see JsonSerializer::serialize)
+    pub double_serialize: bool,
+    /// Collects a RangeTrace for each computed range, in computation order
+    pub trace: bool,
+    /// Checks that each computed range slices the independently serialized value of its key, panicking otherwise
+    /// Costly (each value is serialized again), meant for tests & debugging
+    pub verify: bool,
+    /// Key of the top level object left out, with its subtree (DataCache uses it for its scratch subtree)
+    pub excluded_root_key: Option<String>,
+    /// Values serialized instead of the ones at the same paths (DataCache uses it for its compressed strings)
+    pub substitutions: HashMap<String, Value>,
+}
+
+/// A range computed by the serializer, for debugging
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RangeTrace {
+    pub key: String,
+    pub start: usize,
+    pub end: usize,
+    pub double_serialized: bool, // Range of the doubly serialized data
+}
+
+/// State shared by a whole serialization
+struct SerializerContext<'a> {
+    options: &'a SerializerOptions,
+    trace: Vec<RangeTrace>,
+}
+
+impl SerializerContext<'_> {
+    /// Stores the range of the value at path
+    fn insert_range(&mut self, serialized: &mut SerializedDataLegacy, path: &str, value: &Value, range: Range, double_serialized: bool) {
+        if self.options.verify {
+            assert_eq!(
+                String::from_utf8_lossy(&serialized.data[range.start..range.end]),
+                expected_serialization(value, double_serialized),
+                "JsonSerializer range {}..{} of {path} does not match its value (double serialized: {double_serialized})",
+                range.start,
+                range.end
+            );
+        }
+        log::trace!("JsonSerializer range {path} : {}..{}{}", range.start, range.end, if double_serialized { " (double serialized)" } else { "" });
+        if self.options.trace {
+            self.trace.push(RangeTrace {
+                key: path.to_string(),
+                start: range.start,
+                end: range.end,
+                double_serialized,
+            });
+        }
+        serialized.key_values.insert(path.to_string(), range);
+    }
+}
+
+/// The value of a key as its range should slice it, serialized independently from its parents
+fn expected_serialization(value: &Value, double_serialized: bool) -> String {
+    let mut expected = value.to_string();
+    if double_serialized {
+        let serialized_again = Value::String(expected).to_string();
+        expected = serialized_again[1..serialized_again.len() - 1].to_string();
+    }
+    if value.is_string() {
+        let quote_len = if double_serialized { 2 } else { 1 };
+        expected = expected[quote_len..expected.len() - quote_len].to_string();
+    }
+    expected
+}
+
 /// A tool used to stringify a json Value, while collecting all keys and building slices
 /// In memory, there will be a single String with as many references to it as there are nested keys
 /// This is useful when using AhoCorasick to make mass replacements
@@ -19,13 +88,30 @@ impl JsonSerializer {
     /// Serialize a value and return it along with a list of all possible nested keys with the start & end indexes of their pointed value in the serialized result
     /// double_serialize, if set, will also provide a second doubly serialized string with its own set of value ranges - but without final double quotes!
     pub fn serialize(value: &Value, double_serialize: bool) -> (SerializedDataLegacy, Option<SerializedDataLegacy>) {
+        let options = SerializerOptions {
+            double_serialize,
+            ..Default::default()
+        };
+        let (serialized, double_serialized, _) = Self::serialize_with(value, &options);
+        (serialized, double_serialized)
+    }
+
+    /// Same as serialize, also returning the trace of computed ranges if options.trace is set (empty otherwise)
+    pub fn serialize_with(
+        value: &Value,
+        options: &SerializerOptions
+    ) -> (SerializedDataLegacy, Option<SerializedDataLegacy>, Vec<RangeTrace>) {
+        let mut context = SerializerContext {
+            options,
+            trace: Vec::new(),
+        };
         let mut path = String::new();
         let mut serialized = SerializedDataLegacy {
             data: Vec::new(),
             key_values: HashMap::new(),
             length: 0
         };
-        let mut double_serialized = if double_serialize {
+        let mut double_serialized = if options.double_serialize {
             Some(
                 SerializedDataLegacy {
                     data: Vec::new(),
@@ -40,250 +126,176 @@ impl JsonSerializer {
             &mut path,
             &mut serialized,
             &mut double_serialized,
-            0,
-            0 // Double serialized index starts at 1 because of the final double quotes
+            &mut context,
+        );
+        log::debug!(
+            "JsonSerializer serialized {} bytes & {} keys{}",
+            serialized.data.len(),
+            serialized.key_values.len(),
+            if double_serialized.is_some() { ", doubly serialized too" } else { "" }
         );
 
-        (serialized, double_serialized)
+        (serialized, double_serialized, context.trace)
     }
 
-    /// Recursively serializes a Value while building a map of keys with indices to their (byte) positions in the final serialized string
-    fn rec_serialize(
+    /// Same as serialize, but fails instead of recursing through a value having more than max_depth nested arrays & objects
+    pub fn try_serialize(
         value: &Value,
+        double_serialize: bool,
+        max_depth: usize
+    ) -> Result<(SerializedDataLegacy, Option<SerializedDataLegacy>), JsonDataCacheError> {
+        if exceeds_depth(value, max_depth) {
+            return Err(format!("Unable to serialize a value nested deeper than {max_depth}").into());
+        }
+        Ok(Self::serialize(value, double_serialize))
+    }
+
+    /// Serializes a Value while building a map of keys with their (byte) ranges in the final serialized data
+    /// Walks the value with an explicit stack instead of recursing, so that deeply nested values can not overflow the call stack
+    fn rec_serialize<'v>(
+        value: &'v Value,
         path: &mut String, // Pointing to the current parent, for example list.0
         serialized: &mut SerializedDataLegacy,
         double_serialized: &mut Option<SerializedDataLegacy>,
-        serialized_index: usize,
-        double_serialized_index: usize,
-    ) -> (JsonLength, JsonLength) { // Return value is the length of the newly serialized element, for serialized and double_serialized
-        match value {
-            Value::Null => {
-                let ret = "null";
-                serialized.data.extend(ret.as_bytes());
-                let len = ret.as_bytes().len();
-                if let Some(double_serialized) = double_serialized {
-                    double_serialized.data.extend(ret.as_bytes());
-                }
-                (len.into(), len.into()) // Same for double serialized
-            },
-            Value::Bool(b) => {
-                let ret = b.to_string();
-                serialized.data.extend(ret.as_bytes());
-                let len = ret.as_bytes().len();
-                if let Some(double_serialized) = double_serialized {
-                    double_serialized.data.extend(ret.as_bytes());
-                }
-                (len.into(), len.into()) // Same for double serialized
-            },
-            Value::Number(number) => {
-                let ret = number.to_string();
-                serialized.data.extend(ret.as_bytes());
-                let len = ret.as_bytes().len();
-                if let Some(double_serialized) = double_serialized {
-                    double_serialized.data.extend(ret.as_bytes());
-                }
-                (len.into(), len.into()) // Same for double serialized
-            },
-            Value::String(string) => {
-                let ret = Value::String(string.to_string()).to_string(); // Including potential escapes and surrounding quotes
-                serialized.data.extend(ret.as_bytes());
-                let len = ret.as_bytes().len();
-                let double_serialized_len = if let Some(double_serialized) = double_serialized {
-                    // Here we stringify an additional time (and remove the surrouding quotes)
-                    let mut double_serialized_data = Value::String(ret).to_string();
-                    double_serialized_data.remove(0);
-                    double_serialized_data.remove(double_serialized_data.len()-1);
-                    double_serialized.data.extend(double_serialized_data.as_bytes());
-                    double_serialized_data.as_bytes().len()
-                } else { 0 };
-                ((len-2, len).into(), (double_serialized_len-2, double_serialized_len).into())
-            },
-            Value::Object(map) => {
-                serialized.data.push(b'{');
-                if let Some(double_serialized) = double_serialized {
-                    double_serialized.data.push(b'{');
-                }
-                let mut serialized_current_map_length = 1usize;
-                let mut double_serialized_current_map_length = 1usize;
-                let original_path_len = path.len();
-                for (idx, (key, val)) in map.iter().enumerate() {
-                    if path != "" {
-                        path.push('.');
-                    }
-                    path.push_str(key);
+        context: &mut SerializerContext<'v>,
+    ) {
+        let mut stack: Vec<SerializeStep<'v>> = Vec::from([SerializeStep::Value(value)]);
+        while let Some(step) = stack.pop() {
+            match step {
+                SerializeStep::Value(value) => Self::serialize_opening(value, path, serialized, double_serialized, &mut stack),
+                SerializeStep::Entries { mut entries, written, path_len } => {
+                    path.truncate(path_len); // Remove the key suffix of the previous entry
+                    let excluded_key = context.options.excluded_root_key.as_deref().filter(|_| path.is_empty());
+                    let Some((key, val)) = entries.find(|(key, _)| Some(key.as_str()) != excluded_key) else {
+                        Self::push_both(serialized, double_serialized, b"}");
+                        continue;
+                    };
                     let key_serialized = Value::String(key.to_string()).to_string(); // Including potential escapes and surrounding quotes
-                    if idx > 0 {
+                    if written > 0 {
                         serialized.data.push(b',');
-                        serialized_current_map_length += 1;
                     }
-                    let key_serialized_bytes = key_serialized.as_bytes();
-                    serialized.data.extend(key_serialized_bytes);
+                    serialized.data.extend(key_serialized.as_bytes());
                     serialized.data.push(b':');
-                    serialized_current_map_length += key_serialized_bytes.len() + 1;
 
                     if let Some(double_serialized) = double_serialized {
-                        // Double serialization of key & computing its own serialized value and indices separately
-                        let mut key_double_serialized = Value::String(key_serialized).to_string();
-                        key_double_serialized.remove(0);
-                        key_double_serialized.remove(key_double_serialized.len()-1);
-                        if idx > 0 {
+                        // Double serialization of key, without the surrounding quotes of the second serialization
+                        let key_double_serialized = Value::String(key_serialized).to_string();
+                        if written > 0 {
                             double_serialized.data.push(b',');
-                            double_serialized_current_map_length += 1;
                         }
-                        let key_double_serialized_bytes = key_double_serialized.as_bytes();
-                        double_serialized.data.extend(key_double_serialized_bytes);
+                        double_serialized.data.extend(&key_double_serialized.as_bytes()[1..key_double_serialized.len() - 1]);
                         double_serialized.data.push(b':');
-                        double_serialized_current_map_length += key_double_serialized.len() + 1;
-                    }
-
-                    let child_length = Self::rec_serialize(
-                        val,
-                        path,
-                        serialized,
-                        double_serialized,
-                        serialized_index + serialized_current_map_length,
-                        double_serialized_index + double_serialized_current_map_length,
-                    );
-
-                    let starting_position = serialized_current_map_length;
-                    serialized_current_map_length += child_length.0.outer;
-
-                    let (child_start, child_end) = if child_length.0.inner != child_length.0.outer {
-                        // For child strings, the actual pointed value is the inner part between the quotes, not the whole thing
-                        (
-                            serialized_index + starting_position + 1,
-                            serialized_index + serialized_current_map_length - 1
-                        )
-                    } else {
-                        (
-                            serialized_index + starting_position,
-                            serialized_index + serialized_current_map_length
-                        )
-                    };
-                    let serialized_child_range: Range = (child_start, child_end).into();
-
-                    serialized.key_values.insert(path.to_string(), serialized_child_range);
-
-                    if let Some(double_serialized) = double_serialized {
-                        // Double serialization handling
-                        let starting_position = double_serialized_current_map_length;
-                        double_serialized_current_map_length += child_length.1.outer;
-
-                        let (child_start, child_end) = if child_length.1.inner != child_length.1.outer {
-                            // For child strings, the actual pointed value is the inner part between the quotes, not the whole thing
-                            (
-                                double_serialized_index + starting_position + 2, // 2 characters because quotes are preceeded with backslashes
-                                double_serialized_index + double_serialized_current_map_length - 2
-                            )
-                        } else {
-                            (
-                                double_serialized_index + starting_position,
-                                double_serialized_index + double_serialized_current_map_length
-                            )
-                        };
-                        let double_serialized_child_range: Range = (child_start, child_end).into();
-
-                        double_serialized.key_values.insert(path.to_string(), double_serialized_child_range);
                     }
 
-                    // Post key
-                    path.drain(original_path_len..); // Remove the key suffix that has been temporarily added to path
-                }
-                serialized.data.push(b'}');
-                if let Some(double_serialized) = double_serialized {
-                    double_serialized.data.push(b'}');
-                }
-                serialized_current_map_length += 1;
-                double_serialized_current_map_length += 1;
-                (serialized_current_map_length.into(), double_serialized_current_map_length.into())
-            },
-            Value::Array(values) => {
-                serialized.data.push(b'[');
-                if let Some(double_serialized) = double_serialized {
-                    double_serialized.data.push(b'[');
-                }
-                let mut serialized_current_array_length = 1usize;
-                let mut double_serialized_current_array_length = 1usize;
-                let original_path_len = path.len();
-
-                for (idx, val) in values.iter().enumerate() {
-                    if path != "" {
+                    if !path.is_empty() {
                         path.push('.');
                     }
-                    let idx_str = idx.to_string();
-                    path.push_str(&idx_str);
-
+                    path.push_str(key);
+                    stack.push(SerializeStep::Entries { entries, written: written + 1, path_len });
+                    Self::push_child(val, path, serialized, double_serialized, context, &mut stack);
+                },
+                SerializeStep::Elements { mut elements, path_len } => {
+                    path.truncate(path_len); // Remove the index suffix of the previous element
+                    let Some((idx, val)) = elements.next() else {
+                        Self::push_both(serialized, double_serialized, b"]");
+                        continue;
+                    };
                     if idx > 0 {
-                        serialized.data.push(b',');
-                        serialized_current_array_length += 1;
-
-                        if let Some(double_serialized) = double_serialized {
-                            double_serialized.data.push(b',');
-                            double_serialized_current_array_length += 1;
-                        }
+                        Self::push_both(serialized, double_serialized, b",");
                     }
 
-                    let child_length = Self::rec_serialize(
-                        val,
-                        path,
-                        serialized,
-                        double_serialized,
-                        serialized_index + serialized_current_array_length,
-                        double_serialized_index + double_serialized_current_array_length
-                    );
-
-                    let starting_position = serialized_current_array_length;
-                    serialized_current_array_length += child_length.0.outer; // child_range.end here is equivalent to the length of stringified child
-
-                    let (child_start, child_end) = if child_length.0.inner != child_length.0.outer {
-                        // For child strings, the actual pointed value is the inner part between the quotes, not the whole thing
-                        (
-                            serialized_index + starting_position + 1,
-                            serialized_index + serialized_current_array_length - 1
-                        )
-                    } else {
-                        (
-                            serialized_index + starting_position,
-                            serialized_index + serialized_current_array_length
-                        )
-                    };
-
-                    let serialized_child_range: Range = (child_start, child_end).into();
-
-                    serialized.key_values.insert(path.to_string(), serialized_child_range);
-
+                    if !path.is_empty() {
+                        path.push('.');
+                    }
+                    path.push_str(&idx.to_string());
+                    stack.push(SerializeStep::Elements { elements, path_len });
+                    Self::push_child(val, path, serialized, double_serialized, context, &mut stack);
+                },
+                SerializeStep::ChildEnd { value, start, double_serialized_start } => {
+                    // path still points to the child, parents only truncate it when resuming
+                    let quote_len = if value.is_string() { 1 } else { 0 };
+                    let range: Range = (start + quote_len, serialized.data.len() - quote_len).into();
+                    context.insert_range(serialized, path, value, range, false);
                     if let Some(double_serialized) = double_serialized {
-                        let starting_position = double_serialized_current_array_length;
-                        double_serialized_current_array_length += child_length.1.outer;
-
-                        let (child_start, child_end) = if child_length.1.inner != child_length.1.outer {
-                            // For child strings, the actual pointed value is the inner part between the quotes, not the whole thing
-                            (
-                                double_serialized_index + starting_position + 2, // 2 characters because quotes are preceeded with backslashes
-                                double_serialized_index + double_serialized_current_array_length - 2
-                            )
-                        } else {
-                            (
-                                double_serialized_index + starting_position,
-                                double_serialized_index + double_serialized_current_array_length
-                            )
-                        };
-                        let double_serialized_child_range: Range = (child_start, child_end).into();
-
-                        double_serialized.key_values.insert(path.to_string(), double_serialized_child_range);
+                        let quote_len = if value.is_string() { 2 } else { 0 }; // 2 characters because quotes are preceeded with backslashes
+                        let range: Range = (double_serialized_start + quote_len, double_serialized.data.len() - quote_len).into();
+                        context.insert_range(double_serialized, path, value, range, true);
                     }
+                },
+            }
+        }
+    }
 
-                    // Post key
-                    path.drain(original_path_len..); // Remove the key suffix that has been temporarily added to path
-                }
-                serialized.data.push(b']');
-                serialized_current_array_length += 1;
+    /// Serializes a scalar, or opens an object or array and pushes the step serializing its content
+    fn serialize_opening<'v>(
+        value: &'v Value,
+        path: &str,
+        serialized: &mut SerializedDataLegacy,
+        double_serialized: &mut Option<SerializedDataLegacy>,
+        stack: &mut Vec<SerializeStep<'v>>,
+    ) {
+        match value {
+            Value::Null | Value::Bool(_) | Value::Number(_) => {
+                let ret = value.to_string();
+                Self::push_both(serialized, double_serialized, ret.as_bytes()); // Same for double serialized
+            },
+            Value::String(string) => {
+                let ret = Value::String(string.to_string()).to_string(); // Including potential escapes and surrounding quotes
+                serialized.data.extend(ret.as_bytes());
                 if let Some(double_serialized) = double_serialized {
-                    double_serialized.data.push(b']');
-                    double_serialized_current_array_length += 1;
+                    // Here we stringify an additional time (and remove the surrouding quotes)
+                    let double_serialized_data = Value::String(ret).to_string();
+                    double_serialized.data.extend(&double_serialized_data.as_bytes()[1..double_serialized_data.len() - 1]);
                 }
-                (serialized_current_array_length.into(), double_serialized_current_array_length.into())
+            },
+            Value::Object(map) => {
+                Self::push_both(serialized, double_serialized, b"{");
+                stack.push(SerializeStep::Entries { entries: map.iter(), written: 0, path_len: path.len() });
+            },
+            Value::Array(values) => {
+                Self::push_both(serialized, double_serialized, b"[");
+                stack.push(SerializeStep::Elements { elements: values.iter().enumerate(), path_len: path.len() });
             },
         }
     }
+
+    /// Pushes the steps serializing a child of an object or array, then storing its ranges
+    /// Ranges are the positions of the data before & after serializing the child, so no length has to be accounted for
+    /// by parents. Only strings need an adjustment, as their ranges exclude their surrounding quotes
+    fn push_child<'v>(
+        value: &'v Value,
+        path: &str,
+        serialized: &SerializedDataLegacy,
+        double_serialized: &Option<SerializedDataLegacy>,
+        context: &SerializerContext<'v>,
+        stack: &mut Vec<SerializeStep<'v>>,
+    ) {
+        let value = context.options.substitutions.get(path).unwrap_or(value);
+        stack.push(SerializeStep::ChildEnd {
+            value,
+            start: serialized.data.len(),
+            double_serialized_start: double_serialized.as_ref().map(|d| d.data.len()).unwrap_or_default(),
+        });
+        stack.push(SerializeStep::Value(value));
+    }
+
+    /// Appends the same bytes to the serialized data & to the doubly serialized one, if any
+    fn push_both(serialized: &mut SerializedDataLegacy, double_serialized: &mut Option<SerializedDataLegacy>, bytes: &[u8]) {
+        serialized.data.extend(bytes);
+        if let Some(double_serialized) = double_serialized {
+            double_serialized.data.extend(bytes);
+        }
+    }
+}
+
+/// Pending work of JsonSerializer::rec_serialize
+enum SerializeStep<'v> {
+    /// Serializes a value
+    Value(&'v Value),
+    /// Serializes the remaining entries of an object, then closes it
+    Entries { entries: serde_json::map::Iter<'v>, written: usize, path_len: usize },
+    /// Serializes the remaining elements of an array, then closes it
+    Elements { elements: std::iter::Enumerate<std::slice::Iter<'v, Value>>, path_len: usize },
+    /// Stores the ranges of a child serialized since start
+    ChildEnd { value: &'v Value, start: usize, double_serialized_start: usize },
 }
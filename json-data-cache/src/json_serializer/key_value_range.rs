@@ -1,4 +1,4 @@
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Range {
     pub start: usize, // Including
     pub end: usize // Excluding
@@ -1,8 +1,8 @@
 use indexmap::IndexMap;
-use serde_json::Number;
 
 use crate::json_serializer::serialized_data::serialized_data_node::SerializedDataNode;
 
+#[allow(dead_code)]
 pub enum SerializedDataType {
     Null,
     Bool,
@@ -1,34 +1,260 @@
 use core::{fmt, str};
-use std::{collections::HashMap, io, rc::Rc};
+use std::{borrow::Cow, collections::HashMap, rc::Rc};
+#[cfg(feature = "serializer")]
+use std::cell::OnceCell;
 
+#[cfg(feature = "replace-engine")]
 use aho_corasick::AhoCorasick;
+#[cfg(feature = "replace-engine")]
+use indexmap::IndexMap;
+#[cfg(feature = "regex")]
 use regex::Regex;
 use serde_json::{Value, json};
 
-use crate::{error::JsonDataCacheError, json_serializer::{JsonSerializer, serialized_data::SerializedDataLegacy}};
-
+use crate::{coercion::DataCacheSchemas, compression::DataCacheCompression, computed::ComputedPath, crdt::DataCacheCrdts, decisions::DecisionLog, degraded::DataCacheDegraded, freshness::DataCacheFreshness, invalidation::InvalidationListeners, path_pattern::PathPattern, profiles::DataCacheProfiles, reads::DataCacheReads, refs::DataCacheRefs, render_context::DataCacheRenderContext, runtime::{Clock, Rng}, unicode::{Normalization, normalize_value}, vary::DataCacheVary, versions::DataCacheVersions, visibility::DataCacheVisibility};
+
+use crate::error::JsonDataCacheError;
+#[cfg(feature = "serializer")]
+use crate::{json_serializer::serialized_data::SerializedDataLegacy, string_values::SerializedKeys};
+#[cfg(feature = "replace-engine")]
+use crate::{fragments::Fragment, renderers::DataCacheRenderers, replace_engine::{AutomatonPatterns, Replacement, VariantAutomaton}, prepared::PreparedMatcher, static_keys::StaticAutomaton};
+
+#[cfg(feature = "replace-engine")]
+pub mod assets;
+mod base64;
+pub mod bind;
+#[cfg(feature = "replace-engine")]
+pub mod bundle;
+pub mod cache_path;
+pub mod coercion;
+pub mod compression;
+pub mod computed;
+pub mod consent;
+mod crc32c;
+pub mod crdt;
+pub mod critical_css;
+pub mod decisions;
+pub mod degraded;
+pub mod delta;
 pub mod error;
+#[cfg(feature = "replace-engine")]
+pub mod export;
+mod freshness;
+pub mod html_rewriter;
+pub mod hydration;
+pub mod images;
+#[cfg(feature = "replace-engine")]
+pub mod incremental;
+mod invalidation;
+pub mod japanese;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+pub mod fetcher;
+#[cfg(feature = "replace-engine")]
+mod fragments;
+#[cfg(feature = "serializer")]
 pub mod json_serializer;
+pub mod links;
+pub mod lint;
+pub mod minify;
+pub mod mock;
+pub mod path_pattern;
+pub mod pipeline;
+pub mod preload;
+pub mod preview;
+#[cfg(feature = "serializer")]
+mod prepared;
+pub mod profiles;
+pub mod reads;
+pub mod recording;
+pub mod redirect;
+mod refs;
+pub mod render_context;
+#[cfg(feature = "replace-engine")]
+pub mod renderers;
+#[cfg(feature = "replace-engine")]
+mod replace_engine;
+pub mod runtime;
+pub mod sampling;
+pub mod schedule;
+pub mod scoring;
+pub mod search;
+pub mod security_headers;
+pub mod session;
+mod sha256;
+#[cfg(feature = "replace-engine")]
+mod static_keys;
+pub mod store;
+pub mod tenant;
+#[cfg(feature = "replace-engine")]
+pub mod testing;
+pub mod unicode;
+mod unicode_tables;
+pub mod validation;
+pub mod vary;
+mod versions;
+pub mod visibility;
+pub mod writers;
+#[cfg(feature = "serializer")]
+mod string_values;
+
+#[cfg(feature = "replace-engine")]
+pub use aho_corasick::{AhoCorasickKind, MatchKind};
+pub use cache_path::CachePath;
+pub use freshness::{Freshness, FreshnessMeta};
+pub use invalidation::InvalidationListener;
+#[cfg(feature = "replace-engine")]
+pub use replace_engine::{AuditMarkers, AutomatonStats, BuildProgress, BuildStep, BUDGET_DEGRADED_REASON, CancellationToken, FlushPolicy, OnBudgetExceeded, PlaceholderMap, PlaceholderOffset, RENDER_BUDGET_EXCEEDED, RenderBudget, ReplaceProgress, SourceMap, SourceMapEntry};
+#[cfg(feature = "serializer")]
+pub use string_values::{DataCacheStringValues, RangeMeta};
+
+/// Maximum nesting of inserted values when DataCacheOptions::max_depth is not set
+pub const DEFAULT_MAX_DEPTH: usize = 256;
+
+/// Top level key of the scratch subtree, holding intermediate values: they are read like any other value, but left out of
+/// serialization, replacement patterns, string values (and Display) and persistence
+pub const SCRATCH_KEY: &str = "_tmp";
+
+/// True if path is the scratch subtree or one of its descendants
+pub(crate) fn is_scratch_path(path: &str) -> bool {
+    path.strip_prefix(SCRATCH_KEY).is_some_and(|rest| rest.is_empty() || rest.starts_with('.'))
+}
 
 #[derive(Debug)]
 pub struct DataCache {
     pub root: Value,
     options: DataCacheOptions,
-    serialized_data: DataCacheSerializedData // Cache for AC & replacements, updated on each insert
+    computed: Vec<ComputedPath>, // Derived values, in definition order
+    refs: DataCacheRefs, // Aliases set by insert_ref, resolved on reads & replacements
+    freshness: DataCacheFreshness, // Validity periods set by set_freshness
+    invalidation_listeners: InvalidationListeners,
+    versions: DataCacheVersions, // Bumped on each modification, see version
+    crdts: DataCacheCrdts, // States merged by merge_crdt, whose values are stored at their path
+    decision_log: Option<DecisionLog>, // Set by set_decision_log
+    preview: bool, // Set by enter_preview
+    schemas: DataCacheSchemas, // Used by get_as
+    compression: DataCacheCompression, // Strings kept compressed outside of the tree, see compress_strings
+    render_context: DataCacheRenderContext, // Paths of the render context, derived from them on first use
+    visibility: DataCacheVisibility, // Rules set by set_visibility & the values they hide, kept outside of the tree
+    reads: DataCacheReads, // Paths read since track_reads
+    vary: DataCacheVary, // Dynamic paths set by declare_dynamic
+    degraded: DataCacheDegraded, // Preset set by set_degraded_mode & the values replaced by degrade
+    profiles: DataCacheProfiles, // Transformation profiles by name, see register_profile
+    #[cfg(feature = "serializer")]
+    serialized_data: DataCacheSerializedData, // Cache for AC & replacements, updated on each insert
+    #[cfg(feature = "serializer")]
+    string_values: DataCacheStringValues, // Cache for string_values_view, partially recomputed after inserts
+    #[cfg(feature = "replace-engine")]
+    raw_values: IndexMap<String, Vec<u8>>, // Opaque values set by insert_bytes, only used by replacements
+    #[cfg(feature = "replace-engine")]
+    fragments: HashMap<String, Fragment>, // Templates registered for render_fragment_cached, by id
+    #[cfg(feature = "replace-engine")]
+    renderers: DataCacheRenderers, // Display formats of replacements, set by set_renderer
+    #[cfg(feature = "replace-engine")]
+    variants: HashMap<String, String>, // Overlay path of each variant, set by define_variant
+    #[cfg(feature = "replace-engine")]
+    static_keys: Vec<String>, // Top level keys set by set_static_keys
+    #[cfg(feature = "replace-engine")]
+    static_automaton: Option<StaticAutomaton> // Automaton of the static keys, reset when one of them is modified
 }
 
+#[cfg(feature = "serializer")]
 #[derive(Debug, Default)]
 pub struct DataCacheSerializedData {
+    serialized: OnceCell<SerializedDataLegacy>, // In memory serialized data cache tree, built on first use
+    double_serialized: OnceCell<SerializedDataLegacy>, // In memory doubly serialized data cache tree, built along with serialized
+    key_index: OnceCell<Vec<String>>, // Sorted keys of serialized, built on first prefix lookup
+    #[cfg(feature = "replace-engine")]
     is_built: bool,
+    #[cfg(feature = "replace-engine")]
     ac: Option<AhoCorasick>,
-    serialized: Option<SerializedDataLegacy>, // In memory serialized data cache tree
-    double_serialized: Option<SerializedDataLegacy>, // In memory doubly serialized data cache tree
-    replacements: Vec<Rc<[u8]>>
+    #[cfg(feature = "replace-engine")]
+    prepared_matcher: Option<PreparedMatcher>, // Restored by from_prepared_bytes, in place of ac
+    #[cfg(feature = "replace-engine")]
+    replacements: Vec<Replacement>, // Indexed by pattern
+    #[cfg(feature = "replace-engine")]
+    rendered: Vec<Vec<u8>>, // Values of Replacement::Rendered
+    #[cfg(feature = "replace-engine")]
+    variants: HashMap<String, VariantAutomaton>, // Built on first replacement with each variant
+    #[cfg(feature = "replace-engine")]
+    pending_patterns: Option<AutomatonPatterns>, // Collected by build_step, until the automaton is built
+    #[cfg(feature = "replace-engine")]
+    stats: Option<AutomatonStats>
 }
 
-#[derive(Debug, Default)]
+#[derive(Debug, Clone, Default)]
 pub struct DataCacheOptions {
-    pub reserved_cache_top_level_names: Vec<String>
+    pub reserved_cache_top_level_names: Vec<String>,
+    /// Maximum count of nested arrays & objects, path segments included, accepted by inserts (None uses DEFAULT_MAX_DEPTH)
+    /// Deeper values are rejected, protecting recursive processing (serialization, merge) from stack overflows
+    pub max_depth: Option<usize>,
+    /// Time of freshness checks, fetches & statistics (None uses the platform clock, see the runtime module)
+    pub clock: Option<Rc<dyn Clock>>,
+    /// Randomness of nonces, which must be cryptographically secure (None uses the platform rng, see runtime::PlatformRng)
+    pub rng: Option<Rc<dyn Rng>>,
+    /// Normalization of the strings of inserted & merged values (None keeps them as they are), object keys excepted
+    pub normalization: Option<Normalization>,
+    /// If not empty, only keys covered by one of these patterns (see PathPattern) become replacement patterns, serialized
+    /// ranges & string values
+    #[cfg(feature = "serializer")]
+    pub serialize_only: Vec<String>,
+    /// Keys covered by one of these patterns never become replacement patterns, serialized ranges nor string values, even
+    /// if included by serialize_only
+    #[cfg(feature = "serializer")]
+    pub serialize_exclude: Vec<String>,
+    /// Building an automaton with more patterns than this fails instead of allocating it
+    #[cfg(feature = "replace-engine")]
+    pub max_patterns: Option<usize>,
+    /// Automaton implementation (None lets aho-corasick choose). DFA is the fastest but uses the most memory, NFA the opposite
+    #[cfg(feature = "replace-engine")]
+    pub ac_kind: Option<AhoCorasickKind>,
+    /// Enables or disables the automaton prefilter (None keeps aho-corasick default: enabled)
+    #[cfg(feature = "replace-engine")]
+    pub ac_prefilter: Option<bool>,
+    /// Match semantics (None uses MatchKind::LeftmostLongest, replacing overlapping patterns by the longest one, see
+    /// detect_ambiguous_keys). MatchKind::Standard replaces the first pattern to end, even if a longer one starts at the same byte
+    #[cfg(feature = "replace-engine")]
+    pub ac_match_kind: Option<MatchKind>,
+    /// Replacements write `\{$` as `{$`, leaving the placeholder following it as is: `\{$key}` outputs the literal {$key},
+    /// for pages documenting the template syntax or embedding client side templates using it
+    #[cfg(feature = "replace-engine")]
+    pub escape_placeholders: bool,
+    /// Audit mode, for QA: each replacement is written between these markers (None writes replacements only)
+    #[cfg(feature = "replace-engine")]
+    pub audit_markers: Option<AuditMarkers>,
+    /// When replacements flush their writer (FlushPolicy::Never by default, leaving it to the writer). Flushing after some
+    /// bytes or after each placeholder trades more flushes for a lower time to first byte
+    #[cfg(feature = "replace-engine")]
+    pub flush_policy: FlushPolicy,
+    /// Maximum time spent by each replacement (None for no limit), aborting it or degrading the cache once exceeded
+    #[cfg(feature = "replace-engine")]
+    pub render_budget: Option<RenderBudget>,
+    /// Minifies the whitespace of templates as they are replaced, in the same pass (see the minify module). Placeholder
+    /// maps & ranges (see placeholder_map) describe the output without minification
+    #[cfg(feature = "replace-engine")]
+    pub minify: bool,
+    /// Keys covered by one of these patterns (see PathPattern) also get a {$key|safe_redirect} pattern, replaced by their
+    /// string value if the allowlist at redirect_allowlist_path allows it as a redirect target, else by its fallback
+    #[cfg(feature = "replace-engine")]
+    pub safe_redirect_keys: Vec<String>,
+    /// Allowlist of the safe_redirect filter (None uses DEFAULT_REDIRECT_ALLOWLIST_PATH, see the redirect module)
+    #[cfg(feature = "replace-engine")]
+    pub redirect_allowlist_path: Option<String>
+}
+
+/// True if the value has more than max_depth levels of nested arrays & objects
+/// Iterative, so that checking a pathological value can not overflow the stack by itself
+pub(crate) fn exceeds_depth(value: &Value, max_depth: usize) -> bool {
+    let mut stack: Vec<(&Value, usize)> = Vec::from([(value, 0)]);
+    while let Some((value, depth)) = stack.pop() {
+        match value {
+            Value::Array(a) if depth < max_depth => stack.extend(a.iter().map(|el| (el, depth + 1))),
+            Value::Object(o) if depth < max_depth => stack.extend(o.values().map(|v| (v, depth + 1))),
+            Value::Array(_) | Value::Object(_) => return true,
+            _ => {}
+        }
+    }
+    false
 }
 
 impl fmt::Display for DataCache {
@@ -41,222 +267,441 @@ impl fmt::Display for DataCache {
 
 impl DataCache {
     pub fn new(options: DataCacheOptions) -> Self {
-        let new_data_cache = Self {
+        Self {
             root: json!({}),
             options,
-            serialized_data: DataCacheSerializedData::default()
-        };
-        new_data_cache
+            computed: Vec::new(),
+            refs: DataCacheRefs::default(),
+            freshness: DataCacheFreshness::default(),
+            invalidation_listeners: InvalidationListeners::default(),
+            versions: DataCacheVersions::default(),
+            crdts: DataCacheCrdts::default(),
+            decision_log: None,
+            preview: false,
+            schemas: DataCacheSchemas::default(),
+            compression: DataCacheCompression::default(),
+            render_context: DataCacheRenderContext::default(),
+            visibility: DataCacheVisibility::default(),
+            reads: DataCacheReads::default(),
+            vary: DataCacheVary::default(),
+            degraded: DataCacheDegraded::default(),
+            profiles: DataCacheProfiles::default(),
+            #[cfg(feature = "serializer")]
+            serialized_data: DataCacheSerializedData::default(),
+            #[cfg(feature = "serializer")]
+            string_values: DataCacheStringValues::default(),
+            #[cfg(feature = "replace-engine")]
+            raw_values: IndexMap::new(),
+            #[cfg(feature = "replace-engine")]
+            fragments: HashMap::new(),
+            #[cfg(feature = "replace-engine")]
+            renderers: DataCacheRenderers::default(),
+            #[cfg(feature = "replace-engine")]
+            variants: HashMap::new(),
+            #[cfg(feature = "replace-engine")]
+            static_keys: Vec::new(),
+            #[cfg(feature = "replace-engine")]
+            static_automaton: None
+        }
     }
 
-    fn insert_rec(parent: &mut Value, path: &str, mut value: Value) {
-        let two_parts: Vec<&str> = path.splitn(2, '.').collect(); // Can only have length 1 or 2
+    pub fn options(&self) -> &DataCacheOptions {
+        &self.options
+    }
 
-        if two_parts.len() == 1 {
-            let current_key = two_parts.get(0).unwrap();
-            match parent {
-                Value::Array(p) => {
-                    if current_key.len() == 0 {
+    /// Fails if the value, inserted at path, would be nested deeper than the configured maximum depth
+    fn check_depth(&self, path: &str, value: &Value) -> Result<(), JsonDataCacheError> {
+        let max_depth = self.options.max_depth.unwrap_or(DEFAULT_MAX_DEPTH);
+        let path_depth = if path.is_empty() { 0 } else { path.split('.').count() };
+        if path_depth > max_depth || exceeds_depth(value, max_depth - path_depth) {
+            return Err(format!("Value at {path} is nested deeper than the maximum depth of {max_depth}").into());
+        }
+        Ok(())
+    }
+
+    fn insert_rec(mut parent: &mut Value, mut path: &str, mut value: Value) {
+        loop {
+            let two_parts: Vec<&str> = path.splitn(2, '.').collect(); // Can only have length 1 or 2
+
+            if two_parts.len() == 1 {
+                let current_key = two_parts.first().unwrap();
+                match parent {
+                    Value::Array(p) if current_key.is_empty() => {
                         p.push(value);
-                    }
-                },
-                Value::Object(parent_object) => {
-                    if current_key.len() > 0 {
+                    },
+                    Value::Object(parent_object) if !current_key.is_empty() => {
                         let new_current = parent_object
                             .entry(*current_key)
                             .or_insert(json!({}));
                         Self::merge_rec(new_current, value);
-                    }
-                },
-                _ => {
-                    // Can't handle other cases. Object case should've been handled in the previous iteration
-                },
-            }
-        } else {
-            match parent {
-                Value::Object(parent_object) => {
-                    // There is something else to insert
-                    let current_key = two_parts.get(0).unwrap();
-                    let remaining_path = two_parts.get(1).unwrap();
-                    if remaining_path.contains('.') {
-                        parent_object
-                            .entry(*current_key)
-                            .and_modify(|new_parent| {
-                                Self::insert_rec(new_parent, remaining_path, value.clone());
-                            })
-                            .or_insert_with(|| {
-                                let mut new_parent = json!({});
-                                Self::insert_rec(&mut new_parent, remaining_path, value);
-                                new_parent
-                            });
-                    } else {
-                        // No more nesting
-                        if remaining_path == &"" {
-                            // Build array (path ended with a single '.')
-                            let new_array = parent_object
-                                .entry(*current_key)
-                                .and_modify(|existing: &mut Value| {
-                                    if !existing.is_array() {
-                                        // Force conversion to array
-                                        *existing = Value::Array(Vec::new());
-                                    }
-                                })
-                                .or_insert(Value::Array(Vec::new()));
-                            new_array.as_array_mut().unwrap().push(value);
+                    },
+                    _ => {
+                        // Can't handle other cases. Object case should've been handled in the previous iteration
+                    },
+                }
+            } else {
+                match parent {
+                    Value::Object(parent_object) => {
+                        // There is something else to insert
+                        let current_key = two_parts.first().unwrap();
+                        let remaining_path = two_parts.get(1).unwrap();
+                        if remaining_path.contains('.') {
+                            // Descend instead of recursing, so that long paths can not overflow the call stack
+                            parent = parent_object.entry(*current_key).or_insert(json!({}));
+                            path = remaining_path;
+                            continue;
                         } else {
-                            if parent_object.get(*current_key).map(|found| found.is_array()).unwrap_or(false) {
-                                // Special case : parent object is an array and we set a key => we want to set the give key & value for each object item
-                                let arr = parent_object.get_mut(*current_key).unwrap().as_array_mut().unwrap();
-                                if value.is_array() {
-                                    // Prepare for special case of special case, and reverse value array to efficiently consume it during iterating
-                                    value.as_array_mut().unwrap().reverse();
-                                }
-                                for item in arr.iter_mut() {
-                                    let value_to_insert = if value.is_array() {
-                                        // Even more special case : if the value is an array, distribute it
-                                        let value_arr = value.as_array_mut().unwrap();
-                                        if value_arr.len() > 0 {
-                                            value_arr.pop().unwrap()
-                                        } else {
-                                            // Value array was shorter than parent, nothing left to distribute
-                                            break;
+                            // No more nesting
+                            if remaining_path == &"" {
+                                // Build array (path ended with a single '.')
+                                let new_array = parent_object
+                                    .entry(*current_key)
+                                    .and_modify(|existing: &mut Value| {
+                                        if !existing.is_array() {
+                                            // Force conversion to array
+                                            *existing = Value::Array(Vec::new());
                                         }
-                                    } else {
-                                        value.clone()
-                                    };
-                                    if item.is_object() {
-                                        let previous_value = item.as_object_mut().unwrap()
-                                            .entry(remaining_path.to_string())
-                                            .or_insert(Value::Object(serde_json::Map::new()));
-                                        if previous_value.is_object() && value_to_insert.is_object() {
-                                            // Both are objects : merge is possible
-                                            Self::merge_rec(previous_value, value_to_insert);
+                                    })
+                                    .or_insert(Value::Array(Vec::new()));
+                                new_array.as_array_mut().unwrap().push(value);
+                            } else {
+                                if parent_object.get(*current_key).map(|found| found.is_array()).unwrap_or(false) {
+                                    // Special case : parent object is an array and we set a key => we want to set the give key & value for each object item
+                                    let arr = parent_object.get_mut(*current_key).unwrap().as_array_mut().unwrap();
+                                    if value.is_array() {
+                                        // Prepare for special case of special case, and reverse value array to efficiently consume it during iterating
+                                        value.as_array_mut().unwrap().reverse();
+                                    }
+                                    for item in arr.iter_mut() {
+                                        let value_to_insert = if value.is_array() {
+                                            // Even more special case : if the value is an array, distribute it
+                                            let value_arr = value.as_array_mut().unwrap();
+                                            if !value_arr.is_empty() {
+                                                value_arr.pop().unwrap()
+                                            } else {
+                                                // Value array was shorter than parent, nothing left to distribute
+                                                break;
+                                            }
+                                        } else {
+                                            value.clone()
+                                        };
+                                        if item.is_object() {
+                                            let previous_value = item.as_object_mut().unwrap()
+                                                .entry(remaining_path.to_string())
+                                                .or_insert(Value::Object(serde_json::Map::new()));
+                                            if previous_value.is_object() && value_to_insert.is_object() {
+                                                // Both are objects : merge is possible
+                                                Self::merge_rec(previous_value, value_to_insert);
+                                            } else {
+                                                // Replace the existing value by new one
+                                                item.as_object_mut().unwrap().insert(remaining_path.to_string(), value_to_insert);
+                                            }
                                         } else {
-                                            // Replace the existing value by new one
-                                            item.as_object_mut().unwrap().insert(remaining_path.to_string(), value_to_insert);
+                                            // Not an object - ignore
                                         }
-                                    } else {
-                                        // Not an object - ignore
                                     }
-                                }
-                            } else {
-                                // Parent is not an object (not array special case), so we force its conversion to object
-                                let new_object = parent_object
-                                    .entry(*current_key)
-                                    .and_modify(|existing| {
-                                        if !existing.is_object() {
-                                            // Force conversion to object
-                                            *existing = Value::Object(serde_json::Map::new());
-                                        }
-                                    })
-                                    .or_insert(Value::Object(serde_json::Map::new()));
-                                let previous_value = new_object.as_object_mut().unwrap()
-                                    .entry(remaining_path.to_string())
-                                    .or_insert(Value::Object(serde_json::Map::new()));
-                                if previous_value.is_object() && value.is_object() {
-                                    // Both are objects : merge is possible
-                                    Self::merge_rec(previous_value, value);
                                 } else {
-                                    // Replace the existing value by new one
-                                    new_object.as_object_mut().unwrap().insert(remaining_path.to_string(), value);
+                                    // Parent is not an object (not array special case), so we force its conversion to object
+                                    let new_object = parent_object
+                                        .entry(*current_key)
+                                        .and_modify(|existing| {
+                                            if !existing.is_object() {
+                                                // Force conversion to object
+                                                *existing = Value::Object(serde_json::Map::new());
+                                            }
+                                        })
+                                        .or_insert(Value::Object(serde_json::Map::new()));
+                                    let previous_value = new_object.as_object_mut().unwrap()
+                                        .entry(remaining_path.to_string())
+                                        .or_insert(Value::Object(serde_json::Map::new()));
+                                    if previous_value.is_object() && value.is_object() {
+                                        // Both are objects : merge is possible
+                                        Self::merge_rec(previous_value, value);
+                                    } else {
+                                        // Replace the existing value by new one
+                                        new_object.as_object_mut().unwrap().insert(remaining_path.to_string(), value);
+                                    }
                                 }
                             }
                         }
+                    },
+                    _ => {
+                        // Unable to process
                     }
-                },
-                _ => {
-                    // Unable to process
                 }
             }
+            return;
         }
     }
 
     fn merge_rec(a: &mut Value, b: Value) {
-        if let Value::Object(a) = a {
-            if let Value::Object(b) = b {
-                for (k, v) in b {
-                    if v.is_null() {
-                        a.remove(&k);
+        // Explicit stack instead of recursion, so that deeply nested values can not overflow the call stack
+        let mut stack: Vec<(&mut Value, Value)> = Vec::from([(a, b)]);
+        while let Some((a, b)) = stack.pop() {
+            match (a, b) {
+                (Value::Object(a), Value::Object(b)) => {
+                    let mut to_merge = serde_json::Map::new();
+                    for (k, v) in b {
+                        if v.is_null() {
+                            a.remove(&k);
+                        }
+                        else {
+                            a.entry(k.clone()).or_insert(Value::Null);
+                            to_merge.insert(k, v);
+                        }
                     }
-                    else {
-                        Self::merge_rec(a.entry(k).or_insert(Value::Null), v);
+                    if !to_merge.is_empty() {
+                        stack.extend(a.iter_mut().filter_map(|(k, existing)| to_merge.remove(k).map(|v| (existing, v))));
                     }
-                } 
-    
-                return;
+                },
+                (a, b) => *a = b,
             }
         }
-    
-        *a = b;
     }
 
     pub fn merge(&mut self, other: Value) {
+        if let Err(e) = self.try_merge(other) {
+            log::info!("[WARN] DataCache merge : {}", e);
+        }
+    }
+
+    /// Same as merge, failing without any modification if other is nested deeper than the maximum depth
+    pub fn try_merge(&mut self, mut other: Value) -> Result<(), JsonDataCacheError> {
+        self.check_depth("", &other)?;
+        if let Some(form) = self.options.normalization {
+            normalize_value(&mut other, form);
+        }
+        let modified_keys: Vec<String> = match other.as_object() {
+            Some(other_object) => other_object.keys().cloned().collect(),
+            None => {
+                // The whole root gets replaced
+                #[cfg(feature = "serializer")]
+                {
+                    self.string_values.is_built = false;
+                }
+                Vec::from([String::new()])
+            }
+        };
         Self::merge_rec(&mut self.root, other);
+        for key in &modified_keys {
+            self.mark_dirty(key);
+        }
+        let modified_keys: Vec<&str> = modified_keys.iter().map(|k| k.as_str()).collect();
+        self.update_computed(&modified_keys);
+        self.compress_inserted(&modified_keys);
+
+        self.on_after_data_insert();
+        Ok(())
     }
 
     /// Inserts the new value. Path containing dot '.' will build nested object.
     /// If the target object exists and is an array, the value will be appended
+    /// Values nested deeper than the maximum depth are ignored (see try_insert)
     pub fn insert(&mut self, path: &str, value: Value) {
+        if let Err(e) = self.try_insert(path, value) {
+            log::info!("[WARN] DataCache insert : {}", e);
+        }
+    }
+
+    /// Same as insert, failing without any modification if the value would be nested deeper than the maximum depth
+    pub fn try_insert(&mut self, path: &str, mut value: Value) -> Result<(), JsonDataCacheError> {
+        self.check_depth(path, &value)?;
+        if let Some(form) = self.options.normalization {
+            normalize_value(&mut value, form);
+        }
+        if value.is_null() {
+            self.compression.forget(path);
+            self.visibility.forget(path);
+        }
         Self::insert_rec(&mut self.root, path, value);
+        self.mark_dirty(path);
+        self.update_computed(&[path]);
+        self.compress_inserted(&[path]);
 
-        self.on_after_insert();
+        self.on_after_data_insert();
+        Ok(())
+    }
+
+    /// Same as try_insert, replacing the value at path instead of merging an object into the previous one
+    pub fn try_replace(&mut self, path: &str, value: Value) -> Result<(), JsonDataCacheError> {
+        self.check_depth(path, &value)?;
+        // Null first overwrites any previous object
+        self.try_insert(path, Value::Null)?;
+        self.try_insert(path, value)
     }
 
     // A more efficient insert of many elements that only recalculates final state after all insertions instead of after each
+    // Like insert, values nested deeper than the maximum depth are ignored
     pub fn insert_bulk(&mut self, values: Vec<(String, Value)>) {
-        for (path, value) in values {
+        let mut paths = Vec::with_capacity(values.len());
+        for (path, mut value) in values {
+            if let Err(e) = self.check_depth(&path, &value) {
+                log::info!("[WARN] DataCache insert_bulk : {}", e);
+                continue;
+            }
+            if let Some(form) = self.options.normalization {
+                normalize_value(&mut value, form);
+            }
+            if value.is_null() {
+                self.compression.forget(&path);
+                self.visibility.forget(&path);
+            }
             Self::insert_rec(&mut self.root, &path, value);
+            self.mark_dirty(&path);
+            paths.push(path);
         }
-        self.on_after_insert();
+        let paths: Vec<&str> = paths.iter().map(|p| p.as_str()).collect();
+        self.update_computed(&paths);
+        self.compress_inserted(&paths);
+        self.on_after_data_insert();
+    }
+
+    /// Removes & returns the value at path, like "news.items.0" (later items of an array being shifted), along with its
+    /// freshness metadata & CRDT states. An empty path removes everything. Returns None without such a value
+    pub fn remove(&mut self, path: &str) -> Option<Value> {
+        self.remove_path(path, false)
+    }
+
+    /// Same as remove, the parent objects & arrays left empty being removed as well, up to the top level
+    pub fn remove_pruned(&mut self, path: &str) -> Option<Value> {
+        self.remove_path(path, true)
     }
 
+    fn remove_path(&mut self, path: &str, prune_empty_parents: bool) -> Option<Value> {
+        let mut value = if path.is_empty() {
+            #[cfg(feature = "serializer")]
+            {
+                self.string_values.is_built = false;
+            }
+            std::mem::replace(&mut self.root, json!({}))
+        } else {
+            self.remove_value(path)?
+        };
+        self.compression.restore(&mut value, path);
+        self.compression.forget(path);
+        self.visibility.forget(path);
+        self.freshness.remove_subtree(path);
+        self.crdts.remove_subtree(path);
+        let mut removed_path = path;
+        while prune_empty_parents && let Some((parent_path, _)) = removed_path.rsplit_once('.') {
+            let is_empty = match Self::lookup(&self.root, parent_path) {
+                Some(Value::Object(o)) => o.is_empty(),
+                Some(Value::Array(a)) => a.is_empty(),
+                _ => false,
+            };
+            if !is_empty {
+                break;
+            }
+            self.remove_value(parent_path);
+            removed_path = parent_path;
+        }
+        // Removing an array item shifts the following ones, so the whole array is modified
+        let modified_path = match removed_path.rsplit_once('.') {
+            Some((parent_path, _)) if Self::lookup(&self.root, parent_path).is_some_and(Value::is_array) => parent_path,
+            _ => removed_path,
+        };
+        self.mark_dirty(modified_path);
+        self.update_computed(&[modified_path]);
+        self.on_after_data_insert();
+        Some(value)
+    }
+
+    /// Resets everything derived from the tree & settings
     fn on_after_insert(&mut self) {
+        #[cfg(feature = "replace-engine")]
+        {
+            self.static_automaton = None;
+        }
+        self.on_after_data_insert();
+    }
+
+    /// Resets everything derived from the tree, once the modified paths went through mark_dirty
+    fn on_after_data_insert(&mut self) {
+        self.apply_visibility();
+        self.render_context.on_modified();
+        if !self.compression.is_empty() {
+            self.compression.on_modified(&self.root);
+        }
         // Reset (cached) serialized data
-        self.serialized_data = DataCacheSerializedData::default()
+        #[cfg(feature = "serializer")]
+        {
+            self.serialized_data = DataCacheSerializedData::default()
+        }
+    }
+
+    /// Flags the top level key of the path as modified, so that its subtree gets recomputed in string_values_view,
+    /// and the cached fragments depending on the path as outdated. Also bumps the version of the path
+    fn mark_dirty(&mut self, path: &str) {
+        self.versions.bump(path);
+        #[cfg(feature = "serializer")]
+        if self.string_values.is_built {
+            let top_level_key = path.split('.').next().unwrap_or_default();
+            self.string_values.dirty_keys.insert(top_level_key.to_string());
+        }
+        #[cfg(feature = "replace-engine")]
+        for fragment in self.fragments.values_mut() {
+            fragment.mark_dirty(path);
+        }
+        #[cfg(feature = "replace-engine")]
+        if path.is_empty() || self.is_static_key(path) {
+            self.static_automaton = None;
+        }
     }
 
     fn as_string_values_map_rec(map: &mut HashMap<String, String>, parent: &Value, current_path: String) {
         let build_prefix = |path: &String| {
-            if path.len() > 0 {
+            if !path.is_empty() {
                 format!("{}.", path)
             } else {
                 String::new()
             }
         };
-        match parent {
-            Value::Array(a) => {
-                for (idx, el) in a.iter().enumerate() {
-                    Self::as_string_values_map_rec(map, el, format!("{}{}", build_prefix(&current_path), idx));
-                }
-                map.insert(current_path, serde_json::to_string(a).unwrap_or(String::from("[]")));
-            },
-            Value::Object(o) => {
-                for (k, v) in o {
-                    Self::as_string_values_map_rec(map, v, format!("{}{}", build_prefix(&current_path), k));
-                }
-                if current_path.len() > 0 {
-                    map.insert(current_path, serde_json::to_string(o).unwrap_or(String::from("{}")));
-                }
-            },
-            Value::String(v) => {
-                map.insert(current_path, v.to_string());
-            },
-            Value::Number(v) => {
-                map.insert(current_path, v.to_string());
-            },
-            Value::Bool(v) => {
-                map.insert(current_path, v.to_string());
-            },
-            Value::Null => {
-                map.insert(current_path, "null".to_string());
-            },
+        // Explicit stack instead of recursion, so that deeply nested values can not overflow the call stack
+        let mut stack: Vec<(&Value, String)> = Vec::from([(parent, current_path)]);
+        while let Some((parent, current_path)) = stack.pop() {
+            match parent {
+                Value::Array(a) => {
+                    for (idx, el) in a.iter().enumerate() {
+                        stack.push((el, format!("{}{}", build_prefix(&current_path), idx)));
+                    }
+                    map.insert(current_path, serde_json::to_string(a).unwrap_or(String::from("[]")));
+                },
+                Value::Object(o) => {
+                    for (k, v) in o {
+                        stack.push((v, format!("{}{}", build_prefix(&current_path), k)));
+                    }
+                    if !current_path.is_empty() {
+                        map.insert(current_path, serde_json::to_string(o).unwrap_or(String::from("{}")));
+                    }
+                },
+                Value::String(v) => {
+                    map.insert(current_path, v.to_string());
+                },
+                Value::Number(v) => {
+                    map.insert(current_path, v.to_string());
+                },
+                Value::Bool(v) => {
+                    map.insert(current_path, v.to_string());
+                },
+                Value::Null => {
+                    map.insert(current_path, "null".to_string());
+                },
+            }
         }
     }
 
     /// Returns a map with all String values of the data cache, using '.' for nested elements and numbers for array keys
     pub fn as_string_values_map(&self) -> HashMap<String, String> {
         let mut map: HashMap<String, String> = HashMap::new();
-        Self::as_string_values_map_rec(&mut map, &self.root, String::new());
+        let root = self.root_with_computed();
+        Self::as_string_values_map_rec(&mut map, &root, String::new());
+        for (path, value) in self.compression.values() {
+            map.insert(path.to_string(), value.as_str().unwrap_or_default().to_string());
+        }
+        map.retain(|key, _| !is_scratch_path(key));
+        self.refs.copy_values(&root, &self.compression, &mut map);
+        #[cfg(feature = "serializer")]
+        SerializedKeys::new(&self.options).retain(&mut map);
         map
     }
 
@@ -267,22 +712,131 @@ impl DataCache {
     /// Access a data node in the tree through a pointer path expression
     /// Example: get("root_object.some_array.0") => <first element of array>
     pub fn get<'b>(&'b self, target: &str) -> Option<&'b Value> {
-        let target_pointer = DataCache::target_to_pointer(target);
-        self.root.pointer(&target_pointer)
+        let resolved = self.refs.resolve(target);
+        let resolved = self.refs.resolve_fallback(&self.root, &self.compression, &resolved).map(Cow::Owned).unwrap_or(resolved);
+        self.reads.record(&resolved);
+        if !self.computed.is_empty()
+            && let Some(value) = self.computed_value(&resolved) {
+            return Some(value);
+        }
+        if let Some(value) = self.compression.get(&resolved).or_else(|| self.compression.get_ancestor(&self.root, &resolved)) {
+            return Some(value);
+        }
+        Self::lookup(&self.root, &resolved)
+    }
+
+    /// Child of a node for a path segment: key of an object or index of an array, as in a pointer
+    fn child<'b>(node: &'b Value, segment: &str) -> Option<&'b Value> {
+        match node {
+            Value::Object(o) => o.get(segment),
+            Value::Array(a) if segment == "0" || !segment.starts_with(['0', '+']) => a.get(segment.parse::<usize>().ok()?),
+            _ => None,
+        }
+    }
+
+    /// Node at path below node, walking its segments without building a pointer string
+    pub(crate) fn lookup<'b>(node: &'b Value, path: &str) -> Option<&'b Value> {
+        path.split('.').try_fold(node, Self::child)
+    }
+
+    /// Same as get for each target, in order. The nodes of the previous target are reused for a shared prefix, so
+    /// that paths of the same subtree are not each looked up from the root
+    /// Example: get_many(&["content.title", "content.body", "seo.description"])
+    pub fn get_many<'b>(&'b self, targets: &[&str]) -> Vec<Option<&'b Value>> {
+        let mut values = Vec::with_capacity(targets.len());
+        let mut previous_segments: Vec<String> = Vec::new();
+        let mut previous_nodes: Vec<&Value> = Vec::new(); // Node of each found segment of the previous target
+        for target in targets {
+            let resolved = self.refs.resolve(target);
+            let resolved = self.refs.resolve_fallback(&self.root, &self.compression, &resolved).map(Cow::Owned).unwrap_or(resolved);
+            self.reads.record(&resolved);
+            if !self.computed.is_empty()
+                && let Some(value) = self.computed_value(&resolved) {
+                values.push(Some(value));
+                continue;
+            }
+            if let Some(value) = self.compression.get(&resolved).or_else(|| self.compression.get_ancestor(&self.root, &resolved)) {
+                values.push(Some(value));
+                continue;
+            }
+            let segments: Vec<&str> = resolved.split('.').collect();
+            let shared = segments.iter().zip(&previous_segments).take_while(|(a, b)| *a == b).count().min(previous_nodes.len());
+            previous_nodes.truncate(shared);
+            let mut node = previous_nodes.last().copied().unwrap_or(&self.root);
+            let mut found = true;
+            for segment in &segments[shared..] {
+                match Self::child(node, segment) {
+                    Some(child) => {
+                        previous_nodes.push(child);
+                        node = child;
+                    },
+                    None => {
+                        found = false;
+                        break;
+                    },
+                }
+            }
+            values.push(found.then_some(node));
+            previous_segments = segments.into_iter().map(str::to_string).collect();
+        }
+        values
+    }
+
+    /// All the descendants of the value at prefix (the whole tree for ""), keyed by their path, intermediate nodes included
+    /// Example: get_subtree_map("content") => {"content.title": .., "content.tags": [..], "content.tags.0": .., ...}
+    pub fn get_subtree_map<'b>(&'b self, prefix: &str) -> HashMap<String, &'b Value> {
+        let mut map = HashMap::new();
+        let Some(top) = (if prefix.is_empty() { Some(&self.root) } else { self.get(prefix) }) else {
+            return map;
+        };
+        let path_prefix = if prefix.is_empty() { String::new() } else { format!("{prefix}.") };
+        // Explicit stack instead of recursion, as in as_string_values_map
+        let mut stack: Vec<(&Value, String)> = Vec::from([(top, path_prefix)]);
+        while let Some((node, node_prefix)) = stack.pop() {
+            let mut push_child = |key: &str, child: &'b Value| {
+                let path = format!("{node_prefix}{key}");
+                map.insert(path.clone(), child);
+                stack.push((child, format!("{path}.")));
+            };
+            match node {
+                Value::Object(o) => o.iter().for_each(|(key, child)| push_child(key, child)),
+                Value::Array(a) => a.iter().enumerate().for_each(|(idx, child)| push_child(&idx.to_string(), child)),
+                _ => {},
+            }
+        }
+        if prefix.is_empty() {
+            map.retain(|key, _| !is_scratch_path(key));
+        }
+        for (path, value) in self.compression.values() {
+            if let Some(node) = map.get_mut(path) {
+                *node = value;
+            }
+        }
+        for (path, value) in self.outdated_computed() {
+            if prefix.is_empty() || path.strip_prefix(prefix).is_some_and(|rest| rest.starts_with('.')) {
+                let nested_prefix = format!("{path}.");
+                map.retain(|key, _| !key.starts_with(&nested_prefix));
+                map.insert(path.to_string(), value);
+            }
+        }
+        map
     }
 
     /// Get a list of references using a single wildcard * to collect specific data from a (nested) array
     /// Example: get_list("root_object.*.id") => `[1,2,3,...]` assuming every element of the array is an object having an id property
     pub fn get_list<'b>(&'b self, target: &str) -> Vec<&'b Value> {
+        let resolved_target = self.refs.resolve(target);
+        let target = resolved_target.as_ref();
+        self.reads.record_pattern(&self.root, target);
         let wildcard_match_indices: Vec<_> = target.match_indices("*").collect();
         match wildcard_match_indices.len() {
-            0 => match self.root.pointer(&DataCache::target_to_pointer(target)) {
+            0 => match Self::lookup(&self.root, target) {
                 // Standard usage without wildcards => return a vector of 1 or 0 elements
                 Some(found) => Vec::from([found]),
                 None => Vec::new(),
             },
             1 => {
-                let (wc_idx, _) = wildcard_match_indices.get(0).unwrap();
+                let (wc_idx, _) = wildcard_match_indices.first().unwrap();
                 let parent_array: Option<&Value> = if *wc_idx == 0 {
                     if self.root.is_array() {
                         // Actually impossible case, because with DataCache, root should never be an array.
@@ -297,7 +851,7 @@ impl DataCache {
                         None // Invalid syntax : if * is not the first character, then it is expected to be after a dot
                     } else {
                         let parent_path = &target[..*wc_idx-1];
-                        if let Some(parent_arr) = self.root.pointer(&DataCache::target_to_pointer(parent_path)) {
+                        if let Some(parent_arr) = Self::lookup(&self.root, parent_path) {
                             if parent_arr.is_array() {
                                 Some(parent_arr)
                             } else {
@@ -314,7 +868,7 @@ impl DataCache {
                         // This unwrap is safe because parent_array is always confirmed to be an array to become Some during construction
                         let parent_arr: &'b Vec<Value> = arr.as_array().unwrap();
 
-                        if suffix.len() == 0 {
+                        if suffix.is_empty() {
                             // Wildcard is the end => the parent itself, owned
                             parent_arr.iter().collect::<Vec<&'b Value>>()
                         } else if suffix.len() == 1 || &target[*wc_idx+1..*wc_idx+2] != "." {
@@ -325,7 +879,7 @@ impl DataCache {
                             let suffix = &suffix[1..]; // Without the following dot
                             parent_arr.iter().map(|el| {
                                 // We should not filter_map to preserve element count property of the wildcard on the parent
-                                el.pointer(&DataCache::target_to_pointer(suffix)).unwrap_or(&Value::Null)
+                                Self::lookup(el, suffix).unwrap_or(&Value::Null)
                             }).collect::<Vec<&'b Value>>()
                         }
                     },
@@ -340,21 +894,45 @@ impl DataCache {
         }
     }
 
+    /// Concrete paths & values of the nodes matching a pattern, where * matches any single key or array index and ** any
+    /// number of them, in tree order (see PathPattern). Unlike get_list, nodes lacking the rest of the pattern are skipped
+    /// Example: get_all("items.*.title") => [("items.0.title", <title>), ("items.1.title", <title>)]
+    pub fn get_all<'b>(&'b self, pattern: &str) -> Vec<(String, &'b Value)> {
+        let resolved_pattern = self.refs.resolve(pattern);
+        self.reads.record_pattern(&self.root, &resolved_pattern);
+        let pattern = PathPattern::from(resolved_pattern.as_ref());
+        let mut nodes: Vec<(String, &Value)> = pattern
+            .matching_nodes(&self.root)
+            .into_iter()
+            .map(|(path, value)| {
+                let value = self.compression.get(&path).unwrap_or(value);
+                (path, value)
+            })
+            .collect();
+        // Outdated computed paths, which the tree may not hold yet
+        for (path, value) in self.outdated_computed().filter(|(path, _)| pattern.is_match(path)) {
+            match nodes.iter_mut().find(|(node_path, _)| node_path == path) {
+                Some(node) => node.1 = value,
+                None => nodes.push((path.to_string(), value)),
+            }
+        }
+        nodes
+    }
+
     /// Match a pattern while storing captured named capture groups in data_cache
+    #[cfg(feature = "regex")]
     pub fn match_regex(&mut self, regex: &str, source: &str) -> Result<bool, JsonDataCacheError> {
         match Regex::new(regex) {
             Ok(re) => {
                 match re.captures(source) {
                     Some(captures) => {
-                        for name_opt in re.capture_names() {
-                            if let Some(name) = name_opt {
-                                if self.options.reserved_cache_top_level_names.iter().map(|s| s.as_str()).any(|i| i == name) {
-                                    return Err(format!("Capturing into the reserved variable {name} is not allowed").into());
-                                }
-                                if let Some(matched) = captures.name(name) {
-                                    // Named capture detected => insert into data_cache
-                                    self.insert(name, Value::String(matched.as_str().to_owned()));
-                                }
+                        for name in re.capture_names().flatten() {
+                            if self.options.reserved_cache_top_level_names.iter().map(|s| s.as_str()).any(|i| i == name) {
+                                return Err(format!("Capturing into the reserved variable {name} is not allowed").into());
+                            }
+                            if let Some(matched) = captures.name(name) {
+                                // Named capture detected => insert into data_cache
+                                self.insert(name, Value::String(matched.as_str().to_owned()));
                             }
                         }
                         Ok(true) // Matched
@@ -365,58 +943,4 @@ impl DataCache {
             Err(_) => Err(format!("Invalid regex {}", regex).into()),
         }
     }
-
-    /// Performs replacements of {$key} into mapped values from data_cache if key exists
-    /// It uses Aho-Corasick algorithm for efficient multi-replacement, and works on streams (Vec<u8> does work, too)
-    pub fn replace_with_data_cache<R, W>(
-        &mut self,
-        reader: R,
-        writer: W
-    ) -> Result<(), JsonDataCacheError>
-    where
-        R: io::Read,
-        W: io::Write,
-    {
-        if !self.serialized_data.is_built {
-            // Rebuild serialized data
-            let (serialized, double_serialized) = JsonSerializer::serialize(&self.root, true);
-
-            self.serialized_data.serialized = Some(serialized);
-            self.serialized_data.double_serialized = double_serialized;
-
-            // Build AC
-            let mut keys_count = self.serialized_data.serialized.as_ref().unwrap().key_values.len();
-            if let Some(double_serialized) = self.serialized_data.double_serialized.as_ref() {
-                keys_count += double_serialized.key_values.len();
-            }
-            let mut patterns: Vec<String> = Vec::with_capacity(keys_count);
-            let mut replacements: Vec<Rc<[u8]>> = Vec::with_capacity(keys_count);
-
-            for (key, range) in &self.serialized_data.serialized.as_ref().unwrap().key_values {
-                let formatted_key = format!("{{${key}}}");
-                patterns.push(formatted_key);
-
-                let actual_value = &self.serialized_data.serialized.as_ref().unwrap().data[range.start..range.end];
-                replacements.push(actual_value.into());
-            }
-            if let Some(double_serialized) = self.serialized_data.double_serialized.as_ref() {
-                for (key, range) in &double_serialized.key_values {
-                    let formatted_key = format!("{{$${key}}}");
-                    patterns.push(formatted_key);
-
-                    let actual_value = &double_serialized.data[range.start..range.end];
-                    replacements.push(actual_value.into());
-                }
-            }
-
-            self.serialized_data.ac = Some(AhoCorasick::new(patterns)?);
-            self.serialized_data.replacements = replacements;
-            self.serialized_data.is_built = true;
-        }
-
-        let ac = self.serialized_data.ac.as_ref().unwrap();
-
-        ac.try_stream_replace_all(reader, writer, &self.serialized_data.replacements)?;
-        Ok(())
-    }
 }
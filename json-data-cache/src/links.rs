@@ -0,0 +1,167 @@
+//! Link rewriting: LinkRules rewrite the URLs of the href & src attributes while the HTML streams through an HtmlRewriter,
+//! like pointing the links of a production bundle to a staging domain, prefixing internal links with the locale of the
+//! render or appending tracking parameters. Rules are built per render (see DataCache::replace_with_links), parameter
+//! values possibly coming from the cache.
+
+#[cfg(feature = "replace-engine")]
+use std::io;
+
+use serde_json::Value;
+
+use crate::{DataCache, fetcher::percent_encode, html_rewriter::RewriteRule};
+#[cfg(feature = "replace-engine")]
+use crate::{error::JsonDataCacheError, html_rewriter::HtmlRewriter};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum LinkRule {
+    SwapDomain { from: String, to: String },
+    PrefixLocale(String),
+    AppendParameter { name: String, value: String },
+    AppendParameterFrom { name: String, path: String }, // Value at path, resolved by LinkRules::resolve
+}
+
+/// URL rewrites, applied in order. Domain swaps apply to the href & src attributes of every element (scripts, images,
+/// stylesheets...), locale prefixes & parameters to the links of <a> & <area> elements only. Fragments, mailto:, tel:,
+/// javascript: & data: URLs are kept as they are
+/// Example: LinkRules::default().swap_domain("www.example.com", "staging.example.com").prefix_locale("ja")
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LinkRules {
+    rules: Vec<LinkRule>,
+}
+
+impl LinkRules {
+    /// Replaces the host of absolute & protocol-relative URLs to the from host (ignoring case), keeping their scheme & port
+    pub fn swap_domain(mut self, from: &str, to: &str) -> Self {
+        self.rules.push(LinkRule::SwapDomain { from: from.to_ascii_lowercase(), to: to.to_string() });
+        self
+    }
+
+    /// Prefixes the root-relative links (like /news/1 => /ja/news/1), unless already prefixed by the locale
+    pub fn prefix_locale(mut self, locale: &str) -> Self {
+        self.rules.push(LinkRule::PrefixLocale(locale.trim_matches('/').to_string()));
+        self
+    }
+
+    /// Appends the query parameter (percent-encoded) to the links not already having it
+    pub fn append_parameter(mut self, name: &str, value: &str) -> Self {
+        self.rules.push(LinkRule::AppendParameter { name: name.to_string(), value: value.to_string() });
+        self
+    }
+
+    /// Same as append_parameter, the value being the one at path in the cache of the render (skipped without value)
+    pub fn append_parameter_from(mut self, name: &str, path: &str) -> Self {
+        self.rules.push(LinkRule::AppendParameterFrom { name: name.to_string(), path: path.to_string() });
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// Same rules, the parameters values from paths being those of the cache
+    pub fn resolve(&self, data_cache: &DataCache) -> Self {
+        let rules = self.rules.iter().filter_map(|rule| match rule {
+            LinkRule::AppendParameterFrom { name, path } => {
+                let value = match data_cache.get(path)? {
+                    Value::String(s) => s.clone(),
+                    Value::Null | Value::Array(_) | Value::Object(_) => return None,
+                    value => value.to_string(),
+                };
+                Some(LinkRule::AppendParameter { name: name.clone(), value })
+            },
+            rule => Some(rule.clone()),
+        }).collect();
+        Self { rules }
+    }
+
+    /// The rewrite rule applying these rules to every element, for an HtmlRewriter or a TransformProfile
+    pub fn rewrite_rule(&self) -> RewriteRule {
+        RewriteRule::new("*").rewrite_links(self.clone())
+    }
+
+    /// The URL of an attribute value (HTML-escaped, like a=1&amp;b=2) rewritten, None if unchanged. is_link is true for
+    /// the href of <a> & <area> elements
+    pub fn rewrite(&self, url: &str, is_link: bool) -> Option<String> {
+        let trimmed = url.trim();
+        let scheme = trimmed.split_once(':')
+            .map(|(scheme, _)| scheme.to_ascii_lowercase())
+            .filter(|scheme| scheme.bytes().all(|b| b.is_ascii_alphanumeric() || matches!(b, b'+' | b'-' | b'.')));
+        if trimmed.is_empty() || trimmed.starts_with('#') || scheme.as_deref().is_some_and(|scheme| !matches!(scheme, "http" | "https")) {
+            return None;
+        }
+        let mut rewritten = trimmed.to_string();
+        for rule in &self.rules {
+            match rule {
+                LinkRule::SwapDomain { from, to } => {
+                    let Some((host_start, host_end)) = host_range(&rewritten) else {
+                        continue;
+                    };
+                    let host = &rewritten[host_start..host_end];
+                    let host_len = host.find(':').unwrap_or(host.len()); // Without the port
+                    if host[..host_len].eq_ignore_ascii_case(from) {
+                        rewritten.replace_range(host_start..host_start + host_len, to);
+                    }
+                },
+                LinkRule::PrefixLocale(locale) if is_link && !locale.is_empty() => {
+                    if !rewritten.starts_with('/') || rewritten.starts_with("//") {
+                        continue;
+                    }
+                    let is_prefixed = rewritten[1..].strip_prefix(locale.as_str())
+                        .is_some_and(|rest| rest.is_empty() || rest.starts_with(['/', '?', '#']));
+                    if !is_prefixed {
+                        rewritten.insert_str(0, &format!("/{locale}"));
+                    }
+                },
+                LinkRule::AppendParameter { name, value } if is_link => append_parameter(&mut rewritten, name, value),
+                _ => {},
+            }
+        }
+        (rewritten != trimmed).then_some(rewritten)
+    }
+}
+
+/// Byte range of the host (with its port) of an absolute or protocol-relative URL
+fn host_range(url: &str) -> Option<(usize, usize)> {
+    let start = match url.find("//") {
+        Some(0) => 2,
+        Some(idx) if url[..idx].ends_with(':') && !url[..idx - 1].contains(['/', '?', '#']) => idx + 2,
+        _ => return None,
+    };
+    let len = url[start..].find(['/', '?', '#']).unwrap_or(url.len() - start);
+    let host = &url[start..start + len];
+    let host_start = host.rfind('@').map_or(start, |idx| start + idx + 1); // Without user info
+    Some((host_start, start + len))
+}
+
+/// Adds the query parameter before the fragment, unless the query already has it
+fn append_parameter(url: &mut String, name: &str, value: &str) {
+    let fragment_start = url.find('#').unwrap_or(url.len());
+    let query = url[..fragment_start].split_once('?').map(|(_, query)| query);
+    let has_parameter = |query: &str| query.split('&').any(|pair| pair.trim_start_matches("amp;").split('=').next() == Some(name));
+    if query.is_some_and(has_parameter) {
+        return;
+    }
+    let mut parameter = String::from(match query {
+        Some("") => "",
+        Some(_) => "&amp;",
+        None => "?",
+    });
+    percent_encode(name, &mut parameter);
+    parameter.push('=');
+    percent_encode(value, &mut parameter);
+    url.insert_str(fragment_start, &parameter);
+}
+
+impl DataCache {
+    /// Same as replace_with_data_cache, the URLs of the output being rewritten by the rules (resolved with this cache)
+    #[cfg(feature = "replace-engine")]
+    pub fn replace_with_links<R, W>(&mut self, links: &LinkRules, reader: R, writer: W) -> Result<W, JsonDataCacheError>
+    where
+        R: io::Read,
+        W: io::Write,
+    {
+        let mut rewriter = HtmlRewriter::new(writer, Vec::from([links.resolve(self).rewrite_rule()]));
+        self.replace_with_data_cache(reader, &mut rewriter)?;
+        Ok(rewriter.finish()?)
+    }
+}
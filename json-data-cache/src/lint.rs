@@ -0,0 +1,143 @@
+//! Static checks of templates against a cache or a schema (see the mock module for the supported subset of JSON Schema),
+//! for CI through the lint-template command of kuroco-edge-cache.
+//! Placeholders have no filters but safe_redirect (see the redirect module) nor conditional blocks: {$key|date} is
+//! reported as a filter that is never applied, and display formats are checked through the renderers of the cache (see
+//! set_renderer), which may reject a value.
+
+use std::fmt;
+
+use serde_json::Value;
+
+#[cfg(feature = "replace-engine")]
+use crate::path_pattern::PathPattern;
+use crate::{DataCache, redirect::SAFE_REDIRECT_FILTER};
+
+/// A {$key} or {$$key} placeholder of a template, at its 1-based line & column
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TemplatePlaceholder<'a> {
+    pub line: usize,
+    pub column: usize,
+    pub key: &'a str,
+    pub is_double: bool, // {$$key}
+}
+
+/// Lists the placeholders of a template. Candidates without a closing brace on their line, empty or containing whitespace are skipped
+pub fn find_placeholders(template: &str) -> Vec<TemplatePlaceholder<'_>> {
+    let mut placeholders = Vec::new();
+    for (line_idx, line) in template.lines().enumerate() {
+        let mut offset = 0;
+        while let Some(start) = line[offset..].find("{$") {
+            let start = offset + start;
+            let is_double = line[start + 2..].starts_with('$');
+            let key_start = if is_double { start + 3 } else { start + 2 };
+            match line[key_start..].find('}') {
+                Some(len) if len > 0 && !line[key_start..key_start + len].contains(char::is_whitespace) => {
+                    placeholders.push(TemplatePlaceholder { line: line_idx + 1, column: start + 1, key: &line[key_start..key_start + len], is_double });
+                    offset = key_start + len + 1;
+                },
+                _ => offset = start + 2,
+            }
+        }
+    }
+    placeholders
+}
+
+/// What templates are checked against
+#[derive(Debug, Clone, Copy)]
+pub enum LintSource<'a> {
+    Cache(&'a DataCache),
+    Schema(&'a Value),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LintIssue {
+    UnknownPath, // Neither in the cache nor in the schema, the placeholder is left as is
+    UnsupportedFilter(String), // {$key|filter}, left as is
+    RejectedByRenderer(&'static str), // Type of the value the renderer of the key does not render, whose serialization is written instead
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LintDiagnostic {
+    pub line: usize,
+    pub column: usize,
+    pub key: String,
+    pub issue: LintIssue,
+}
+
+impl fmt::Display for LintDiagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}: ", self.line, self.column)?;
+        match &self.issue {
+            LintIssue::UnknownPath => write!(f, "unknown path {}", self.key),
+            LintIssue::UnsupportedFilter(filter) => write!(f, "unsupported filter {filter} on {}", self.key),
+            LintIssue::RejectedByRenderer(value_type) => write!(f, "the renderer of {} does not render its {value_type} value", self.key),
+        }
+    }
+}
+
+#[cfg(feature = "replace-engine")]
+fn value_type(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+/// Schema of the dotted path: properties of objects (or additionalProperties), items of arrays for numeric segments
+pub fn schema_at<'a>(schema: &'a Value, path: &str) -> Option<&'a Value> {
+    let mut schema = schema;
+    for segment in path.split('.').filter(|segment| !segment.is_empty()) {
+        schema = match schema.get("type").and_then(Value::as_str).unwrap_or("object") {
+            "object" => schema.get("properties").and_then(|properties| properties.get(segment))
+                .or_else(|| schema.get("additionalProperties").filter(|additional| additional.is_object()))?,
+            "array" if segment.parse::<usize>().is_ok() => schema.get("items")?,
+            _ => return None,
+        };
+    }
+    Some(schema)
+}
+
+/// Lists the issues of the placeholders of the template, in template order
+pub fn lint_template(template: &str, source: LintSource) -> Vec<LintDiagnostic> {
+    let mut diagnostics = Vec::new();
+    for placeholder in find_placeholders(template) {
+        let issue = if let Some((key, filter)) = placeholder.key.split_once('|') {
+            match source {
+                #[cfg(feature = "replace-engine")]
+                LintSource::Cache(data_cache) if filter == SAFE_REDIRECT_FILTER && !placeholder.is_double => {
+                    let is_covered = data_cache.options().safe_redirect_keys.iter().any(|pattern| PathPattern::from(pattern).covers(key));
+                    if is_covered {
+                        data_cache.get(key).is_none().then_some((key, LintIssue::UnknownPath))
+                    } else {
+                        Some((key, LintIssue::UnsupportedFilter(filter.to_string()))) // Left as is
+                    }
+                },
+                LintSource::Schema(schema) if filter == SAFE_REDIRECT_FILTER && !placeholder.is_double => {
+                    schema_at(schema, key).is_none().then_some((key, LintIssue::UnknownPath))
+                },
+                _ => Some((key, LintIssue::UnsupportedFilter(filter.to_string()))),
+            }
+        } else {
+            let key = placeholder.key;
+            match source {
+                LintSource::Schema(schema) => schema_at(schema, key).is_none().then_some((key, LintIssue::UnknownPath)),
+                LintSource::Cache(data_cache) => match data_cache.get(key) {
+                    None => Some((key, LintIssue::UnknownPath)),
+                    #[cfg(feature = "replace-engine")]
+                    Some(value) if !placeholder.is_double && data_cache.renderers.rejects(key, value, data_cache.render_context()) => {
+                        Some((key, LintIssue::RejectedByRenderer(value_type(value))))
+                    },
+                    Some(_) => None,
+                },
+            }
+        };
+        if let Some((key, issue)) = issue {
+            diagnostics.push(LintDiagnostic { line: placeholder.line, column: placeholder.column, key: key.to_string(), issue });
+        }
+    }
+    diagnostics
+}
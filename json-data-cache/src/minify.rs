@@ -0,0 +1,203 @@
+//! Whitespace minification of HTML, applied by replacements to the bytes of templates as they stream through (see
+//! DataCacheOptions::minify), instead of a second pass over the whole body. Runs of whitespace are collapsed to a single
+//! space, or newline if they hold one, in text, between attributes & in CSS outside of its strings & comments. The content
+//! of pre, textarea & script elements (whose whitespace is significant, or JavaScript template literals may hold) and
+//! comments is kept as is, as are attribute values. Replaced values are not minified.
+
+use std::io;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum State {
+    Text,
+    TagName, // After <, collecting the name
+    Tag(Option<u8>), // Attributes, with the quote of the current value
+    Comment,
+    Raw, // Content of pre, textarea & script, until its closing tag
+    Style(Option<u8>), // CSS, with the quote of the current string
+    StyleComment,
+}
+
+/// Elements whose content is kept as is
+const RAW_ELEMENTS: [&[u8]; 3] = [b"pre", b"textarea", b"script"];
+
+/// Longest tag name collected, longer ones being none of the elements handled
+const MAX_NAME_LEN: usize = 16;
+
+/// Streaming minifier, keeping its state between writes
+#[derive(Debug)]
+pub(crate) struct Minifier {
+    state: State,
+    name: Vec<u8>, // Lowercase name of the current tag, or of the element whose closing tag is searched
+    matched: usize, // Bytes of the closing tag (or dashes of the comment end) matched so far
+    pending_space: Option<u8>, // Collapsed whitespace, written before the next byte
+    previous: u8,
+    is_escaped: bool, // After a backslash of a CSS string
+    output: Vec<u8>, // Bytes of the current write
+}
+
+impl Default for Minifier {
+    fn default() -> Self {
+        Self { state: State::Text, name: Vec::new(), matched: 0, pending_space: None, previous: 0, is_escaped: false, output: Vec::new() }
+    }
+}
+
+impl Minifier {
+    /// Writes the minified bytes, whitespace ending them being held until the next write or flush_pending
+    pub(crate) fn write<W: io::Write>(&mut self, writer: &mut W, bytes: &[u8]) -> io::Result<()> {
+        self.output.clear();
+        for &byte in bytes {
+            self.push(byte);
+        }
+        writer.write_all(&self.output)
+    }
+
+    /// Writes the whitespace held, before a replaced value or at the end
+    pub(crate) fn flush_pending<W: io::Write>(&mut self, writer: &mut W) -> io::Result<()> {
+        match self.pending_space.take() {
+            Some(space) => writer.write_all(&[space]),
+            None => Ok(()),
+        }
+    }
+
+    fn collapse(&mut self, byte: u8) {
+        let is_newline = matches!(byte, b'\n' | b'\r') || self.pending_space == Some(b'\n');
+        self.pending_space = Some(if is_newline { b'\n' } else { b' ' });
+    }
+
+    fn keep(&mut self, byte: u8) {
+        if let Some(space) = self.pending_space.take() {
+            self.output.push(space);
+        }
+        self.output.push(byte);
+    }
+
+    /// Matches the closing tag of the current element, returning true once complete
+    fn match_closing(&mut self, byte: u8) -> bool {
+        let byte = byte.to_ascii_lowercase();
+        let expected = match self.matched {
+            0 => b'<',
+            1 => b'/',
+            idx => self.name[idx - 2],
+        };
+        if byte == expected {
+            self.matched += 1;
+        } else {
+            self.matched = usize::from(byte == b'<');
+        }
+        self.matched == self.name.len() + 2
+    }
+
+    fn end_tag(&mut self) {
+        self.state = if RAW_ELEMENTS.contains(&self.name.as_slice()) {
+            State::Raw
+        } else if self.name == b"style" {
+            State::Style(None)
+        } else {
+            State::Text
+        };
+        self.matched = 0;
+        self.is_escaped = false;
+    }
+
+    fn push(&mut self, byte: u8) {
+        let is_space = matches!(byte, b' ' | b'\t' | b'\n' | b'\r' | b'\x0c');
+        let state = self.state;
+        match state {
+            State::Text if is_space => self.collapse(byte),
+            State::Text => {
+                if byte == b'<' {
+                    self.state = State::TagName;
+                    self.name.clear();
+                }
+                self.keep(byte);
+            },
+            State::TagName => {
+                if self.name.is_empty() && !(byte.is_ascii_alphabetic() || matches!(byte, b'!' | b'/' | b'?')) {
+                    // Not a tag, like "a < b"
+                    self.state = State::Text;
+                    return self.push(byte);
+                }
+                if byte.is_ascii_alphanumeric() || matches!(byte, b'!' | b'/' | b'-' | b'?') {
+                    if self.name.len() < MAX_NAME_LEN {
+                        self.name.push(byte.to_ascii_lowercase());
+                    }
+                    self.keep(byte);
+                    if self.name == b"!--" {
+                        self.state = State::Comment;
+                        self.matched = 0;
+                    }
+                    return;
+                }
+                self.state = State::Tag(None);
+                self.push(byte);
+            },
+            State::Tag(None) if is_space => self.collapse(byte),
+            State::Tag(None) => {
+                if byte == b'>' {
+                    self.pending_space = None;
+                    self.end_tag();
+                } else if matches!(byte, b'"' | b'\'') {
+                    self.state = State::Tag(Some(byte));
+                }
+                self.keep(byte);
+            },
+            State::Tag(Some(quote)) => {
+                if byte == quote {
+                    self.state = State::Tag(None);
+                }
+                self.keep(byte);
+            },
+            State::Comment => {
+                if byte == b'>' && self.matched >= 2 {
+                    self.state = State::Text;
+                }
+                self.matched = if byte == b'-' { self.matched + 1 } else { 0 };
+                self.keep(byte);
+            },
+            State::Raw | State::Style(_) | State::StyleComment if self.match_closing(byte) => {
+                self.keep(byte);
+                self.name.insert(0, b'/');
+                self.state = State::TagName;
+            },
+            State::Raw => self.keep(byte),
+            State::Style(None) if is_space => self.collapse(byte),
+            State::Style(None) => {
+                if matches!(byte, b'"' | b'\'') {
+                    self.state = State::Style(Some(byte));
+                } else if byte == b'*' && self.previous == b'/' {
+                    self.state = State::StyleComment;
+                    self.keep(byte);
+                    self.previous = 0; // Not closing /*/
+                    return;
+                }
+                self.keep(byte);
+            },
+            State::Style(Some(quote)) => {
+                if self.is_escaped {
+                    self.is_escaped = false;
+                } else if byte == b'\\' {
+                    self.is_escaped = true;
+                } else if byte == quote {
+                    self.state = State::Style(None);
+                }
+                self.keep(byte);
+            },
+            State::StyleComment => {
+                if byte == b'/' && self.previous == b'*' {
+                    self.state = State::Style(None);
+                }
+                self.keep(byte);
+            },
+        }
+        self.previous = byte;
+    }
+}
+
+/// Minified HTML, as written by replacements with DataCacheOptions::minify
+pub fn minify(html: &[u8]) -> Vec<u8> {
+    let mut minifier = Minifier::default();
+    let mut output = Vec::with_capacity(html.len());
+    // Writes to a Vec never fail
+    let _ = minifier.write(&mut output, html).and_then(|_| minifier.flush_pending(&mut output));
+    output
+}
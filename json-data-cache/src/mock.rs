@@ -0,0 +1,224 @@
+//! Deterministic fake data matching a schema, to develop & load-test templates before the real API exists.
+//!
+//! Schemas are a subset of JSON Schema: `type` (object, array, string, integer, number, boolean, null), `properties`, `items`,
+//! `minItems` / `maxItems`, `minimum` / `maximum`, `enum` & `const`. String `format`s select the kind of fake value:
+//! name, first_name, last_name, email, date, date-time, url, image_url, title & text (the default).
+//! `locale: "ja"` generates Japanese names & text, for the schema and its descendants.
+
+use serde_json::{Map, Value, json};
+
+use crate::{DataCache, DataCacheOptions, error::JsonDataCacheError};
+
+const FIRST_NAMES: &[&str] = &["Emma", "Liam", "Olivia", "Noah", "Ava", "Lucas", "Mia", "Ethan", "Sophia", "Leo"];
+const LAST_NAMES: &[&str] = &["Smith", "Johnson", "Brown", "Garcia", "Miller", "Davis", "Martin", "Wilson", "Moore", "Clark"];
+const JA_FIRST_NAMES: &[&str] = &["太郎", "花子", "翔太", "美咲", "大輔", "陽菜", "健太", "さくら", "蓮", "結衣"];
+const JA_LAST_NAMES: &[&str] = &["佐藤", "鈴木", "高橋", "田中", "伊藤", "渡辺", "山本", "中村", "小林", "加藤"];
+const WORDS: &[&str] = &[
+    "lorem", "ipsum", "dolor", "sit", "amet", "consectetur", "adipiscing", "elit", "sed", "do", "eiusmod", "tempor",
+    "incididunt", "ut", "labore", "et", "dolore", "magna", "aliqua", "enim", "minim", "veniam", "quis", "nostrud"
+];
+const JA_PHRASES: &[&str] = &[
+    "新しい", "お知らせ", "サービス", "について", "本日は", "キャンペーン", "開催中", "詳しくは", "こちら", "ご覧ください",
+    "イベント", "情報", "最新の", "商品", "紹介"
+];
+
+/// Default count of items of arrays without minItems / maxItems
+const DEFAULT_ARRAY_LEN: (u64, u64) = (1, 5);
+
+/// Nesting beyond which generation fails, as schemas may be recursive by mistake
+const MAX_SCHEMA_DEPTH: usize = 64;
+
+/// xorshift64* : small, fast & identical on every platform, which is all fake data needs
+#[derive(Debug, Clone)]
+pub struct MockGenerator {
+    state: u64,
+}
+
+impl MockGenerator {
+    pub fn new(seed: u64) -> Self {
+        // splitmix64 of the seed, so that close seeds give unrelated sequences. A zero state would only produce zeros
+        let mut state = seed.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        state = (state ^ (state >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        state = (state ^ (state >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        Self { state: (state ^ (state >> 31)).max(1) }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.state ^= self.state >> 12;
+        self.state ^= self.state << 25;
+        self.state ^= self.state >> 27;
+        self.state.wrapping_mul(0x2545_f491_4f6c_dd1d)
+    }
+
+    /// In min..=max
+    fn range(&mut self, min: u64, max: u64) -> u64 {
+        if max <= min {
+            return min;
+        }
+        min + self.next_u64() % (max - min + 1)
+    }
+
+    fn pick<'a>(&mut self, values: &[&'a str]) -> &'a str {
+        values[self.range(0, values.len() as u64 - 1) as usize]
+    }
+
+    /// Generates a value matching the schema
+    pub fn generate(&mut self, schema: &Value) -> Result<Value, JsonDataCacheError> {
+        self.generate_rec(schema, false, 0)
+    }
+
+    fn generate_rec(&mut self, schema: &Value, is_ja: bool, depth: usize) -> Result<Value, JsonDataCacheError> {
+        if depth > MAX_SCHEMA_DEPTH {
+            return Err(format!("Mock schema nested deeper than {MAX_SCHEMA_DEPTH}").into());
+        }
+        let schema = schema.as_object().ok_or(format!("Invalid mock schema {schema}"))?;
+        let is_ja = schema.get("locale").and_then(Value::as_str).map(|locale| locale.starts_with("ja")).unwrap_or(is_ja);
+        if let Some(value) = schema.get("const") {
+            return Ok(value.clone());
+        }
+        if let Some(values) = schema.get("enum").and_then(Value::as_array).filter(|values| !values.is_empty()) {
+            return Ok(values[self.range(0, values.len() as u64 - 1) as usize].clone());
+        }
+        let number = |key: &str| schema.get(key).and_then(Value::as_f64);
+        let value = match schema.get("type").and_then(Value::as_str).unwrap_or("string") {
+            "object" => {
+                let mut object = Map::new();
+                if let Some(properties) = schema.get("properties").and_then(Value::as_object) {
+                    for (key, property_schema) in properties {
+                        object.insert(key.clone(), self.generate_rec(property_schema, is_ja, depth + 1)?);
+                    }
+                }
+                Value::Object(object)
+            },
+            "array" => {
+                let min = schema.get("minItems").and_then(Value::as_u64).unwrap_or(DEFAULT_ARRAY_LEN.0);
+                let max = schema.get("maxItems").and_then(Value::as_u64).unwrap_or(DEFAULT_ARRAY_LEN.1.max(min));
+                let items_schema = schema.get("items").cloned().unwrap_or(json!({}));
+                let len = self.range(min, max);
+                Value::Array((0..len).map(|_| self.generate_rec(&items_schema, is_ja, depth + 1)).collect::<Result<_, _>>()?)
+            },
+            "integer" => {
+                let min = number("minimum").unwrap_or(0.0) as i64;
+                let max = number("maximum").unwrap_or(1000.0) as i64;
+                json!(min + self.range(0, max.saturating_sub(min).max(0) as u64) as i64)
+            },
+            "number" => {
+                let min = number("minimum").unwrap_or(0.0);
+                let max = number("maximum").unwrap_or(1000.0);
+                let fraction = self.range(0, 10_000) as f64 / 10_000.0;
+                json!(((min + (max - min) * fraction) * 100.0).round() / 100.0)
+            },
+            "boolean" => json!(self.range(0, 1) == 1),
+            "null" => Value::Null,
+            "string" => Value::String(self.string(schema.get("format").and_then(Value::as_str).unwrap_or("text"), is_ja)),
+            other => return Err(format!("Unsupported mock schema type {other}").into()),
+        };
+        Ok(value)
+    }
+
+    fn string(&mut self, format: &str, is_ja: bool) -> String {
+        let (first_names, last_names) = if is_ja { (JA_FIRST_NAMES, JA_LAST_NAMES) } else { (FIRST_NAMES, LAST_NAMES) };
+        match format {
+            "first_name" => self.pick(first_names).to_string(),
+            "last_name" => self.pick(last_names).to_string(),
+            "name" if is_ja => format!("{} {}", self.pick(last_names), self.pick(first_names)),
+            "name" => format!("{} {}", self.pick(first_names), self.pick(last_names)),
+            "email" => format!("{}.{}{}@example.com", self.pick(FIRST_NAMES), self.pick(LAST_NAMES), self.range(1, 99)).to_lowercase(),
+            "date" => {
+                let (year, month, day) = civil_from_days(self.range(0, 5 * 365) as i64 + DAYS_TO_2020);
+                format!("{year:04}-{month:02}-{day:02}")
+            },
+            "date-time" => {
+                let (year, month, day) = civil_from_days(self.range(0, 5 * 365) as i64 + DAYS_TO_2020);
+                let seconds = self.range(0, 86_399);
+                format!("{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}+09:00", seconds / 3600, seconds / 60 % 60, seconds % 60)
+            },
+            "url" => format!("https://example.com/{}/{}", self.pick(WORDS), self.range(1, 9999)),
+            "image_url" => format!("https://picsum.photos/seed/{}/800/600", self.range(1, 99_999)),
+            "title" => self.words(is_ja, 2, 5),
+            _ => self.words(is_ja, 8, 20),
+        }
+    }
+
+    fn words(&mut self, is_ja: bool, min: u64, max: u64) -> String {
+        let count = self.range(min, max);
+        let words: Vec<&str> = (0..count).map(|_| self.pick(if is_ja { JA_PHRASES } else { WORDS })).collect();
+        if is_ja {
+            format!("{}。", words.concat())
+        } else {
+            let sentence = words.join(" ");
+            let mut chars = sentence.chars();
+            chars.next().map(|first| first.to_uppercase().chain(chars).collect()).unwrap_or_default()
+        }
+    }
+}
+
+/// Days from 1970-01-01 to 2020-01-01
+const DAYS_TO_2020: i64 = 18_262;
+
+/// Gregorian date of a count of days since the Unix epoch (Howard Hinnant's algorithm)
+pub(crate) fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * month_index + 2) / 5 + 1) as u32;
+    let month = if month_index < 10 { month_index + 3 } else { month_index - 9 } as u32;
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+/// Unix timestamp of "YYYY-MM-DDTHH:MM:SS[.fraction](Z|±HH:MM)", the fraction being dropped
+pub(crate) fn parse_rfc3339(s: &str) -> Option<i64> {
+    let bytes = s.as_bytes();
+    if bytes.len() < 20 || bytes[4] != b'-' || bytes[7] != b'-' || !matches!(bytes[10], b'T' | b't' | b' ') || bytes[13] != b':' || bytes[16] != b':' {
+        return None;
+    }
+    let number = |range: std::ops::Range<usize>| -> Option<i64> {
+        s.get(range).filter(|part| part.bytes().all(|b| b.is_ascii_digit()))?.parse().ok()
+    };
+    let (year, month, day) = (number(0..4)?, number(5..7)?, number(8..10)?);
+    let (hour, minute, second) = (number(11..13)?, number(14..16)?, number(17..19)?);
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) || hour > 23 || minute > 59 || second > 60 {
+        return None;
+    }
+    let mut zone = &s[19..];
+    if let Some(fraction) = zone.strip_prefix('.') {
+        zone = fraction.trim_start_matches(|c: char| c.is_ascii_digit());
+    }
+    let offset = match zone.as_bytes() {
+        [b'Z' | b'z'] => 0,
+        [sign @ (b'+' | b'-'), ..] if zone.len() == 6 && zone.as_bytes()[3] == b':' => {
+            let offset = zone[1..3].parse::<i64>().ok()? * 3600 + zone[4..6].parse::<i64>().ok()? * 60;
+            if *sign == b'-' { -offset } else { offset }
+        },
+        _ => return None,
+    };
+    Some(days_from_civil(year, month, day) * 86_400 + hour * 3600 + minute * 60 + second - offset)
+}
+
+/// Count of days since the Unix epoch of a Gregorian date (inverse of civil_from_days)
+pub(crate) fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let day_of_year = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+impl DataCache {
+    /// Builds a cache holding fake data matching the schema of an object (see the mock module). The same seed always
+    /// generates the same data
+    pub fn from_schema_mock(schema: &Value, seed: u64) -> Result<DataCache, JsonDataCacheError> {
+        let value = MockGenerator::new(seed).generate(schema)?;
+        if !value.is_object() {
+            return Err("Mock schema must describe an object".into());
+        }
+        let mut data_cache = DataCache::new(DataCacheOptions::default());
+        data_cache.try_merge(value)?;
+        Ok(data_cache)
+    }
+}
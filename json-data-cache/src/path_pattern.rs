@@ -0,0 +1,137 @@
+use std::collections::HashSet;
+
+use serde_json::Value;
+
+use crate::DataCache;
+
+/// A dotted path expression where a `*` segment matches any single key or array index, and a `**` segment any number of
+/// them (none included)
+/// Example: "content.*.title" matches "content.0.title" and "content.news.title", but not "content.title" (which
+/// "content.**.title" matches, along with "content.news.0.title")
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PathPattern {
+    segments: Vec<PathPatternSegment>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum PathPatternSegment {
+    Key(String),
+    Wildcard,
+    RecursiveWildcard,
+}
+
+impl From<&str> for PathPattern {
+    fn from(pattern: &str) -> Self {
+        let segments = pattern
+            .split('.')
+            .filter(|segment| !segment.is_empty())
+            .map(|segment| match segment {
+                "*" => PathPatternSegment::Wildcard,
+                "**" => PathPatternSegment::RecursiveWildcard,
+                key => PathPatternSegment::Key(key.to_string()),
+            })
+            .collect();
+        Self { segments }
+    }
+}
+
+impl From<&String> for PathPattern {
+    fn from(pattern: &String) -> Self {
+        pattern.as_str().into()
+    }
+}
+
+impl PathPattern {
+    /// Matches the path segments against the pattern segments. is_ancestor_allowed accepts paths ending before the
+    /// pattern (their subtree may match), is_descendant_allowed paths continuing after it (under a matching path)
+    fn match_segments(segments: &[PathPatternSegment], path: &[&str], is_ancestor_allowed: bool, is_descendant_allowed: bool) -> bool {
+        let Some((segment, rest)) = segments.split_first() else {
+            return path.is_empty() || is_descendant_allowed;
+        };
+        let Some((path_segment, path_rest)) = path.split_first() else {
+            return is_ancestor_allowed || segments.iter().all(|segment| *segment == PathPatternSegment::RecursiveWildcard);
+        };
+        match segment {
+            PathPatternSegment::Key(key) if key != path_segment => false,
+            PathPatternSegment::Key(_) | PathPatternSegment::Wildcard => {
+                Self::match_segments(rest, path_rest, is_ancestor_allowed, is_descendant_allowed)
+            },
+            PathPatternSegment::RecursiveWildcard => {
+                Self::match_segments(rest, path, is_ancestor_allowed, is_descendant_allowed)
+                    || Self::match_segments(segments, path_rest, is_ancestor_allowed, is_descendant_allowed)
+            },
+        }
+    }
+
+    /// True if the path is exactly matching the pattern
+    pub fn is_match(&self, path: &str) -> bool {
+        let path: Vec<&str> = path.split('.').collect();
+        Self::match_segments(&self.segments, &path, false, false)
+    }
+
+    /// True if the path is matching the pattern, or is a descendant of a path matching it
+    /// Example: "content.*" covers "content.list" and "content.list.0.id", but not "content" itself
+    pub fn covers(&self, path: &str) -> bool {
+        let path: Vec<&str> = path.split('.').collect();
+        Self::match_segments(&self.segments, &path, false, true)
+    }
+
+    /// True if the pattern covers the path, or the path is an ancestor of paths matching it (its subtree may contain them)
+    /// Example: "user" & "user.segment.id" overlap "user.segment", "users" does not
+    pub fn overlaps(&self, path: &str) -> bool {
+        let path_segments: Vec<&str> = path.split('.').collect();
+        path.is_empty() || Self::match_segments(&self.segments, &path_segments, true, true)
+    }
+
+    /// True if the pattern has * or ** segments
+    pub fn has_wildcards(&self) -> bool {
+        self.segments.iter().any(|segment| !matches!(segment, PathPatternSegment::Key(_)))
+    }
+
+    /// Paths of the nodes of the tree exactly matching the pattern, in tree order
+    pub(crate) fn matching_paths(&self, root: &Value) -> Vec<String> {
+        self.matching_nodes(root).into_iter().map(|(path, _)| path).collect()
+    }
+
+    /// Paths & nodes of the tree exactly matching the pattern, in tree order
+    pub(crate) fn matching_nodes<'b>(&self, root: &'b Value) -> Vec<(String, &'b Value)> {
+        if self.segments.is_empty() {
+            return Vec::new();
+        }
+        let child_path = |path: &str, key: &str| if path.is_empty() { key.to_string() } else { format!("{path}.{key}") };
+        let children = |path: &str, node: &'b Value| -> Vec<(String, &'b Value)> {
+            match node {
+                Value::Object(o) => o.iter().map(|(key, child)| (child_path(path, key), child)).collect(),
+                Value::Array(a) => a.iter().enumerate().map(|(idx, child)| (child_path(path, &idx.to_string()), child)).collect(),
+                _ => Vec::new(),
+            }
+        };
+        let mut nodes: Vec<(String, &'b Value)> = Vec::from([(String::new(), root)]);
+        for segment in &self.segments {
+            nodes = match segment {
+                PathPatternSegment::Key(key) => nodes.into_iter()
+                    .filter_map(|(path, node)| DataCache::child(node, key).map(|child| (child_path(&path, key), child)))
+                    .collect(),
+                PathPatternSegment::Wildcard => nodes.into_iter().flat_map(|(path, node)| children(&path, node)).collect(),
+                PathPatternSegment::RecursiveWildcard => {
+                    // Each node & its descendants, depth first. Explicit stack so that deep trees can not overflow the call stack
+                    let mut expanded = Vec::new();
+                    let mut seen = HashSet::new();
+                    for node in nodes {
+                        let mut stack = Vec::from([node]);
+                        while let Some((path, node)) = stack.pop() {
+                            stack.extend(children(&path, node).into_iter().rev());
+                            if seen.insert(path.clone()) {
+                                expanded.push((path, node));
+                            }
+                        }
+                    }
+                    expanded
+                },
+            };
+        }
+        // Paths reached through the root itself (like "**" alone) are not paths of the tree
+        nodes.retain(|(path, _)| !path.is_empty());
+        nodes
+    }
+}
@@ -0,0 +1,756 @@
+//! Declarative edge behavior: a pipeline is a list of steps loaded from a JSON configuration, so that routes, origins,
+//! validations & renders change by deploying a new configuration rather than the code of each worker.
+//! YAML configurations are not parsed here, convert them to JSON when deploying.
+//!
+//! ```json
+//! {"steps": [
+//!     {"match_route": {"from": "request.path", "into": "route", "required": true, "routes": [
+//!         {"pattern": "/news/:id", "name": "news"}, {"pattern": "/files/*", "name": "files"}
+//!     ]}},
+//!     {"fetch": {"path": "news", "url": "https://origin.example/news/{$route.params.id}", "ttl_secs": 60,
+//!         "headers": {"X-Api-Key": "..."}, "fallback": {"title": ""}},
+//!         "retry": {"attempts": 3, "backoff_ms": 50, "factor": 2}, "on_error": "pass_through"},
+//!     {"transform": {"into": "page.title", "from": "news.title"}, "when": "route.name == \"news\" && news.title"},
+//!     {"transform": {"into": "page.total", "expr": "cart.price * cart.count"}},
+//!     {"validate": {"data": "form", "errors": "form.errors", "halt_on_errors": false, "rules": [
+//!         {"field": "email", "rule": "required", "message": "Required"},
+//!         {"field": "age", "rule": "range", "min": 0, "max": 150, "message": "Invalid age"}
+//!     ]}},
+//!     {"render": {}, "on_error": {"error_page": "templates.error"}}
+//! ], "error_path": "pipeline.error"}
+//! ```
+//!
+//! - match_route: matches the path at from against the routes in order (`:name` segments are parameters, a last `*` segment
+//!   matches the rest), inserting {"name", "params"} at into, or null if none matches. required halts the pipeline then
+//! - fetch: declares the source (see DataSource) to the fetcher of the PipelineIo & makes sure it is fetched
+//! - transform: copies the value at from, or defines a computed path (see define_computed) from expr
+//! - validate: validates the form data (see validate_form), with the rules required, required_with & same_as (other),
+//!   length & range (min, max) and pattern (pattern, with the regex feature). halt_on_errors halts the pipeline if any fails
+//! - render: replaces the template of the PipelineIo, or the string at template_path, into its output. With vary_by (a list
+//!   of paths), the render fails if it read dynamic paths missing from it (see the vary module)
+//!
+//! Besides its kind, a step may declare how it runs & fails:
+//! - when: a Condition over the cache, the step is skipped unless it is met
+//! - retry: {"attempts", "backoff_ms", "factor"}, the step is run again on errors, waiting backoff_ms * factor^retry
+//!   through the sleep of the PipelineIo. Retries suit fetch steps, as a render step may have read or written partially
+//! - on_error: "abort" (default, the error is returned), "pass_through" (the next steps run),
+//!   {"fallback_template": path} or {"error_page": path}, rendering the template at path & ending the run.
+//!   Except with abort, {"step", "message"} is inserted at the error_path of the configuration (DEFAULT_ERROR_PATH) first,
+//!   for templates to show
+//!
+//! Pipeline::explain is a dry run for debugging endpoints: steps run against a copy of the cache, without fetches or renders,
+//! returning a PipelineTrace of the conditions, routes, requests, values & validation errors of each step. Routing rules are
+//! the routes of match_route steps and flags the when conditions of steps, traced route by route & clause by clause;
+//! rules evaluated by the code of workers outside of a pipeline are not covered (see Condition::explain for their flags)
+
+use std::{io, time::Duration};
+
+use serde_json::{Map, Value};
+
+use crate::{DataCache, computed::Expr, error::JsonDataCacheError, fetcher::{DataSource, Fetcher, HttpClient}, validation::{FormValidator, Rule}};
+#[cfg(feature = "replace-engine")]
+use crate::lint::{LintSource, lint_template};
+
+/// Default path of the error of a failed step, see Pipeline::error_path
+pub const DEFAULT_ERROR_PATH: &str = "pipeline.error";
+
+/// Keys of a step configuration besides its kind
+const POLICY_KEYS: &[&str] = &["when", "retry", "on_error"];
+
+/// Condition of a step: clauses joined by && and ||, && binding tighter, without parentheses. Clauses are `path`
+/// (a truthy value: not missing, null, false, 0, "" or empty), `!path`, `path == json` & `path != json` (a JSON literal,
+/// a missing path being null), numeric comparisons (< <= > >=) of computed path expressions like "sum(cart.*.price) >= 100",
+/// and `published(path)` & `!published(path)` (see the schedule module). Operators inside of string literals are part of them
+#[derive(Debug, Clone)]
+pub struct Condition {
+    any: Vec<Vec<(String, Clause)>>, // Clauses with their source
+}
+
+#[derive(Debug, Clone)]
+enum Clause {
+    Truthy(String),
+    Falsy(String),
+    Equals(String, Value),
+    NotEquals(String, Value),
+    Compare(Expr, &'static str, Expr),
+    Published(String),
+    NotPublished(String),
+}
+
+fn is_truthy(value: Option<&Value>) -> bool {
+    match value {
+        None | Some(Value::Null) | Some(Value::Bool(false)) => false,
+        Some(Value::Number(number)) => number.as_f64() != Some(0.0),
+        Some(Value::String(value)) => !value.is_empty(),
+        Some(Value::Array(items)) => !items.is_empty(),
+        Some(Value::Object(entries)) => !entries.is_empty(),
+        Some(Value::Bool(true)) => true,
+    }
+}
+
+/// Byte offset of the first occurrence of pattern outside of the JSON string literals of a condition
+fn find_unquoted(condition: &str, pattern: &str) -> Option<usize> {
+    let mut is_quoted = false;
+    let mut is_escaped = false;
+    for (idx, c) in condition.char_indices() {
+        if is_quoted {
+            match c {
+                _ if is_escaped => is_escaped = false,
+                '\\' => is_escaped = true,
+                '"' => is_quoted = false,
+                _ => {},
+            }
+        } else if c == '"' {
+            is_quoted = true;
+        } else if condition[idx..].starts_with(pattern) {
+            return Some(idx);
+        }
+    }
+    None
+}
+
+/// Parts of a condition between the separators outside of its JSON string literals, like "a == \"x||y\"" being one part
+fn split_unquoted<'a>(condition: &'a str, separator: &str) -> Vec<&'a str> {
+    let mut parts = Vec::new();
+    let mut rest = condition;
+    while let Some(idx) = find_unquoted(rest, separator) {
+        parts.push(&rest[..idx]);
+        rest = &rest[idx + separator.len()..];
+    }
+    parts.push(rest);
+    parts
+}
+
+impl Clause {
+    fn parse(clause: &str) -> Result<Self, JsonDataCacheError> {
+        let clause = clause.trim();
+        let Some((op, op_idx)) = ["==", "!=", "<=", ">=", "<", ">"].into_iter().find_map(|op| find_unquoted(clause, op).map(|op_idx| (op, op_idx))) else {
+            let published = |operand: &str| operand.trim().strip_prefix("published(").and_then(|path| path.strip_suffix(')')).map(|path| path.trim().to_string());
+            return match clause.strip_prefix('!') {
+                Some(operand) if let Some(path) = published(operand) => Ok(Clause::NotPublished(path)),
+                None if let Some(path) = published(clause) => Ok(Clause::Published(path)),
+                Some(path) if !path.trim().is_empty() => Ok(Clause::Falsy(path.trim().to_string())),
+                None if !clause.is_empty() => Ok(Clause::Truthy(clause.to_string())),
+                _ => Err("Empty clause in condition".into()),
+            };
+        };
+        let (left, right) = (clause[..op_idx].trim(), clause[op_idx + op.len()..].trim());
+        match op {
+            "==" | "!=" => {
+                let value = serde_json::from_str(right).map_err(|_| format!("Invalid JSON value {right} in condition"))?;
+                Ok(if op == "==" { Clause::Equals(left.to_string(), value) } else { Clause::NotEquals(left.to_string(), value) })
+            },
+            _ => {
+                let parse = |expr: &str| Expr::parse(expr).map_err(|e| JsonDataCacheError::from(format!("Invalid expression {expr} in condition : {e}")));
+                Ok(Clause::Compare(parse(left)?, op, parse(right)?))
+            },
+        }
+    }
+
+    fn is_met(&self, data_cache: &DataCache) -> bool {
+        match self {
+            Clause::Truthy(path) => is_truthy(data_cache.get(path)),
+            Clause::Falsy(path) => !is_truthy(data_cache.get(path)),
+            Clause::Published(path) => data_cache.is_published(path),
+            Clause::NotPublished(path) => !data_cache.is_published(path),
+            Clause::Equals(path, value) => data_cache.get(path).unwrap_or(&Value::Null) == value,
+            Clause::NotEquals(path, value) => data_cache.get(path).unwrap_or(&Value::Null) != value,
+            Clause::Compare(left, op, right) => {
+                let (Some(left), Some(right)) = (left.eval(data_cache), right.eval(data_cache)) else {
+                    return false;
+                };
+                match *op {
+                    "<" => left < right,
+                    "<=" => left <= right,
+                    ">" => left > right,
+                    _ => left >= right,
+                }
+            },
+        }
+    }
+}
+
+impl Condition {
+    pub fn parse(condition: &str) -> Result<Self, JsonDataCacheError> {
+        let any = split_unquoted(condition, "||").into_iter()
+            .map(|all| split_unquoted(all, "&&").into_iter().map(|clause| Ok((clause.trim().to_string(), Clause::parse(clause)?))).collect::<Result<Vec<_>, JsonDataCacheError>>())
+            .collect::<Result<_, _>>()?;
+        Ok(Self { any })
+    }
+
+    pub fn is_met(&self, data_cache: &DataCache) -> bool {
+        self.any.iter().any(|all| all.iter().all(|(_, clause)| clause.is_met(data_cache)))
+    }
+
+    /// {"met", "clauses": [{"clause", "group", "met", "value"}]}, group being the index of the || alternative of the clause,
+    /// value the value at the path of the clause, or the numbers compared
+    pub fn explain(&self, data_cache: &DataCache) -> Value {
+        let clauses = self.any.iter().enumerate().flat_map(|(group, all)| all.iter().map(move |clause| (group, clause)));
+        let clauses: Vec<Value> = clauses.map(|(group, (source, clause))| {
+            let value = match clause {
+                Clause::Truthy(path) | Clause::Falsy(path) | Clause::Equals(path, _) | Clause::NotEquals(path, _) | Clause::Published(path) | Clause::NotPublished(path) => {
+                    data_cache.get(path).cloned().unwrap_or(Value::Null)
+                },
+                Clause::Compare(left, _, right) => serde_json::json!([left.eval(data_cache), right.eval(data_cache)]),
+            };
+            serde_json::json!({"clause": source, "group": group, "met": clause.is_met(data_cache), "value": value})
+        }).collect();
+        serde_json::json!({"met": self.is_met(data_cache), "clauses": clauses})
+    }
+}
+
+/// Runs of a failing step
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    pub attempts: u32, // Runs including the first one
+    pub backoff: Duration, // Wait before the first retry
+    pub factor: u32, // Multiplier of the wait of each further retry
+}
+
+impl RetryPolicy {
+    /// Wait before the retry (0 for the first one)
+    pub fn delay(&self, retry: u32) -> Duration {
+        self.backoff.saturating_mul(self.factor.saturating_pow(retry))
+    }
+}
+
+/// What a failing step does, once its retries are exhausted
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum OnError {
+    /// The run ends with the error
+    #[default]
+    Abort,
+    /// The next steps run
+    PassThrough,
+    /// The template at the path is rendered instead, ending the run as PipelineOutcome::Recovered
+    #[cfg(feature = "replace-engine")]
+    FallbackTemplate(String),
+    /// The template at the path is rendered as an error page, ending the run as PipelineOutcome::Failed
+    #[cfg(feature = "replace-engine")]
+    ErrorPage(String),
+}
+
+/// How a step runs & fails, see the module documentation
+#[derive(Debug, Clone, Default)]
+pub struct StepPolicy {
+    pub when: Option<Condition>,
+    pub retry: Option<RetryPolicy>,
+    pub on_error: OnError,
+}
+
+/// What a step did, or would have done, in a dry run
+#[derive(Debug, Clone, PartialEq)]
+pub struct StepTrace {
+    pub step: usize,
+    pub kind: &'static str,
+    pub condition: Option<Value>, // Condition::explain of the when of the step
+    pub skipped: bool, // The condition was not met
+    pub detail: Value, // Depends on the kind, see Pipeline::explain
+    pub error: Option<String>,
+}
+
+/// Result of Pipeline::explain
+#[derive(Debug, Clone, PartialEq)]
+pub struct PipelineTrace {
+    pub steps: Vec<StepTrace>, // Steps until the end of the run, skipped ones included
+    pub outcome: Option<PipelineOutcome>, // None if the error of the last step is aborting the run
+}
+
+impl StepTrace {
+    pub fn to_json(&self) -> Value {
+        serde_json::json!({
+            "step": self.step,
+            "kind": self.kind,
+            "condition": self.condition,
+            "skipped": self.skipped,
+            "detail": self.detail,
+            "error": self.error
+        })
+    }
+}
+
+impl PipelineTrace {
+    pub fn to_json(&self) -> Value {
+        let outcome = match self.outcome {
+            Some(PipelineOutcome::Completed) => serde_json::json!({"kind": "completed"}),
+            Some(PipelineOutcome::Halted { step }) => serde_json::json!({"kind": "halted", "step": step}),
+            Some(PipelineOutcome::Recovered { step }) => serde_json::json!({"kind": "recovered", "step": step}),
+            Some(PipelineOutcome::Failed { step }) => serde_json::json!({"kind": "failed", "step": step}),
+            None => serde_json::json!({"kind": "aborted"}),
+        };
+        serde_json::json!({"steps": self.steps.iter().map(StepTrace::to_json).collect::<Vec<_>>(), "outcome": outcome})
+    }
+}
+
+/// Value of a transform step
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TransformSource {
+    Copy(String), // Path of the copied value
+    Expr(String), // Expression of a computed path
+}
+
+/// A step of a pipeline, see the module documentation for their configuration
+#[derive(Debug, Clone)]
+pub enum PipelineStep {
+    MatchRoute { from: String, into: String, routes: Vec<(String, String)>, required: bool }, // Routes are (pattern, name)
+    Fetch(DataSource),
+    Transform { into: String, source: TransformSource },
+    Validate { data: String, validator: FormValidator, halt_on_errors: bool },
+    #[cfg(feature = "replace-engine")]
+    Render { template_path: Option<String>, vary_by: Option<Vec<String>> },
+}
+
+impl PipelineStep {
+    pub fn kind(&self) -> &'static str {
+        match self {
+            PipelineStep::MatchRoute { .. } => "match_route",
+            PipelineStep::Fetch(_) => "fetch",
+            PipelineStep::Transform { .. } => "transform",
+            PipelineStep::Validate { .. } => "validate",
+            #[cfg(feature = "replace-engine")]
+            PipelineStep::Render { .. } => "render",
+        }
+    }
+}
+
+/// How a run ended
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PipelineOutcome {
+    Completed,
+    Halted { step: usize }, // Index of the step (an unmatched required route, or a failed validation) which halted the run
+    Recovered { step: usize }, // Index of the failed step whose fallback template was rendered
+    Failed { step: usize }, // Index of the failed step whose error page was rendered
+}
+
+/// What steps read & write: the fetcher of fetch steps, the template & output of render steps
+pub struct PipelineIo<'a, C: HttpClient> {
+    pub fetcher: &'a mut Fetcher<C>,
+    pub template: &'a mut dyn io::Read,
+    pub output: &'a mut dyn io::Write,
+    pub sleep: Option<&'a mut dyn FnMut(Duration)>, // Waits between retries. None retries immediately
+}
+
+impl<'a, C: HttpClient> PipelineIo<'a, C> {
+    pub fn new(fetcher: &'a mut Fetcher<C>, template: &'a mut dyn io::Read, output: &'a mut dyn io::Write) -> Self {
+        Self { fetcher, template, output, sleep: None }
+    }
+
+    pub fn sleep(mut self, sleep: &'a mut dyn FnMut(Duration)) -> Self {
+        self.sleep = Some(sleep);
+        self
+    }
+}
+
+/// Ordered steps run against a DataCache
+#[derive(Debug, Clone)]
+pub struct Pipeline {
+    steps: Vec<(PipelineStep, StepPolicy)>,
+    error_path: String,
+}
+
+fn str_field<'a>(config: &'a Value, name: &str, step: &str) -> Result<&'a str, JsonDataCacheError> {
+    config.get(name).and_then(Value::as_str).ok_or_else(|| format!("Pipeline step {step} requires a string {name}").into())
+}
+
+fn optional_str_field(config: &Value, name: &str) -> Option<String> {
+    config.get(name).and_then(Value::as_str).map(str::to_string)
+}
+
+fn parse_rule(config: &Value) -> Result<Rule, JsonDataCacheError> {
+    let rule = str_field(config, "rule", "validate")?;
+    Ok(match rule {
+        "required" => Rule::Required,
+        "required_with" => Rule::RequiredWith(str_field(config, "other", "validate")?.to_string()),
+        "same_as" => Rule::SameAs(str_field(config, "other", "validate")?.to_string()),
+        "length" => Rule::Length {
+            min: config.get("min").and_then(Value::as_u64).map(|min| min as usize),
+            max: config.get("max").and_then(Value::as_u64).map(|max| max as usize),
+        },
+        "range" => Rule::Range { min: config.get("min").and_then(Value::as_f64), max: config.get("max").and_then(Value::as_f64) },
+        #[cfg(feature = "regex")]
+        "pattern" => {
+            let pattern = str_field(config, "pattern", "validate")?;
+            Rule::Pattern(regex::Regex::new(pattern).map_err(|_| format!("Invalid regex {pattern}"))?)
+        },
+        _ => return Err(format!("Unknown validation rule {rule}").into()),
+    })
+}
+
+fn parse_policy(step: &Value) -> Result<StepPolicy, JsonDataCacheError> {
+    let when = match step.get("when") {
+        Some(Value::String(condition)) => Some(Condition::parse(condition)?),
+        Some(_) => return Err("Pipeline step when must be a string".into()),
+        None => None,
+    };
+    let retry = step.get("retry").map(|retry| RetryPolicy {
+        attempts: retry.get("attempts").and_then(Value::as_u64).unwrap_or(1) as u32,
+        backoff: Duration::from_millis(retry.get("backoff_ms").and_then(Value::as_u64).unwrap_or(0)),
+        factor: retry.get("factor").and_then(Value::as_u64).unwrap_or(1) as u32,
+    });
+    let on_error = match step.get("on_error") {
+        None => OnError::Abort,
+        Some(Value::String(on_error)) if on_error == "abort" => OnError::Abort,
+        Some(Value::String(on_error)) if on_error == "pass_through" => OnError::PassThrough,
+        #[cfg(feature = "replace-engine")]
+        Some(on_error) if on_error.get("fallback_template").is_some() => OnError::FallbackTemplate(str_field(on_error, "fallback_template", "on_error")?.to_string()),
+        #[cfg(feature = "replace-engine")]
+        Some(on_error) if on_error.get("error_page").is_some() => OnError::ErrorPage(str_field(on_error, "error_page", "on_error")?.to_string()),
+        Some(on_error) => return Err(format!("Unknown pipeline on_error {on_error}").into()),
+    };
+    Ok(StepPolicy { when, retry, on_error })
+}
+
+fn parse_step(step: &Value) -> Result<(PipelineStep, StepPolicy), JsonDataCacheError> {
+    let mut kinds = step.as_object().into_iter().flatten().filter(|(key, _)| !POLICY_KEYS.contains(&key.as_str()));
+    let (Some((name, config)), None) = (kinds.next(), kinds.next()) else {
+        return Err(format!("Pipeline steps must be objects with a single kind key, found {step}").into());
+    };
+    let policy = parse_policy(step)?;
+    let step = match name.as_str() {
+        "match_route" => {
+            let routes = config.get("routes").and_then(Value::as_array).ok_or("Pipeline step match_route requires routes")?;
+            PipelineStep::MatchRoute {
+                from: str_field(config, "from", name)?.to_string(),
+                into: str_field(config, "into", name)?.to_string(),
+                routes: routes.iter()
+                    .map(|route| Ok((str_field(route, "pattern", name)?.to_string(), str_field(route, "name", name)?.to_string())))
+                    .collect::<Result<_, JsonDataCacheError>>()?,
+                required: config.get("required").and_then(Value::as_bool).unwrap_or(false),
+            }
+        },
+        "fetch" => {
+            let mut source = DataSource::new(str_field(config, "path", name)?, str_field(config, "url", name)?);
+            for (header, value) in config.get("headers").and_then(Value::as_object).into_iter().flatten() {
+                source = source.header(header, value.as_str().ok_or(format!("Header {header} must be a string"))?);
+            }
+            if let Some(ttl) = config.get("ttl_secs").and_then(Value::as_u64) {
+                source = source.ttl(Duration::from_secs(ttl));
+            }
+            if let Some(fallback) = config.get("fallback") {
+                source = source.fallback(fallback.clone());
+            }
+            PipelineStep::Fetch(source)
+        },
+        "transform" => {
+            let source = match (optional_str_field(config, "from"), optional_str_field(config, "expr")) {
+                (Some(from), None) => TransformSource::Copy(from),
+                (None, Some(expr)) => TransformSource::Expr(expr),
+                _ => return Err("Pipeline step transform requires either from or expr".into()),
+            };
+            PipelineStep::Transform { into: str_field(config, "into", name)?.to_string(), source }
+        },
+        "validate" => {
+            let mut validator = FormValidator::new();
+            if let Some(errors_path) = config.get("errors").and_then(Value::as_str) {
+                validator = validator.errors_path(errors_path);
+            }
+            for rule in config.get("rules").and_then(Value::as_array).ok_or("Pipeline step validate requires rules")? {
+                validator = validator.rule(str_field(rule, "field", name)?, parse_rule(rule)?, rule.get("message").and_then(Value::as_str).unwrap_or_default());
+            }
+            PipelineStep::Validate {
+                data: str_field(config, "data", name)?.to_string(),
+                validator,
+                halt_on_errors: config.get("halt_on_errors").and_then(Value::as_bool).unwrap_or(false),
+            }
+        },
+        #[cfg(feature = "replace-engine")]
+        "render" => {
+            let vary_by = match config.get("vary_by") {
+                Some(Value::Array(paths)) => Some(paths.iter()
+                    .map(|path| path.as_str().map(str::to_string).ok_or("Pipeline step render vary_by must list paths"))
+                    .collect::<Result<_, _>>()?),
+                Some(_) => return Err("Pipeline step render vary_by must list paths".into()),
+                None => None,
+            };
+            PipelineStep::Render { template_path: optional_str_field(config, "template_path"), vary_by }
+        },
+        _ => return Err(format!("Unknown pipeline step {name}").into()),
+    };
+    Ok((step, policy))
+}
+
+/// Parameters of the path if it matches the route pattern
+fn match_route(pattern: &str, path: &str) -> Option<Map<String, Value>> {
+    let path = path.split(['?', '#']).next().unwrap_or_default();
+    let mut path_segments = path.split('/').filter(|segment| !segment.is_empty());
+    let mut params = Map::new();
+    for pattern_segment in pattern.split('/').filter(|segment| !segment.is_empty()) {
+        if pattern_segment == "*" {
+            params.insert(String::from("*"), Value::String(path_segments.collect::<Vec<_>>().join("/")));
+            return Some(params);
+        }
+        let path_segment = path_segments.next()?;
+        match pattern_segment.strip_prefix(':') {
+            Some(param) => {
+                params.insert(param.to_string(), Value::String(path_segment.to_string()));
+            },
+            None if pattern_segment == path_segment => {},
+            None => return None,
+        }
+    }
+    path_segments.next().is_none().then_some(params)
+}
+
+impl Pipeline {
+    /// Steps with the default StepPolicy
+    pub fn new(steps: Vec<PipelineStep>) -> Self {
+        Self { steps: steps.into_iter().map(|step| (step, StepPolicy::default())).collect(), error_path: DEFAULT_ERROR_PATH.to_string() }
+    }
+
+    pub fn step(mut self, step: PipelineStep, policy: StepPolicy) -> Self {
+        self.steps.push((step, policy));
+        self
+    }
+
+    /// Path where the error of failed steps is inserted (DEFAULT_ERROR_PATH by default)
+    pub fn error_path(mut self, error_path: &str) -> Self {
+        self.error_path = error_path.to_string();
+        self
+    }
+
+    /// Parses a configuration: {"steps": [...], "error_path": ..}, see the module documentation
+    pub fn from_value(config: &Value) -> Result<Self, JsonDataCacheError> {
+        let steps = config.get("steps").and_then(Value::as_array).ok_or("Pipeline configuration requires a steps array")?;
+        Ok(Self {
+            steps: steps.iter().map(parse_step).collect::<Result<_, _>>()?,
+            error_path: config.get("error_path").and_then(Value::as_str).unwrap_or(DEFAULT_ERROR_PATH).to_string(),
+        })
+    }
+
+    pub fn from_json(config: &str) -> Result<Self, JsonDataCacheError> {
+        let config: Value = serde_json::from_str(config).map_err(|e| format!("Invalid pipeline configuration : {e}"))?;
+        Self::from_value(&config)
+    }
+
+    pub fn steps(&self) -> &[(PipelineStep, StepPolicy)] {
+        &self.steps
+    }
+
+    /// Runs the steps in order, stopping at the first halting one or failing one (see OnError)
+    pub fn run<C: HttpClient>(&self, data_cache: &mut DataCache, io: &mut PipelineIo<'_, C>) -> Result<PipelineOutcome, JsonDataCacheError> {
+        for (step_idx, (step, policy)) in self.steps.iter().enumerate() {
+            if policy.when.as_ref().is_some_and(|when| !when.is_met(data_cache)) {
+                continue;
+            }
+            let mut retry = 0;
+            let result = loop {
+                match run_step(step, data_cache, io) {
+                    Err(_) if policy.retry.is_some_and(|retry_policy| retry + 1 < retry_policy.attempts) => {
+                        if let (Some(sleep), Some(retry_policy)) = (io.sleep.as_mut(), policy.retry) {
+                            sleep(retry_policy.delay(retry));
+                        }
+                        retry += 1;
+                    },
+                    result => break result,
+                }
+            };
+            let error = match result {
+                Ok(true) => continue,
+                Ok(false) => return Ok(PipelineOutcome::Halted { step: step_idx }),
+                Err(error) => error,
+            };
+            if policy.on_error == OnError::Abort {
+                return Err(error);
+            }
+            self.insert_error(data_cache, step_idx, &error)?;
+            #[cfg(feature = "replace-engine")]
+            if let OnError::FallbackTemplate(template_path) | OnError::ErrorPage(template_path) = &policy.on_error {
+                render_path(data_cache, template_path, None, io)?;
+                return Ok(match policy.on_error {
+                    OnError::FallbackTemplate(_) => PipelineOutcome::Recovered { step: step_idx },
+                    _ => PipelineOutcome::Failed { step: step_idx },
+                });
+            }
+        }
+        Ok(PipelineOutcome::Completed)
+    }
+}
+
+impl Pipeline {
+    fn insert_error(&self, data_cache: &mut DataCache, step_idx: usize, error: &JsonDataCacheError) -> Result<(), JsonDataCacheError> {
+        data_cache.try_replace(&self.error_path, serde_json::json!({"step": step_idx, "message": error.to_string()}))
+    }
+
+    /// Dry run of the steps, for debugging: they run against a copy of the cache (its tree, options, references, schemas,
+    /// renderers, visibility rules & compressed strings), fetch steps only rendering their request & render steps only
+    /// linting their template (see lint_template), so that neither the cache nor the origins are affected. Retries are not
+    /// attempted.
+    /// Details of the steps:
+    /// - match_route: {"path", "matched": name or null, "params", "routes": [{"pattern", "name", "matched"}]}, the routes tried
+    ///   in order until the matching one
+    /// - fetch: {"path", "url", "headers": names only, not to expose credentials} of the request
+    /// - transform: {"into", "value"}
+    /// - validate: {"data", "errors": [{"field", "rule", "message"}]}
+    /// - render: {"template_path", "issues": lint diagnostics of the template at template_path}
+    pub fn explain<C: HttpClient>(&self, data_cache: &DataCache, fetcher: &Fetcher<C>) -> PipelineTrace {
+        let mut dry_run = DataCache::new(data_cache.options.clone());
+        dry_run.root = data_cache.root.clone();
+        dry_run.computed = data_cache.computed.clone();
+        data_cache.compression.restore(&mut dry_run.root, "");
+        dry_run.refs = data_cache.refs.clone();
+        dry_run.schemas = data_cache.schemas.clone();
+        dry_run.visibility = data_cache.visibility.clone();
+        #[cfg(feature = "replace-engine")]
+        {
+            dry_run.renderers = data_cache.renderers.clone();
+        }
+
+        let mut steps = Vec::new();
+        for (step_idx, (step, policy)) in self.steps.iter().enumerate() {
+            let condition = policy.when.as_ref().map(|when| when.explain(&dry_run));
+            let skipped = policy.when.as_ref().is_some_and(|when| !when.is_met(&dry_run));
+            let mut trace = StepTrace { step: step_idx, kind: step.kind(), condition, skipped, detail: Value::Null, error: None };
+            if skipped {
+                steps.push(trace);
+                continue;
+            }
+            let result = explain_step(step, &mut dry_run, fetcher);
+            let error = match result {
+                Ok((detail, is_continuing)) => {
+                    trace.detail = detail;
+                    steps.push(trace);
+                    if is_continuing {
+                        continue;
+                    }
+                    return PipelineTrace { steps, outcome: Some(PipelineOutcome::Halted { step: step_idx }) };
+                },
+                Err(error) => error,
+            };
+            trace.error = Some(error.to_string());
+            steps.push(trace);
+            let outcome = match &policy.on_error {
+                OnError::Abort => None,
+                OnError::PassThrough => {
+                    let _ = self.insert_error(&mut dry_run, step_idx, &error);
+                    continue;
+                },
+                #[cfg(feature = "replace-engine")]
+                OnError::FallbackTemplate(_) => Some(PipelineOutcome::Recovered { step: step_idx }),
+                #[cfg(feature = "replace-engine")]
+                OnError::ErrorPage(_) => Some(PipelineOutcome::Failed { step: step_idx }),
+            };
+            return PipelineTrace { steps, outcome };
+        }
+        PipelineTrace { steps, outcome: Some(PipelineOutcome::Completed) }
+    }
+}
+
+/// Dry run of a step, see Pipeline::explain: its detail, and false if it halts the run
+fn explain_step<C: HttpClient>(step: &PipelineStep, dry_run: &mut DataCache, fetcher: &Fetcher<C>) -> Result<(Value, bool), JsonDataCacheError> {
+    Ok(match step {
+        PipelineStep::MatchRoute { from, into, routes, required } => {
+            let path = dry_run.get(from).and_then(Value::as_str).unwrap_or_default().to_string();
+            let mut tried = Vec::new();
+            let matched = routes.iter().find_map(|(pattern, name)| {
+                let params = match_route(pattern, &path);
+                tried.push(serde_json::json!({"pattern": pattern, "name": name, "matched": params.is_some()}));
+                params.map(|params| (name, params))
+            });
+            let detail = match &matched {
+                Some((name, params)) => {
+                    dry_run.try_replace(into, serde_json::json!({"name": name, "params": params}))?;
+                    serde_json::json!({"path": path, "matched": name, "params": params, "routes": tried})
+                },
+                None => {
+                    dry_run.try_replace(into, Value::Null)?;
+                    serde_json::json!({"path": path, "matched": null, "params": null, "routes": tried})
+                },
+            };
+            (detail, matched.is_some() || !required)
+        },
+        PipelineStep::Fetch(source) => {
+            let request = fetcher.render_request(dry_run, source)?;
+            let headers: Vec<&str> = request.headers.iter().map(|(name, _)| name.as_str()).collect();
+            (serde_json::json!({"path": source.path, "url": request.url, "headers": headers}), true)
+        },
+        PipelineStep::Transform { into, source } => {
+            match source {
+                TransformSource::Copy(from) => {
+                    let value = dry_run.get(from).cloned().unwrap_or(Value::Null);
+                    dry_run.try_replace(into, value)?;
+                },
+                TransformSource::Expr(expr) => dry_run.define_computed(into, expr)?,
+            }
+            (serde_json::json!({"into": into, "value": dry_run.get(into)}), true)
+        },
+        PipelineStep::Validate { data, validator, halt_on_errors } => {
+            let errors = dry_run.validate_form(data, validator)?;
+            let detail: Vec<Value> = errors.iter()
+                .map(|error| serde_json::json!({"field": error.field, "rule": error.rule, "message": error.message}))
+                .collect();
+            (serde_json::json!({"data": data, "errors": detail}), !*halt_on_errors || errors.is_empty())
+        },
+        #[cfg(feature = "replace-engine")]
+        PipelineStep::Render { template_path, .. } => {
+            let issues: Vec<String> = match template_path.as_ref().map(|template_path| dry_run.get(template_path)) {
+                Some(Some(Value::String(template))) => lint_template(template, LintSource::Cache(dry_run)).iter().map(ToString::to_string).collect(),
+                Some(_) => return Err(format!("No template at {}", template_path.as_deref().unwrap_or_default()).into()),
+                None => Vec::new(),
+            };
+            (serde_json::json!({"template_path": template_path, "issues": issues}), true)
+        },
+    })
+}
+
+/// Replaces the template into the output, checking the dynamic paths it reads if vary_by is set
+#[cfg(feature = "replace-engine")]
+fn render<R: io::Read>(data_cache: &mut DataCache, template: R, vary_by: Option<&[String]>, output: &mut dyn io::Write) -> Result<(), JsonDataCacheError> {
+    match vary_by {
+        Some(vary_by) => data_cache.replace_with_vary_by(&vary_by.iter().map(String::as_str).collect::<Vec<_>>(), template, output),
+        None => data_cache.replace_with_data_cache(template, output),
+    }
+}
+
+/// Renders the template string at template_path into the output
+#[cfg(feature = "replace-engine")]
+fn render_path<C: HttpClient>(
+    data_cache: &mut DataCache,
+    template_path: &str,
+    vary_by: Option<&[String]>,
+    io: &mut PipelineIo<'_, C>
+) -> Result<(), JsonDataCacheError> {
+    let template = match data_cache.get(template_path) {
+        Some(Value::String(template)) => template.clone(),
+        _ => return Err(format!("No template at {template_path}").into()),
+    };
+    render(data_cache, template.as_bytes(), vary_by, &mut *io.output)
+}
+
+/// Runs a step once. Ok(false) halts the run
+fn run_step<C: HttpClient>(step: &PipelineStep, data_cache: &mut DataCache, io: &mut PipelineIo<'_, C>) -> Result<bool, JsonDataCacheError> {
+    match step {
+        PipelineStep::MatchRoute { from, into, routes, required } => {
+            let path = data_cache.get(from).and_then(Value::as_str).unwrap_or_default().to_string();
+            let matched = routes.iter().find_map(|(pattern, name)| match_route(pattern, &path).map(|params| (name, params)));
+            match matched {
+                Some((name, params)) => data_cache.try_replace(into, serde_json::json!({"name": name, "params": params}))?,
+                None => {
+                    data_cache.try_replace(into, Value::Null)?;
+                    if *required {
+                        return Ok(false);
+                    }
+                },
+            }
+        },
+        PipelineStep::Fetch(source) => {
+            io.fetcher.add_source(source.clone());
+            io.fetcher.ensure(data_cache, &source.path)?;
+        },
+        PipelineStep::Transform { into, source: TransformSource::Copy(from) } => {
+            let value = data_cache.get(from).cloned().unwrap_or(Value::Null);
+            data_cache.try_replace(into, value)?;
+        },
+        PipelineStep::Transform { into, source: TransformSource::Expr(expr) } => data_cache.define_computed(into, expr)?,
+        PipelineStep::Validate { data, validator, halt_on_errors } => {
+            let errors = data_cache.validate_form(data, validator)?;
+            if *halt_on_errors && !errors.is_empty() {
+                return Ok(false);
+            }
+        },
+        #[cfg(feature = "replace-engine")]
+        PipelineStep::Render { template_path: Some(template_path), vary_by } => render_path(data_cache, template_path, vary_by.as_deref(), io)?,
+        #[cfg(feature = "replace-engine")]
+        PipelineStep::Render { template_path: None, vary_by } => render(data_cache, &mut *io.template, vary_by.as_deref(), &mut *io.output)?,
+    }
+    Ok(true)
+}
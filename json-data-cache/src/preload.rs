@@ -0,0 +1,242 @@
+//! Collects critical asset URLs while HTML is streamed to the client, for the integration to send them as
+//! `Link: rel=preload` headers or 103 Early Hints, and renders preload tags from an asset list held in the cache.
+
+use std::io::{self, Write};
+
+use serde_json::Value;
+
+use crate::{DataCache, error::JsonDataCacheError};
+
+/// Tags longer than this (split across chunks) are not inspected
+const MAX_PENDING_TAG_LEN: usize = 4096;
+
+/// Selects tags whose URL attribute should be preloaded
+#[derive(Debug, Clone)]
+pub struct PreloadRule {
+    tag: String,
+    conditions: Vec<(String, String)>, // Attribute names & substrings their values must contain, ignoring case
+    url_attribute: String,
+    as_type: String,
+}
+
+impl PreloadRule {
+    /// Preloads the url_attribute of the tags as as_type (style, image, font, script...)
+    pub fn new(tag: &str, url_attribute: &str, as_type: &str) -> Self {
+        Self {
+            tag: tag.to_ascii_lowercase(),
+            conditions: Vec::new(),
+            url_attribute: url_attribute.to_ascii_lowercase(),
+            as_type: as_type.to_string()
+        }
+    }
+
+    /// Only matches tags whose attribute contains the substring (an empty substring only requires the attribute)
+    pub fn when(mut self, attribute: &str, substring: &str) -> Self {
+        self.conditions.push((attribute.to_ascii_lowercase(), substring.to_ascii_lowercase()));
+        self
+    }
+
+    /// Stylesheets, high priority images & preloaded fonts
+    pub fn defaults() -> Vec<Self> {
+        Vec::from([
+            Self::new("link", "href", "style").when("rel", "stylesheet"),
+            Self::new("img", "src", "image").when("fetchpriority", "high"),
+            Self::new("link", "href", "font").when("rel", "preload").when("as", "font"),
+        ])
+    }
+
+    fn matches(&self, tag_name: &str, attributes: &[(String, String)]) -> bool {
+        tag_name == self.tag && self.conditions.iter().all(|(name, substring)| {
+            attributes.iter().any(|(attribute, value)| attribute == name && value.to_ascii_lowercase().contains(substring))
+        })
+    }
+}
+
+/// A URL to preload
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PreloadAsset {
+    pub url: String,
+    pub as_type: String
+}
+
+impl PreloadAsset {
+    /// Fonts are always fetched in CORS mode, so their preload must be too
+    fn is_crossorigin(&self) -> bool {
+        self.as_type == "font"
+    }
+
+    /// Value of a Link header (or of one of its comma separated items)
+    pub fn link_header_value(&self) -> String {
+        let crossorigin = if self.is_crossorigin() { "; crossorigin" } else { "" };
+        format!("<{}>; rel=preload; as={}{crossorigin}", self.url, self.as_type)
+    }
+
+    pub fn preload_tag(&self) -> String {
+        let crossorigin = if self.is_crossorigin() { " crossorigin" } else { "" };
+        format!("<link rel=\"preload\" href=\"{}\" as=\"{}\"{crossorigin}>", escape_attribute(&self.url), escape_attribute(&self.as_type))
+    }
+}
+
+/// Single Link header value for all the assets
+pub fn link_header(assets: &[PreloadAsset]) -> String {
+    assets.iter().map(PreloadAsset::link_header_value).collect::<Vec<String>>().join(", ")
+}
+
+pub(crate) fn escape_attribute(value: &str) -> String {
+    value.replace('&', "&amp;").replace('"', "&quot;").replace('<', "&lt;")
+}
+
+/// Writer passing HTML through unchanged to the inner writer, while collecting the assets matched by its rules
+/// Typically wraps the output of replace_with_data_cache, its chunks possibly splitting tags
+#[derive(Debug)]
+pub struct PreloadExtractor<W: Write> {
+    inner: W,
+    rules: Vec<PreloadRule>,
+    pending_tag: Option<Vec<u8>>, // Start of a tag not closed in the previous chunk
+    assets: Vec<PreloadAsset>
+}
+
+impl<W: Write> PreloadExtractor<W> {
+    pub fn new(inner: W, rules: Vec<PreloadRule>) -> Self {
+        Self {
+            inner,
+            rules,
+            pending_tag: None,
+            assets: Vec::new()
+        }
+    }
+
+    /// Assets found so far, without duplicates, in document order
+    pub fn assets(&self) -> &[PreloadAsset] {
+        &self.assets
+    }
+
+    pub fn into_parts(self) -> (W, Vec<PreloadAsset>) {
+        (self.inner, self.assets)
+    }
+
+    fn scan(&mut self, chunk: &[u8]) {
+        let mut idx = 0;
+        if let Some(mut tag) = self.pending_tag.take() {
+            let Some(tag_end) = chunk.iter().position(|b| *b == b'>') else {
+                if tag.len() + chunk.len() <= MAX_PENDING_TAG_LEN {
+                    tag.extend_from_slice(chunk);
+                    self.pending_tag = Some(tag);
+                }
+                return;
+            };
+            if tag.len() + tag_end <= MAX_PENDING_TAG_LEN {
+                tag.extend_from_slice(&chunk[..tag_end]);
+                self.inspect_tag(&tag);
+            }
+            idx = tag_end + 1;
+        }
+        while let Some(offset) = chunk[idx..].iter().position(|b| *b == b'<') {
+            let tag_start = idx + offset + 1;
+            let Some(tag_len) = chunk[tag_start..].iter().position(|b| *b == b'>') else {
+                if chunk.len() - tag_start <= MAX_PENDING_TAG_LEN {
+                    self.pending_tag = Some(chunk[tag_start..].to_vec());
+                }
+                return;
+            };
+            self.inspect_tag(&chunk[tag_start..tag_start + tag_len]);
+            idx = tag_start + tag_len + 1;
+        }
+    }
+
+    /// Tag content, between < and >
+    fn inspect_tag(&mut self, tag: &[u8]) {
+        let name_len = tag.iter().take_while(|b| b.is_ascii_alphanumeric()).count();
+        if name_len == 0 {
+            return; // Closing tags, comments, doctype
+        }
+        let tag_name = String::from_utf8_lossy(&tag[..name_len]).to_ascii_lowercase();
+        if !self.rules.iter().any(|rule| rule.tag == tag_name) {
+            return;
+        }
+        let attributes = parse_attributes(&tag[name_len..]);
+        let Some(rule) = self.rules.iter().find(|rule| rule.matches(&tag_name, &attributes)) else {
+            return;
+        };
+        let Some((_, url)) = attributes.iter().find(|(name, value)| *name == rule.url_attribute && !value.is_empty()) else {
+            return;
+        };
+        let asset = PreloadAsset {
+            url: url.clone(),
+            as_type: rule.as_type.clone()
+        };
+        if !self.assets.contains(&asset) {
+            self.assets.push(asset);
+        }
+    }
+}
+
+impl<W: Write> Write for PreloadExtractor<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let written = self.inner.write(buf)?;
+        self.scan(&buf[..written]);
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// Attributes of a tag (after its name) with lowercase names. Values are not unescaped
+pub(crate) fn parse_attributes(tag: &[u8]) -> Vec<(String, String)> {
+    let mut attributes = Vec::new();
+    let mut idx = 0;
+    loop {
+        while idx < tag.len() && (tag[idx].is_ascii_whitespace() || tag[idx] == b'/') {
+            idx += 1;
+        }
+        let name_start = idx;
+        while idx < tag.len() && !tag[idx].is_ascii_whitespace() && !matches!(tag[idx], b'=' | b'/') {
+            idx += 1;
+        }
+        if name_start == idx {
+            return attributes;
+        }
+        let name = String::from_utf8_lossy(&tag[name_start..idx]).to_ascii_lowercase();
+        let mut value = String::new();
+        if idx < tag.len() && tag[idx] == b'=' {
+            idx += 1;
+            let (value_start, value_end) = match tag.get(idx) {
+                Some(quote @ (b'"' | b'\'')) => {
+                    let value_len = tag[idx + 1..].iter().position(|b| b == quote).unwrap_or(tag.len() - idx - 1);
+                    (idx + 1, idx + 1 + value_len)
+                },
+                _ => (idx, idx + tag[idx..].iter().take_while(|b| !b.is_ascii_whitespace()).count()),
+            };
+            value = String::from_utf8_lossy(&tag[value_start..value_end]).into_owned();
+            idx = (value_end + 1).min(tag.len());
+        }
+        attributes.push((name, value));
+    }
+}
+
+impl DataCache {
+    /// Reads the asset list at path, an array of {"url", "as"} objects (like [{"url": "/main.css", "as": "style"}])
+    pub fn preload_assets(&self, path: &str) -> Result<Vec<PreloadAsset>, JsonDataCacheError> {
+        let Some(list) = self.get(path) else {
+            return Ok(Vec::new());
+        };
+        let list = list.as_array().ok_or(format!("Preload asset list {path} is not an array"))?;
+        list.iter()
+            .map(|asset| {
+                let url = asset.get("url").and_then(Value::as_str);
+                let as_type = asset.get("as").and_then(Value::as_str);
+                match (url, as_type) {
+                    (Some(url), Some(as_type)) => Ok(PreloadAsset { url: url.to_string(), as_type: as_type.to_string() }),
+                    _ => Err(format!("Invalid preload asset in {path} : {asset}").into()),
+                }
+            })
+            .collect()
+    }
+
+    /// Preload tags of the asset list at path (see preload_assets), to be injected into the head of documents
+    pub fn preload_tags(&self, path: &str) -> Result<String, JsonDataCacheError> {
+        Ok(self.preload_assets(path)?.iter().map(PreloadAsset::preload_tag).collect())
+    }
+}
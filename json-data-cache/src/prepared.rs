@@ -0,0 +1,280 @@
+//! Snapshots of the serialized data, so that cold started instances skip serialization & the automaton construction:
+//! to_prepared_bytes stores the serialized & doubly serialized buffers with the ranges of their keys, and (with the
+//! replace-engine feature) a DFA of the placeholders with their replacements. from_prepared_bytes restores the tree from
+//! the serialized buffer, reuses the ranges as is and replaces with the DFA until the next modification, which builds the
+//! aho-corasick automaton (those can not be serialized) like for any cache.
+//! Only the tree is restored: references, raw values, renderers & other settings have to be set again (which discards the
+//! snapshot like any insert), and values of keys of references are restored as plain values. The DFA replaces the keys the
+//! source cache did (as if it had no static keys), raw & rendered values included, with the default match kind.
+//!
+//! Format: magic, then for each buffer (the doubly serialized one being preceded by a presence byte) the varint length
+//! and bytes of the data, the varint count of keys, and for each key its varint length & bytes, varint start & end.
+//! Then a presence byte for the automaton, followed by the varint length & bytes of the DFA, the varint count of patterns,
+//! for each pattern its varint length, replacement kind byte & varints, and the varint count of rendered values with
+//! the varint length & bytes of each.
+
+use std::collections::HashMap;
+#[cfg(feature = "replace-engine")]
+use std::{collections::HashSet, time::Duration};
+
+use serde_json::Value;
+
+#[cfg(feature = "replace-engine")]
+use aho_corasick::{Input, Match};
+#[cfg(feature = "replace-engine")]
+use regex_automata::dfa::{Automaton, dense, sparse};
+
+use crate::{DataCache, DataCacheOptions, compression::{push_varint, read_varint}, error::JsonDataCacheError, json_serializer::{key_value_range::Range, serialized_data::SerializedDataLegacy}};
+#[cfg(feature = "replace-engine")]
+use crate::replace_engine::{AutomatonStats, KeyScope, PlaceholderMatcher, Replacement};
+
+const MAGIC: &[u8] = b"JDCP2";
+
+/// DFA matching the placeholders of a restored cache, in place of its aho-corasick automaton until the next modification
+#[cfg(feature = "replace-engine")]
+#[derive(Debug)]
+pub(crate) struct PreparedMatcher {
+    dfa: sparse::DFA<Vec<u8>>,
+    pattern_lens: Vec<usize>, // By pattern, patterns being literals: matches start this many bytes before their end
+}
+
+#[cfg(feature = "replace-engine")]
+impl PreparedMatcher {
+    /// DFA of literal patterns, which must be sorted longest first: the DFA reports the first pattern matching at the
+    /// leftmost position, which then is the longest one like with MatchKind::LeftmostLongest
+    fn build(patterns: &[String]) -> Result<Self, JsonDataCacheError> {
+        let escaped: Vec<String> = patterns.iter()
+            .map(|pattern| pattern.bytes().map(|byte| format!(r"\x{byte:02X}")).collect())
+            .collect();
+        let dense = dense::Builder::new()
+            .syntax(regex_automata::util::syntax::Config::new().unicode(false).utf8(false))
+            .thompson(regex_automata::nfa::thompson::Config::new().utf8(false))
+            .build_many(&escaped)?;
+        Ok(Self { dfa: dense.to_sparse()?, pattern_lens: patterns.iter().map(String::len).collect() })
+    }
+
+    pub(crate) fn memory_usage(&self) -> usize {
+        self.dfa.memory_usage()
+    }
+}
+
+#[cfg(feature = "replace-engine")]
+impl PlaceholderMatcher for PreparedMatcher {
+    fn find_match(&self, input: Input<'_>) -> Option<Match> {
+        self.try_find_match(input).expect("DFA of prepared placeholders failed")
+    }
+
+    fn try_find_match(&self, input: Input<'_>) -> Result<Option<Match>, JsonDataCacheError> {
+        let search = regex_automata::Input::new(input.haystack()).span(input.get_span().range());
+        let Some(half_match) = self.dfa.try_search_fwd(&search)? else {
+            return Ok(None);
+        };
+        let pattern_idx = half_match.pattern().as_usize();
+        Ok(Some(Match::must(pattern_idx, half_match.offset() - self.pattern_lens[pattern_idx]..half_match.offset())))
+    }
+
+    fn max_pattern_len(&self) -> usize {
+        self.pattern_lens.iter().copied().max().unwrap_or(0)
+    }
+}
+
+fn push_serialized(output: &mut Vec<u8>, serialized: &SerializedDataLegacy) {
+    push_varint(output, serialized.data.len());
+    output.extend_from_slice(&serialized.data);
+    push_varint(output, serialized.key_values.len());
+    for (key, range) in &serialized.key_values {
+        push_varint(output, key.len());
+        output.extend_from_slice(key.as_bytes());
+        push_varint(output, range.start);
+        push_varint(output, range.end);
+    }
+}
+
+fn read_bytes<'a>(bytes: &'a [u8], pos: &mut usize, len: usize) -> Result<&'a [u8], JsonDataCacheError> {
+    let slice = bytes.get(*pos..pos.saturating_add(len)).ok_or("Truncated prepared data")?;
+    *pos += len;
+    Ok(slice)
+}
+
+fn read_serialized(bytes: &[u8], pos: &mut usize) -> Result<SerializedDataLegacy, JsonDataCacheError> {
+    let len = read_varint(bytes, pos)?;
+    let data = read_bytes(bytes, pos, len)?.to_vec();
+    let count = read_varint(bytes, pos)?;
+    let mut key_values = HashMap::with_capacity(count.min(bytes.len()));
+    for _ in 0..count {
+        let key_len = read_varint(bytes, pos)?;
+        let key = String::from_utf8(read_bytes(bytes, pos, key_len)?.to_vec()).map_err(|_| "Invalid key in prepared data")?;
+        let (start, end) = (read_varint(bytes, pos)?, read_varint(bytes, pos)?);
+        if start > end || end > data.len() {
+            return Err(format!("Range {start}..{end} of {key} out of the prepared data").into());
+        }
+        key_values.insert(key, Range { start, end });
+    }
+    Ok(SerializedDataLegacy { length: data.len(), data, key_values })
+}
+
+/// Automaton section of a snapshot, as read
+struct PreparedAutomaton {
+    dfa: Vec<u8>,
+    patterns: Vec<(usize, u8, usize, usize)>, // Length, replacement kind & its two varints
+    rendered: Vec<Vec<u8>>,
+}
+
+fn read_automaton(bytes: &[u8], pos: &mut usize) -> Result<PreparedAutomaton, JsonDataCacheError> {
+    let len = read_varint(bytes, pos)?;
+    let dfa = read_bytes(bytes, pos, len)?.to_vec();
+    let count = read_varint(bytes, pos)?;
+    let mut patterns = Vec::with_capacity(count.min(bytes.len()));
+    for _ in 0..count {
+        let pattern_len = read_varint(bytes, pos)?;
+        let kind = read_bytes(bytes, pos, 1)?[0];
+        patterns.push((pattern_len, kind, read_varint(bytes, pos)?, read_varint(bytes, pos)?));
+    }
+    let count = read_varint(bytes, pos)?;
+    let mut rendered = Vec::with_capacity(count.min(bytes.len()));
+    for _ in 0..count {
+        let len = read_varint(bytes, pos)?;
+        rendered.push(read_bytes(bytes, pos, len)?.to_vec());
+    }
+    Ok(PreparedAutomaton { dfa, patterns, rendered })
+}
+
+impl DataCache {
+    /// Snapshot of the serialized data (built if needed) & of the automaton, see the prepared module
+    pub fn to_prepared_bytes(&self) -> Result<Vec<u8>, JsonDataCacheError> {
+        Self::build_serialized(&self.serialized_data, &self.root, &self.refs, &self.compression);
+        let serialized = self.serialized_data.serialized.get().unwrap();
+        let mut output = Vec::with_capacity(MAGIC.len() + serialized.data.len() * 3);
+        output.extend_from_slice(MAGIC);
+        push_serialized(&mut output, serialized);
+        match self.serialized_data.double_serialized.get() {
+            Some(double_serialized) => {
+                output.push(1);
+                push_serialized(&mut output, double_serialized);
+            },
+            None => output.push(0),
+        }
+        #[cfg(feature = "replace-engine")]
+        {
+            output.push(1);
+            self.push_prepared_automaton(&mut output)?;
+        }
+        #[cfg(not(feature = "replace-engine"))]
+        output.push(0);
+        Ok(output)
+    }
+
+    /// Writes the DFA, replacements & rendered values of every key. Raw values become rendered ones, as they are not restored
+    #[cfg(feature = "replace-engine")]
+    fn push_prepared_automaton(&self, output: &mut Vec<u8>) -> Result<(), JsonDataCacheError> {
+        let (patterns, replacements, mut rendered) = self.automaton_patterns(None, KeyScope::All)?;
+        let mut sorted: Vec<(String, Replacement)> = patterns.into_iter().zip(replacements).collect();
+        sorted.sort_by_key(|(pattern, _)| std::cmp::Reverse(pattern.len()));
+        let patterns: Vec<String> = sorted.iter().map(|(pattern, _)| pattern.clone()).collect();
+        let dfa_bytes = PreparedMatcher::build(&patterns)?.dfa.to_bytes_native_endian();
+        push_varint(output, dfa_bytes.len());
+        output.extend_from_slice(&dfa_bytes);
+        push_varint(output, sorted.len());
+        for (pattern, replacement) in &sorted {
+            push_varint(output, pattern.len());
+            let (kind, a, b) = match *replacement {
+                Replacement::Serialized(start, end) => (0, start, end),
+                Replacement::DoubleSerialized(start, end) => (1, start, end),
+                Replacement::Rendered(idx) => (2, idx, 0),
+                Replacement::Raw(idx) => {
+                    rendered.push(self.raw_values.get_index(idx).map(|(_, bytes)| bytes.clone()).unwrap_or_default());
+                    (2, rendered.len() - 1, 0)
+                },
+            };
+            output.push(kind);
+            push_varint(output, a);
+            push_varint(output, b);
+        }
+        push_varint(output, rendered.len());
+        for value in &rendered {
+            push_varint(output, value.len());
+            output.extend_from_slice(value);
+        }
+        Ok(())
+    }
+
+    /// Cache restored from a snapshot of to_prepared_bytes, with its serialized data already built
+    pub fn from_prepared_bytes(bytes: &[u8], options: DataCacheOptions) -> Result<Self, JsonDataCacheError> {
+        let mut pos = MAGIC.len();
+        if bytes.get(..pos) != Some(MAGIC) {
+            return Err("Not prepared data".into());
+        }
+        let serialized = read_serialized(bytes, &mut pos)?;
+        let double_serialized = match read_bytes(bytes, &mut pos, 1)? {
+            [0] => None,
+            [1] => Some(read_serialized(bytes, &mut pos)?),
+            _ => return Err("Invalid prepared data".into()),
+        };
+        let automaton = match read_bytes(bytes, &mut pos, 1)? {
+            [0] => None,
+            [1] => Some(read_automaton(bytes, &mut pos)?),
+            _ => return Err("Invalid prepared data".into()),
+        };
+        if pos != bytes.len() {
+            return Err("Trailing bytes after prepared data".into());
+        }
+        let root: Value = serde_json::from_slice(&serialized.data).map_err(|e| format!("Invalid serialized data in prepared data : {e}"))?;
+        if !root.is_object() {
+            return Err("Prepared data is not an object".into());
+        }
+
+        let mut data_cache = DataCache::new(options);
+        data_cache.check_depth("", &root)?;
+        data_cache.root = root;
+        let _ = data_cache.serialized_data.serialized.set(serialized);
+        if let Some(double_serialized) = double_serialized {
+            let _ = data_cache.serialized_data.double_serialized.set(double_serialized);
+        }
+        #[cfg(feature = "replace-engine")]
+        if let Some(automaton) = automaton {
+            data_cache.restore_automaton(automaton)?;
+        }
+        #[cfg(not(feature = "replace-engine"))]
+        let _ = automaton; // Nothing replaces without the replace-engine feature
+        Ok(data_cache)
+    }
+
+    /// Replaces with the automaton of a snapshot, checking that its replacements slice the restored buffers
+    #[cfg(feature = "replace-engine")]
+    fn restore_automaton(&mut self, automaton: PreparedAutomaton) -> Result<(), JsonDataCacheError> {
+        let buffers = self.buffers(&automaton.rendered);
+        let (serialized_len, double_serialized_len) = (buffers.serialized.len(), buffers.double_serialized.len());
+        let (dfa, _) = sparse::DFA::from_bytes(&automaton.dfa)?;
+        if dfa.pattern_len() != automaton.patterns.len() {
+            return Err("Patterns of the prepared automaton do not match its DFA".into());
+        }
+        let mut pattern_lens = Vec::with_capacity(automaton.patterns.len());
+        let mut replacements = Vec::with_capacity(automaton.patterns.len());
+        for (pattern_len, kind, a, b) in automaton.patterns {
+            let replacement = match kind {
+                0 if a <= b && b <= serialized_len => Replacement::Serialized(a, b),
+                1 if a <= b && b <= double_serialized_len => Replacement::DoubleSerialized(a, b),
+                2 if a < automaton.rendered.len() => Replacement::Rendered(a),
+                _ => return Err("Invalid replacement in prepared data".into()),
+            };
+            pattern_lens.push(pattern_len);
+            replacements.push(replacement);
+        }
+        let matcher = PreparedMatcher { dfa: dfa.to_owned(), pattern_lens };
+        let slots: HashSet<Replacement> = replacements.iter().copied().collect();
+        let value_bytes = slots.into_iter().map(|slot| self.replacement_of(&[slot], self.buffers(&automaton.rendered), 0).len()).sum();
+        let serialized_data = &mut self.serialized_data;
+        serialized_data.stats = Some(AutomatonStats {
+            pattern_count: replacements.len(),
+            heap_bytes: matcher.memory_usage(),
+            rendered_bytes: automaton.rendered.iter().map(Vec::len).sum(),
+            value_bytes,
+            build_time: Duration::ZERO // Not built
+        });
+        serialized_data.prepared_matcher = Some(matcher);
+        serialized_data.replacements = replacements;
+        serialized_data.rendered = automaton.rendered;
+        serialized_data.is_built = true;
+        Ok(())
+    }
+}
@@ -0,0 +1,113 @@
+//! Preview mode, as in Kuroco: editors previewing a page get the draft content instead of the published one, and the
+//! scheduled content before its open date. A signed preview token, given as query parameter or cookie, enters the mode.
+//! Tokens are `{payload}.{signature}`, the payload being base64url encoded JSON claims (including "exp", a Unix timestamp
+//! in seconds) signed with HMAC-SHA256.
+//! Entering the mode merges the drafts subtree over the tree (see merge: objects are merged, other values replaced and null
+//! removes a published value), so that every read & replacement sees the drafts, and bypasses the schedule filters
+//! of DataCache (see the schedule module).
+
+use std::time::SystemTime;
+
+use serde_json::Value;
+
+use crate::{DataCache, base64, error::JsonDataCacheError, sha256::{constant_time_eq, hmac_sha256}};
+
+/// Default name of the query parameter & cookie holding the token
+pub const DEFAULT_PREVIEW_TOKEN_NAME: &str = "preview_token";
+
+#[derive(Debug, Clone)]
+pub struct PreviewCodec {
+    signing_key: [u8; 32],
+    param_name: String,
+    cookie_name: String,
+}
+
+impl PreviewCodec {
+    pub fn new(secret: &[u8]) -> Self {
+        Self {
+            signing_key: hmac_sha256(secret, b"preview-signing"),
+            param_name: DEFAULT_PREVIEW_TOKEN_NAME.to_string(),
+            cookie_name: DEFAULT_PREVIEW_TOKEN_NAME.to_string(),
+        }
+    }
+
+    pub fn param_name(mut self, param_name: &str) -> Self {
+        self.param_name = param_name.to_string();
+        self
+    }
+
+    pub fn cookie_name(mut self, cookie_name: &str) -> Self {
+        self.cookie_name = cookie_name.to_string();
+        self
+    }
+
+    /// Signs the claims (an object) into a token valid until expires_at
+    pub fn issue(&self, claims: &Value, expires_at: SystemTime) -> Result<String, JsonDataCacheError> {
+        let Value::Object(claims) = claims else {
+            return Err("Preview token claims must be an object".into());
+        };
+        let mut claims = claims.clone();
+        let exp = expires_at.duration_since(SystemTime::UNIX_EPOCH).map_err(|_| "Preview token expiry before the Unix epoch")?.as_secs();
+        claims.insert(String::from("exp"), Value::from(exp));
+        let json = serde_json::to_vec(&claims).map_err(|e| format!("Unable to serialize preview token : {e}"))?;
+        let payload = base64::encode_url_safe(&json);
+        let signature = base64::encode_url_safe(&hmac_sha256(&self.signing_key, payload.as_bytes()));
+        Ok(format!("{payload}.{signature}"))
+    }
+
+    /// Claims of the token if its signature is valid and it has not expired at now
+    pub fn verify(&self, token: &str, now: SystemTime) -> Result<Value, JsonDataCacheError> {
+        let invalid = || -> JsonDataCacheError { "Invalid preview token".into() };
+        let (payload, signature) = token.split_once('.').ok_or_else(invalid)?;
+        let signature = base64::decode_url_safe(signature).ok_or_else(invalid)?;
+        if !constant_time_eq(&signature, &hmac_sha256(&self.signing_key, payload.as_bytes())) {
+            return Err("Invalid preview token signature".into());
+        }
+        let claims: Value = serde_json::from_slice(&base64::decode_url_safe(payload).ok_or_else(invalid)?).map_err(|_| invalid())?;
+        let exp = claims.get("exp").and_then(Value::as_u64).ok_or_else(invalid)?;
+        let now = now.duration_since(SystemTime::UNIX_EPOCH).map(|elapsed| elapsed.as_secs()).unwrap_or_default();
+        if exp <= now {
+            return Err("Expired preview token".into());
+        }
+        Ok(claims)
+    }
+
+    /// The token of the query string (without '?'), or else of the Cookie header
+    pub fn find_token<'a>(&self, query: &'a str, cookie_header: &'a str) -> Option<&'a str> {
+        let from_query = query.split('&')
+            .filter_map(|param| param.split_once('='))
+            .find(|(name, _)| *name == self.param_name);
+        let from_cookie = || cookie_header.split(';')
+            .filter_map(|cookie| cookie.trim().split_once('='))
+            .find(|(name, _)| *name == self.cookie_name);
+        from_query.or_else(from_cookie).map(|(_, token)| token).filter(|token| !token.is_empty())
+    }
+}
+
+impl DataCache {
+    /// Verifies the token against the clock of the cache and enters the preview mode, merging the subtree at drafts_path
+    /// over the tree. Returns the claims of the token. Nothing is modified if the token is invalid
+    pub fn enter_preview(&mut self, codec: &PreviewCodec, token: &str, drafts_path: &str) -> Result<Value, JsonDataCacheError> {
+        let claims = codec.verify(token, self.clock().now())?;
+        match self.get(drafts_path).cloned() {
+            Some(drafts @ Value::Object(_)) => self.try_merge(drafts)?,
+            None | Some(Value::Null) => {},
+            Some(_) => return Err(format!("Drafts at {drafts_path} must be an object").into()),
+        }
+        self.preview = true;
+        Ok(claims)
+    }
+
+    /// Same as enter_preview with the token of the request, if any. Returns None without token
+    pub fn enter_preview_from_request(&mut self, codec: &PreviewCodec, query: &str, cookie_header: &str, drafts_path: &str) -> Result<Option<Value>, JsonDataCacheError> {
+        match codec.find_token(query, cookie_header) {
+            Some(token) => self.enter_preview(codec, token, drafts_path).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// True once enter_preview succeeded
+    pub fn is_preview(&self) -> bool {
+        self.preview
+    }
+}
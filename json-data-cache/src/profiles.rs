@@ -0,0 +1,75 @@
+//! Transformation profiles: structural variants of the same render, like the AMP version of a page, produced by rewriting
+//! its HTML (see the html_rewriter module) rather than maintaining a parallel set of templates. Profiles are registered in
+//! the cache by name, a render selecting one per request (like "amp" for /amp/ URLs) through replace_with_profile.
+
+use std::collections::HashMap;
+#[cfg(feature = "replace-engine")]
+use std::io;
+
+use crate::{DataCache, html_rewriter::RewriteRule};
+#[cfg(feature = "replace-engine")]
+use crate::{error::JsonDataCacheError, html_rewriter::HtmlRewriter};
+
+/// A named list of rewrite rules, applied in order
+#[derive(Debug, Clone)]
+pub struct TransformProfile {
+    pub name: String,
+    pub rules: Vec<RewriteRule>,
+}
+
+impl TransformProfile {
+    pub fn new(name: &str) -> Self {
+        Self { name: name.to_string(), rules: Vec::new() }
+    }
+
+    pub fn rule(mut self, rule: RewriteRule) -> Self {
+        self.rules.push(rule);
+        self
+    }
+
+    /// AMP pages: scripts (JSON-LD data excepted), stylesheet links, event handler & style attributes removed, media
+    /// elements swapped for their AMP components (responsive when sized), the html element flagged amp. The AMP runtime
+    /// script & amp-custom styles are left to the template of the profile's layout, like with {$page.amp_head}
+    pub fn amp() -> Self {
+        Self::new("amp")
+            .rule(RewriteRule::new("html").set_attribute("amp", ""))
+            .rule(RewriteRule::new("script").unless("type", "ld+json").remove())
+            .rule(RewriteRule::new("link").when("rel", "stylesheet").remove())
+            .rule(RewriteRule::new("*").remove_attribute("on*").remove_attribute("style"))
+            .rule(RewriteRule::new("img").when("width", "").when("height", "").set_attribute("layout", "responsive"))
+            .rule(RewriteRule::new("img").remove_attribute("loading").rename("amp-img"))
+            .rule(RewriteRule::new("video").rename("amp-video"))
+            .rule(RewriteRule::new("audio").rename("amp-audio"))
+            .rule(RewriteRule::new("iframe").when("width", "").when("height", "").set_attribute("layout", "responsive"))
+            .rule(RewriteRule::new("iframe").rename("amp-iframe"))
+    }
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct DataCacheProfiles {
+    profiles: HashMap<String, TransformProfile>,
+}
+
+impl DataCache {
+    /// Registers a profile, replacing any previous one of the same name
+    pub fn register_profile(&mut self, profile: TransformProfile) {
+        self.profiles.profiles.insert(profile.name.clone(), profile);
+    }
+
+    pub fn profile(&self, name: &str) -> Option<&TransformProfile> {
+        self.profiles.profiles.get(name)
+    }
+
+    /// Same as replace_with_data_cache, the output being rewritten by the rules of the profile in the same pass
+    #[cfg(feature = "replace-engine")]
+    pub fn replace_with_profile<R, W>(&mut self, profile_name: &str, reader: R, writer: W) -> Result<W, JsonDataCacheError>
+    where
+        R: io::Read,
+        W: io::Write,
+    {
+        let rules = self.profile(profile_name).ok_or_else(|| format!("Unknown profile {profile_name}"))?.rules.clone();
+        let mut rewriter = HtmlRewriter::new(writer, rules);
+        self.replace_with_data_cache(reader, &mut rewriter)?;
+        Ok(rewriter.finish()?)
+    }
+}
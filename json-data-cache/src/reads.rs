@@ -0,0 +1,131 @@
+//! Read tracking, opt-in: between track_reads & take_reads, the cache records the paths read through get (get_many &
+//! get_list included, so the paths of computed expressions & pipeline conditions too) and the keys of the expanded
+//! placeholders. The tracked reads tell which dynamic paths a render depends on (see the vary module), and which values of
+//! the cache templates never use (see read_coverage). Building the automaton reads every value, it is not tracked.
+
+use std::{cell::RefCell, collections::BTreeSet};
+#[cfg(feature = "replace-engine")]
+use std::io;
+
+use serde_json::Value;
+
+#[cfg(feature = "replace-engine")]
+use crate::error::JsonDataCacheError;
+use crate::{DataCache, is_scratch_path, path_pattern::PathPattern};
+
+#[derive(Debug, Default)]
+pub(crate) struct DataCacheReads {
+    reads: RefCell<Option<BTreeSet<String>>>, // Paths read since track_reads, None when not tracking
+}
+
+impl DataCacheReads {
+    pub(crate) fn is_tracking(&self) -> bool {
+        self.reads.borrow().is_some()
+    }
+
+    /// Called by the reads of the cache
+    pub(crate) fn record(&self, path: &str) {
+        if let Some(reads) = self.reads.borrow_mut().as_mut() {
+            reads.insert(path.to_string());
+        }
+    }
+
+    /// Records the paths of the tree matching a wildcard pattern, or the path itself without wildcards
+    pub(crate) fn record_pattern(&self, root: &Value, pattern: &str) {
+        if !self.is_tracking() {
+            return;
+        }
+        let path_pattern = PathPattern::from(pattern);
+        if !path_pattern.has_wildcards() {
+            return self.record(pattern);
+        }
+        for path in path_pattern.matching_paths(root) {
+            self.record(&path);
+        }
+    }
+
+    /// Suspends tracking, returning the reads to give back to resume
+    #[cfg(feature = "replace-engine")]
+    pub(crate) fn pause(&self) -> Option<BTreeSet<String>> {
+        self.reads.take()
+    }
+
+    #[cfg(feature = "replace-engine")]
+    pub(crate) fn resume(&self, reads: Option<BTreeSet<String>>) {
+        self.reads.replace(reads);
+    }
+}
+
+/// Leaf values of the cache read by a render, see read_coverage
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ReadCoverage {
+    pub read: Vec<String>, // Paths of the read leaves (their own path or an ancestor was read), sorted
+    pub unread: Vec<String>, // Paths of the other leaves, sorted
+}
+
+impl ReadCoverage {
+    /// Share of the leaves which were read, 1 without any leaf
+    pub fn ratio(&self) -> f64 {
+        let total = self.read.len() + self.unread.len();
+        if total == 0 { 1.0 } else { self.read.len() as f64 / total as f64 }
+    }
+}
+
+/// Paths of the leaves below node (scalars, empty arrays & objects), scratch values excluded
+fn collect_leaves(node: &Value, path: &str, leaves: &mut Vec<String>) {
+    let child_path = |key: &str| if path.is_empty() { key.to_string() } else { format!("{path}.{key}") };
+    match node {
+        Value::Object(o) if !o.is_empty() => {
+            for (key, child) in o.iter().filter(|(key, _)| !(path.is_empty() && is_scratch_path(key))) {
+                collect_leaves(child, &child_path(key), leaves);
+            }
+        },
+        Value::Array(items) if !items.is_empty() => {
+            for (idx, child) in items.iter().enumerate() {
+                collect_leaves(child, &child_path(&idx.to_string()), leaves);
+            }
+        },
+        _ if !path.is_empty() => leaves.push(path.to_string()),
+        _ => {},
+    }
+}
+
+impl DataCache {
+    /// Starts tracking the paths read, dropping any previous tracked reads
+    pub fn track_reads(&self) {
+        self.reads.reads.replace(Some(BTreeSet::new()));
+    }
+
+    /// Stops tracking reads, returning the paths read since track_reads, sorted
+    pub fn take_reads(&self) -> Vec<String> {
+        self.reads.reads.take().unwrap_or_default().into_iter().collect()
+    }
+
+    /// Same as replace_with_data_cache, returning the paths read by the render (see track_reads)
+    #[cfg(feature = "replace-engine")]
+    pub fn replace_with_read_tracking<R, W>(&mut self, reader: R, writer: W) -> Result<Vec<String>, JsonDataCacheError>
+    where
+        R: io::Read,
+        W: io::Write,
+    {
+        self.track_reads();
+        let result = self.replace_with_data_cache(reader, writer);
+        let reads = self.take_reads();
+        result.map(|_| reads)
+    }
+
+    /// Splits the leaves of the tree between the ones covered by the reads & the others, for reports of the data a
+    /// template never uses
+    /// Example: read_coverage(&data_cache.replace_with_read_tracking(template, output)?)
+    pub fn read_coverage(&self, reads: &[String]) -> ReadCoverage {
+        let reads: BTreeSet<&str> = reads.iter().map(String::as_str).collect();
+        let mut leaves = Vec::new();
+        collect_leaves(&self.root, "", &mut leaves);
+        leaves.sort_unstable();
+        let (read, unread) = leaves.into_iter().partition(|leaf: &String| {
+            let mut prefixes = leaf.match_indices('.').map(|(idx, _)| &leaf[..idx]).chain([leaf.as_str()]);
+            prefixes.any(|prefix| reads.contains(prefix))
+        });
+        ReadCoverage { read, unread }
+    }
+}
@@ -0,0 +1,155 @@
+//! Record & replay of cache interactions: a Recorder wraps a DataCache and logs its inserts, merges, reads & replacements
+//! (one JSON object per line), and replay rebuilds the cache state from such a log and renders its templates again,
+//! reporting any difference with the recorded results. This reproduces production rendering bugs locally.
+
+#[cfg(feature = "replace-engine")]
+use std::io::Read;
+use std::io::{self, BufRead, Write};
+
+use serde_json::{Value, json};
+
+#[cfg(feature = "replace-engine")]
+use crate::base64;
+use crate::{DataCache, DataCacheOptions, error::JsonDataCacheError};
+
+/// Wraps a cache, logging each call made through it
+#[derive(Debug)]
+pub struct Recorder<W: Write> {
+    data_cache: DataCache,
+    log: W
+}
+
+impl<W: Write> Recorder<W> {
+    /// The current state of the cache is logged first, so that the log can be replayed without any other input
+    pub fn new(data_cache: DataCache, mut log: W) -> Result<Self, JsonDataCacheError> {
+        write_entry(&mut log, json!({"op": "merge", "value": data_cache.root}))?;
+        Ok(Self { data_cache, log })
+    }
+
+    pub fn data_cache(&self) -> &DataCache {
+        &self.data_cache
+    }
+
+    pub fn into_parts(self) -> (DataCache, W) {
+        (self.data_cache, self.log)
+    }
+
+    pub fn insert(&mut self, path: &str, value: Value) -> Result<(), JsonDataCacheError> {
+        write_entry(&mut self.log, json!({"op": "insert", "path": path, "value": value}))?;
+        self.data_cache.insert(path, value);
+        Ok(())
+    }
+
+    pub fn insert_bulk(&mut self, values: Vec<(String, Value)>) -> Result<(), JsonDataCacheError> {
+        write_entry(&mut self.log, json!({"op": "insert_bulk", "values": values}))?;
+        self.data_cache.insert_bulk(values);
+        Ok(())
+    }
+
+    pub fn merge(&mut self, value: Value) -> Result<(), JsonDataCacheError> {
+        write_entry(&mut self.log, json!({"op": "merge", "value": value}))?;
+        self.data_cache.merge(value);
+        Ok(())
+    }
+
+    /// Logs the read value along with the path, null if there was none
+    pub fn get(&mut self, path: &str) -> Result<Option<&Value>, JsonDataCacheError> {
+        let value = self.data_cache.get(path);
+        write_entry(&mut self.log, json!({"op": "get", "path": path, "value": value}))?;
+        Ok(value)
+    }
+
+    /// Same as DataCache::replace_with_data_cache, reading the whole template first to log it along with the output
+    #[cfg(feature = "replace-engine")]
+    pub fn replace_with_data_cache<R, O>(&mut self, mut reader: R, mut writer: O) -> Result<(), JsonDataCacheError>
+    where
+        R: Read,
+        O: Write,
+    {
+        let mut template = Vec::new();
+        reader.read_to_end(&mut template)?;
+        let mut output = Vec::with_capacity(template.len());
+        let result = self.data_cache.replace_with_data_cache(template.as_slice(), &mut output);
+        write_entry(&mut self.log, json!({
+            "op": "replace",
+            "template": base64::encode_url_safe(&template),
+            "output": base64::encode_url_safe(&output),
+            "error": result.as_ref().err().map(|e| e.msg.clone())
+        }))?;
+        result?;
+        writer.write_all(&output)?;
+        Ok(())
+    }
+}
+
+fn write_entry<W: Write>(log: &mut W, entry: Value) -> Result<(), JsonDataCacheError> {
+    serde_json::to_writer(&mut *log, &entry).map_err(io::Error::from)?;
+    log.write_all(b"\n")?;
+    Ok(())
+}
+
+/// Result of replay
+#[derive(Debug)]
+pub struct ReplayReport {
+    pub data_cache: DataCache, // State after the last logged call
+    pub renders: Vec<Vec<u8>>, // Outputs of the replayed replacements, in log order
+    pub mismatches: Vec<String> // Reads & replacements whose result differs from the recorded one, by log line
+}
+
+/// Replays a log written by a Recorder into a new cache built with the options
+pub fn replay<R: BufRead>(options: DataCacheOptions, log: R) -> Result<ReplayReport, JsonDataCacheError> {
+    let mut report = ReplayReport {
+        data_cache: DataCache::new(options),
+        renders: Vec::new(),
+        mismatches: Vec::new()
+    };
+    for (line_idx, line) in log.lines().enumerate() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let line_number = line_idx + 1;
+        let entry: Value = serde_json::from_str(&line).map_err(|e| format!("Invalid log entry on line {line_number} : {e}"))?;
+        let invalid = || -> JsonDataCacheError { format!("Invalid log entry on line {line_number}").into() };
+        let field = |key: &str| entry.get(key).ok_or_else(invalid);
+        let string_field = |key: &str| field(key).and_then(|value| value.as_str().ok_or_else(invalid));
+        match field("op")?.as_str() {
+            Some("insert") => report.data_cache.insert(string_field("path")?, field("value")?.clone()),
+            Some("insert_bulk") => {
+                let values: Vec<(String, Value)> = serde_json::from_value(field("values")?.clone()).map_err(|_| invalid())?;
+                report.data_cache.insert_bulk(values);
+            },
+            Some("merge") => report.data_cache.merge(field("value")?.clone()),
+            Some("get") => {
+                let path = string_field("path")?;
+                let value = report.data_cache.get(path).unwrap_or(&Value::Null);
+                if value != field("value")? {
+                    report.mismatches.push(format!("Line {line_number} : get {path} returned {value} instead of {}", field("value")?));
+                }
+            },
+            #[cfg(feature = "replace-engine")]
+            Some("replace") => {
+                let template = base64::decode_url_safe(string_field("template")?).ok_or_else(invalid)?;
+                let recorded_output = base64::decode_url_safe(string_field("output")?).ok_or_else(invalid)?;
+                let mut output = Vec::with_capacity(template.len());
+                let result = report.data_cache.replace_with_data_cache(template.as_slice(), &mut output);
+                let recorded_error = entry.get("error").and_then(Value::as_str);
+                match (&result, recorded_error) {
+                    (Ok(_), None) if output != recorded_output => {
+                        report.mismatches.push(format!("Line {line_number} : replacement output differs from the recorded one"));
+                    },
+                    (Err(e), None) => report.mismatches.push(format!("Line {line_number} : replacement failed with {}", e.msg)),
+                    (Ok(_), Some(recorded_error)) => {
+                        report.mismatches.push(format!("Line {line_number} : replacement succeeded, recorded failure {recorded_error}"));
+                    },
+                    _ => {},
+                }
+                report.renders.push(output);
+            },
+            #[cfg(not(feature = "replace-engine"))]
+            Some("replace") => return Err(format!("Replaying the replacement on line {line_number} requires the replace-engine feature").into()),
+            _ => return Err(invalid()),
+        }
+    }
+    Ok(report)
+}
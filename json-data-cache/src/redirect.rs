@@ -0,0 +1,131 @@
+//! Redirect target validation, against open redirects: edge redirects to a target taken from the request (like
+//! ?next=/mypage after a login) must not send users to another site. Targets are checked against an allowlist of the
+//! cache, like {"hosts": ["example.com", "*.example.com"], "schemes": ["https"], "fallback": "/"}, either by
+//! validate_redirect_target or in templates by the {$key|safe_redirect} filter (see DataCacheOptions::safe_redirect_keys),
+//! writing the fallback instead of a target that is not allowed.
+
+use serde_json::Value;
+
+use crate::{DataCache, error::JsonDataCacheError};
+
+/// Default path of the redirect allowlist
+pub const DEFAULT_REDIRECT_ALLOWLIST_PATH: &str = "redirect_allowlist";
+
+/// Filter of the placeholders replaced by a safe redirect target, like {$query.next|safe_redirect}
+pub const SAFE_REDIRECT_FILTER: &str = "safe_redirect";
+
+/// Fallback of allowlists without one, and of targets checked against a missing or invalid allowlist
+pub const DEFAULT_REDIRECT_FALLBACK: &str = "/";
+
+/// Allowed targets of redirects. Root-relative paths (same origin) are always allowed, absolute & protocol-relative URLs
+/// if their host is listed ("*.example.com" matching its subdomains only) and their scheme too
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RedirectAllowlist {
+    pub hosts: Vec<String>, // Lowercase
+    pub schemes: Vec<String>, // Lowercase, ["https", "http"] when not set
+    pub fallback: String,
+}
+
+impl RedirectAllowlist {
+    /// Parses an allowlist stored in the cache, an object with a "hosts" array & optional "schemes" array & "fallback" string
+    pub fn from_value(value: &Value) -> Result<Self, JsonDataCacheError> {
+        let strings = |name: &str| -> Result<Option<Vec<String>>, JsonDataCacheError> {
+            let Some(list) = value.get(name) else {
+                return Ok(None);
+            };
+            let list = list.as_array().ok_or(format!("{name} of a redirect allowlist is not an array"))?;
+            list.iter()
+                .map(|item| item.as_str().map(str::to_ascii_lowercase).ok_or(format!("{name} of a redirect allowlist are not all strings").into()))
+                .collect::<Result<Vec<String>, JsonDataCacheError>>()
+                .map(Some)
+        };
+        if !value.is_object() {
+            return Err("Redirect allowlist is not an object".into());
+        }
+        let fallback = match value.get("fallback") {
+            Some(Value::String(fallback)) => fallback.clone(),
+            Some(_) => return Err("fallback of a redirect allowlist is not a string".into()),
+            None => DEFAULT_REDIRECT_FALLBACK.to_string(),
+        };
+        Ok(Self {
+            hosts: strings("hosts")?.unwrap_or_default(),
+            schemes: strings("schemes")?.unwrap_or_else(|| Vec::from([String::from("https"), String::from("http")])),
+            fallback,
+        })
+    }
+
+    /// Checks the target, failing with the reason it is not allowed
+    pub fn validate(&self, target: &str) -> Result<(), JsonDataCacheError> {
+        let target = target.trim_matches(|c: char| c.is_ascii_whitespace());
+        // Browsers read \ as / (/\evil.com is //evil.com) and drop tabs & newlines anywhere in URLs
+        if target.is_empty() || target.contains(|c: char| c == '\\' || c.is_control() || c.is_whitespace()) {
+            return Err(format!("Invalid redirect target {target:?}").into());
+        }
+        let scheme_len = target.find(':').filter(|len| {
+            let scheme = &target[..*len];
+            scheme.starts_with(|c: char| c.is_ascii_alphabetic()) && scheme.bytes().all(|b| b.is_ascii_alphanumeric() || matches!(b, b'+' | b'-' | b'.'))
+        });
+        let authority = match scheme_len {
+            Some(len) => {
+                let scheme = target[..len].to_ascii_lowercase();
+                if !self.schemes.contains(&scheme) {
+                    return Err(format!("Redirect target scheme {scheme} is not allowed").into());
+                }
+                target[len + 1..].strip_prefix("//").ok_or(format!("Redirect target {target} has no host"))?
+            },
+            None => match target.strip_prefix("//") {
+                Some(authority) => authority,
+                None if target.starts_with('/') => return Ok(()), // Same origin
+                None => return Err(format!("Redirect target {target} is not a root-relative path").into()),
+            },
+        };
+        let authority = &authority[..authority.find(['/', '?', '#']).unwrap_or(authority.len())];
+        if authority.contains('@') {
+            return Err(format!("Redirect target {target} has credentials").into());
+        }
+        let host = authority.rsplit_once(':').filter(|(_, port)| port.bytes().all(|b| b.is_ascii_digit())).map_or(authority, |(host, _)| host);
+        let host = host.strip_suffix('.').unwrap_or(host).to_ascii_lowercase();
+        let is_allowed = !host.is_empty() && self.hosts.iter().any(|allowed| match allowed.strip_prefix("*.") {
+            Some(domain) => host.strip_suffix(domain).is_some_and(|subdomain| subdomain.len() > 1 && subdomain.ends_with('.')),
+            None => host == *allowed,
+        });
+        if !is_allowed {
+            return Err(format!("Redirect target host {host} is not allowed").into());
+        }
+        Ok(())
+    }
+
+    /// The target if allowed, else the fallback
+    pub fn safe_target<'a>(&'a self, target: &'a str) -> &'a str {
+        match self.validate(target) {
+            Ok(()) => target.trim_matches(|c: char| c.is_ascii_whitespace()),
+            Err(_) => &self.fallback,
+        }
+    }
+}
+
+impl DataCache {
+    /// Reads the allowlist at allowlist_path, failing if missing or invalid
+    pub fn redirect_allowlist(&self, allowlist_path: &str) -> Result<RedirectAllowlist, JsonDataCacheError> {
+        let allowlist = self.get(allowlist_path).ok_or(format!("No redirect allowlist at {allowlist_path}"))?;
+        RedirectAllowlist::from_value(allowlist)
+    }
+
+    /// Checks a redirect target against the allowlist at allowlist_path, failing with the reason it is not allowed
+    /// Example: validate_redirect_target("https://evil.example/", DEFAULT_REDIRECT_ALLOWLIST_PATH) => Err(..)
+    pub fn validate_redirect_target(&self, url: &str, allowlist_path: &str) -> Result<(), JsonDataCacheError> {
+        self.redirect_allowlist(allowlist_path)?.validate(url)
+    }
+
+    /// The target if allowed by the allowlist at allowlist_path, else the fallback of the allowlist
+    /// (DEFAULT_REDIRECT_FALLBACK if it is missing or invalid), as written by the safe_redirect filter
+    pub fn safe_redirect_target(&self, url: &str, allowlist_path: &str) -> String {
+        match self.redirect_allowlist(allowlist_path) {
+            Ok(allowlist) => allowlist.safe_target(url).to_string(),
+            Err(e) => {
+                log::info!("[WARN] DataCache safe_redirect_target : {}", e);
+                DEFAULT_REDIRECT_FALLBACK.to_string()
+            },
+        }
+    }
+}
@@ -0,0 +1,171 @@
+use std::{borrow::Cow, collections::HashMap};
+
+use serde_json::Value;
+
+use crate::{DataCache, compression::DataCacheCompression, computed::paths_overlap, error::JsonDataCacheError};
+
+/// Maximum count of references followed while resolving a path, as references may point to other references
+const MAX_REF_DEPTH: usize = 16;
+
+/// Aliases registered by insert_ref, mapping each alias path to its referenced path, and fallback chains registered by insert_fallback
+#[derive(Debug, Clone, Default)]
+pub(crate) struct DataCacheRefs {
+    aliases: HashMap<String, String>,
+    fallbacks: HashMap<String, Vec<String>>, // Alias path & the paths it resolves to, first one set (not null) first
+}
+
+impl DataCacheRefs {
+    /// Finds the longest alias being the path or one of its ancestors, returning its length and referenced path
+    fn find(&self, path: &str) -> Option<(usize, &str)> {
+        if self.aliases.is_empty() {
+            return None;
+        }
+        let mut end = path.len();
+        loop {
+            if let Some(target) = self.aliases.get(&path[..end]) {
+                return Some((end, target));
+            }
+            end = path[..end].rfind('.')?;
+        }
+    }
+
+    /// Rewrites the path through references until it points to a node of the tree
+    pub(crate) fn resolve<'a>(&self, path: &'a str) -> Cow<'a, str> {
+        let mut resolved = Cow::Borrowed(path);
+        for _ in 0..MAX_REF_DEPTH {
+            let Some((alias_len, target)) = self.find(&resolved) else {
+                break;
+            };
+            let rewritten = format!("{target}{}", &resolved[alias_len..]);
+            resolved = Cow::Owned(rewritten);
+        }
+        resolved
+    }
+
+    /// Rewrites a path covered by a fallback chain into the first path of the chain having a value which is not null
+    /// Compressed strings, held as null in root, count as values
+    pub(crate) fn resolve_fallback(&self, root: &Value, compression: &DataCacheCompression, path: &str) -> Option<String> {
+        if self.fallbacks.is_empty() {
+            return None;
+        }
+        let mut end = path.len();
+        let chain = loop {
+            if let Some(chain) = self.fallbacks.get(&path[..end]) {
+                break chain;
+            }
+            end = path[..end].rfind('.')?;
+        };
+        chain.iter()
+            .map(|target| self.resolve(&format!("{target}{}", &path[end..])).into_owned())
+            .find(|candidate| compression.get(candidate).is_some() || DataCache::lookup(root, candidate).is_some_and(|value| !value.is_null()))
+    }
+
+    /// True if path is covered by a reference or a fallback chain resolving (for one of the paths of the chain) to a path
+    /// for which is_outside is true
+    #[cfg(feature = "replace-engine")]
+    pub(crate) fn resolves_outside(&self, path: &str, is_outside: impl Fn(&str) -> bool) -> bool {
+        if self.find(path).is_some() && is_outside(&self.resolve(path)) {
+            return true;
+        }
+        let mut end = path.len();
+        loop {
+            if let Some(chain) = self.fallbacks.get(&path[..end]) {
+                return chain.iter().any(|target| is_outside(&self.resolve(&format!("{target}{}", &path[end..]))));
+            }
+            match path[..end].rfind('.') {
+                Some(dot) => end = dot,
+                None => return false,
+            }
+        }
+    }
+
+    /// Copies the values of referenced keys (and their descendants) to the keys of their aliases, in a map keyed by path,
+    /// then does the same for fallback chains with the first path of the chain having a value in root
+    /// Values previously present under an alias are removed, as a reference shadows them
+    pub(crate) fn copy_values<V: Clone>(&self, root: &Value, compression: &DataCacheCompression, map: &mut HashMap<String, V>) {
+        for (alias, target) in &self.aliases {
+            copy_subtree(map, alias, &self.resolve(target));
+        }
+        for alias in self.fallbacks.keys() {
+            match self.resolve_fallback(root, compression, alias) {
+                Some(target) => copy_subtree(map, alias, &target),
+                None => copy_subtree(map, alias, ""),
+            }
+        }
+    }
+}
+
+/// Replaces the keys of alias & its descendants by copies of the ones of target (none for an empty target)
+fn copy_subtree<V: Clone>(map: &mut HashMap<String, V>, alias: &str, target: &str) {
+    let alias_prefix = format!("{alias}.");
+    map.retain(|key, _| key != alias && !key.starts_with(&alias_prefix));
+    if target.is_empty() {
+        return;
+    }
+    let target_prefix = format!("{target}.");
+    let copies: Vec<(String, V)> = map.iter()
+        .filter_map(|(key, value)| if key == target {
+            Some((alias.to_string(), value.clone()))
+        } else {
+            key.strip_prefix(&target_prefix).map(|rest| (format!("{alias_prefix}{rest}"), value.clone()))
+        })
+        .collect();
+    map.extend(copies);
+}
+
+impl DataCache {
+    /// Makes path a reference to target: reading or replacing path (or one of its descendants) resolves to the current
+    /// value of target, so that the same value is not duplicated across aliases. A reference shadows any value inserted at path.
+    /// Example: insert_ref("seo.title", "content.details.subject") => {$seo.title} renders content.details.subject
+    pub fn insert_ref(&mut self, path: &str, target: &str) -> Result<(), JsonDataCacheError> {
+        let previous = self.refs.aliases.remove(path);
+        let resolved_target = self.refs.resolve(target).into_owned();
+        if paths_overlap(path, &resolved_target) || self.refs.find(&resolved_target).is_some() {
+            // Either a cycle, or too many chained references
+            if let Some(previous) = previous {
+                self.refs.aliases.insert(path.to_string(), previous);
+            }
+            return Err(format!("Reference {path} can not point to {target}, resolved as {resolved_target}").into());
+        }
+        self.refs.aliases.insert(path.to_string(), target.to_string());
+        self.mark_dirty(path);
+        self.update_computed(&[path]);
+
+        self.on_after_insert();
+        Ok(())
+    }
+
+    /// Returns the path referenced by the alias, if path was set with insert_ref
+    pub fn get_ref(&self, path: &str) -> Option<&str> {
+        self.refs.aliases.get(path).map(|target| target.as_str())
+    }
+
+    /// Makes path resolve to the first path of the chain having a value which is not null, on reads & replacements.
+    /// Like a reference, it shadows any value inserted at path
+    /// Example: insert_fallback("locale", &["user.pref.locale", "geo.locale", "site.default_locale"]) => {$locale} renders
+    /// the preferred locale of the user if set, otherwise the one of its location, otherwise the default one
+    pub fn insert_fallback(&mut self, path: &str, chain: &[&str]) -> Result<(), JsonDataCacheError> {
+        if chain.is_empty() {
+            return Err(format!("Fallback chain of {path} is empty").into());
+        }
+        if let Some(target) = chain.iter().find(|target| paths_overlap(path, &self.refs.resolve(target))) {
+            return Err(format!("Fallback chain of {path} can not contain {target}").into());
+        }
+        self.refs.fallbacks.insert(path.to_string(), chain.iter().map(|target| target.to_string()).collect());
+        self.mark_dirty(path);
+        self.update_computed(&[path]);
+
+        self.on_after_insert();
+        Ok(())
+    }
+
+    /// Returns the chain of the path, if path was set with insert_fallback
+    pub fn get_fallback(&self, path: &str) -> Option<&[String]> {
+        self.refs.fallbacks.get(path).map(Vec::as_slice)
+    }
+
+    /// Returns the first value of paths which is not null
+    pub fn get_with_fallback(&self, paths: &[&str]) -> Option<&Value> {
+        paths.iter().filter_map(|path| self.get(path)).find(|value| !value.is_null())
+    }
+}
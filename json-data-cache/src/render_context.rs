@@ -0,0 +1,129 @@
+//! Request wide display settings: the locale, time zone, currency & device of a request, derived once from the values of the
+//! cache at RenderContextPaths (until the next insert), and consulted by the context renderers (see set_context_renderer)
+//! instead of each of them reading & parsing those values again. override_render_context replaces them, like for a preview
+//! of another locale.
+
+use std::cell::OnceCell;
+
+use serde_json::Value;
+
+use crate::DataCache;
+
+/// Display settings of a request
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RenderContext {
+    pub locale: String, // BCP 47 tag like "ja-JP", "-" separated
+    pub timezone_offset_minutes: i32, // From UTC, like 540 for Japan
+    pub currency: String, // ISO 4217 code like "JPY", empty if unknown
+    pub device: String, // Device class like "mobile", empty if unknown
+}
+
+impl Default for RenderContext {
+    fn default() -> Self {
+        Self {
+            locale: String::from("en"),
+            timezone_offset_minutes: 0,
+            currency: String::new(),
+            device: String::new(),
+        }
+    }
+}
+
+/// Fixed offsets of common time zone names. Zones observing daylight saving time are left out
+const TIMEZONE_OFFSETS: &[(&str, i32)] = &[
+    ("UTC", 0), ("GMT", 0), ("Etc/UTC", 0), ("Asia/Tokyo", 540), ("JST", 540), ("Asia/Seoul", 540), ("KST", 540),
+    ("Asia/Shanghai", 480), ("Asia/Hong_Kong", 480), ("Asia/Taipei", 480), ("Asia/Singapore", 480), ("Asia/Manila", 480),
+    ("Asia/Bangkok", 420), ("Asia/Jakarta", 420), ("Asia/Ho_Chi_Minh", 420), ("Asia/Kolkata", 330), ("Asia/Dubai", 240),
+];
+
+/// Offset from UTC of a time zone value: minutes, "+09:00" like offsets or a name of TIMEZONE_OFFSETS
+pub fn parse_timezone(value: &Value) -> Option<i32> {
+    let s = match value {
+        Value::Number(n) => return n.as_i64().and_then(|minutes| i32::try_from(minutes).ok()).filter(|minutes| minutes.abs() < 24 * 60),
+        Value::String(s) => s.trim(),
+        _ => return None,
+    };
+    if let Some((_, offset)) = TIMEZONE_OFFSETS.iter().find(|(name, _)| name.eq_ignore_ascii_case(s)) {
+        return Some(*offset);
+    }
+    if s == "Z" {
+        return Some(0);
+    }
+    let (sign, offset) = match s.strip_prefix(['+', '-']) {
+        Some(offset) if s.starts_with('-') => (-1, offset),
+        Some(offset) => (1, offset),
+        None => return None,
+    };
+    let (hours, minutes) = offset.split_once(':').unwrap_or((offset, "0"));
+    let (hours, minutes) = (hours.parse::<i32>().ok()?, minutes.parse::<i32>().ok()?);
+    (hours < 24 && minutes < 60).then_some(sign * (hours * 60 + minutes))
+}
+
+/// Paths of the values the render context is derived from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RenderContextPaths {
+    pub locale: String,
+    pub timezone: String,
+    pub currency: String,
+    pub device: String,
+}
+
+impl Default for RenderContextPaths {
+    fn default() -> Self {
+        Self {
+            locale: String::from("locale"),
+            timezone: String::from("timezone"),
+            currency: String::from("currency"),
+            device: String::from("device"),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct DataCacheRenderContext {
+    paths: RenderContextPaths,
+    derived: OnceCell<RenderContext>, // Derived on first use, reset by inserts
+    pub(crate) override_context: Option<RenderContext>,
+}
+
+impl DataCacheRenderContext {
+    pub(crate) fn on_modified(&mut self) {
+        self.derived.take();
+    }
+}
+
+impl DataCache {
+    /// Sets the paths the render context is derived from
+    pub fn set_render_context_paths(&mut self, paths: RenderContextPaths) {
+        self.render_context.paths = paths;
+        self.on_after_insert();
+    }
+
+    /// The render context of override_render_context if any, or else the one derived from the values at the render context paths.
+    /// Missing or unreadable values keep the ones of RenderContext::default
+    pub fn render_context(&self) -> &RenderContext {
+        if let Some(context) = &self.render_context.override_context {
+            return context;
+        }
+        self.render_context.derived.get_or_init(|| {
+            let paths = &self.render_context.paths;
+            let string_at = |path: &str| self.get(path).and_then(Value::as_str).map(str::trim).filter(|s| !s.is_empty());
+            let default = RenderContext::default();
+            RenderContext {
+                locale: string_at(&paths.locale).map(|locale| locale.replace('_', "-")).unwrap_or(default.locale),
+                timezone_offset_minutes: self.get(&paths.timezone).and_then(parse_timezone).unwrap_or(default.timezone_offset_minutes),
+                currency: string_at(&paths.currency).map(str::to_ascii_uppercase).unwrap_or(default.currency),
+                device: string_at(&paths.device).map(str::to_ascii_lowercase).unwrap_or(default.device),
+            }
+        })
+    }
+
+    /// Replaces the derived render context (None derives it again). Rendered values are only recomputed if it changes
+    pub fn override_render_context(&mut self, context: Option<RenderContext>) {
+        let previous = self.render_context().clone();
+        self.render_context.override_context = context;
+        if *self.render_context() != previous {
+            self.on_after_insert();
+        }
+    }
+}
@@ -0,0 +1,251 @@
+//! Display formats of values in replacements: a renderer registered for a path pattern (see PathPattern) turns the values of
+//! the matching keys into the strings replacing their {$key} placeholders, while the tree keeps the raw values.
+//! {$$key} placeholders are not rendered, as they are meant for JSON contexts.
+//! Context renderers (see set_context_renderer) also get the render context of the request, for locale aware formats.
+
+use std::{fmt, io, rc::Rc};
+
+use serde_json::Value;
+
+use crate::{DataCache, error::JsonDataCacheError, mock::{civil_from_days, parse_rfc3339}, path_pattern::PathPattern, render_context::RenderContext, unicode::truncate};
+
+/// Renders a value, None keeping its serialization
+pub type ValueRenderer = Box<dyn Fn(&Value) -> Option<String>>;
+
+/// Renders a value in the render context of the request, None keeping its serialization
+pub type ContextRenderer = Box<dyn Fn(&Value, &RenderContext) -> Option<String>>;
+
+/// ContextRenderer shared by the copies of the cache, like the dry run of Pipeline::explain
+type SharedRenderer = Rc<dyn Fn(&Value, &RenderContext) -> Option<String>>;
+
+/// Renderers registered by set_renderer & set_context_renderer, first matching one first
+#[derive(Clone, Default)]
+pub(crate) struct DataCacheRenderers(Vec<(String, PathPattern, SharedRenderer)>);
+
+impl DataCacheRenderers {
+    pub(crate) fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub(crate) fn render(&self, key: &str, value: &Value, context: &RenderContext) -> Option<String> {
+        self.0.iter().find(|(_, pattern, _)| pattern.is_match(key)).and_then(|(_, _, renderer)| renderer(value, context))
+    }
+
+    /// True if a renderer is registered for the key but does not render the value, which keeps its serialization
+    pub(crate) fn rejects(&self, key: &str, value: &Value, context: &RenderContext) -> bool {
+        self.0.iter().find(|(_, pattern, _)| pattern.is_match(key)).is_some_and(|(_, _, renderer)| renderer(value, context).is_none())
+    }
+}
+
+impl fmt::Debug for DataCacheRenderers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.0.iter().map(|(pattern, _, _)| pattern)).finish()
+    }
+}
+
+/// Decimal number with its integer part grouped by thousands with separator, and its fraction after decimal_separator
+fn group_digits(number: &str, separator: &str, decimal_separator: &str) -> String {
+    let (sign, digits) = number.strip_prefix('-').map_or(("", number), |digits| ("-", digits));
+    let (integer, fraction) = digits.split_once('.').map_or((digits, None), |(integer, fraction)| (integer, Some(fraction)));
+    let mut grouped = String::with_capacity(number.len() + integer.len() / 3 * separator.len());
+    grouped.push_str(sign);
+    for (idx, digit) in integer.chars().enumerate() {
+        if idx > 0 && (integer.len() - idx) % 3 == 0 {
+            grouped.push_str(separator);
+        }
+        grouped.push(digit);
+    }
+    if let Some(fraction) = fraction {
+        grouped.push_str(decimal_separator);
+        grouped.push_str(fraction);
+    }
+    grouped
+}
+
+/// Numbers with their integer part grouped by thousands, between prefix & suffix. Example: grouped_number("", "円", ",")
+/// renders 1234 as 1,234円. Numeric strings are rendered too
+pub fn grouped_number(prefix: &str, suffix: &str, separator: &str) -> impl Fn(&Value) -> Option<String> + 'static {
+    let (prefix, suffix, separator) = (prefix.to_string(), suffix.to_string(), separator.to_string());
+    move |value| {
+        let number = match value {
+            Value::Number(n) => n.to_string(),
+            Value::String(s) if s.parse::<f64>().is_ok_and(f64::is_finite) => s.trim().to_string(),
+            _ => return None,
+        };
+        Some(format!("{prefix}{}{suffix}", group_digits(&number, &separator, ".")))
+    }
+}
+
+/// RFC 3339 date-times (or Unix timestamps in seconds) rendered as "YYYY-MM-DD HH:MM" in the time zone at offset_minutes
+/// from UTC, like 540 for Japan
+pub fn datetime_at_offset(offset_minutes: i32) -> impl Fn(&Value) -> Option<String> + 'static {
+    move |value| {
+        let ((year, month, day), (hour, minute)) = local_datetime(timestamp_of(value)?, offset_minutes);
+        Some(format!("{year:04}-{month:02}-{day:02} {hour:02}:{minute:02}"))
+    }
+}
+
+/// Date & time of a timestamp at offset_minutes from UTC
+fn local_datetime(timestamp: i64, offset_minutes: i32) -> ((i64, u32, u32), (i64, i64)) {
+    let local = timestamp + i64::from(offset_minutes) * 60;
+    let seconds = local.rem_euclid(86_400);
+    (civil_from_days(local.div_euclid(86_400)), (seconds / 3600, seconds / 60 % 60))
+}
+
+/// Timestamp of RFC 3339 date-times or Unix timestamps in seconds
+fn timestamp_of(value: &Value) -> Option<i64> {
+    match value {
+        Value::Number(n) => n.as_i64(),
+        Value::String(s) => parse_rfc3339(s),
+        _ => None,
+    }
+}
+
+/// Language of a locale, like "ja" for "ja-JP"
+fn language(locale: &str) -> &str {
+    locale.split('-').next().unwrap_or(locale)
+}
+
+/// Thousands & decimal separators of a locale
+fn number_separators(locale: &str) -> (&'static str, &'static str) {
+    match language(locale) {
+        "de" | "es" | "it" | "pt" | "nl" | "id" | "tr" | "da" => (".", ","),
+        "fr" | "ru" | "pl" | "cs" | "sv" | "fi" | "nb" | "uk" => ("\u{202f}", ","),
+        _ => (",", "."),
+    }
+}
+
+/// Context renderer of RFC 3339 date-times (or Unix timestamps in seconds) in the time zone of the render context, in the
+/// date order of its locale: "YYYY/MM/DD HH:MM" for ja, zh & ko, "MM/DD/YYYY HH:MM" for en-US, "DD.MM.YYYY HH:MM" for de...
+pub fn localized_datetime() -> impl Fn(&Value, &RenderContext) -> Option<String> + 'static {
+    |value, context| {
+        let ((year, month, day), (hour, minute)) = local_datetime(timestamp_of(value)?, context.timezone_offset_minutes);
+        let date = match (language(&context.locale), context.locale.as_str()) {
+            ("ja" | "zh" | "ko", _) => format!("{year:04}/{month:02}/{day:02}"),
+            (_, "en" | "en-US") => format!("{month:02}/{day:02}/{year:04}"),
+            ("de" | "ru" | "pl" | "cs" | "fi" | "nb" | "da" | "tr", _) => format!("{day:02}.{month:02}.{year:04}"),
+            ("en" | "fr" | "es" | "it" | "pt" | "nl" | "id" | "uk", _) => format!("{day:02}/{month:02}/{year:04}"),
+            _ => format!("{year:04}-{month:02}-{day:02}"),
+        };
+        Some(format!("{date} {hour:02}:{minute:02}"))
+    }
+}
+
+/// Numbers (or numeric strings) with the separators of the locale of the render context, like 1,234.5 for en and 1.234,5 for de
+pub fn localized_number() -> impl Fn(&Value, &RenderContext) -> Option<String> + 'static {
+    |value, context| {
+        let number = numeric_string(value)?;
+        let (separator, decimal_separator) = number_separators(&context.locale);
+        Some(group_digits(&number, separator, decimal_separator))
+    }
+}
+
+fn numeric_string(value: &Value) -> Option<String> {
+    match value {
+        Value::Number(n) => Some(n.to_string()),
+        Value::String(s) if s.trim().parse::<f64>().is_ok_and(f64::is_finite) => Some(s.trim().to_string()),
+        _ => None,
+    }
+}
+
+/// Prices in the currency of the render context, with its digits (none for JPY & KRW, 2 otherwise) & the separators &
+/// symbol position of the locale: 1,234円 for ja & JPY, ¥1,234 for en & JPY, 1.234,50 € for de & EUR, $1,234.50 for USD...
+/// Without a currency, prices are rendered as localized_number
+pub fn localized_price() -> impl Fn(&Value, &RenderContext) -> Option<String> + 'static {
+    |value, context| {
+        let number: f64 = numeric_string(value)?.parse().ok()?;
+        let digits = if matches!(context.currency.as_str(), "JPY" | "KRW") { 0 } else { 2 };
+        let (separator, decimal_separator) = number_separators(&context.locale);
+        let amount = group_digits(&format!("{number:.digits$}"), separator, decimal_separator);
+        let is_ja = language(&context.locale) == "ja";
+        let suffix_symbol = decimal_separator == ",";
+        Some(match (context.currency.as_str(), is_ja) {
+            ("", _) => amount,
+            ("JPY", true) => format!("{amount}円"),
+            (currency, _) => {
+                let symbol = match currency {
+                    "JPY" => "¥",
+                    "USD" => "$",
+                    "EUR" => "€",
+                    "GBP" => "£",
+                    "KRW" => "₩",
+                    "CNY" => "元",
+                    currency => return Some(format!("{amount} {currency}")),
+                };
+                if suffix_symbol { format!("{amount}\u{a0}{symbol}") } else { format!("{symbol}{amount}") }
+            },
+        })
+    }
+}
+
+/// Objects of translations by locale rendered as the one of the locale of the render context, or else of its language,
+/// like {"ja": "ニュース", "en": "News"}. Other values keep their serialization
+pub fn localized_text() -> impl Fn(&Value, &RenderContext) -> Option<String> + 'static {
+    |value, context| {
+        let translations = value.as_object()?;
+        translations.get(&context.locale)
+            .or_else(|| translations.get(language(&context.locale)))
+            .and_then(Value::as_str)
+            .map(str::to_string)
+    }
+}
+
+/// Strings rendered by f, like text(normalize_width) (see the japanese module). truncated cuts strings without a closure
+pub fn text<F>(f: F) -> impl Fn(&Value) -> Option<String> + 'static
+where
+    F: Fn(&str) -> String + 'static,
+{
+    move |value| value.as_str().map(&f)
+}
+
+/// Strings cut to at most max_graphemes characters as perceived by readers, ellipsis included (see unicode::truncate)
+/// Example: set_renderer("comments.*.body", truncated(100, "…"))
+pub fn truncated(max_graphemes: usize, ellipsis: &str) -> impl Fn(&Value) -> Option<String> + 'static {
+    let ellipsis = ellipsis.to_string();
+    move |value| value.as_str().map(|s| truncate(s, max_graphemes, &ellipsis))
+}
+
+impl DataCache {
+    /// Renders the values of the keys matching pattern with renderer in {$key} replacements, replacing any renderer
+    /// previously set for the same pattern
+    /// Example: set_renderer("items.*.price", grouped_number("", "円", ","))
+    pub fn set_renderer<F>(&mut self, pattern: &str, renderer: F)
+    where
+        F: Fn(&Value) -> Option<String> + 'static,
+    {
+        self.set_context_renderer(pattern, move |value, _| renderer(value));
+    }
+
+    /// Same as set_renderer, renderer also getting the render context of the request (see render_context)
+    /// Example: set_context_renderer("items.*.price", localized_price())
+    pub fn set_context_renderer<F>(&mut self, pattern: &str, renderer: F)
+    where
+        F: Fn(&Value, &RenderContext) -> Option<String> + 'static,
+    {
+        self.renderers.0.retain(|(existing, _, _)| existing != pattern);
+        self.renderers.0.push((pattern.to_string(), PathPattern::from(pattern), Rc::new(renderer)));
+        self.on_after_insert();
+    }
+
+    /// Same as replace_with_data_cache in another render context than the one of the cache, like for a preview of another
+    /// locale. The values rendered by renderers are recomputed if it differs (and once again on the next replacement)
+    pub fn replace_with_context<R, W>(&mut self, context: RenderContext, reader: R, writer: W) -> Result<(), JsonDataCacheError>
+    where
+        R: io::Read,
+        W: io::Write,
+    {
+        let previous = self.render_context.override_context.take();
+        self.override_render_context(Some(context));
+        let result = self.replace_with_data_cache(reader, writer);
+        self.override_render_context(previous);
+        result
+    }
+
+    /// Returns false if no renderer was set for pattern
+    pub fn remove_renderer(&mut self, pattern: &str) -> bool {
+        let count = self.renderers.0.len();
+        self.renderers.0.retain(|(existing, _, _)| existing != pattern);
+        self.on_after_insert();
+        count != self.renderers.0.len()
+    }
+}
@@ -0,0 +1,1211 @@
+use std::{borrow::Cow, cell::Cell, cmp::Reverse, collections::{HashMap, HashSet}, io, ops::{Deref, Range}, sync::{Arc, LazyLock, atomic::{AtomicBool, Ordering}}, rc::Rc, time::Duration};
+
+use aho_corasick::{AhoCorasick, Input, Match, MatchKind};
+use indexmap::IndexMap;
+use serde_json::{Value, json};
+
+use crate::{DEFAULT_MAX_DEPTH, DataCache, DataCacheSerializedData, crc32c::Crc32c, decisions::PLACEHOLDER_DECISION, error::JsonDataCacheError, exceeds_depth, json_serializer::{key_value_range::Range as KeyRange, serialized_data::SerializedDataLegacy}, minify::Minifier, path_pattern::PathPattern, redirect::{DEFAULT_REDIRECT_ALLOWLIST_PATH, DEFAULT_REDIRECT_FALLBACK, SAFE_REDIRECT_FILTER}, runtime::Clock, static_keys::StaticAutomaton, string_values::SerializedKeys};
+
+/// Figures of the last built automaton
+#[derive(Debug, Clone)]
+pub struct AutomatonStats {
+    pub pattern_count: usize,
+    pub heap_bytes: usize, // Heap memory used by the automaton itself, without replacements
+    pub rendered_bytes: usize, // Values of renderers, each distinct value being stored once
+    pub value_bytes: usize, // Replacement values, identical values sharing one slot counted once
+    pub build_time: Duration // Serialization excluded
+}
+
+/// Figures of a replacement in progress, given to the callback of replace_with_data_cache_progress
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReplaceProgress {
+    pub bytes_in: u64, // Read from the template
+    pub bytes_out: u64, // Written to the writer, which may block when the client reads slowly
+    pub elapsed: Duration
+}
+
+/// Shared flag aborting replacements, set for example when the client disconnects (possibly from another thread)
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// Counts the bytes read, failing once cancelled
+struct ProgressReader<'a, R> {
+    inner: R,
+    bytes_in: &'a Cell<u64>,
+    cancellation: Option<&'a CancellationToken>
+}
+
+impl<R: io::Read> io::Read for ProgressReader<'_, R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.cancellation.is_some_and(CancellationToken::is_cancelled) {
+            return Err(io::Error::other("Replacement cancelled"));
+        }
+        let read = self.inner.read(buf)?;
+        self.bytes_in.set(self.bytes_in.get() + read as u64);
+        Ok(read)
+    }
+}
+
+/// Counts the bytes written, reporting progress after each write & failing once cancelled
+struct ProgressWriter<'a, W, F> {
+    inner: W,
+    bytes_in: &'a Cell<u64>,
+    bytes_out: u64,
+    clock: Rc<dyn Clock>,
+    start: Duration, // Monotonic time of the start
+    on_progress: F,
+    cancellation: Option<&'a CancellationToken>
+}
+
+impl<W: io::Write, F: FnMut(&ReplaceProgress)> io::Write for ProgressWriter<'_, W, F> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if self.cancellation.is_some_and(CancellationToken::is_cancelled) {
+            return Err(io::Error::other("Replacement cancelled"));
+        }
+        let written = self.inner.write(buf)?;
+        self.bytes_out += written as u64;
+        (self.on_progress)(&ReplaceProgress {
+            bytes_in: self.bytes_in.get(),
+            bytes_out: self.bytes_out,
+            elapsed: self.clock.monotonic().saturating_sub(self.start)
+        });
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// A placeholder of a template, located in the template & in the output of its replacement
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlaceholderOffset {
+    pub template: Range<usize>,
+    pub output: Range<usize>
+}
+
+/// Offsets of the placeholders of a template, computed by placeholder_map for replace_range
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlaceholderMap {
+    pub placeholders: Vec<PlaceholderOffset>,
+    pub template_len: usize,
+    pub output_len: usize, // Length of the whole replaced document, for Content-Range headers
+    fingerprint: u32 // CRC-32C of the replacement values, detecting cache modifications since the map was computed
+}
+
+impl PlaceholderMap {
+    /// Form stored along with the template: {"template_len": 50, "output_len": 42, "fingerprint": 1234,
+    /// "placeholders": [[4, 12, 4, 16], ...]}, each placeholder being its template range then its output range
+    pub fn to_json(&self) -> Value {
+        json!({
+            "template_len": self.template_len,
+            "output_len": self.output_len,
+            "fingerprint": self.fingerprint,
+            "placeholders": self.placeholders.iter()
+                .map(|placeholder| json!([placeholder.template.start, placeholder.template.end, placeholder.output.start, placeholder.output.end]))
+                .collect::<Vec<Value>>()
+        })
+    }
+
+    /// Parses the form written by to_json
+    pub fn from_json(map: &Value) -> Result<Self, JsonDataCacheError> {
+        let field = |name: &str| map.get(name).and_then(Value::as_u64).ok_or_else(|| format!("Placeholder map has no {name}"));
+        let placeholders = map.get("placeholders").and_then(Value::as_array).ok_or("Placeholder map has no placeholders")?;
+        let placeholders = placeholders.iter().enumerate().map(|(idx, placeholder)| {
+            let offsets: Option<Vec<usize>> = placeholder.as_array()
+                .filter(|offsets| offsets.len() == 4)
+                .and_then(|offsets| offsets.iter().map(|offset| offset.as_u64().map(|offset| offset as usize)).collect());
+            match offsets.as_deref() {
+                Some(&[template_start, template_end, output_start, output_end]) if template_start <= template_end && output_start <= output_end => {
+                    Ok(PlaceholderOffset { template: template_start..template_end, output: output_start..output_end })
+                },
+                _ => Err(format!("Placeholder {idx} of the placeholder map is not 4 ordered offsets")),
+            }
+        }).collect::<Result<Vec<PlaceholderOffset>, String>>()?;
+        Ok(Self {
+            placeholders,
+            template_len: field("template_len")? as usize,
+            output_len: field("output_len")? as usize,
+            fingerprint: u32::try_from(field("fingerprint")?).map_err(|_| "Placeholder map fingerprint is not a CRC-32C")?
+        })
+    }
+}
+
+/// Steps of the construction of the automaton, see build_step
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BuildStep {
+    Serialize,
+    CollectPatterns,
+    BuildAutomaton
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BuildProgress {
+    Pending(BuildStep), // Next step
+    Done
+}
+
+/// Keys an automaton is built from
+#[derive(Clone, Copy)]
+pub(crate) enum KeyScope<'a> {
+    All,
+    Dynamic, // Without the static keys set by set_static_keys
+    Static(&'a SerializedDataLegacy, Option<&'a SerializedDataLegacy>) // Serialization of the static keys
+}
+
+/// Buffers sliced by the replacements of an automaton
+#[derive(Clone, Copy)]
+pub(crate) struct ReplacementBuffers<'a> {
+    pub(crate) serialized: &'a [u8],
+    pub(crate) double_serialized: &'a [u8],
+    pub(crate) rendered: &'a [Vec<u8>]
+}
+
+/// Patterns, their replacements & the values of Replacement::Rendered
+pub(crate) type AutomatonPatterns = (Vec<String>, Vec<Replacement>, Vec<Vec<u8>>);
+
+/// Automaton of a variant declared by define_variant, built on first use
+#[derive(Debug)]
+pub(crate) struct VariantAutomaton {
+    ac: AhoCorasick,
+    replacements: Vec<Replacement>,
+    rendered: Vec<Vec<u8>>
+}
+
+/// Markers written around each replacement in audit mode (see DataCacheOptions::audit_markers), so that the page source
+/// shows which data fed each region. {key} in open is replaced by the key of the placeholder
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuditMarkers {
+    pub open: String,
+    pub close: String,
+}
+
+impl AuditMarkers {
+    /// HTML comments: <!--kv:user.name-->value<!--/kv-->
+    pub fn html_comments() -> Self {
+        Self { open: String::from("<!--kv:{key}-->"), close: String::from("<!--/kv-->") }
+    }
+
+    fn write<W: io::Write>(&self, writer: &mut W, placeholder: &[u8], value: &[u8]) -> io::Result<()> {
+        writer.write_all(self.open.replace("{key}", &placeholder_key(placeholder)).as_bytes())?;
+        writer.write_all(value)?;
+        writer.write_all(self.close.as_bytes())
+    }
+}
+
+/// Key of a {$key} or {$$key} placeholder
+fn placeholder_key(placeholder: &[u8]) -> Cow<'_, str> {
+    let key = placeholder.strip_prefix(b"{$$").or_else(|| placeholder.strip_prefix(b"{$")).unwrap_or(placeholder);
+    String::from_utf8_lossy(key.strip_suffix(b"}").unwrap_or(key))
+}
+
+/// Output range of a replacement & key of its placeholder, see replace_with_source_map
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourceMapEntry {
+    pub output: Range<usize>,
+    pub key: String
+}
+
+/// Replacements of an output, for tooling highlighting the personalized regions of a page or measuring their coverage
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SourceMap {
+    pub entries: Vec<SourceMapEntry>, // In output order
+    pub output_len: usize
+}
+
+impl SourceMap {
+    /// Bytes of the output written by replacements
+    pub fn replaced_bytes(&self) -> usize {
+        self.entries.iter().map(|entry| entry.output.len()).sum()
+    }
+
+    /// Share of the output written by replacements, 0 for an empty output
+    pub fn coverage(&self) -> f64 {
+        if self.output_len == 0 {
+            return 0.0;
+        }
+        self.replaced_bytes() as f64 / self.output_len as f64
+    }
+
+    /// Machine readable form: {"output_len": 42, "entries": [{"start": 4, "end": 9, "key": "user.name"}]}
+    pub fn to_json(&self) -> Value {
+        json!({
+            "output_len": self.output_len,
+            "entries": self.entries.iter()
+                .map(|entry| json!({"start": entry.output.start, "end": entry.output.end, "key": entry.key}))
+                .collect::<Vec<Value>>()
+        })
+    }
+}
+
+/// Writes the value replacing a placeholder, between audit markers if any
+fn write_replacement<W: io::Write>(writer: &mut W, audit_markers: Option<&AuditMarkers>, placeholder: &[u8], value: &[u8]) -> io::Result<()> {
+    match audit_markers {
+        Some(audit_markers) => audit_markers.write(writer, placeholder, value),
+        None => writer.write_all(value),
+    }
+}
+
+/// Finds placeholders for stream_replace_all: an aho-corasick automaton, or the DFA of a prepared snapshot (see the prepared module)
+pub(crate) trait PlaceholderMatcher {
+    /// Leftmost match within the span of input, panicking like AhoCorasick::find if the search fails
+    fn find_match(&self, input: Input<'_>) -> Option<Match>;
+
+    fn try_find_match(&self, input: Input<'_>) -> Result<Option<Match>, JsonDataCacheError>;
+
+    fn max_pattern_len(&self) -> usize;
+}
+
+impl PlaceholderMatcher for AhoCorasick {
+    fn find_match(&self, input: Input<'_>) -> Option<Match> {
+        self.find(input)
+    }
+
+    fn try_find_match(&self, input: Input<'_>) -> Result<Option<Match>, JsonDataCacheError> {
+        Ok(self.try_find(input)?)
+    }
+
+    fn max_pattern_len(&self) -> usize {
+        AhoCorasick::max_pattern_len(self)
+    }
+}
+
+/// Matches the escape of placeholders, see DataCacheOptions::escape_placeholders
+static ESCAPE_AUTOMATON: LazyLock<AhoCorasick> = LazyLock::new(|| AhoCorasick::new(["\\{$"]).unwrap());
+
+/// Automata of a replacement of the tree, run in a single pass in precedence order: the one of the tree (or of a variant),
+/// the one of the static keys, then escapes
+struct ReplacementAutomata<'a> {
+    data_cache: &'a DataCache,
+    automata: Vec<&'a dyn PlaceholderMatcher>,
+    replacements: &'a [Replacement],
+    rendered: &'a [Vec<u8>],
+    static_automaton: Option<&'a StaticAutomaton>,
+    escape_idx: Option<usize>
+}
+
+impl<'a> ReplacementAutomata<'a> {
+    /// Automata of the variant if built (see prepare_variant), otherwise of the tree (see prepare)
+    fn new(data_cache: &'a DataCache, variant: Option<&str>) -> Self {
+        let serialized_data = &data_cache.serialized_data;
+        let (matcher, replacements, rendered, static_automaton): (&dyn PlaceholderMatcher, _, _, _) = match variant.and_then(|variant| serialized_data.variants.get(variant)) {
+            Some(automaton) => (&automaton.ac, automaton.replacements.as_slice(), automaton.rendered.as_slice(), None),
+            None => (
+                serialized_data.matcher().unwrap(),
+                serialized_data.replacements.as_slice(),
+                serialized_data.rendered.as_slice(),
+                data_cache.static_automaton.as_ref()
+            ),
+        };
+        let mut automata = Vec::from([matcher]);
+        if let Some(static_automaton) = static_automaton {
+            automata.push(&static_automaton.ac);
+        }
+        let escape_idx = data_cache.options.escape_placeholders.then_some(automata.len());
+        if escape_idx.is_some() {
+            automata.push(&*ESCAPE_AUTOMATON);
+        }
+        Self { data_cache, automata, replacements, rendered, static_automaton, escape_idx }
+    }
+
+    /// Writes the output of a match of stream_replace_all, returning false for escapes
+    fn write<W: io::Write>(&self, writer: &mut W, automaton_idx: usize, pattern_idx: usize, placeholder: &[u8]) -> io::Result<bool> {
+        if self.escape_idx == Some(automaton_idx) {
+            writer.write_all(b"{$")?;
+            return Ok(false);
+        }
+        let value = match self.static_automaton.filter(|_| automaton_idx > 0) {
+            Some(static_automaton) => static_automaton.replacement(self.data_cache, pattern_idx),
+            None => self.data_cache.replacement_of(self.replacements, self.data_cache.buffers(self.rendered), pattern_idx),
+        };
+        write_replacement(writer, self.data_cache.options.audit_markers.as_ref(), placeholder, value)?;
+        Ok(true)
+    }
+
+    /// Output of the bytes of a single match, like a placeholder of a PlaceholderMap, None if they are not one. The
+    /// longest match covering them wins, then the one of the first automaton, like in stream_replace_all
+    fn replace_placeholder(&self, placeholder: &[u8]) -> Result<Option<Vec<u8>>, JsonDataCacheError> {
+        for (automaton_idx, matcher) in self.automata.iter().enumerate() {
+            if let Some(mat) = matcher.try_find_match(Input::new(placeholder))?
+                && mat.range() == (0..placeholder.len()) {
+                let mut output = Vec::new();
+                self.write(&mut output, automaton_idx, mat.pattern().as_usize(), placeholder)?;
+                return Ok(Some(output));
+            }
+        }
+        Ok(None)
+    }
+}
+
+/// Template range of a match & its output, see find_placeholders
+type FoundPlaceholder = (Range<usize>, Vec<u8>);
+
+/// Counts the bytes written to the inner writer
+struct CountingWriter<W> {
+    inner: W,
+    written: usize
+}
+
+impl<W: io::Write> io::Write for CountingWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let written = self.inner.write(buf)?;
+        self.written += written;
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// When streaming replacements flush their writer (see DataCacheOptions::flush_policy)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum FlushPolicy {
+    /// Never, leaving flushes to the writer (or its owner)
+    #[default]
+    Never,
+    /// Once the whole output is written: a single flush
+    AtEnd,
+    /// Whenever at least this many bytes were written since the last flush, bounding what a buffered writer holds back
+    AfterBytes(usize),
+    /// After each replaced placeholder (and at the end), so that the output up to each value is sent as soon as it is
+    /// written: the lowest time to first byte, at the cost of a flush per placeholder
+    AfterPlaceholder,
+}
+
+/// What a replacement exceeding its DataCacheOptions::render_budget does
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OnBudgetExceeded {
+    /// Fails with RENDER_BUDGET_EXCEEDED
+    #[default]
+    Abort,
+    /// Also flags the cache degraded (see DataCache::degrade) with BUDGET_DEGRADED_REASON, so that the render can be retried
+    /// with the cheaper preset, as following ones. Replacements borrowing caches (replace_with_caches) abort only
+    Degrade,
+}
+
+/// Error message of replacements exceeding their budget
+pub const RENDER_BUDGET_EXCEEDED: &str = "Render budget exceeded";
+
+/// Reason of the degraded status set by OnBudgetExceeded::Degrade
+pub const BUDGET_DEGRADED_REASON: &str = "render-budget";
+
+/// Maximum time spent replacing a template, checked before reading each chunk of the template & before replacing each
+/// placeholder, so that a replacement running out of time stops at a placeholder boundary: the writer then holds the
+/// template replaced up to there, never half of a value. Edge platforms kill requests exceeding their wall clock limit,
+/// a budget below it leaves the time to answer otherwise
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RenderBudget {
+    pub limit: Duration,
+    pub on_exceeded: OnBudgetExceeded,
+}
+
+impl RenderBudget {
+    pub fn new(limit: Duration) -> Self {
+        Self { limit, on_exceeded: OnBudgetExceeded::Abort }
+    }
+
+    pub fn on_exceeded(mut self, on_exceeded: OnBudgetExceeded) -> Self {
+        self.on_exceeded = on_exceeded;
+        self
+    }
+}
+
+/// Monotonic end of a replacement with a budget
+pub(crate) struct Deadline<'a> {
+    clock: &'a dyn Clock,
+    start: Duration,
+    limit: Duration,
+}
+
+impl<'a> Deadline<'a> {
+    pub(crate) fn new(clock: &'a dyn Clock, limit: Duration) -> Self {
+        Self { clock, start: clock.monotonic(), limit }
+    }
+
+    fn check(&self) -> io::Result<()> {
+        let elapsed = self.clock.monotonic().saturating_sub(self.start);
+        if elapsed > self.limit {
+            return Err(io::Error::other(format!("{RENDER_BUDGET_EXCEEDED}: {} ms spent of {} ms", elapsed.as_millis(), self.limit.as_millis())));
+        }
+        Ok(())
+    }
+}
+
+/// Flushes the inner writer as told by its policy. Derefs to the inner writer
+pub(crate) struct FlushingWriter<W> {
+    inner: W,
+    policy: FlushPolicy,
+    unflushed: usize // Bytes written since the last flush
+}
+
+impl<W: io::Write> FlushingWriter<W> {
+    /// Called once a placeholder is replaced
+    fn on_replaced(&mut self) -> io::Result<()> {
+        match self.policy {
+            FlushPolicy::AfterPlaceholder => io::Write::flush(self),
+            FlushPolicy::Never | FlushPolicy::AtEnd | FlushPolicy::AfterBytes(_) => Ok(()),
+        }
+    }
+}
+
+impl<W: io::Write> io::Write for FlushingWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let written = self.inner.write(buf)?;
+        self.unflushed += written;
+        if let FlushPolicy::AfterBytes(max_unflushed) = self.policy
+            && self.unflushed >= max_unflushed {
+            self.flush()?;
+        }
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.unflushed = 0;
+        self.inner.flush()
+    }
+}
+
+impl<W> Deref for FlushingWriter<W> {
+    type Target = W;
+
+    fn deref(&self) -> &W {
+        &self.inner
+    }
+}
+
+/// How stream_replace_all writes its output
+#[derive(Default)]
+pub(crate) struct StreamOptions<'a> {
+    flush_policy: FlushPolicy,
+    deadline: Option<Deadline<'a>>, // Failing once past it
+    minify: bool, // Minifying the bytes of the input, see the minify module
+}
+
+impl<'a> StreamOptions<'a> {
+    /// Options of the replacements of the cache, timed by clock
+    fn of(data_cache: &DataCache, clock: &'a dyn Clock) -> Self {
+        Self {
+            flush_policy: data_cache.options.flush_policy,
+            deadline: data_cache.options.render_budget.map(|budget| Deadline::new(clock, budget.limit)),
+            minify: data_cache.options.minify,
+        }
+    }
+}
+
+/// Replaces the matches of several automata in a single streaming pass, calling replace with the index of the automaton,
+/// the pattern & the matched bytes. The match starting first wins, then the longest one, then the one of the first automaton.
+/// Input is read in chunks, keeping the bytes which may start a match not read completely yet, so that streaming works
+/// with every match kind. The writer is flushed as told by the flush policy (and at the end unless Never). Fails once past
+/// the deadline if any (see RenderBudget). Returns the writer once the input is replaced
+pub(crate) fn stream_replace_all<R, W, F>(
+    automata: &[&dyn PlaceholderMatcher],
+    mut reader: R,
+    writer: W,
+    options: StreamOptions,
+    mut replace: F
+) -> io::Result<W>
+where
+    R: io::Read,
+    W: io::Write,
+    F: FnMut(usize, usize, &[u8], &mut FlushingWriter<W>) -> io::Result<()>,
+{
+    let mut writer = FlushingWriter { inner: writer, policy: options.flush_policy, unflushed: 0 };
+    let mut minifier = options.minify.then(Minifier::default);
+    let deadline = options.deadline.as_ref();
+    // Bytes of the input, minified if told so
+    fn write_input<W: io::Write>(minifier: &mut Option<Minifier>, writer: &mut W, bytes: &[u8]) -> io::Result<()> {
+        match minifier {
+            Some(minifier) => minifier.write(writer, bytes),
+            None => writer.write_all(bytes),
+        }
+    }
+    const CHUNK_LEN: usize = 64 * 1024;
+    let max_pattern_len = automata.iter().map(|matcher| matcher.max_pattern_len()).max().unwrap_or(0);
+    let mut buffer: Vec<u8> = Vec::with_capacity(CHUNK_LEN + max_pattern_len);
+    let mut chunk = vec![0; CHUNK_LEN];
+    let mut is_eof = false;
+    while !is_eof {
+        if let Some(deadline) = deadline {
+            deadline.check()?;
+        }
+        match reader.read(&mut chunk) {
+            Ok(0) => is_eof = true,
+            Ok(len) => buffer.extend_from_slice(&chunk[..len]),
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        }
+        // Matches starting from safe_end may end in the next chunk
+        let safe_end = if is_eof { buffer.len() } else { buffer.len().saturating_sub(max_pattern_len.saturating_sub(1)) };
+        let mut pos = 0;
+        let mut next_matches: Vec<Option<Match>> = automata.iter().map(|matcher| matcher.find_match(Input::new(&buffer))).collect();
+        loop {
+            let next = next_matches.iter().enumerate()
+                .filter_map(|(automaton_idx, mat)| mat.map(|mat| (automaton_idx, mat)))
+                .min_by_key(|(automaton_idx, mat)| (mat.start(), Reverse(mat.len()), *automaton_idx));
+            let Some((automaton_idx, mat)) = next.filter(|(_, mat)| mat.start() < safe_end) else {
+                break;
+            };
+            write_input(&mut minifier, &mut writer, &buffer[pos..mat.start()])?;
+            if let Some(deadline) = deadline {
+                deadline.check()?;
+            }
+            if let Some(minifier) = minifier.as_mut() {
+                minifier.flush_pending(&mut writer)?;
+            }
+            replace(automaton_idx, mat.pattern().as_usize(), &buffer[mat.range()], &mut writer)?;
+            writer.on_replaced()?;
+            pos = mat.end();
+            for (matcher, next_match) in automata.iter().zip(next_matches.iter_mut()) {
+                if next_match.is_some_and(|next_match| next_match.start() < pos) {
+                    *next_match = matcher.find_match(Input::new(&buffer).span(pos..buffer.len()));
+                }
+            }
+        }
+        let end = safe_end.max(pos);
+        write_input(&mut minifier, &mut writer, &buffer[pos..end])?;
+        buffer.drain(..end);
+    }
+    if let Some(minifier) = minifier.as_mut() {
+        minifier.flush_pending(&mut writer)?;
+    }
+    if writer.policy != FlushPolicy::Never {
+        io::Write::flush(&mut writer)?;
+    }
+    Ok(writer.inner)
+}
+
+/// Source of a replacement value, sliced when a pattern matches so that values are never copied out of their buffers
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) enum Replacement {
+    Serialized(usize, usize), // Range in the serialized data
+    DoubleSerialized(usize, usize), // Range in the doubly serialized data
+    Raw(usize), // Index of a value set by insert_bytes
+    Rendered(usize) // Index of a value rendered by a renderer set with set_renderer
+}
+
+impl DataCacheSerializedData {
+    /// Automaton of the tree: the one built by prepare, else the one restored by from_prepared_bytes
+    pub(crate) fn matcher(&self) -> Option<&dyn PlaceholderMatcher> {
+        match (&self.ac, &self.prepared_matcher) {
+            (Some(ac), _) => Some(ac),
+            (None, Some(prepared_matcher)) => Some(prepared_matcher),
+            (None, None) => None,
+        }
+    }
+}
+
+impl DataCache {
+    /// Builds serialized data & the automaton ahead of time, so that the next replace_with_data_cache does not pay for it
+    /// Does nothing if they are already built
+    pub fn prepare(&mut self) -> Result<(), JsonDataCacheError> {
+        self.prepare_with_yield(|| {})
+    }
+
+    /// Same as prepare, calling on_yield between serialization and automaton construction
+    /// This allows single threaded runtimes (wasm) to interleave other work between the two most expensive steps
+    pub fn prepare_with_yield<F>(&mut self, mut on_yield: F) -> Result<(), JsonDataCacheError>
+    where
+        F: FnMut(),
+    {
+        self.prepare_computed();
+        while let Some(step) = self.next_build_step() {
+            self.perform_build_step(step)?;
+            if step == BuildStep::Serialize {
+                on_yield();
+            }
+        }
+        self.prepare_static()
+    }
+
+    /// Same as prepare, in steps: returns once budget is spent, telling the next step if the construction is not done.
+    /// Each call performs at least one step, so that calling it between other work always progresses. Steps are not
+    /// split further (aho-corasick builds the automaton at once), and inserts restart the construction
+    pub fn build_step(&mut self, budget: Duration) -> Result<BuildProgress, JsonDataCacheError> {
+        let start = self.clock().monotonic();
+        self.prepare_computed();
+        while let Some(step) = self.next_build_step() {
+            self.perform_build_step(step)?;
+            if let Some(next) = self.next_build_step()
+                && self.clock().monotonic().saturating_sub(start) >= budget {
+                return Ok(BuildProgress::Pending(next));
+            }
+        }
+        self.prepare_static()?;
+        Ok(BuildProgress::Done)
+    }
+
+    fn next_build_step(&self) -> Option<BuildStep> {
+        if self.serialized_data.is_built {
+            None
+        } else if self.serialized_data.serialized.get().is_none() {
+            Some(BuildStep::Serialize)
+        } else if self.serialized_data.pending_patterns.is_none() {
+            Some(BuildStep::CollectPatterns)
+        } else {
+            Some(BuildStep::BuildAutomaton)
+        }
+    }
+
+    fn perform_build_step(&mut self, step: BuildStep) -> Result<(), JsonDataCacheError> {
+        match step {
+            BuildStep::Serialize => {
+                // Inserts enforce the maximum depth, but root may also have been modified directly
+                let max_depth = self.options.max_depth.unwrap_or(DEFAULT_MAX_DEPTH);
+                if exceeds_depth(&self.root, max_depth) {
+                    return Err(format!("Unable to serialize data nested deeper than {max_depth}").into());
+                }
+                Self::build_serialized(&self.serialized_data, &self.root, &self.refs, &self.compression);
+            },
+            BuildStep::CollectPatterns => {
+                let scope = if self.static_keys.is_empty() { KeyScope::All } else { KeyScope::Dynamic };
+                self.serialized_data.pending_patterns = Some(self.automaton_patterns(None, scope)?);
+            },
+            BuildStep::BuildAutomaton => self.build_automaton()?,
+        }
+        Ok(())
+    }
+
+    /// Builds the AC automaton from the collected patterns, restricted to keys selected by serialize_only & serialize_exclude
+    fn build_automaton(&mut self) -> Result<(), JsonDataCacheError> {
+        let Some((patterns, replacements, rendered)) = self.serialized_data.pending_patterns.take() else {
+            return Ok(());
+        };
+        let build_start = self.clock().monotonic();
+        let pattern_count = patterns.len();
+        let ac = self.build_ac(patterns)?;
+        let slots: HashSet<Replacement> = replacements.iter().copied().collect();
+        let value_bytes = slots.into_iter().map(|slot| self.replacement_of(&[slot], self.buffers(&rendered), 0).len()).sum();
+        self.serialized_data.stats = Some(AutomatonStats {
+            pattern_count,
+            heap_bytes: ac.memory_usage(),
+            rendered_bytes: rendered.iter().map(Vec::len).sum(),
+            value_bytes,
+            build_time: self.clock().monotonic().saturating_sub(build_start)
+        });
+        self.serialized_data.ac = Some(ac);
+        self.serialized_data.replacements = replacements;
+        self.serialized_data.rendered = rendered;
+        self.serialized_data.is_built = true;
+        Ok(())
+    }
+
+    /// Patterns of the automaton, their replacements & the values rendered for them. With an overlay prefix (see define_variant),
+    /// keys under the prefix become patterns without it, replacing the values of the same keys outside of the overlay
+    pub(crate) fn automaton_patterns(&self, overlay_prefix: Option<&str>, scope: KeyScope) -> Result<AutomatonPatterns, JsonDataCacheError> {
+        // Every value is read, which is not a read of the render (see track_reads)
+        let reads = self.reads.pause();
+        let patterns = self.collect_automaton_patterns(overlay_prefix, scope);
+        self.reads.resume(reads);
+        patterns
+    }
+
+    fn collect_automaton_patterns(&self, overlay_prefix: Option<&str>, scope: KeyScope) -> Result<AutomatonPatterns, JsonDataCacheError> {
+        let serialized_keys = SerializedKeys::new(&self.options);
+        let is_serialized_key = |key: &str| {
+            serialized_keys.contains(key)
+                && match scope {
+                    KeyScope::All => true,
+                    KeyScope::Dynamic => !self.is_static_value(key),
+                    KeyScope::Static(..) => self.is_static_value(key), // Without the root key of the static subtrees
+                }
+        };
+        let (serialized, double_serialized) = match scope {
+            KeyScope::Static(serialized, double_serialized) => (serialized, double_serialized),
+            KeyScope::All | KeyScope::Dynamic => (self.serialized_data.serialized.get().unwrap(), self.serialized_data.double_serialized.get()),
+        };
+
+        // Build AC
+        let mut keys_count = serialized.key_values.len();
+        if let Some(double_serialized) = double_serialized {
+            keys_count += double_serialized.key_values.len();
+        }
+        let mut patterns: Vec<String> = Vec::with_capacity(keys_count);
+        let mut replacements: Vec<Replacement> = Vec::with_capacity(keys_count + self.raw_values.len());
+        let mut rendered = Vec::new();
+        // Repeated values (prices, dates, flags of list items) share one slot, rendered values equal to a serialized one
+        // slicing it instead of being copied
+        let mut interned: HashMap<Cow<[u8]>, Replacement> = HashMap::new();
+        let mut intern_rendered = |value: String, interned: &mut HashMap<Cow<[u8]>, Replacement>| {
+            *interned.entry(Cow::Owned(value.into_bytes())).or_insert_with_key(|value| {
+                rendered.push(value.to_vec());
+                Replacement::Rendered(rendered.len() - 1)
+            })
+        };
+
+        let raw_values = if matches!(scope, KeyScope::Static(..)) { 0 } else { self.raw_values.len() }; // Static automata leave them out
+        for (idx, key) in self.raw_values.keys().take(raw_values).enumerate().filter(|(_, key)| is_serialized_key(key)) {
+            patterns.push(format!("{{${key}}}"));
+            replacements.push(Replacement::Raw(idx));
+        }
+        // Keys of raw values shadow the same keys of the JSON tree
+        let is_shadowed_key = |key: &str| self.raw_values.contains_key(key);
+        // Pattern key & key of the value replacing it, the overlaid value if any
+        let overlaid_keys = |key_values: &HashMap<String, KeyRange>| -> Vec<(String, String)> {
+            let mut keys = Vec::with_capacity(key_values.len());
+            for key in key_values.keys() {
+                match overlay_prefix {
+                    None => keys.push((key.clone(), key.clone())),
+                    Some(prefix) => match key.strip_prefix(prefix) {
+                        Some(pattern_key) if !key_values.contains_key(pattern_key) => keys.push((pattern_key.to_string(), key.clone())),
+                        Some(_) => {},
+                        None => {
+                            let overlaid_key = format!("{prefix}{key}");
+                            let value_key = if key_values.contains_key(&overlaid_key) { overlaid_key } else { key.clone() };
+                            keys.push((key.clone(), value_key));
+                        },
+                    },
+                }
+            }
+            keys.retain(|(pattern_key, _)| is_serialized_key(pattern_key));
+            keys
+        };
+        for (key, value_key) in overlaid_keys(&serialized.key_values).into_iter().filter(|(key, _)| !is_shadowed_key(key)) {
+            let range = &serialized.key_values[&value_key];
+            patterns.push(format!("{{${key}}}"));
+
+            match self.get(&value_key).filter(|_| !self.renderers.is_empty()).and_then(|value| self.renderers.render(&key, value, self.render_context())) {
+                Some(value) => replacements.push(intern_rendered(value, &mut interned)),
+                None => replacements.push(*interned.entry(Cow::Borrowed(&serialized.data[range.start..range.end]))
+                    .or_insert(Replacement::Serialized(range.start, range.end))),
+            }
+        }
+        // {$key|safe_redirect} patterns, left out of static automata: their values depend on the allowlist
+        if !self.options.safe_redirect_keys.is_empty() && !matches!(scope, KeyScope::Static(..)) {
+            let safe_redirect_keys: Vec<PathPattern> = self.options.safe_redirect_keys.iter().map(PathPattern::from).collect();
+            let allowlist = self.redirect_allowlist(self.options.redirect_allowlist_path.as_deref().unwrap_or(DEFAULT_REDIRECT_ALLOWLIST_PATH));
+            if let Err(e) = &allowlist {
+                log::info!("[WARN] DataCache safe_redirect : {}", e);
+            }
+            let keys = overlaid_keys(&serialized.key_values).into_iter()
+                .filter(|(key, _)| !is_shadowed_key(key) && safe_redirect_keys.iter().any(|pattern| pattern.covers(key)));
+            for (key, value_key) in keys {
+                let Some(Value::String(target)) = self.get(&value_key) else {
+                    continue;
+                };
+                let target = allowlist.as_ref().map_or(DEFAULT_REDIRECT_FALLBACK, |allowlist| allowlist.safe_target(target));
+                patterns.push(format!("{{${key}|{SAFE_REDIRECT_FILTER}}}"));
+                replacements.push(intern_rendered(target.to_string(), &mut interned));
+            }
+        }
+        if let Some(double_serialized) = double_serialized {
+            for (key, value_key) in overlaid_keys(&double_serialized.key_values) {
+                let range = &double_serialized.key_values[&value_key];
+                patterns.push(format!("{{$${key}}}"));
+
+                replacements.push(*interned.entry(Cow::Borrowed(&double_serialized.data[range.start..range.end]))
+                    .or_insert(Replacement::DoubleSerialized(range.start, range.end)));
+            }
+        }
+
+        if let Some(max_patterns) = self.options.max_patterns
+            && patterns.len() > max_patterns {
+            return Err(format!("Automaton would have {} patterns, above the configured maximum of {max_patterns}", patterns.len()).into());
+        }
+        Ok((patterns, replacements, rendered))
+    }
+
+    pub(crate) fn build_ac(&self, patterns: Vec<String>) -> Result<AhoCorasick, JsonDataCacheError> {
+        let mut ac_builder = AhoCorasick::builder();
+        ac_builder
+            .kind(self.options.ac_kind)
+            .match_kind(self.options.ac_match_kind.unwrap_or(MatchKind::LeftmostLongest));
+        if let Some(prefilter) = self.options.ac_prefilter {
+            ac_builder.prefilter(prefilter);
+        }
+        Ok(ac_builder.build(patterns)?)
+    }
+
+    /// Builds the automaton of the variant if needed, after the one of the tree
+    fn prepare_variant(&mut self, variant: &str) -> Result<(), JsonDataCacheError> {
+        self.prepare()?;
+        if self.serialized_data.variants.contains_key(variant) {
+            return Ok(());
+        }
+        let overlay_path = self.variants.get(variant).ok_or(format!("Unknown variant {variant}"))?;
+        let (patterns, replacements, rendered) = self.automaton_patterns(Some(&format!("{overlay_path}.")), KeyScope::All)?;
+        let ac = self.build_ac(patterns)?;
+        self.serialized_data.variants.insert(variant.to_string(), VariantAutomaton { ac, replacements, rendered });
+        Ok(())
+    }
+
+    /// Declares a variant (a locale, a currency, a device class...) whose values are the ones of the subtree at overlay_path,
+    /// falling back to the values of the rest of the tree: rendering variant "en" with overlay_path "variants.en" replaces
+    /// {$title} with the value of variants.en.title if set, of title otherwise. Only the automaton is built for each variant,
+    /// values are sliced from the same serialized data, so that variants only cost the values which differ.
+    /// Overlaid keys do not modify the serialization of their parents: {$$page} is the value of variants.en.page if set,
+    /// of page otherwise
+    pub fn define_variant(&mut self, variant: &str, overlay_path: &str) {
+        self.variants.insert(variant.to_string(), overlay_path.to_string());
+        self.serialized_data.variants.remove(variant);
+    }
+
+    /// Returns false if the variant was not defined
+    pub fn remove_variant(&mut self, variant: &str) -> bool {
+        self.serialized_data.variants.remove(variant);
+        self.variants.remove(variant).is_some()
+    }
+
+    /// Same as replace_with_data_cache, with the values of a variant declared by define_variant
+    pub fn replace_with_variant<R, W>(&mut self, variant: &str, reader: R, writer: W) -> Result<(), JsonDataCacheError>
+    where
+        R: io::Read,
+        W: io::Write,
+    {
+        self.prepare_variant(variant)?;
+        self.replace_with_automaton(Some(variant), reader, writer, None)
+    }
+
+    /// Stores opaque bytes (binary, pre-rendered fragments...) replacing {$key}, outside of the JSON tree: get & string values ignore them
+    /// A raw value shadows the JSON value of the same key for {$key} replacements, {$$key} still uses the JSON value
+    pub fn insert_bytes(&mut self, key: &str, bytes: Vec<u8>) {
+        self.raw_values.insert(key.to_string(), bytes);
+        self.mark_dirty(key);
+
+        self.on_after_insert();
+    }
+
+    /// Returns the bytes set by insert_bytes for the key
+    pub fn get_bytes(&self, key: &str) -> Option<&[u8]> {
+        self.raw_values.get(key).map(|bytes| bytes.as_slice())
+    }
+
+    /// Removes the bytes set by insert_bytes for the key, if any
+    pub fn remove_bytes(&mut self, key: &str) -> Option<Vec<u8>> {
+        let removed = self.raw_values.shift_remove(key);
+        if removed.is_some() {
+            self.mark_dirty(key);
+            self.on_after_insert();
+        }
+        removed
+    }
+
+    /// Returns the figures of the automaton, if it has been built since the last insert
+    pub fn automaton_stats(&self) -> Option<&AutomatonStats> {
+        self.serialized_data.stats.as_ref()
+    }
+
+    /// Buffers of the serialized data, for automata built from it
+    pub(crate) fn buffers<'a>(&'a self, rendered: &'a [Vec<u8>]) -> ReplacementBuffers<'a> {
+        let serialized = self.serialized_data.serialized.get().unwrap();
+        ReplacementBuffers {
+            serialized: &serialized.data,
+            double_serialized: &self.serialized_data.double_serialized.get().unwrap_or(serialized).data,
+            rendered
+        }
+    }
+
+    /// Value replacing the pattern of an automaton, given its replacements & the buffers they slice
+    pub(crate) fn replacement_of<'a>(&'a self, replacements: &[Replacement], buffers: ReplacementBuffers<'a>, pattern_idx: usize) -> &'a [u8] {
+        match replacements[pattern_idx] {
+            Replacement::Serialized(start, end) => &buffers.serialized[start..end],
+            Replacement::DoubleSerialized(start, end) => &buffers.double_serialized[start..end],
+            Replacement::Raw(idx) => self.raw_values.get_index(idx).map(|(_, bytes)| bytes.as_slice()).unwrap_or_default(),
+            Replacement::Rendered(idx) => &buffers.rendered[idx],
+        }
+    }
+
+    /// Pairs of patterns of the tree where the first one occurs in the second one, sorted. Patterns end with '}', so that
+    /// keys sharing a prefix (user & user_name) never collide: only keys containing '}' or "{$" do, such as a & a}b, whose
+    /// {$a} occurs in {$a}b}. The default match kind replaces {$a}b} whole, MatchKind::Standard would replace {$a}
+    pub fn detect_ambiguous_keys(&mut self) -> Result<Vec<(String, String)>, JsonDataCacheError> {
+        self.prepare()?;
+        let (patterns, _, _) = self.automaton_patterns(None, KeyScope::All)?;
+        let ac = AhoCorasick::new(&patterns)?;
+        let mut ambiguous = Vec::new();
+        for (idx, pattern) in patterns.iter().enumerate() {
+            for mat in ac.find_overlapping_iter(pattern).filter(|mat| mat.pattern().as_usize() != idx) {
+                ambiguous.push((patterns[mat.pattern().as_usize()].clone(), pattern.clone()));
+            }
+        }
+        ambiguous.sort();
+        ambiguous.dedup();
+        Ok(ambiguous)
+    }
+
+    /// Performs replacements of {$key} into mapped values from data_cache if key exists
+    /// It uses Aho-Corasick algorithm for efficient multi-replacement, and works on streams (Vec<u8> does work, too)
+    /// Overlapping patterns (a key being a prefix of another, see detect_ambiguous_keys) are replaced by the longest match
+    /// unless DataCacheOptions::ac_match_kind says otherwise
+    /// If the decision log logs placeholders, each distinct expanded placeholder is logged with its count once done
+    pub fn replace_with_data_cache<R, W>(
+        &mut self,
+        reader: R,
+        writer: W
+    ) -> Result<(), JsonDataCacheError>
+    where
+        R: io::Read,
+        W: io::Write,
+    {
+        self.prepare()?;
+        self.replace_with_automaton(None, reader, writer, None)
+    }
+
+    /// Same as replace_with_data_cache, also returning the output range of each replacement along with its key
+    /// Ranges cover the audit markers if any, escapes are not replacements
+    pub fn replace_with_source_map<R, W>(&mut self, reader: R, writer: W) -> Result<SourceMap, JsonDataCacheError>
+    where
+        R: io::Read,
+        W: io::Write,
+    {
+        self.prepare()?;
+        let mut source_map = SourceMap::default();
+        self.replace_with_automaton(None, reader, writer, Some(&mut source_map))?;
+        Ok(source_map)
+    }
+
+    /// Replaces with the automaton of the variant (built beforehand), or the one of the tree
+    fn replace_with_automaton<R, W>(
+        &mut self,
+        variant: Option<&str>,
+        reader: R,
+        writer: W,
+        source_map: Option<&mut SourceMap>
+    ) -> Result<(), JsonDataCacheError>
+    where
+        R: io::Read,
+        W: io::Write,
+    {
+        let automata = ReplacementAutomata::new(self, variant);
+        let is_logging = self.is_logging_placeholders();
+        let mut expanded: IndexMap<Vec<u8>, u64> = IndexMap::new();
+        let writer = CountingWriter { inner: writer, written: 0 };
+        let mut entries = Vec::new();
+        let clock = self.shared_clock();
+        let result = stream_replace_all(&automata.automata, reader, writer, StreamOptions::of(self, clock.as_ref()), |automaton_idx, pattern_idx, placeholder, writer| {
+            let start = writer.written;
+            let is_replaced = automata.write(writer, automaton_idx, pattern_idx, placeholder)?;
+            if is_replaced && is_logging {
+                *expanded.entry(placeholder.to_vec()).or_default() += 1;
+            }
+            if is_replaced && self.reads.is_tracking() {
+                self.reads.record(&placeholder_key(placeholder));
+            }
+            if is_replaced && source_map.is_some() {
+                entries.push(SourceMapEntry { output: start..writer.written, key: placeholder_key(placeholder).into_owned() });
+            }
+            Ok(())
+        });
+        let writer = match result {
+            Ok(writer) => writer,
+            Err(e) => {
+                let error = JsonDataCacheError::from(e);
+                let is_degrading = self.options.render_budget.is_some_and(|budget| budget.on_exceeded == OnBudgetExceeded::Degrade)
+                    && error.msg.starts_with(RENDER_BUDGET_EXCEEDED)
+                    && self.degraded_status().is_none();
+                if is_degrading {
+                    self.degrade(BUDGET_DEGRADED_REASON)?;
+                }
+                return Err(error);
+            },
+        };
+        if let Some(source_map) = source_map {
+            *source_map = SourceMap { entries, output_len: writer.written };
+        }
+        for (placeholder, count) in expanded {
+            self.log_decision(PLACEHOLDER_DECISION, &String::from_utf8_lossy(&placeholder), json!({"count": count}))?;
+        }
+        Ok(())
+    }
+
+    /// Performs replacements against several caches in a single streaming pass, without merging their trees: a placeholder
+    /// matching keys of several caches is replaced with the value of the first one. Caches must be prepared beforehand
+    /// (see prepare), as replacements only borrow them. Variants and the decision log are not used, escapes, audit markers
+    /// flushes, the budget & minification follow the options of the first cache (see DataCacheOptions::escape_placeholders,
+    /// audit_markers, flush_policy, render_budget & minify)
+    /// Example: DataCache::replace_with_caches(&[&page_cache, &site_cache], reader, writer)
+    pub fn replace_with_caches<R, W>(caches: &[&DataCache], reader: R, writer: W) -> Result<(), JsonDataCacheError>
+    where
+        R: io::Read,
+        W: io::Write,
+    {
+        // Automata in precedence order, with the cache & automaton they come from
+        let mut automata: Vec<(&dyn PlaceholderMatcher, &DataCache, Option<&StaticAutomaton>)> = Vec::with_capacity(caches.len() * 2);
+        for (idx, cache) in caches.iter().enumerate() {
+            let Some(matcher) = cache.serialized_data.matcher().filter(|_| cache.serialized_data.is_built) else {
+                return Err(format!("Cache {idx} is not prepared, call prepare before replace_with_caches").into());
+            };
+            automata.push((matcher, cache, None));
+            if !cache.static_keys.is_empty() {
+                let Some(static_automaton) = &cache.static_automaton else {
+                    return Err(format!("Cache {idx} is not prepared, call prepare before replace_with_caches").into());
+                };
+                automata.push((&static_automaton.ac, cache, Some(static_automaton)));
+            }
+        }
+        let mut matchers: Vec<&dyn PlaceholderMatcher> = automata.iter().map(|(matcher, _, _)| *matcher).collect();
+        if caches.first().is_some_and(|cache| cache.options.escape_placeholders) {
+            matchers.push(&*ESCAPE_AUTOMATON);
+        }
+        let audit_markers = caches.first().and_then(|cache| cache.options.audit_markers.as_ref());
+        let clock = caches.first().map(|cache| cache.shared_clock());
+        let options = caches.first().zip(clock.as_ref()).map(|(cache, clock)| StreamOptions::of(cache, clock.as_ref())).unwrap_or_default();
+        stream_replace_all(&matchers, reader, writer, options, |automaton_idx, pattern_idx, placeholder, writer| {
+            let Some(&(_, cache, static_automaton)) = automata.get(automaton_idx) else {
+                return io::Write::write_all(writer, b"{$");
+            };
+            let value = match static_automaton {
+                Some(static_automaton) => static_automaton.replacement(cache, pattern_idx),
+                None => cache.replacement_of(&cache.serialized_data.replacements, cache.buffers(&cache.serialized_data.rendered), pattern_idx),
+            };
+            if cache.reads.is_tracking() {
+                cache.reads.record(&placeholder_key(placeholder));
+            }
+            write_replacement(writer, audit_markers, placeholder, value)
+        })?;
+        Ok(())
+    }
+
+    /// Same as replace_with_data_cache, calling on_progress after each write to the writer, and failing with "Replacement cancelled"
+    /// as soon as the token is cancelled (checked before each read & write), for huge bodies whose client has disconnected
+    pub fn replace_with_data_cache_progress<R, W, F>(
+        &mut self,
+        reader: R,
+        writer: W,
+        on_progress: F,
+        cancellation: Option<&CancellationToken>
+    ) -> Result<(), JsonDataCacheError>
+    where
+        R: io::Read,
+        W: io::Write,
+        F: FnMut(&ReplaceProgress),
+    {
+        let bytes_in = Cell::new(0);
+        let clock = self.shared_clock();
+        let reader = ProgressReader { inner: reader, bytes_in: &bytes_in, cancellation };
+        let writer = ProgressWriter {
+            inner: writer,
+            bytes_in: &bytes_in,
+            bytes_out: 0,
+            start: clock.monotonic(),
+            clock,
+            on_progress,
+            cancellation
+        };
+        self.replace_with_data_cache(reader, writer)
+    }
+
+    /// Locates the placeholders of the template & their replacements in the output, without writing it
+    /// The map can be kept along with the template (in a KV store for example, see PlaceholderMap::to_json) to serve range
+    /// requests with replace_range
+    pub fn placeholder_map(&mut self, template: &[u8]) -> Result<PlaceholderMap, JsonDataCacheError> {
+        self.prepare()?;
+        let mut placeholders = Vec::new();
+        let mut crc = Crc32c::default();
+        let mut template_pos = 0;
+        let mut output_pos = 0;
+        for (template_range, replacement) in self.find_placeholders(template)? {
+            crc.update(&replacement);
+            output_pos += template_range.start - template_pos;
+            template_pos = template_range.end;
+            placeholders.push(PlaceholderOffset {
+                template: template_range,
+                output: output_pos..output_pos + replacement.len()
+            });
+            output_pos += replacement.len();
+        }
+        Ok(PlaceholderMap {
+            placeholders,
+            template_len: template.len(),
+            output_len: output_pos + template.len() - template_pos,
+            fingerprint: crc.finalize()
+        })
+    }
+
+    /// Writes the range (in output offsets, end excluded) of the replaced template, as a 206 Partial Content body,
+    /// without writing anything before or after it. The template is not scanned again: the placeholders of the map are
+    /// replaced from their bytes, and only the segments overlapping the range are written. Fails if the cache has been
+    /// modified since the map was computed in a way changing the output, as the range would not be consistent with the
+    /// full document anymore. The map must be the one of this template
+    pub fn replace_range<W>(&mut self, template: &[u8], range: Range<usize>, map: &PlaceholderMap, mut writer: W) -> Result<(), JsonDataCacheError>
+    where
+        W: io::Write,
+    {
+        if range.start > range.end || range.end > map.output_len {
+            return Err(format!("Range {}-{} is out of the {} bytes output", range.start, range.end, map.output_len).into());
+        }
+        if template.len() != map.template_len || map.placeholders.last().is_some_and(|placeholder| placeholder.template.end > template.len()) {
+            return Err("Placeholder map is not the one of the template".into());
+        }
+        self.prepare()?;
+        let automata = ReplacementAutomata::new(self, None);
+        let mut replacements = Vec::with_capacity(map.placeholders.len());
+        let mut crc = Crc32c::default();
+        for placeholder in &map.placeholders {
+            let replacement = automata.replace_placeholder(&template[placeholder.template.clone()])?;
+            match replacement {
+                Some(replacement) if replacement.len() == placeholder.output.len() => {
+                    crc.update(&replacement);
+                    replacements.push(replacement);
+                },
+                _ => return Err("Placeholder map is outdated".into()),
+            }
+        }
+        if crc.finalize() != map.fingerprint {
+            return Err("Placeholder map is outdated".into());
+        }
+
+        // Writes the part of a segment starting at output offset start that is in the range
+        let mut write_overlap = |bytes: &[u8], start: usize| -> io::Result<()> {
+            let overlap_start = range.start.max(start);
+            let overlap_end = range.end.min(start + bytes.len());
+            if overlap_start < overlap_end {
+                writer.write_all(&bytes[overlap_start - start..overlap_end - start])?;
+            }
+            Ok(())
+        };
+        let mut template_pos = 0;
+        let mut output_pos = 0;
+        for (replacement, placeholder) in replacements.iter().zip(&map.placeholders) {
+            if output_pos >= range.end {
+                return Ok(());
+            }
+            if placeholder.output.end > range.start {
+                write_overlap(&template[template_pos..placeholder.template.start], output_pos)?;
+                write_overlap(replacement, placeholder.output.start)?;
+            }
+            template_pos = placeholder.template.end;
+            output_pos = placeholder.output.end;
+        }
+        write_overlap(&template[template_pos..], output_pos)?;
+        Ok(())
+    }
+
+    /// Output of replace_with_data_cache for input, once prepared, without logging the placeholders
+    pub(crate) fn replace_prepared(&self, input: &[u8]) -> Result<Vec<u8>, JsonDataCacheError> {
+        let automata = ReplacementAutomata::new(self, None);
+        let output = Vec::with_capacity(input.len());
+        Ok(stream_replace_all(&automata.automata, input, output, StreamOptions::default(), |automaton_idx, pattern_idx, placeholder, writer| {
+            automata.write(writer, automaton_idx, pattern_idx, placeholder).map(|_| ())
+        })?)
+    }
+
+    /// Template ranges of the matches of the built automata & their output, as replaced by replace_with_data_cache
+    fn find_placeholders(&self, template: &[u8]) -> Result<Vec<FoundPlaceholder>, JsonDataCacheError> {
+        let automata = ReplacementAutomata::new(self, None);
+        let mut found = Vec::new();
+        // Matches are written as is, so that the count of written bytes is their offset in the template
+        let writer = CountingWriter { inner: io::sink(), written: 0 };
+        stream_replace_all(&automata.automata, template, writer, StreamOptions::default(), |automaton_idx, pattern_idx, placeholder, writer| {
+            let mut output = Vec::new();
+            automata.write(&mut output, automaton_idx, pattern_idx, placeholder)?;
+            found.push((writer.written..writer.written + placeholder.len(), output));
+            io::Write::write_all(writer, placeholder)
+        })?;
+        Ok(found)
+    }
+}
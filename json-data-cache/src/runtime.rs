@@ -0,0 +1,201 @@
+//! Time & randomness used by freshness, stores, fetches, automaton statistics and nonces, behind the Clock and Rng traits.
+//!
+//! Time comes from the standard library, and randomness from the OS entropy pool on unix. On wasm32-unknown-unknown, where
+//! std has neither a clock nor an entropy source, the host provides them through the imports of the `json_data_cache`
+//! module (Date.now / performance.now / crypto.getRandomValues). Other targets (WASI included) use the clock of std, and
+//! have no default rng. Rngs used for nonces (see DataCacheOptions::rng) must be cryptographically secure.
+//! ManualClock & SeededRng make tests deterministic.
+
+#[cfg(feature = "replace-engine")]
+use std::rc::Rc;
+use std::{cell::Cell, fmt, time::{Duration, SystemTime}};
+
+use crate::{DataCache, error::JsonDataCacheError};
+
+pub trait Clock: fmt::Debug {
+    /// Wall clock time, for expiries
+    fn now(&self) -> SystemTime;
+    /// Time since an arbitrary origin, never going backwards, for measuring durations
+    fn monotonic(&self) -> Duration;
+}
+
+pub trait Rng: fmt::Debug {
+    fn fill_bytes(&self, dest: &mut [u8]);
+
+    fn next_u64(&self) -> u64 {
+        let mut bytes = [0; 8];
+        self.fill_bytes(&mut bytes);
+        u64::from_le_bytes(bytes)
+    }
+}
+
+/// Clock of the standard library
+#[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct StdClock;
+
+#[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+impl Clock for StdClock {
+    fn now(&self) -> SystemTime {
+        SystemTime::now()
+    }
+
+    fn monotonic(&self) -> Duration {
+        static ORIGIN: std::sync::OnceLock<std::time::Instant> = std::sync::OnceLock::new();
+        ORIGIN.get_or_init(std::time::Instant::now).elapsed()
+    }
+}
+
+/// Randomness of the OS entropy pool, read from /dev/urandom
+#[cfg(unix)]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct OsRng;
+
+#[cfg(unix)]
+impl Rng for OsRng {
+    fn fill_bytes(&self, dest: &mut [u8]) {
+        use std::io::Read;
+
+        // Never failing on a working system, and no fallback would be secure
+        std::fs::File::open("/dev/urandom")
+            .and_then(|mut urandom| urandom.read_exact(dest))
+            .expect("Reading /dev/urandom failed");
+    }
+}
+
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+#[link(wasm_import_module = "json_data_cache")]
+unsafe extern "C" {
+    /// Date.now() : milliseconds since the Unix epoch
+    fn host_date_now() -> f64;
+    /// performance.now() : milliseconds since the start of the isolate
+    fn host_performance_now() -> f64;
+    /// crypto.getRandomValues() on the len bytes at ptr
+    fn host_random_bytes(ptr: *mut u8, len: usize);
+}
+
+/// Clock of the wasm host
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct HostClock;
+
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+impl Clock for HostClock {
+    fn now(&self) -> SystemTime {
+        let millis = unsafe { host_date_now() };
+        SystemTime::UNIX_EPOCH + Duration::from_secs_f64(millis.max(0.0) / 1000.0)
+    }
+
+    fn monotonic(&self) -> Duration {
+        let millis = unsafe { host_performance_now() };
+        Duration::from_secs_f64(millis.max(0.0) / 1000.0)
+    }
+}
+
+/// Randomness of the wasm host
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct HostRng;
+
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+impl Rng for HostRng {
+    fn fill_bytes(&self, dest: &mut [u8]) {
+        unsafe { host_random_bytes(dest.as_mut_ptr(), dest.len()) }
+    }
+}
+
+/// Clock used when none is configured
+#[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+pub use StdClock as PlatformClock;
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+pub use HostClock as PlatformClock;
+
+/// Rng used when none is configured. Other platforms (Windows, WASI) have none: std exposes no secure entropy source
+/// there, so DataCacheOptions::rng must be set
+#[cfg(unix)]
+pub use OsRng as PlatformRng;
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+pub use HostRng as PlatformRng;
+
+/// Clock only moving when told to
+#[derive(Debug)]
+pub struct ManualClock {
+    now: Cell<SystemTime>,
+    monotonic: Cell<Duration>,
+}
+
+impl ManualClock {
+    pub fn new(now: SystemTime) -> Self {
+        Self { now: Cell::new(now), monotonic: Cell::new(Duration::ZERO) }
+    }
+
+    pub fn advance(&self, duration: Duration) {
+        self.now.set(self.now.get() + duration);
+        self.monotonic.set(self.monotonic.get() + duration);
+    }
+
+    /// Sets the wall clock time only, the monotonic time never going backwards
+    pub fn set(&self, now: SystemTime) {
+        self.now.set(now);
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> SystemTime {
+        self.now.get()
+    }
+
+    fn monotonic(&self) -> Duration {
+        self.monotonic.get()
+    }
+}
+
+/// xorshift64* sequence: the same seed always gives the same bytes. Not suitable for secrets
+#[derive(Debug)]
+pub struct SeededRng {
+    state: Cell<u64>,
+}
+
+impl SeededRng {
+    pub fn new(seed: u64) -> Self {
+        // A zero state would only produce zeros
+        Self { state: Cell::new((seed ^ 0x9e37_79b9_7f4a_7c15).max(1)) }
+    }
+}
+
+impl Rng for SeededRng {
+    fn fill_bytes(&self, dest: &mut [u8]) {
+        for chunk in dest.chunks_mut(8) {
+            let mut state = self.state.get();
+            state ^= state >> 12;
+            state ^= state << 25;
+            state ^= state >> 27;
+            self.state.set(state);
+            chunk.copy_from_slice(&state.wrapping_mul(0x2545_f491_4f6c_dd1d).to_le_bytes()[..chunk.len()]);
+        }
+    }
+}
+
+impl DataCache {
+    /// The clock of the options, or the platform one
+    pub fn clock(&self) -> &dyn Clock {
+        self.options.clock.as_deref().unwrap_or(&PlatformClock)
+    }
+
+    /// The rng of the options, or the platform one. Fails on platforms without one (see PlatformRng) if none is configured
+    pub fn rng(&self) -> Result<&dyn Rng, JsonDataCacheError> {
+        match self.options.rng.as_deref() {
+            Some(rng) => Ok(rng),
+            #[cfg(any(unix, all(target_arch = "wasm32", target_os = "unknown")))]
+            None => Ok(&PlatformRng),
+            #[cfg(not(any(unix, all(target_arch = "wasm32", target_os = "unknown"))))]
+            None => Err("No secure platform rng, DataCacheOptions::rng must be set".into()),
+        }
+    }
+
+    /// Same as clock, for holders outliving a borrow of the cache
+    #[cfg(feature = "replace-engine")]
+    pub(crate) fn shared_clock(&self) -> Rc<dyn Clock> {
+        self.options.clock.clone().unwrap_or_else(|| Rc::new(PlatformClock))
+    }
+}
@@ -0,0 +1,139 @@
+//! Deterministic sampling: arrays are shuffled by a SeededRng seeded from a value of the cache (a user id, a page variant...)
+//! and the array path, so that "random" picks are stable for the same seed value and vary between seed values.
+//! Rotation picks one item of an array by weights, the pick changing with each time window of the clock of the cache.
+//! Not suitable for anything security related.
+
+use std::time::{Duration, SystemTime};
+
+use serde_json::Value;
+
+use crate::{DataCache, error::JsonDataCacheError, runtime::{Rng, SeededRng}, sha256::Sha256};
+
+/// Default key of the weight of the items, see Rotation::weight_key
+pub const DEFAULT_WEIGHT_KEY: &str = "weight";
+
+/// Weighted pick of an item per time window, see DataCache::rotate
+#[derive(Debug, Clone)]
+pub struct Rotation {
+    window: Duration,
+    weight_key: String,
+    seed_path: Option<String>,
+}
+
+impl Rotation {
+    /// The pick changes every window (at least a second), windows starting at the Unix epoch
+    pub fn new(window: Duration) -> Self {
+        Self {
+            window: window.max(Duration::from_secs(1)),
+            weight_key: DEFAULT_WEIGHT_KEY.to_string(),
+            seed_path: None,
+        }
+    }
+
+    /// Key of the weight of the items (DEFAULT_WEIGHT_KEY by default). Items without a weight weigh 1, negative or non
+    /// numeric weights 0
+    pub fn weight_key(mut self, weight_key: &str) -> Self {
+        self.weight_key = weight_key.to_string();
+        self
+    }
+
+    /// Seeds the pick with the value at seed_path too, like a user id for picks varying between users
+    pub fn seed_path(mut self, seed_path: &str) -> Self {
+        self.seed_path = Some(seed_path.to_string());
+        self
+    }
+
+    /// Index of the window containing now
+    pub fn bucket(&self, now: SystemTime) -> u64 {
+        let elapsed = now.duration_since(SystemTime::UNIX_EPOCH).unwrap_or_default();
+        elapsed.as_secs() / self.window.as_secs()
+    }
+
+    /// Start of the window following the one containing now, when the pick may change
+    pub fn next_change(&self, now: SystemTime) -> SystemTime {
+        SystemTime::UNIX_EPOCH + Duration::from_secs((self.bucket(now) + 1) * self.window.as_secs())
+    }
+
+    /// Index of the item picked among the items of the array at path for the bucket, None if no item weighs anything
+    fn pick(&self, path: &str, items: &[Value], seed_value: Option<&Value>, bucket: u64) -> Option<usize> {
+        let weights: Vec<f64> = items.iter().map(|item| match item.get(&self.weight_key) {
+            None => 1.0,
+            Some(weight) => weight.as_f64().filter(|weight| weight.is_finite() && *weight > 0.0).unwrap_or(0.0),
+        }).collect();
+        let total: f64 = weights.iter().sum();
+        if total <= 0.0 {
+            return None;
+        }
+        let rng = SeededRng::new(seed(path, seed_value, &bucket.to_le_bytes()));
+        let mut target = (rng.next_u64() >> 11) as f64 / (1u64 << 53) as f64 * total; // In [0, total)
+        for (idx, weight) in weights.iter().enumerate() {
+            if *weight > 0.0 && target < *weight {
+                return Some(idx);
+            }
+            target -= weight;
+        }
+        weights.iter().rposition(|weight| *weight > 0.0) // Rounding errors
+    }
+}
+
+/// Seed of the array at path, from the value at seed_path (null if missing) & the salt
+fn seed(path: &str, seed_value: Option<&Value>, salt: &[u8]) -> u64 {
+    let mut hasher = Sha256::default();
+    hasher.update(path.as_bytes());
+    hasher.update(&[0]);
+    hasher.update(seed_value.unwrap_or(&Value::Null).to_string().as_bytes());
+    hasher.update(&[0]);
+    hasher.update(salt);
+    let digest = hasher.finalize();
+    u64::from_le_bytes(digest[..8].try_into().unwrap())
+}
+
+impl DataCache {
+    /// Items of the array at path in an order depending only on them & the value at seed_path (Fisher-Yates shuffle)
+    pub fn shuffle(&self, path: &str, seed_path: &str) -> Vec<&Value> {
+        let Some(Value::Array(items)) = self.get(path) else {
+            return Vec::new();
+        };
+        let rng = SeededRng::new(seed(path, self.get(seed_path), &[]));
+        let mut shuffled: Vec<&Value> = items.iter().collect();
+        for i in (1..shuffled.len()).rev() {
+            let j = (rng.next_u64() % (i as u64 + 1)) as usize;
+            shuffled.swap(i, j);
+        }
+        shuffled
+    }
+
+    /// n items picked from the array at path, in the order of shuffle (so that a smaller sample is a prefix of a bigger one).
+    /// All of them if the array is shorter
+    pub fn sample(&self, path: &str, n: usize, seed_path: &str) -> Vec<&Value> {
+        let mut sampled = self.shuffle(path, seed_path);
+        sampled.truncate(n);
+        sampled
+    }
+
+    /// Replaces the value at target with the sample of the array at path, returning its length
+    /// Example: sample_into("related", "articles", 3, "user.id") for a "{$related.0.title}" template
+    pub fn sample_into(&mut self, target: &str, path: &str, n: usize, seed_path: &str) -> Result<usize, JsonDataCacheError> {
+        let Some(Value::Array(_)) = self.get(path) else {
+            return Err(format!("No array at {path}").into());
+        };
+        let sampled: Vec<Value> = self.sample(path, n, seed_path).into_iter().cloned().collect();
+        let count = sampled.len();
+        self.try_replace(target, Value::Array(sampled))?;
+        Ok(count)
+    }
+
+    /// Replaces the value at target with the item of the array at path picked by rotation for the current window of the
+    /// clock, returning its index. Null is inserted if no item weighs anything
+    /// Example: rotate("banners", "hero", &Rotation::new(Duration::from_secs(3600)))
+    pub fn rotate(&mut self, path: &str, target: &str, rotation: &Rotation) -> Result<Option<usize>, JsonDataCacheError> {
+        let Some(Value::Array(items)) = self.get(path) else {
+            return Err(format!("No array at {path}").into());
+        };
+        let seed_value = rotation.seed_path.as_deref().and_then(|seed_path| self.get(seed_path));
+        let picked = rotation.pick(path, items, seed_value, rotation.bucket(self.clock().now()));
+        let value = picked.map(|idx| items[idx].clone()).unwrap_or(Value::Null);
+        self.try_replace(target, value)?;
+        Ok(picked)
+    }
+}
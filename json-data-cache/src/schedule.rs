@@ -0,0 +1,87 @@
+//! Scheduled publishing: content items carrying open_date / close_date fields are published from their open date (included)
+//! until their close date (excluded), according to the clock of the cache. Filtering at the edge makes publishes & unpublishes
+//! happen on time even when the cached API response was fetched before them.
+//! Dates are RFC 3339 date-times, "YYYY-MM-DD" dates (midnight UTC) or Unix timestamps in seconds. A missing, null or empty
+//! date sets no bound, while an unreadable one unpublishes the item, rather than exposing content too early.
+//! In preview mode (see the preview module), the methods of DataCache consider every item published.
+
+use std::time::{Duration, SystemTime};
+
+use serde_json::Value;
+
+use crate::{DataCache, coercion::Date, error::JsonDataCacheError, mock::{days_from_civil, parse_rfc3339}};
+
+pub const OPEN_DATE_FIELD: &str = "open_date";
+pub const CLOSE_DATE_FIELD: &str = "close_date";
+
+/// Unix timestamp of a date field. Some(None) if it sets no bound, None if it is unreadable
+fn schedule_timestamp(value: Option<&Value>) -> Option<Option<i64>> {
+    match value {
+        None | Some(Value::Null) => Some(None),
+        Some(Value::String(s)) if s.is_empty() => Some(None),
+        Some(Value::String(s)) => parse_rfc3339(s).or_else(|| Date::parse(s).map(|date| days_from_civil(date.year, date.month.into(), date.day.into()) * 86_400)).map(Some),
+        Some(Value::Number(n)) => n.as_i64().map(Some),
+        _ => None,
+    }
+}
+
+fn unix_timestamp(time: SystemTime) -> i64 {
+    match time.duration_since(SystemTime::UNIX_EPOCH) {
+        Ok(elapsed) => elapsed.as_secs() as i64,
+        Err(e) => -(e.duration().as_secs_f64().ceil() as i64),
+    }
+}
+
+/// True if the item is published at now
+pub fn is_published(item: &Value, now: SystemTime) -> bool {
+    let (Some(open), Some(close)) = (schedule_timestamp(item.get(OPEN_DATE_FIELD)), schedule_timestamp(item.get(CLOSE_DATE_FIELD))) else {
+        return false;
+    };
+    let now = unix_timestamp(now);
+    open.is_none_or(|open| open <= now) && close.is_none_or(|close| now < close)
+}
+
+impl DataCache {
+    /// True if the item at path is published now (see the schedule module)
+    pub fn is_published(&self, path: &str) -> bool {
+        self.get(path).is_some_and(|item| self.preview || is_published(item, self.clock().now()))
+    }
+
+    /// Items of the array at path published now
+    pub fn published_items(&self, path: &str) -> Vec<&Value> {
+        let now = self.clock().now();
+        match self.get(path) {
+            Some(Value::Array(items)) => items.iter().filter(|item| self.preview || is_published(item, now)).collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Removes the items of the array at path which are not published now, returning their count
+    pub fn filter_published(&mut self, path: &str) -> Result<usize, JsonDataCacheError> {
+        let Some(Value::Array(items)) = self.get(path) else {
+            return Err(format!("No array at {path}").into());
+        };
+        let count = items.len();
+        let published: Vec<Value> = self.published_items(path).into_iter().cloned().collect();
+        let removed = count - published.len();
+        if removed > 0 {
+            self.try_replace(path, Value::Array(published))?;
+        }
+        Ok(removed)
+    }
+
+    /// Earliest open or close date of the items of the array at path still to come, when the published items change.
+    /// Useful to bound the ttl of a response filtered by filter_published
+    pub fn next_schedule_change(&self, path: &str) -> Option<SystemTime> {
+        let now = unix_timestamp(self.clock().now());
+        let Some(Value::Array(items)) = self.get(path).filter(|_| !self.preview) else {
+            return None;
+        };
+        items.iter()
+            .flat_map(|item| [item.get(OPEN_DATE_FIELD), item.get(CLOSE_DATE_FIELD)])
+            .filter_map(|date| schedule_timestamp(date).flatten())
+            .filter(|timestamp| *timestamp > now)
+            .min()
+            .map(|timestamp| SystemTime::UNIX_EPOCH + Duration::from_secs(timestamp as u64))
+    }
+}
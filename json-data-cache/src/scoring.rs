@@ -0,0 +1,137 @@
+//! Bot likelihood scoring: weighted heuristics over request values held in the cache (headers, user agent, form timings, geo),
+//! whose result is inserted back into the cache for conditional template blocks or redirect rules to act on.
+
+use serde_json::{Value, json};
+
+use crate::{DataCache, error::JsonDataCacheError};
+
+/// Default path of the score, see BotScorer::output_path
+pub const DEFAULT_SCORE_PATH: &str = "bot";
+
+/// Lowercase user agent substrings of common crawlers, HTTP libraries & headless browsers
+pub const BOT_USER_AGENT_MARKERS: &[&str] = &[
+    "bot", "crawler", "spider", "slurp", "curl", "wget", "python-requests", "python-urllib", "go-http-client",
+    "java/", "okhttp", "libwww", "httpclient", "headlesschrome", "phantomjs", "puppeteer", "playwright", "selenium"
+];
+
+/// A condition over a cache value. Each matched heuristic adds its weight to the score
+#[derive(Debug, Clone)]
+pub enum Heuristic {
+    /// No value at path, like a header every browser sends (accept-language...)
+    Missing(String),
+    /// A value at path, like a honeypot form field hidden from humans
+    Present(String),
+    /// The string at path contains one of the substrings, ignoring case (see BOT_USER_AGENT_MARKERS)
+    Contains(String, Vec<String>),
+    /// The number (or numeric string) at path is lower than the threshold, like the time spent filling a form
+    LessThan(String, f64),
+    /// The value at path is one of the values, like the country of the request
+    OneOf(String, Vec<Value>),
+}
+
+impl Heuristic {
+    /// Contains with BOT_USER_AGENT_MARKERS
+    pub fn bot_user_agent(path: &str) -> Self {
+        Heuristic::Contains(path.to_string(), BOT_USER_AGENT_MARKERS.iter().map(|marker| marker.to_string()).collect())
+    }
+
+    fn is_matched(&self, data_cache: &DataCache) -> bool {
+        match self {
+            Heuristic::Missing(path) => data_cache.get(path).is_none_or(Value::is_null),
+            Heuristic::Present(path) => data_cache.get(path).is_some_and(|value| match value {
+                Value::Null => false,
+                Value::String(value) => !value.is_empty(),
+                _ => true,
+            }),
+            Heuristic::Contains(path, substrings) => data_cache.get(path).and_then(Value::as_str).is_some_and(|value| {
+                let value = value.to_lowercase();
+                substrings.iter().any(|substring| value.contains(&substring.to_lowercase()))
+            }),
+            Heuristic::LessThan(path, threshold) => data_cache.get(path).and_then(|value| match value {
+                Value::Number(number) => number.as_f64(),
+                Value::String(value) => value.trim().parse().ok(),
+                _ => None,
+            }).is_some_and(|number| number < *threshold),
+            Heuristic::OneOf(path, values) => data_cache.get(path).is_some_and(|value| values.contains(value)),
+        }
+    }
+}
+
+/// Result of BotScorer::score
+#[derive(Debug, Clone, PartialEq)]
+pub struct BotScore {
+    pub score: f64, // Sum of the weights of matched heuristics, capped to 1
+    pub is_bot: bool, // score reached the threshold
+    pub matched: Vec<String> // Names of the matched heuristics, in declaration order
+}
+
+/// Named & weighted heuristics
+#[derive(Debug, Clone)]
+pub struct BotScorer {
+    heuristics: Vec<(String, Heuristic, f64)>,
+    threshold: f64,
+    output_path: String
+}
+
+impl Default for BotScorer {
+    fn default() -> Self {
+        Self {
+            heuristics: Vec::new(),
+            threshold: 0.5,
+            output_path: DEFAULT_SCORE_PATH.to_string()
+        }
+    }
+}
+
+impl BotScorer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Negative weights lower the score, for signals of a human visitor
+    pub fn heuristic(mut self, name: &str, heuristic: Heuristic, weight: f64) -> Self {
+        self.heuristics.push((name.to_string(), heuristic, weight));
+        self
+    }
+
+    /// Minimum score for is_bot, 0.5 by default
+    pub fn threshold(mut self, threshold: f64) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    pub fn output_path(mut self, output_path: &str) -> Self {
+        self.output_path = output_path.to_string();
+        self
+    }
+
+    pub fn score(&self, data_cache: &DataCache) -> BotScore {
+        let mut score = 0.0;
+        let mut matched = Vec::new();
+        for (name, heuristic, weight) in &self.heuristics {
+            if heuristic.is_matched(data_cache) {
+                score += weight;
+                matched.push(name.clone());
+            }
+        }
+        let score = score.clamp(0.0, 1.0);
+        BotScore {
+            score,
+            is_bot: score >= self.threshold,
+            matched
+        }
+    }
+}
+
+impl DataCache {
+    /// Scores the request and inserts {"score", "is_bot", "matched"} at the output path of the scorer (bot by default)
+    pub fn score_bot(&mut self, scorer: &BotScorer) -> Result<BotScore, JsonDataCacheError> {
+        let bot_score = scorer.score(self);
+        self.try_replace(&scorer.output_path, json!({
+            "score": bot_score.score,
+            "is_bot": bot_score.is_bot,
+            "matched": bot_score.matched
+        }))?;
+        Ok(bot_score)
+    }
+}
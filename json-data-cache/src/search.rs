@@ -0,0 +1,137 @@
+//! Edge side search over cached content: an inverted index of the strings at (and below) the paths matching some patterns,
+//! like "news.*" for whole articles. Strings are lowercased & split into words; runs of Japanese (kana & kanji) characters,
+//! not separated by spaces, are split into bigrams when enabled. Results are ranked by tf-idf.
+
+use std::collections::{BTreeMap, HashMap};
+
+use serde_json::Value;
+
+use crate::{DataCache, path_pattern::PathPattern};
+
+/// Pattern matched path & its relevance to the query
+#[derive(Debug, Clone, PartialEq)]
+pub struct SearchHit {
+    pub path: String,
+    pub score: f64,
+}
+
+#[derive(Debug, Clone)]
+pub struct SearchIndex {
+    patterns: Vec<PathPattern>,
+    bigrams: bool,
+    postings: HashMap<String, BTreeMap<String, usize>>, // Paths containing each token, with its number of occurrences
+    path_count: usize,
+}
+
+fn is_japanese(c: char) -> bool {
+    matches!(c, '\u{3040}'..='\u{30ff}' | '\u{3400}'..='\u{4dbf}' | '\u{4e00}'..='\u{9fff}' | '\u{f900}'..='\u{faff}' | '\u{ff66}'..='\u{ff9f}')
+}
+
+/// Lowercased words, Japanese runs being split into bigrams (or kept whole) depending on bigrams
+fn tokenize(text: &str, bigrams: bool) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut push_run = |run: &mut Vec<char>, japanese: bool| {
+        if japanese && bigrams && run.len() > 1 {
+            tokens.extend(run.windows(2).map(|pair| pair.iter().collect::<String>()));
+        } else if !run.is_empty() {
+            tokens.push(run.iter().collect::<String>().to_lowercase());
+        }
+        run.clear();
+    };
+    let mut run = Vec::new();
+    let mut run_japanese = false;
+    for c in text.chars() {
+        if !c.is_alphanumeric() {
+            push_run(&mut run, run_japanese);
+            continue;
+        }
+        let japanese = is_japanese(c);
+        if japanese != run_japanese {
+            push_run(&mut run, run_japanese);
+            run_japanese = japanese;
+        }
+        run.push(c);
+    }
+    push_run(&mut run, run_japanese);
+    tokens
+}
+
+fn collect_strings<'a>(value: &'a Value, strings: &mut Vec<&'a str>) {
+    match value {
+        Value::String(s) => strings.push(s),
+        Value::Array(items) => items.iter().for_each(|item| collect_strings(item, strings)),
+        Value::Object(o) => o.values().for_each(|item| collect_strings(item, strings)),
+        _ => {},
+    }
+}
+
+impl SearchIndex {
+    /// An empty index of the strings at & below the paths matching patterns, filled by rebuild
+    pub fn new(patterns: &[&str]) -> Self {
+        Self {
+            patterns: patterns.iter().map(|pattern| PathPattern::from(*pattern)).collect(),
+            bigrams: false,
+            postings: HashMap::new(),
+            path_count: 0,
+        }
+    }
+
+    /// Splits runs of Japanese characters into bigrams, for queries to match within them
+    pub fn bigrams(mut self, bigrams: bool) -> Self {
+        self.bigrams = bigrams;
+        self
+    }
+
+    /// Indexes the current content of the cache, replacing the previous one. Returns the number of indexed paths
+    pub fn rebuild(&mut self, data_cache: &DataCache) -> usize {
+        self.postings.clear();
+        self.path_count = 0;
+        for pattern in &self.patterns {
+            for path in pattern.matching_paths(&data_cache.root) {
+                let Some(value) = DataCache::lookup(&data_cache.root, &path) else {
+                    continue;
+                };
+                let mut strings = Vec::new();
+                collect_strings(value, &mut strings);
+                let tokens: Vec<String> = strings.into_iter().flat_map(|s| tokenize(s, self.bigrams)).collect();
+                if tokens.is_empty() {
+                    continue;
+                }
+                self.path_count += 1;
+                for token in tokens {
+                    *self.postings.entry(token).or_default().entry(path.clone()).or_default() += 1;
+                }
+            }
+        }
+        self.path_count
+    }
+
+    /// Paths containing every token of the query, the most relevant first (ties by path)
+    pub fn search(&self, query: &str) -> Vec<SearchHit> {
+        let mut tokens = tokenize(query, self.bigrams);
+        tokens.sort_unstable();
+        tokens.dedup();
+        let Some((first, others)) = tokens.split_first() else {
+            return Vec::new();
+        };
+        let idf = |paths: &BTreeMap<String, usize>| (1.0 + self.path_count as f64 / paths.len() as f64).ln();
+        let Some(first_paths) = self.postings.get(first) else {
+            return Vec::new();
+        };
+        let mut scores: BTreeMap<&str, f64> = first_paths.iter()
+            .map(|(path, count)| (path.as_str(), *count as f64 * idf(first_paths)))
+            .collect();
+        for token in others {
+            let Some(paths) = self.postings.get(token) else {
+                return Vec::new();
+            };
+            scores.retain(|path, _| paths.contains_key(*path));
+            for (path, score) in scores.iter_mut() {
+                *score += paths[*path] as f64 * idf(paths);
+            }
+        }
+        let mut hits: Vec<SearchHit> = scores.into_iter().map(|(path, score)| SearchHit { path: path.to_string(), score }).collect();
+        hits.sort_by(|a, b| b.score.total_cmp(&a.score)); // Stable, keeping the ties sorted by path
+        hits
+    }
+}
@@ -0,0 +1,159 @@
+//! Security response headers (CSP, HSTS, Referrer-Policy, Permissions-Policy) rendered from a policy document held in the cache,
+//! with a per-response CSP nonce exposed to templates as {$csp_nonce}.
+//!
+//! Example policy document:
+//! ```json
+//! {
+//!     "csp": {"default-src": ["'self'"], "script-src": ["'self'", "'nonce'"]},
+//!     "hsts": {"max_age": 31536000, "include_subdomains": true, "preload": false},
+//!     "referrer_policy": "strict-origin-when-cross-origin",
+//!     "permissions_policy": {"camera": [], "geolocation": ["self", "https://maps.example.com"]}
+//! }
+//! ```
+//! The `'nonce'` source is replaced by `'nonce-{value}'`.
+
+use std::io::Write;
+
+use serde_json::Value;
+
+use crate::{DataCache, base64, error::JsonDataCacheError, html_rewriter::{HtmlRewriter, RewriteRule}, runtime::Rng};
+
+/// Cache key of the nonce, for templates to render <script nonce="{$csp_nonce}">
+pub const CSP_NONCE_KEY: &str = "csp_nonce";
+
+/// Source of a CSP directive replaced by the nonce
+const NONCE_SOURCE: &str = "'nonce'";
+
+/// Builds SecurityHeaders from the policy document at a path of the cache
+#[derive(Debug, Clone)]
+pub struct SecurityHeadersBuilder {
+    policy_path: String,
+    nonce: Option<String>
+}
+
+/// Rendered headers, in policy document order, along with the nonce they use
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SecurityHeaders {
+    pub headers: Vec<(&'static str, String)>,
+    pub nonce: String
+}
+
+impl SecurityHeadersBuilder {
+    pub fn new(policy_path: &str) -> Self {
+        Self {
+            policy_path: policy_path.to_string(),
+            nonce: None
+        }
+    }
+
+    /// Uses this nonce instead of generating one, for example when it is provided by the platform
+    pub fn nonce(mut self, nonce: &str) -> Self {
+        self.nonce = Some(nonce.to_string());
+        self
+    }
+
+    /// Renders the headers & inserts the nonce at CSP_NONCE_KEY. Unknown policy keys are ignored
+    pub fn build(&self, data_cache: &mut DataCache) -> Result<SecurityHeaders, JsonDataCacheError> {
+        let policy = data_cache.get(&self.policy_path).ok_or(format!("No security policy at {}", self.policy_path))?;
+        let nonce = match &self.nonce {
+            Some(nonce) => nonce.clone(),
+            None => generate_nonce(data_cache.rng()?),
+        };
+        let mut headers = Vec::new();
+        for (key, value) in policy.as_object().ok_or(format!("Security policy at {} is not an object", self.policy_path))? {
+            let rendered = match key.as_str() {
+                "csp" => ("Content-Security-Policy", render_csp(value, &nonce)?),
+                "csp_report_only" => ("Content-Security-Policy-Report-Only", render_csp(value, &nonce)?),
+                "hsts" => ("Strict-Transport-Security", render_hsts(value)?),
+                "referrer_policy" => ("Referrer-Policy", value.as_str().ok_or("referrer_policy must be a string")?.to_string()),
+                "permissions_policy" => ("Permissions-Policy", render_permissions_policy(value)?),
+                _ => continue,
+            };
+            headers.push(rendered);
+        }
+        data_cache.try_insert(CSP_NONCE_KEY, Value::String(nonce.clone()))?;
+        Ok(SecurityHeaders { headers, nonce })
+    }
+}
+
+impl SecurityHeaders {
+    /// Rules adding the nonce attribute to the inline <script> & <style> tags lacking one, for an HtmlRewriter
+    pub fn nonce_rules(&self) -> Vec<RewriteRule> {
+        Vec::from([
+            RewriteRule::new("script").unless("nonce", "").unless("src", "").set_attribute("nonce", &self.nonce),
+            RewriteRule::new("style").unless("nonce", "").set_attribute("nonce", &self.nonce),
+        ])
+    }
+
+    /// Adds the nonce attribute to the inline <script> & <style> tags of the HTML lacking one (see nonce_rules)
+    /// Templates can also render it themselves with {$csp_nonce}
+    pub fn inject_nonces(&self, html: &[u8]) -> Vec<u8> {
+        let mut rewriter = HtmlRewriter::new(Vec::with_capacity(html.len()), self.nonce_rules());
+        // Writes to a Vec never fail
+        rewriter.write_all(html).and_then(|()| rewriter.finish()).unwrap_or_default()
+    }
+}
+
+/// 128 random bits
+fn generate_nonce(rng: &dyn Rng) -> String {
+    let mut bytes = [0; 16];
+    rng.fill_bytes(&mut bytes);
+    base64::encode_url_safe(&bytes)
+}
+
+/// Directives are objects of source lists (a list of strings) or flags (true, like upgrade-insecure-requests)
+fn render_csp(csp: &Value, nonce: &str) -> Result<String, JsonDataCacheError> {
+    let nonce_source = format!("'nonce-{nonce}'");
+    let mut directives = Vec::new();
+    for (directive, sources) in csp.as_object().ok_or("csp must be an object")? {
+        match sources {
+            Value::Bool(true) => directives.push(directive.clone()),
+            Value::Bool(false) => {},
+            Value::Array(sources) => {
+                let mut rendered = directive.clone();
+                for source in sources {
+                    let source = source.as_str().ok_or(format!("Invalid source in csp {directive}"))?;
+                    rendered.push(' ');
+                    rendered.push_str(if source == NONCE_SOURCE { &nonce_source } else { source });
+                }
+                directives.push(rendered);
+            },
+            _ => return Err(format!("Invalid csp {directive}").into()),
+        }
+    }
+    Ok(directives.join("; "))
+}
+
+fn render_hsts(hsts: &Value) -> Result<String, JsonDataCacheError> {
+    let max_age = hsts.get("max_age").and_then(Value::as_u64).ok_or("hsts requires a max_age")?;
+    let mut rendered = format!("max-age={max_age}");
+    if hsts.get("include_subdomains").and_then(Value::as_bool).unwrap_or_default() {
+        rendered.push_str("; includeSubDomains");
+    }
+    if hsts.get("preload").and_then(Value::as_bool).unwrap_or_default() {
+        rendered.push_str("; preload");
+    }
+    Ok(rendered)
+}
+
+/// Features are objects of allowlists : self, * or origins
+fn render_permissions_policy(permissions_policy: &Value) -> Result<String, JsonDataCacheError> {
+    let mut features = Vec::new();
+    for (feature, allowlist) in permissions_policy.as_object().ok_or("permissions_policy must be an object")? {
+        let allowlist = allowlist.as_array().ok_or(format!("Invalid permissions_policy {feature}"))?;
+        let origins = allowlist.iter()
+            .map(|origin| match origin.as_str() {
+                Some("*") => Ok("*".to_string()),
+                Some("self") => Ok("self".to_string()),
+                Some(origin) => Ok(format!("\"{origin}\"")),
+                None => Err(format!("Invalid origin in permissions_policy {feature}")),
+            })
+            .collect::<Result<Vec<String>, String>>()?;
+        if origins.len() == 1 && origins[0] == "*" {
+            features.push(format!("{feature}=*"));
+        } else {
+            features.push(format!("{feature}=({})", origins.join(" ")));
+        }
+    }
+    Ok(features.join(", "))
+}
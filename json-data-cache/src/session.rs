@@ -0,0 +1,165 @@
+use serde_json::Value;
+
+use crate::{DataCache, base64, error::JsonDataCacheError, runtime::Rng, sha256::{constant_time_eq, hmac_sha256}};
+
+/// Maximum size of a cookie (name, '=' & value) accepted by all major browsers
+pub const COOKIE_SIZE_LIMIT: usize = 4096;
+
+/// Length of the nonce of encrypted payloads, drawn by encode_encrypted
+pub const SESSION_NONCE_LEN: usize = 16;
+
+#[derive(Debug, Clone)]
+struct SessionKey {
+    id: String,
+    signing_key: [u8; 32],
+    encryption_key: [u8; 32],
+}
+
+/// Signs (and optionally encrypts) a session subtree into a cookie value, and restores it from the cookie of the next request
+/// Cookie values are `{key id}.{s|e}.{payload}.{signature}`, payload & signature being base64url encoded.
+/// Encrypted payloads are the nonce followed by the JSON XORed with an HMAC-SHA256 keystream, signed after encryption
+#[derive(Debug, Clone)]
+pub struct SessionCodec {
+    cookie_name: String,
+    keys: Vec<SessionKey>, // The last one signs, all of them verify
+}
+
+/// Encoded session, along with its size accounting against COOKIE_SIZE_LIMIT
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SessionCookie {
+    pub value: String,
+    pub size: usize, // Of the name, '=' & value
+    pub remaining: usize // Bytes left before COOKIE_SIZE_LIMIT
+}
+
+impl SessionCodec {
+    /// The key id is written in cookies to find the key verifying them, and must not contain '.'
+    pub fn new(cookie_name: &str, key_id: &str, secret: &[u8]) -> Self {
+        Self {
+            cookie_name: cookie_name.to_string(),
+            keys: Vec::new(),
+        }.rotate(key_id, secret)
+    }
+
+    /// Signs new cookies with this key, previous keys still verifying the cookies signed before the rotation
+    pub fn rotate(mut self, key_id: &str, secret: &[u8]) -> Self {
+        self.keys.retain(|key| key.id != key_id);
+        self.keys.push(SessionKey {
+            id: key_id.to_string(),
+            signing_key: hmac_sha256(secret, b"session-signing"),
+            encryption_key: hmac_sha256(secret, b"session-encryption"),
+        });
+        self
+    }
+
+    /// Stops accepting cookies signed with this key. Fails when it is the last key, which signs the new cookies
+    pub fn retire(mut self, key_id: &str) -> Result<Self, JsonDataCacheError> {
+        if self.keys.len() == 1 {
+            return Err(format!("Unable to retire session key {key_id}, the codec would have no key left").into());
+        }
+        self.keys.retain(|key| key.id != key_id);
+        Ok(self)
+    }
+
+    pub fn cookie_name(&self) -> &str {
+        &self.cookie_name
+    }
+
+    /// Signs the value, readable by the client
+    pub fn encode(&self, value: &Value) -> Result<SessionCookie, JsonDataCacheError> {
+        let json = serde_json::to_vec(value).map_err(|e| format!("Unable to serialize session : {e}"))?;
+        self.seal(&json, None)
+    }
+
+    /// Encrypts then signs the value, with a nonce drawn from the rng, which must be a secure random source (see
+    /// DataCacheOptions::rng) as nonces must never be reused with the same key
+    pub fn encode_encrypted(&self, value: &Value, rng: &dyn Rng) -> Result<SessionCookie, JsonDataCacheError> {
+        let json = serde_json::to_vec(value).map_err(|e| format!("Unable to serialize session : {e}"))?;
+        self.seal(&json, Some(rng))
+    }
+
+    /// Verifies (and decrypts) a cookie value. Fails on any tampering, unknown key or malformed value
+    pub fn decode(&self, cookie_value: &str) -> Result<Value, JsonDataCacheError> {
+        let invalid = || -> JsonDataCacheError { "Invalid session cookie".into() };
+        let (signed, signature) = cookie_value.rsplit_once('.').ok_or_else(invalid)?;
+        let mut parts = signed.splitn(3, '.');
+        let (Some(key_id), Some(mode), Some(payload)) = (parts.next(), parts.next(), parts.next()) else {
+            return Err(invalid());
+        };
+        let key = self.keys.iter().find(|key| key.id == key_id).ok_or_else(|| format!("Unknown session key {key_id}"))?;
+        let signature = base64::decode_url_safe(signature).ok_or_else(invalid)?;
+        if !constant_time_eq(&signature, &hmac_sha256(&key.signing_key, signed.as_bytes())) {
+            return Err("Invalid session signature".into());
+        }
+        let mut payload = base64::decode_url_safe(payload).ok_or_else(invalid)?;
+        let json = match mode {
+            "s" => &payload[..],
+            "e" if payload.len() >= SESSION_NONCE_LEN => {
+                let (nonce, ciphertext) = payload.split_at_mut(SESSION_NONCE_LEN);
+                apply_keystream(&key.encryption_key, nonce, ciphertext);
+                ciphertext
+            },
+            _ => return Err(invalid()),
+        };
+        serde_json::from_slice(json).map_err(|e| format!("Invalid session JSON : {e}").into())
+    }
+
+    /// Signs the JSON, encrypting it first with a nonce of the rng if any
+    fn seal(&self, json: &[u8], rng: Option<&dyn Rng>) -> Result<SessionCookie, JsonDataCacheError> {
+        let key = self.keys.last().unwrap();
+        let (mode, payload) = match rng {
+            Some(rng) => {
+                let mut payload = vec![0; SESSION_NONCE_LEN];
+                rng.fill_bytes(&mut payload);
+                payload.extend_from_slice(json);
+                let (nonce, plaintext) = payload.split_at_mut(SESSION_NONCE_LEN);
+                apply_keystream(&key.encryption_key, nonce, plaintext);
+                ("e", payload)
+            },
+            None => ("s", json.to_vec()),
+        };
+        let signed = format!("{}.{mode}.{}", key.id, base64::encode_url_safe(&payload));
+        let signature = base64::encode_url_safe(&hmac_sha256(&key.signing_key, signed.as_bytes()));
+        let value = format!("{signed}.{signature}");
+        let size = self.cookie_name.len() + 1 + value.len();
+        if size > COOKIE_SIZE_LIMIT {
+            return Err(format!("Session cookie {} is {size} bytes, over the {COOKIE_SIZE_LIMIT} bytes limit", self.cookie_name).into());
+        }
+        Ok(SessionCookie {
+            value,
+            size,
+            remaining: COOKIE_SIZE_LIMIT - size,
+        })
+    }
+}
+
+/// XORs data with HMAC-SHA256(key, nonce || block counter) blocks
+fn apply_keystream(key: &[u8; 32], nonce: &[u8], data: &mut [u8]) {
+    let mut block_input = nonce.to_vec();
+    block_input.extend_from_slice(&[0; 4]);
+    for (counter, chunk) in data.chunks_mut(32).enumerate() {
+        block_input[nonce.len()..].copy_from_slice(&(counter as u32).to_be_bytes());
+        let keystream = hmac_sha256(key, &block_input);
+        for (byte, key_byte) in chunk.iter_mut().zip(keystream) {
+            *byte ^= key_byte;
+        }
+    }
+}
+
+impl DataCache {
+    /// Encodes the subtree at path into a cookie value (see SessionCodec::encode)
+    pub fn save_session(&self, codec: &SessionCodec, path: &str) -> Result<SessionCookie, JsonDataCacheError> {
+        codec.encode(self.get(path).unwrap_or(&Value::Null))
+    }
+
+    /// Same as save_session, encrypting the subtree with a nonce of the rng of the options (see SessionCodec::encode_encrypted)
+    pub fn save_session_encrypted(&self, codec: &SessionCodec, path: &str) -> Result<SessionCookie, JsonDataCacheError> {
+        codec.encode_encrypted(self.get(path).unwrap_or(&Value::Null), self.rng()?)
+    }
+
+    /// Replaces the subtree at path with the session of the cookie value. Nothing is inserted if the cookie is invalid
+    pub fn restore_session(&mut self, codec: &SessionCodec, path: &str, cookie_value: &str) -> Result<(), JsonDataCacheError> {
+        let value = codec.decode(cookie_value)?;
+        self.try_replace(path, value)
+    }
+}
@@ -0,0 +1,91 @@
+//! Static top level keys (site configuration, navigation), set by set_static_keys: their values get their own serialization
+//! & automaton, rebuilt only when one of them is modified, while inserts of other keys only rebuild the automaton of the
+//! dynamic keys. Replacements run both automata in a single streaming pass.
+//! References & fallbacks under a static key pointing outside of the static keys are replaced by the automaton of the dynamic keys.
+//! The serialized data of the whole tree (string values, serialized_range...) still covers the static keys, and
+//! replacements with a variant (see define_variant) use a single automaton of all keys.
+
+use aho_corasick::AhoCorasick;
+use serde_json::{Map, Value};
+
+use crate::{DataCache, SCRATCH_KEY, error::JsonDataCacheError, json_serializer::{JsonSerializer, SerializerOptions, serialized_data::SerializedDataLegacy}, replace_engine::{KeyScope, Replacement, ReplacementBuffers}};
+
+#[derive(Debug)]
+pub(crate) struct StaticAutomaton {
+    serialized: SerializedDataLegacy,
+    double_serialized: Option<SerializedDataLegacy>,
+    pub(crate) ac: AhoCorasick,
+    replacements: Vec<Replacement>,
+    rendered: Vec<Vec<u8>>
+}
+
+impl StaticAutomaton {
+    pub(crate) fn replacement<'a>(&'a self, data_cache: &'a DataCache, pattern_idx: usize) -> &'a [u8] {
+        let buffers = ReplacementBuffers {
+            serialized: &self.serialized.data,
+            double_serialized: &self.double_serialized.as_ref().unwrap_or(&self.serialized).data,
+            rendered: &self.rendered
+        };
+        data_cache.replacement_of(&self.replacements, buffers, pattern_idx)
+    }
+}
+
+impl DataCache {
+    /// Sets the top level keys whose values are static (see the static_keys module), replacing the previous ones
+    pub fn set_static_keys(&mut self, keys: &[&str]) {
+        self.static_keys = keys.iter().filter(|key| **key != SCRATCH_KEY).map(|key| key.to_string()).collect();
+        self.on_after_insert();
+    }
+
+    pub fn static_keys(&self) -> &[String] {
+        &self.static_keys
+    }
+
+    /// True if the top level key of path is static
+    pub(crate) fn is_static_key(&self, path: &str) -> bool {
+        let top_level_key = path.split('.').next().unwrap_or_default();
+        self.static_keys.iter().any(|key| key == top_level_key)
+    }
+
+    /// True if the value of path belongs to the automaton of the static keys: its top level key is static, and it is not a
+    /// reference or fallback to a key outside of them, whose modifications would not rebuild that automaton
+    pub(crate) fn is_static_value(&self, path: &str) -> bool {
+        self.is_static_key(path) && !self.refs.resolves_outside(path, |target| !self.is_static_key(target))
+    }
+
+    /// True if the automaton of the static keys is built, and not outdated by a modification since
+    pub fn is_static_automaton_built(&self) -> bool {
+        self.static_automaton.is_some()
+    }
+
+    /// Builds the automaton of the static keys if needed
+    pub(crate) fn prepare_static(&mut self) -> Result<(), JsonDataCacheError> {
+        if self.static_keys.is_empty() || self.static_automaton.is_some() {
+            return Ok(());
+        }
+        let mut static_root = Map::new();
+        for key in &self.static_keys {
+            if let Some(value) = self.root.get(key) {
+                static_root.insert(key.clone(), value.clone());
+            }
+        }
+        let static_root = Value::Object(static_root);
+        let options = SerializerOptions {
+            double_serialize: true,
+            substitutions: self.compression.values()
+                .filter(|(path, _)| self.is_static_key(path))
+                .map(|(path, value)| (path.to_string(), value.clone()))
+                .collect(),
+            ..Default::default()
+        };
+        let (mut serialized, mut double_serialized, _) = JsonSerializer::serialize_with(&static_root, &options);
+        self.refs.copy_values(&self.root, &self.compression, &mut serialized.key_values);
+        if let Some(double_serialized) = &mut double_serialized {
+            self.refs.copy_values(&self.root, &self.compression, &mut double_serialized.key_values);
+        }
+        let (patterns, replacements, rendered) = self.automaton_patterns(None, KeyScope::Static(&serialized, double_serialized.as_ref()))?;
+        let ac = self.build_ac(patterns)?;
+        self.static_automaton = Some(StaticAutomaton { serialized, double_serialized, ac, replacements, rendered });
+        Ok(())
+    }
+}
@@ -0,0 +1,176 @@
+use std::{borrow::Cow, collections::HashMap, fs, io, path::PathBuf, rc::Rc, time::{Duration, SystemTime, UNIX_EPOCH}};
+
+use serde_json::Value;
+
+use crate::{DataCache, SCRATCH_KEY, error::JsonDataCacheError, is_scratch_path, runtime::{Clock, PlatformClock}};
+
+/// A key-value store holding serialized subtrees, shared between workers (edge KV stores, Redis via a sidecar...)
+/// Expired entries must behave as missing ones
+pub trait CacheStore {
+    fn get(&mut self, key: &str) -> Result<Option<Vec<u8>>, JsonDataCacheError>;
+    /// ttl None keeps the entry until it is deleted or overwritten
+    fn put(&mut self, key: &str, value: &[u8], ttl: Option<Duration>) -> Result<(), JsonDataCacheError>;
+    /// Deleting a missing key is not an error
+    fn delete(&mut self, key: &str) -> Result<(), JsonDataCacheError>;
+}
+
+/// Store kept in process memory, mostly useful for tests and as a reference implementation
+#[derive(Debug, Default)]
+pub struct MemoryCacheStore {
+    entries: HashMap<String, (Vec<u8>, Option<SystemTime>)>, // Value & expiry
+    clock: Option<Rc<dyn Clock>> // None uses the platform clock
+}
+
+impl MemoryCacheStore {
+    pub fn with_clock(mut self, clock: Rc<dyn Clock>) -> Self {
+        self.clock = Some(clock);
+        self
+    }
+
+    fn now(&self) -> SystemTime {
+        self.clock.as_deref().map_or_else(|| PlatformClock.now(), Clock::now)
+    }
+}
+
+impl CacheStore for MemoryCacheStore {
+    fn get(&mut self, key: &str) -> Result<Option<Vec<u8>>, JsonDataCacheError> {
+        let now = self.now();
+        match self.entries.get(key) {
+            Some((_, Some(expiry))) if *expiry <= now => {
+                self.entries.remove(key);
+                Ok(None)
+            },
+            Some((value, _)) => Ok(Some(value.clone())),
+            None => Ok(None),
+        }
+    }
+
+    fn put(&mut self, key: &str, value: &[u8], ttl: Option<Duration>) -> Result<(), JsonDataCacheError> {
+        let expiry = ttl.map(|ttl| self.now() + ttl);
+        self.entries.insert(key.to_string(), (value.to_vec(), expiry));
+        Ok(())
+    }
+
+    fn delete(&mut self, key: &str) -> Result<(), JsonDataCacheError> {
+        self.entries.remove(key);
+        Ok(())
+    }
+}
+
+/// Store writing one file per key in a directory. Each file starts with a line holding the expiry
+/// (milliseconds since the Unix epoch, 0 for none), followed by the value
+#[derive(Debug)]
+pub struct FileCacheStore {
+    directory: PathBuf,
+    clock: Option<Rc<dyn Clock>> // None uses the platform clock
+}
+
+impl FileCacheStore {
+    /// Creates the directory if needed
+    pub fn new(directory: impl Into<PathBuf>) -> Result<Self, JsonDataCacheError> {
+        let directory = directory.into();
+        fs::create_dir_all(&directory)?;
+        Ok(Self { directory, clock: None })
+    }
+
+    pub fn with_clock(mut self, clock: Rc<dyn Clock>) -> Self {
+        self.clock = Some(clock);
+        self
+    }
+
+    fn now(&self) -> SystemTime {
+        self.clock.as_deref().map_or_else(|| PlatformClock.now(), Clock::now)
+    }
+
+    /// Keys are percent-encoded into file names, so that any key maps to a single file of the directory
+    fn file_path(&self, key: &str) -> PathBuf {
+        let mut file_name = String::with_capacity(key.len());
+        for byte in key.bytes() {
+            if byte.is_ascii_alphanumeric() || byte == b'-' || byte == b'_' {
+                file_name.push(byte as char);
+            } else {
+                file_name.push_str(&format!("%{byte:02X}"));
+            }
+        }
+        self.directory.join(file_name)
+    }
+}
+
+impl CacheStore for FileCacheStore {
+    fn get(&mut self, key: &str) -> Result<Option<Vec<u8>>, JsonDataCacheError> {
+        let file_path = self.file_path(key);
+        let content = match fs::read(&file_path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(e.into()),
+        };
+        let header_len = content.iter().position(|b| *b == b'\n').ok_or(format!("Invalid cache file {}", file_path.display()))?;
+        let expiry: u64 = str::from_utf8(&content[..header_len]).ok()
+            .and_then(|header| header.parse().ok())
+            .ok_or(format!("Invalid cache file {}", file_path.display()))?;
+        if expiry > 0 && UNIX_EPOCH + Duration::from_millis(expiry) <= self.now() {
+            self.delete(key)?;
+            return Ok(None);
+        }
+        Ok(Some(content[header_len + 1..].to_vec()))
+    }
+
+    fn put(&mut self, key: &str, value: &[u8], ttl: Option<Duration>) -> Result<(), JsonDataCacheError> {
+        let expiry = match ttl {
+            Some(ttl) => (self.now() + ttl).duration_since(UNIX_EPOCH).map(|d| d.as_millis() as u64).unwrap_or(1).max(1),
+            None => 0,
+        };
+        let mut content = format!("{expiry}\n").into_bytes();
+        content.extend_from_slice(value);
+        fs::write(self.file_path(key), content)?;
+        Ok(())
+    }
+
+    fn delete(&mut self, key: &str) -> Result<(), JsonDataCacheError> {
+        match fs::remove_file(self.file_path(key)) {
+            Err(e) if e.kind() != io::ErrorKind::NotFound => Err(e.into()),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl DataCache {
+    /// Inserts the JSON value stored under key at path (merged into the root if path is empty)
+    /// Returns false if the store has no such key
+    pub fn hydrate_from<S: CacheStore + ?Sized>(&mut self, store: &mut S, key: &str, path: &str) -> Result<bool, JsonDataCacheError> {
+        let Some(bytes) = store.get(key)? else {
+            return Ok(false);
+        };
+        let value: Value = serde_json::from_slice(&bytes).map_err(|e| format!("Invalid JSON in cache store key {key} : {e}"))?;
+        if path.is_empty() {
+            self.try_merge(value)?;
+        } else {
+            self.try_insert(path, value)?;
+        }
+        Ok(true)
+    }
+
+    /// Stores the value at path (the whole root if path is empty) under key, as JSON
+    /// The scratch subtree (see SCRATCH_KEY) is never persisted
+    pub fn persist_to<S: CacheStore + ?Sized>(&self, store: &mut S, key: &str, path: &str, ttl: Option<Duration>) -> Result<(), JsonDataCacheError> {
+        if is_scratch_path(path) {
+            return Err(format!("Scratch values at {path} can not be persisted").into());
+        }
+        let mut value = if path.is_empty() {
+            let mut root = Cow::Borrowed(&self.root);
+            if self.root.get(SCRATCH_KEY).is_some()
+                && let Some(root) = root.to_mut().as_object_mut() {
+                root.remove(SCRATCH_KEY);
+            }
+            root
+        } else {
+            Cow::Borrowed(self.get(path).ok_or(format!("No value at {path} to persist"))?)
+        };
+        if !self.compression.is_empty() {
+            // Compressed strings are persisted decompressed
+            self.compression.restore(value.to_mut(), path);
+        }
+        let bytes = serde_json::to_vec(value.as_ref()).map_err(|e| format!("Unable to serialize {path} : {e}"))?;
+        store.put(key, &bytes, ttl)
+    }
+}
@@ -0,0 +1,229 @@
+use std::{borrow::Cow, collections::{HashMap, HashSet}};
+
+use serde_json::Value;
+
+use crate::{DataCache, DataCacheOptions, DataCacheSerializedData, compression::DataCacheCompression, SCRATCH_KEY, is_scratch_path, path_pattern::PathPattern, refs::DataCacheRefs, json_serializer::{JsonSerializer, SerializerOptions, key_value_range::Range, serialized_data::SerializedDataLegacy}};
+
+/// Details of a value slice returned by DataCache::serialized_range
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RangeMeta {
+    pub start: usize, // Position of the slice in the whole serialized data
+    pub end: usize,
+    pub is_string: bool, // The slice is the content of a JSON string, without its quotes
+    pub has_escapes: bool // String content contains JSON escapes (\", \n, \u...), so it is not the raw string
+}
+
+impl RangeMeta {
+    fn new(data: &[u8], range: &Range) -> Self {
+        // Ranges of strings exclude the surrounding quotes, while other values can never be preceeded by a quote
+        let is_string = range.start > 0 && data[range.start - 1] == b'"';
+        Self {
+            start: range.start,
+            end: range.end,
+            is_string,
+            has_escapes: is_string && data[range.start..range.end].contains(&b'\\')
+        }
+    }
+}
+
+/// Keys selected by DataCacheOptions::serialize_only & serialize_exclude, the scratch subtree excepted
+pub(crate) struct SerializedKeys {
+    only: Vec<PathPattern>,
+    exclude: Vec<PathPattern>,
+}
+
+impl SerializedKeys {
+    pub(crate) fn new(options: &DataCacheOptions) -> Self {
+        Self {
+            only: options.serialize_only.iter().map(PathPattern::from).collect(),
+            exclude: options.serialize_exclude.iter().map(PathPattern::from).collect(),
+        }
+    }
+
+    pub(crate) fn contains(&self, key: &str) -> bool {
+        !is_scratch_path(key)
+            && (self.only.is_empty() || self.only.iter().any(|pattern| pattern.covers(key)))
+            && !self.exclude.iter().any(|pattern| pattern.covers(key))
+    }
+
+    /// Drops the keys which are not selected from a map keyed by path
+    pub(crate) fn retain<V>(&self, map: &mut HashMap<String, V>) {
+        if !self.only.is_empty() || !self.exclude.is_empty() {
+            map.retain(|key, _| self.contains(key));
+        }
+    }
+}
+
+/// Drops the values of the scratch subtree from a map keyed by path
+pub(crate) fn remove_scratch_keys<V>(map: &mut HashMap<String, V>) {
+    map.retain(|key, _| !is_scratch_path(key));
+}
+
+#[derive(Debug, Default)]
+pub struct DataCacheStringValues {
+    pub(crate) is_built: bool,
+    values: HashMap<String, String>,
+    pub(crate) dirty_keys: HashSet<String> // Top level keys modified since the last build
+}
+
+impl DataCache {
+    /// Fills the map with string values of a node and its children, slicing them from an already serialized buffer
+    /// relative_start is the position in path where the keys of serialized start (path itself for a serialized subtree, path + '.' otherwise)
+    /// Walks the node with an explicit stack, so that deeply nested values can not overflow the call stack
+    fn collect_string_values(
+        map: &mut HashMap<String, String>,
+        value: &Value,
+        path: String,
+        relative_start: usize,
+        serialized: &SerializedDataLegacy
+    ) {
+        let child_path = |path: &str, key: &str| if path.is_empty() { key.to_string() } else { format!("{path}.{key}") };
+        let mut stack: Vec<(&Value, String)> = Vec::from([(value, path)]);
+        while let Some((value, path)) = stack.pop() {
+            match value {
+                Value::Array(a) => {
+                    stack.extend(a.iter().enumerate().map(|(idx, el)| (el, child_path(&path, &idx.to_string()))));
+                },
+                Value::Object(o) => {
+                    stack.extend(o.iter().map(|(k, v)| (v, child_path(&path, k))));
+                },
+                Value::String(v) => {
+                    // Serialized strings are escaped, so the original value is used instead
+                    map.insert(path, v.to_string());
+                    continue;
+                },
+                _ => {}
+            }
+            let serialized_value = if path.len() < relative_start {
+                // Top node of a serialized subtree
+                Some(&serialized.data[..])
+            } else {
+                serialized.key_values
+                    .get(&path[relative_start..])
+                    .map(|range| &serialized.data[range.start..range.end])
+            };
+            if let Some(serialized_value) = serialized_value {
+                map.insert(path, String::from_utf8_lossy(serialized_value).into_owned());
+            }
+        }
+    }
+
+    /// Cached equivalent of as_string_values_map. Values of intermediate nodes are sliced from a single serialization instead of
+    /// being stringified one by one, and after an insert only the modified top level subtrees are recomputed
+    pub fn string_values_view(&mut self) -> &HashMap<String, String> {
+        self.prepare_computed();
+        let string_values = &mut self.string_values;
+        if !string_values.is_built {
+            string_values.values.clear();
+            string_values.dirty_keys.clear();
+            // Reuse the serialized data used for replacements
+            Self::build_serialized(&self.serialized_data, &self.root, &self.refs, &self.compression);
+            let serialized = self.serialized_data.serialized.get().unwrap();
+            Self::collect_string_values(&mut string_values.values, &self.root, String::new(), 0, serialized);
+            remove_scratch_keys(&mut string_values.values);
+            Self::copy_decompressed(&self.compression, &mut string_values.values);
+            self.refs.copy_values(&self.root, &self.compression, &mut string_values.values);
+            string_values.is_built = true;
+        } else if !string_values.dirty_keys.is_empty() {
+            for key in string_values.dirty_keys.drain().filter(|key| key != SCRATCH_KEY) {
+                let nested_prefix = format!("{key}.");
+                string_values.values.retain(|k, _| k != &key && !k.starts_with(&nested_prefix));
+                if let Some(value) = self.root.get(&key) {
+                    // Same substitutions as build_serialized, relative to the subtree
+                    let options = SerializerOptions {
+                        substitutions: self.compression.values_under(&key).map(|(path, value)| (path.to_string(), value.clone())).collect(),
+                        ..Default::default()
+                    };
+                    let (serialized, _, _) = JsonSerializer::serialize_with(value, &options);
+                    Self::collect_string_values(&mut string_values.values, value, key.clone(), nested_prefix.len(), &serialized);
+                }
+            }
+            Self::copy_decompressed(&self.compression, &mut string_values.values);
+            // Referenced keys may have been modified
+            self.refs.copy_values(&self.root, &self.compression, &mut string_values.values);
+        }
+        SerializedKeys::new(&self.options).retain(&mut string_values.values);
+        &self.string_values.values
+    }
+
+    /// String values of the compressed strings, which the tree holds as null
+    fn copy_decompressed(compression: &DataCacheCompression, map: &mut HashMap<String, String>) {
+        for (path, value) in compression.values() {
+            if let Some(string_value) = map.get_mut(path) {
+                *string_value = value.as_str().unwrap_or_default().to_string();
+            }
+        }
+    }
+
+    /// Serializes the tree into the serialized data cache if it has not been done since the last insert
+    /// Keys of references point to the same ranges as their referenced keys, compressed strings are serialized decompressed
+    pub(crate) fn build_serialized(serialized_data: &DataCacheSerializedData, root: &Value, refs: &DataCacheRefs, compression: &DataCacheCompression) {
+        if serialized_data.serialized.get().is_none() {
+            let options = SerializerOptions {
+                double_serialize: true,
+                excluded_root_key: Some(SCRATCH_KEY.to_string()),
+                substitutions: compression.values().map(|(path, value)| (path.to_string(), value.clone())).collect(),
+                ..Default::default()
+            };
+            let (mut serialized, mut double_serialized, _) = JsonSerializer::serialize_with(root, &options);
+            refs.copy_values(root, compression, &mut serialized.key_values);
+            if let Some(double_serialized) = &mut double_serialized {
+                refs.copy_values(root, compression, &mut double_serialized.key_values);
+            }
+            let _ = serialized_data.serialized.set(serialized);
+            if let Some(double_serialized) = double_serialized {
+                let _ = serialized_data.double_serialized.set(double_serialized);
+            }
+        }
+    }
+
+    /// Iterates over the same keys & values as as_string_values_map, without building a map.
+    /// Values are borrowed from the serialized data (built if needed), and only strings containing escaped characters are allocated
+    pub fn as_str_values(&self) -> impl Iterator<Item = (&str, Cow<'_, str>)> {
+        Self::build_serialized(&self.serialized_data, &self.root, &self.refs, &self.compression);
+        let serialized = self.serialized_data.serialized.get().unwrap();
+        let serialized_keys = SerializedKeys::new(&self.options);
+        serialized.key_values.iter().filter(move |(key, _)| serialized_keys.contains(key)).map(|(key, range)| {
+            let data = &serialized.data;
+            let value_bytes = &data[range.start..range.end];
+            let value = if RangeMeta::new(data, range).has_escapes {
+                // Serialized string has escapes : decode it (including its quotes) back to the original string
+                serde_json::from_slice::<String>(&data[range.start - 1..range.end + 1])
+                    .map(Cow::Owned)
+                    .unwrap_or_else(|_| String::from_utf8_lossy(value_bytes))
+            } else {
+                String::from_utf8_lossy(value_bytes)
+            };
+            (key.as_str(), value)
+        })
+    }
+
+    /// Direct access to the serialized bytes of a key (building the serialized data if needed), as used for {$key} replacements
+    /// Strings are sliced without their quotes but with their JSON escapes, see RangeMeta
+    pub fn serialized_range(&self, key: &str) -> Option<(&[u8], RangeMeta)> {
+        Self::build_serialized(&self.serialized_data, &self.root, &self.refs, &self.compression);
+        let serialized = self.serialized_data.serialized.get().unwrap();
+        serialized.key_values.get(key).filter(|_| SerializedKeys::new(&self.options).contains(key)).map(|range| (&serialized.data[range.start..range.end], RangeMeta::new(&serialized.data, range)))
+    }
+
+    /// Lists all keys (as used by as_string_values_map) starting with the given prefix, in lexicographic order
+    /// Example: keys_with_prefix("content.list.") => ["content.list.0", "content.list.0.id", ...]
+    /// The sorted index is built on first call after an insert, then each lookup is a binary search
+    pub fn keys_with_prefix(&self, prefix: &str) -> Vec<&str> {
+        let key_index = self.serialized_data.key_index.get_or_init(|| {
+            Self::build_serialized(&self.serialized_data, &self.root, &self.refs, &self.compression);
+            let serialized_keys = SerializedKeys::new(&self.options);
+            let mut keys: Vec<String> = self.serialized_data.serialized.get().unwrap().key_values.keys()
+                .filter(|key| serialized_keys.contains(key))
+                .cloned()
+                .collect();
+            keys.sort_unstable();
+            keys
+        });
+        let start = key_index.partition_point(|key| key.as_str() < prefix);
+        key_index[start..].iter()
+            .take_while(|key| key.starts_with(prefix))
+            .map(|key| key.as_str())
+            .collect()
+    }
+}
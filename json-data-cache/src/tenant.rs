@@ -0,0 +1,181 @@
+//! Several sites served by the same worker: each tenant (a site id) has its own DataCache, options & limits, addressed
+//! through paths prefixed with its id, like "site_a.news.0.title". Templates of a tenant are replaced with its cache only,
+//! so that they can not read the values of another tenant, whatever their placeholders.
+
+use std::collections::HashMap;
+#[cfg(feature = "replace-engine")]
+use std::io;
+
+use serde_json::Value;
+
+use crate::{DataCache, DataCacheOptions, error::JsonDataCacheError, unicode::normalize_value};
+
+/// Settings of a tenant
+#[derive(Debug, Default)]
+pub struct TenantOptions {
+    pub cache: DataCacheOptions, // Options of the cache of the tenant (max_depth, max_patterns...)
+    pub max_bytes: Option<usize>, // Inserts making the serialized tree of the tenant bigger than this are rejected
+}
+
+#[derive(Debug)]
+struct Tenant {
+    data_cache: DataCache,
+    max_bytes: Option<usize>,
+    bytes: Option<usize>, // Size of the serialized tree, kept up to date by the inserts. None once modified otherwise
+}
+
+#[derive(Debug, Default)]
+pub struct TenantCache {
+    tenants: HashMap<String, Tenant>,
+    max_tenants: Option<usize>,
+}
+
+/// Tenant id & path below it
+fn split_tenant(path: &str) -> (&str, &str) {
+    path.split_once('.').unwrap_or((path, ""))
+}
+
+fn serialized_len(value: &Value) -> usize {
+    serde_json::to_vec(value).map(|json| json.len()).unwrap_or_default()
+}
+
+/// Keys of the only value an insert at path changes, the rest of the tree staying the same, & the path below it. The insert
+/// descends through the existing objects, then sets, merges or pushes into a single entry of the object it stops at
+fn changed_entry<'a>(root: &Value, path: &'a str) -> (Vec<&'a str>, &'a str) {
+    let mut node = root;
+    let mut keys = Vec::new();
+    let mut path = path;
+    while let Some((key, remaining_path)) = path.split_once('.')
+        && !remaining_path.is_empty()
+        && let Some(child @ Value::Object(_)) = node.get(key)
+    {
+        node = child;
+        keys.push(key);
+        path = remaining_path;
+    }
+    keys.push(path.split_once('.').map_or(path, |(key, _)| key));
+    (keys, path)
+}
+
+/// Serialized size of an entry & of its separator, in an object of parent_len entries. The separators are counted once
+/// too many, which does not matter for the differences between two sizes of an entry
+fn entry_len(key: &str, value: &Value, parent_len: usize) -> usize {
+    serialized_len(&Value::String(key.to_string())) + ":".len() + serialized_len(value) + usize::from(parent_len > 1)
+}
+
+/// Object holding the entry at keys
+fn entry_parent<'a>(root: &'a Value, keys: &[&str]) -> Option<&'a serde_json::Map<String, Value>> {
+    keys[..keys.len() - 1].iter().try_fold(root, |node, key| node.get(key))?.as_object()
+}
+
+/// Size of the entry at keys, 0 if there is none
+fn current_entry_len(root: &Value, keys: &[&str]) -> usize {
+    let key = keys[keys.len() - 1];
+    entry_parent(root, keys).and_then(|parent| Some(entry_len(key, parent.get(key)?, parent.len()))).unwrap_or_default()
+}
+
+impl TenantCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adding more tenants than this fails
+    pub fn max_tenants(mut self, max_tenants: usize) -> Self {
+        self.max_tenants = Some(max_tenants);
+        self
+    }
+
+    /// Adds a tenant with an empty cache, replacing any previous tenant of the same id. Ids must not be empty nor contain '.'
+    pub fn add_tenant(&mut self, tenant_id: &str, options: TenantOptions) -> Result<(), JsonDataCacheError> {
+        if tenant_id.is_empty() || tenant_id.contains('.') {
+            return Err(format!("Invalid tenant id {tenant_id}").into());
+        }
+        if let Some(max_tenants) = self.max_tenants && !self.tenants.contains_key(tenant_id) && self.tenants.len() >= max_tenants {
+            return Err(format!("Unable to add tenant {tenant_id}, the maximum of {max_tenants} tenants is reached").into());
+        }
+        self.tenants.insert(tenant_id.to_string(), Tenant { data_cache: DataCache::new(options.cache), max_bytes: options.max_bytes, bytes: None });
+        Ok(())
+    }
+
+    /// Returns false if there was no such tenant
+    pub fn remove_tenant(&mut self, tenant_id: &str) -> bool {
+        self.tenants.remove(tenant_id).is_some()
+    }
+
+    pub fn tenant_ids(&self) -> impl Iterator<Item = &str> {
+        self.tenants.keys().map(String::as_str)
+    }
+
+    /// The cache of a tenant
+    pub fn tenant(&self, tenant_id: &str) -> Option<&DataCache> {
+        self.tenants.get(tenant_id).map(|tenant| &tenant.data_cache)
+    }
+
+    /// The cache of a tenant, for the operations TenantCache does not wrap. Its modifications are not checked against max_bytes
+    pub fn tenant_mut(&mut self, tenant_id: &str) -> Option<&mut DataCache> {
+        let tenant = self.tenants.get_mut(tenant_id)?;
+        tenant.bytes = None;
+        Some(&mut tenant.data_cache)
+    }
+
+    /// Value at a path prefixed with the tenant id. The tenant id alone is the whole tree of the tenant
+    pub fn get(&self, path: &str) -> Option<&Value> {
+        let (tenant_id, path) = split_tenant(path);
+        let data_cache = self.tenant(tenant_id)?;
+        if path.is_empty() { Some(&data_cache.root) } else { data_cache.get(path) }
+    }
+
+    /// Same as DataCache::try_insert at a path prefixed with the tenant id. Fails without any modification if the tenant
+    /// does not exist, or if its tree would exceed its max_bytes
+    pub fn insert(&mut self, path: &str, mut value: Value) -> Result<(), JsonDataCacheError> {
+        let (tenant_id, path) = split_tenant(path);
+        let tenant = self.tenants.get_mut(tenant_id).ok_or_else(|| format!("Unknown tenant {tenant_id}"))?;
+        if path.is_empty() {
+            return Err(format!("Inserts of tenant {tenant_id} require a path below the tenant id").into());
+        }
+        let Some(max_bytes) = tenant.max_bytes else {
+            return tenant.data_cache.try_insert(path, value);
+        };
+        if let Some(form) = tenant.data_cache.options.normalization {
+            normalize_value(&mut value, form); // As try_insert does, for the size to be the inserted one
+        }
+        let bytes = *tenant.bytes.get_or_insert_with(|| serialized_len(&tenant.data_cache.root));
+        // The limit is checked on a copy of the changed entry only, so that a rejected insert does not modify the cache
+        let (keys, entry_path) = changed_entry(&tenant.data_cache.root, path);
+        let previous_len = current_entry_len(&tenant.data_cache.root, &keys);
+        if let Some(parent) = entry_parent(&tenant.data_cache.root, &keys) {
+            let key = keys[keys.len() - 1];
+            let mut entry = Value::Object(parent.get(key).map(|previous| serde_json::Map::from_iter([(key.to_string(), previous.clone())])).unwrap_or_default());
+            DataCache::insert_rec(&mut entry, entry_path, value.clone());
+            let parent_len = parent.len() + usize::from(!parent.contains_key(key));
+            let size = bytes - previous_len + entry.get(key).map(|value| entry_len(key, value, parent_len)).unwrap_or_default();
+            if size > max_bytes {
+                return Err(format!("Insert at {path} would make tenant {tenant_id} {size} bytes, over its {max_bytes} bytes limit").into());
+            }
+        }
+        tenant.data_cache.try_insert(path, value)?;
+        // Visibility rules may move any value in or out of the tree
+        tenant.bytes = tenant.data_cache.visibility.is_empty().then(|| bytes - previous_len + current_entry_len(&tenant.data_cache.root, &keys));
+        Ok(())
+    }
+
+    /// Size of the serialized tree of the tenant
+    pub fn tenant_bytes(&self, tenant_id: &str) -> Option<usize> {
+        let tenant = self.tenants.get(tenant_id)?;
+        Some(tenant.bytes.unwrap_or_else(|| serialized_len(&tenant.data_cache.root)))
+    }
+
+    /// Replaces the placeholders of a template of the tenant with the values of its cache only (see replace_with_data_cache)
+    #[cfg(feature = "replace-engine")]
+    pub fn replace<R, W>(&mut self, tenant_id: &str, reader: R, writer: W) -> Result<(), JsonDataCacheError>
+    where
+        R: io::Read,
+        W: io::Write,
+    {
+        let tenant = self.tenants.get_mut(tenant_id).ok_or_else(|| format!("Unknown tenant {tenant_id}"))?;
+        if !tenant.data_cache.computed.is_empty() {
+            tenant.bytes = None; // Outdated computed values are written into the tree
+        }
+        tenant.data_cache.replace_with_data_cache(reader, writer)
+    }
+}
@@ -0,0 +1,148 @@
+//! Render regression tests for downstream apps: templates are rendered against fixture caches and compared to golden files,
+//! after redaction of volatile parts (nonces, dates...). Setting the UPDATE_GOLDEN environment variable writes
+//! the golden files instead of comparing them.
+
+use std::{env, fs, path::Path};
+
+#[cfg(feature = "regex")]
+use regex::bytes::Regex;
+use serde_json::Value;
+
+use crate::{DataCache, DataCacheOptions, error::JsonDataCacheError};
+
+/// Environment variable enabling the update of golden files
+pub const UPDATE_GOLDEN_ENV: &str = "UPDATE_GOLDEN";
+
+/// Loads a cache from a JSON file holding an object
+pub fn fixture_cache(path: impl AsRef<Path>) -> Result<DataCache, JsonDataCacheError> {
+    let path = path.as_ref();
+    let data: Value = serde_json::from_slice(&fs::read(path)?).map_err(|e| format!("Invalid JSON in {} : {e}", path.display()))?;
+    if !data.is_object() {
+        return Err(format!("{} must contain a JSON object", path.display()).into());
+    }
+    let mut data_cache = DataCache::new(DataCacheOptions::default());
+    data_cache.try_merge(data)?;
+    Ok(data_cache)
+}
+
+/// Replaces volatile parts of renders before comparison
+#[derive(Debug, Clone)]
+pub enum Redaction {
+    /// Replaces what is between each occurrence of the prefix and the next suffix (both kept), like nonce=" and "
+    Between { prefix: String, suffix: String, replacement: String },
+    /// Replaces each match
+    #[cfg(feature = "regex")]
+    Regex { regex: Regex, replacement: String },
+}
+
+impl Redaction {
+    pub fn between(prefix: &str, suffix: &str, replacement: &str) -> Self {
+        Redaction::Between { prefix: prefix.to_string(), suffix: suffix.to_string(), replacement: replacement.to_string() }
+    }
+
+    #[cfg(feature = "regex")]
+    pub fn regex(pattern: &str, replacement: &str) -> Result<Self, JsonDataCacheError> {
+        let regex = Regex::new(pattern).map_err(|_| format!("Invalid regex {pattern}"))?;
+        Ok(Redaction::Regex { regex, replacement: replacement.to_string() })
+    }
+
+    pub fn apply(&self, output: &[u8]) -> Vec<u8> {
+        match self {
+            Redaction::Between { prefix, suffix, replacement } => {
+                let mut redacted = Vec::with_capacity(output.len());
+                let mut rest = output;
+                while let Some(start) = find(rest, prefix.as_bytes()) {
+                    let value_start = start + prefix.len();
+                    let Some(value_len) = find(&rest[value_start..], suffix.as_bytes()) else {
+                        break;
+                    };
+                    redacted.extend_from_slice(&rest[..value_start]);
+                    redacted.extend_from_slice(replacement.as_bytes());
+                    rest = &rest[value_start + value_len..];
+                    redacted.extend_from_slice(&rest[..suffix.len()]);
+                    rest = &rest[suffix.len()..];
+                }
+                redacted.extend_from_slice(rest);
+                redacted
+            },
+            #[cfg(feature = "regex")]
+            Redaction::Regex { regex, replacement } => regex.replace_all(output, replacement.as_bytes()).into_owned(),
+        }
+    }
+}
+
+fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    if needle.is_empty() {
+        return None;
+    }
+    haystack.windows(needle.len()).position(|w| w == needle)
+}
+
+/// Compares renders with golden files
+#[derive(Debug, Clone, Default)]
+pub struct GoldenTest {
+    redactions: Vec<Redaction>
+}
+
+impl GoldenTest {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn redact(mut self, redaction: Redaction) -> Self {
+        self.redactions.push(redaction);
+        self
+    }
+
+    /// Renders the template (see replace_with_data_cache) & applies the redactions
+    pub fn render(&self, data_cache: &mut DataCache, template: &[u8]) -> Result<Vec<u8>, JsonDataCacheError> {
+        let mut output = Vec::with_capacity(template.len());
+        data_cache.replace_with_data_cache(template, &mut output)?;
+        Ok(self.redactions.iter().fold(output, |output, redaction| redaction.apply(&output)))
+    }
+
+    /// Renders & compares with the golden file, describing the first differing line on mismatch
+    /// With UPDATE_GOLDEN set, writes the golden file (and its directory) instead
+    pub fn check(&self, data_cache: &mut DataCache, template: &[u8], golden_path: impl AsRef<Path>) -> Result<(), JsonDataCacheError> {
+        let golden_path = golden_path.as_ref();
+        let output = self.render(data_cache, template)?;
+        if env::var_os(UPDATE_GOLDEN_ENV).is_some() {
+            if let Some(parent) = golden_path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::write(golden_path, &output)?;
+            return Ok(());
+        }
+        let golden = fs::read(golden_path)
+            .map_err(|e| format!("Unable to read golden file {} ({e}), run with {UPDATE_GOLDEN_ENV}=1 to create it", golden_path.display()))?;
+        if golden == output {
+            return Ok(());
+        }
+        let golden_text = String::from_utf8_lossy(&golden);
+        let output_text = String::from_utf8_lossy(&output);
+        let mut golden_lines = golden_text.lines();
+        let mut output_lines = output_text.lines();
+        let mut line_number = 1;
+        loop {
+            match (golden_lines.next(), output_lines.next()) {
+                (Some(expected), Some(actual)) if expected == actual => line_number += 1,
+                (None, None) => return Err(format!("Render differs from {} by its line endings", golden_path.display()).into()),
+                (expected, actual) => {
+                    return Err(format!(
+                        "Render differs from {} on line {line_number}\n  expected: {}\n  actual:   {}",
+                        golden_path.display(),
+                        expected.unwrap_or("<end of file>"),
+                        actual.unwrap_or("<end of file>")
+                    ).into());
+                },
+            }
+        }
+    }
+
+    /// Same as check, panicking on mismatch
+    pub fn assert(&self, data_cache: &mut DataCache, template: &[u8], golden_path: impl AsRef<Path>) {
+        if let Err(e) = self.check(data_cache, template, golden_path) {
+            panic!("{}", e.msg);
+        }
+    }
+}
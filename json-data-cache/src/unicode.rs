@@ -0,0 +1,249 @@
+//! Unicode text handling of user generated content: NFC & NFKC normalization (applied to the strings of inserted values by
+//! DataCacheOptions::normalization), and extended grapheme clusters, the characters as perceived by readers, so that
+//! truncating never separates an emoji from its modifiers or a letter from its combining marks.
+//! Tables are generated from the Unicode Character Database 14.0 (see unicode_tables.rs). Grapheme clusters follow the
+//! rules of UAX #29, with Extended_Pictographic approximated by the emoji blocks.
+
+use std::{borrow::Cow, cmp::Ordering};
+
+use serde_json::Value;
+
+use crate::unicode_tables::{COMBINING_CLASSES, COMPOSITIONS, DECOMPOSITIONS, GRAPHEME_CLASSES};
+
+/// Normalization form of DataCacheOptions::normalization
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Normalization {
+    /// Canonical composition: the same text always has the same code points, like "é" whether typed as one or two characters
+    Nfc,
+    /// Compatibility composition: NFC also folding compatibility characters, like full-width letters, half-width katakana
+    /// or ligatures
+    Nfkc,
+}
+
+// Hangul syllables, composed & decomposed algorithmically
+const S_BASE: u32 = 0xac00;
+const L_BASE: u32 = 0x1100;
+const V_BASE: u32 = 0x1161;
+const T_BASE: u32 = 0x11a7;
+const L_COUNT: u32 = 19;
+const V_COUNT: u32 = 21;
+const T_COUNT: u32 = 28;
+const N_COUNT: u32 = V_COUNT * T_COUNT;
+const S_COUNT: u32 = L_COUNT * N_COUNT;
+
+/// Value of the range containing cp
+fn find_range<T: Copy>(ranges: &[(u32, u32, T)], cp: u32) -> Option<T> {
+    ranges.binary_search_by(|(start, end, _)| {
+        if *end < cp { Ordering::Less } else if *start > cp { Ordering::Greater } else { Ordering::Equal }
+    }).ok().map(|idx| ranges[idx].2)
+}
+
+fn combining_class(c: char) -> u8 {
+    find_range(COMBINING_CLASSES, c as u32).unwrap_or(0)
+}
+
+fn decompose(c: char, compat: bool, output: &mut Vec<char>) {
+    let cp = c as u32;
+    if (S_BASE..S_BASE + S_COUNT).contains(&cp) {
+        let index = cp - S_BASE;
+        output.push(char::from_u32(L_BASE + index / N_COUNT).unwrap());
+        output.push(char::from_u32(V_BASE + index % N_COUNT / T_COUNT).unwrap());
+        if !index.is_multiple_of(T_COUNT) {
+            output.push(char::from_u32(T_BASE + index % T_COUNT).unwrap());
+        }
+        return;
+    }
+    match DECOMPOSITIONS.binary_search_by_key(&cp, |(c, _, _)| *c) {
+        Ok(idx) if compat || !DECOMPOSITIONS[idx].1 => {
+            for part in DECOMPOSITIONS[idx].2 {
+                decompose(char::from_u32(*part).unwrap(), compat, output);
+            }
+        },
+        _ => output.push(c),
+    }
+}
+
+fn compose(first: char, second: char) -> Option<char> {
+    let (first_cp, second_cp) = (first as u32, second as u32);
+    if (L_BASE..L_BASE + L_COUNT).contains(&first_cp) && (V_BASE..V_BASE + V_COUNT).contains(&second_cp) {
+        return char::from_u32(S_BASE + ((first_cp - L_BASE) * V_COUNT + second_cp - V_BASE) * T_COUNT);
+    }
+    let is_lv = (S_BASE..S_BASE + S_COUNT).contains(&first_cp) && (first_cp - S_BASE).is_multiple_of(T_COUNT);
+    if is_lv && (T_BASE + 1..T_BASE + T_COUNT).contains(&second_cp) {
+        return char::from_u32(first_cp + second_cp - T_BASE);
+    }
+    COMPOSITIONS.binary_search_by_key(&(first_cp, second_cp), |(first, second, _)| (*first, *second))
+        .ok()
+        .and_then(|idx| char::from_u32(COMPOSITIONS[idx].2))
+}
+
+/// The text in the normalization form, borrowed when ASCII (which every form keeps as it is)
+pub fn normalize(text: &str, form: Normalization) -> Cow<'_, str> {
+    if text.is_ascii() {
+        return Cow::Borrowed(text);
+    }
+    let mut decomposed = Vec::with_capacity(text.len());
+    for c in text.chars() {
+        decompose(c, form == Normalization::Nfkc, &mut decomposed);
+    }
+    // Canonical ordering: marks sorted by combining class between starters (stable, keeping the order of equal classes)
+    let mut run_start = 0;
+    for idx in 0..=decomposed.len() {
+        if idx == decomposed.len() || combining_class(decomposed[idx]) == 0 {
+            if idx > run_start + 1 {
+                decomposed[run_start..idx].sort_by_key(|c| combining_class(*c));
+            }
+            run_start = idx + 1;
+        }
+    }
+    // Canonical composition of each character with the last starter, unless blocked by a mark of the same or a higher class
+    let mut composed: Vec<char> = Vec::with_capacity(decomposed.len());
+    let mut starter: Option<usize> = None;
+    let mut last_class = 0;
+    for c in decomposed {
+        let class = combining_class(c);
+        if let Some(starter) = starter
+            && (last_class < class || last_class == 0 && composed.len() == starter + 1)
+            && let Some(pair) = compose(composed[starter], c) {
+            composed[starter] = pair;
+            continue;
+        }
+        if class == 0 {
+            starter = Some(composed.len());
+        }
+        last_class = class;
+        composed.push(c);
+    }
+    Cow::Owned(composed.into_iter().collect())
+}
+
+/// Normalizes the strings of value, object keys excepted
+pub(crate) fn normalize_value(value: &mut Value, form: Normalization) {
+    match value {
+        Value::String(s) => {
+            if let Cow::Owned(normalized) = normalize(s, form) {
+                *s = normalized;
+            }
+        },
+        Value::Array(items) => items.iter_mut().for_each(|item| normalize_value(item, form)),
+        Value::Object(o) => o.values_mut().for_each(|item| normalize_value(item, form)),
+        _ => {},
+    }
+}
+
+/// Grapheme cluster break property of a character (UAX #29)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum GraphemeClass {
+    Cr,
+    Lf,
+    Control,
+    Extend,
+    Zwj,
+    RegionalIndicator,
+    Prepend,
+    SpacingMark,
+    L,
+    V,
+    T,
+    Lv,
+    Lvt,
+    Pictographic,
+    Other,
+}
+
+fn is_pictographic(cp: u32) -> bool {
+    matches!(cp,
+        0xa9 | 0xae | 0x203c | 0x2049 | 0x2122 | 0x2139 | 0x2194..=0x2199 | 0x21a9..=0x21aa | 0x231a..=0x231b | 0x2328
+        | 0x2388 | 0x23cf | 0x23e9..=0x23f3 | 0x23f8..=0x23fa | 0x24c2 | 0x25aa..=0x25ab | 0x25b6 | 0x25c0 | 0x25fb..=0x25fe
+        | 0x2600..=0x27bf | 0x2934..=0x2935 | 0x2b05..=0x2b07 | 0x2b1b..=0x2b1c | 0x2b50 | 0x2b55 | 0x3030 | 0x303d | 0x3297
+        | 0x3299 | 0x1f000..=0x1f0ff | 0x1f10d..=0x1f10f | 0x1f12f | 0x1f16c..=0x1f171 | 0x1f17e..=0x1f17f | 0x1f18e
+        | 0x1f191..=0x1f19a | 0x1f1ad..=0x1f1e5 | 0x1f201..=0x1f20f | 0x1f21a | 0x1f22f | 0x1f232..=0x1f23a | 0x1f23c..=0x1f23f
+        | 0x1f249..=0x1f3fa | 0x1f400..=0x1f53d | 0x1f546..=0x1f64f | 0x1f680..=0x1f6ff | 0x1f774..=0x1f77f | 0x1f7d5..=0x1f7ff
+        | 0x1f80c..=0x1f80f | 0x1f848..=0x1f84f | 0x1f85a..=0x1f85f | 0x1f888..=0x1f88f | 0x1f8ae..=0x1f8ff
+        | 0x1f90c..=0x1f93a | 0x1f93c..=0x1f945 | 0x1f947..=0x1faff | 0x1fc00..=0x1fffd
+    )
+}
+
+fn grapheme_class(c: char) -> GraphemeClass {
+    let cp = c as u32;
+    match cp {
+        0x0d => return GraphemeClass::Cr,
+        0x0a => return GraphemeClass::Lf,
+        0x1100..=0x115f | 0xa960..=0xa97c => return GraphemeClass::L,
+        0x1160..=0x11a7 | 0xd7b0..=0xd7c6 => return GraphemeClass::V,
+        0x11a8..=0x11ff | 0xd7cb..=0xd7fb => return GraphemeClass::T,
+        0x1f1e6..=0x1f1ff => return GraphemeClass::RegionalIndicator,
+        _ if (S_BASE..S_BASE + S_COUNT).contains(&cp) => {
+            return if (cp - S_BASE).is_multiple_of(T_COUNT) { GraphemeClass::Lv } else { GraphemeClass::Lvt };
+        },
+        _ => {},
+    }
+    match find_range(GRAPHEME_CLASSES, cp) {
+        Some(class) => class,
+        None if is_pictographic(cp) => GraphemeClass::Pictographic,
+        None => GraphemeClass::Other,
+    }
+}
+
+/// Iterator over the extended grapheme clusters of a text, see graphemes
+#[derive(Debug, Clone)]
+pub struct Graphemes<'a> {
+    rest: &'a str,
+}
+
+impl<'a> Iterator for Graphemes<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        use GraphemeClass::*;
+        let mut chars = self.rest.char_indices();
+        let (_, first) = chars.next()?;
+        let mut previous = grapheme_class(first);
+        let mut pictographic_sequence = previous == Pictographic; // Pictographic Extend* (ZWJ), for emoji ZWJ sequences
+        let mut regional_indicators = usize::from(previous == RegionalIndicator);
+        let mut end = self.rest.len();
+        for (idx, c) in chars {
+            let class = grapheme_class(c);
+            let joined = match (previous, class) {
+                (Cr, Lf) => true,
+                (Cr | Lf | Control, _) | (_, Cr | Lf | Control) => false,
+                (L, L | V | Lv | Lvt) | (Lv | V, V | T) | (Lvt | T, T) => true,
+                (_, Extend | Zwj | SpacingMark) | (Prepend, _) => true,
+                (Zwj, Pictographic) => pictographic_sequence,
+                (RegionalIndicator, RegionalIndicator) => regional_indicators % 2 == 1,
+                _ => false,
+            };
+            if !joined {
+                end = idx;
+                break;
+            }
+            pictographic_sequence = match class {
+                Pictographic => true,
+                Extend | Zwj => pictographic_sequence && previous != Zwj,
+                _ => false,
+            };
+            regional_indicators += usize::from(class == RegionalIndicator);
+            previous = class;
+        }
+        let (grapheme, rest) = self.rest.split_at(end);
+        self.rest = rest;
+        Some(grapheme)
+    }
+}
+
+/// Extended grapheme clusters of text
+/// Example: "🇯🇵👍🏽é" (with a combining accent) has 3 graphemes of 2 characters each
+pub fn graphemes(text: &str) -> Graphemes<'_> {
+    Graphemes { rest: text }
+}
+
+/// Text cut to at most max_graphemes grapheme clusters, ellipsis included, when longer
+/// Example: truncate("👨‍👩‍👧 family", 3, "…") is "👨‍👩‍👧 …"
+pub fn truncate(text: &str, max_graphemes: usize, ellipsis: &str) -> String {
+    if graphemes(text).count() <= max_graphemes {
+        return text.to_string();
+    }
+    let kept = max_graphemes.saturating_sub(graphemes(ellipsis).count());
+    let end: usize = graphemes(text).take(kept).map(str::len).sum();
+    format!("{}{ellipsis}", &text[..end])
+}
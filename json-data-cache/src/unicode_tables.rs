@@ -0,0 +1,1857 @@
+// Generated by scripts/unicode_tables.py from the Unicode Character Database 14.0.0 (Python unicodedata), do not edit
+
+use crate::unicode::GraphemeClass;
+
+/// Canonical combining classes other than 0, by ranges of code points
+pub(crate) const COMBINING_CLASSES: &[(u32, u32, u8)] = &[
+    (0x300, 0x314, 230), (0x315, 0x315, 232), (0x316, 0x319, 220), (0x31a, 0x31a, 232), (0x31b, 0x31b, 216), (0x31c, 0x320, 220),
+    (0x321, 0x322, 202), (0x323, 0x326, 220), (0x327, 0x328, 202), (0x329, 0x333, 220), (0x334, 0x338, 1), (0x339, 0x33c, 220),
+    (0x33d, 0x344, 230), (0x345, 0x345, 240), (0x346, 0x346, 230), (0x347, 0x349, 220), (0x34a, 0x34c, 230), (0x34d, 0x34e, 220),
+    (0x350, 0x352, 230), (0x353, 0x356, 220), (0x357, 0x357, 230), (0x358, 0x358, 232), (0x359, 0x35a, 220), (0x35b, 0x35b, 230),
+    (0x35c, 0x35c, 233), (0x35d, 0x35e, 234), (0x35f, 0x35f, 233), (0x360, 0x361, 234), (0x362, 0x362, 233), (0x363, 0x36f, 230),
+    (0x483, 0x487, 230), (0x591, 0x591, 220), (0x592, 0x595, 230), (0x596, 0x596, 220), (0x597, 0x599, 230), (0x59a, 0x59a, 222),
+    (0x59b, 0x59b, 220), (0x59c, 0x5a1, 230), (0x5a2, 0x5a7, 220), (0x5a8, 0x5a9, 230), (0x5aa, 0x5aa, 220), (0x5ab, 0x5ac, 230),
+    (0x5ad, 0x5ad, 222), (0x5ae, 0x5ae, 228), (0x5af, 0x5af, 230), (0x5b0, 0x5b0, 10), (0x5b1, 0x5b1, 11), (0x5b2, 0x5b2, 12),
+    (0x5b3, 0x5b3, 13), (0x5b4, 0x5b4, 14), (0x5b5, 0x5b5, 15), (0x5b6, 0x5b6, 16), (0x5b7, 0x5b7, 17), (0x5b8, 0x5b8, 18),
+    (0x5b9, 0x5ba, 19), (0x5bb, 0x5bb, 20), (0x5bc, 0x5bc, 21), (0x5bd, 0x5bd, 22), (0x5bf, 0x5bf, 23), (0x5c1, 0x5c1, 24),
+    (0x5c2, 0x5c2, 25), (0x5c4, 0x5c4, 230), (0x5c5, 0x5c5, 220), (0x5c7, 0x5c7, 18), (0x610, 0x617, 230), (0x618, 0x618, 30),
+    (0x619, 0x619, 31), (0x61a, 0x61a, 32), (0x64b, 0x64b, 27), (0x64c, 0x64c, 28), (0x64d, 0x64d, 29), (0x64e, 0x64e, 30),
+    (0x64f, 0x64f, 31), (0x650, 0x650, 32), (0x651, 0x651, 33), (0x652, 0x652, 34), (0x653, 0x654, 230), (0x655, 0x656, 220),
+    (0x657, 0x65b, 230), (0x65c, 0x65c, 220), (0x65d, 0x65e, 230), (0x65f, 0x65f, 220), (0x670, 0x670, 35), (0x6d6, 0x6dc, 230),
+    (0x6df, 0x6e2, 230), (0x6e3, 0x6e3, 220), (0x6e4, 0x6e4, 230), (0x6e7, 0x6e8, 230), (0x6ea, 0x6ea, 220), (0x6eb, 0x6ec, 230),
+    (0x6ed, 0x6ed, 220), (0x711, 0x711, 36), (0x730, 0x730, 230), (0x731, 0x731, 220), (0x732, 0x733, 230), (0x734, 0x734, 220),
+    (0x735, 0x736, 230), (0x737, 0x739, 220), (0x73a, 0x73a, 230), (0x73b, 0x73c, 220), (0x73d, 0x73d, 230), (0x73e, 0x73e, 220),
+    (0x73f, 0x741, 230), (0x742, 0x742, 220), (0x743, 0x743, 230), (0x744, 0x744, 220), (0x745, 0x745, 230), (0x746, 0x746, 220),
+    (0x747, 0x747, 230), (0x748, 0x748, 220), (0x749, 0x74a, 230), (0x7eb, 0x7f1, 230), (0x7f2, 0x7f2, 220), (0x7f3, 0x7f3, 230),
+    (0x7fd, 0x7fd, 220), (0x816, 0x819, 230), (0x81b, 0x823, 230), (0x825, 0x827, 230), (0x829, 0x82d, 230), (0x859, 0x85b, 220),
+    (0x898, 0x898, 230), (0x899, 0x89b, 220), (0x89c, 0x89f, 230), (0x8ca, 0x8ce, 230), (0x8cf, 0x8d3, 220), (0x8d4, 0x8e1, 230),
+    (0x8e3, 0x8e3, 220), (0x8e4, 0x8e5, 230), (0x8e6, 0x8e6, 220), (0x8e7, 0x8e8, 230), (0x8e9, 0x8e9, 220), (0x8ea, 0x8ec, 230),
+    (0x8ed, 0x8ef, 220), (0x8f0, 0x8f0, 27), (0x8f1, 0x8f1, 28), (0x8f2, 0x8f2, 29), (0x8f3, 0x8f5, 230), (0x8f6, 0x8f6, 220),
+    (0x8f7, 0x8f8, 230), (0x8f9, 0x8fa, 220), (0x8fb, 0x8ff, 230), (0x93c, 0x93c, 7), (0x94d, 0x94d, 9), (0x951, 0x951, 230),
+    (0x952, 0x952, 220), (0x953, 0x954, 230), (0x9bc, 0x9bc, 7), (0x9cd, 0x9cd, 9), (0x9fe, 0x9fe, 230), (0xa3c, 0xa3c, 7),
+    (0xa4d, 0xa4d, 9), (0xabc, 0xabc, 7), (0xacd, 0xacd, 9), (0xb3c, 0xb3c, 7), (0xb4d, 0xb4d, 9), (0xbcd, 0xbcd, 9),
+    (0xc3c, 0xc3c, 7), (0xc4d, 0xc4d, 9), (0xc55, 0xc55, 84), (0xc56, 0xc56, 91), (0xcbc, 0xcbc, 7), (0xccd, 0xccd, 9),
+    (0xd3b, 0xd3c, 9), (0xd4d, 0xd4d, 9), (0xdca, 0xdca, 9), (0xe38, 0xe39, 103), (0xe3a, 0xe3a, 9), (0xe48, 0xe4b, 107),
+    (0xeb8, 0xeb9, 118), (0xeba, 0xeba, 9), (0xec8, 0xecb, 122), (0xf18, 0xf19, 220), (0xf35, 0xf35, 220), (0xf37, 0xf37, 220),
+    (0xf39, 0xf39, 216), (0xf71, 0xf71, 129), (0xf72, 0xf72, 130), (0xf74, 0xf74, 132), (0xf7a, 0xf7d, 130), (0xf80, 0xf80, 130),
+    (0xf82, 0xf83, 230), (0xf84, 0xf84, 9), (0xf86, 0xf87, 230), (0xfc6, 0xfc6, 220), (0x1037, 0x1037, 7), (0x1039, 0x103a, 9),
+    (0x108d, 0x108d, 220), (0x135d, 0x135f, 230), (0x1714, 0x1715, 9), (0x1734, 0x1734, 9), (0x17d2, 0x17d2, 9), (0x17dd, 0x17dd, 230),
+    (0x18a9, 0x18a9, 228), (0x1939, 0x1939, 222), (0x193a, 0x193a, 230), (0x193b, 0x193b, 220), (0x1a17, 0x1a17, 230), (0x1a18, 0x1a18, 220),
+    (0x1a60, 0x1a60, 9), (0x1a75, 0x1a7c, 230), (0x1a7f, 0x1a7f, 220), (0x1ab0, 0x1ab4, 230), (0x1ab5, 0x1aba, 220), (0x1abb, 0x1abc, 230),
+    (0x1abd, 0x1abd, 220), (0x1abf, 0x1ac0, 220), (0x1ac1, 0x1ac2, 230), (0x1ac3, 0x1ac4, 220), (0x1ac5, 0x1ac9, 230), (0x1aca, 0x1aca, 220),
+    (0x1acb, 0x1ace, 230), (0x1b34, 0x1b34, 7), (0x1b44, 0x1b44, 9), (0x1b6b, 0x1b6b, 230), (0x1b6c, 0x1b6c, 220), (0x1b6d, 0x1b73, 230),
+    (0x1baa, 0x1bab, 9), (0x1be6, 0x1be6, 7), (0x1bf2, 0x1bf3, 9), (0x1c37, 0x1c37, 7), (0x1cd0, 0x1cd2, 230), (0x1cd4, 0x1cd4, 1),
+    (0x1cd5, 0x1cd9, 220), (0x1cda, 0x1cdb, 230), (0x1cdc, 0x1cdf, 220), (0x1ce0, 0x1ce0, 230), (0x1ce2, 0x1ce8, 1), (0x1ced, 0x1ced, 220),
+    (0x1cf4, 0x1cf4, 230), (0x1cf8, 0x1cf9, 230), (0x1dc0, 0x1dc1, 230), (0x1dc2, 0x1dc2, 220), (0x1dc3, 0x1dc9, 230), (0x1dca, 0x1dca, 220),
+    (0x1dcb, 0x1dcc, 230), (0x1dcd, 0x1dcd, 234), (0x1dce, 0x1dce, 214), (0x1dcf, 0x1dcf, 220), (0x1dd0, 0x1dd0, 202), (0x1dd1, 0x1df5, 230),
+    (0x1df6, 0x1df6, 232), (0x1df7, 0x1df8, 228), (0x1df9, 0x1df9, 220), (0x1dfa, 0x1dfa, 218), (0x1dfb, 0x1dfb, 230), (0x1dfc, 0x1dfc, 233),
+    (0x1dfd, 0x1dfd, 220), (0x1dfe, 0x1dfe, 230), (0x1dff, 0x1dff, 220), (0x20d0, 0x20d1, 230), (0x20d2, 0x20d3, 1), (0x20d4, 0x20d7, 230),
+    (0x20d8, 0x20da, 1), (0x20db, 0x20dc, 230), (0x20e1, 0x20e1, 230), (0x20e5, 0x20e6, 1), (0x20e7, 0x20e7, 230), (0x20e8, 0x20e8, 220),
+    (0x20e9, 0x20e9, 230), (0x20ea, 0x20eb, 1), (0x20ec, 0x20ef, 220), (0x20f0, 0x20f0, 230), (0x2cef, 0x2cf1, 230), (0x2d7f, 0x2d7f, 9),
+    (0x2de0, 0x2dff, 230), (0x302a, 0x302a, 218), (0x302b, 0x302b, 228), (0x302c, 0x302c, 232), (0x302d, 0x302d, 222), (0x302e, 0x302f, 224),
+    (0x3099, 0x309a, 8), (0xa66f, 0xa66f, 230), (0xa674, 0xa67d, 230), (0xa69e, 0xa69f, 230), (0xa6f0, 0xa6f1, 230), (0xa806, 0xa806, 9),
+    (0xa82c, 0xa82c, 9), (0xa8c4, 0xa8c4, 9), (0xa8e0, 0xa8f1, 230), (0xa92b, 0xa92d, 220), (0xa953, 0xa953, 9), (0xa9b3, 0xa9b3, 7),
+    (0xa9c0, 0xa9c0, 9), (0xaab0, 0xaab0, 230), (0xaab2, 0xaab3, 230), (0xaab4, 0xaab4, 220), (0xaab7, 0xaab8, 230), (0xaabe, 0xaabf, 230),
+    (0xaac1, 0xaac1, 230), (0xaaf6, 0xaaf6, 9), (0xabed, 0xabed, 9), (0xfb1e, 0xfb1e, 26), (0xfe20, 0xfe26, 230), (0xfe27, 0xfe2d, 220),
+    (0xfe2e, 0xfe2f, 230), (0x101fd, 0x101fd, 220), (0x102e0, 0x102e0, 220), (0x10376, 0x1037a, 230), (0x10a0d, 0x10a0d, 220), (0x10a0f, 0x10a0f, 230),
+    (0x10a38, 0x10a38, 230), (0x10a39, 0x10a39, 1), (0x10a3a, 0x10a3a, 220), (0x10a3f, 0x10a3f, 9), (0x10ae5, 0x10ae5, 230), (0x10ae6, 0x10ae6, 220),
+    (0x10d24, 0x10d27, 230), (0x10eab, 0x10eac, 230), (0x10f46, 0x10f47, 220), (0x10f48, 0x10f4a, 230), (0x10f4b, 0x10f4b, 220), (0x10f4c, 0x10f4c, 230),
+    (0x10f4d, 0x10f50, 220), (0x10f82, 0x10f82, 230), (0x10f83, 0x10f83, 220), (0x10f84, 0x10f84, 230), (0x10f85, 0x10f85, 220), (0x11046, 0x11046, 9),
+    (0x11070, 0x11070, 9), (0x1107f, 0x1107f, 9), (0x110b9, 0x110b9, 9), (0x110ba, 0x110ba, 7), (0x11100, 0x11102, 230), (0x11133, 0x11134, 9),
+    (0x11173, 0x11173, 7), (0x111c0, 0x111c0, 9), (0x111ca, 0x111ca, 7), (0x11235, 0x11235, 9), (0x11236, 0x11236, 7), (0x112e9, 0x112e9, 7),
+    (0x112ea, 0x112ea, 9), (0x1133b, 0x1133c, 7), (0x1134d, 0x1134d, 9), (0x11366, 0x1136c, 230), (0x11370, 0x11374, 230), (0x11442, 0x11442, 9),
+    (0x11446, 0x11446, 7), (0x1145e, 0x1145e, 230), (0x114c2, 0x114c2, 9), (0x114c3, 0x114c3, 7), (0x115bf, 0x115bf, 9), (0x115c0, 0x115c0, 7),
+    (0x1163f, 0x1163f, 9), (0x116b6, 0x116b6, 9), (0x116b7, 0x116b7, 7), (0x1172b, 0x1172b, 9), (0x11839, 0x11839, 9), (0x1183a, 0x1183a, 7),
+    (0x1193d, 0x1193e, 9), (0x11943, 0x11943, 7), (0x119e0, 0x119e0, 9), (0x11a34, 0x11a34, 9), (0x11a47, 0x11a47, 9), (0x11a99, 0x11a99, 9),
+    (0x11c3f, 0x11c3f, 9), (0x11d42, 0x11d42, 7), (0x11d44, 0x11d45, 9), (0x11d97, 0x11d97, 9), (0x16af0, 0x16af4, 1), (0x16b30, 0x16b36, 230),
+    (0x16ff0, 0x16ff1, 6), (0x1bc9e, 0x1bc9e, 1), (0x1d165, 0x1d166, 216), (0x1d167, 0x1d169, 1), (0x1d16d, 0x1d16d, 226), (0x1d16e, 0x1d172, 216),
+    (0x1d17b, 0x1d182, 220), (0x1d185, 0x1d189, 230), (0x1d18a, 0x1d18b, 220), (0x1d1aa, 0x1d1ad, 230), (0x1d242, 0x1d244, 230), (0x1e000, 0x1e006, 230),
+    (0x1e008, 0x1e018, 230), (0x1e01b, 0x1e021, 230), (0x1e023, 0x1e024, 230), (0x1e026, 0x1e02a, 230), (0x1e130, 0x1e136, 230), (0x1e2ae, 0x1e2ae, 230),
+    (0x1e2ec, 0x1e2ef, 230), (0x1e8d0, 0x1e8d6, 220), (0x1e944, 0x1e949, 230), (0x1e94a, 0x1e94a, 7),
+];
+
+/// Decomposition of each character (one level, Hangul syllables excepted), compatibility ones flagged
+pub(crate) const DECOMPOSITIONS: &[(u32, bool, &[u32])] = &[
+    (0xa0, true, &[0x20]), (0xa8, true, &[0x20, 0x308]), (0xaa, true, &[0x61]), (0xaf, true, &[0x20, 0x304]),
+    (0xb2, true, &[0x32]), (0xb3, true, &[0x33]), (0xb4, true, &[0x20, 0x301]), (0xb5, true, &[0x3bc]),
+    (0xb8, true, &[0x20, 0x327]), (0xb9, true, &[0x31]), (0xba, true, &[0x6f]), (0xbc, true, &[0x31, 0x2044, 0x34]),
+    (0xbd, true, &[0x31, 0x2044, 0x32]), (0xbe, true, &[0x33, 0x2044, 0x34]), (0xc0, false, &[0x41, 0x300]), (0xc1, false, &[0x41, 0x301]),
+    (0xc2, false, &[0x41, 0x302]), (0xc3, false, &[0x41, 0x303]), (0xc4, false, &[0x41, 0x308]), (0xc5, false, &[0x41, 0x30a]),
+    (0xc7, false, &[0x43, 0x327]), (0xc8, false, &[0x45, 0x300]), (0xc9, false, &[0x45, 0x301]), (0xca, false, &[0x45, 0x302]),
+    (0xcb, false, &[0x45, 0x308]), (0xcc, false, &[0x49, 0x300]), (0xcd, false, &[0x49, 0x301]), (0xce, false, &[0x49, 0x302]),
+    (0xcf, false, &[0x49, 0x308]), (0xd1, false, &[0x4e, 0x303]), (0xd2, false, &[0x4f, 0x300]), (0xd3, false, &[0x4f, 0x301]),
+    (0xd4, false, &[0x4f, 0x302]), (0xd5, false, &[0x4f, 0x303]), (0xd6, false, &[0x4f, 0x308]), (0xd9, false, &[0x55, 0x300]),
+    (0xda, false, &[0x55, 0x301]), (0xdb, false, &[0x55, 0x302]), (0xdc, false, &[0x55, 0x308]), (0xdd, false, &[0x59, 0x301]),
+    (0xe0, false, &[0x61, 0x300]), (0xe1, false, &[0x61, 0x301]), (0xe2, false, &[0x61, 0x302]), (0xe3, false, &[0x61, 0x303]),
+    (0xe4, false, &[0x61, 0x308]), (0xe5, false, &[0x61, 0x30a]), (0xe7, false, &[0x63, 0x327]), (0xe8, false, &[0x65, 0x300]),
+    (0xe9, false, &[0x65, 0x301]), (0xea, false, &[0x65, 0x302]), (0xeb, false, &[0x65, 0x308]), (0xec, false, &[0x69, 0x300]),
+    (0xed, false, &[0x69, 0x301]), (0xee, false, &[0x69, 0x302]), (0xef, false, &[0x69, 0x308]), (0xf1, false, &[0x6e, 0x303]),
+    (0xf2, false, &[0x6f, 0x300]), (0xf3, false, &[0x6f, 0x301]), (0xf4, false, &[0x6f, 0x302]), (0xf5, false, &[0x6f, 0x303]),
+    (0xf6, false, &[0x6f, 0x308]), (0xf9, false, &[0x75, 0x300]), (0xfa, false, &[0x75, 0x301]), (0xfb, false, &[0x75, 0x302]),
+    (0xfc, false, &[0x75, 0x308]), (0xfd, false, &[0x79, 0x301]), (0xff, false, &[0x79, 0x308]), (0x100, false, &[0x41, 0x304]),
+    (0x101, false, &[0x61, 0x304]), (0x102, false, &[0x41, 0x306]), (0x103, false, &[0x61, 0x306]), (0x104, false, &[0x41, 0x328]),
+    (0x105, false, &[0x61, 0x328]), (0x106, false, &[0x43, 0x301]), (0x107, false, &[0x63, 0x301]), (0x108, false, &[0x43, 0x302]),
+    (0x109, false, &[0x63, 0x302]), (0x10a, false, &[0x43, 0x307]), (0x10b, false, &[0x63, 0x307]), (0x10c, false, &[0x43, 0x30c]),
+    (0x10d, false, &[0x63, 0x30c]), (0x10e, false, &[0x44, 0x30c]), (0x10f, false, &[0x64, 0x30c]), (0x112, false, &[0x45, 0x304]),
+    (0x113, false, &[0x65, 0x304]), (0x114, false, &[0x45, 0x306]), (0x115, false, &[0x65, 0x306]), (0x116, false, &[0x45, 0x307]),
+    (0x117, false, &[0x65, 0x307]), (0x118, false, &[0x45, 0x328]), (0x119, false, &[0x65, 0x328]), (0x11a, false, &[0x45, 0x30c]),
+    (0x11b, false, &[0x65, 0x30c]), (0x11c, false, &[0x47, 0x302]), (0x11d, false, &[0x67, 0x302]), (0x11e, false, &[0x47, 0x306]),
+    (0x11f, false, &[0x67, 0x306]), (0x120, false, &[0x47, 0x307]), (0x121, false, &[0x67, 0x307]), (0x122, false, &[0x47, 0x327]),
+    (0x123, false, &[0x67, 0x327]), (0x124, false, &[0x48, 0x302]), (0x125, false, &[0x68, 0x302]), (0x128, false, &[0x49, 0x303]),
+    (0x129, false, &[0x69, 0x303]), (0x12a, false, &[0x49, 0x304]), (0x12b, false, &[0x69, 0x304]), (0x12c, false, &[0x49, 0x306]),
+    (0x12d, false, &[0x69, 0x306]), (0x12e, false, &[0x49, 0x328]), (0x12f, false, &[0x69, 0x328]), (0x130, false, &[0x49, 0x307]),
+    (0x132, true, &[0x49, 0x4a]), (0x133, true, &[0x69, 0x6a]), (0x134, false, &[0x4a, 0x302]), (0x135, false, &[0x6a, 0x302]),
+    (0x136, false, &[0x4b, 0x327]), (0x137, false, &[0x6b, 0x327]), (0x139, false, &[0x4c, 0x301]), (0x13a, false, &[0x6c, 0x301]),
+    (0x13b, false, &[0x4c, 0x327]), (0x13c, false, &[0x6c, 0x327]), (0x13d, false, &[0x4c, 0x30c]), (0x13e, false, &[0x6c, 0x30c]),
+    (0x13f, true, &[0x4c, 0xb7]), (0x140, true, &[0x6c, 0xb7]), (0x143, false, &[0x4e, 0x301]), (0x144, false, &[0x6e, 0x301]),
+    (0x145, false, &[0x4e, 0x327]), (0x146, false, &[0x6e, 0x327]), (0x147, false, &[0x4e, 0x30c]), (0x148, false, &[0x6e, 0x30c]),
+    (0x149, true, &[0x2bc, 0x6e]), (0x14c, false, &[0x4f, 0x304]), (0x14d, false, &[0x6f, 0x304]), (0x14e, false, &[0x4f, 0x306]),
+    (0x14f, false, &[0x6f, 0x306]), (0x150, false, &[0x4f, 0x30b]), (0x151, false, &[0x6f, 0x30b]), (0x154, false, &[0x52, 0x301]),
+    (0x155, false, &[0x72, 0x301]), (0x156, false, &[0x52, 0x327]), (0x157, false, &[0x72, 0x327]), (0x158, false, &[0x52, 0x30c]),
+    (0x159, false, &[0x72, 0x30c]), (0x15a, false, &[0x53, 0x301]), (0x15b, false, &[0x73, 0x301]), (0x15c, false, &[0x53, 0x302]),
+    (0x15d, false, &[0x73, 0x302]), (0x15e, false, &[0x53, 0x327]), (0x15f, false, &[0x73, 0x327]), (0x160, false, &[0x53, 0x30c]),
+    (0x161, false, &[0x73, 0x30c]), (0x162, false, &[0x54, 0x327]), (0x163, false, &[0x74, 0x327]), (0x164, false, &[0x54, 0x30c]),
+    (0x165, false, &[0x74, 0x30c]), (0x168, false, &[0x55, 0x303]), (0x169, false, &[0x75, 0x303]), (0x16a, false, &[0x55, 0x304]),
+    (0x16b, false, &[0x75, 0x304]), (0x16c, false, &[0x55, 0x306]), (0x16d, false, &[0x75, 0x306]), (0x16e, false, &[0x55, 0x30a]),
+    (0x16f, false, &[0x75, 0x30a]), (0x170, false, &[0x55, 0x30b]), (0x171, false, &[0x75, 0x30b]), (0x172, false, &[0x55, 0x328]),
+    (0x173, false, &[0x75, 0x328]), (0x174, false, &[0x57, 0x302]), (0x175, false, &[0x77, 0x302]), (0x176, false, &[0x59, 0x302]),
+    (0x177, false, &[0x79, 0x302]), (0x178, false, &[0x59, 0x308]), (0x179, false, &[0x5a, 0x301]), (0x17a, false, &[0x7a, 0x301]),
+    (0x17b, false, &[0x5a, 0x307]), (0x17c, false, &[0x7a, 0x307]), (0x17d, false, &[0x5a, 0x30c]), (0x17e, false, &[0x7a, 0x30c]),
+    (0x17f, true, &[0x73]), (0x1a0, false, &[0x4f, 0x31b]), (0x1a1, false, &[0x6f, 0x31b]), (0x1af, false, &[0x55, 0x31b]),
+    (0x1b0, false, &[0x75, 0x31b]), (0x1c4, true, &[0x44, 0x17d]), (0x1c5, true, &[0x44, 0x17e]), (0x1c6, true, &[0x64, 0x17e]),
+    (0x1c7, true, &[0x4c, 0x4a]), (0x1c8, true, &[0x4c, 0x6a]), (0x1c9, true, &[0x6c, 0x6a]), (0x1ca, true, &[0x4e, 0x4a]),
+    (0x1cb, true, &[0x4e, 0x6a]), (0x1cc, true, &[0x6e, 0x6a]), (0x1cd, false, &[0x41, 0x30c]), (0x1ce, false, &[0x61, 0x30c]),
+    (0x1cf, false, &[0x49, 0x30c]), (0x1d0, false, &[0x69, 0x30c]), (0x1d1, false, &[0x4f, 0x30c]), (0x1d2, false, &[0x6f, 0x30c]),
+    (0x1d3, false, &[0x55, 0x30c]), (0x1d4, false, &[0x75, 0x30c]), (0x1d5, false, &[0xdc, 0x304]), (0x1d6, false, &[0xfc, 0x304]),
+    (0x1d7, false, &[0xdc, 0x301]), (0x1d8, false, &[0xfc, 0x301]), (0x1d9, false, &[0xdc, 0x30c]), (0x1da, false, &[0xfc, 0x30c]),
+    (0x1db, false, &[0xdc, 0x300]), (0x1dc, false, &[0xfc, 0x300]), (0x1de, false, &[0xc4, 0x304]), (0x1df, false, &[0xe4, 0x304]),
+    (0x1e0, false, &[0x226, 0x304]), (0x1e1, false, &[0x227, 0x304]), (0x1e2, false, &[0xc6, 0x304]), (0x1e3, false, &[0xe6, 0x304]),
+    (0x1e6, false, &[0x47, 0x30c]), (0x1e7, false, &[0x67, 0x30c]), (0x1e8, false, &[0x4b, 0x30c]), (0x1e9, false, &[0x6b, 0x30c]),
+    (0x1ea, false, &[0x4f, 0x328]), (0x1eb, false, &[0x6f, 0x328]), (0x1ec, false, &[0x1ea, 0x304]), (0x1ed, false, &[0x1eb, 0x304]),
+    (0x1ee, false, &[0x1b7, 0x30c]), (0x1ef, false, &[0x292, 0x30c]), (0x1f0, false, &[0x6a, 0x30c]), (0x1f1, true, &[0x44, 0x5a]),
+    (0x1f2, true, &[0x44, 0x7a]), (0x1f3, true, &[0x64, 0x7a]), (0x1f4, false, &[0x47, 0x301]), (0x1f5, false, &[0x67, 0x301]),
+    (0x1f8, false, &[0x4e, 0x300]), (0x1f9, false, &[0x6e, 0x300]), (0x1fa, false, &[0xc5, 0x301]), (0x1fb, false, &[0xe5, 0x301]),
+    (0x1fc, false, &[0xc6, 0x301]), (0x1fd, false, &[0xe6, 0x301]), (0x1fe, false, &[0xd8, 0x301]), (0x1ff, false, &[0xf8, 0x301]),
+    (0x200, false, &[0x41, 0x30f]), (0x201, false, &[0x61, 0x30f]), (0x202, false, &[0x41, 0x311]), (0x203, false, &[0x61, 0x311]),
+    (0x204, false, &[0x45, 0x30f]), (0x205, false, &[0x65, 0x30f]), (0x206, false, &[0x45, 0x311]), (0x207, false, &[0x65, 0x311]),
+    (0x208, false, &[0x49, 0x30f]), (0x209, false, &[0x69, 0x30f]), (0x20a, false, &[0x49, 0x311]), (0x20b, false, &[0x69, 0x311]),
+    (0x20c, false, &[0x4f, 0x30f]), (0x20d, false, &[0x6f, 0x30f]), (0x20e, false, &[0x4f, 0x311]), (0x20f, false, &[0x6f, 0x311]),
+    (0x210, false, &[0x52, 0x30f]), (0x211, false, &[0x72, 0x30f]), (0x212, false, &[0x52, 0x311]), (0x213, false, &[0x72, 0x311]),
+    (0x214, false, &[0x55, 0x30f]), (0x215, false, &[0x75, 0x30f]), (0x216, false, &[0x55, 0x311]), (0x217, false, &[0x75, 0x311]),
+    (0x218, false, &[0x53, 0x326]), (0x219, false, &[0x73, 0x326]), (0x21a, false, &[0x54, 0x326]), (0x21b, false, &[0x74, 0x326]),
+    (0x21e, false, &[0x48, 0x30c]), (0x21f, false, &[0x68, 0x30c]), (0x226, false, &[0x41, 0x307]), (0x227, false, &[0x61, 0x307]),
+    (0x228, false, &[0x45, 0x327]), (0x229, false, &[0x65, 0x327]), (0x22a, false, &[0xd6, 0x304]), (0x22b, false, &[0xf6, 0x304]),
+    (0x22c, false, &[0xd5, 0x304]), (0x22d, false, &[0xf5, 0x304]), (0x22e, false, &[0x4f, 0x307]), (0x22f, false, &[0x6f, 0x307]),
+    (0x230, false, &[0x22e, 0x304]), (0x231, false, &[0x22f, 0x304]), (0x232, false, &[0x59, 0x304]), (0x233, false, &[0x79, 0x304]),
+    (0x2b0, true, &[0x68]), (0x2b1, true, &[0x266]), (0x2b2, true, &[0x6a]), (0x2b3, true, &[0x72]),
+    (0x2b4, true, &[0x279]), (0x2b5, true, &[0x27b]), (0x2b6, true, &[0x281]), (0x2b7, true, &[0x77]),
+    (0x2b8, true, &[0x79]), (0x2d8, true, &[0x20, 0x306]), (0x2d9, true, &[0x20, 0x307]), (0x2da, true, &[0x20, 0x30a]),
+    (0x2db, true, &[0x20, 0x328]), (0x2dc, true, &[0x20, 0x303]), (0x2dd, true, &[0x20, 0x30b]), (0x2e0, true, &[0x263]),
+    (0x2e1, true, &[0x6c]), (0x2e2, true, &[0x73]), (0x2e3, true, &[0x78]), (0x2e4, true, &[0x295]),
+    (0x340, false, &[0x300]), (0x341, false, &[0x301]), (0x343, false, &[0x313]), (0x344, false, &[0x308, 0x301]),
+    (0x374, false, &[0x2b9]), (0x37a, true, &[0x20, 0x345]), (0x37e, false, &[0x3b]), (0x384, true, &[0x20, 0x301]),
+    (0x385, false, &[0xa8, 0x301]), (0x386, false, &[0x391, 0x301]), (0x387, false, &[0xb7]), (0x388, false, &[0x395, 0x301]),
+    (0x389, false, &[0x397, 0x301]), (0x38a, false, &[0x399, 0x301]), (0x38c, false, &[0x39f, 0x301]), (0x38e, false, &[0x3a5, 0x301]),
+    (0x38f, false, &[0x3a9, 0x301]), (0x390, false, &[0x3ca, 0x301]), (0x3aa, false, &[0x399, 0x308]), (0x3ab, false, &[0x3a5, 0x308]),
+    (0x3ac, false, &[0x3b1, 0x301]), (0x3ad, false, &[0x3b5, 0x301]), (0x3ae, false, &[0x3b7, 0x301]), (0x3af, false, &[0x3b9, 0x301]),
+    (0x3b0, false, &[0x3cb, 0x301]), (0x3ca, false, &[0x3b9, 0x308]), (0x3cb, false, &[0x3c5, 0x308]), (0x3cc, false, &[0x3bf, 0x301]),
+    (0x3cd, false, &[0x3c5, 0x301]), (0x3ce, false, &[0x3c9, 0x301]), (0x3d0, true, &[0x3b2]), (0x3d1, true, &[0x3b8]),
+    (0x3d2, true, &[0x3a5]), (0x3d3, false, &[0x3d2, 0x301]), (0x3d4, false, &[0x3d2, 0x308]), (0x3d5, true, &[0x3c6]),
+    (0x3d6, true, &[0x3c0]), (0x3f0, true, &[0x3ba]), (0x3f1, true, &[0x3c1]), (0x3f2, true, &[0x3c2]),
+    (0x3f4, true, &[0x398]), (0x3f5, true, &[0x3b5]), (0x3f9, true, &[0x3a3]), (0x400, false, &[0x415, 0x300]),
+    (0x401, false, &[0x415, 0x308]), (0x403, false, &[0x413, 0x301]), (0x407, false, &[0x406, 0x308]), (0x40c, false, &[0x41a, 0x301]),
+    (0x40d, false, &[0x418, 0x300]), (0x40e, false, &[0x423, 0x306]), (0x419, false, &[0x418, 0x306]), (0x439, false, &[0x438, 0x306]),
+    (0x450, false, &[0x435, 0x300]), (0x451, false, &[0x435, 0x308]), (0x453, false, &[0x433, 0x301]), (0x457, false, &[0x456, 0x308]),
+    (0x45c, false, &[0x43a, 0x301]), (0x45d, false, &[0x438, 0x300]), (0x45e, false, &[0x443, 0x306]), (0x476, false, &[0x474, 0x30f]),
+    (0x477, false, &[0x475, 0x30f]), (0x4c1, false, &[0x416, 0x306]), (0x4c2, false, &[0x436, 0x306]), (0x4d0, false, &[0x410, 0x306]),
+    (0x4d1, false, &[0x430, 0x306]), (0x4d2, false, &[0x410, 0x308]), (0x4d3, false, &[0x430, 0x308]), (0x4d6, false, &[0x415, 0x306]),
+    (0x4d7, false, &[0x435, 0x306]), (0x4da, false, &[0x4d8, 0x308]), (0x4db, false, &[0x4d9, 0x308]), (0x4dc, false, &[0x416, 0x308]),
+    (0x4dd, false, &[0x436, 0x308]), (0x4de, false, &[0x417, 0x308]), (0x4df, false, &[0x437, 0x308]), (0x4e2, false, &[0x418, 0x304]),
+    (0x4e3, false, &[0x438, 0x304]), (0x4e4, false, &[0x418, 0x308]), (0x4e5, false, &[0x438, 0x308]), (0x4e6, false, &[0x41e, 0x308]),
+    (0x4e7, false, &[0x43e, 0x308]), (0x4ea, false, &[0x4e8, 0x308]), (0x4eb, false, &[0x4e9, 0x308]), (0x4ec, false, &[0x42d, 0x308]),
+    (0x4ed, false, &[0x44d, 0x308]), (0x4ee, false, &[0x423, 0x304]), (0x4ef, false, &[0x443, 0x304]), (0x4f0, false, &[0x423, 0x308]),
+    (0x4f1, false, &[0x443, 0x308]), (0x4f2, false, &[0x423, 0x30b]), (0x4f3, false, &[0x443, 0x30b]), (0x4f4, false, &[0x427, 0x308]),
+    (0x4f5, false, &[0x447, 0x308]), (0x4f8, false, &[0x42b, 0x308]), (0x4f9, false, &[0x44b, 0x308]), (0x587, true, &[0x565, 0x582]),
+    (0x622, false, &[0x627, 0x653]), (0x623, false, &[0x627, 0x654]), (0x624, false, &[0x648, 0x654]), (0x625, false, &[0x627, 0x655]),
+    (0x626, false, &[0x64a, 0x654]), (0x675, true, &[0x627, 0x674]), (0x676, true, &[0x648, 0x674]), (0x677, true, &[0x6c7, 0x674]),
+    (0x678, true, &[0x64a, 0x674]), (0x6c0, false, &[0x6d5, 0x654]), (0x6c2, false, &[0x6c1, 0x654]), (0x6d3, false, &[0x6d2, 0x654]),
+    (0x929, false, &[0x928, 0x93c]), (0x931, false, &[0x930, 0x93c]), (0x934, false, &[0x933, 0x93c]), (0x958, false, &[0x915, 0x93c]),
+    (0x959, false, &[0x916, 0x93c]), (0x95a, false, &[0x917, 0x93c]), (0x95b, false, &[0x91c, 0x93c]), (0x95c, false, &[0x921, 0x93c]),
+    (0x95d, false, &[0x922, 0x93c]), (0x95e, false, &[0x92b, 0x93c]), (0x95f, false, &[0x92f, 0x93c]), (0x9cb, false, &[0x9c7, 0x9be]),
+    (0x9cc, false, &[0x9c7, 0x9d7]), (0x9dc, false, &[0x9a1, 0x9bc]), (0x9dd, false, &[0x9a2, 0x9bc]), (0x9df, false, &[0x9af, 0x9bc]),
+    (0xa33, false, &[0xa32, 0xa3c]), (0xa36, false, &[0xa38, 0xa3c]), (0xa59, false, &[0xa16, 0xa3c]), (0xa5a, false, &[0xa17, 0xa3c]),
+    (0xa5b, false, &[0xa1c, 0xa3c]), (0xa5e, false, &[0xa2b, 0xa3c]), (0xb48, false, &[0xb47, 0xb56]), (0xb4b, false, &[0xb47, 0xb3e]),
+    (0xb4c, false, &[0xb47, 0xb57]), (0xb5c, false, &[0xb21, 0xb3c]), (0xb5d, false, &[0xb22, 0xb3c]), (0xb94, false, &[0xb92, 0xbd7]),
+    (0xbca, false, &[0xbc6, 0xbbe]), (0xbcb, false, &[0xbc7, 0xbbe]), (0xbcc, false, &[0xbc6, 0xbd7]), (0xc48, false, &[0xc46, 0xc56]),
+    (0xcc0, false, &[0xcbf, 0xcd5]), (0xcc7, false, &[0xcc6, 0xcd5]), (0xcc8, false, &[0xcc6, 0xcd6]), (0xcca, false, &[0xcc6, 0xcc2]),
+    (0xccb, false, &[0xcca, 0xcd5]), (0xd4a, false, &[0xd46, 0xd3e]), (0xd4b, false, &[0xd47, 0xd3e]), (0xd4c, false, &[0xd46, 0xd57]),
+    (0xdda, false, &[0xdd9, 0xdca]), (0xddc, false, &[0xdd9, 0xdcf]), (0xddd, false, &[0xddc, 0xdca]), (0xdde, false, &[0xdd9, 0xddf]),
+    (0xe33, true, &[0xe4d, 0xe32]), (0xeb3, true, &[0xecd, 0xeb2]), (0xedc, true, &[0xeab, 0xe99]), (0xedd, true, &[0xeab, 0xea1]),
+    (0xf0c, true, &[0xf0b]), (0xf43, false, &[0xf42, 0xfb7]), (0xf4d, false, &[0xf4c, 0xfb7]), (0xf52, false, &[0xf51, 0xfb7]),
+    (0xf57, false, &[0xf56, 0xfb7]), (0xf5c, false, &[0xf5b, 0xfb7]), (0xf69, false, &[0xf40, 0xfb5]), (0xf73, false, &[0xf71, 0xf72]),
+    (0xf75, false, &[0xf71, 0xf74]), (0xf76, false, &[0xfb2, 0xf80]), (0xf77, true, &[0xfb2, 0xf81]), (0xf78, false, &[0xfb3, 0xf80]),
+    (0xf79, true, &[0xfb3, 0xf81]), (0xf81, false, &[0xf71, 0xf80]), (0xf93, false, &[0xf92, 0xfb7]), (0xf9d, false, &[0xf9c, 0xfb7]),
+    (0xfa2, false, &[0xfa1, 0xfb7]), (0xfa7, false, &[0xfa6, 0xfb7]), (0xfac, false, &[0xfab, 0xfb7]), (0xfb9, false, &[0xf90, 0xfb5]),
+    (0x1026, false, &[0x1025, 0x102e]), (0x10fc, true, &[0x10dc]), (0x1b06, false, &[0x1b05, 0x1b35]), (0x1b08, false, &[0x1b07, 0x1b35]),
+    (0x1b0a, false, &[0x1b09, 0x1b35]), (0x1b0c, false, &[0x1b0b, 0x1b35]), (0x1b0e, false, &[0x1b0d, 0x1b35]), (0x1b12, false, &[0x1b11, 0x1b35]),
+    (0x1b3b, false, &[0x1b3a, 0x1b35]), (0x1b3d, false, &[0x1b3c, 0x1b35]), (0x1b40, false, &[0x1b3e, 0x1b35]), (0x1b41, false, &[0x1b3f, 0x1b35]),
+    (0x1b43, false, &[0x1b42, 0x1b35]), (0x1d2c, true, &[0x41]), (0x1d2d, true, &[0xc6]), (0x1d2e, true, &[0x42]),
+    (0x1d30, true, &[0x44]), (0x1d31, true, &[0x45]), (0x1d32, true, &[0x18e]), (0x1d33, true, &[0x47]),
+    (0x1d34, true, &[0x48]), (0x1d35, true, &[0x49]), (0x1d36, true, &[0x4a]), (0x1d37, true, &[0x4b]),
+    (0x1d38, true, &[0x4c]), (0x1d39, true, &[0x4d]), (0x1d3a, true, &[0x4e]), (0x1d3c, true, &[0x4f]),
+    (0x1d3d, true, &[0x222]), (0x1d3e, true, &[0x50]), (0x1d3f, true, &[0x52]), (0x1d40, true, &[0x54]),
+    (0x1d41, true, &[0x55]), (0x1d42, true, &[0x57]), (0x1d43, true, &[0x61]), (0x1d44, true, &[0x250]),
+    (0x1d45, true, &[0x251]), (0x1d46, true, &[0x1d02]), (0x1d47, true, &[0x62]), (0x1d48, true, &[0x64]),
+    (0x1d49, true, &[0x65]), (0x1d4a, true, &[0x259]), (0x1d4b, true, &[0x25b]), (0x1d4c, true, &[0x25c]),
+    (0x1d4d, true, &[0x67]), (0x1d4f, true, &[0x6b]), (0x1d50, true, &[0x6d]), (0x1d51, true, &[0x14b]),
+    (0x1d52, true, &[0x6f]), (0x1d53, true, &[0x254]), (0x1d54, true, &[0x1d16]), (0x1d55, true, &[0x1d17]),
+    (0x1d56, true, &[0x70]), (0x1d57, true, &[0x74]), (0x1d58, true, &[0x75]), (0x1d59, true, &[0x1d1d]),
+    (0x1d5a, true, &[0x26f]), (0x1d5b, true, &[0x76]), (0x1d5c, true, &[0x1d25]), (0x1d5d, true, &[0x3b2]),
+    (0x1d5e, true, &[0x3b3]), (0x1d5f, true, &[0x3b4]), (0x1d60, true, &[0x3c6]), (0x1d61, true, &[0x3c7]),
+    (0x1d62, true, &[0x69]), (0x1d63, true, &[0x72]), (0x1d64, true, &[0x75]), (0x1d65, true, &[0x76]),
+    (0x1d66, true, &[0x3b2]), (0x1d67, true, &[0x3b3]), (0x1d68, true, &[0x3c1]), (0x1d69, true, &[0x3c6]),
+    (0x1d6a, true, &[0x3c7]), (0x1d78, true, &[0x43d]), (0x1d9b, true, &[0x252]), (0x1d9c, true, &[0x63]),
+    (0x1d9d, true, &[0x255]), (0x1d9e, true, &[0xf0]), (0x1d9f, true, &[0x25c]), (0x1da0, true, &[0x66]),
+    (0x1da1, true, &[0x25f]), (0x1da2, true, &[0x261]), (0x1da3, true, &[0x265]), (0x1da4, true, &[0x268]),
+    (0x1da5, true, &[0x269]), (0x1da6, true, &[0x26a]), (0x1da7, true, &[0x1d7b]), (0x1da8, true, &[0x29d]),
+    (0x1da9, true, &[0x26d]), (0x1daa, true, &[0x1d85]), (0x1dab, true, &[0x29f]), (0x1dac, true, &[0x271]),
+    (0x1dad, true, &[0x270]), (0x1dae, true, &[0x272]), (0x1daf, true, &[0x273]), (0x1db0, true, &[0x274]),
+    (0x1db1, true, &[0x275]), (0x1db2, true, &[0x278]), (0x1db3, true, &[0x282]), (0x1db4, true, &[0x283]),
+    (0x1db5, true, &[0x1ab]), (0x1db6, true, &[0x289]), (0x1db7, true, &[0x28a]), (0x1db8, true, &[0x1d1c]),
+    (0x1db9, true, &[0x28b]), (0x1dba, true, &[0x28c]), (0x1dbb, true, &[0x7a]), (0x1dbc, true, &[0x290]),
+    (0x1dbd, true, &[0x291]), (0x1dbe, true, &[0x292]), (0x1dbf, true, &[0x3b8]), (0x1e00, false, &[0x41, 0x325]),
+    (0x1e01, false, &[0x61, 0x325]), (0x1e02, false, &[0x42, 0x307]), (0x1e03, false, &[0x62, 0x307]), (0x1e04, false, &[0x42, 0x323]),
+    (0x1e05, false, &[0x62, 0x323]), (0x1e06, false, &[0x42, 0x331]), (0x1e07, false, &[0x62, 0x331]), (0x1e08, false, &[0xc7, 0x301]),
+    (0x1e09, false, &[0xe7, 0x301]), (0x1e0a, false, &[0x44, 0x307]), (0x1e0b, false, &[0x64, 0x307]), (0x1e0c, false, &[0x44, 0x323]),
+    (0x1e0d, false, &[0x64, 0x323]), (0x1e0e, false, &[0x44, 0x331]), (0x1e0f, false, &[0x64, 0x331]), (0x1e10, false, &[0x44, 0x327]),
+    (0x1e11, false, &[0x64, 0x327]), (0x1e12, false, &[0x44, 0x32d]), (0x1e13, false, &[0x64, 0x32d]), (0x1e14, false, &[0x112, 0x300]),
+    (0x1e15, false, &[0x113, 0x300]), (0x1e16, false, &[0x112, 0x301]), (0x1e17, false, &[0x113, 0x301]), (0x1e18, false, &[0x45, 0x32d]),
+    (0x1e19, false, &[0x65, 0x32d]), (0x1e1a, false, &[0x45, 0x330]), (0x1e1b, false, &[0x65, 0x330]), (0x1e1c, false, &[0x228, 0x306]),
+    (0x1e1d, false, &[0x229, 0x306]), (0x1e1e, false, &[0x46, 0x307]), (0x1e1f, false, &[0x66, 0x307]), (0x1e20, false, &[0x47, 0x304]),
+    (0x1e21, false, &[0x67, 0x304]), (0x1e22, false, &[0x48, 0x307]), (0x1e23, false, &[0x68, 0x307]), (0x1e24, false, &[0x48, 0x323]),
+    (0x1e25, false, &[0x68, 0x323]), (0x1e26, false, &[0x48, 0x308]), (0x1e27, false, &[0x68, 0x308]), (0x1e28, false, &[0x48, 0x327]),
+    (0x1e29, false, &[0x68, 0x327]), (0x1e2a, false, &[0x48, 0x32e]), (0x1e2b, false, &[0x68, 0x32e]), (0x1e2c, false, &[0x49, 0x330]),
+    (0x1e2d, false, &[0x69, 0x330]), (0x1e2e, false, &[0xcf, 0x301]), (0x1e2f, false, &[0xef, 0x301]), (0x1e30, false, &[0x4b, 0x301]),
+    (0x1e31, false, &[0x6b, 0x301]), (0x1e32, false, &[0x4b, 0x323]), (0x1e33, false, &[0x6b, 0x323]), (0x1e34, false, &[0x4b, 0x331]),
+    (0x1e35, false, &[0x6b, 0x331]), (0x1e36, false, &[0x4c, 0x323]), (0x1e37, false, &[0x6c, 0x323]), (0x1e38, false, &[0x1e36, 0x304]),
+    (0x1e39, false, &[0x1e37, 0x304]), (0x1e3a, false, &[0x4c, 0x331]), (0x1e3b, false, &[0x6c, 0x331]), (0x1e3c, false, &[0x4c, 0x32d]),
+    (0x1e3d, false, &[0x6c, 0x32d]), (0x1e3e, false, &[0x4d, 0x301]), (0x1e3f, false, &[0x6d, 0x301]), (0x1e40, false, &[0x4d, 0x307]),
+    (0x1e41, false, &[0x6d, 0x307]), (0x1e42, false, &[0x4d, 0x323]), (0x1e43, false, &[0x6d, 0x323]), (0x1e44, false, &[0x4e, 0x307]),
+    (0x1e45, false, &[0x6e, 0x307]), (0x1e46, false, &[0x4e, 0x323]), (0x1e47, false, &[0x6e, 0x323]), (0x1e48, false, &[0x4e, 0x331]),
+    (0x1e49, false, &[0x6e, 0x331]), (0x1e4a, false, &[0x4e, 0x32d]), (0x1e4b, false, &[0x6e, 0x32d]), (0x1e4c, false, &[0xd5, 0x301]),
+    (0x1e4d, false, &[0xf5, 0x301]), (0x1e4e, false, &[0xd5, 0x308]), (0x1e4f, false, &[0xf5, 0x308]), (0x1e50, false, &[0x14c, 0x300]),
+    (0x1e51, false, &[0x14d, 0x300]), (0x1e52, false, &[0x14c, 0x301]), (0x1e53, false, &[0x14d, 0x301]), (0x1e54, false, &[0x50, 0x301]),
+    (0x1e55, false, &[0x70, 0x301]), (0x1e56, false, &[0x50, 0x307]), (0x1e57, false, &[0x70, 0x307]), (0x1e58, false, &[0x52, 0x307]),
+    (0x1e59, false, &[0x72, 0x307]), (0x1e5a, false, &[0x52, 0x323]), (0x1e5b, false, &[0x72, 0x323]), (0x1e5c, false, &[0x1e5a, 0x304]),
+    (0x1e5d, false, &[0x1e5b, 0x304]), (0x1e5e, false, &[0x52, 0x331]), (0x1e5f, false, &[0x72, 0x331]), (0x1e60, false, &[0x53, 0x307]),
+    (0x1e61, false, &[0x73, 0x307]), (0x1e62, false, &[0x53, 0x323]), (0x1e63, false, &[0x73, 0x323]), (0x1e64, false, &[0x15a, 0x307]),
+    (0x1e65, false, &[0x15b, 0x307]), (0x1e66, false, &[0x160, 0x307]), (0x1e67, false, &[0x161, 0x307]), (0x1e68, false, &[0x1e62, 0x307]),
+    (0x1e69, false, &[0x1e63, 0x307]), (0x1e6a, false, &[0x54, 0x307]), (0x1e6b, false, &[0x74, 0x307]), (0x1e6c, false, &[0x54, 0x323]),
+    (0x1e6d, false, &[0x74, 0x323]), (0x1e6e, false, &[0x54, 0x331]), (0x1e6f, false, &[0x74, 0x331]), (0x1e70, false, &[0x54, 0x32d]),
+    (0x1e71, false, &[0x74, 0x32d]), (0x1e72, false, &[0x55, 0x324]), (0x1e73, false, &[0x75, 0x324]), (0x1e74, false, &[0x55, 0x330]),
+    (0x1e75, false, &[0x75, 0x330]), (0x1e76, false, &[0x55, 0x32d]), (0x1e77, false, &[0x75, 0x32d]), (0x1e78, false, &[0x168, 0x301]),
+    (0x1e79, false, &[0x169, 0x301]), (0x1e7a, false, &[0x16a, 0x308]), (0x1e7b, false, &[0x16b, 0x308]), (0x1e7c, false, &[0x56, 0x303]),
+    (0x1e7d, false, &[0x76, 0x303]), (0x1e7e, false, &[0x56, 0x323]), (0x1e7f, false, &[0x76, 0x323]), (0x1e80, false, &[0x57, 0x300]),
+    (0x1e81, false, &[0x77, 0x300]), (0x1e82, false, &[0x57, 0x301]), (0x1e83, false, &[0x77, 0x301]), (0x1e84, false, &[0x57, 0x308]),
+    (0x1e85, false, &[0x77, 0x308]), (0x1e86, false, &[0x57, 0x307]), (0x1e87, false, &[0x77, 0x307]), (0x1e88, false, &[0x57, 0x323]),
+    (0x1e89, false, &[0x77, 0x323]), (0x1e8a, false, &[0x58, 0x307]), (0x1e8b, false, &[0x78, 0x307]), (0x1e8c, false, &[0x58, 0x308]),
+    (0x1e8d, false, &[0x78, 0x308]), (0x1e8e, false, &[0x59, 0x307]), (0x1e8f, false, &[0x79, 0x307]), (0x1e90, false, &[0x5a, 0x302]),
+    (0x1e91, false, &[0x7a, 0x302]), (0x1e92, false, &[0x5a, 0x323]), (0x1e93, false, &[0x7a, 0x323]), (0x1e94, false, &[0x5a, 0x331]),
+    (0x1e95, false, &[0x7a, 0x331]), (0x1e96, false, &[0x68, 0x331]), (0x1e97, false, &[0x74, 0x308]), (0x1e98, false, &[0x77, 0x30a]),
+    (0x1e99, false, &[0x79, 0x30a]), (0x1e9a, true, &[0x61, 0x2be]), (0x1e9b, false, &[0x17f, 0x307]), (0x1ea0, false, &[0x41, 0x323]),
+    (0x1ea1, false, &[0x61, 0x323]), (0x1ea2, false, &[0x41, 0x309]), (0x1ea3, false, &[0x61, 0x309]), (0x1ea4, false, &[0xc2, 0x301]),
+    (0x1ea5, false, &[0xe2, 0x301]), (0x1ea6, false, &[0xc2, 0x300]), (0x1ea7, false, &[0xe2, 0x300]), (0x1ea8, false, &[0xc2, 0x309]),
+    (0x1ea9, false, &[0xe2, 0x309]), (0x1eaa, false, &[0xc2, 0x303]), (0x1eab, false, &[0xe2, 0x303]), (0x1eac, false, &[0x1ea0, 0x302]),
+    (0x1ead, false, &[0x1ea1, 0x302]), (0x1eae, false, &[0x102, 0x301]), (0x1eaf, false, &[0x103, 0x301]), (0x1eb0, false, &[0x102, 0x300]),
+    (0x1eb1, false, &[0x103, 0x300]), (0x1eb2, false, &[0x102, 0x309]), (0x1eb3, false, &[0x103, 0x309]), (0x1eb4, false, &[0x102, 0x303]),
+    (0x1eb5, false, &[0x103, 0x303]), (0x1eb6, false, &[0x1ea0, 0x306]), (0x1eb7, false, &[0x1ea1, 0x306]), (0x1eb8, false, &[0x45, 0x323]),
+    (0x1eb9, false, &[0x65, 0x323]), (0x1eba, false, &[0x45, 0x309]), (0x1ebb, false, &[0x65, 0x309]), (0x1ebc, false, &[0x45, 0x303]),
+    (0x1ebd, false, &[0x65, 0x303]), (0x1ebe, false, &[0xca, 0x301]), (0x1ebf, false, &[0xea, 0x301]), (0x1ec0, false, &[0xca, 0x300]),
+    (0x1ec1, false, &[0xea, 0x300]), (0x1ec2, false, &[0xca, 0x309]), (0x1ec3, false, &[0xea, 0x309]), (0x1ec4, false, &[0xca, 0x303]),
+    (0x1ec5, false, &[0xea, 0x303]), (0x1ec6, false, &[0x1eb8, 0x302]), (0x1ec7, false, &[0x1eb9, 0x302]), (0x1ec8, false, &[0x49, 0x309]),
+    (0x1ec9, false, &[0x69, 0x309]), (0x1eca, false, &[0x49, 0x323]), (0x1ecb, false, &[0x69, 0x323]), (0x1ecc, false, &[0x4f, 0x323]),
+    (0x1ecd, false, &[0x6f, 0x323]), (0x1ece, false, &[0x4f, 0x309]), (0x1ecf, false, &[0x6f, 0x309]), (0x1ed0, false, &[0xd4, 0x301]),
+    (0x1ed1, false, &[0xf4, 0x301]), (0x1ed2, false, &[0xd4, 0x300]), (0x1ed3, false, &[0xf4, 0x300]), (0x1ed4, false, &[0xd4, 0x309]),
+    (0x1ed5, false, &[0xf4, 0x309]), (0x1ed6, false, &[0xd4, 0x303]), (0x1ed7, false, &[0xf4, 0x303]), (0x1ed8, false, &[0x1ecc, 0x302]),
+    (0x1ed9, false, &[0x1ecd, 0x302]), (0x1eda, false, &[0x1a0, 0x301]), (0x1edb, false, &[0x1a1, 0x301]), (0x1edc, false, &[0x1a0, 0x300]),
+    (0x1edd, false, &[0x1a1, 0x300]), (0x1ede, false, &[0x1a0, 0x309]), (0x1edf, false, &[0x1a1, 0x309]), (0x1ee0, false, &[0x1a0, 0x303]),
+    (0x1ee1, false, &[0x1a1, 0x303]), (0x1ee2, false, &[0x1a0, 0x323]), (0x1ee3, false, &[0x1a1, 0x323]), (0x1ee4, false, &[0x55, 0x323]),
+    (0x1ee5, false, &[0x75, 0x323]), (0x1ee6, false, &[0x55, 0x309]), (0x1ee7, false, &[0x75, 0x309]), (0x1ee8, false, &[0x1af, 0x301]),
+    (0x1ee9, false, &[0x1b0, 0x301]), (0x1eea, false, &[0x1af, 0x300]), (0x1eeb, false, &[0x1b0, 0x300]), (0x1eec, false, &[0x1af, 0x309]),
+    (0x1eed, false, &[0x1b0, 0x309]), (0x1eee, false, &[0x1af, 0x303]), (0x1eef, false, &[0x1b0, 0x303]), (0x1ef0, false, &[0x1af, 0x323]),
+    (0x1ef1, false, &[0x1b0, 0x323]), (0x1ef2, false, &[0x59, 0x300]), (0x1ef3, false, &[0x79, 0x300]), (0x1ef4, false, &[0x59, 0x323]),
+    (0x1ef5, false, &[0x79, 0x323]), (0x1ef6, false, &[0x59, 0x309]), (0x1ef7, false, &[0x79, 0x309]), (0x1ef8, false, &[0x59, 0x303]),
+    (0x1ef9, false, &[0x79, 0x303]), (0x1f00, false, &[0x3b1, 0x313]), (0x1f01, false, &[0x3b1, 0x314]), (0x1f02, false, &[0x1f00, 0x300]),
+    (0x1f03, false, &[0x1f01, 0x300]), (0x1f04, false, &[0x1f00, 0x301]), (0x1f05, false, &[0x1f01, 0x301]), (0x1f06, false, &[0x1f00, 0x342]),
+    (0x1f07, false, &[0x1f01, 0x342]), (0x1f08, false, &[0x391, 0x313]), (0x1f09, false, &[0x391, 0x314]), (0x1f0a, false, &[0x1f08, 0x300]),
+    (0x1f0b, false, &[0x1f09, 0x300]), (0x1f0c, false, &[0x1f08, 0x301]), (0x1f0d, false, &[0x1f09, 0x301]), (0x1f0e, false, &[0x1f08, 0x342]),
+    (0x1f0f, false, &[0x1f09, 0x342]), (0x1f10, false, &[0x3b5, 0x313]), (0x1f11, false, &[0x3b5, 0x314]), (0x1f12, false, &[0x1f10, 0x300]),
+    (0x1f13, false, &[0x1f11, 0x300]), (0x1f14, false, &[0x1f10, 0x301]), (0x1f15, false, &[0x1f11, 0x301]), (0x1f18, false, &[0x395, 0x313]),
+    (0x1f19, false, &[0x395, 0x314]), (0x1f1a, false, &[0x1f18, 0x300]), (0x1f1b, false, &[0x1f19, 0x300]), (0x1f1c, false, &[0x1f18, 0x301]),
+    (0x1f1d, false, &[0x1f19, 0x301]), (0x1f20, false, &[0x3b7, 0x313]), (0x1f21, false, &[0x3b7, 0x314]), (0x1f22, false, &[0x1f20, 0x300]),
+    (0x1f23, false, &[0x1f21, 0x300]), (0x1f24, false, &[0x1f20, 0x301]), (0x1f25, false, &[0x1f21, 0x301]), (0x1f26, false, &[0x1f20, 0x342]),
+    (0x1f27, false, &[0x1f21, 0x342]), (0x1f28, false, &[0x397, 0x313]), (0x1f29, false, &[0x397, 0x314]), (0x1f2a, false, &[0x1f28, 0x300]),
+    (0x1f2b, false, &[0x1f29, 0x300]), (0x1f2c, false, &[0x1f28, 0x301]), (0x1f2d, false, &[0x1f29, 0x301]), (0x1f2e, false, &[0x1f28, 0x342]),
+    (0x1f2f, false, &[0x1f29, 0x342]), (0x1f30, false, &[0x3b9, 0x313]), (0x1f31, false, &[0x3b9, 0x314]), (0x1f32, false, &[0x1f30, 0x300]),
+    (0x1f33, false, &[0x1f31, 0x300]), (0x1f34, false, &[0x1f30, 0x301]), (0x1f35, false, &[0x1f31, 0x301]), (0x1f36, false, &[0x1f30, 0x342]),
+    (0x1f37, false, &[0x1f31, 0x342]), (0x1f38, false, &[0x399, 0x313]), (0x1f39, false, &[0x399, 0x314]), (0x1f3a, false, &[0x1f38, 0x300]),
+    (0x1f3b, false, &[0x1f39, 0x300]), (0x1f3c, false, &[0x1f38, 0x301]), (0x1f3d, false, &[0x1f39, 0x301]), (0x1f3e, false, &[0x1f38, 0x342]),
+    (0x1f3f, false, &[0x1f39, 0x342]), (0x1f40, false, &[0x3bf, 0x313]), (0x1f41, false, &[0x3bf, 0x314]), (0x1f42, false, &[0x1f40, 0x300]),
+    (0x1f43, false, &[0x1f41, 0x300]), (0x1f44, false, &[0x1f40, 0x301]), (0x1f45, false, &[0x1f41, 0x301]), (0x1f48, false, &[0x39f, 0x313]),
+    (0x1f49, false, &[0x39f, 0x314]), (0x1f4a, false, &[0x1f48, 0x300]), (0x1f4b, false, &[0x1f49, 0x300]), (0x1f4c, false, &[0x1f48, 0x301]),
+    (0x1f4d, false, &[0x1f49, 0x301]), (0x1f50, false, &[0x3c5, 0x313]), (0x1f51, false, &[0x3c5, 0x314]), (0x1f52, false, &[0x1f50, 0x300]),
+    (0x1f53, false, &[0x1f51, 0x300]), (0x1f54, false, &[0x1f50, 0x301]), (0x1f55, false, &[0x1f51, 0x301]), (0x1f56, false, &[0x1f50, 0x342]),
+    (0x1f57, false, &[0x1f51, 0x342]), (0x1f59, false, &[0x3a5, 0x314]), (0x1f5b, false, &[0x1f59, 0x300]), (0x1f5d, false, &[0x1f59, 0x301]),
+    (0x1f5f, false, &[0x1f59, 0x342]), (0x1f60, false, &[0x3c9, 0x313]), (0x1f61, false, &[0x3c9, 0x314]), (0x1f62, false, &[0x1f60, 0x300]),
+    (0x1f63, false, &[0x1f61, 0x300]), (0x1f64, false, &[0x1f60, 0x301]), (0x1f65, false, &[0x1f61, 0x301]), (0x1f66, false, &[0x1f60, 0x342]),
+    (0x1f67, false, &[0x1f61, 0x342]), (0x1f68, false, &[0x3a9, 0x313]), (0x1f69, false, &[0x3a9, 0x314]), (0x1f6a, false, &[0x1f68, 0x300]),
+    (0x1f6b, false, &[0x1f69, 0x300]), (0x1f6c, false, &[0x1f68, 0x301]), (0x1f6d, false, &[0x1f69, 0x301]), (0x1f6e, false, &[0x1f68, 0x342]),
+    (0x1f6f, false, &[0x1f69, 0x342]), (0x1f70, false, &[0x3b1, 0x300]), (0x1f71, false, &[0x3ac]), (0x1f72, false, &[0x3b5, 0x300]),
+    (0x1f73, false, &[0x3ad]), (0x1f74, false, &[0x3b7, 0x300]), (0x1f75, false, &[0x3ae]), (0x1f76, false, &[0x3b9, 0x300]),
+    (0x1f77, false, &[0x3af]), (0x1f78, false, &[0x3bf, 0x300]), (0x1f79, false, &[0x3cc]), (0x1f7a, false, &[0x3c5, 0x300]),
+    (0x1f7b, false, &[0x3cd]), (0x1f7c, false, &[0x3c9, 0x300]), (0x1f7d, false, &[0x3ce]), (0x1f80, false, &[0x1f00, 0x345]),
+    (0x1f81, false, &[0x1f01, 0x345]), (0x1f82, false, &[0x1f02, 0x345]), (0x1f83, false, &[0x1f03, 0x345]), (0x1f84, false, &[0x1f04, 0x345]),
+    (0x1f85, false, &[0x1f05, 0x345]), (0x1f86, false, &[0x1f06, 0x345]), (0x1f87, false, &[0x1f07, 0x345]), (0x1f88, false, &[0x1f08, 0x345]),
+    (0x1f89, false, &[0x1f09, 0x345]), (0x1f8a, false, &[0x1f0a, 0x345]), (0x1f8b, false, &[0x1f0b, 0x345]), (0x1f8c, false, &[0x1f0c, 0x345]),
+    (0x1f8d, false, &[0x1f0d, 0x345]), (0x1f8e, false, &[0x1f0e, 0x345]), (0x1f8f, false, &[0x1f0f, 0x345]), (0x1f90, false, &[0x1f20, 0x345]),
+    (0x1f91, false, &[0x1f21, 0x345]), (0x1f92, false, &[0x1f22, 0x345]), (0x1f93, false, &[0x1f23, 0x345]), (0x1f94, false, &[0x1f24, 0x345]),
+    (0x1f95, false, &[0x1f25, 0x345]), (0x1f96, false, &[0x1f26, 0x345]), (0x1f97, false, &[0x1f27, 0x345]), (0x1f98, false, &[0x1f28, 0x345]),
+    (0x1f99, false, &[0x1f29, 0x345]), (0x1f9a, false, &[0x1f2a, 0x345]), (0x1f9b, false, &[0x1f2b, 0x345]), (0x1f9c, false, &[0x1f2c, 0x345]),
+    (0x1f9d, false, &[0x1f2d, 0x345]), (0x1f9e, false, &[0x1f2e, 0x345]), (0x1f9f, false, &[0x1f2f, 0x345]), (0x1fa0, false, &[0x1f60, 0x345]),
+    (0x1fa1, false, &[0x1f61, 0x345]), (0x1fa2, false, &[0x1f62, 0x345]), (0x1fa3, false, &[0x1f63, 0x345]), (0x1fa4, false, &[0x1f64, 0x345]),
+    (0x1fa5, false, &[0x1f65, 0x345]), (0x1fa6, false, &[0x1f66, 0x345]), (0x1fa7, false, &[0x1f67, 0x345]), (0x1fa8, false, &[0x1f68, 0x345]),
+    (0x1fa9, false, &[0x1f69, 0x345]), (0x1faa, false, &[0x1f6a, 0x345]), (0x1fab, false, &[0x1f6b, 0x345]), (0x1fac, false, &[0x1f6c, 0x345]),
+    (0x1fad, false, &[0x1f6d, 0x345]), (0x1fae, false, &[0x1f6e, 0x345]), (0x1faf, false, &[0x1f6f, 0x345]), (0x1fb0, false, &[0x3b1, 0x306]),
+    (0x1fb1, false, &[0x3b1, 0x304]), (0x1fb2, false, &[0x1f70, 0x345]), (0x1fb3, false, &[0x3b1, 0x345]), (0x1fb4, false, &[0x3ac, 0x345]),
+    (0x1fb6, false, &[0x3b1, 0x342]), (0x1fb7, false, &[0x1fb6, 0x345]), (0x1fb8, false, &[0x391, 0x306]), (0x1fb9, false, &[0x391, 0x304]),
+    (0x1fba, false, &[0x391, 0x300]), (0x1fbb, false, &[0x386]), (0x1fbc, false, &[0x391, 0x345]), (0x1fbd, true, &[0x20, 0x313]),
+    (0x1fbe, false, &[0x3b9]), (0x1fbf, true, &[0x20, 0x313]), (0x1fc0, true, &[0x20, 0x342]), (0x1fc1, false, &[0xa8, 0x342]),
+    (0x1fc2, false, &[0x1f74, 0x345]), (0x1fc3, false, &[0x3b7, 0x345]), (0x1fc4, false, &[0x3ae, 0x345]), (0x1fc6, false, &[0x3b7, 0x342]),
+    (0x1fc7, false, &[0x1fc6, 0x345]), (0x1fc8, false, &[0x395, 0x300]), (0x1fc9, false, &[0x388]), (0x1fca, false, &[0x397, 0x300]),
+    (0x1fcb, false, &[0x389]), (0x1fcc, false, &[0x397, 0x345]), (0x1fcd, false, &[0x1fbf, 0x300]), (0x1fce, false, &[0x1fbf, 0x301]),
+    (0x1fcf, false, &[0x1fbf, 0x342]), (0x1fd0, false, &[0x3b9, 0x306]), (0x1fd1, false, &[0x3b9, 0x304]), (0x1fd2, false, &[0x3ca, 0x300]),
+    (0x1fd3, false, &[0x390]), (0x1fd6, false, &[0x3b9, 0x342]), (0x1fd7, false, &[0x3ca, 0x342]), (0x1fd8, false, &[0x399, 0x306]),
+    (0x1fd9, false, &[0x399, 0x304]), (0x1fda, false, &[0x399, 0x300]), (0x1fdb, false, &[0x38a]), (0x1fdd, false, &[0x1ffe, 0x300]),
+    (0x1fde, false, &[0x1ffe, 0x301]), (0x1fdf, false, &[0x1ffe, 0x342]), (0x1fe0, false, &[0x3c5, 0x306]), (0x1fe1, false, &[0x3c5, 0x304]),
+    (0x1fe2, false, &[0x3cb, 0x300]), (0x1fe3, false, &[0x3b0]), (0x1fe4, false, &[0x3c1, 0x313]), (0x1fe5, false, &[0x3c1, 0x314]),
+    (0x1fe6, false, &[0x3c5, 0x342]), (0x1fe7, false, &[0x3cb, 0x342]), (0x1fe8, false, &[0x3a5, 0x306]), (0x1fe9, false, &[0x3a5, 0x304]),
+    (0x1fea, false, &[0x3a5, 0x300]), (0x1feb, false, &[0x38e]), (0x1fec, false, &[0x3a1, 0x314]), (0x1fed, false, &[0xa8, 0x300]),
+    (0x1fee, false, &[0x385]), (0x1fef, false, &[0x60]), (0x1ff2, false, &[0x1f7c, 0x345]), (0x1ff3, false, &[0x3c9, 0x345]),
+    (0x1ff4, false, &[0x3ce, 0x345]), (0x1ff6, false, &[0x3c9, 0x342]), (0x1ff7, false, &[0x1ff6, 0x345]), (0x1ff8, false, &[0x39f, 0x300]),
+    (0x1ff9, false, &[0x38c]), (0x1ffa, false, &[0x3a9, 0x300]), (0x1ffb, false, &[0x38f]), (0x1ffc, false, &[0x3a9, 0x345]),
+    (0x1ffd, false, &[0xb4]), (0x1ffe, true, &[0x20, 0x314]), (0x2000, false, &[0x2002]), (0x2001, false, &[0x2003]),
+    (0x2002, true, &[0x20]), (0x2003, true, &[0x20]), (0x2004, true, &[0x20]), (0x2005, true, &[0x20]),
+    (0x2006, true, &[0x20]), (0x2007, true, &[0x20]), (0x2008, true, &[0x20]), (0x2009, true, &[0x20]),
+    (0x200a, true, &[0x20]), (0x2011, true, &[0x2010]), (0x2017, true, &[0x20, 0x333]), (0x2024, true, &[0x2e]),
+    (0x2025, true, &[0x2e, 0x2e]), (0x2026, true, &[0x2e, 0x2e, 0x2e]), (0x202f, true, &[0x20]), (0x2033, true, &[0x2032, 0x2032]),
+    (0x2034, true, &[0x2032, 0x2032, 0x2032]), (0x2036, true, &[0x2035, 0x2035]), (0x2037, true, &[0x2035, 0x2035, 0x2035]), (0x203c, true, &[0x21, 0x21]),
+    (0x203e, true, &[0x20, 0x305]), (0x2047, true, &[0x3f, 0x3f]), (0x2048, true, &[0x3f, 0x21]), (0x2049, true, &[0x21, 0x3f]),
+    (0x2057, true, &[0x2032, 0x2032, 0x2032, 0x2032]), (0x205f, true, &[0x20]), (0x2070, true, &[0x30]), (0x2071, true, &[0x69]),
+    (0x2074, true, &[0x34]), (0x2075, true, &[0x35]), (0x2076, true, &[0x36]), (0x2077, true, &[0x37]),
+    (0x2078, true, &[0x38]), (0x2079, true, &[0x39]), (0x207a, true, &[0x2b]), (0x207b, true, &[0x2212]),
+    (0x207c, true, &[0x3d]), (0x207d, true, &[0x28]), (0x207e, true, &[0x29]), (0x207f, true, &[0x6e]),
+    (0x2080, true, &[0x30]), (0x2081, true, &[0x31]), (0x2082, true, &[0x32]), (0x2083, true, &[0x33]),
+    (0x2084, true, &[0x34]), (0x2085, true, &[0x35]), (0x2086, true, &[0x36]), (0x2087, true, &[0x37]),
+    (0x2088, true, &[0x38]), (0x2089, true, &[0x39]), (0x208a, true, &[0x2b]), (0x208b, true, &[0x2212]),
+    (0x208c, true, &[0x3d]), (0x208d, true, &[0x28]), (0x208e, true, &[0x29]), (0x2090, true, &[0x61]),
+    (0x2091, true, &[0x65]), (0x2092, true, &[0x6f]), (0x2093, true, &[0x78]), (0x2094, true, &[0x259]),
+    (0x2095, true, &[0x68]), (0x2096, true, &[0x6b]), (0x2097, true, &[0x6c]), (0x2098, true, &[0x6d]),
+    (0x2099, true, &[0x6e]), (0x209a, true, &[0x70]), (0x209b, true, &[0x73]), (0x209c, true, &[0x74]),
+    (0x20a8, true, &[0x52, 0x73]), (0x2100, true, &[0x61, 0x2f, 0x63]), (0x2101, true, &[0x61, 0x2f, 0x73]), (0x2102, true, &[0x43]),
+    (0x2103, true, &[0xb0, 0x43]), (0x2105, true, &[0x63, 0x2f, 0x6f]), (0x2106, true, &[0x63, 0x2f, 0x75]), (0x2107, true, &[0x190]),
+    (0x2109, true, &[0xb0, 0x46]), (0x210a, true, &[0x67]), (0x210b, true, &[0x48]), (0x210c, true, &[0x48]),
+    (0x210d, true, &[0x48]), (0x210e, true, &[0x68]), (0x210f, true, &[0x127]), (0x2110, true, &[0x49]),
+    (0x2111, true, &[0x49]), (0x2112, true, &[0x4c]), (0x2113, true, &[0x6c]), (0x2115, true, &[0x4e]),
+    (0x2116, true, &[0x4e, 0x6f]), (0x2119, true, &[0x50]), (0x211a, true, &[0x51]), (0x211b, true, &[0x52]),
+    (0x211c, true, &[0x52]), (0x211d, true, &[0x52]), (0x2120, true, &[0x53, 0x4d]), (0x2121, true, &[0x54, 0x45, 0x4c]),
+    (0x2122, true, &[0x54, 0x4d]), (0x2124, true, &[0x5a]), (0x2126, false, &[0x3a9]), (0x2128, true, &[0x5a]),
+    (0x212a, false, &[0x4b]), (0x212b, false, &[0xc5]), (0x212c, true, &[0x42]), (0x212d, true, &[0x43]),
+    (0x212f, true, &[0x65]), (0x2130, true, &[0x45]), (0x2131, true, &[0x46]), (0x2133, true, &[0x4d]),
+    (0x2134, true, &[0x6f]), (0x2135, true, &[0x5d0]), (0x2136, true, &[0x5d1]), (0x2137, true, &[0x5d2]),
+    (0x2138, true, &[0x5d3]), (0x2139, true, &[0x69]), (0x213b, true, &[0x46, 0x41, 0x58]), (0x213c, true, &[0x3c0]),
+    (0x213d, true, &[0x3b3]), (0x213e, true, &[0x393]), (0x213f, true, &[0x3a0]), (0x2140, true, &[0x2211]),
+    (0x2145, true, &[0x44]), (0x2146, true, &[0x64]), (0x2147, true, &[0x65]), (0x2148, true, &[0x69]),
+    (0x2149, true, &[0x6a]), (0x2150, true, &[0x31, 0x2044, 0x37]), (0x2151, true, &[0x31, 0x2044, 0x39]), (0x2152, true, &[0x31, 0x2044, 0x31, 0x30]),
+    (0x2153, true, &[0x31, 0x2044, 0x33]), (0x2154, true, &[0x32, 0x2044, 0x33]), (0x2155, true, &[0x31, 0x2044, 0x35]), (0x2156, true, &[0x32, 0x2044, 0x35]),
+    (0x2157, true, &[0x33, 0x2044, 0x35]), (0x2158, true, &[0x34, 0x2044, 0x35]), (0x2159, true, &[0x31, 0x2044, 0x36]), (0x215a, true, &[0x35, 0x2044, 0x36]),
+    (0x215b, true, &[0x31, 0x2044, 0x38]), (0x215c, true, &[0x33, 0x2044, 0x38]), (0x215d, true, &[0x35, 0x2044, 0x38]), (0x215e, true, &[0x37, 0x2044, 0x38]),
+    (0x215f, true, &[0x31, 0x2044]), (0x2160, true, &[0x49]), (0x2161, true, &[0x49, 0x49]), (0x2162, true, &[0x49, 0x49, 0x49]),
+    (0x2163, true, &[0x49, 0x56]), (0x2164, true, &[0x56]), (0x2165, true, &[0x56, 0x49]), (0x2166, true, &[0x56, 0x49, 0x49]),
+    (0x2167, true, &[0x56, 0x49, 0x49, 0x49]), (0x2168, true, &[0x49, 0x58]), (0x2169, true, &[0x58]), (0x216a, true, &[0x58, 0x49]),
+    (0x216b, true, &[0x58, 0x49, 0x49]), (0x216c, true, &[0x4c]), (0x216d, true, &[0x43]), (0x216e, true, &[0x44]),
+    (0x216f, true, &[0x4d]), (0x2170, true, &[0x69]), (0x2171, true, &[0x69, 0x69]), (0x2172, true, &[0x69, 0x69, 0x69]),
+    (0x2173, true, &[0x69, 0x76]), (0x2174, true, &[0x76]), (0x2175, true, &[0x76, 0x69]), (0x2176, true, &[0x76, 0x69, 0x69]),
+    (0x2177, true, &[0x76, 0x69, 0x69, 0x69]), (0x2178, true, &[0x69, 0x78]), (0x2179, true, &[0x78]), (0x217a, true, &[0x78, 0x69]),
+    (0x217b, true, &[0x78, 0x69, 0x69]), (0x217c, true, &[0x6c]), (0x217d, true, &[0x63]), (0x217e, true, &[0x64]),
+    (0x217f, true, &[0x6d]), (0x2189, true, &[0x30, 0x2044, 0x33]), (0x219a, false, &[0x2190, 0x338]), (0x219b, false, &[0x2192, 0x338]),
+    (0x21ae, false, &[0x2194, 0x338]), (0x21cd, false, &[0x21d0, 0x338]), (0x21ce, false, &[0x21d4, 0x338]), (0x21cf, false, &[0x21d2, 0x338]),
+    (0x2204, false, &[0x2203, 0x338]), (0x2209, false, &[0x2208, 0x338]), (0x220c, false, &[0x220b, 0x338]), (0x2224, false, &[0x2223, 0x338]),
+    (0x2226, false, &[0x2225, 0x338]), (0x222c, true, &[0x222b, 0x222b]), (0x222d, true, &[0x222b, 0x222b, 0x222b]), (0x222f, true, &[0x222e, 0x222e]),
+    (0x2230, true, &[0x222e, 0x222e, 0x222e]), (0x2241, false, &[0x223c, 0x338]), (0x2244, false, &[0x2243, 0x338]), (0x2247, false, &[0x2245, 0x338]),
+    (0x2249, false, &[0x2248, 0x338]), (0x2260, false, &[0x3d, 0x338]), (0x2262, false, &[0x2261, 0x338]), (0x226d, false, &[0x224d, 0x338]),
+    (0x226e, false, &[0x3c, 0x338]), (0x226f, false, &[0x3e, 0x338]), (0x2270, false, &[0x2264, 0x338]), (0x2271, false, &[0x2265, 0x338]),
+    (0x2274, false, &[0x2272, 0x338]), (0x2275, false, &[0x2273, 0x338]), (0x2278, false, &[0x2276, 0x338]), (0x2279, false, &[0x2277, 0x338]),
+    (0x2280, false, &[0x227a, 0x338]), (0x2281, false, &[0x227b, 0x338]), (0x2284, false, &[0x2282, 0x338]), (0x2285, false, &[0x2283, 0x338]),
+    (0x2288, false, &[0x2286, 0x338]), (0x2289, false, &[0x2287, 0x338]), (0x22ac, false, &[0x22a2, 0x338]), (0x22ad, false, &[0x22a8, 0x338]),
+    (0x22ae, false, &[0x22a9, 0x338]), (0x22af, false, &[0x22ab, 0x338]), (0x22e0, false, &[0x227c, 0x338]), (0x22e1, false, &[0x227d, 0x338]),
+    (0x22e2, false, &[0x2291, 0x338]), (0x22e3, false, &[0x2292, 0x338]), (0x22ea, false, &[0x22b2, 0x338]), (0x22eb, false, &[0x22b3, 0x338]),
+    (0x22ec, false, &[0x22b4, 0x338]), (0x22ed, false, &[0x22b5, 0x338]), (0x2329, false, &[0x3008]), (0x232a, false, &[0x3009]),
+    (0x2460, true, &[0x31]), (0x2461, true, &[0x32]), (0x2462, true, &[0x33]), (0x2463, true, &[0x34]),
+    (0x2464, true, &[0x35]), (0x2465, true, &[0x36]), (0x2466, true, &[0x37]), (0x2467, true, &[0x38]),
+    (0x2468, true, &[0x39]), (0x2469, true, &[0x31, 0x30]), (0x246a, true, &[0x31, 0x31]), (0x246b, true, &[0x31, 0x32]),
+    (0x246c, true, &[0x31, 0x33]), (0x246d, true, &[0x31, 0x34]), (0x246e, true, &[0x31, 0x35]), (0x246f, true, &[0x31, 0x36]),
+    (0x2470, true, &[0x31, 0x37]), (0x2471, true, &[0x31, 0x38]), (0x2472, true, &[0x31, 0x39]), (0x2473, true, &[0x32, 0x30]),
+    (0x2474, true, &[0x28, 0x31, 0x29]), (0x2475, true, &[0x28, 0x32, 0x29]), (0x2476, true, &[0x28, 0x33, 0x29]), (0x2477, true, &[0x28, 0x34, 0x29]),
+    (0x2478, true, &[0x28, 0x35, 0x29]), (0x2479, true, &[0x28, 0x36, 0x29]), (0x247a, true, &[0x28, 0x37, 0x29]), (0x247b, true, &[0x28, 0x38, 0x29]),
+    (0x247c, true, &[0x28, 0x39, 0x29]), (0x247d, true, &[0x28, 0x31, 0x30, 0x29]), (0x247e, true, &[0x28, 0x31, 0x31, 0x29]), (0x247f, true, &[0x28, 0x31, 0x32, 0x29]),
+    (0x2480, true, &[0x28, 0x31, 0x33, 0x29]), (0x2481, true, &[0x28, 0x31, 0x34, 0x29]), (0x2482, true, &[0x28, 0x31, 0x35, 0x29]), (0x2483, true, &[0x28, 0x31, 0x36, 0x29]),
+    (0x2484, true, &[0x28, 0x31, 0x37, 0x29]), (0x2485, true, &[0x28, 0x31, 0x38, 0x29]), (0x2486, true, &[0x28, 0x31, 0x39, 0x29]), (0x2487, true, &[0x28, 0x32, 0x30, 0x29]),
+    (0x2488, true, &[0x31, 0x2e]), (0x2489, true, &[0x32, 0x2e]), (0x248a, true, &[0x33, 0x2e]), (0x248b, true, &[0x34, 0x2e]),
+    (0x248c, true, &[0x35, 0x2e]), (0x248d, true, &[0x36, 0x2e]), (0x248e, true, &[0x37, 0x2e]), (0x248f, true, &[0x38, 0x2e]),
+    (0x2490, true, &[0x39, 0x2e]), (0x2491, true, &[0x31, 0x30, 0x2e]), (0x2492, true, &[0x31, 0x31, 0x2e]), (0x2493, true, &[0x31, 0x32, 0x2e]),
+    (0x2494, true, &[0x31, 0x33, 0x2e]), (0x2495, true, &[0x31, 0x34, 0x2e]), (0x2496, true, &[0x31, 0x35, 0x2e]), (0x2497, true, &[0x31, 0x36, 0x2e]),
+    (0x2498, true, &[0x31, 0x37, 0x2e]), (0x2499, true, &[0x31, 0x38, 0x2e]), (0x249a, true, &[0x31, 0x39, 0x2e]), (0x249b, true, &[0x32, 0x30, 0x2e]),
+    (0x249c, true, &[0x28, 0x61, 0x29]), (0x249d, true, &[0x28, 0x62, 0x29]), (0x249e, true, &[0x28, 0x63, 0x29]), (0x249f, true, &[0x28, 0x64, 0x29]),
+    (0x24a0, true, &[0x28, 0x65, 0x29]), (0x24a1, true, &[0x28, 0x66, 0x29]), (0x24a2, true, &[0x28, 0x67, 0x29]), (0x24a3, true, &[0x28, 0x68, 0x29]),
+    (0x24a4, true, &[0x28, 0x69, 0x29]), (0x24a5, true, &[0x28, 0x6a, 0x29]), (0x24a6, true, &[0x28, 0x6b, 0x29]), (0x24a7, true, &[0x28, 0x6c, 0x29]),
+    (0x24a8, true, &[0x28, 0x6d, 0x29]), (0x24a9, true, &[0x28, 0x6e, 0x29]), (0x24aa, true, &[0x28, 0x6f, 0x29]), (0x24ab, true, &[0x28, 0x70, 0x29]),
+    (0x24ac, true, &[0x28, 0x71, 0x29]), (0x24ad, true, &[0x28, 0x72, 0x29]), (0x24ae, true, &[0x28, 0x73, 0x29]), (0x24af, true, &[0x28, 0x74, 0x29]),
+    (0x24b0, true, &[0x28, 0x75, 0x29]), (0x24b1, true, &[0x28, 0x76, 0x29]), (0x24b2, true, &[0x28, 0x77, 0x29]), (0x24b3, true, &[0x28, 0x78, 0x29]),
+    (0x24b4, true, &[0x28, 0x79, 0x29]), (0x24b5, true, &[0x28, 0x7a, 0x29]), (0x24b6, true, &[0x41]), (0x24b7, true, &[0x42]),
+    (0x24b8, true, &[0x43]), (0x24b9, true, &[0x44]), (0x24ba, true, &[0x45]), (0x24bb, true, &[0x46]),
+    (0x24bc, true, &[0x47]), (0x24bd, true, &[0x48]), (0x24be, true, &[0x49]), (0x24bf, true, &[0x4a]),
+    (0x24c0, true, &[0x4b]), (0x24c1, true, &[0x4c]), (0x24c2, true, &[0x4d]), (0x24c3, true, &[0x4e]),
+    (0x24c4, true, &[0x4f]), (0x24c5, true, &[0x50]), (0x24c6, true, &[0x51]), (0x24c7, true, &[0x52]),
+    (0x24c8, true, &[0x53]), (0x24c9, true, &[0x54]), (0x24ca, true, &[0x55]), (0x24cb, true, &[0x56]),
+    (0x24cc, true, &[0x57]), (0x24cd, true, &[0x58]), (0x24ce, true, &[0x59]), (0x24cf, true, &[0x5a]),
+    (0x24d0, true, &[0x61]), (0x24d1, true, &[0x62]), (0x24d2, true, &[0x63]), (0x24d3, true, &[0x64]),
+    (0x24d4, true, &[0x65]), (0x24d5, true, &[0x66]), (0x24d6, true, &[0x67]), (0x24d7, true, &[0x68]),
+    (0x24d8, true, &[0x69]), (0x24d9, true, &[0x6a]), (0x24da, true, &[0x6b]), (0x24db, true, &[0x6c]),
+    (0x24dc, true, &[0x6d]), (0x24dd, true, &[0x6e]), (0x24de, true, &[0x6f]), (0x24df, true, &[0x70]),
+    (0x24e0, true, &[0x71]), (0x24e1, true, &[0x72]), (0x24e2, true, &[0x73]), (0x24e3, true, &[0x74]),
+    (0x24e4, true, &[0x75]), (0x24e5, true, &[0x76]), (0x24e6, true, &[0x77]), (0x24e7, true, &[0x78]),
+    (0x24e8, true, &[0x79]), (0x24e9, true, &[0x7a]), (0x24ea, true, &[0x30]), (0x2a0c, true, &[0x222b, 0x222b, 0x222b, 0x222b]),
+    (0x2a74, true, &[0x3a, 0x3a, 0x3d]), (0x2a75, true, &[0x3d, 0x3d]), (0x2a76, true, &[0x3d, 0x3d, 0x3d]), (0x2adc, false, &[0x2add, 0x338]),
+    (0x2c7c, true, &[0x6a]), (0x2c7d, true, &[0x56]), (0x2d6f, true, &[0x2d61]), (0x2e9f, true, &[0x6bcd]),
+    (0x2ef3, true, &[0x9f9f]), (0x2f00, true, &[0x4e00]), (0x2f01, true, &[0x4e28]), (0x2f02, true, &[0x4e36]),
+    (0x2f03, true, &[0x4e3f]), (0x2f04, true, &[0x4e59]), (0x2f05, true, &[0x4e85]), (0x2f06, true, &[0x4e8c]),
+    (0x2f07, true, &[0x4ea0]), (0x2f08, true, &[0x4eba]), (0x2f09, true, &[0x513f]), (0x2f0a, true, &[0x5165]),
+    (0x2f0b, true, &[0x516b]), (0x2f0c, true, &[0x5182]), (0x2f0d, true, &[0x5196]), (0x2f0e, true, &[0x51ab]),
+    (0x2f0f, true, &[0x51e0]), (0x2f10, true, &[0x51f5]), (0x2f11, true, &[0x5200]), (0x2f12, true, &[0x529b]),
+    (0x2f13, true, &[0x52f9]), (0x2f14, true, &[0x5315]), (0x2f15, true, &[0x531a]), (0x2f16, true, &[0x5338]),
+    (0x2f17, true, &[0x5341]), (0x2f18, true, &[0x535c]), (0x2f19, true, &[0x5369]), (0x2f1a, true, &[0x5382]),
+    (0x2f1b, true, &[0x53b6]), (0x2f1c, true, &[0x53c8]), (0x2f1d, true, &[0x53e3]), (0x2f1e, true, &[0x56d7]),
+    (0x2f1f, true, &[0x571f]), (0x2f20, true, &[0x58eb]), (0x2f21, true, &[0x5902]), (0x2f22, true, &[0x590a]),
+    (0x2f23, true, &[0x5915]), (0x2f24, true, &[0x5927]), (0x2f25, true, &[0x5973]), (0x2f26, true, &[0x5b50]),
+    (0x2f27, true, &[0x5b80]), (0x2f28, true, &[0x5bf8]), (0x2f29, true, &[0x5c0f]), (0x2f2a, true, &[0x5c22]),
+    (0x2f2b, true, &[0x5c38]), (0x2f2c, true, &[0x5c6e]), (0x2f2d, true, &[0x5c71]), (0x2f2e, true, &[0x5ddb]),
+    (0x2f2f, true, &[0x5de5]), (0x2f30, true, &[0x5df1]), (0x2f31, true, &[0x5dfe]), (0x2f32, true, &[0x5e72]),
+    (0x2f33, true, &[0x5e7a]), (0x2f34, true, &[0x5e7f]), (0x2f35, true, &[0x5ef4]), (0x2f36, true, &[0x5efe]),
+    (0x2f37, true, &[0x5f0b]), (0x2f38, true, &[0x5f13]), (0x2f39, true, &[0x5f50]), (0x2f3a, true, &[0x5f61]),
+    (0x2f3b, true, &[0x5f73]), (0x2f3c, true, &[0x5fc3]), (0x2f3d, true, &[0x6208]), (0x2f3e, true, &[0x6236]),
+    (0x2f3f, true, &[0x624b]), (0x2f40, true, &[0x652f]), (0x2f41, true, &[0x6534]), (0x2f42, true, &[0x6587]),
+    (0x2f43, true, &[0x6597]), (0x2f44, true, &[0x65a4]), (0x2f45, true, &[0x65b9]), (0x2f46, true, &[0x65e0]),
+    (0x2f47, true, &[0x65e5]), (0x2f48, true, &[0x66f0]), (0x2f49, true, &[0x6708]), (0x2f4a, true, &[0x6728]),
+    (0x2f4b, true, &[0x6b20]), (0x2f4c, true, &[0x6b62]), (0x2f4d, true, &[0x6b79]), (0x2f4e, true, &[0x6bb3]),
+    (0x2f4f, true, &[0x6bcb]), (0x2f50, true, &[0x6bd4]), (0x2f51, true, &[0x6bdb]), (0x2f52, true, &[0x6c0f]),
+    (0x2f53, true, &[0x6c14]), (0x2f54, true, &[0x6c34]), (0x2f55, true, &[0x706b]), (0x2f56, true, &[0x722a]),
+    (0x2f57, true, &[0x7236]), (0x2f58, true, &[0x723b]), (0x2f59, true, &[0x723f]), (0x2f5a, true, &[0x7247]),
+    (0x2f5b, true, &[0x7259]), (0x2f5c, true, &[0x725b]), (0x2f5d, true, &[0x72ac]), (0x2f5e, true, &[0x7384]),
+    (0x2f5f, true, &[0x7389]), (0x2f60, true, &[0x74dc]), (0x2f61, true, &[0x74e6]), (0x2f62, true, &[0x7518]),
+    (0x2f63, true, &[0x751f]), (0x2f64, true, &[0x7528]), (0x2f65, true, &[0x7530]), (0x2f66, true, &[0x758b]),
+    (0x2f67, true, &[0x7592]), (0x2f68, true, &[0x7676]), (0x2f69, true, &[0x767d]), (0x2f6a, true, &[0x76ae]),
+    (0x2f6b, true, &[0x76bf]), (0x2f6c, true, &[0x76ee]), (0x2f6d, true, &[0x77db]), (0x2f6e, true, &[0x77e2]),
+    (0x2f6f, true, &[0x77f3]), (0x2f70, true, &[0x793a]), (0x2f71, true, &[0x79b8]), (0x2f72, true, &[0x79be]),
+    (0x2f73, true, &[0x7a74]), (0x2f74, true, &[0x7acb]), (0x2f75, true, &[0x7af9]), (0x2f76, true, &[0x7c73]),
+    (0x2f77, true, &[0x7cf8]), (0x2f78, true, &[0x7f36]), (0x2f79, true, &[0x7f51]), (0x2f7a, true, &[0x7f8a]),
+    (0x2f7b, true, &[0x7fbd]), (0x2f7c, true, &[0x8001]), (0x2f7d, true, &[0x800c]), (0x2f7e, true, &[0x8012]),
+    (0x2f7f, true, &[0x8033]), (0x2f80, true, &[0x807f]), (0x2f81, true, &[0x8089]), (0x2f82, true, &[0x81e3]),
+    (0x2f83, true, &[0x81ea]), (0x2f84, true, &[0x81f3]), (0x2f85, true, &[0x81fc]), (0x2f86, true, &[0x820c]),
+    (0x2f87, true, &[0x821b]), (0x2f88, true, &[0x821f]), (0x2f89, true, &[0x826e]), (0x2f8a, true, &[0x8272]),
+    (0x2f8b, true, &[0x8278]), (0x2f8c, true, &[0x864d]), (0x2f8d, true, &[0x866b]), (0x2f8e, true, &[0x8840]),
+    (0x2f8f, true, &[0x884c]), (0x2f90, true, &[0x8863]), (0x2f91, true, &[0x897e]), (0x2f92, true, &[0x898b]),
+    (0x2f93, true, &[0x89d2]), (0x2f94, true, &[0x8a00]), (0x2f95, true, &[0x8c37]), (0x2f96, true, &[0x8c46]),
+    (0x2f97, true, &[0x8c55]), (0x2f98, true, &[0x8c78]), (0x2f99, true, &[0x8c9d]), (0x2f9a, true, &[0x8d64]),
+    (0x2f9b, true, &[0x8d70]), (0x2f9c, true, &[0x8db3]), (0x2f9d, true, &[0x8eab]), (0x2f9e, true, &[0x8eca]),
+    (0x2f9f, true, &[0x8f9b]), (0x2fa0, true, &[0x8fb0]), (0x2fa1, true, &[0x8fb5]), (0x2fa2, true, &[0x9091]),
+    (0x2fa3, true, &[0x9149]), (0x2fa4, true, &[0x91c6]), (0x2fa5, true, &[0x91cc]), (0x2fa6, true, &[0x91d1]),
+    (0x2fa7, true, &[0x9577]), (0x2fa8, true, &[0x9580]), (0x2fa9, true, &[0x961c]), (0x2faa, true, &[0x96b6]),
+    (0x2fab, true, &[0x96b9]), (0x2fac, true, &[0x96e8]), (0x2fad, true, &[0x9751]), (0x2fae, true, &[0x975e]),
+    (0x2faf, true, &[0x9762]), (0x2fb0, true, &[0x9769]), (0x2fb1, true, &[0x97cb]), (0x2fb2, true, &[0x97ed]),
+    (0x2fb3, true, &[0x97f3]), (0x2fb4, true, &[0x9801]), (0x2fb5, true, &[0x98a8]), (0x2fb6, true, &[0x98db]),
+    (0x2fb7, true, &[0x98df]), (0x2fb8, true, &[0x9996]), (0x2fb9, true, &[0x9999]), (0x2fba, true, &[0x99ac]),
+    (0x2fbb, true, &[0x9aa8]), (0x2fbc, true, &[0x9ad8]), (0x2fbd, true, &[0x9adf]), (0x2fbe, true, &[0x9b25]),
+    (0x2fbf, true, &[0x9b2f]), (0x2fc0, true, &[0x9b32]), (0x2fc1, true, &[0x9b3c]), (0x2fc2, true, &[0x9b5a]),
+    (0x2fc3, true, &[0x9ce5]), (0x2fc4, true, &[0x9e75]), (0x2fc5, true, &[0x9e7f]), (0x2fc6, true, &[0x9ea5]),
+    (0x2fc7, true, &[0x9ebb]), (0x2fc8, true, &[0x9ec3]), (0x2fc9, true, &[0x9ecd]), (0x2fca, true, &[0x9ed1]),
+    (0x2fcb, true, &[0x9ef9]), (0x2fcc, true, &[0x9efd]), (0x2fcd, true, &[0x9f0e]), (0x2fce, true, &[0x9f13]),
+    (0x2fcf, true, &[0x9f20]), (0x2fd0, true, &[0x9f3b]), (0x2fd1, true, &[0x9f4a]), (0x2fd2, true, &[0x9f52]),
+    (0x2fd3, true, &[0x9f8d]), (0x2fd4, true, &[0x9f9c]), (0x2fd5, true, &[0x9fa0]), (0x3000, true, &[0x20]),
+    (0x3036, true, &[0x3012]), (0x3038, true, &[0x5341]), (0x3039, true, &[0x5344]), (0x303a, true, &[0x5345]),
+    (0x304c, false, &[0x304b, 0x3099]), (0x304e, false, &[0x304d, 0x3099]), (0x3050, false, &[0x304f, 0x3099]), (0x3052, false, &[0x3051, 0x3099]),
+    (0x3054, false, &[0x3053, 0x3099]), (0x3056, false, &[0x3055, 0x3099]), (0x3058, false, &[0x3057, 0x3099]), (0x305a, false, &[0x3059, 0x3099]),
+    (0x305c, false, &[0x305b, 0x3099]), (0x305e, false, &[0x305d, 0x3099]), (0x3060, false, &[0x305f, 0x3099]), (0x3062, false, &[0x3061, 0x3099]),
+    (0x3065, false, &[0x3064, 0x3099]), (0x3067, false, &[0x3066, 0x3099]), (0x3069, false, &[0x3068, 0x3099]), (0x3070, false, &[0x306f, 0x3099]),
+    (0x3071, false, &[0x306f, 0x309a]), (0x3073, false, &[0x3072, 0x3099]), (0x3074, false, &[0x3072, 0x309a]), (0x3076, false, &[0x3075, 0x3099]),
+    (0x3077, false, &[0x3075, 0x309a]), (0x3079, false, &[0x3078, 0x3099]), (0x307a, false, &[0x3078, 0x309a]), (0x307c, false, &[0x307b, 0x3099]),
+    (0x307d, false, &[0x307b, 0x309a]), (0x3094, false, &[0x3046, 0x3099]), (0x309b, true, &[0x20, 0x3099]), (0x309c, true, &[0x20, 0x309a]),
+    (0x309e, false, &[0x309d, 0x3099]), (0x309f, true, &[0x3088, 0x308a]), (0x30ac, false, &[0x30ab, 0x3099]), (0x30ae, false, &[0x30ad, 0x3099]),
+    (0x30b0, false, &[0x30af, 0x3099]), (0x30b2, false, &[0x30b1, 0x3099]), (0x30b4, false, &[0x30b3, 0x3099]), (0x30b6, false, &[0x30b5, 0x3099]),
+    (0x30b8, false, &[0x30b7, 0x3099]), (0x30ba, false, &[0x30b9, 0x3099]), (0x30bc, false, &[0x30bb, 0x3099]), (0x30be, false, &[0x30bd, 0x3099]),
+    (0x30c0, false, &[0x30bf, 0x3099]), (0x30c2, false, &[0x30c1, 0x3099]), (0x30c5, false, &[0x30c4, 0x3099]), (0x30c7, false, &[0x30c6, 0x3099]),
+    (0x30c9, false, &[0x30c8, 0x3099]), (0x30d0, false, &[0x30cf, 0x3099]), (0x30d1, false, &[0x30cf, 0x309a]), (0x30d3, false, &[0x30d2, 0x3099]),
+    (0x30d4, false, &[0x30d2, 0x309a]), (0x30d6, false, &[0x30d5, 0x3099]), (0x30d7, false, &[0x30d5, 0x309a]), (0x30d9, false, &[0x30d8, 0x3099]),
+    (0x30da, false, &[0x30d8, 0x309a]), (0x30dc, false, &[0x30db, 0x3099]), (0x30dd, false, &[0x30db, 0x309a]), (0x30f4, false, &[0x30a6, 0x3099]),
+    (0x30f7, false, &[0x30ef, 0x3099]), (0x30f8, false, &[0x30f0, 0x3099]), (0x30f9, false, &[0x30f1, 0x3099]), (0x30fa, false, &[0x30f2, 0x3099]),
+    (0x30fe, false, &[0x30fd, 0x3099]), (0x30ff, true, &[0x30b3, 0x30c8]), (0x3131, true, &[0x1100]), (0x3132, true, &[0x1101]),
+    (0x3133, true, &[0x11aa]), (0x3134, true, &[0x1102]), (0x3135, true, &[0x11ac]), (0x3136, true, &[0x11ad]),
+    (0x3137, true, &[0x1103]), (0x3138, true, &[0x1104]), (0x3139, true, &[0x1105]), (0x313a, true, &[0x11b0]),
+    (0x313b, true, &[0x11b1]), (0x313c, true, &[0x11b2]), (0x313d, true, &[0x11b3]), (0x313e, true, &[0x11b4]),
+    (0x313f, true, &[0x11b5]), (0x3140, true, &[0x111a]), (0x3141, true, &[0x1106]), (0x3142, true, &[0x1107]),
+    (0x3143, true, &[0x1108]), (0x3144, true, &[0x1121]), (0x3145, true, &[0x1109]), (0x3146, true, &[0x110a]),
+    (0x3147, true, &[0x110b]), (0x3148, true, &[0x110c]), (0x3149, true, &[0x110d]), (0x314a, true, &[0x110e]),
+    (0x314b, true, &[0x110f]), (0x314c, true, &[0x1110]), (0x314d, true, &[0x1111]), (0x314e, true, &[0x1112]),
+    (0x314f, true, &[0x1161]), (0x3150, true, &[0x1162]), (0x3151, true, &[0x1163]), (0x3152, true, &[0x1164]),
+    (0x3153, true, &[0x1165]), (0x3154, true, &[0x1166]), (0x3155, true, &[0x1167]), (0x3156, true, &[0x1168]),
+    (0x3157, true, &[0x1169]), (0x3158, true, &[0x116a]), (0x3159, true, &[0x116b]), (0x315a, true, &[0x116c]),
+    (0x315b, true, &[0x116d]), (0x315c, true, &[0x116e]), (0x315d, true, &[0x116f]), (0x315e, true, &[0x1170]),
+    (0x315f, true, &[0x1171]), (0x3160, true, &[0x1172]), (0x3161, true, &[0x1173]), (0x3162, true, &[0x1174]),
+    (0x3163, true, &[0x1175]), (0x3164, true, &[0x1160]), (0x3165, true, &[0x1114]), (0x3166, true, &[0x1115]),
+    (0x3167, true, &[0x11c7]), (0x3168, true, &[0x11c8]), (0x3169, true, &[0x11cc]), (0x316a, true, &[0x11ce]),
+    (0x316b, true, &[0x11d3]), (0x316c, true, &[0x11d7]), (0x316d, true, &[0x11d9]), (0x316e, true, &[0x111c]),
+    (0x316f, true, &[0x11dd]), (0x3170, true, &[0x11df]), (0x3171, true, &[0x111d]), (0x3172, true, &[0x111e]),
+    (0x3173, true, &[0x1120]), (0x3174, true, &[0x1122]), (0x3175, true, &[0x1123]), (0x3176, true, &[0x1127]),
+    (0x3177, true, &[0x1129]), (0x3178, true, &[0x112b]), (0x3179, true, &[0x112c]), (0x317a, true, &[0x112d]),
+    (0x317b, true, &[0x112e]), (0x317c, true, &[0x112f]), (0x317d, true, &[0x1132]), (0x317e, true, &[0x1136]),
+    (0x317f, true, &[0x1140]), (0x3180, true, &[0x1147]), (0x3181, true, &[0x114c]), (0x3182, true, &[0x11f1]),
+    (0x3183, true, &[0x11f2]), (0x3184, true, &[0x1157]), (0x3185, true, &[0x1158]), (0x3186, true, &[0x1159]),
+    (0x3187, true, &[0x1184]), (0x3188, true, &[0x1185]), (0x3189, true, &[0x1188]), (0x318a, true, &[0x1191]),
+    (0x318b, true, &[0x1192]), (0x318c, true, &[0x1194]), (0x318d, true, &[0x119e]), (0x318e, true, &[0x11a1]),
+    (0x3192, true, &[0x4e00]), (0x3193, true, &[0x4e8c]), (0x3194, true, &[0x4e09]), (0x3195, true, &[0x56db]),
+    (0x3196, true, &[0x4e0a]), (0x3197, true, &[0x4e2d]), (0x3198, true, &[0x4e0b]), (0x3199, true, &[0x7532]),
+    (0x319a, true, &[0x4e59]), (0x319b, true, &[0x4e19]), (0x319c, true, &[0x4e01]), (0x319d, true, &[0x5929]),
+    (0x319e, true, &[0x5730]), (0x319f, true, &[0x4eba]), (0x3200, true, &[0x28, 0x1100, 0x29]), (0x3201, true, &[0x28, 0x1102, 0x29]),
+    (0x3202, true, &[0x28, 0x1103, 0x29]), (0x3203, true, &[0x28, 0x1105, 0x29]), (0x3204, true, &[0x28, 0x1106, 0x29]), (0x3205, true, &[0x28, 0x1107, 0x29]),
+    (0x3206, true, &[0x28, 0x1109, 0x29]), (0x3207, true, &[0x28, 0x110b, 0x29]), (0x3208, true, &[0x28, 0x110c, 0x29]), (0x3209, true, &[0x28, 0x110e, 0x29]),
+    (0x320a, true, &[0x28, 0x110f, 0x29]), (0x320b, true, &[0x28, 0x1110, 0x29]), (0x320c, true, &[0x28, 0x1111, 0x29]), (0x320d, true, &[0x28, 0x1112, 0x29]),
+    (0x320e, true, &[0x28, 0x1100, 0x1161, 0x29]), (0x320f, true, &[0x28, 0x1102, 0x1161, 0x29]), (0x3210, true, &[0x28, 0x1103, 0x1161, 0x29]), (0x3211, true, &[0x28, 0x1105, 0x1161, 0x29]),
+    (0x3212, true, &[0x28, 0x1106, 0x1161, 0x29]), (0x3213, true, &[0x28, 0x1107, 0x1161, 0x29]), (0x3214, true, &[0x28, 0x1109, 0x1161, 0x29]), (0x3215, true, &[0x28, 0x110b, 0x1161, 0x29]),
+    (0x3216, true, &[0x28, 0x110c, 0x1161, 0x29]), (0x3217, true, &[0x28, 0x110e, 0x1161, 0x29]), (0x3218, true, &[0x28, 0x110f, 0x1161, 0x29]), (0x3219, true, &[0x28, 0x1110, 0x1161, 0x29]),
+    (0x321a, true, &[0x28, 0x1111, 0x1161, 0x29]), (0x321b, true, &[0x28, 0x1112, 0x1161, 0x29]), (0x321c, true, &[0x28, 0x110c, 0x116e, 0x29]), (0x321d, true, &[0x28, 0x110b, 0x1169, 0x110c, 0x1165, 0x11ab, 0x29]),
+    (0x321e, true, &[0x28, 0x110b, 0x1169, 0x1112, 0x116e, 0x29]), (0x3220, true, &[0x28, 0x4e00, 0x29]), (0x3221, true, &[0x28, 0x4e8c, 0x29]), (0x3222, true, &[0x28, 0x4e09, 0x29]),
+    (0x3223, true, &[0x28, 0x56db, 0x29]), (0x3224, true, &[0x28, 0x4e94, 0x29]), (0x3225, true, &[0x28, 0x516d, 0x29]), (0x3226, true, &[0x28, 0x4e03, 0x29]),
+    (0x3227, true, &[0x28, 0x516b, 0x29]), (0x3228, true, &[0x28, 0x4e5d, 0x29]), (0x3229, true, &[0x28, 0x5341, 0x29]), (0x322a, true, &[0x28, 0x6708, 0x29]),
+    (0x322b, true, &[0x28, 0x706b, 0x29]), (0x322c, true, &[0x28, 0x6c34, 0x29]), (0x322d, true, &[0x28, 0x6728, 0x29]), (0x322e, true, &[0x28, 0x91d1, 0x29]),
+    (0x322f, true, &[0x28, 0x571f, 0x29]), (0x3230, true, &[0x28, 0x65e5, 0x29]), (0x3231, true, &[0x28, 0x682a, 0x29]), (0x3232, true, &[0x28, 0x6709, 0x29]),
+    (0x3233, true, &[0x28, 0x793e, 0x29]), (0x3234, true, &[0x28, 0x540d, 0x29]), (0x3235, true, &[0x28, 0x7279, 0x29]), (0x3236, true, &[0x28, 0x8ca1, 0x29]),
+    (0x3237, true, &[0x28, 0x795d, 0x29]), (0x3238, true, &[0x28, 0x52b4, 0x29]), (0x3239, true, &[0x28, 0x4ee3, 0x29]), (0x323a, true, &[0x28, 0x547c, 0x29]),
+    (0x323b, true, &[0x28, 0x5b66, 0x29]), (0x323c, true, &[0x28, 0x76e3, 0x29]), (0x323d, true, &[0x28, 0x4f01, 0x29]), (0x323e, true, &[0x28, 0x8cc7, 0x29]),
+    (0x323f, true, &[0x28, 0x5354, 0x29]), (0x3240, true, &[0x28, 0x796d, 0x29]), (0x3241, true, &[0x28, 0x4f11, 0x29]), (0x3242, true, &[0x28, 0x81ea, 0x29]),
+    (0x3243, true, &[0x28, 0x81f3, 0x29]), (0x3244, true, &[0x554f]), (0x3245, true, &[0x5e7c]), (0x3246, true, &[0x6587]),
+    (0x3247, true, &[0x7b8f]), (0x3250, true, &[0x50, 0x54, 0x45]), (0x3251, true, &[0x32, 0x31]), (0x3252, true, &[0x32, 0x32]),
+    (0x3253, true, &[0x32, 0x33]), (0x3254, true, &[0x32, 0x34]), (0x3255, true, &[0x32, 0x35]), (0x3256, true, &[0x32, 0x36]),
+    (0x3257, true, &[0x32, 0x37]), (0x3258, true, &[0x32, 0x38]), (0x3259, true, &[0x32, 0x39]), (0x325a, true, &[0x33, 0x30]),
+    (0x325b, true, &[0x33, 0x31]), (0x325c, true, &[0x33, 0x32]), (0x325d, true, &[0x33, 0x33]), (0x325e, true, &[0x33, 0x34]),
+    (0x325f, true, &[0x33, 0x35]), (0x3260, true, &[0x1100]), (0x3261, true, &[0x1102]), (0x3262, true, &[0x1103]),
+    (0x3263, true, &[0x1105]), (0x3264, true, &[0x1106]), (0x3265, true, &[0x1107]), (0x3266, true, &[0x1109]),
+    (0x3267, true, &[0x110b]), (0x3268, true, &[0x110c]), (0x3269, true, &[0x110e]), (0x326a, true, &[0x110f]),
+    (0x326b, true, &[0x1110]), (0x326c, true, &[0x1111]), (0x326d, true, &[0x1112]), (0x326e, true, &[0x1100, 0x1161]),
+    (0x326f, true, &[0x1102, 0x1161]), (0x3270, true, &[0x1103, 0x1161]), (0x3271, true, &[0x1105, 0x1161]), (0x3272, true, &[0x1106, 0x1161]),
+    (0x3273, true, &[0x1107, 0x1161]), (0x3274, true, &[0x1109, 0x1161]), (0x3275, true, &[0x110b, 0x1161]), (0x3276, true, &[0x110c, 0x1161]),
+    (0x3277, true, &[0x110e, 0x1161]), (0x3278, true, &[0x110f, 0x1161]), (0x3279, true, &[0x1110, 0x1161]), (0x327a, true, &[0x1111, 0x1161]),
+    (0x327b, true, &[0x1112, 0x1161]), (0x327c, true, &[0x110e, 0x1161, 0x11b7, 0x1100, 0x1169]), (0x327d, true, &[0x110c, 0x116e, 0x110b, 0x1174]), (0x327e, true, &[0x110b, 0x116e]),
+    (0x3280, true, &[0x4e00]), (0x3281, true, &[0x4e8c]), (0x3282, true, &[0x4e09]), (0x3283, true, &[0x56db]),
+    (0x3284, true, &[0x4e94]), (0x3285, true, &[0x516d]), (0x3286, true, &[0x4e03]), (0x3287, true, &[0x516b]),
+    (0x3288, true, &[0x4e5d]), (0x3289, true, &[0x5341]), (0x328a, true, &[0x6708]), (0x328b, true, &[0x706b]),
+    (0x328c, true, &[0x6c34]), (0x328d, true, &[0x6728]), (0x328e, true, &[0x91d1]), (0x328f, true, &[0x571f]),
+    (0x3290, true, &[0x65e5]), (0x3291, true, &[0x682a]), (0x3292, true, &[0x6709]), (0x3293, true, &[0x793e]),
+    (0x3294, true, &[0x540d]), (0x3295, true, &[0x7279]), (0x3296, true, &[0x8ca1]), (0x3297, true, &[0x795d]),
+    (0x3298, true, &[0x52b4]), (0x3299, true, &[0x79d8]), (0x329a, true, &[0x7537]), (0x329b, true, &[0x5973]),
+    (0x329c, true, &[0x9069]), (0x329d, true, &[0x512a]), (0x329e, true, &[0x5370]), (0x329f, true, &[0x6ce8]),
+    (0x32a0, true, &[0x9805]), (0x32a1, true, &[0x4f11]), (0x32a2, true, &[0x5199]), (0x32a3, true, &[0x6b63]),
+    (0x32a4, true, &[0x4e0a]), (0x32a5, true, &[0x4e2d]), (0x32a6, true, &[0x4e0b]), (0x32a7, true, &[0x5de6]),
+    (0x32a8, true, &[0x53f3]), (0x32a9, true, &[0x533b]), (0x32aa, true, &[0x5b97]), (0x32ab, true, &[0x5b66]),
+    (0x32ac, true, &[0x76e3]), (0x32ad, true, &[0x4f01]), (0x32ae, true, &[0x8cc7]), (0x32af, true, &[0x5354]),
+    (0x32b0, true, &[0x591c]), (0x32b1, true, &[0x33, 0x36]), (0x32b2, true, &[0x33, 0x37]), (0x32b3, true, &[0x33, 0x38]),
+    (0x32b4, true, &[0x33, 0x39]), (0x32b5, true, &[0x34, 0x30]), (0x32b6, true, &[0x34, 0x31]), (0x32b7, true, &[0x34, 0x32]),
+    (0x32b8, true, &[0x34, 0x33]), (0x32b9, true, &[0x34, 0x34]), (0x32ba, true, &[0x34, 0x35]), (0x32bb, true, &[0x34, 0x36]),
+    (0x32bc, true, &[0x34, 0x37]), (0x32bd, true, &[0x34, 0x38]), (0x32be, true, &[0x34, 0x39]), (0x32bf, true, &[0x35, 0x30]),
+    (0x32c0, true, &[0x31, 0x6708]), (0x32c1, true, &[0x32, 0x6708]), (0x32c2, true, &[0x33, 0x6708]), (0x32c3, true, &[0x34, 0x6708]),
+    (0x32c4, true, &[0x35, 0x6708]), (0x32c5, true, &[0x36, 0x6708]), (0x32c6, true, &[0x37, 0x6708]), (0x32c7, true, &[0x38, 0x6708]),
+    (0x32c8, true, &[0x39, 0x6708]), (0x32c9, true, &[0x31, 0x30, 0x6708]), (0x32ca, true, &[0x31, 0x31, 0x6708]), (0x32cb, true, &[0x31, 0x32, 0x6708]),
+    (0x32cc, true, &[0x48, 0x67]), (0x32cd, true, &[0x65, 0x72, 0x67]), (0x32ce, true, &[0x65, 0x56]), (0x32cf, true, &[0x4c, 0x54, 0x44]),
+    (0x32d0, true, &[0x30a2]), (0x32d1, true, &[0x30a4]), (0x32d2, true, &[0x30a6]), (0x32d3, true, &[0x30a8]),
+    (0x32d4, true, &[0x30aa]), (0x32d5, true, &[0x30ab]), (0x32d6, true, &[0x30ad]), (0x32d7, true, &[0x30af]),
+    (0x32d8, true, &[0x30b1]), (0x32d9, true, &[0x30b3]), (0x32da, true, &[0x30b5]), (0x32db, true, &[0x30b7]),
+    (0x32dc, true, &[0x30b9]), (0x32dd, true, &[0x30bb]), (0x32de, true, &[0x30bd]), (0x32df, true, &[0x30bf]),
+    (0x32e0, true, &[0x30c1]), (0x32e1, true, &[0x30c4]), (0x32e2, true, &[0x30c6]), (0x32e3, true, &[0x30c8]),
+    (0x32e4, true, &[0x30ca]), (0x32e5, true, &[0x30cb]), (0x32e6, true, &[0x30cc]), (0x32e7, true, &[0x30cd]),
+    (0x32e8, true, &[0x30ce]), (0x32e9, true, &[0x30cf]), (0x32ea, true, &[0x30d2]), (0x32eb, true, &[0x30d5]),
+    (0x32ec, true, &[0x30d8]), (0x32ed, true, &[0x30db]), (0x32ee, true, &[0x30de]), (0x32ef, true, &[0x30df]),
+    (0x32f0, true, &[0x30e0]), (0x32f1, true, &[0x30e1]), (0x32f2, true, &[0x30e2]), (0x32f3, true, &[0x30e4]),
+    (0x32f4, true, &[0x30e6]), (0x32f5, true, &[0x30e8]), (0x32f6, true, &[0x30e9]), (0x32f7, true, &[0x30ea]),
+    (0x32f8, true, &[0x30eb]), (0x32f9, true, &[0x30ec]), (0x32fa, true, &[0x30ed]), (0x32fb, true, &[0x30ef]),
+    (0x32fc, true, &[0x30f0]), (0x32fd, true, &[0x30f1]), (0x32fe, true, &[0x30f2]), (0x32ff, true, &[0x4ee4, 0x548c]),
+    (0x3300, true, &[0x30a2, 0x30d1, 0x30fc, 0x30c8]), (0x3301, true, &[0x30a2, 0x30eb, 0x30d5, 0x30a1]), (0x3302, true, &[0x30a2, 0x30f3, 0x30da, 0x30a2]), (0x3303, true, &[0x30a2, 0x30fc, 0x30eb]),
+    (0x3304, true, &[0x30a4, 0x30cb, 0x30f3, 0x30b0]), (0x3305, true, &[0x30a4, 0x30f3, 0x30c1]), (0x3306, true, &[0x30a6, 0x30a9, 0x30f3]), (0x3307, true, &[0x30a8, 0x30b9, 0x30af, 0x30fc, 0x30c9]),
+    (0x3308, true, &[0x30a8, 0x30fc, 0x30ab, 0x30fc]), (0x3309, true, &[0x30aa, 0x30f3, 0x30b9]), (0x330a, true, &[0x30aa, 0x30fc, 0x30e0]), (0x330b, true, &[0x30ab, 0x30a4, 0x30ea]),
+    (0x330c, true, &[0x30ab, 0x30e9, 0x30c3, 0x30c8]), (0x330d, true, &[0x30ab, 0x30ed, 0x30ea, 0x30fc]), (0x330e, true, &[0x30ac, 0x30ed, 0x30f3]), (0x330f, true, &[0x30ac, 0x30f3, 0x30de]),
+    (0x3310, true, &[0x30ae, 0x30ac]), (0x3311, true, &[0x30ae, 0x30cb, 0x30fc]), (0x3312, true, &[0x30ad, 0x30e5, 0x30ea, 0x30fc]), (0x3313, true, &[0x30ae, 0x30eb, 0x30c0, 0x30fc]),
+    (0x3314, true, &[0x30ad, 0x30ed]), (0x3315, true, &[0x30ad, 0x30ed, 0x30b0, 0x30e9, 0x30e0]), (0x3316, true, &[0x30ad, 0x30ed, 0x30e1, 0x30fc, 0x30c8, 0x30eb]), (0x3317, true, &[0x30ad, 0x30ed, 0x30ef, 0x30c3, 0x30c8]),
+    (0x3318, true, &[0x30b0, 0x30e9, 0x30e0]), (0x3319, true, &[0x30b0, 0x30e9, 0x30e0, 0x30c8, 0x30f3]), (0x331a, true, &[0x30af, 0x30eb, 0x30bc, 0x30a4, 0x30ed]), (0x331b, true, &[0x30af, 0x30ed, 0x30fc, 0x30cd]),
+    (0x331c, true, &[0x30b1, 0x30fc, 0x30b9]), (0x331d, true, &[0x30b3, 0x30eb, 0x30ca]), (0x331e, true, &[0x30b3, 0x30fc, 0x30dd]), (0x331f, true, &[0x30b5, 0x30a4, 0x30af, 0x30eb]),
+    (0x3320, true, &[0x30b5, 0x30f3, 0x30c1, 0x30fc, 0x30e0]), (0x3321, true, &[0x30b7, 0x30ea, 0x30f3, 0x30b0]), (0x3322, true, &[0x30bb, 0x30f3, 0x30c1]), (0x3323, true, &[0x30bb, 0x30f3, 0x30c8]),
+    (0x3324, true, &[0x30c0, 0x30fc, 0x30b9]), (0x3325, true, &[0x30c7, 0x30b7]), (0x3326, true, &[0x30c9, 0x30eb]), (0x3327, true, &[0x30c8, 0x30f3]),
+    (0x3328, true, &[0x30ca, 0x30ce]), (0x3329, true, &[0x30ce, 0x30c3, 0x30c8]), (0x332a, true, &[0x30cf, 0x30a4, 0x30c4]), (0x332b, true, &[0x30d1, 0x30fc, 0x30bb, 0x30f3, 0x30c8]),
+    (0x332c, true, &[0x30d1, 0x30fc, 0x30c4]), (0x332d, true, &[0x30d0, 0x30fc, 0x30ec, 0x30eb]), (0x332e, true, &[0x30d4, 0x30a2, 0x30b9, 0x30c8, 0x30eb]), (0x332f, true, &[0x30d4, 0x30af, 0x30eb]),
+    (0x3330, true, &[0x30d4, 0x30b3]), (0x3331, true, &[0x30d3, 0x30eb]), (0x3332, true, &[0x30d5, 0x30a1, 0x30e9, 0x30c3, 0x30c9]), (0x3333, true, &[0x30d5, 0x30a3, 0x30fc, 0x30c8]),
+    (0x3334, true, &[0x30d6, 0x30c3, 0x30b7, 0x30a7, 0x30eb]), (0x3335, true, &[0x30d5, 0x30e9, 0x30f3]), (0x3336, true, &[0x30d8, 0x30af, 0x30bf, 0x30fc, 0x30eb]), (0x3337, true, &[0x30da, 0x30bd]),
+    (0x3338, true, &[0x30da, 0x30cb, 0x30d2]), (0x3339, true, &[0x30d8, 0x30eb, 0x30c4]), (0x333a, true, &[0x30da, 0x30f3, 0x30b9]), (0x333b, true, &[0x30da, 0x30fc, 0x30b8]),
+    (0x333c, true, &[0x30d9, 0x30fc, 0x30bf]), (0x333d, true, &[0x30dd, 0x30a4, 0x30f3, 0x30c8]), (0x333e, true, &[0x30dc, 0x30eb, 0x30c8]), (0x333f, true, &[0x30db, 0x30f3]),
+    (0x3340, true, &[0x30dd, 0x30f3, 0x30c9]), (0x3341, true, &[0x30db, 0x30fc, 0x30eb]), (0x3342, true, &[0x30db, 0x30fc, 0x30f3]), (0x3343, true, &[0x30de, 0x30a4, 0x30af, 0x30ed]),
+    (0x3344, true, &[0x30de, 0x30a4, 0x30eb]), (0x3345, true, &[0x30de, 0x30c3, 0x30cf]), (0x3346, true, &[0x30de, 0x30eb, 0x30af]), (0x3347, true, &[0x30de, 0x30f3, 0x30b7, 0x30e7, 0x30f3]),
+    (0x3348, true, &[0x30df, 0x30af, 0x30ed, 0x30f3]), (0x3349, true, &[0x30df, 0x30ea]), (0x334a, true, &[0x30df, 0x30ea, 0x30d0, 0x30fc, 0x30eb]), (0x334b, true, &[0x30e1, 0x30ac]),
+    (0x334c, true, &[0x30e1, 0x30ac, 0x30c8, 0x30f3]), (0x334d, true, &[0x30e1, 0x30fc, 0x30c8, 0x30eb]), (0x334e, true, &[0x30e4, 0x30fc, 0x30c9]), (0x334f, true, &[0x30e4, 0x30fc, 0x30eb]),
+    (0x3350, true, &[0x30e6, 0x30a2, 0x30f3]), (0x3351, true, &[0x30ea, 0x30c3, 0x30c8, 0x30eb]), (0x3352, true, &[0x30ea, 0x30e9]), (0x3353, true, &[0x30eb, 0x30d4, 0x30fc]),
+    (0x3354, true, &[0x30eb, 0x30fc, 0x30d6, 0x30eb]), (0x3355, true, &[0x30ec, 0x30e0]), (0x3356, true, &[0x30ec, 0x30f3, 0x30c8, 0x30b2, 0x30f3]), (0x3357, true, &[0x30ef, 0x30c3, 0x30c8]),
+    (0x3358, true, &[0x30, 0x70b9]), (0x3359, true, &[0x31, 0x70b9]), (0x335a, true, &[0x32, 0x70b9]), (0x335b, true, &[0x33, 0x70b9]),
+    (0x335c, true, &[0x34, 0x70b9]), (0x335d, true, &[0x35, 0x70b9]), (0x335e, true, &[0x36, 0x70b9]), (0x335f, true, &[0x37, 0x70b9]),
+    (0x3360, true, &[0x38, 0x70b9]), (0x3361, true, &[0x39, 0x70b9]), (0x3362, true, &[0x31, 0x30, 0x70b9]), (0x3363, true, &[0x31, 0x31, 0x70b9]),
+    (0x3364, true, &[0x31, 0x32, 0x70b9]), (0x3365, true, &[0x31, 0x33, 0x70b9]), (0x3366, true, &[0x31, 0x34, 0x70b9]), (0x3367, true, &[0x31, 0x35, 0x70b9]),
+    (0x3368, true, &[0x31, 0x36, 0x70b9]), (0x3369, true, &[0x31, 0x37, 0x70b9]), (0x336a, true, &[0x31, 0x38, 0x70b9]), (0x336b, true, &[0x31, 0x39, 0x70b9]),
+    (0x336c, true, &[0x32, 0x30, 0x70b9]), (0x336d, true, &[0x32, 0x31, 0x70b9]), (0x336e, true, &[0x32, 0x32, 0x70b9]), (0x336f, true, &[0x32, 0x33, 0x70b9]),
+    (0x3370, true, &[0x32, 0x34, 0x70b9]), (0x3371, true, &[0x68, 0x50, 0x61]), (0x3372, true, &[0x64, 0x61]), (0x3373, true, &[0x41, 0x55]),
+    (0x3374, true, &[0x62, 0x61, 0x72]), (0x3375, true, &[0x6f, 0x56]), (0x3376, true, &[0x70, 0x63]), (0x3377, true, &[0x64, 0x6d]),
+    (0x3378, true, &[0x64, 0x6d, 0xb2]), (0x3379, true, &[0x64, 0x6d, 0xb3]), (0x337a, true, &[0x49, 0x55]), (0x337b, true, &[0x5e73, 0x6210]),
+    (0x337c, true, &[0x662d, 0x548c]), (0x337d, true, &[0x5927, 0x6b63]), (0x337e, true, &[0x660e, 0x6cbb]), (0x337f, true, &[0x682a, 0x5f0f, 0x4f1a, 0x793e]),
+    (0x3380, true, &[0x70, 0x41]), (0x3381, true, &[0x6e, 0x41]), (0x3382, true, &[0x3bc, 0x41]), (0x3383, true, &[0x6d, 0x41]),
+    (0x3384, true, &[0x6b, 0x41]), (0x3385, true, &[0x4b, 0x42]), (0x3386, true, &[0x4d, 0x42]), (0x3387, true, &[0x47, 0x42]),
+    (0x3388, true, &[0x63, 0x61, 0x6c]), (0x3389, true, &[0x6b, 0x63, 0x61, 0x6c]), (0x338a, true, &[0x70, 0x46]), (0x338b, true, &[0x6e, 0x46]),
+    (0x338c, true, &[0x3bc, 0x46]), (0x338d, true, &[0x3bc, 0x67]), (0x338e, true, &[0x6d, 0x67]), (0x338f, true, &[0x6b, 0x67]),
+    (0x3390, true, &[0x48, 0x7a]), (0x3391, true, &[0x6b, 0x48, 0x7a]), (0x3392, true, &[0x4d, 0x48, 0x7a]), (0x3393, true, &[0x47, 0x48, 0x7a]),
+    (0x3394, true, &[0x54, 0x48, 0x7a]), (0x3395, true, &[0x3bc, 0x2113]), (0x3396, true, &[0x6d, 0x2113]), (0x3397, true, &[0x64, 0x2113]),
+    (0x3398, true, &[0x6b, 0x2113]), (0x3399, true, &[0x66, 0x6d]), (0x339a, true, &[0x6e, 0x6d]), (0x339b, true, &[0x3bc, 0x6d]),
+    (0x339c, true, &[0x6d, 0x6d]), (0x339d, true, &[0x63, 0x6d]), (0x339e, true, &[0x6b, 0x6d]), (0x339f, true, &[0x6d, 0x6d, 0xb2]),
+    (0x33a0, true, &[0x63, 0x6d, 0xb2]), (0x33a1, true, &[0x6d, 0xb2]), (0x33a2, true, &[0x6b, 0x6d, 0xb2]), (0x33a3, true, &[0x6d, 0x6d, 0xb3]),
+    (0x33a4, true, &[0x63, 0x6d, 0xb3]), (0x33a5, true, &[0x6d, 0xb3]), (0x33a6, true, &[0x6b, 0x6d, 0xb3]), (0x33a7, true, &[0x6d, 0x2215, 0x73]),
+    (0x33a8, true, &[0x6d, 0x2215, 0x73, 0xb2]), (0x33a9, true, &[0x50, 0x61]), (0x33aa, true, &[0x6b, 0x50, 0x61]), (0x33ab, true, &[0x4d, 0x50, 0x61]),
+    (0x33ac, true, &[0x47, 0x50, 0x61]), (0x33ad, true, &[0x72, 0x61, 0x64]), (0x33ae, true, &[0x72, 0x61, 0x64, 0x2215, 0x73]), (0x33af, true, &[0x72, 0x61, 0x64, 0x2215, 0x73, 0xb2]),
+    (0x33b0, true, &[0x70, 0x73]), (0x33b1, true, &[0x6e, 0x73]), (0x33b2, true, &[0x3bc, 0x73]), (0x33b3, true, &[0x6d, 0x73]),
+    (0x33b4, true, &[0x70, 0x56]), (0x33b5, true, &[0x6e, 0x56]), (0x33b6, true, &[0x3bc, 0x56]), (0x33b7, true, &[0x6d, 0x56]),
+    (0x33b8, true, &[0x6b, 0x56]), (0x33b9, true, &[0x4d, 0x56]), (0x33ba, true, &[0x70, 0x57]), (0x33bb, true, &[0x6e, 0x57]),
+    (0x33bc, true, &[0x3bc, 0x57]), (0x33bd, true, &[0x6d, 0x57]), (0x33be, true, &[0x6b, 0x57]), (0x33bf, true, &[0x4d, 0x57]),
+    (0x33c0, true, &[0x6b, 0x3a9]), (0x33c1, true, &[0x4d, 0x3a9]), (0x33c2, true, &[0x61, 0x2e, 0x6d, 0x2e]), (0x33c3, true, &[0x42, 0x71]),
+    (0x33c4, true, &[0x63, 0x63]), (0x33c5, true, &[0x63, 0x64]), (0x33c6, true, &[0x43, 0x2215, 0x6b, 0x67]), (0x33c7, true, &[0x43, 0x6f, 0x2e]),
+    (0x33c8, true, &[0x64, 0x42]), (0x33c9, true, &[0x47, 0x79]), (0x33ca, true, &[0x68, 0x61]), (0x33cb, true, &[0x48, 0x50]),
+    (0x33cc, true, &[0x69, 0x6e]), (0x33cd, true, &[0x4b, 0x4b]), (0x33ce, true, &[0x4b, 0x4d]), (0x33cf, true, &[0x6b, 0x74]),
+    (0x33d0, true, &[0x6c, 0x6d]), (0x33d1, true, &[0x6c, 0x6e]), (0x33d2, true, &[0x6c, 0x6f, 0x67]), (0x33d3, true, &[0x6c, 0x78]),
+    (0x33d4, true, &[0x6d, 0x62]), (0x33d5, true, &[0x6d, 0x69, 0x6c]), (0x33d6, true, &[0x6d, 0x6f, 0x6c]), (0x33d7, true, &[0x50, 0x48]),
+    (0x33d8, true, &[0x70, 0x2e, 0x6d, 0x2e]), (0x33d9, true, &[0x50, 0x50, 0x4d]), (0x33da, true, &[0x50, 0x52]), (0x33db, true, &[0x73, 0x72]),
+    (0x33dc, true, &[0x53, 0x76]), (0x33dd, true, &[0x57, 0x62]), (0x33de, true, &[0x56, 0x2215, 0x6d]), (0x33df, true, &[0x41, 0x2215, 0x6d]),
+    (0x33e0, true, &[0x31, 0x65e5]), (0x33e1, true, &[0x32, 0x65e5]), (0x33e2, true, &[0x33, 0x65e5]), (0x33e3, true, &[0x34, 0x65e5]),
+    (0x33e4, true, &[0x35, 0x65e5]), (0x33e5, true, &[0x36, 0x65e5]), (0x33e6, true, &[0x37, 0x65e5]), (0x33e7, true, &[0x38, 0x65e5]),
+    (0x33e8, true, &[0x39, 0x65e5]), (0x33e9, true, &[0x31, 0x30, 0x65e5]), (0x33ea, true, &[0x31, 0x31, 0x65e5]), (0x33eb, true, &[0x31, 0x32, 0x65e5]),
+    (0x33ec, true, &[0x31, 0x33, 0x65e5]), (0x33ed, true, &[0x31, 0x34, 0x65e5]), (0x33ee, true, &[0x31, 0x35, 0x65e5]), (0x33ef, true, &[0x31, 0x36, 0x65e5]),
+    (0x33f0, true, &[0x31, 0x37, 0x65e5]), (0x33f1, true, &[0x31, 0x38, 0x65e5]), (0x33f2, true, &[0x31, 0x39, 0x65e5]), (0x33f3, true, &[0x32, 0x30, 0x65e5]),
+    (0x33f4, true, &[0x32, 0x31, 0x65e5]), (0x33f5, true, &[0x32, 0x32, 0x65e5]), (0x33f6, true, &[0x32, 0x33, 0x65e5]), (0x33f7, true, &[0x32, 0x34, 0x65e5]),
+    (0x33f8, true, &[0x32, 0x35, 0x65e5]), (0x33f9, true, &[0x32, 0x36, 0x65e5]), (0x33fa, true, &[0x32, 0x37, 0x65e5]), (0x33fb, true, &[0x32, 0x38, 0x65e5]),
+    (0x33fc, true, &[0x32, 0x39, 0x65e5]), (0x33fd, true, &[0x33, 0x30, 0x65e5]), (0x33fe, true, &[0x33, 0x31, 0x65e5]), (0x33ff, true, &[0x67, 0x61, 0x6c]),
+    (0xa69c, true, &[0x44a]), (0xa69d, true, &[0x44c]), (0xa770, true, &[0xa76f]), (0xa7f2, true, &[0x43]),
+    (0xa7f3, true, &[0x46]), (0xa7f4, true, &[0x51]), (0xa7f8, true, &[0x126]), (0xa7f9, true, &[0x153]),
+    (0xab5c, true, &[0xa727]), (0xab5d, true, &[0xab37]), (0xab5e, true, &[0x26b]), (0xab5f, true, &[0xab52]),
+    (0xab69, true, &[0x28d]), (0xf900, false, &[0x8c48]), (0xf901, false, &[0x66f4]), (0xf902, false, &[0x8eca]),
+    (0xf903, false, &[0x8cc8]), (0xf904, false, &[0x6ed1]), (0xf905, false, &[0x4e32]), (0xf906, false, &[0x53e5]),
+    (0xf907, false, &[0x9f9c]), (0xf908, false, &[0x9f9c]), (0xf909, false, &[0x5951]), (0xf90a, false, &[0x91d1]),
+    (0xf90b, false, &[0x5587]), (0xf90c, false, &[0x5948]), (0xf90d, false, &[0x61f6]), (0xf90e, false, &[0x7669]),
+    (0xf90f, false, &[0x7f85]), (0xf910, false, &[0x863f]), (0xf911, false, &[0x87ba]), (0xf912, false, &[0x88f8]),
+    (0xf913, false, &[0x908f]), (0xf914, false, &[0x6a02]), (0xf915, false, &[0x6d1b]), (0xf916, false, &[0x70d9]),
+    (0xf917, false, &[0x73de]), (0xf918, false, &[0x843d]), (0xf919, false, &[0x916a]), (0xf91a, false, &[0x99f1]),
+    (0xf91b, false, &[0x4e82]), (0xf91c, false, &[0x5375]), (0xf91d, false, &[0x6b04]), (0xf91e, false, &[0x721b]),
+    (0xf91f, false, &[0x862d]), (0xf920, false, &[0x9e1e]), (0xf921, false, &[0x5d50]), (0xf922, false, &[0x6feb]),
+    (0xf923, false, &[0x85cd]), (0xf924, false, &[0x8964]), (0xf925, false, &[0x62c9]), (0xf926, false, &[0x81d8]),
+    (0xf927, false, &[0x881f]), (0xf928, false, &[0x5eca]), (0xf929, false, &[0x6717]), (0xf92a, false, &[0x6d6a]),
+    (0xf92b, false, &[0x72fc]), (0xf92c, false, &[0x90ce]), (0xf92d, false, &[0x4f86]), (0xf92e, false, &[0x51b7]),
+    (0xf92f, false, &[0x52de]), (0xf930, false, &[0x64c4]), (0xf931, false, &[0x6ad3]), (0xf932, false, &[0x7210]),
+    (0xf933, false, &[0x76e7]), (0xf934, false, &[0x8001]), (0xf935, false, &[0x8606]), (0xf936, false, &[0x865c]),
+    (0xf937, false, &[0x8def]), (0xf938, false, &[0x9732]), (0xf939, false, &[0x9b6f]), (0xf93a, false, &[0x9dfa]),
+    (0xf93b, false, &[0x788c]), (0xf93c, false, &[0x797f]), (0xf93d, false, &[0x7da0]), (0xf93e, false, &[0x83c9]),
+    (0xf93f, false, &[0x9304]), (0xf940, false, &[0x9e7f]), (0xf941, false, &[0x8ad6]), (0xf942, false, &[0x58df]),
+    (0xf943, false, &[0x5f04]), (0xf944, false, &[0x7c60]), (0xf945, false, &[0x807e]), (0xf946, false, &[0x7262]),
+    (0xf947, false, &[0x78ca]), (0xf948, false, &[0x8cc2]), (0xf949, false, &[0x96f7]), (0xf94a, false, &[0x58d8]),
+    (0xf94b, false, &[0x5c62]), (0xf94c, false, &[0x6a13]), (0xf94d, false, &[0x6dda]), (0xf94e, false, &[0x6f0f]),
+    (0xf94f, false, &[0x7d2f]), (0xf950, false, &[0x7e37]), (0xf951, false, &[0x964b]), (0xf952, false, &[0x52d2]),
+    (0xf953, false, &[0x808b]), (0xf954, false, &[0x51dc]), (0xf955, false, &[0x51cc]), (0xf956, false, &[0x7a1c]),
+    (0xf957, false, &[0x7dbe]), (0xf958, false, &[0x83f1]), (0xf959, false, &[0x9675]), (0xf95a, false, &[0x8b80]),
+    (0xf95b, false, &[0x62cf]), (0xf95c, false, &[0x6a02]), (0xf95d, false, &[0x8afe]), (0xf95e, false, &[0x4e39]),
+    (0xf95f, false, &[0x5be7]), (0xf960, false, &[0x6012]), (0xf961, false, &[0x7387]), (0xf962, false, &[0x7570]),
+    (0xf963, false, &[0x5317]), (0xf964, false, &[0x78fb]), (0xf965, false, &[0x4fbf]), (0xf966, false, &[0x5fa9]),
+    (0xf967, false, &[0x4e0d]), (0xf968, false, &[0x6ccc]), (0xf969, false, &[0x6578]), (0xf96a, false, &[0x7d22]),
+    (0xf96b, false, &[0x53c3]), (0xf96c, false, &[0x585e]), (0xf96d, false, &[0x7701]), (0xf96e, false, &[0x8449]),
+    (0xf96f, false, &[0x8aaa]), (0xf970, false, &[0x6bba]), (0xf971, false, &[0x8fb0]), (0xf972, false, &[0x6c88]),
+    (0xf973, false, &[0x62fe]), (0xf974, false, &[0x82e5]), (0xf975, false, &[0x63a0]), (0xf976, false, &[0x7565]),
+    (0xf977, false, &[0x4eae]), (0xf978, false, &[0x5169]), (0xf979, false, &[0x51c9]), (0xf97a, false, &[0x6881]),
+    (0xf97b, false, &[0x7ce7]), (0xf97c, false, &[0x826f]), (0xf97d, false, &[0x8ad2]), (0xf97e, false, &[0x91cf]),
+    (0xf97f, false, &[0x52f5]), (0xf980, false, &[0x5442]), (0xf981, false, &[0x5973]), (0xf982, false, &[0x5eec]),
+    (0xf983, false, &[0x65c5]), (0xf984, false, &[0x6ffe]), (0xf985, false, &[0x792a]), (0xf986, false, &[0x95ad]),
+    (0xf987, false, &[0x9a6a]), (0xf988, false, &[0x9e97]), (0xf989, false, &[0x9ece]), (0xf98a, false, &[0x529b]),
+    (0xf98b, false, &[0x66c6]), (0xf98c, false, &[0x6b77]), (0xf98d, false, &[0x8f62]), (0xf98e, false, &[0x5e74]),
+    (0xf98f, false, &[0x6190]), (0xf990, false, &[0x6200]), (0xf991, false, &[0x649a]), (0xf992, false, &[0x6f23]),
+    (0xf993, false, &[0x7149]), (0xf994, false, &[0x7489]), (0xf995, false, &[0x79ca]), (0xf996, false, &[0x7df4]),
+    (0xf997, false, &[0x806f]), (0xf998, false, &[0x8f26]), (0xf999, false, &[0x84ee]), (0xf99a, false, &[0x9023]),
+    (0xf99b, false, &[0x934a]), (0xf99c, false, &[0x5217]), (0xf99d, false, &[0x52a3]), (0xf99e, false, &[0x54bd]),
+    (0xf99f, false, &[0x70c8]), (0xf9a0, false, &[0x88c2]), (0xf9a1, false, &[0x8aaa]), (0xf9a2, false, &[0x5ec9]),
+    (0xf9a3, false, &[0x5ff5]), (0xf9a4, false, &[0x637b]), (0xf9a5, false, &[0x6bae]), (0xf9a6, false, &[0x7c3e]),
+    (0xf9a7, false, &[0x7375]), (0xf9a8, false, &[0x4ee4]), (0xf9a9, false, &[0x56f9]), (0xf9aa, false, &[0x5be7]),
+    (0xf9ab, false, &[0x5dba]), (0xf9ac, false, &[0x601c]), (0xf9ad, false, &[0x73b2]), (0xf9ae, false, &[0x7469]),
+    (0xf9af, false, &[0x7f9a]), (0xf9b0, false, &[0x8046]), (0xf9b1, false, &[0x9234]), (0xf9b2, false, &[0x96f6]),
+    (0xf9b3, false, &[0x9748]), (0xf9b4, false, &[0x9818]), (0xf9b5, false, &[0x4f8b]), (0xf9b6, false, &[0x79ae]),
+    (0xf9b7, false, &[0x91b4]), (0xf9b8, false, &[0x96b8]), (0xf9b9, false, &[0x60e1]), (0xf9ba, false, &[0x4e86]),
+    (0xf9bb, false, &[0x50da]), (0xf9bc, false, &[0x5bee]), (0xf9bd, false, &[0x5c3f]), (0xf9be, false, &[0x6599]),
+    (0xf9bf, false, &[0x6a02]), (0xf9c0, false, &[0x71ce]), (0xf9c1, false, &[0x7642]), (0xf9c2, false, &[0x84fc]),
+    (0xf9c3, false, &[0x907c]), (0xf9c4, false, &[0x9f8d]), (0xf9c5, false, &[0x6688]), (0xf9c6, false, &[0x962e]),
+    (0xf9c7, false, &[0x5289]), (0xf9c8, false, &[0x677b]), (0xf9c9, false, &[0x67f3]), (0xf9ca, false, &[0x6d41]),
+    (0xf9cb, false, &[0x6e9c]), (0xf9cc, false, &[0x7409]), (0xf9cd, false, &[0x7559]), (0xf9ce, false, &[0x786b]),
+    (0xf9cf, false, &[0x7d10]), (0xf9d0, false, &[0x985e]), (0xf9d1, false, &[0x516d]), (0xf9d2, false, &[0x622e]),
+    (0xf9d3, false, &[0x9678]), (0xf9d4, false, &[0x502b]), (0xf9d5, false, &[0x5d19]), (0xf9d6, false, &[0x6dea]),
+    (0xf9d7, false, &[0x8f2a]), (0xf9d8, false, &[0x5f8b]), (0xf9d9, false, &[0x6144]), (0xf9da, false, &[0x6817]),
+    (0xf9db, false, &[0x7387]), (0xf9dc, false, &[0x9686]), (0xf9dd, false, &[0x5229]), (0xf9de, false, &[0x540f]),
+    (0xf9df, false, &[0x5c65]), (0xf9e0, false, &[0x6613]), (0xf9e1, false, &[0x674e]), (0xf9e2, false, &[0x68a8]),
+    (0xf9e3, false, &[0x6ce5]), (0xf9e4, false, &[0x7406]), (0xf9e5, false, &[0x75e2]), (0xf9e6, false, &[0x7f79]),
+    (0xf9e7, false, &[0x88cf]), (0xf9e8, false, &[0x88e1]), (0xf9e9, false, &[0x91cc]), (0xf9ea, false, &[0x96e2]),
+    (0xf9eb, false, &[0x533f]), (0xf9ec, false, &[0x6eba]), (0xf9ed, false, &[0x541d]), (0xf9ee, false, &[0x71d0]),
+    (0xf9ef, false, &[0x7498]), (0xf9f0, false, &[0x85fa]), (0xf9f1, false, &[0x96a3]), (0xf9f2, false, &[0x9c57]),
+    (0xf9f3, false, &[0x9e9f]), (0xf9f4, false, &[0x6797]), (0xf9f5, false, &[0x6dcb]), (0xf9f6, false, &[0x81e8]),
+    (0xf9f7, false, &[0x7acb]), (0xf9f8, false, &[0x7b20]), (0xf9f9, false, &[0x7c92]), (0xf9fa, false, &[0x72c0]),
+    (0xf9fb, false, &[0x7099]), (0xf9fc, false, &[0x8b58]), (0xf9fd, false, &[0x4ec0]), (0xf9fe, false, &[0x8336]),
+    (0xf9ff, false, &[0x523a]), (0xfa00, false, &[0x5207]), (0xfa01, false, &[0x5ea6]), (0xfa02, false, &[0x62d3]),
+    (0xfa03, false, &[0x7cd6]), (0xfa04, false, &[0x5b85]), (0xfa05, false, &[0x6d1e]), (0xfa06, false, &[0x66b4]),
+    (0xfa07, false, &[0x8f3b]), (0xfa08, false, &[0x884c]), (0xfa09, false, &[0x964d]), (0xfa0a, false, &[0x898b]),
+    (0xfa0b, false, &[0x5ed3]), (0xfa0c, false, &[0x5140]), (0xfa0d, false, &[0x55c0]), (0xfa10, false, &[0x585a]),
+    (0xfa12, false, &[0x6674]), (0xfa15, false, &[0x51de]), (0xfa16, false, &[0x732a]), (0xfa17, false, &[0x76ca]),
+    (0xfa18, false, &[0x793c]), (0xfa19, false, &[0x795e]), (0xfa1a, false, &[0x7965]), (0xfa1b, false, &[0x798f]),
+    (0xfa1c, false, &[0x9756]), (0xfa1d, false, &[0x7cbe]), (0xfa1e, false, &[0x7fbd]), (0xfa20, false, &[0x8612]),
+    (0xfa22, false, &[0x8af8]), (0xfa25, false, &[0x9038]), (0xfa26, false, &[0x90fd]), (0xfa2a, false, &[0x98ef]),
+    (0xfa2b, false, &[0x98fc]), (0xfa2c, false, &[0x9928]), (0xfa2d, false, &[0x9db4]), (0xfa2e, false, &[0x90de]),
+    (0xfa2f, false, &[0x96b7]), (0xfa30, false, &[0x4fae]), (0xfa31, false, &[0x50e7]), (0xfa32, false, &[0x514d]),
+    (0xfa33, false, &[0x52c9]), (0xfa34, false, &[0x52e4]), (0xfa35, false, &[0x5351]), (0xfa36, false, &[0x559d]),
+    (0xfa37, false, &[0x5606]), (0xfa38, false, &[0x5668]), (0xfa39, false, &[0x5840]), (0xfa3a, false, &[0x58a8]),
+    (0xfa3b, false, &[0x5c64]), (0xfa3c, false, &[0x5c6e]), (0xfa3d, false, &[0x6094]), (0xfa3e, false, &[0x6168]),
+    (0xfa3f, false, &[0x618e]), (0xfa40, false, &[0x61f2]), (0xfa41, false, &[0x654f]), (0xfa42, false, &[0x65e2]),
+    (0xfa43, false, &[0x6691]), (0xfa44, false, &[0x6885]), (0xfa45, false, &[0x6d77]), (0xfa46, false, &[0x6e1a]),
+    (0xfa47, false, &[0x6f22]), (0xfa48, false, &[0x716e]), (0xfa49, false, &[0x722b]), (0xfa4a, false, &[0x7422]),
+    (0xfa4b, false, &[0x7891]), (0xfa4c, false, &[0x793e]), (0xfa4d, false, &[0x7949]), (0xfa4e, false, &[0x7948]),
+    (0xfa4f, false, &[0x7950]), (0xfa50, false, &[0x7956]), (0xfa51, false, &[0x795d]), (0xfa52, false, &[0x798d]),
+    (0xfa53, false, &[0x798e]), (0xfa54, false, &[0x7a40]), (0xfa55, false, &[0x7a81]), (0xfa56, false, &[0x7bc0]),
+    (0xfa57, false, &[0x7df4]), (0xfa58, false, &[0x7e09]), (0xfa59, false, &[0x7e41]), (0xfa5a, false, &[0x7f72]),
+    (0xfa5b, false, &[0x8005]), (0xfa5c, false, &[0x81ed]), (0xfa5d, false, &[0x8279]), (0xfa5e, false, &[0x8279]),
+    (0xfa5f, false, &[0x8457]), (0xfa60, false, &[0x8910]), (0xfa61, false, &[0x8996]), (0xfa62, false, &[0x8b01]),
+    (0xfa63, false, &[0x8b39]), (0xfa64, false, &[0x8cd3]), (0xfa65, false, &[0x8d08]), (0xfa66, false, &[0x8fb6]),
+    (0xfa67, false, &[0x9038]), (0xfa68, false, &[0x96e3]), (0xfa69, false, &[0x97ff]), (0xfa6a, false, &[0x983b]),
+    (0xfa6b, false, &[0x6075]), (0xfa6c, false, &[0x242ee]), (0xfa6d, false, &[0x8218]), (0xfa70, false, &[0x4e26]),
+    (0xfa71, false, &[0x51b5]), (0xfa72, false, &[0x5168]), (0xfa73, false, &[0x4f80]), (0xfa74, false, &[0x5145]),
+    (0xfa75, false, &[0x5180]), (0xfa76, false, &[0x52c7]), (0xfa77, false, &[0x52fa]), (0xfa78, false, &[0x559d]),
+    (0xfa79, false, &[0x5555]), (0xfa7a, false, &[0x5599]), (0xfa7b, false, &[0x55e2]), (0xfa7c, false, &[0x585a]),
+    (0xfa7d, false, &[0x58b3]), (0xfa7e, false, &[0x5944]), (0xfa7f, false, &[0x5954]), (0xfa80, false, &[0x5a62]),
+    (0xfa81, false, &[0x5b28]), (0xfa82, false, &[0x5ed2]), (0xfa83, false, &[0x5ed9]), (0xfa84, false, &[0x5f69]),
+    (0xfa85, false, &[0x5fad]), (0xfa86, false, &[0x60d8]), (0xfa87, false, &[0x614e]), (0xfa88, false, &[0x6108]),
+    (0xfa89, false, &[0x618e]), (0xfa8a, false, &[0x6160]), (0xfa8b, false, &[0x61f2]), (0xfa8c, false, &[0x6234]),
+    (0xfa8d, false, &[0x63c4]), (0xfa8e, false, &[0x641c]), (0xfa8f, false, &[0x6452]), (0xfa90, false, &[0x6556]),
+    (0xfa91, false, &[0x6674]), (0xfa92, false, &[0x6717]), (0xfa93, false, &[0x671b]), (0xfa94, false, &[0x6756]),
+    (0xfa95, false, &[0x6b79]), (0xfa96, false, &[0x6bba]), (0xfa97, false, &[0x6d41]), (0xfa98, false, &[0x6edb]),
+    (0xfa99, false, &[0x6ecb]), (0xfa9a, false, &[0x6f22]), (0xfa9b, false, &[0x701e]), (0xfa9c, false, &[0x716e]),
+    (0xfa9d, false, &[0x77a7]), (0xfa9e, false, &[0x7235]), (0xfa9f, false, &[0x72af]), (0xfaa0, false, &[0x732a]),
+    (0xfaa1, false, &[0x7471]), (0xfaa2, false, &[0x7506]), (0xfaa3, false, &[0x753b]), (0xfaa4, false, &[0x761d]),
+    (0xfaa5, false, &[0x761f]), (0xfaa6, false, &[0x76ca]), (0xfaa7, false, &[0x76db]), (0xfaa8, false, &[0x76f4]),
+    (0xfaa9, false, &[0x774a]), (0xfaaa, false, &[0x7740]), (0xfaab, false, &[0x78cc]), (0xfaac, false, &[0x7ab1]),
+    (0xfaad, false, &[0x7bc0]), (0xfaae, false, &[0x7c7b]), (0xfaaf, false, &[0x7d5b]), (0xfab0, false, &[0x7df4]),
+    (0xfab1, false, &[0x7f3e]), (0xfab2, false, &[0x8005]), (0xfab3, false, &[0x8352]), (0xfab4, false, &[0x83ef]),
+    (0xfab5, false, &[0x8779]), (0xfab6, false, &[0x8941]), (0xfab7, false, &[0x8986]), (0xfab8, false, &[0x8996]),
+    (0xfab9, false, &[0x8abf]), (0xfaba, false, &[0x8af8]), (0xfabb, false, &[0x8acb]), (0xfabc, false, &[0x8b01]),
+    (0xfabd, false, &[0x8afe]), (0xfabe, false, &[0x8aed]), (0xfabf, false, &[0x8b39]), (0xfac0, false, &[0x8b8a]),
+    (0xfac1, false, &[0x8d08]), (0xfac2, false, &[0x8f38]), (0xfac3, false, &[0x9072]), (0xfac4, false, &[0x9199]),
+    (0xfac5, false, &[0x9276]), (0xfac6, false, &[0x967c]), (0xfac7, false, &[0x96e3]), (0xfac8, false, &[0x9756]),
+    (0xfac9, false, &[0x97db]), (0xfaca, false, &[0x97ff]), (0xfacb, false, &[0x980b]), (0xfacc, false, &[0x983b]),
+    (0xfacd, false, &[0x9b12]), (0xface, false, &[0x9f9c]), (0xfacf, false, &[0x2284a]), (0xfad0, false, &[0x22844]),
+    (0xfad1, false, &[0x233d5]), (0xfad2, false, &[0x3b9d]), (0xfad3, false, &[0x4018]), (0xfad4, false, &[0x4039]),
+    (0xfad5, false, &[0x25249]), (0xfad6, false, &[0x25cd0]), (0xfad7, false, &[0x27ed3]), (0xfad8, false, &[0x9f43]),
+    (0xfad9, false, &[0x9f8e]), (0xfb00, true, &[0x66, 0x66]), (0xfb01, true, &[0x66, 0x69]), (0xfb02, true, &[0x66, 0x6c]),
+    (0xfb03, true, &[0x66, 0x66, 0x69]), (0xfb04, true, &[0x66, 0x66, 0x6c]), (0xfb05, true, &[0x17f, 0x74]), (0xfb06, true, &[0x73, 0x74]),
+    (0xfb13, true, &[0x574, 0x576]), (0xfb14, true, &[0x574, 0x565]), (0xfb15, true, &[0x574, 0x56b]), (0xfb16, true, &[0x57e, 0x576]),
+    (0xfb17, true, &[0x574, 0x56d]), (0xfb1d, false, &[0x5d9, 0x5b4]), (0xfb1f, false, &[0x5f2, 0x5b7]), (0xfb20, true, &[0x5e2]),
+    (0xfb21, true, &[0x5d0]), (0xfb22, true, &[0x5d3]), (0xfb23, true, &[0x5d4]), (0xfb24, true, &[0x5db]),
+    (0xfb25, true, &[0x5dc]), (0xfb26, true, &[0x5dd]), (0xfb27, true, &[0x5e8]), (0xfb28, true, &[0x5ea]),
+    (0xfb29, true, &[0x2b]), (0xfb2a, false, &[0x5e9, 0x5c1]), (0xfb2b, false, &[0x5e9, 0x5c2]), (0xfb2c, false, &[0xfb49, 0x5c1]),
+    (0xfb2d, false, &[0xfb49, 0x5c2]), (0xfb2e, false, &[0x5d0, 0x5b7]), (0xfb2f, false, &[0x5d0, 0x5b8]), (0xfb30, false, &[0x5d0, 0x5bc]),
+    (0xfb31, false, &[0x5d1, 0x5bc]), (0xfb32, false, &[0x5d2, 0x5bc]), (0xfb33, false, &[0x5d3, 0x5bc]), (0xfb34, false, &[0x5d4, 0x5bc]),
+    (0xfb35, false, &[0x5d5, 0x5bc]), (0xfb36, false, &[0x5d6, 0x5bc]), (0xfb38, false, &[0x5d8, 0x5bc]), (0xfb39, false, &[0x5d9, 0x5bc]),
+    (0xfb3a, false, &[0x5da, 0x5bc]), (0xfb3b, false, &[0x5db, 0x5bc]), (0xfb3c, false, &[0x5dc, 0x5bc]), (0xfb3e, false, &[0x5de, 0x5bc]),
+    (0xfb40, false, &[0x5e0, 0x5bc]), (0xfb41, false, &[0x5e1, 0x5bc]), (0xfb43, false, &[0x5e3, 0x5bc]), (0xfb44, false, &[0x5e4, 0x5bc]),
+    (0xfb46, false, &[0x5e6, 0x5bc]), (0xfb47, false, &[0x5e7, 0x5bc]), (0xfb48, false, &[0x5e8, 0x5bc]), (0xfb49, false, &[0x5e9, 0x5bc]),
+    (0xfb4a, false, &[0x5ea, 0x5bc]), (0xfb4b, false, &[0x5d5, 0x5b9]), (0xfb4c, false, &[0x5d1, 0x5bf]), (0xfb4d, false, &[0x5db, 0x5bf]),
+    (0xfb4e, false, &[0x5e4, 0x5bf]), (0xfb4f, true, &[0x5d0, 0x5dc]), (0xfb50, true, &[0x671]), (0xfb51, true, &[0x671]),
+    (0xfb52, true, &[0x67b]), (0xfb53, true, &[0x67b]), (0xfb54, true, &[0x67b]), (0xfb55, true, &[0x67b]),
+    (0xfb56, true, &[0x67e]), (0xfb57, true, &[0x67e]), (0xfb58, true, &[0x67e]), (0xfb59, true, &[0x67e]),
+    (0xfb5a, true, &[0x680]), (0xfb5b, true, &[0x680]), (0xfb5c, true, &[0x680]), (0xfb5d, true, &[0x680]),
+    (0xfb5e, true, &[0x67a]), (0xfb5f, true, &[0x67a]), (0xfb60, true, &[0x67a]), (0xfb61, true, &[0x67a]),
+    (0xfb62, true, &[0x67f]), (0xfb63, true, &[0x67f]), (0xfb64, true, &[0x67f]), (0xfb65, true, &[0x67f]),
+    (0xfb66, true, &[0x679]), (0xfb67, true, &[0x679]), (0xfb68, true, &[0x679]), (0xfb69, true, &[0x679]),
+    (0xfb6a, true, &[0x6a4]), (0xfb6b, true, &[0x6a4]), (0xfb6c, true, &[0x6a4]), (0xfb6d, true, &[0x6a4]),
+    (0xfb6e, true, &[0x6a6]), (0xfb6f, true, &[0x6a6]), (0xfb70, true, &[0x6a6]), (0xfb71, true, &[0x6a6]),
+    (0xfb72, true, &[0x684]), (0xfb73, true, &[0x684]), (0xfb74, true, &[0x684]), (0xfb75, true, &[0x684]),
+    (0xfb76, true, &[0x683]), (0xfb77, true, &[0x683]), (0xfb78, true, &[0x683]), (0xfb79, true, &[0x683]),
+    (0xfb7a, true, &[0x686]), (0xfb7b, true, &[0x686]), (0xfb7c, true, &[0x686]), (0xfb7d, true, &[0x686]),
+    (0xfb7e, true, &[0x687]), (0xfb7f, true, &[0x687]), (0xfb80, true, &[0x687]), (0xfb81, true, &[0x687]),
+    (0xfb82, true, &[0x68d]), (0xfb83, true, &[0x68d]), (0xfb84, true, &[0x68c]), (0xfb85, true, &[0x68c]),
+    (0xfb86, true, &[0x68e]), (0xfb87, true, &[0x68e]), (0xfb88, true, &[0x688]), (0xfb89, true, &[0x688]),
+    (0xfb8a, true, &[0x698]), (0xfb8b, true, &[0x698]), (0xfb8c, true, &[0x691]), (0xfb8d, true, &[0x691]),
+    (0xfb8e, true, &[0x6a9]), (0xfb8f, true, &[0x6a9]), (0xfb90, true, &[0x6a9]), (0xfb91, true, &[0x6a9]),
+    (0xfb92, true, &[0x6af]), (0xfb93, true, &[0x6af]), (0xfb94, true, &[0x6af]), (0xfb95, true, &[0x6af]),
+    (0xfb96, true, &[0x6b3]), (0xfb97, true, &[0x6b3]), (0xfb98, true, &[0x6b3]), (0xfb99, true, &[0x6b3]),
+    (0xfb9a, true, &[0x6b1]), (0xfb9b, true, &[0x6b1]), (0xfb9c, true, &[0x6b1]), (0xfb9d, true, &[0x6b1]),
+    (0xfb9e, true, &[0x6ba]), (0xfb9f, true, &[0x6ba]), (0xfba0, true, &[0x6bb]), (0xfba1, true, &[0x6bb]),
+    (0xfba2, true, &[0x6bb]), (0xfba3, true, &[0x6bb]), (0xfba4, true, &[0x6c0]), (0xfba5, true, &[0x6c0]),
+    (0xfba6, true, &[0x6c1]), (0xfba7, true, &[0x6c1]), (0xfba8, true, &[0x6c1]), (0xfba9, true, &[0x6c1]),
+    (0xfbaa, true, &[0x6be]), (0xfbab, true, &[0x6be]), (0xfbac, true, &[0x6be]), (0xfbad, true, &[0x6be]),
+    (0xfbae, true, &[0x6d2]), (0xfbaf, true, &[0x6d2]), (0xfbb0, true, &[0x6d3]), (0xfbb1, true, &[0x6d3]),
+    (0xfbd3, true, &[0x6ad]), (0xfbd4, true, &[0x6ad]), (0xfbd5, true, &[0x6ad]), (0xfbd6, true, &[0x6ad]),
+    (0xfbd7, true, &[0x6c7]), (0xfbd8, true, &[0x6c7]), (0xfbd9, true, &[0x6c6]), (0xfbda, true, &[0x6c6]),
+    (0xfbdb, true, &[0x6c8]), (0xfbdc, true, &[0x6c8]), (0xfbdd, true, &[0x677]), (0xfbde, true, &[0x6cb]),
+    (0xfbdf, true, &[0x6cb]), (0xfbe0, true, &[0x6c5]), (0xfbe1, true, &[0x6c5]), (0xfbe2, true, &[0x6c9]),
+    (0xfbe3, true, &[0x6c9]), (0xfbe4, true, &[0x6d0]), (0xfbe5, true, &[0x6d0]), (0xfbe6, true, &[0x6d0]),
+    (0xfbe7, true, &[0x6d0]), (0xfbe8, true, &[0x649]), (0xfbe9, true, &[0x649]), (0xfbea, true, &[0x626, 0x627]),
+    (0xfbeb, true, &[0x626, 0x627]), (0xfbec, true, &[0x626, 0x6d5]), (0xfbed, true, &[0x626, 0x6d5]), (0xfbee, true, &[0x626, 0x648]),
+    (0xfbef, true, &[0x626, 0x648]), (0xfbf0, true, &[0x626, 0x6c7]), (0xfbf1, true, &[0x626, 0x6c7]), (0xfbf2, true, &[0x626, 0x6c6]),
+    (0xfbf3, true, &[0x626, 0x6c6]), (0xfbf4, true, &[0x626, 0x6c8]), (0xfbf5, true, &[0x626, 0x6c8]), (0xfbf6, true, &[0x626, 0x6d0]),
+    (0xfbf7, true, &[0x626, 0x6d0]), (0xfbf8, true, &[0x626, 0x6d0]), (0xfbf9, true, &[0x626, 0x649]), (0xfbfa, true, &[0x626, 0x649]),
+    (0xfbfb, true, &[0x626, 0x649]), (0xfbfc, true, &[0x6cc]), (0xfbfd, true, &[0x6cc]), (0xfbfe, true, &[0x6cc]),
+    (0xfbff, true, &[0x6cc]), (0xfc00, true, &[0x626, 0x62c]), (0xfc01, true, &[0x626, 0x62d]), (0xfc02, true, &[0x626, 0x645]),
+    (0xfc03, true, &[0x626, 0x649]), (0xfc04, true, &[0x626, 0x64a]), (0xfc05, true, &[0x628, 0x62c]), (0xfc06, true, &[0x628, 0x62d]),
+    (0xfc07, true, &[0x628, 0x62e]), (0xfc08, true, &[0x628, 0x645]), (0xfc09, true, &[0x628, 0x649]), (0xfc0a, true, &[0x628, 0x64a]),
+    (0xfc0b, true, &[0x62a, 0x62c]), (0xfc0c, true, &[0x62a, 0x62d]), (0xfc0d, true, &[0x62a, 0x62e]), (0xfc0e, true, &[0x62a, 0x645]),
+    (0xfc0f, true, &[0x62a, 0x649]), (0xfc10, true, &[0x62a, 0x64a]), (0xfc11, true, &[0x62b, 0x62c]), (0xfc12, true, &[0x62b, 0x645]),
+    (0xfc13, true, &[0x62b, 0x649]), (0xfc14, true, &[0x62b, 0x64a]), (0xfc15, true, &[0x62c, 0x62d]), (0xfc16, true, &[0x62c, 0x645]),
+    (0xfc17, true, &[0x62d, 0x62c]), (0xfc18, true, &[0x62d, 0x645]), (0xfc19, true, &[0x62e, 0x62c]), (0xfc1a, true, &[0x62e, 0x62d]),
+    (0xfc1b, true, &[0x62e, 0x645]), (0xfc1c, true, &[0x633, 0x62c]), (0xfc1d, true, &[0x633, 0x62d]), (0xfc1e, true, &[0x633, 0x62e]),
+    (0xfc1f, true, &[0x633, 0x645]), (0xfc20, true, &[0x635, 0x62d]), (0xfc21, true, &[0x635, 0x645]), (0xfc22, true, &[0x636, 0x62c]),
+    (0xfc23, true, &[0x636, 0x62d]), (0xfc24, true, &[0x636, 0x62e]), (0xfc25, true, &[0x636, 0x645]), (0xfc26, true, &[0x637, 0x62d]),
+    (0xfc27, true, &[0x637, 0x645]), (0xfc28, true, &[0x638, 0x645]), (0xfc29, true, &[0x639, 0x62c]), (0xfc2a, true, &[0x639, 0x645]),
+    (0xfc2b, true, &[0x63a, 0x62c]), (0xfc2c, true, &[0x63a, 0x645]), (0xfc2d, true, &[0x641, 0x62c]), (0xfc2e, true, &[0x641, 0x62d]),
+    (0xfc2f, true, &[0x641, 0x62e]), (0xfc30, true, &[0x641, 0x645]), (0xfc31, true, &[0x641, 0x649]), (0xfc32, true, &[0x641, 0x64a]),
+    (0xfc33, true, &[0x642, 0x62d]), (0xfc34, true, &[0x642, 0x645]), (0xfc35, true, &[0x642, 0x649]), (0xfc36, true, &[0x642, 0x64a]),
+    (0xfc37, true, &[0x643, 0x627]), (0xfc38, true, &[0x643, 0x62c]), (0xfc39, true, &[0x643, 0x62d]), (0xfc3a, true, &[0x643, 0x62e]),
+    (0xfc3b, true, &[0x643, 0x644]), (0xfc3c, true, &[0x643, 0x645]), (0xfc3d, true, &[0x643, 0x649]), (0xfc3e, true, &[0x643, 0x64a]),
+    (0xfc3f, true, &[0x644, 0x62c]), (0xfc40, true, &[0x644, 0x62d]), (0xfc41, true, &[0x644, 0x62e]), (0xfc42, true, &[0x644, 0x645]),
+    (0xfc43, true, &[0x644, 0x649]), (0xfc44, true, &[0x644, 0x64a]), (0xfc45, true, &[0x645, 0x62c]), (0xfc46, true, &[0x645, 0x62d]),
+    (0xfc47, true, &[0x645, 0x62e]), (0xfc48, true, &[0x645, 0x645]), (0xfc49, true, &[0x645, 0x649]), (0xfc4a, true, &[0x645, 0x64a]),
+    (0xfc4b, true, &[0x646, 0x62c]), (0xfc4c, true, &[0x646, 0x62d]), (0xfc4d, true, &[0x646, 0x62e]), (0xfc4e, true, &[0x646, 0x645]),
+    (0xfc4f, true, &[0x646, 0x649]), (0xfc50, true, &[0x646, 0x64a]), (0xfc51, true, &[0x647, 0x62c]), (0xfc52, true, &[0x647, 0x645]),
+    (0xfc53, true, &[0x647, 0x649]), (0xfc54, true, &[0x647, 0x64a]), (0xfc55, true, &[0x64a, 0x62c]), (0xfc56, true, &[0x64a, 0x62d]),
+    (0xfc57, true, &[0x64a, 0x62e]), (0xfc58, true, &[0x64a, 0x645]), (0xfc59, true, &[0x64a, 0x649]), (0xfc5a, true, &[0x64a, 0x64a]),
+    (0xfc5b, true, &[0x630, 0x670]), (0xfc5c, true, &[0x631, 0x670]), (0xfc5d, true, &[0x649, 0x670]), (0xfc5e, true, &[0x20, 0x64c, 0x651]),
+    (0xfc5f, true, &[0x20, 0x64d, 0x651]), (0xfc60, true, &[0x20, 0x64e, 0x651]), (0xfc61, true, &[0x20, 0x64f, 0x651]), (0xfc62, true, &[0x20, 0x650, 0x651]),
+    (0xfc63, true, &[0x20, 0x651, 0x670]), (0xfc64, true, &[0x626, 0x631]), (0xfc65, true, &[0x626, 0x632]), (0xfc66, true, &[0x626, 0x645]),
+    (0xfc67, true, &[0x626, 0x646]), (0xfc68, true, &[0x626, 0x649]), (0xfc69, true, &[0x626, 0x64a]), (0xfc6a, true, &[0x628, 0x631]),
+    (0xfc6b, true, &[0x628, 0x632]), (0xfc6c, true, &[0x628, 0x645]), (0xfc6d, true, &[0x628, 0x646]), (0xfc6e, true, &[0x628, 0x649]),
+    (0xfc6f, true, &[0x628, 0x64a]), (0xfc70, true, &[0x62a, 0x631]), (0xfc71, true, &[0x62a, 0x632]), (0xfc72, true, &[0x62a, 0x645]),
+    (0xfc73, true, &[0x62a, 0x646]), (0xfc74, true, &[0x62a, 0x649]), (0xfc75, true, &[0x62a, 0x64a]), (0xfc76, true, &[0x62b, 0x631]),
+    (0xfc77, true, &[0x62b, 0x632]), (0xfc78, true, &[0x62b, 0x645]), (0xfc79, true, &[0x62b, 0x646]), (0xfc7a, true, &[0x62b, 0x649]),
+    (0xfc7b, true, &[0x62b, 0x64a]), (0xfc7c, true, &[0x641, 0x649]), (0xfc7d, true, &[0x641, 0x64a]), (0xfc7e, true, &[0x642, 0x649]),
+    (0xfc7f, true, &[0x642, 0x64a]), (0xfc80, true, &[0x643, 0x627]), (0xfc81, true, &[0x643, 0x644]), (0xfc82, true, &[0x643, 0x645]),
+    (0xfc83, true, &[0x643, 0x649]), (0xfc84, true, &[0x643, 0x64a]), (0xfc85, true, &[0x644, 0x645]), (0xfc86, true, &[0x644, 0x649]),
+    (0xfc87, true, &[0x644, 0x64a]), (0xfc88, true, &[0x645, 0x627]), (0xfc89, true, &[0x645, 0x645]), (0xfc8a, true, &[0x646, 0x631]),
+    (0xfc8b, true, &[0x646, 0x632]), (0xfc8c, true, &[0x646, 0x645]), (0xfc8d, true, &[0x646, 0x646]), (0xfc8e, true, &[0x646, 0x649]),
+    (0xfc8f, true, &[0x646, 0x64a]), (0xfc90, true, &[0x649, 0x670]), (0xfc91, true, &[0x64a, 0x631]), (0xfc92, true, &[0x64a, 0x632]),
+    (0xfc93, true, &[0x64a, 0x645]), (0xfc94, true, &[0x64a, 0x646]), (0xfc95, true, &[0x64a, 0x649]), (0xfc96, true, &[0x64a, 0x64a]),
+    (0xfc97, true, &[0x626, 0x62c]), (0xfc98, true, &[0x626, 0x62d]), (0xfc99, true, &[0x626, 0x62e]), (0xfc9a, true, &[0x626, 0x645]),
+    (0xfc9b, true, &[0x626, 0x647]), (0xfc9c, true, &[0x628, 0x62c]), (0xfc9d, true, &[0x628, 0x62d]), (0xfc9e, true, &[0x628, 0x62e]),
+    (0xfc9f, true, &[0x628, 0x645]), (0xfca0, true, &[0x628, 0x647]), (0xfca1, true, &[0x62a, 0x62c]), (0xfca2, true, &[0x62a, 0x62d]),
+    (0xfca3, true, &[0x62a, 0x62e]), (0xfca4, true, &[0x62a, 0x645]), (0xfca5, true, &[0x62a, 0x647]), (0xfca6, true, &[0x62b, 0x645]),
+    (0xfca7, true, &[0x62c, 0x62d]), (0xfca8, true, &[0x62c, 0x645]), (0xfca9, true, &[0x62d, 0x62c]), (0xfcaa, true, &[0x62d, 0x645]),
+    (0xfcab, true, &[0x62e, 0x62c]), (0xfcac, true, &[0x62e, 0x645]), (0xfcad, true, &[0x633, 0x62c]), (0xfcae, true, &[0x633, 0x62d]),
+    (0xfcaf, true, &[0x633, 0x62e]), (0xfcb0, true, &[0x633, 0x645]), (0xfcb1, true, &[0x635, 0x62d]), (0xfcb2, true, &[0x635, 0x62e]),
+    (0xfcb3, true, &[0x635, 0x645]), (0xfcb4, true, &[0x636, 0x62c]), (0xfcb5, true, &[0x636, 0x62d]), (0xfcb6, true, &[0x636, 0x62e]),
+    (0xfcb7, true, &[0x636, 0x645]), (0xfcb8, true, &[0x637, 0x62d]), (0xfcb9, true, &[0x638, 0x645]), (0xfcba, true, &[0x639, 0x62c]),
+    (0xfcbb, true, &[0x639, 0x645]), (0xfcbc, true, &[0x63a, 0x62c]), (0xfcbd, true, &[0x63a, 0x645]), (0xfcbe, true, &[0x641, 0x62c]),
+    (0xfcbf, true, &[0x641, 0x62d]), (0xfcc0, true, &[0x641, 0x62e]), (0xfcc1, true, &[0x641, 0x645]), (0xfcc2, true, &[0x642, 0x62d]),
+    (0xfcc3, true, &[0x642, 0x645]), (0xfcc4, true, &[0x643, 0x62c]), (0xfcc5, true, &[0x643, 0x62d]), (0xfcc6, true, &[0x643, 0x62e]),
+    (0xfcc7, true, &[0x643, 0x644]), (0xfcc8, true, &[0x643, 0x645]), (0xfcc9, true, &[0x644, 0x62c]), (0xfcca, true, &[0x644, 0x62d]),
+    (0xfccb, true, &[0x644, 0x62e]), (0xfccc, true, &[0x644, 0x645]), (0xfccd, true, &[0x644, 0x647]), (0xfcce, true, &[0x645, 0x62c]),
+    (0xfccf, true, &[0x645, 0x62d]), (0xfcd0, true, &[0x645, 0x62e]), (0xfcd1, true, &[0x645, 0x645]), (0xfcd2, true, &[0x646, 0x62c]),
+    (0xfcd3, true, &[0x646, 0x62d]), (0xfcd4, true, &[0x646, 0x62e]), (0xfcd5, true, &[0x646, 0x645]), (0xfcd6, true, &[0x646, 0x647]),
+    (0xfcd7, true, &[0x647, 0x62c]), (0xfcd8, true, &[0x647, 0x645]), (0xfcd9, true, &[0x647, 0x670]), (0xfcda, true, &[0x64a, 0x62c]),
+    (0xfcdb, true, &[0x64a, 0x62d]), (0xfcdc, true, &[0x64a, 0x62e]), (0xfcdd, true, &[0x64a, 0x645]), (0xfcde, true, &[0x64a, 0x647]),
+    (0xfcdf, true, &[0x626, 0x645]), (0xfce0, true, &[0x626, 0x647]), (0xfce1, true, &[0x628, 0x645]), (0xfce2, true, &[0x628, 0x647]),
+    (0xfce3, true, &[0x62a, 0x645]), (0xfce4, true, &[0x62a, 0x647]), (0xfce5, true, &[0x62b, 0x645]), (0xfce6, true, &[0x62b, 0x647]),
+    (0xfce7, true, &[0x633, 0x645]), (0xfce8, true, &[0x633, 0x647]), (0xfce9, true, &[0x634, 0x645]), (0xfcea, true, &[0x634, 0x647]),
+    (0xfceb, true, &[0x643, 0x644]), (0xfcec, true, &[0x643, 0x645]), (0xfced, true, &[0x644, 0x645]), (0xfcee, true, &[0x646, 0x645]),
+    (0xfcef, true, &[0x646, 0x647]), (0xfcf0, true, &[0x64a, 0x645]), (0xfcf1, true, &[0x64a, 0x647]), (0xfcf2, true, &[0x640, 0x64e, 0x651]),
+    (0xfcf3, true, &[0x640, 0x64f, 0x651]), (0xfcf4, true, &[0x640, 0x650, 0x651]), (0xfcf5, true, &[0x637, 0x649]), (0xfcf6, true, &[0x637, 0x64a]),
+    (0xfcf7, true, &[0x639, 0x649]), (0xfcf8, true, &[0x639, 0x64a]), (0xfcf9, true, &[0x63a, 0x649]), (0xfcfa, true, &[0x63a, 0x64a]),
+    (0xfcfb, true, &[0x633, 0x649]), (0xfcfc, true, &[0x633, 0x64a]), (0xfcfd, true, &[0x634, 0x649]), (0xfcfe, true, &[0x634, 0x64a]),
+    (0xfcff, true, &[0x62d, 0x649]), (0xfd00, true, &[0x62d, 0x64a]), (0xfd01, true, &[0x62c, 0x649]), (0xfd02, true, &[0x62c, 0x64a]),
+    (0xfd03, true, &[0x62e, 0x649]), (0xfd04, true, &[0x62e, 0x64a]), (0xfd05, true, &[0x635, 0x649]), (0xfd06, true, &[0x635, 0x64a]),
+    (0xfd07, true, &[0x636, 0x649]), (0xfd08, true, &[0x636, 0x64a]), (0xfd09, true, &[0x634, 0x62c]), (0xfd0a, true, &[0x634, 0x62d]),
+    (0xfd0b, true, &[0x634, 0x62e]), (0xfd0c, true, &[0x634, 0x645]), (0xfd0d, true, &[0x634, 0x631]), (0xfd0e, true, &[0x633, 0x631]),
+    (0xfd0f, true, &[0x635, 0x631]), (0xfd10, true, &[0x636, 0x631]), (0xfd11, true, &[0x637, 0x649]), (0xfd12, true, &[0x637, 0x64a]),
+    (0xfd13, true, &[0x639, 0x649]), (0xfd14, true, &[0x639, 0x64a]), (0xfd15, true, &[0x63a, 0x649]), (0xfd16, true, &[0x63a, 0x64a]),
+    (0xfd17, true, &[0x633, 0x649]), (0xfd18, true, &[0x633, 0x64a]), (0xfd19, true, &[0x634, 0x649]), (0xfd1a, true, &[0x634, 0x64a]),
+    (0xfd1b, true, &[0x62d, 0x649]), (0xfd1c, true, &[0x62d, 0x64a]), (0xfd1d, true, &[0x62c, 0x649]), (0xfd1e, true, &[0x62c, 0x64a]),
+    (0xfd1f, true, &[0x62e, 0x649]), (0xfd20, true, &[0x62e, 0x64a]), (0xfd21, true, &[0x635, 0x649]), (0xfd22, true, &[0x635, 0x64a]),
+    (0xfd23, true, &[0x636, 0x649]), (0xfd24, true, &[0x636, 0x64a]), (0xfd25, true, &[0x634, 0x62c]), (0xfd26, true, &[0x634, 0x62d]),
+    (0xfd27, true, &[0x634, 0x62e]), (0xfd28, true, &[0x634, 0x645]), (0xfd29, true, &[0x634, 0x631]), (0xfd2a, true, &[0x633, 0x631]),
+    (0xfd2b, true, &[0x635, 0x631]), (0xfd2c, true, &[0x636, 0x631]), (0xfd2d, true, &[0x634, 0x62c]), (0xfd2e, true, &[0x634, 0x62d]),
+    (0xfd2f, true, &[0x634, 0x62e]), (0xfd30, true, &[0x634, 0x645]), (0xfd31, true, &[0x633, 0x647]), (0xfd32, true, &[0x634, 0x647]),
+    (0xfd33, true, &[0x637, 0x645]), (0xfd34, true, &[0x633, 0x62c]), (0xfd35, true, &[0x633, 0x62d]), (0xfd36, true, &[0x633, 0x62e]),
+    (0xfd37, true, &[0x634, 0x62c]), (0xfd38, true, &[0x634, 0x62d]), (0xfd39, true, &[0x634, 0x62e]), (0xfd3a, true, &[0x637, 0x645]),
+    (0xfd3b, true, &[0x638, 0x645]), (0xfd3c, true, &[0x627, 0x64b]), (0xfd3d, true, &[0x627, 0x64b]), (0xfd50, true, &[0x62a, 0x62c, 0x645]),
+    (0xfd51, true, &[0x62a, 0x62d, 0x62c]), (0xfd52, true, &[0x62a, 0x62d, 0x62c]), (0xfd53, true, &[0x62a, 0x62d, 0x645]), (0xfd54, true, &[0x62a, 0x62e, 0x645]),
+    (0xfd55, true, &[0x62a, 0x645, 0x62c]), (0xfd56, true, &[0x62a, 0x645, 0x62d]), (0xfd57, true, &[0x62a, 0x645, 0x62e]), (0xfd58, true, &[0x62c, 0x645, 0x62d]),
+    (0xfd59, true, &[0x62c, 0x645, 0x62d]), (0xfd5a, true, &[0x62d, 0x645, 0x64a]), (0xfd5b, true, &[0x62d, 0x645, 0x649]), (0xfd5c, true, &[0x633, 0x62d, 0x62c]),
+    (0xfd5d, true, &[0x633, 0x62c, 0x62d]), (0xfd5e, true, &[0x633, 0x62c, 0x649]), (0xfd5f, true, &[0x633, 0x645, 0x62d]), (0xfd60, true, &[0x633, 0x645, 0x62d]),
+    (0xfd61, true, &[0x633, 0x645, 0x62c]), (0xfd62, true, &[0x633, 0x645, 0x645]), (0xfd63, true, &[0x633, 0x645, 0x645]), (0xfd64, true, &[0x635, 0x62d, 0x62d]),
+    (0xfd65, true, &[0x635, 0x62d, 0x62d]), (0xfd66, true, &[0x635, 0x645, 0x645]), (0xfd67, true, &[0x634, 0x62d, 0x645]), (0xfd68, true, &[0x634, 0x62d, 0x645]),
+    (0xfd69, true, &[0x634, 0x62c, 0x64a]), (0xfd6a, true, &[0x634, 0x645, 0x62e]), (0xfd6b, true, &[0x634, 0x645, 0x62e]), (0xfd6c, true, &[0x634, 0x645, 0x645]),
+    (0xfd6d, true, &[0x634, 0x645, 0x645]), (0xfd6e, true, &[0x636, 0x62d, 0x649]), (0xfd6f, true, &[0x636, 0x62e, 0x645]), (0xfd70, true, &[0x636, 0x62e, 0x645]),
+    (0xfd71, true, &[0x637, 0x645, 0x62d]), (0xfd72, true, &[0x637, 0x645, 0x62d]), (0xfd73, true, &[0x637, 0x645, 0x645]), (0xfd74, true, &[0x637, 0x645, 0x64a]),
+    (0xfd75, true, &[0x639, 0x62c, 0x645]), (0xfd76, true, &[0x639, 0x645, 0x645]), (0xfd77, true, &[0x639, 0x645, 0x645]), (0xfd78, true, &[0x639, 0x645, 0x649]),
+    (0xfd79, true, &[0x63a, 0x645, 0x645]), (0xfd7a, true, &[0x63a, 0x645, 0x64a]), (0xfd7b, true, &[0x63a, 0x645, 0x649]), (0xfd7c, true, &[0x641, 0x62e, 0x645]),
+    (0xfd7d, true, &[0x641, 0x62e, 0x645]), (0xfd7e, true, &[0x642, 0x645, 0x62d]), (0xfd7f, true, &[0x642, 0x645, 0x645]), (0xfd80, true, &[0x644, 0x62d, 0x645]),
+    (0xfd81, true, &[0x644, 0x62d, 0x64a]), (0xfd82, true, &[0x644, 0x62d, 0x649]), (0xfd83, true, &[0x644, 0x62c, 0x62c]), (0xfd84, true, &[0x644, 0x62c, 0x62c]),
+    (0xfd85, true, &[0x644, 0x62e, 0x645]), (0xfd86, true, &[0x644, 0x62e, 0x645]), (0xfd87, true, &[0x644, 0x645, 0x62d]), (0xfd88, true, &[0x644, 0x645, 0x62d]),
+    (0xfd89, true, &[0x645, 0x62d, 0x62c]), (0xfd8a, true, &[0x645, 0x62d, 0x645]), (0xfd8b, true, &[0x645, 0x62d, 0x64a]), (0xfd8c, true, &[0x645, 0x62c, 0x62d]),
+    (0xfd8d, true, &[0x645, 0x62c, 0x645]), (0xfd8e, true, &[0x645, 0x62e, 0x62c]), (0xfd8f, true, &[0x645, 0x62e, 0x645]), (0xfd92, true, &[0x645, 0x62c, 0x62e]),
+    (0xfd93, true, &[0x647, 0x645, 0x62c]), (0xfd94, true, &[0x647, 0x645, 0x645]), (0xfd95, true, &[0x646, 0x62d, 0x645]), (0xfd96, true, &[0x646, 0x62d, 0x649]),
+    (0xfd97, true, &[0x646, 0x62c, 0x645]), (0xfd98, true, &[0x646, 0x62c, 0x645]), (0xfd99, true, &[0x646, 0x62c, 0x649]), (0xfd9a, true, &[0x646, 0x645, 0x64a]),
+    (0xfd9b, true, &[0x646, 0x645, 0x649]), (0xfd9c, true, &[0x64a, 0x645, 0x645]), (0xfd9d, true, &[0x64a, 0x645, 0x645]), (0xfd9e, true, &[0x628, 0x62e, 0x64a]),
+    (0xfd9f, true, &[0x62a, 0x62c, 0x64a]), (0xfda0, true, &[0x62a, 0x62c, 0x649]), (0xfda1, true, &[0x62a, 0x62e, 0x64a]), (0xfda2, true, &[0x62a, 0x62e, 0x649]),
+    (0xfda3, true, &[0x62a, 0x645, 0x64a]), (0xfda4, true, &[0x62a, 0x645, 0x649]), (0xfda5, true, &[0x62c, 0x645, 0x64a]), (0xfda6, true, &[0x62c, 0x62d, 0x649]),
+    (0xfda7, true, &[0x62c, 0x645, 0x649]), (0xfda8, true, &[0x633, 0x62e, 0x649]), (0xfda9, true, &[0x635, 0x62d, 0x64a]), (0xfdaa, true, &[0x634, 0x62d, 0x64a]),
+    (0xfdab, true, &[0x636, 0x62d, 0x64a]), (0xfdac, true, &[0x644, 0x62c, 0x64a]), (0xfdad, true, &[0x644, 0x645, 0x64a]), (0xfdae, true, &[0x64a, 0x62d, 0x64a]),
+    (0xfdaf, true, &[0x64a, 0x62c, 0x64a]), (0xfdb0, true, &[0x64a, 0x645, 0x64a]), (0xfdb1, true, &[0x645, 0x645, 0x64a]), (0xfdb2, true, &[0x642, 0x645, 0x64a]),
+    (0xfdb3, true, &[0x646, 0x62d, 0x64a]), (0xfdb4, true, &[0x642, 0x645, 0x62d]), (0xfdb5, true, &[0x644, 0x62d, 0x645]), (0xfdb6, true, &[0x639, 0x645, 0x64a]),
+    (0xfdb7, true, &[0x643, 0x645, 0x64a]), (0xfdb8, true, &[0x646, 0x62c, 0x62d]), (0xfdb9, true, &[0x645, 0x62e, 0x64a]), (0xfdba, true, &[0x644, 0x62c, 0x645]),
+    (0xfdbb, true, &[0x643, 0x645, 0x645]), (0xfdbc, true, &[0x644, 0x62c, 0x645]), (0xfdbd, true, &[0x646, 0x62c, 0x62d]), (0xfdbe, true, &[0x62c, 0x62d, 0x64a]),
+    (0xfdbf, true, &[0x62d, 0x62c, 0x64a]), (0xfdc0, true, &[0x645, 0x62c, 0x64a]), (0xfdc1, true, &[0x641, 0x645, 0x64a]), (0xfdc2, true, &[0x628, 0x62d, 0x64a]),
+    (0xfdc3, true, &[0x643, 0x645, 0x645]), (0xfdc4, true, &[0x639, 0x62c, 0x645]), (0xfdc5, true, &[0x635, 0x645, 0x645]), (0xfdc6, true, &[0x633, 0x62e, 0x64a]),
+    (0xfdc7, true, &[0x646, 0x62c, 0x64a]), (0xfdf0, true, &[0x635, 0x644, 0x6d2]), (0xfdf1, true, &[0x642, 0x644, 0x6d2]), (0xfdf2, true, &[0x627, 0x644, 0x644, 0x647]),
+    (0xfdf3, true, &[0x627, 0x643, 0x628, 0x631]), (0xfdf4, true, &[0x645, 0x62d, 0x645, 0x62f]), (0xfdf5, true, &[0x635, 0x644, 0x639, 0x645]), (0xfdf6, true, &[0x631, 0x633, 0x648, 0x644]),
+    (0xfdf7, true, &[0x639, 0x644, 0x64a, 0x647]), (0xfdf8, true, &[0x648, 0x633, 0x644, 0x645]), (0xfdf9, true, &[0x635, 0x644, 0x649]), (0xfdfa, true, &[0x635, 0x644, 0x649, 0x20, 0x627, 0x644, 0x644, 0x647, 0x20, 0x639, 0x644, 0x64a, 0x647, 0x20, 0x648, 0x633, 0x644, 0x645]),
+    (0xfdfb, true, &[0x62c, 0x644, 0x20, 0x62c, 0x644, 0x627, 0x644, 0x647]), (0xfdfc, true, &[0x631, 0x6cc, 0x627, 0x644]), (0xfe10, true, &[0x2c]), (0xfe11, true, &[0x3001]),
+    (0xfe12, true, &[0x3002]), (0xfe13, true, &[0x3a]), (0xfe14, true, &[0x3b]), (0xfe15, true, &[0x21]),
+    (0xfe16, true, &[0x3f]), (0xfe17, true, &[0x3016]), (0xfe18, true, &[0x3017]), (0xfe19, true, &[0x2026]),
+    (0xfe30, true, &[0x2025]), (0xfe31, true, &[0x2014]), (0xfe32, true, &[0x2013]), (0xfe33, true, &[0x5f]),
+    (0xfe34, true, &[0x5f]), (0xfe35, true, &[0x28]), (0xfe36, true, &[0x29]), (0xfe37, true, &[0x7b]),
+    (0xfe38, true, &[0x7d]), (0xfe39, true, &[0x3014]), (0xfe3a, true, &[0x3015]), (0xfe3b, true, &[0x3010]),
+    (0xfe3c, true, &[0x3011]), (0xfe3d, true, &[0x300a]), (0xfe3e, true, &[0x300b]), (0xfe3f, true, &[0x3008]),
+    (0xfe40, true, &[0x3009]), (0xfe41, true, &[0x300c]), (0xfe42, true, &[0x300d]), (0xfe43, true, &[0x300e]),
+    (0xfe44, true, &[0x300f]), (0xfe47, true, &[0x5b]), (0xfe48, true, &[0x5d]), (0xfe49, true, &[0x203e]),
+    (0xfe4a, true, &[0x203e]), (0xfe4b, true, &[0x203e]), (0xfe4c, true, &[0x203e]), (0xfe4d, true, &[0x5f]),
+    (0xfe4e, true, &[0x5f]), (0xfe4f, true, &[0x5f]), (0xfe50, true, &[0x2c]), (0xfe51, true, &[0x3001]),
+    (0xfe52, true, &[0x2e]), (0xfe54, true, &[0x3b]), (0xfe55, true, &[0x3a]), (0xfe56, true, &[0x3f]),
+    (0xfe57, true, &[0x21]), (0xfe58, true, &[0x2014]), (0xfe59, true, &[0x28]), (0xfe5a, true, &[0x29]),
+    (0xfe5b, true, &[0x7b]), (0xfe5c, true, &[0x7d]), (0xfe5d, true, &[0x3014]), (0xfe5e, true, &[0x3015]),
+    (0xfe5f, true, &[0x23]), (0xfe60, true, &[0x26]), (0xfe61, true, &[0x2a]), (0xfe62, true, &[0x2b]),
+    (0xfe63, true, &[0x2d]), (0xfe64, true, &[0x3c]), (0xfe65, true, &[0x3e]), (0xfe66, true, &[0x3d]),
+    (0xfe68, true, &[0x5c]), (0xfe69, true, &[0x24]), (0xfe6a, true, &[0x25]), (0xfe6b, true, &[0x40]),
+    (0xfe70, true, &[0x20, 0x64b]), (0xfe71, true, &[0x640, 0x64b]), (0xfe72, true, &[0x20, 0x64c]), (0xfe74, true, &[0x20, 0x64d]),
+    (0xfe76, true, &[0x20, 0x64e]), (0xfe77, true, &[0x640, 0x64e]), (0xfe78, true, &[0x20, 0x64f]), (0xfe79, true, &[0x640, 0x64f]),
+    (0xfe7a, true, &[0x20, 0x650]), (0xfe7b, true, &[0x640, 0x650]), (0xfe7c, true, &[0x20, 0x651]), (0xfe7d, true, &[0x640, 0x651]),
+    (0xfe7e, true, &[0x20, 0x652]), (0xfe7f, true, &[0x640, 0x652]), (0xfe80, true, &[0x621]), (0xfe81, true, &[0x622]),
+    (0xfe82, true, &[0x622]), (0xfe83, true, &[0x623]), (0xfe84, true, &[0x623]), (0xfe85, true, &[0x624]),
+    (0xfe86, true, &[0x624]), (0xfe87, true, &[0x625]), (0xfe88, true, &[0x625]), (0xfe89, true, &[0x626]),
+    (0xfe8a, true, &[0x626]), (0xfe8b, true, &[0x626]), (0xfe8c, true, &[0x626]), (0xfe8d, true, &[0x627]),
+    (0xfe8e, true, &[0x627]), (0xfe8f, true, &[0x628]), (0xfe90, true, &[0x628]), (0xfe91, true, &[0x628]),
+    (0xfe92, true, &[0x628]), (0xfe93, true, &[0x629]), (0xfe94, true, &[0x629]), (0xfe95, true, &[0x62a]),
+    (0xfe96, true, &[0x62a]), (0xfe97, true, &[0x62a]), (0xfe98, true, &[0x62a]), (0xfe99, true, &[0x62b]),
+    (0xfe9a, true, &[0x62b]), (0xfe9b, true, &[0x62b]), (0xfe9c, true, &[0x62b]), (0xfe9d, true, &[0x62c]),
+    (0xfe9e, true, &[0x62c]), (0xfe9f, true, &[0x62c]), (0xfea0, true, &[0x62c]), (0xfea1, true, &[0x62d]),
+    (0xfea2, true, &[0x62d]), (0xfea3, true, &[0x62d]), (0xfea4, true, &[0x62d]), (0xfea5, true, &[0x62e]),
+    (0xfea6, true, &[0x62e]), (0xfea7, true, &[0x62e]), (0xfea8, true, &[0x62e]), (0xfea9, true, &[0x62f]),
+    (0xfeaa, true, &[0x62f]), (0xfeab, true, &[0x630]), (0xfeac, true, &[0x630]), (0xfead, true, &[0x631]),
+    (0xfeae, true, &[0x631]), (0xfeaf, true, &[0x632]), (0xfeb0, true, &[0x632]), (0xfeb1, true, &[0x633]),
+    (0xfeb2, true, &[0x633]), (0xfeb3, true, &[0x633]), (0xfeb4, true, &[0x633]), (0xfeb5, true, &[0x634]),
+    (0xfeb6, true, &[0x634]), (0xfeb7, true, &[0x634]), (0xfeb8, true, &[0x634]), (0xfeb9, true, &[0x635]),
+    (0xfeba, true, &[0x635]), (0xfebb, true, &[0x635]), (0xfebc, true, &[0x635]), (0xfebd, true, &[0x636]),
+    (0xfebe, true, &[0x636]), (0xfebf, true, &[0x636]), (0xfec0, true, &[0x636]), (0xfec1, true, &[0x637]),
+    (0xfec2, true, &[0x637]), (0xfec3, true, &[0x637]), (0xfec4, true, &[0x637]), (0xfec5, true, &[0x638]),
+    (0xfec6, true, &[0x638]), (0xfec7, true, &[0x638]), (0xfec8, true, &[0x638]), (0xfec9, true, &[0x639]),
+    (0xfeca, true, &[0x639]), (0xfecb, true, &[0x639]), (0xfecc, true, &[0x639]), (0xfecd, true, &[0x63a]),
+    (0xfece, true, &[0x63a]), (0xfecf, true, &[0x63a]), (0xfed0, true, &[0x63a]), (0xfed1, true, &[0x641]),
+    (0xfed2, true, &[0x641]), (0xfed3, true, &[0x641]), (0xfed4, true, &[0x641]), (0xfed5, true, &[0x642]),
+    (0xfed6, true, &[0x642]), (0xfed7, true, &[0x642]), (0xfed8, true, &[0x642]), (0xfed9, true, &[0x643]),
+    (0xfeda, true, &[0x643]), (0xfedb, true, &[0x643]), (0xfedc, true, &[0x643]), (0xfedd, true, &[0x644]),
+    (0xfede, true, &[0x644]), (0xfedf, true, &[0x644]), (0xfee0, true, &[0x644]), (0xfee1, true, &[0x645]),
+    (0xfee2, true, &[0x645]), (0xfee3, true, &[0x645]), (0xfee4, true, &[0x645]), (0xfee5, true, &[0x646]),
+    (0xfee6, true, &[0x646]), (0xfee7, true, &[0x646]), (0xfee8, true, &[0x646]), (0xfee9, true, &[0x647]),
+    (0xfeea, true, &[0x647]), (0xfeeb, true, &[0x647]), (0xfeec, true, &[0x647]), (0xfeed, true, &[0x648]),
+    (0xfeee, true, &[0x648]), (0xfeef, true, &[0x649]), (0xfef0, true, &[0x649]), (0xfef1, true, &[0x64a]),
+    (0xfef2, true, &[0x64a]), (0xfef3, true, &[0x64a]), (0xfef4, true, &[0x64a]), (0xfef5, true, &[0x644, 0x622]),
+    (0xfef6, true, &[0x644, 0x622]), (0xfef7, true, &[0x644, 0x623]), (0xfef8, true, &[0x644, 0x623]), (0xfef9, true, &[0x644, 0x625]),
+    (0xfefa, true, &[0x644, 0x625]), (0xfefb, true, &[0x644, 0x627]), (0xfefc, true, &[0x644, 0x627]), (0xff01, true, &[0x21]),
+    (0xff02, true, &[0x22]), (0xff03, true, &[0x23]), (0xff04, true, &[0x24]), (0xff05, true, &[0x25]),
+    (0xff06, true, &[0x26]), (0xff07, true, &[0x27]), (0xff08, true, &[0x28]), (0xff09, true, &[0x29]),
+    (0xff0a, true, &[0x2a]), (0xff0b, true, &[0x2b]), (0xff0c, true, &[0x2c]), (0xff0d, true, &[0x2d]),
+    (0xff0e, true, &[0x2e]), (0xff0f, true, &[0x2f]), (0xff10, true, &[0x30]), (0xff11, true, &[0x31]),
+    (0xff12, true, &[0x32]), (0xff13, true, &[0x33]), (0xff14, true, &[0x34]), (0xff15, true, &[0x35]),
+    (0xff16, true, &[0x36]), (0xff17, true, &[0x37]), (0xff18, true, &[0x38]), (0xff19, true, &[0x39]),
+    (0xff1a, true, &[0x3a]), (0xff1b, true, &[0x3b]), (0xff1c, true, &[0x3c]), (0xff1d, true, &[0x3d]),
+    (0xff1e, true, &[0x3e]), (0xff1f, true, &[0x3f]), (0xff20, true, &[0x40]), (0xff21, true, &[0x41]),
+    (0xff22, true, &[0x42]), (0xff23, true, &[0x43]), (0xff24, true, &[0x44]), (0xff25, true, &[0x45]),
+    (0xff26, true, &[0x46]), (0xff27, true, &[0x47]), (0xff28, true, &[0x48]), (0xff29, true, &[0x49]),
+    (0xff2a, true, &[0x4a]), (0xff2b, true, &[0x4b]), (0xff2c, true, &[0x4c]), (0xff2d, true, &[0x4d]),
+    (0xff2e, true, &[0x4e]), (0xff2f, true, &[0x4f]), (0xff30, true, &[0x50]), (0xff31, true, &[0x51]),
+    (0xff32, true, &[0x52]), (0xff33, true, &[0x53]), (0xff34, true, &[0x54]), (0xff35, true, &[0x55]),
+    (0xff36, true, &[0x56]), (0xff37, true, &[0x57]), (0xff38, true, &[0x58]), (0xff39, true, &[0x59]),
+    (0xff3a, true, &[0x5a]), (0xff3b, true, &[0x5b]), (0xff3c, true, &[0x5c]), (0xff3d, true, &[0x5d]),
+    (0xff3e, true, &[0x5e]), (0xff3f, true, &[0x5f]), (0xff40, true, &[0x60]), (0xff41, true, &[0x61]),
+    (0xff42, true, &[0x62]), (0xff43, true, &[0x63]), (0xff44, true, &[0x64]), (0xff45, true, &[0x65]),
+    (0xff46, true, &[0x66]), (0xff47, true, &[0x67]), (0xff48, true, &[0x68]), (0xff49, true, &[0x69]),
+    (0xff4a, true, &[0x6a]), (0xff4b, true, &[0x6b]), (0xff4c, true, &[0x6c]), (0xff4d, true, &[0x6d]),
+    (0xff4e, true, &[0x6e]), (0xff4f, true, &[0x6f]), (0xff50, true, &[0x70]), (0xff51, true, &[0x71]),
+    (0xff52, true, &[0x72]), (0xff53, true, &[0x73]), (0xff54, true, &[0x74]), (0xff55, true, &[0x75]),
+    (0xff56, true, &[0x76]), (0xff57, true, &[0x77]), (0xff58, true, &[0x78]), (0xff59, true, &[0x79]),
+    (0xff5a, true, &[0x7a]), (0xff5b, true, &[0x7b]), (0xff5c, true, &[0x7c]), (0xff5d, true, &[0x7d]),
+    (0xff5e, true, &[0x7e]), (0xff5f, true, &[0x2985]), (0xff60, true, &[0x2986]), (0xff61, true, &[0x3002]),
+    (0xff62, true, &[0x300c]), (0xff63, true, &[0x300d]), (0xff64, true, &[0x3001]), (0xff65, true, &[0x30fb]),
+    (0xff66, true, &[0x30f2]), (0xff67, true, &[0x30a1]), (0xff68, true, &[0x30a3]), (0xff69, true, &[0x30a5]),
+    (0xff6a, true, &[0x30a7]), (0xff6b, true, &[0x30a9]), (0xff6c, true, &[0x30e3]), (0xff6d, true, &[0x30e5]),
+    (0xff6e, true, &[0x30e7]), (0xff6f, true, &[0x30c3]), (0xff70, true, &[0x30fc]), (0xff71, true, &[0x30a2]),
+    (0xff72, true, &[0x30a4]), (0xff73, true, &[0x30a6]), (0xff74, true, &[0x30a8]), (0xff75, true, &[0x30aa]),
+    (0xff76, true, &[0x30ab]), (0xff77, true, &[0x30ad]), (0xff78, true, &[0x30af]), (0xff79, true, &[0x30b1]),
+    (0xff7a, true, &[0x30b3]), (0xff7b, true, &[0x30b5]), (0xff7c, true, &[0x30b7]), (0xff7d, true, &[0x30b9]),
+    (0xff7e, true, &[0x30bb]), (0xff7f, true, &[0x30bd]), (0xff80, true, &[0x30bf]), (0xff81, true, &[0x30c1]),
+    (0xff82, true, &[0x30c4]), (0xff83, true, &[0x30c6]), (0xff84, true, &[0x30c8]), (0xff85, true, &[0x30ca]),
+    (0xff86, true, &[0x30cb]), (0xff87, true, &[0x30cc]), (0xff88, true, &[0x30cd]), (0xff89, true, &[0x30ce]),
+    (0xff8a, true, &[0x30cf]), (0xff8b, true, &[0x30d2]), (0xff8c, true, &[0x30d5]), (0xff8d, true, &[0x30d8]),
+    (0xff8e, true, &[0x30db]), (0xff8f, true, &[0x30de]), (0xff90, true, &[0x30df]), (0xff91, true, &[0x30e0]),
+    (0xff92, true, &[0x30e1]), (0xff93, true, &[0x30e2]), (0xff94, true, &[0x30e4]), (0xff95, true, &[0x30e6]),
+    (0xff96, true, &[0x30e8]), (0xff97, true, &[0x30e9]), (0xff98, true, &[0x30ea]), (0xff99, true, &[0x30eb]),
+    (0xff9a, true, &[0x30ec]), (0xff9b, true, &[0x30ed]), (0xff9c, true, &[0x30ef]), (0xff9d, true, &[0x30f3]),
+    (0xff9e, true, &[0x3099]), (0xff9f, true, &[0x309a]), (0xffa0, true, &[0x3164]), (0xffa1, true, &[0x3131]),
+    (0xffa2, true, &[0x3132]), (0xffa3, true, &[0x3133]), (0xffa4, true, &[0x3134]), (0xffa5, true, &[0x3135]),
+    (0xffa6, true, &[0x3136]), (0xffa7, true, &[0x3137]), (0xffa8, true, &[0x3138]), (0xffa9, true, &[0x3139]),
+    (0xffaa, true, &[0x313a]), (0xffab, true, &[0x313b]), (0xffac, true, &[0x313c]), (0xffad, true, &[0x313d]),
+    (0xffae, true, &[0x313e]), (0xffaf, true, &[0x313f]), (0xffb0, true, &[0x3140]), (0xffb1, true, &[0x3141]),
+    (0xffb2, true, &[0x3142]), (0xffb3, true, &[0x3143]), (0xffb4, true, &[0x3144]), (0xffb5, true, &[0x3145]),
+    (0xffb6, true, &[0x3146]), (0xffb7, true, &[0x3147]), (0xffb8, true, &[0x3148]), (0xffb9, true, &[0x3149]),
+    (0xffba, true, &[0x314a]), (0xffbb, true, &[0x314b]), (0xffbc, true, &[0x314c]), (0xffbd, true, &[0x314d]),
+    (0xffbe, true, &[0x314e]), (0xffc2, true, &[0x314f]), (0xffc3, true, &[0x3150]), (0xffc4, true, &[0x3151]),
+    (0xffc5, true, &[0x3152]), (0xffc6, true, &[0x3153]), (0xffc7, true, &[0x3154]), (0xffca, true, &[0x3155]),
+    (0xffcb, true, &[0x3156]), (0xffcc, true, &[0x3157]), (0xffcd, true, &[0x3158]), (0xffce, true, &[0x3159]),
+    (0xffcf, true, &[0x315a]), (0xffd2, true, &[0x315b]), (0xffd3, true, &[0x315c]), (0xffd4, true, &[0x315d]),
+    (0xffd5, true, &[0x315e]), (0xffd6, true, &[0x315f]), (0xffd7, true, &[0x3160]), (0xffda, true, &[0x3161]),
+    (0xffdb, true, &[0x3162]), (0xffdc, true, &[0x3163]), (0xffe0, true, &[0xa2]), (0xffe1, true, &[0xa3]),
+    (0xffe2, true, &[0xac]), (0xffe3, true, &[0xaf]), (0xffe4, true, &[0xa6]), (0xffe5, true, &[0xa5]),
+    (0xffe6, true, &[0x20a9]), (0xffe8, true, &[0x2502]), (0xffe9, true, &[0x2190]), (0xffea, true, &[0x2191]),
+    (0xffeb, true, &[0x2192]), (0xffec, true, &[0x2193]), (0xffed, true, &[0x25a0]), (0xffee, true, &[0x25cb]),
+    (0x10781, true, &[0x2d0]), (0x10782, true, &[0x2d1]), (0x10783, true, &[0xe6]), (0x10784, true, &[0x299]),
+    (0x10785, true, &[0x253]), (0x10787, true, &[0x2a3]), (0x10788, true, &[0xab66]), (0x10789, true, &[0x2a5]),
+    (0x1078a, true, &[0x2a4]), (0x1078b, true, &[0x256]), (0x1078c, true, &[0x257]), (0x1078d, true, &[0x1d91]),
+    (0x1078e, true, &[0x258]), (0x1078f, true, &[0x25e]), (0x10790, true, &[0x2a9]), (0x10791, true, &[0x264]),
+    (0x10792, true, &[0x262]), (0x10793, true, &[0x260]), (0x10794, true, &[0x29b]), (0x10795, true, &[0x127]),
+    (0x10796, true, &[0x29c]), (0x10797, true, &[0x267]), (0x10798, true, &[0x284]), (0x10799, true, &[0x2aa]),
+    (0x1079a, true, &[0x2ab]), (0x1079b, true, &[0x26c]), (0x1079c, true, &[0x1df04]), (0x1079d, true, &[0xa78e]),
+    (0x1079e, true, &[0x26e]), (0x1079f, true, &[0x1df05]), (0x107a0, true, &[0x28e]), (0x107a1, true, &[0x1df06]),
+    (0x107a2, true, &[0xf8]), (0x107a3, true, &[0x276]), (0x107a4, true, &[0x277]), (0x107a5, true, &[0x71]),
+    (0x107a6, true, &[0x27a]), (0x107a7, true, &[0x1df08]), (0x107a8, true, &[0x27d]), (0x107a9, true, &[0x27e]),
+    (0x107aa, true, &[0x280]), (0x107ab, true, &[0x2a8]), (0x107ac, true, &[0x2a6]), (0x107ad, true, &[0xab67]),
+    (0x107ae, true, &[0x2a7]), (0x107af, true, &[0x288]), (0x107b0, true, &[0x2c71]), (0x107b2, true, &[0x28f]),
+    (0x107b3, true, &[0x2a1]), (0x107b4, true, &[0x2a2]), (0x107b5, true, &[0x298]), (0x107b6, true, &[0x1c0]),
+    (0x107b7, true, &[0x1c1]), (0x107b8, true, &[0x1c2]), (0x107b9, true, &[0x1df0a]), (0x107ba, true, &[0x1df1e]),
+    (0x1109a, false, &[0x11099, 0x110ba]), (0x1109c, false, &[0x1109b, 0x110ba]), (0x110ab, false, &[0x110a5, 0x110ba]), (0x1112e, false, &[0x11131, 0x11127]),
+    (0x1112f, false, &[0x11132, 0x11127]), (0x1134b, false, &[0x11347, 0x1133e]), (0x1134c, false, &[0x11347, 0x11357]), (0x114bb, false, &[0x114b9, 0x114ba]),
+    (0x114bc, false, &[0x114b9, 0x114b0]), (0x114be, false, &[0x114b9, 0x114bd]), (0x115ba, false, &[0x115b8, 0x115af]), (0x115bb, false, &[0x115b9, 0x115af]),
+    (0x11938, false, &[0x11935, 0x11930]), (0x1d15e, false, &[0x1d157, 0x1d165]), (0x1d15f, false, &[0x1d158, 0x1d165]), (0x1d160, false, &[0x1d15f, 0x1d16e]),
+    (0x1d161, false, &[0x1d15f, 0x1d16f]), (0x1d162, false, &[0x1d15f, 0x1d170]), (0x1d163, false, &[0x1d15f, 0x1d171]), (0x1d164, false, &[0x1d15f, 0x1d172]),
+    (0x1d1bb, false, &[0x1d1b9, 0x1d165]), (0x1d1bc, false, &[0x1d1ba, 0x1d165]), (0x1d1bd, false, &[0x1d1bb, 0x1d16e]), (0x1d1be, false, &[0x1d1bc, 0x1d16e]),
+    (0x1d1bf, false, &[0x1d1bb, 0x1d16f]), (0x1d1c0, false, &[0x1d1bc, 0x1d16f]), (0x1d400, true, &[0x41]), (0x1d401, true, &[0x42]),
+    (0x1d402, true, &[0x43]), (0x1d403, true, &[0x44]), (0x1d404, true, &[0x45]), (0x1d405, true, &[0x46]),
+    (0x1d406, true, &[0x47]), (0x1d407, true, &[0x48]), (0x1d408, true, &[0x49]), (0x1d409, true, &[0x4a]),
+    (0x1d40a, true, &[0x4b]), (0x1d40b, true, &[0x4c]), (0x1d40c, true, &[0x4d]), (0x1d40d, true, &[0x4e]),
+    (0x1d40e, true, &[0x4f]), (0x1d40f, true, &[0x50]), (0x1d410, true, &[0x51]), (0x1d411, true, &[0x52]),
+    (0x1d412, true, &[0x53]), (0x1d413, true, &[0x54]), (0x1d414, true, &[0x55]), (0x1d415, true, &[0x56]),
+    (0x1d416, true, &[0x57]), (0x1d417, true, &[0x58]), (0x1d418, true, &[0x59]), (0x1d419, true, &[0x5a]),
+    (0x1d41a, true, &[0x61]), (0x1d41b, true, &[0x62]), (0x1d41c, true, &[0x63]), (0x1d41d, true, &[0x64]),
+    (0x1d41e, true, &[0x65]), (0x1d41f, true, &[0x66]), (0x1d420, true, &[0x67]), (0x1d421, true, &[0x68]),
+    (0x1d422, true, &[0x69]), (0x1d423, true, &[0x6a]), (0x1d424, true, &[0x6b]), (0x1d425, true, &[0x6c]),
+    (0x1d426, true, &[0x6d]), (0x1d427, true, &[0x6e]), (0x1d428, true, &[0x6f]), (0x1d429, true, &[0x70]),
+    (0x1d42a, true, &[0x71]), (0x1d42b, true, &[0x72]), (0x1d42c, true, &[0x73]), (0x1d42d, true, &[0x74]),
+    (0x1d42e, true, &[0x75]), (0x1d42f, true, &[0x76]), (0x1d430, true, &[0x77]), (0x1d431, true, &[0x78]),
+    (0x1d432, true, &[0x79]), (0x1d433, true, &[0x7a]), (0x1d434, true, &[0x41]), (0x1d435, true, &[0x42]),
+    (0x1d436, true, &[0x43]), (0x1d437, true, &[0x44]), (0x1d438, true, &[0x45]), (0x1d439, true, &[0x46]),
+    (0x1d43a, true, &[0x47]), (0x1d43b, true, &[0x48]), (0x1d43c, true, &[0x49]), (0x1d43d, true, &[0x4a]),
+    (0x1d43e, true, &[0x4b]), (0x1d43f, true, &[0x4c]), (0x1d440, true, &[0x4d]), (0x1d441, true, &[0x4e]),
+    (0x1d442, true, &[0x4f]), (0x1d443, true, &[0x50]), (0x1d444, true, &[0x51]), (0x1d445, true, &[0x52]),
+    (0x1d446, true, &[0x53]), (0x1d447, true, &[0x54]), (0x1d448, true, &[0x55]), (0x1d449, true, &[0x56]),
+    (0x1d44a, true, &[0x57]), (0x1d44b, true, &[0x58]), (0x1d44c, true, &[0x59]), (0x1d44d, true, &[0x5a]),
+    (0x1d44e, true, &[0x61]), (0x1d44f, true, &[0x62]), (0x1d450, true, &[0x63]), (0x1d451, true, &[0x64]),
+    (0x1d452, true, &[0x65]), (0x1d453, true, &[0x66]), (0x1d454, true, &[0x67]), (0x1d456, true, &[0x69]),
+    (0x1d457, true, &[0x6a]), (0x1d458, true, &[0x6b]), (0x1d459, true, &[0x6c]), (0x1d45a, true, &[0x6d]),
+    (0x1d45b, true, &[0x6e]), (0x1d45c, true, &[0x6f]), (0x1d45d, true, &[0x70]), (0x1d45e, true, &[0x71]),
+    (0x1d45f, true, &[0x72]), (0x1d460, true, &[0x73]), (0x1d461, true, &[0x74]), (0x1d462, true, &[0x75]),
+    (0x1d463, true, &[0x76]), (0x1d464, true, &[0x77]), (0x1d465, true, &[0x78]), (0x1d466, true, &[0x79]),
+    (0x1d467, true, &[0x7a]), (0x1d468, true, &[0x41]), (0x1d469, true, &[0x42]), (0x1d46a, true, &[0x43]),
+    (0x1d46b, true, &[0x44]), (0x1d46c, true, &[0x45]), (0x1d46d, true, &[0x46]), (0x1d46e, true, &[0x47]),
+    (0x1d46f, true, &[0x48]), (0x1d470, true, &[0x49]), (0x1d471, true, &[0x4a]), (0x1d472, true, &[0x4b]),
+    (0x1d473, true, &[0x4c]), (0x1d474, true, &[0x4d]), (0x1d475, true, &[0x4e]), (0x1d476, true, &[0x4f]),
+    (0x1d477, true, &[0x50]), (0x1d478, true, &[0x51]), (0x1d479, true, &[0x52]), (0x1d47a, true, &[0x53]),
+    (0x1d47b, true, &[0x54]), (0x1d47c, true, &[0x55]), (0x1d47d, true, &[0x56]), (0x1d47e, true, &[0x57]),
+    (0x1d47f, true, &[0x58]), (0x1d480, true, &[0x59]), (0x1d481, true, &[0x5a]), (0x1d482, true, &[0x61]),
+    (0x1d483, true, &[0x62]), (0x1d484, true, &[0x63]), (0x1d485, true, &[0x64]), (0x1d486, true, &[0x65]),
+    (0x1d487, true, &[0x66]), (0x1d488, true, &[0x67]), (0x1d489, true, &[0x68]), (0x1d48a, true, &[0x69]),
+    (0x1d48b, true, &[0x6a]), (0x1d48c, true, &[0x6b]), (0x1d48d, true, &[0x6c]), (0x1d48e, true, &[0x6d]),
+    (0x1d48f, true, &[0x6e]), (0x1d490, true, &[0x6f]), (0x1d491, true, &[0x70]), (0x1d492, true, &[0x71]),
+    (0x1d493, true, &[0x72]), (0x1d494, true, &[0x73]), (0x1d495, true, &[0x74]), (0x1d496, true, &[0x75]),
+    (0x1d497, true, &[0x76]), (0x1d498, true, &[0x77]), (0x1d499, true, &[0x78]), (0x1d49a, true, &[0x79]),
+    (0x1d49b, true, &[0x7a]), (0x1d49c, true, &[0x41]), (0x1d49e, true, &[0x43]), (0x1d49f, true, &[0x44]),
+    (0x1d4a2, true, &[0x47]), (0x1d4a5, true, &[0x4a]), (0x1d4a6, true, &[0x4b]), (0x1d4a9, true, &[0x4e]),
+    (0x1d4aa, true, &[0x4f]), (0x1d4ab, true, &[0x50]), (0x1d4ac, true, &[0x51]), (0x1d4ae, true, &[0x53]),
+    (0x1d4af, true, &[0x54]), (0x1d4b0, true, &[0x55]), (0x1d4b1, true, &[0x56]), (0x1d4b2, true, &[0x57]),
+    (0x1d4b3, true, &[0x58]), (0x1d4b4, true, &[0x59]), (0x1d4b5, true, &[0x5a]), (0x1d4b6, true, &[0x61]),
+    (0x1d4b7, true, &[0x62]), (0x1d4b8, true, &[0x63]), (0x1d4b9, true, &[0x64]), (0x1d4bb, true, &[0x66]),
+    (0x1d4bd, true, &[0x68]), (0x1d4be, true, &[0x69]), (0x1d4bf, true, &[0x6a]), (0x1d4c0, true, &[0x6b]),
+    (0x1d4c1, true, &[0x6c]), (0x1d4c2, true, &[0x6d]), (0x1d4c3, true, &[0x6e]), (0x1d4c5, true, &[0x70]),
+    (0x1d4c6, true, &[0x71]), (0x1d4c7, true, &[0x72]), (0x1d4c8, true, &[0x73]), (0x1d4c9, true, &[0x74]),
+    (0x1d4ca, true, &[0x75]), (0x1d4cb, true, &[0x76]), (0x1d4cc, true, &[0x77]), (0x1d4cd, true, &[0x78]),
+    (0x1d4ce, true, &[0x79]), (0x1d4cf, true, &[0x7a]), (0x1d4d0, true, &[0x41]), (0x1d4d1, true, &[0x42]),
+    (0x1d4d2, true, &[0x43]), (0x1d4d3, true, &[0x44]), (0x1d4d4, true, &[0x45]), (0x1d4d5, true, &[0x46]),
+    (0x1d4d6, true, &[0x47]), (0x1d4d7, true, &[0x48]), (0x1d4d8, true, &[0x49]), (0x1d4d9, true, &[0x4a]),
+    (0x1d4da, true, &[0x4b]), (0x1d4db, true, &[0x4c]), (0x1d4dc, true, &[0x4d]), (0x1d4dd, true, &[0x4e]),
+    (0x1d4de, true, &[0x4f]), (0x1d4df, true, &[0x50]), (0x1d4e0, true, &[0x51]), (0x1d4e1, true, &[0x52]),
+    (0x1d4e2, true, &[0x53]), (0x1d4e3, true, &[0x54]), (0x1d4e4, true, &[0x55]), (0x1d4e5, true, &[0x56]),
+    (0x1d4e6, true, &[0x57]), (0x1d4e7, true, &[0x58]), (0x1d4e8, true, &[0x59]), (0x1d4e9, true, &[0x5a]),
+    (0x1d4ea, true, &[0x61]), (0x1d4eb, true, &[0x62]), (0x1d4ec, true, &[0x63]), (0x1d4ed, true, &[0x64]),
+    (0x1d4ee, true, &[0x65]), (0x1d4ef, true, &[0x66]), (0x1d4f0, true, &[0x67]), (0x1d4f1, true, &[0x68]),
+    (0x1d4f2, true, &[0x69]), (0x1d4f3, true, &[0x6a]), (0x1d4f4, true, &[0x6b]), (0x1d4f5, true, &[0x6c]),
+    (0x1d4f6, true, &[0x6d]), (0x1d4f7, true, &[0x6e]), (0x1d4f8, true, &[0x6f]), (0x1d4f9, true, &[0x70]),
+    (0x1d4fa, true, &[0x71]), (0x1d4fb, true, &[0x72]), (0x1d4fc, true, &[0x73]), (0x1d4fd, true, &[0x74]),
+    (0x1d4fe, true, &[0x75]), (0x1d4ff, true, &[0x76]), (0x1d500, true, &[0x77]), (0x1d501, true, &[0x78]),
+    (0x1d502, true, &[0x79]), (0x1d503, true, &[0x7a]), (0x1d504, true, &[0x41]), (0x1d505, true, &[0x42]),
+    (0x1d507, true, &[0x44]), (0x1d508, true, &[0x45]), (0x1d509, true, &[0x46]), (0x1d50a, true, &[0x47]),
+    (0x1d50d, true, &[0x4a]), (0x1d50e, true, &[0x4b]), (0x1d50f, true, &[0x4c]), (0x1d510, true, &[0x4d]),
+    (0x1d511, true, &[0x4e]), (0x1d512, true, &[0x4f]), (0x1d513, true, &[0x50]), (0x1d514, true, &[0x51]),
+    (0x1d516, true, &[0x53]), (0x1d517, true, &[0x54]), (0x1d518, true, &[0x55]), (0x1d519, true, &[0x56]),
+    (0x1d51a, true, &[0x57]), (0x1d51b, true, &[0x58]), (0x1d51c, true, &[0x59]), (0x1d51e, true, &[0x61]),
+    (0x1d51f, true, &[0x62]), (0x1d520, true, &[0x63]), (0x1d521, true, &[0x64]), (0x1d522, true, &[0x65]),
+    (0x1d523, true, &[0x66]), (0x1d524, true, &[0x67]), (0x1d525, true, &[0x68]), (0x1d526, true, &[0x69]),
+    (0x1d527, true, &[0x6a]), (0x1d528, true, &[0x6b]), (0x1d529, true, &[0x6c]), (0x1d52a, true, &[0x6d]),
+    (0x1d52b, true, &[0x6e]), (0x1d52c, true, &[0x6f]), (0x1d52d, true, &[0x70]), (0x1d52e, true, &[0x71]),
+    (0x1d52f, true, &[0x72]), (0x1d530, true, &[0x73]), (0x1d531, true, &[0x74]), (0x1d532, true, &[0x75]),
+    (0x1d533, true, &[0x76]), (0x1d534, true, &[0x77]), (0x1d535, true, &[0x78]), (0x1d536, true, &[0x79]),
+    (0x1d537, true, &[0x7a]), (0x1d538, true, &[0x41]), (0x1d539, true, &[0x42]), (0x1d53b, true, &[0x44]),
+    (0x1d53c, true, &[0x45]), (0x1d53d, true, &[0x46]), (0x1d53e, true, &[0x47]), (0x1d540, true, &[0x49]),
+    (0x1d541, true, &[0x4a]), (0x1d542, true, &[0x4b]), (0x1d543, true, &[0x4c]), (0x1d544, true, &[0x4d]),
+    (0x1d546, true, &[0x4f]), (0x1d54a, true, &[0x53]), (0x1d54b, true, &[0x54]), (0x1d54c, true, &[0x55]),
+    (0x1d54d, true, &[0x56]), (0x1d54e, true, &[0x57]), (0x1d54f, true, &[0x58]), (0x1d550, true, &[0x59]),
+    (0x1d552, true, &[0x61]), (0x1d553, true, &[0x62]), (0x1d554, true, &[0x63]), (0x1d555, true, &[0x64]),
+    (0x1d556, true, &[0x65]), (0x1d557, true, &[0x66]), (0x1d558, true, &[0x67]), (0x1d559, true, &[0x68]),
+    (0x1d55a, true, &[0x69]), (0x1d55b, true, &[0x6a]), (0x1d55c, true, &[0x6b]), (0x1d55d, true, &[0x6c]),
+    (0x1d55e, true, &[0x6d]), (0x1d55f, true, &[0x6e]), (0x1d560, true, &[0x6f]), (0x1d561, true, &[0x70]),
+    (0x1d562, true, &[0x71]), (0x1d563, true, &[0x72]), (0x1d564, true, &[0x73]), (0x1d565, true, &[0x74]),
+    (0x1d566, true, &[0x75]), (0x1d567, true, &[0x76]), (0x1d568, true, &[0x77]), (0x1d569, true, &[0x78]),
+    (0x1d56a, true, &[0x79]), (0x1d56b, true, &[0x7a]), (0x1d56c, true, &[0x41]), (0x1d56d, true, &[0x42]),
+    (0x1d56e, true, &[0x43]), (0x1d56f, true, &[0x44]), (0x1d570, true, &[0x45]), (0x1d571, true, &[0x46]),
+    (0x1d572, true, &[0x47]), (0x1d573, true, &[0x48]), (0x1d574, true, &[0x49]), (0x1d575, true, &[0x4a]),
+    (0x1d576, true, &[0x4b]), (0x1d577, true, &[0x4c]), (0x1d578, true, &[0x4d]), (0x1d579, true, &[0x4e]),
+    (0x1d57a, true, &[0x4f]), (0x1d57b, true, &[0x50]), (0x1d57c, true, &[0x51]), (0x1d57d, true, &[0x52]),
+    (0x1d57e, true, &[0x53]), (0x1d57f, true, &[0x54]), (0x1d580, true, &[0x55]), (0x1d581, true, &[0x56]),
+    (0x1d582, true, &[0x57]), (0x1d583, true, &[0x58]), (0x1d584, true, &[0x59]), (0x1d585, true, &[0x5a]),
+    (0x1d586, true, &[0x61]), (0x1d587, true, &[0x62]), (0x1d588, true, &[0x63]), (0x1d589, true, &[0x64]),
+    (0x1d58a, true, &[0x65]), (0x1d58b, true, &[0x66]), (0x1d58c, true, &[0x67]), (0x1d58d, true, &[0x68]),
+    (0x1d58e, true, &[0x69]), (0x1d58f, true, &[0x6a]), (0x1d590, true, &[0x6b]), (0x1d591, true, &[0x6c]),
+    (0x1d592, true, &[0x6d]), (0x1d593, true, &[0x6e]), (0x1d594, true, &[0x6f]), (0x1d595, true, &[0x70]),
+    (0x1d596, true, &[0x71]), (0x1d597, true, &[0x72]), (0x1d598, true, &[0x73]), (0x1d599, true, &[0x74]),
+    (0x1d59a, true, &[0x75]), (0x1d59b, true, &[0x76]), (0x1d59c, true, &[0x77]), (0x1d59d, true, &[0x78]),
+    (0x1d59e, true, &[0x79]), (0x1d59f, true, &[0x7a]), (0x1d5a0, true, &[0x41]), (0x1d5a1, true, &[0x42]),
+    (0x1d5a2, true, &[0x43]), (0x1d5a3, true, &[0x44]), (0x1d5a4, true, &[0x45]), (0x1d5a5, true, &[0x46]),
+    (0x1d5a6, true, &[0x47]), (0x1d5a7, true, &[0x48]), (0x1d5a8, true, &[0x49]), (0x1d5a9, true, &[0x4a]),
+    (0x1d5aa, true, &[0x4b]), (0x1d5ab, true, &[0x4c]), (0x1d5ac, true, &[0x4d]), (0x1d5ad, true, &[0x4e]),
+    (0x1d5ae, true, &[0x4f]), (0x1d5af, true, &[0x50]), (0x1d5b0, true, &[0x51]), (0x1d5b1, true, &[0x52]),
+    (0x1d5b2, true, &[0x53]), (0x1d5b3, true, &[0x54]), (0x1d5b4, true, &[0x55]), (0x1d5b5, true, &[0x56]),
+    (0x1d5b6, true, &[0x57]), (0x1d5b7, true, &[0x58]), (0x1d5b8, true, &[0x59]), (0x1d5b9, true, &[0x5a]),
+    (0x1d5ba, true, &[0x61]), (0x1d5bb, true, &[0x62]), (0x1d5bc, true, &[0x63]), (0x1d5bd, true, &[0x64]),
+    (0x1d5be, true, &[0x65]), (0x1d5bf, true, &[0x66]), (0x1d5c0, true, &[0x67]), (0x1d5c1, true, &[0x68]),
+    (0x1d5c2, true, &[0x69]), (0x1d5c3, true, &[0x6a]), (0x1d5c4, true, &[0x6b]), (0x1d5c5, true, &[0x6c]),
+    (0x1d5c6, true, &[0x6d]), (0x1d5c7, true, &[0x6e]), (0x1d5c8, true, &[0x6f]), (0x1d5c9, true, &[0x70]),
+    (0x1d5ca, true, &[0x71]), (0x1d5cb, true, &[0x72]), (0x1d5cc, true, &[0x73]), (0x1d5cd, true, &[0x74]),
+    (0x1d5ce, true, &[0x75]), (0x1d5cf, true, &[0x76]), (0x1d5d0, true, &[0x77]), (0x1d5d1, true, &[0x78]),
+    (0x1d5d2, true, &[0x79]), (0x1d5d3, true, &[0x7a]), (0x1d5d4, true, &[0x41]), (0x1d5d5, true, &[0x42]),
+    (0x1d5d6, true, &[0x43]), (0x1d5d7, true, &[0x44]), (0x1d5d8, true, &[0x45]), (0x1d5d9, true, &[0x46]),
+    (0x1d5da, true, &[0x47]), (0x1d5db, true, &[0x48]), (0x1d5dc, true, &[0x49]), (0x1d5dd, true, &[0x4a]),
+    (0x1d5de, true, &[0x4b]), (0x1d5df, true, &[0x4c]), (0x1d5e0, true, &[0x4d]), (0x1d5e1, true, &[0x4e]),
+    (0x1d5e2, true, &[0x4f]), (0x1d5e3, true, &[0x50]), (0x1d5e4, true, &[0x51]), (0x1d5e5, true, &[0x52]),
+    (0x1d5e6, true, &[0x53]), (0x1d5e7, true, &[0x54]), (0x1d5e8, true, &[0x55]), (0x1d5e9, true, &[0x56]),
+    (0x1d5ea, true, &[0x57]), (0x1d5eb, true, &[0x58]), (0x1d5ec, true, &[0x59]), (0x1d5ed, true, &[0x5a]),
+    (0x1d5ee, true, &[0x61]), (0x1d5ef, true, &[0x62]), (0x1d5f0, true, &[0x63]), (0x1d5f1, true, &[0x64]),
+    (0x1d5f2, true, &[0x65]), (0x1d5f3, true, &[0x66]), (0x1d5f4, true, &[0x67]), (0x1d5f5, true, &[0x68]),
+    (0x1d5f6, true, &[0x69]), (0x1d5f7, true, &[0x6a]), (0x1d5f8, true, &[0x6b]), (0x1d5f9, true, &[0x6c]),
+    (0x1d5fa, true, &[0x6d]), (0x1d5fb, true, &[0x6e]), (0x1d5fc, true, &[0x6f]), (0x1d5fd, true, &[0x70]),
+    (0x1d5fe, true, &[0x71]), (0x1d5ff, true, &[0x72]), (0x1d600, true, &[0x73]), (0x1d601, true, &[0x74]),
+    (0x1d602, true, &[0x75]), (0x1d603, true, &[0x76]), (0x1d604, true, &[0x77]), (0x1d605, true, &[0x78]),
+    (0x1d606, true, &[0x79]), (0x1d607, true, &[0x7a]), (0x1d608, true, &[0x41]), (0x1d609, true, &[0x42]),
+    (0x1d60a, true, &[0x43]), (0x1d60b, true, &[0x44]), (0x1d60c, true, &[0x45]), (0x1d60d, true, &[0x46]),
+    (0x1d60e, true, &[0x47]), (0x1d60f, true, &[0x48]), (0x1d610, true, &[0x49]), (0x1d611, true, &[0x4a]),
+    (0x1d612, true, &[0x4b]), (0x1d613, true, &[0x4c]), (0x1d614, true, &[0x4d]), (0x1d615, true, &[0x4e]),
+    (0x1d616, true, &[0x4f]), (0x1d617, true, &[0x50]), (0x1d618, true, &[0x51]), (0x1d619, true, &[0x52]),
+    (0x1d61a, true, &[0x53]), (0x1d61b, true, &[0x54]), (0x1d61c, true, &[0x55]), (0x1d61d, true, &[0x56]),
+    (0x1d61e, true, &[0x57]), (0x1d61f, true, &[0x58]), (0x1d620, true, &[0x59]), (0x1d621, true, &[0x5a]),
+    (0x1d622, true, &[0x61]), (0x1d623, true, &[0x62]), (0x1d624, true, &[0x63]), (0x1d625, true, &[0x64]),
+    (0x1d626, true, &[0x65]), (0x1d627, true, &[0x66]), (0x1d628, true, &[0x67]), (0x1d629, true, &[0x68]),
+    (0x1d62a, true, &[0x69]), (0x1d62b, true, &[0x6a]), (0x1d62c, true, &[0x6b]), (0x1d62d, true, &[0x6c]),
+    (0x1d62e, true, &[0x6d]), (0x1d62f, true, &[0x6e]), (0x1d630, true, &[0x6f]), (0x1d631, true, &[0x70]),
+    (0x1d632, true, &[0x71]), (0x1d633, true, &[0x72]), (0x1d634, true, &[0x73]), (0x1d635, true, &[0x74]),
+    (0x1d636, true, &[0x75]), (0x1d637, true, &[0x76]), (0x1d638, true, &[0x77]), (0x1d639, true, &[0x78]),
+    (0x1d63a, true, &[0x79]), (0x1d63b, true, &[0x7a]), (0x1d63c, true, &[0x41]), (0x1d63d, true, &[0x42]),
+    (0x1d63e, true, &[0x43]), (0x1d63f, true, &[0x44]), (0x1d640, true, &[0x45]), (0x1d641, true, &[0x46]),
+    (0x1d642, true, &[0x47]), (0x1d643, true, &[0x48]), (0x1d644, true, &[0x49]), (0x1d645, true, &[0x4a]),
+    (0x1d646, true, &[0x4b]), (0x1d647, true, &[0x4c]), (0x1d648, true, &[0x4d]), (0x1d649, true, &[0x4e]),
+    (0x1d64a, true, &[0x4f]), (0x1d64b, true, &[0x50]), (0x1d64c, true, &[0x51]), (0x1d64d, true, &[0x52]),
+    (0x1d64e, true, &[0x53]), (0x1d64f, true, &[0x54]), (0x1d650, true, &[0x55]), (0x1d651, true, &[0x56]),
+    (0x1d652, true, &[0x57]), (0x1d653, true, &[0x58]), (0x1d654, true, &[0x59]), (0x1d655, true, &[0x5a]),
+    (0x1d656, true, &[0x61]), (0x1d657, true, &[0x62]), (0x1d658, true, &[0x63]), (0x1d659, true, &[0x64]),
+    (0x1d65a, true, &[0x65]), (0x1d65b, true, &[0x66]), (0x1d65c, true, &[0x67]), (0x1d65d, true, &[0x68]),
+    (0x1d65e, true, &[0x69]), (0x1d65f, true, &[0x6a]), (0x1d660, true, &[0x6b]), (0x1d661, true, &[0x6c]),
+    (0x1d662, true, &[0x6d]), (0x1d663, true, &[0x6e]), (0x1d664, true, &[0x6f]), (0x1d665, true, &[0x70]),
+    (0x1d666, true, &[0x71]), (0x1d667, true, &[0x72]), (0x1d668, true, &[0x73]), (0x1d669, true, &[0x74]),
+    (0x1d66a, true, &[0x75]), (0x1d66b, true, &[0x76]), (0x1d66c, true, &[0x77]), (0x1d66d, true, &[0x78]),
+    (0x1d66e, true, &[0x79]), (0x1d66f, true, &[0x7a]), (0x1d670, true, &[0x41]), (0x1d671, true, &[0x42]),
+    (0x1d672, true, &[0x43]), (0x1d673, true, &[0x44]), (0x1d674, true, &[0x45]), (0x1d675, true, &[0x46]),
+    (0x1d676, true, &[0x47]), (0x1d677, true, &[0x48]), (0x1d678, true, &[0x49]), (0x1d679, true, &[0x4a]),
+    (0x1d67a, true, &[0x4b]), (0x1d67b, true, &[0x4c]), (0x1d67c, true, &[0x4d]), (0x1d67d, true, &[0x4e]),
+    (0x1d67e, true, &[0x4f]), (0x1d67f, true, &[0x50]), (0x1d680, true, &[0x51]), (0x1d681, true, &[0x52]),
+    (0x1d682, true, &[0x53]), (0x1d683, true, &[0x54]), (0x1d684, true, &[0x55]), (0x1d685, true, &[0x56]),
+    (0x1d686, true, &[0x57]), (0x1d687, true, &[0x58]), (0x1d688, true, &[0x59]), (0x1d689, true, &[0x5a]),
+    (0x1d68a, true, &[0x61]), (0x1d68b, true, &[0x62]), (0x1d68c, true, &[0x63]), (0x1d68d, true, &[0x64]),
+    (0x1d68e, true, &[0x65]), (0x1d68f, true, &[0x66]), (0x1d690, true, &[0x67]), (0x1d691, true, &[0x68]),
+    (0x1d692, true, &[0x69]), (0x1d693, true, &[0x6a]), (0x1d694, true, &[0x6b]), (0x1d695, true, &[0x6c]),
+    (0x1d696, true, &[0x6d]), (0x1d697, true, &[0x6e]), (0x1d698, true, &[0x6f]), (0x1d699, true, &[0x70]),
+    (0x1d69a, true, &[0x71]), (0x1d69b, true, &[0x72]), (0x1d69c, true, &[0x73]), (0x1d69d, true, &[0x74]),
+    (0x1d69e, true, &[0x75]), (0x1d69f, true, &[0x76]), (0x1d6a0, true, &[0x77]), (0x1d6a1, true, &[0x78]),
+    (0x1d6a2, true, &[0x79]), (0x1d6a3, true, &[0x7a]), (0x1d6a4, true, &[0x131]), (0x1d6a5, true, &[0x237]),
+    (0x1d6a8, true, &[0x391]), (0x1d6a9, true, &[0x392]), (0x1d6aa, true, &[0x393]), (0x1d6ab, true, &[0x394]),
+    (0x1d6ac, true, &[0x395]), (0x1d6ad, true, &[0x396]), (0x1d6ae, true, &[0x397]), (0x1d6af, true, &[0x398]),
+    (0x1d6b0, true, &[0x399]), (0x1d6b1, true, &[0x39a]), (0x1d6b2, true, &[0x39b]), (0x1d6b3, true, &[0x39c]),
+    (0x1d6b4, true, &[0x39d]), (0x1d6b5, true, &[0x39e]), (0x1d6b6, true, &[0x39f]), (0x1d6b7, true, &[0x3a0]),
+    (0x1d6b8, true, &[0x3a1]), (0x1d6b9, true, &[0x3f4]), (0x1d6ba, true, &[0x3a3]), (0x1d6bb, true, &[0x3a4]),
+    (0x1d6bc, true, &[0x3a5]), (0x1d6bd, true, &[0x3a6]), (0x1d6be, true, &[0x3a7]), (0x1d6bf, true, &[0x3a8]),
+    (0x1d6c0, true, &[0x3a9]), (0x1d6c1, true, &[0x2207]), (0x1d6c2, true, &[0x3b1]), (0x1d6c3, true, &[0x3b2]),
+    (0x1d6c4, true, &[0x3b3]), (0x1d6c5, true, &[0x3b4]), (0x1d6c6, true, &[0x3b5]), (0x1d6c7, true, &[0x3b6]),
+    (0x1d6c8, true, &[0x3b7]), (0x1d6c9, true, &[0x3b8]), (0x1d6ca, true, &[0x3b9]), (0x1d6cb, true, &[0x3ba]),
+    (0x1d6cc, true, &[0x3bb]), (0x1d6cd, true, &[0x3bc]), (0x1d6ce, true, &[0x3bd]), (0x1d6cf, true, &[0x3be]),
+    (0x1d6d0, true, &[0x3bf]), (0x1d6d1, true, &[0x3c0]), (0x1d6d2, true, &[0x3c1]), (0x1d6d3, true, &[0x3c2]),
+    (0x1d6d4, true, &[0x3c3]), (0x1d6d5, true, &[0x3c4]), (0x1d6d6, true, &[0x3c5]), (0x1d6d7, true, &[0x3c6]),
+    (0x1d6d8, true, &[0x3c7]), (0x1d6d9, true, &[0x3c8]), (0x1d6da, true, &[0x3c9]), (0x1d6db, true, &[0x2202]),
+    (0x1d6dc, true, &[0x3f5]), (0x1d6dd, true, &[0x3d1]), (0x1d6de, true, &[0x3f0]), (0x1d6df, true, &[0x3d5]),
+    (0x1d6e0, true, &[0x3f1]), (0x1d6e1, true, &[0x3d6]), (0x1d6e2, true, &[0x391]), (0x1d6e3, true, &[0x392]),
+    (0x1d6e4, true, &[0x393]), (0x1d6e5, true, &[0x394]), (0x1d6e6, true, &[0x395]), (0x1d6e7, true, &[0x396]),
+    (0x1d6e8, true, &[0x397]), (0x1d6e9, true, &[0x398]), (0x1d6ea, true, &[0x399]), (0x1d6eb, true, &[0x39a]),
+    (0x1d6ec, true, &[0x39b]), (0x1d6ed, true, &[0x39c]), (0x1d6ee, true, &[0x39d]), (0x1d6ef, true, &[0x39e]),
+    (0x1d6f0, true, &[0x39f]), (0x1d6f1, true, &[0x3a0]), (0x1d6f2, true, &[0x3a1]), (0x1d6f3, true, &[0x3f4]),
+    (0x1d6f4, true, &[0x3a3]), (0x1d6f5, true, &[0x3a4]), (0x1d6f6, true, &[0x3a5]), (0x1d6f7, true, &[0x3a6]),
+    (0x1d6f8, true, &[0x3a7]), (0x1d6f9, true, &[0x3a8]), (0x1d6fa, true, &[0x3a9]), (0x1d6fb, true, &[0x2207]),
+    (0x1d6fc, true, &[0x3b1]), (0x1d6fd, true, &[0x3b2]), (0x1d6fe, true, &[0x3b3]), (0x1d6ff, true, &[0x3b4]),
+    (0x1d700, true, &[0x3b5]), (0x1d701, true, &[0x3b6]), (0x1d702, true, &[0x3b7]), (0x1d703, true, &[0x3b8]),
+    (0x1d704, true, &[0x3b9]), (0x1d705, true, &[0x3ba]), (0x1d706, true, &[0x3bb]), (0x1d707, true, &[0x3bc]),
+    (0x1d708, true, &[0x3bd]), (0x1d709, true, &[0x3be]), (0x1d70a, true, &[0x3bf]), (0x1d70b, true, &[0x3c0]),
+    (0x1d70c, true, &[0x3c1]), (0x1d70d, true, &[0x3c2]), (0x1d70e, true, &[0x3c3]), (0x1d70f, true, &[0x3c4]),
+    (0x1d710, true, &[0x3c5]), (0x1d711, true, &[0x3c6]), (0x1d712, true, &[0x3c7]), (0x1d713, true, &[0x3c8]),
+    (0x1d714, true, &[0x3c9]), (0x1d715, true, &[0x2202]), (0x1d716, true, &[0x3f5]), (0x1d717, true, &[0x3d1]),
+    (0x1d718, true, &[0x3f0]), (0x1d719, true, &[0x3d5]), (0x1d71a, true, &[0x3f1]), (0x1d71b, true, &[0x3d6]),
+    (0x1d71c, true, &[0x391]), (0x1d71d, true, &[0x392]), (0x1d71e, true, &[0x393]), (0x1d71f, true, &[0x394]),
+    (0x1d720, true, &[0x395]), (0x1d721, true, &[0x396]), (0x1d722, true, &[0x397]), (0x1d723, true, &[0x398]),
+    (0x1d724, true, &[0x399]), (0x1d725, true, &[0x39a]), (0x1d726, true, &[0x39b]), (0x1d727, true, &[0x39c]),
+    (0x1d728, true, &[0x39d]), (0x1d729, true, &[0x39e]), (0x1d72a, true, &[0x39f]), (0x1d72b, true, &[0x3a0]),
+    (0x1d72c, true, &[0x3a1]), (0x1d72d, true, &[0x3f4]), (0x1d72e, true, &[0x3a3]), (0x1d72f, true, &[0x3a4]),
+    (0x1d730, true, &[0x3a5]), (0x1d731, true, &[0x3a6]), (0x1d732, true, &[0x3a7]), (0x1d733, true, &[0x3a8]),
+    (0x1d734, true, &[0x3a9]), (0x1d735, true, &[0x2207]), (0x1d736, true, &[0x3b1]), (0x1d737, true, &[0x3b2]),
+    (0x1d738, true, &[0x3b3]), (0x1d739, true, &[0x3b4]), (0x1d73a, true, &[0x3b5]), (0x1d73b, true, &[0x3b6]),
+    (0x1d73c, true, &[0x3b7]), (0x1d73d, true, &[0x3b8]), (0x1d73e, true, &[0x3b9]), (0x1d73f, true, &[0x3ba]),
+    (0x1d740, true, &[0x3bb]), (0x1d741, true, &[0x3bc]), (0x1d742, true, &[0x3bd]), (0x1d743, true, &[0x3be]),
+    (0x1d744, true, &[0x3bf]), (0x1d745, true, &[0x3c0]), (0x1d746, true, &[0x3c1]), (0x1d747, true, &[0x3c2]),
+    (0x1d748, true, &[0x3c3]), (0x1d749, true, &[0x3c4]), (0x1d74a, true, &[0x3c5]), (0x1d74b, true, &[0x3c6]),
+    (0x1d74c, true, &[0x3c7]), (0x1d74d, true, &[0x3c8]), (0x1d74e, true, &[0x3c9]), (0x1d74f, true, &[0x2202]),
+    (0x1d750, true, &[0x3f5]), (0x1d751, true, &[0x3d1]), (0x1d752, true, &[0x3f0]), (0x1d753, true, &[0x3d5]),
+    (0x1d754, true, &[0x3f1]), (0x1d755, true, &[0x3d6]), (0x1d756, true, &[0x391]), (0x1d757, true, &[0x392]),
+    (0x1d758, true, &[0x393]), (0x1d759, true, &[0x394]), (0x1d75a, true, &[0x395]), (0x1d75b, true, &[0x396]),
+    (0x1d75c, true, &[0x397]), (0x1d75d, true, &[0x398]), (0x1d75e, true, &[0x399]), (0x1d75f, true, &[0x39a]),
+    (0x1d760, true, &[0x39b]), (0x1d761, true, &[0x39c]), (0x1d762, true, &[0x39d]), (0x1d763, true, &[0x39e]),
+    (0x1d764, true, &[0x39f]), (0x1d765, true, &[0x3a0]), (0x1d766, true, &[0x3a1]), (0x1d767, true, &[0x3f4]),
+    (0x1d768, true, &[0x3a3]), (0x1d769, true, &[0x3a4]), (0x1d76a, true, &[0x3a5]), (0x1d76b, true, &[0x3a6]),
+    (0x1d76c, true, &[0x3a7]), (0x1d76d, true, &[0x3a8]), (0x1d76e, true, &[0x3a9]), (0x1d76f, true, &[0x2207]),
+    (0x1d770, true, &[0x3b1]), (0x1d771, true, &[0x3b2]), (0x1d772, true, &[0x3b3]), (0x1d773, true, &[0x3b4]),
+    (0x1d774, true, &[0x3b5]), (0x1d775, true, &[0x3b6]), (0x1d776, true, &[0x3b7]), (0x1d777, true, &[0x3b8]),
+    (0x1d778, true, &[0x3b9]), (0x1d779, true, &[0x3ba]), (0x1d77a, true, &[0x3bb]), (0x1d77b, true, &[0x3bc]),
+    (0x1d77c, true, &[0x3bd]), (0x1d77d, true, &[0x3be]), (0x1d77e, true, &[0x3bf]), (0x1d77f, true, &[0x3c0]),
+    (0x1d780, true, &[0x3c1]), (0x1d781, true, &[0x3c2]), (0x1d782, true, &[0x3c3]), (0x1d783, true, &[0x3c4]),
+    (0x1d784, true, &[0x3c5]), (0x1d785, true, &[0x3c6]), (0x1d786, true, &[0x3c7]), (0x1d787, true, &[0x3c8]),
+    (0x1d788, true, &[0x3c9]), (0x1d789, true, &[0x2202]), (0x1d78a, true, &[0x3f5]), (0x1d78b, true, &[0x3d1]),
+    (0x1d78c, true, &[0x3f0]), (0x1d78d, true, &[0x3d5]), (0x1d78e, true, &[0x3f1]), (0x1d78f, true, &[0x3d6]),
+    (0x1d790, true, &[0x391]), (0x1d791, true, &[0x392]), (0x1d792, true, &[0x393]), (0x1d793, true, &[0x394]),
+    (0x1d794, true, &[0x395]), (0x1d795, true, &[0x396]), (0x1d796, true, &[0x397]), (0x1d797, true, &[0x398]),
+    (0x1d798, true, &[0x399]), (0x1d799, true, &[0x39a]), (0x1d79a, true, &[0x39b]), (0x1d79b, true, &[0x39c]),
+    (0x1d79c, true, &[0x39d]), (0x1d79d, true, &[0x39e]), (0x1d79e, true, &[0x39f]), (0x1d79f, true, &[0x3a0]),
+    (0x1d7a0, true, &[0x3a1]), (0x1d7a1, true, &[0x3f4]), (0x1d7a2, true, &[0x3a3]), (0x1d7a3, true, &[0x3a4]),
+    (0x1d7a4, true, &[0x3a5]), (0x1d7a5, true, &[0x3a6]), (0x1d7a6, true, &[0x3a7]), (0x1d7a7, true, &[0x3a8]),
+    (0x1d7a8, true, &[0x3a9]), (0x1d7a9, true, &[0x2207]), (0x1d7aa, true, &[0x3b1]), (0x1d7ab, true, &[0x3b2]),
+    (0x1d7ac, true, &[0x3b3]), (0x1d7ad, true, &[0x3b4]), (0x1d7ae, true, &[0x3b5]), (0x1d7af, true, &[0x3b6]),
+    (0x1d7b0, true, &[0x3b7]), (0x1d7b1, true, &[0x3b8]), (0x1d7b2, true, &[0x3b9]), (0x1d7b3, true, &[0x3ba]),
+    (0x1d7b4, true, &[0x3bb]), (0x1d7b5, true, &[0x3bc]), (0x1d7b6, true, &[0x3bd]), (0x1d7b7, true, &[0x3be]),
+    (0x1d7b8, true, &[0x3bf]), (0x1d7b9, true, &[0x3c0]), (0x1d7ba, true, &[0x3c1]), (0x1d7bb, true, &[0x3c2]),
+    (0x1d7bc, true, &[0x3c3]), (0x1d7bd, true, &[0x3c4]), (0x1d7be, true, &[0x3c5]), (0x1d7bf, true, &[0x3c6]),
+    (0x1d7c0, true, &[0x3c7]), (0x1d7c1, true, &[0x3c8]), (0x1d7c2, true, &[0x3c9]), (0x1d7c3, true, &[0x2202]),
+    (0x1d7c4, true, &[0x3f5]), (0x1d7c5, true, &[0x3d1]), (0x1d7c6, true, &[0x3f0]), (0x1d7c7, true, &[0x3d5]),
+    (0x1d7c8, true, &[0x3f1]), (0x1d7c9, true, &[0x3d6]), (0x1d7ca, true, &[0x3dc]), (0x1d7cb, true, &[0x3dd]),
+    (0x1d7ce, true, &[0x30]), (0x1d7cf, true, &[0x31]), (0x1d7d0, true, &[0x32]), (0x1d7d1, true, &[0x33]),
+    (0x1d7d2, true, &[0x34]), (0x1d7d3, true, &[0x35]), (0x1d7d4, true, &[0x36]), (0x1d7d5, true, &[0x37]),
+    (0x1d7d6, true, &[0x38]), (0x1d7d7, true, &[0x39]), (0x1d7d8, true, &[0x30]), (0x1d7d9, true, &[0x31]),
+    (0x1d7da, true, &[0x32]), (0x1d7db, true, &[0x33]), (0x1d7dc, true, &[0x34]), (0x1d7dd, true, &[0x35]),
+    (0x1d7de, true, &[0x36]), (0x1d7df, true, &[0x37]), (0x1d7e0, true, &[0x38]), (0x1d7e1, true, &[0x39]),
+    (0x1d7e2, true, &[0x30]), (0x1d7e3, true, &[0x31]), (0x1d7e4, true, &[0x32]), (0x1d7e5, true, &[0x33]),
+    (0x1d7e6, true, &[0x34]), (0x1d7e7, true, &[0x35]), (0x1d7e8, true, &[0x36]), (0x1d7e9, true, &[0x37]),
+    (0x1d7ea, true, &[0x38]), (0x1d7eb, true, &[0x39]), (0x1d7ec, true, &[0x30]), (0x1d7ed, true, &[0x31]),
+    (0x1d7ee, true, &[0x32]), (0x1d7ef, true, &[0x33]), (0x1d7f0, true, &[0x34]), (0x1d7f1, true, &[0x35]),
+    (0x1d7f2, true, &[0x36]), (0x1d7f3, true, &[0x37]), (0x1d7f4, true, &[0x38]), (0x1d7f5, true, &[0x39]),
+    (0x1d7f6, true, &[0x30]), (0x1d7f7, true, &[0x31]), (0x1d7f8, true, &[0x32]), (0x1d7f9, true, &[0x33]),
+    (0x1d7fa, true, &[0x34]), (0x1d7fb, true, &[0x35]), (0x1d7fc, true, &[0x36]), (0x1d7fd, true, &[0x37]),
+    (0x1d7fe, true, &[0x38]), (0x1d7ff, true, &[0x39]), (0x1ee00, true, &[0x627]), (0x1ee01, true, &[0x628]),
+    (0x1ee02, true, &[0x62c]), (0x1ee03, true, &[0x62f]), (0x1ee05, true, &[0x648]), (0x1ee06, true, &[0x632]),
+    (0x1ee07, true, &[0x62d]), (0x1ee08, true, &[0x637]), (0x1ee09, true, &[0x64a]), (0x1ee0a, true, &[0x643]),
+    (0x1ee0b, true, &[0x644]), (0x1ee0c, true, &[0x645]), (0x1ee0d, true, &[0x646]), (0x1ee0e, true, &[0x633]),
+    (0x1ee0f, true, &[0x639]), (0x1ee10, true, &[0x641]), (0x1ee11, true, &[0x635]), (0x1ee12, true, &[0x642]),
+    (0x1ee13, true, &[0x631]), (0x1ee14, true, &[0x634]), (0x1ee15, true, &[0x62a]), (0x1ee16, true, &[0x62b]),
+    (0x1ee17, true, &[0x62e]), (0x1ee18, true, &[0x630]), (0x1ee19, true, &[0x636]), (0x1ee1a, true, &[0x638]),
+    (0x1ee1b, true, &[0x63a]), (0x1ee1c, true, &[0x66e]), (0x1ee1d, true, &[0x6ba]), (0x1ee1e, true, &[0x6a1]),
+    (0x1ee1f, true, &[0x66f]), (0x1ee21, true, &[0x628]), (0x1ee22, true, &[0x62c]), (0x1ee24, true, &[0x647]),
+    (0x1ee27, true, &[0x62d]), (0x1ee29, true, &[0x64a]), (0x1ee2a, true, &[0x643]), (0x1ee2b, true, &[0x644]),
+    (0x1ee2c, true, &[0x645]), (0x1ee2d, true, &[0x646]), (0x1ee2e, true, &[0x633]), (0x1ee2f, true, &[0x639]),
+    (0x1ee30, true, &[0x641]), (0x1ee31, true, &[0x635]), (0x1ee32, true, &[0x642]), (0x1ee34, true, &[0x634]),
+    (0x1ee35, true, &[0x62a]), (0x1ee36, true, &[0x62b]), (0x1ee37, true, &[0x62e]), (0x1ee39, true, &[0x636]),
+    (0x1ee3b, true, &[0x63a]), (0x1ee42, true, &[0x62c]), (0x1ee47, true, &[0x62d]), (0x1ee49, true, &[0x64a]),
+    (0x1ee4b, true, &[0x644]), (0x1ee4d, true, &[0x646]), (0x1ee4e, true, &[0x633]), (0x1ee4f, true, &[0x639]),
+    (0x1ee51, true, &[0x635]), (0x1ee52, true, &[0x642]), (0x1ee54, true, &[0x634]), (0x1ee57, true, &[0x62e]),
+    (0x1ee59, true, &[0x636]), (0x1ee5b, true, &[0x63a]), (0x1ee5d, true, &[0x6ba]), (0x1ee5f, true, &[0x66f]),
+    (0x1ee61, true, &[0x628]), (0x1ee62, true, &[0x62c]), (0x1ee64, true, &[0x647]), (0x1ee67, true, &[0x62d]),
+    (0x1ee68, true, &[0x637]), (0x1ee69, true, &[0x64a]), (0x1ee6a, true, &[0x643]), (0x1ee6c, true, &[0x645]),
+    (0x1ee6d, true, &[0x646]), (0x1ee6e, true, &[0x633]), (0x1ee6f, true, &[0x639]), (0x1ee70, true, &[0x641]),
+    (0x1ee71, true, &[0x635]), (0x1ee72, true, &[0x642]), (0x1ee74, true, &[0x634]), (0x1ee75, true, &[0x62a]),
+    (0x1ee76, true, &[0x62b]), (0x1ee77, true, &[0x62e]), (0x1ee79, true, &[0x636]), (0x1ee7a, true, &[0x638]),
+    (0x1ee7b, true, &[0x63a]), (0x1ee7c, true, &[0x66e]), (0x1ee7e, true, &[0x6a1]), (0x1ee80, true, &[0x627]),
+    (0x1ee81, true, &[0x628]), (0x1ee82, true, &[0x62c]), (0x1ee83, true, &[0x62f]), (0x1ee84, true, &[0x647]),
+    (0x1ee85, true, &[0x648]), (0x1ee86, true, &[0x632]), (0x1ee87, true, &[0x62d]), (0x1ee88, true, &[0x637]),
+    (0x1ee89, true, &[0x64a]), (0x1ee8b, true, &[0x644]), (0x1ee8c, true, &[0x645]), (0x1ee8d, true, &[0x646]),
+    (0x1ee8e, true, &[0x633]), (0x1ee8f, true, &[0x639]), (0x1ee90, true, &[0x641]), (0x1ee91, true, &[0x635]),
+    (0x1ee92, true, &[0x642]), (0x1ee93, true, &[0x631]), (0x1ee94, true, &[0x634]), (0x1ee95, true, &[0x62a]),
+    (0x1ee96, true, &[0x62b]), (0x1ee97, true, &[0x62e]), (0x1ee98, true, &[0x630]), (0x1ee99, true, &[0x636]),
+    (0x1ee9a, true, &[0x638]), (0x1ee9b, true, &[0x63a]), (0x1eea1, true, &[0x628]), (0x1eea2, true, &[0x62c]),
+    (0x1eea3, true, &[0x62f]), (0x1eea5, true, &[0x648]), (0x1eea6, true, &[0x632]), (0x1eea7, true, &[0x62d]),
+    (0x1eea8, true, &[0x637]), (0x1eea9, true, &[0x64a]), (0x1eeab, true, &[0x644]), (0x1eeac, true, &[0x645]),
+    (0x1eead, true, &[0x646]), (0x1eeae, true, &[0x633]), (0x1eeaf, true, &[0x639]), (0x1eeb0, true, &[0x641]),
+    (0x1eeb1, true, &[0x635]), (0x1eeb2, true, &[0x642]), (0x1eeb3, true, &[0x631]), (0x1eeb4, true, &[0x634]),
+    (0x1eeb5, true, &[0x62a]), (0x1eeb6, true, &[0x62b]), (0x1eeb7, true, &[0x62e]), (0x1eeb8, true, &[0x630]),
+    (0x1eeb9, true, &[0x636]), (0x1eeba, true, &[0x638]), (0x1eebb, true, &[0x63a]), (0x1f100, true, &[0x30, 0x2e]),
+    (0x1f101, true, &[0x30, 0x2c]), (0x1f102, true, &[0x31, 0x2c]), (0x1f103, true, &[0x32, 0x2c]), (0x1f104, true, &[0x33, 0x2c]),
+    (0x1f105, true, &[0x34, 0x2c]), (0x1f106, true, &[0x35, 0x2c]), (0x1f107, true, &[0x36, 0x2c]), (0x1f108, true, &[0x37, 0x2c]),
+    (0x1f109, true, &[0x38, 0x2c]), (0x1f10a, true, &[0x39, 0x2c]), (0x1f110, true, &[0x28, 0x41, 0x29]), (0x1f111, true, &[0x28, 0x42, 0x29]),
+    (0x1f112, true, &[0x28, 0x43, 0x29]), (0x1f113, true, &[0x28, 0x44, 0x29]), (0x1f114, true, &[0x28, 0x45, 0x29]), (0x1f115, true, &[0x28, 0x46, 0x29]),
+    (0x1f116, true, &[0x28, 0x47, 0x29]), (0x1f117, true, &[0x28, 0x48, 0x29]), (0x1f118, true, &[0x28, 0x49, 0x29]), (0x1f119, true, &[0x28, 0x4a, 0x29]),
+    (0x1f11a, true, &[0x28, 0x4b, 0x29]), (0x1f11b, true, &[0x28, 0x4c, 0x29]), (0x1f11c, true, &[0x28, 0x4d, 0x29]), (0x1f11d, true, &[0x28, 0x4e, 0x29]),
+    (0x1f11e, true, &[0x28, 0x4f, 0x29]), (0x1f11f, true, &[0x28, 0x50, 0x29]), (0x1f120, true, &[0x28, 0x51, 0x29]), (0x1f121, true, &[0x28, 0x52, 0x29]),
+    (0x1f122, true, &[0x28, 0x53, 0x29]), (0x1f123, true, &[0x28, 0x54, 0x29]), (0x1f124, true, &[0x28, 0x55, 0x29]), (0x1f125, true, &[0x28, 0x56, 0x29]),
+    (0x1f126, true, &[0x28, 0x57, 0x29]), (0x1f127, true, &[0x28, 0x58, 0x29]), (0x1f128, true, &[0x28, 0x59, 0x29]), (0x1f129, true, &[0x28, 0x5a, 0x29]),
+    (0x1f12a, true, &[0x3014, 0x53, 0x3015]), (0x1f12b, true, &[0x43]), (0x1f12c, true, &[0x52]), (0x1f12d, true, &[0x43, 0x44]),
+    (0x1f12e, true, &[0x57, 0x5a]), (0x1f130, true, &[0x41]), (0x1f131, true, &[0x42]), (0x1f132, true, &[0x43]),
+    (0x1f133, true, &[0x44]), (0x1f134, true, &[0x45]), (0x1f135, true, &[0x46]), (0x1f136, true, &[0x47]),
+    (0x1f137, true, &[0x48]), (0x1f138, true, &[0x49]), (0x1f139, true, &[0x4a]), (0x1f13a, true, &[0x4b]),
+    (0x1f13b, true, &[0x4c]), (0x1f13c, true, &[0x4d]), (0x1f13d, true, &[0x4e]), (0x1f13e, true, &[0x4f]),
+    (0x1f13f, true, &[0x50]), (0x1f140, true, &[0x51]), (0x1f141, true, &[0x52]), (0x1f142, true, &[0x53]),
+    (0x1f143, true, &[0x54]), (0x1f144, true, &[0x55]), (0x1f145, true, &[0x56]), (0x1f146, true, &[0x57]),
+    (0x1f147, true, &[0x58]), (0x1f148, true, &[0x59]), (0x1f149, true, &[0x5a]), (0x1f14a, true, &[0x48, 0x56]),
+    (0x1f14b, true, &[0x4d, 0x56]), (0x1f14c, true, &[0x53, 0x44]), (0x1f14d, true, &[0x53, 0x53]), (0x1f14e, true, &[0x50, 0x50, 0x56]),
+    (0x1f14f, true, &[0x57, 0x43]), (0x1f16a, true, &[0x4d, 0x43]), (0x1f16b, true, &[0x4d, 0x44]), (0x1f16c, true, &[0x4d, 0x52]),
+    (0x1f190, true, &[0x44, 0x4a]), (0x1f200, true, &[0x307b, 0x304b]), (0x1f201, true, &[0x30b3, 0x30b3]), (0x1f202, true, &[0x30b5]),
+    (0x1f210, true, &[0x624b]), (0x1f211, true, &[0x5b57]), (0x1f212, true, &[0x53cc]), (0x1f213, true, &[0x30c7]),
+    (0x1f214, true, &[0x4e8c]), (0x1f215, true, &[0x591a]), (0x1f216, true, &[0x89e3]), (0x1f217, true, &[0x5929]),
+    (0x1f218, true, &[0x4ea4]), (0x1f219, true, &[0x6620]), (0x1f21a, true, &[0x7121]), (0x1f21b, true, &[0x6599]),
+    (0x1f21c, true, &[0x524d]), (0x1f21d, true, &[0x5f8c]), (0x1f21e, true, &[0x518d]), (0x1f21f, true, &[0x65b0]),
+    (0x1f220, true, &[0x521d]), (0x1f221, true, &[0x7d42]), (0x1f222, true, &[0x751f]), (0x1f223, true, &[0x8ca9]),
+    (0x1f224, true, &[0x58f0]), (0x1f225, true, &[0x5439]), (0x1f226, true, &[0x6f14]), (0x1f227, true, &[0x6295]),
+    (0x1f228, true, &[0x6355]), (0x1f229, true, &[0x4e00]), (0x1f22a, true, &[0x4e09]), (0x1f22b, true, &[0x904a]),
+    (0x1f22c, true, &[0x5de6]), (0x1f22d, true, &[0x4e2d]), (0x1f22e, true, &[0x53f3]), (0x1f22f, true, &[0x6307]),
+    (0x1f230, true, &[0x8d70]), (0x1f231, true, &[0x6253]), (0x1f232, true, &[0x7981]), (0x1f233, true, &[0x7a7a]),
+    (0x1f234, true, &[0x5408]), (0x1f235, true, &[0x6e80]), (0x1f236, true, &[0x6709]), (0x1f237, true, &[0x6708]),
+    (0x1f238, true, &[0x7533]), (0x1f239, true, &[0x5272]), (0x1f23a, true, &[0x55b6]), (0x1f23b, true, &[0x914d]),
+    (0x1f240, true, &[0x3014, 0x672c, 0x3015]), (0x1f241, true, &[0x3014, 0x4e09, 0x3015]), (0x1f242, true, &[0x3014, 0x4e8c, 0x3015]), (0x1f243, true, &[0x3014, 0x5b89, 0x3015]),
+    (0x1f244, true, &[0x3014, 0x70b9, 0x3015]), (0x1f245, true, &[0x3014, 0x6253, 0x3015]), (0x1f246, true, &[0x3014, 0x76d7, 0x3015]), (0x1f247, true, &[0x3014, 0x52dd, 0x3015]),
+    (0x1f248, true, &[0x3014, 0x6557, 0x3015]), (0x1f250, true, &[0x5f97]), (0x1f251, true, &[0x53ef]), (0x1fbf0, true, &[0x30]),
+    (0x1fbf1, true, &[0x31]), (0x1fbf2, true, &[0x32]), (0x1fbf3, true, &[0x33]), (0x1fbf4, true, &[0x34]),
+    (0x1fbf5, true, &[0x35]), (0x1fbf6, true, &[0x36]), (0x1fbf7, true, &[0x37]), (0x1fbf8, true, &[0x38]),
+    (0x1fbf9, true, &[0x39]), (0x2f800, false, &[0x4e3d]), (0x2f801, false, &[0x4e38]), (0x2f802, false, &[0x4e41]),
+    (0x2f803, false, &[0x20122]), (0x2f804, false, &[0x4f60]), (0x2f805, false, &[0x4fae]), (0x2f806, false, &[0x4fbb]),
+    (0x2f807, false, &[0x5002]), (0x2f808, false, &[0x507a]), (0x2f809, false, &[0x5099]), (0x2f80a, false, &[0x50e7]),
+    (0x2f80b, false, &[0x50cf]), (0x2f80c, false, &[0x349e]), (0x2f80d, false, &[0x2063a]), (0x2f80e, false, &[0x514d]),
+    (0x2f80f, false, &[0x5154]), (0x2f810, false, &[0x5164]), (0x2f811, false, &[0x5177]), (0x2f812, false, &[0x2051c]),
+    (0x2f813, false, &[0x34b9]), (0x2f814, false, &[0x5167]), (0x2f815, false, &[0x518d]), (0x2f816, false, &[0x2054b]),
+    (0x2f817, false, &[0x5197]), (0x2f818, false, &[0x51a4]), (0x2f819, false, &[0x4ecc]), (0x2f81a, false, &[0x51ac]),
+    (0x2f81b, false, &[0x51b5]), (0x2f81c, false, &[0x291df]), (0x2f81d, false, &[0x51f5]), (0x2f81e, false, &[0x5203]),
+    (0x2f81f, false, &[0x34df]), (0x2f820, false, &[0x523b]), (0x2f821, false, &[0x5246]), (0x2f822, false, &[0x5272]),
+    (0x2f823, false, &[0x5277]), (0x2f824, false, &[0x3515]), (0x2f825, false, &[0x52c7]), (0x2f826, false, &[0x52c9]),
+    (0x2f827, false, &[0x52e4]), (0x2f828, false, &[0x52fa]), (0x2f829, false, &[0x5305]), (0x2f82a, false, &[0x5306]),
+    (0x2f82b, false, &[0x5317]), (0x2f82c, false, &[0x5349]), (0x2f82d, false, &[0x5351]), (0x2f82e, false, &[0x535a]),
+    (0x2f82f, false, &[0x5373]), (0x2f830, false, &[0x537d]), (0x2f831, false, &[0x537f]), (0x2f832, false, &[0x537f]),
+    (0x2f833, false, &[0x537f]), (0x2f834, false, &[0x20a2c]), (0x2f835, false, &[0x7070]), (0x2f836, false, &[0x53ca]),
+    (0x2f837, false, &[0x53df]), (0x2f838, false, &[0x20b63]), (0x2f839, false, &[0x53eb]), (0x2f83a, false, &[0x53f1]),
+    (0x2f83b, false, &[0x5406]), (0x2f83c, false, &[0x549e]), (0x2f83d, false, &[0x5438]), (0x2f83e, false, &[0x5448]),
+    (0x2f83f, false, &[0x5468]), (0x2f840, false, &[0x54a2]), (0x2f841, false, &[0x54f6]), (0x2f842, false, &[0x5510]),
+    (0x2f843, false, &[0x5553]), (0x2f844, false, &[0x5563]), (0x2f845, false, &[0x5584]), (0x2f846, false, &[0x5584]),
+    (0x2f847, false, &[0x5599]), (0x2f848, false, &[0x55ab]), (0x2f849, false, &[0x55b3]), (0x2f84a, false, &[0x55c2]),
+    (0x2f84b, false, &[0x5716]), (0x2f84c, false, &[0x5606]), (0x2f84d, false, &[0x5717]), (0x2f84e, false, &[0x5651]),
+    (0x2f84f, false, &[0x5674]), (0x2f850, false, &[0x5207]), (0x2f851, false, &[0x58ee]), (0x2f852, false, &[0x57ce]),
+    (0x2f853, false, &[0x57f4]), (0x2f854, false, &[0x580d]), (0x2f855, false, &[0x578b]), (0x2f856, false, &[0x5832]),
+    (0x2f857, false, &[0x5831]), (0x2f858, false, &[0x58ac]), (0x2f859, false, &[0x214e4]), (0x2f85a, false, &[0x58f2]),
+    (0x2f85b, false, &[0x58f7]), (0x2f85c, false, &[0x5906]), (0x2f85d, false, &[0x591a]), (0x2f85e, false, &[0x5922]),
+    (0x2f85f, false, &[0x5962]), (0x2f860, false, &[0x216a8]), (0x2f861, false, &[0x216ea]), (0x2f862, false, &[0x59ec]),
+    (0x2f863, false, &[0x5a1b]), (0x2f864, false, &[0x5a27]), (0x2f865, false, &[0x59d8]), (0x2f866, false, &[0x5a66]),
+    (0x2f867, false, &[0x36ee]), (0x2f868, false, &[0x36fc]), (0x2f869, false, &[0x5b08]), (0x2f86a, false, &[0x5b3e]),
+    (0x2f86b, false, &[0x5b3e]), (0x2f86c, false, &[0x219c8]), (0x2f86d, false, &[0x5bc3]), (0x2f86e, false, &[0x5bd8]),
+    (0x2f86f, false, &[0x5be7]), (0x2f870, false, &[0x5bf3]), (0x2f871, false, &[0x21b18]), (0x2f872, false, &[0x5bff]),
+    (0x2f873, false, &[0x5c06]), (0x2f874, false, &[0x5f53]), (0x2f875, false, &[0x5c22]), (0x2f876, false, &[0x3781]),
+    (0x2f877, false, &[0x5c60]), (0x2f878, false, &[0x5c6e]), (0x2f879, false, &[0x5cc0]), (0x2f87a, false, &[0x5c8d]),
+    (0x2f87b, false, &[0x21de4]), (0x2f87c, false, &[0x5d43]), (0x2f87d, false, &[0x21de6]), (0x2f87e, false, &[0x5d6e]),
+    (0x2f87f, false, &[0x5d6b]), (0x2f880, false, &[0x5d7c]), (0x2f881, false, &[0x5de1]), (0x2f882, false, &[0x5de2]),
+    (0x2f883, false, &[0x382f]), (0x2f884, false, &[0x5dfd]), (0x2f885, false, &[0x5e28]), (0x2f886, false, &[0x5e3d]),
+    (0x2f887, false, &[0x5e69]), (0x2f888, false, &[0x3862]), (0x2f889, false, &[0x22183]), (0x2f88a, false, &[0x387c]),
+    (0x2f88b, false, &[0x5eb0]), (0x2f88c, false, &[0x5eb3]), (0x2f88d, false, &[0x5eb6]), (0x2f88e, false, &[0x5eca]),
+    (0x2f88f, false, &[0x2a392]), (0x2f890, false, &[0x5efe]), (0x2f891, false, &[0x22331]), (0x2f892, false, &[0x22331]),
+    (0x2f893, false, &[0x8201]), (0x2f894, false, &[0x5f22]), (0x2f895, false, &[0x5f22]), (0x2f896, false, &[0x38c7]),
+    (0x2f897, false, &[0x232b8]), (0x2f898, false, &[0x261da]), (0x2f899, false, &[0x5f62]), (0x2f89a, false, &[0x5f6b]),
+    (0x2f89b, false, &[0x38e3]), (0x2f89c, false, &[0x5f9a]), (0x2f89d, false, &[0x5fcd]), (0x2f89e, false, &[0x5fd7]),
+    (0x2f89f, false, &[0x5ff9]), (0x2f8a0, false, &[0x6081]), (0x2f8a1, false, &[0x393a]), (0x2f8a2, false, &[0x391c]),
+    (0x2f8a3, false, &[0x6094]), (0x2f8a4, false, &[0x226d4]), (0x2f8a5, false, &[0x60c7]), (0x2f8a6, false, &[0x6148]),
+    (0x2f8a7, false, &[0x614c]), (0x2f8a8, false, &[0x614e]), (0x2f8a9, false, &[0x614c]), (0x2f8aa, false, &[0x617a]),
+    (0x2f8ab, false, &[0x618e]), (0x2f8ac, false, &[0x61b2]), (0x2f8ad, false, &[0x61a4]), (0x2f8ae, false, &[0x61af]),
+    (0x2f8af, false, &[0x61de]), (0x2f8b0, false, &[0x61f2]), (0x2f8b1, false, &[0x61f6]), (0x2f8b2, false, &[0x6210]),
+    (0x2f8b3, false, &[0x621b]), (0x2f8b4, false, &[0x625d]), (0x2f8b5, false, &[0x62b1]), (0x2f8b6, false, &[0x62d4]),
+    (0x2f8b7, false, &[0x6350]), (0x2f8b8, false, &[0x22b0c]), (0x2f8b9, false, &[0x633d]), (0x2f8ba, false, &[0x62fc]),
+    (0x2f8bb, false, &[0x6368]), (0x2f8bc, false, &[0x6383]), (0x2f8bd, false, &[0x63e4]), (0x2f8be, false, &[0x22bf1]),
+    (0x2f8bf, false, &[0x6422]), (0x2f8c0, false, &[0x63c5]), (0x2f8c1, false, &[0x63a9]), (0x2f8c2, false, &[0x3a2e]),
+    (0x2f8c3, false, &[0x6469]), (0x2f8c4, false, &[0x647e]), (0x2f8c5, false, &[0x649d]), (0x2f8c6, false, &[0x6477]),
+    (0x2f8c7, false, &[0x3a6c]), (0x2f8c8, false, &[0x654f]), (0x2f8c9, false, &[0x656c]), (0x2f8ca, false, &[0x2300a]),
+    (0x2f8cb, false, &[0x65e3]), (0x2f8cc, false, &[0x66f8]), (0x2f8cd, false, &[0x6649]), (0x2f8ce, false, &[0x3b19]),
+    (0x2f8cf, false, &[0x6691]), (0x2f8d0, false, &[0x3b08]), (0x2f8d1, false, &[0x3ae4]), (0x2f8d2, false, &[0x5192]),
+    (0x2f8d3, false, &[0x5195]), (0x2f8d4, false, &[0x6700]), (0x2f8d5, false, &[0x669c]), (0x2f8d6, false, &[0x80ad]),
+    (0x2f8d7, false, &[0x43d9]), (0x2f8d8, false, &[0x6717]), (0x2f8d9, false, &[0x671b]), (0x2f8da, false, &[0x6721]),
+    (0x2f8db, false, &[0x675e]), (0x2f8dc, false, &[0x6753]), (0x2f8dd, false, &[0x233c3]), (0x2f8de, false, &[0x3b49]),
+    (0x2f8df, false, &[0x67fa]), (0x2f8e0, false, &[0x6785]), (0x2f8e1, false, &[0x6852]), (0x2f8e2, false, &[0x6885]),
+    (0x2f8e3, false, &[0x2346d]), (0x2f8e4, false, &[0x688e]), (0x2f8e5, false, &[0x681f]), (0x2f8e6, false, &[0x6914]),
+    (0x2f8e7, false, &[0x3b9d]), (0x2f8e8, false, &[0x6942]), (0x2f8e9, false, &[0x69a3]), (0x2f8ea, false, &[0x69ea]),
+    (0x2f8eb, false, &[0x6aa8]), (0x2f8ec, false, &[0x236a3]), (0x2f8ed, false, &[0x6adb]), (0x2f8ee, false, &[0x3c18]),
+    (0x2f8ef, false, &[0x6b21]), (0x2f8f0, false, &[0x238a7]), (0x2f8f1, false, &[0x6b54]), (0x2f8f2, false, &[0x3c4e]),
+    (0x2f8f3, false, &[0x6b72]), (0x2f8f4, false, &[0x6b9f]), (0x2f8f5, false, &[0x6bba]), (0x2f8f6, false, &[0x6bbb]),
+    (0x2f8f7, false, &[0x23a8d]), (0x2f8f8, false, &[0x21d0b]), (0x2f8f9, false, &[0x23afa]), (0x2f8fa, false, &[0x6c4e]),
+    (0x2f8fb, false, &[0x23cbc]), (0x2f8fc, false, &[0x6cbf]), (0x2f8fd, false, &[0x6ccd]), (0x2f8fe, false, &[0x6c67]),
+    (0x2f8ff, false, &[0x6d16]), (0x2f900, false, &[0x6d3e]), (0x2f901, false, &[0x6d77]), (0x2f902, false, &[0x6d41]),
+    (0x2f903, false, &[0x6d69]), (0x2f904, false, &[0x6d78]), (0x2f905, false, &[0x6d85]), (0x2f906, false, &[0x23d1e]),
+    (0x2f907, false, &[0x6d34]), (0x2f908, false, &[0x6e2f]), (0x2f909, false, &[0x6e6e]), (0x2f90a, false, &[0x3d33]),
+    (0x2f90b, false, &[0x6ecb]), (0x2f90c, false, &[0x6ec7]), (0x2f90d, false, &[0x23ed1]), (0x2f90e, false, &[0x6df9]),
+    (0x2f90f, false, &[0x6f6e]), (0x2f910, false, &[0x23f5e]), (0x2f911, false, &[0x23f8e]), (0x2f912, false, &[0x6fc6]),
+    (0x2f913, false, &[0x7039]), (0x2f914, false, &[0x701e]), (0x2f915, false, &[0x701b]), (0x2f916, false, &[0x3d96]),
+    (0x2f917, false, &[0x704a]), (0x2f918, false, &[0x707d]), (0x2f919, false, &[0x7077]), (0x2f91a, false, &[0x70ad]),
+    (0x2f91b, false, &[0x20525]), (0x2f91c, false, &[0x7145]), (0x2f91d, false, &[0x24263]), (0x2f91e, false, &[0x719c]),
+    (0x2f91f, false, &[0x243ab]), (0x2f920, false, &[0x7228]), (0x2f921, false, &[0x7235]), (0x2f922, false, &[0x7250]),
+    (0x2f923, false, &[0x24608]), (0x2f924, false, &[0x7280]), (0x2f925, false, &[0x7295]), (0x2f926, false, &[0x24735]),
+    (0x2f927, false, &[0x24814]), (0x2f928, false, &[0x737a]), (0x2f929, false, &[0x738b]), (0x2f92a, false, &[0x3eac]),
+    (0x2f92b, false, &[0x73a5]), (0x2f92c, false, &[0x3eb8]), (0x2f92d, false, &[0x3eb8]), (0x2f92e, false, &[0x7447]),
+    (0x2f92f, false, &[0x745c]), (0x2f930, false, &[0x7471]), (0x2f931, false, &[0x7485]), (0x2f932, false, &[0x74ca]),
+    (0x2f933, false, &[0x3f1b]), (0x2f934, false, &[0x7524]), (0x2f935, false, &[0x24c36]), (0x2f936, false, &[0x753e]),
+    (0x2f937, false, &[0x24c92]), (0x2f938, false, &[0x7570]), (0x2f939, false, &[0x2219f]), (0x2f93a, false, &[0x7610]),
+    (0x2f93b, false, &[0x24fa1]), (0x2f93c, false, &[0x24fb8]), (0x2f93d, false, &[0x25044]), (0x2f93e, false, &[0x3ffc]),
+    (0x2f93f, false, &[0x4008]), (0x2f940, false, &[0x76f4]), (0x2f941, false, &[0x250f3]), (0x2f942, false, &[0x250f2]),
+    (0x2f943, false, &[0x25119]), (0x2f944, false, &[0x25133]), (0x2f945, false, &[0x771e]), (0x2f946, false, &[0x771f]),
+    (0x2f947, false, &[0x771f]), (0x2f948, false, &[0x774a]), (0x2f949, false, &[0x4039]), (0x2f94a, false, &[0x778b]),
+    (0x2f94b, false, &[0x4046]), (0x2f94c, false, &[0x4096]), (0x2f94d, false, &[0x2541d]), (0x2f94e, false, &[0x784e]),
+    (0x2f94f, false, &[0x788c]), (0x2f950, false, &[0x78cc]), (0x2f951, false, &[0x40e3]), (0x2f952, false, &[0x25626]),
+    (0x2f953, false, &[0x7956]), (0x2f954, false, &[0x2569a]), (0x2f955, false, &[0x256c5]), (0x2f956, false, &[0x798f]),
+    (0x2f957, false, &[0x79eb]), (0x2f958, false, &[0x412f]), (0x2f959, false, &[0x7a40]), (0x2f95a, false, &[0x7a4a]),
+    (0x2f95b, false, &[0x7a4f]), (0x2f95c, false, &[0x2597c]), (0x2f95d, false, &[0x25aa7]), (0x2f95e, false, &[0x25aa7]),
+    (0x2f95f, false, &[0x7aee]), (0x2f960, false, &[0x4202]), (0x2f961, false, &[0x25bab]), (0x2f962, false, &[0x7bc6]),
+    (0x2f963, false, &[0x7bc9]), (0x2f964, false, &[0x4227]), (0x2f965, false, &[0x25c80]), (0x2f966, false, &[0x7cd2]),
+    (0x2f967, false, &[0x42a0]), (0x2f968, false, &[0x7ce8]), (0x2f969, false, &[0x7ce3]), (0x2f96a, false, &[0x7d00]),
+    (0x2f96b, false, &[0x25f86]), (0x2f96c, false, &[0x7d63]), (0x2f96d, false, &[0x4301]), (0x2f96e, false, &[0x7dc7]),
+    (0x2f96f, false, &[0x7e02]), (0x2f970, false, &[0x7e45]), (0x2f971, false, &[0x4334]), (0x2f972, false, &[0x26228]),
+    (0x2f973, false, &[0x26247]), (0x2f974, false, &[0x4359]), (0x2f975, false, &[0x262d9]), (0x2f976, false, &[0x7f7a]),
+    (0x2f977, false, &[0x2633e]), (0x2f978, false, &[0x7f95]), (0x2f979, false, &[0x7ffa]), (0x2f97a, false, &[0x8005]),
+    (0x2f97b, false, &[0x264da]), (0x2f97c, false, &[0x26523]), (0x2f97d, false, &[0x8060]), (0x2f97e, false, &[0x265a8]),
+    (0x2f97f, false, &[0x8070]), (0x2f980, false, &[0x2335f]), (0x2f981, false, &[0x43d5]), (0x2f982, false, &[0x80b2]),
+    (0x2f983, false, &[0x8103]), (0x2f984, false, &[0x440b]), (0x2f985, false, &[0x813e]), (0x2f986, false, &[0x5ab5]),
+    (0x2f987, false, &[0x267a7]), (0x2f988, false, &[0x267b5]), (0x2f989, false, &[0x23393]), (0x2f98a, false, &[0x2339c]),
+    (0x2f98b, false, &[0x8201]), (0x2f98c, false, &[0x8204]), (0x2f98d, false, &[0x8f9e]), (0x2f98e, false, &[0x446b]),
+    (0x2f98f, false, &[0x8291]), (0x2f990, false, &[0x828b]), (0x2f991, false, &[0x829d]), (0x2f992, false, &[0x52b3]),
+    (0x2f993, false, &[0x82b1]), (0x2f994, false, &[0x82b3]), (0x2f995, false, &[0x82bd]), (0x2f996, false, &[0x82e6]),
+    (0x2f997, false, &[0x26b3c]), (0x2f998, false, &[0x82e5]), (0x2f999, false, &[0x831d]), (0x2f99a, false, &[0x8363]),
+    (0x2f99b, false, &[0x83ad]), (0x2f99c, false, &[0x8323]), (0x2f99d, false, &[0x83bd]), (0x2f99e, false, &[0x83e7]),
+    (0x2f99f, false, &[0x8457]), (0x2f9a0, false, &[0x8353]), (0x2f9a1, false, &[0x83ca]), (0x2f9a2, false, &[0x83cc]),
+    (0x2f9a3, false, &[0x83dc]), (0x2f9a4, false, &[0x26c36]), (0x2f9a5, false, &[0x26d6b]), (0x2f9a6, false, &[0x26cd5]),
+    (0x2f9a7, false, &[0x452b]), (0x2f9a8, false, &[0x84f1]), (0x2f9a9, false, &[0x84f3]), (0x2f9aa, false, &[0x8516]),
+    (0x2f9ab, false, &[0x273ca]), (0x2f9ac, false, &[0x8564]), (0x2f9ad, false, &[0x26f2c]), (0x2f9ae, false, &[0x455d]),
+    (0x2f9af, false, &[0x4561]), (0x2f9b0, false, &[0x26fb1]), (0x2f9b1, false, &[0x270d2]), (0x2f9b2, false, &[0x456b]),
+    (0x2f9b3, false, &[0x8650]), (0x2f9b4, false, &[0x865c]), (0x2f9b5, false, &[0x8667]), (0x2f9b6, false, &[0x8669]),
+    (0x2f9b7, false, &[0x86a9]), (0x2f9b8, false, &[0x8688]), (0x2f9b9, false, &[0x870e]), (0x2f9ba, false, &[0x86e2]),
+    (0x2f9bb, false, &[0x8779]), (0x2f9bc, false, &[0x8728]), (0x2f9bd, false, &[0x876b]), (0x2f9be, false, &[0x8786]),
+    (0x2f9bf, false, &[0x45d7]), (0x2f9c0, false, &[0x87e1]), (0x2f9c1, false, &[0x8801]), (0x2f9c2, false, &[0x45f9]),
+    (0x2f9c3, false, &[0x8860]), (0x2f9c4, false, &[0x8863]), (0x2f9c5, false, &[0x27667]), (0x2f9c6, false, &[0x88d7]),
+    (0x2f9c7, false, &[0x88de]), (0x2f9c8, false, &[0x4635]), (0x2f9c9, false, &[0x88fa]), (0x2f9ca, false, &[0x34bb]),
+    (0x2f9cb, false, &[0x278ae]), (0x2f9cc, false, &[0x27966]), (0x2f9cd, false, &[0x46be]), (0x2f9ce, false, &[0x46c7]),
+    (0x2f9cf, false, &[0x8aa0]), (0x2f9d0, false, &[0x8aed]), (0x2f9d1, false, &[0x8b8a]), (0x2f9d2, false, &[0x8c55]),
+    (0x2f9d3, false, &[0x27ca8]), (0x2f9d4, false, &[0x8cab]), (0x2f9d5, false, &[0x8cc1]), (0x2f9d6, false, &[0x8d1b]),
+    (0x2f9d7, false, &[0x8d77]), (0x2f9d8, false, &[0x27f2f]), (0x2f9d9, false, &[0x20804]), (0x2f9da, false, &[0x8dcb]),
+    (0x2f9db, false, &[0x8dbc]), (0x2f9dc, false, &[0x8df0]), (0x2f9dd, false, &[0x208de]), (0x2f9de, false, &[0x8ed4]),
+    (0x2f9df, false, &[0x8f38]), (0x2f9e0, false, &[0x285d2]), (0x2f9e1, false, &[0x285ed]), (0x2f9e2, false, &[0x9094]),
+    (0x2f9e3, false, &[0x90f1]), (0x2f9e4, false, &[0x9111]), (0x2f9e5, false, &[0x2872e]), (0x2f9e6, false, &[0x911b]),
+    (0x2f9e7, false, &[0x9238]), (0x2f9e8, false, &[0x92d7]), (0x2f9e9, false, &[0x92d8]), (0x2f9ea, false, &[0x927c]),
+    (0x2f9eb, false, &[0x93f9]), (0x2f9ec, false, &[0x9415]), (0x2f9ed, false, &[0x28bfa]), (0x2f9ee, false, &[0x958b]),
+    (0x2f9ef, false, &[0x4995]), (0x2f9f0, false, &[0x95b7]), (0x2f9f1, false, &[0x28d77]), (0x2f9f2, false, &[0x49e6]),
+    (0x2f9f3, false, &[0x96c3]), (0x2f9f4, false, &[0x5db2]), (0x2f9f5, false, &[0x9723]), (0x2f9f6, false, &[0x29145]),
+    (0x2f9f7, false, &[0x2921a]), (0x2f9f8, false, &[0x4a6e]), (0x2f9f9, false, &[0x4a76]), (0x2f9fa, false, &[0x97e0]),
+    (0x2f9fb, false, &[0x2940a]), (0x2f9fc, false, &[0x4ab2]), (0x2f9fd, false, &[0x29496]), (0x2f9fe, false, &[0x980b]),
+    (0x2f9ff, false, &[0x980b]), (0x2fa00, false, &[0x9829]), (0x2fa01, false, &[0x295b6]), (0x2fa02, false, &[0x98e2]),
+    (0x2fa03, false, &[0x4b33]), (0x2fa04, false, &[0x9929]), (0x2fa05, false, &[0x99a7]), (0x2fa06, false, &[0x99c2]),
+    (0x2fa07, false, &[0x99fe]), (0x2fa08, false, &[0x4bce]), (0x2fa09, false, &[0x29b30]), (0x2fa0a, false, &[0x9b12]),
+    (0x2fa0b, false, &[0x9c40]), (0x2fa0c, false, &[0x9cfd]), (0x2fa0d, false, &[0x4cce]), (0x2fa0e, false, &[0x4ced]),
+    (0x2fa0f, false, &[0x9d67]), (0x2fa10, false, &[0x2a0ce]), (0x2fa11, false, &[0x4cf8]), (0x2fa12, false, &[0x2a105]),
+    (0x2fa13, false, &[0x2a20e]), (0x2fa14, false, &[0x2a291]), (0x2fa15, false, &[0x9ebb]), (0x2fa16, false, &[0x4d56]),
+    (0x2fa17, false, &[0x9ef9]), (0x2fa18, false, &[0x9efe]), (0x2fa19, false, &[0x9f05]), (0x2fa1a, false, &[0x9f0f]),
+    (0x2fa1b, false, &[0x9f16]), (0x2fa1c, false, &[0x9f3b]), (0x2fa1d, false, &[0x2a600]),
+];
+
+/// Canonical compositions of pairs of characters, composition exclusions left out, sorted by pair
+pub(crate) const COMPOSITIONS: &[(u32, u32, u32)] = &[
+    (0x3c, 0x338, 0x226e), (0x3d, 0x338, 0x2260), (0x3e, 0x338, 0x226f), (0x41, 0x300, 0xc0), (0x41, 0x301, 0xc1),
+    (0x41, 0x302, 0xc2), (0x41, 0x303, 0xc3), (0x41, 0x304, 0x100), (0x41, 0x306, 0x102), (0x41, 0x307, 0x226),
+    (0x41, 0x308, 0xc4), (0x41, 0x309, 0x1ea2), (0x41, 0x30a, 0xc5), (0x41, 0x30c, 0x1cd), (0x41, 0x30f, 0x200),
+    (0x41, 0x311, 0x202), (0x41, 0x323, 0x1ea0), (0x41, 0x325, 0x1e00), (0x41, 0x328, 0x104), (0x42, 0x307, 0x1e02),
+    (0x42, 0x323, 0x1e04), (0x42, 0x331, 0x1e06), (0x43, 0x301, 0x106), (0x43, 0x302, 0x108), (0x43, 0x307, 0x10a),
+    (0x43, 0x30c, 0x10c), (0x43, 0x327, 0xc7), (0x44, 0x307, 0x1e0a), (0x44, 0x30c, 0x10e), (0x44, 0x323, 0x1e0c),
+    (0x44, 0x327, 0x1e10), (0x44, 0x32d, 0x1e12), (0x44, 0x331, 0x1e0e), (0x45, 0x300, 0xc8), (0x45, 0x301, 0xc9),
+    (0x45, 0x302, 0xca), (0x45, 0x303, 0x1ebc), (0x45, 0x304, 0x112), (0x45, 0x306, 0x114), (0x45, 0x307, 0x116),
+    (0x45, 0x308, 0xcb), (0x45, 0x309, 0x1eba), (0x45, 0x30c, 0x11a), (0x45, 0x30f, 0x204), (0x45, 0x311, 0x206),
+    (0x45, 0x323, 0x1eb8), (0x45, 0x327, 0x228), (0x45, 0x328, 0x118), (0x45, 0x32d, 0x1e18), (0x45, 0x330, 0x1e1a),
+    (0x46, 0x307, 0x1e1e), (0x47, 0x301, 0x1f4), (0x47, 0x302, 0x11c), (0x47, 0x304, 0x1e20), (0x47, 0x306, 0x11e),
+    (0x47, 0x307, 0x120), (0x47, 0x30c, 0x1e6), (0x47, 0x327, 0x122), (0x48, 0x302, 0x124), (0x48, 0x307, 0x1e22),
+    (0x48, 0x308, 0x1e26), (0x48, 0x30c, 0x21e), (0x48, 0x323, 0x1e24), (0x48, 0x327, 0x1e28), (0x48, 0x32e, 0x1e2a),
+    (0x49, 0x300, 0xcc), (0x49, 0x301, 0xcd), (0x49, 0x302, 0xce), (0x49, 0x303, 0x128), (0x49, 0x304, 0x12a),
+    (0x49, 0x306, 0x12c), (0x49, 0x307, 0x130), (0x49, 0x308, 0xcf), (0x49, 0x309, 0x1ec8), (0x49, 0x30c, 0x1cf),
+    (0x49, 0x30f, 0x208), (0x49, 0x311, 0x20a), (0x49, 0x323, 0x1eca), (0x49, 0x328, 0x12e), (0x49, 0x330, 0x1e2c),
+    (0x4a, 0x302, 0x134), (0x4b, 0x301, 0x1e30), (0x4b, 0x30c, 0x1e8), (0x4b, 0x323, 0x1e32), (0x4b, 0x327, 0x136),
+    (0x4b, 0x331, 0x1e34), (0x4c, 0x301, 0x139), (0x4c, 0x30c, 0x13d), (0x4c, 0x323, 0x1e36), (0x4c, 0x327, 0x13b),
+    (0x4c, 0x32d, 0x1e3c), (0x4c, 0x331, 0x1e3a), (0x4d, 0x301, 0x1e3e), (0x4d, 0x307, 0x1e40), (0x4d, 0x323, 0x1e42),
+    (0x4e, 0x300, 0x1f8), (0x4e, 0x301, 0x143), (0x4e, 0x303, 0xd1), (0x4e, 0x307, 0x1e44), (0x4e, 0x30c, 0x147),
+    (0x4e, 0x323, 0x1e46), (0x4e, 0x327, 0x145), (0x4e, 0x32d, 0x1e4a), (0x4e, 0x331, 0x1e48), (0x4f, 0x300, 0xd2),
+    (0x4f, 0x301, 0xd3), (0x4f, 0x302, 0xd4), (0x4f, 0x303, 0xd5), (0x4f, 0x304, 0x14c), (0x4f, 0x306, 0x14e),
+    (0x4f, 0x307, 0x22e), (0x4f, 0x308, 0xd6), (0x4f, 0x309, 0x1ece), (0x4f, 0x30b, 0x150), (0x4f, 0x30c, 0x1d1),
+    (0x4f, 0x30f, 0x20c), (0x4f, 0x311, 0x20e), (0x4f, 0x31b, 0x1a0), (0x4f, 0x323, 0x1ecc), (0x4f, 0x328, 0x1ea),
+    (0x50, 0x301, 0x1e54), (0x50, 0x307, 0x1e56), (0x52, 0x301, 0x154), (0x52, 0x307, 0x1e58), (0x52, 0x30c, 0x158),
+    (0x52, 0x30f, 0x210), (0x52, 0x311, 0x212), (0x52, 0x323, 0x1e5a), (0x52, 0x327, 0x156), (0x52, 0x331, 0x1e5e),
+    (0x53, 0x301, 0x15a), (0x53, 0x302, 0x15c), (0x53, 0x307, 0x1e60), (0x53, 0x30c, 0x160), (0x53, 0x323, 0x1e62),
+    (0x53, 0x326, 0x218), (0x53, 0x327, 0x15e), (0x54, 0x307, 0x1e6a), (0x54, 0x30c, 0x164), (0x54, 0x323, 0x1e6c),
+    (0x54, 0x326, 0x21a), (0x54, 0x327, 0x162), (0x54, 0x32d, 0x1e70), (0x54, 0x331, 0x1e6e), (0x55, 0x300, 0xd9),
+    (0x55, 0x301, 0xda), (0x55, 0x302, 0xdb), (0x55, 0x303, 0x168), (0x55, 0x304, 0x16a), (0x55, 0x306, 0x16c),
+    (0x55, 0x308, 0xdc), (0x55, 0x309, 0x1ee6), (0x55, 0x30a, 0x16e), (0x55, 0x30b, 0x170), (0x55, 0x30c, 0x1d3),
+    (0x55, 0x30f, 0x214), (0x55, 0x311, 0x216), (0x55, 0x31b, 0x1af), (0x55, 0x323, 0x1ee4), (0x55, 0x324, 0x1e72),
+    (0x55, 0x328, 0x172), (0x55, 0x32d, 0x1e76), (0x55, 0x330, 0x1e74), (0x56, 0x303, 0x1e7c), (0x56, 0x323, 0x1e7e),
+    (0x57, 0x300, 0x1e80), (0x57, 0x301, 0x1e82), (0x57, 0x302, 0x174), (0x57, 0x307, 0x1e86), (0x57, 0x308, 0x1e84),
+    (0x57, 0x323, 0x1e88), (0x58, 0x307, 0x1e8a), (0x58, 0x308, 0x1e8c), (0x59, 0x300, 0x1ef2), (0x59, 0x301, 0xdd),
+    (0x59, 0x302, 0x176), (0x59, 0x303, 0x1ef8), (0x59, 0x304, 0x232), (0x59, 0x307, 0x1e8e), (0x59, 0x308, 0x178),
+    (0x59, 0x309, 0x1ef6), (0x59, 0x323, 0x1ef4), (0x5a, 0x301, 0x179), (0x5a, 0x302, 0x1e90), (0x5a, 0x307, 0x17b),
+    (0x5a, 0x30c, 0x17d), (0x5a, 0x323, 0x1e92), (0x5a, 0x331, 0x1e94), (0x61, 0x300, 0xe0), (0x61, 0x301, 0xe1),
+    (0x61, 0x302, 0xe2), (0x61, 0x303, 0xe3), (0x61, 0x304, 0x101), (0x61, 0x306, 0x103), (0x61, 0x307, 0x227),
+    (0x61, 0x308, 0xe4), (0x61, 0x309, 0x1ea3), (0x61, 0x30a, 0xe5), (0x61, 0x30c, 0x1ce), (0x61, 0x30f, 0x201),
+    (0x61, 0x311, 0x203), (0x61, 0x323, 0x1ea1), (0x61, 0x325, 0x1e01), (0x61, 0x328, 0x105), (0x62, 0x307, 0x1e03),
+    (0x62, 0x323, 0x1e05), (0x62, 0x331, 0x1e07), (0x63, 0x301, 0x107), (0x63, 0x302, 0x109), (0x63, 0x307, 0x10b),
+    (0x63, 0x30c, 0x10d), (0x63, 0x327, 0xe7), (0x64, 0x307, 0x1e0b), (0x64, 0x30c, 0x10f), (0x64, 0x323, 0x1e0d),
+    (0x64, 0x327, 0x1e11), (0x64, 0x32d, 0x1e13), (0x64, 0x331, 0x1e0f), (0x65, 0x300, 0xe8), (0x65, 0x301, 0xe9),
+    (0x65, 0x302, 0xea), (0x65, 0x303, 0x1ebd), (0x65, 0x304, 0x113), (0x65, 0x306, 0x115), (0x65, 0x307, 0x117),
+    (0x65, 0x308, 0xeb), (0x65, 0x309, 0x1ebb), (0x65, 0x30c, 0x11b), (0x65, 0x30f, 0x205), (0x65, 0x311, 0x207),
+    (0x65, 0x323, 0x1eb9), (0x65, 0x327, 0x229), (0x65, 0x328, 0x119), (0x65, 0x32d, 0x1e19), (0x65, 0x330, 0x1e1b),
+    (0x66, 0x307, 0x1e1f), (0x67, 0x301, 0x1f5), (0x67, 0x302, 0x11d), (0x67, 0x304, 0x1e21), (0x67, 0x306, 0x11f),
+    (0x67, 0x307, 0x121), (0x67, 0x30c, 0x1e7), (0x67, 0x327, 0x123), (0x68, 0x302, 0x125), (0x68, 0x307, 0x1e23),
+    (0x68, 0x308, 0x1e27), (0x68, 0x30c, 0x21f), (0x68, 0x323, 0x1e25), (0x68, 0x327, 0x1e29), (0x68, 0x32e, 0x1e2b),
+    (0x68, 0x331, 0x1e96), (0x69, 0x300, 0xec), (0x69, 0x301, 0xed), (0x69, 0x302, 0xee), (0x69, 0x303, 0x129),
+    (0x69, 0x304, 0x12b), (0x69, 0x306, 0x12d), (0x69, 0x308, 0xef), (0x69, 0x309, 0x1ec9), (0x69, 0x30c, 0x1d0),
+    (0x69, 0x30f, 0x209), (0x69, 0x311, 0x20b), (0x69, 0x323, 0x1ecb), (0x69, 0x328, 0x12f), (0x69, 0x330, 0x1e2d),
+    (0x6a, 0x302, 0x135), (0x6a, 0x30c, 0x1f0), (0x6b, 0x301, 0x1e31), (0x6b, 0x30c, 0x1e9), (0x6b, 0x323, 0x1e33),
+    (0x6b, 0x327, 0x137), (0x6b, 0x331, 0x1e35), (0x6c, 0x301, 0x13a), (0x6c, 0x30c, 0x13e), (0x6c, 0x323, 0x1e37),
+    (0x6c, 0x327, 0x13c), (0x6c, 0x32d, 0x1e3d), (0x6c, 0x331, 0x1e3b), (0x6d, 0x301, 0x1e3f), (0x6d, 0x307, 0x1e41),
+    (0x6d, 0x323, 0x1e43), (0x6e, 0x300, 0x1f9), (0x6e, 0x301, 0x144), (0x6e, 0x303, 0xf1), (0x6e, 0x307, 0x1e45),
+    (0x6e, 0x30c, 0x148), (0x6e, 0x323, 0x1e47), (0x6e, 0x327, 0x146), (0x6e, 0x32d, 0x1e4b), (0x6e, 0x331, 0x1e49),
+    (0x6f, 0x300, 0xf2), (0x6f, 0x301, 0xf3), (0x6f, 0x302, 0xf4), (0x6f, 0x303, 0xf5), (0x6f, 0x304, 0x14d),
+    (0x6f, 0x306, 0x14f), (0x6f, 0x307, 0x22f), (0x6f, 0x308, 0xf6), (0x6f, 0x309, 0x1ecf), (0x6f, 0x30b, 0x151),
+    (0x6f, 0x30c, 0x1d2), (0x6f, 0x30f, 0x20d), (0x6f, 0x311, 0x20f), (0x6f, 0x31b, 0x1a1), (0x6f, 0x323, 0x1ecd),
+    (0x6f, 0x328, 0x1eb), (0x70, 0x301, 0x1e55), (0x70, 0x307, 0x1e57), (0x72, 0x301, 0x155), (0x72, 0x307, 0x1e59),
+    (0x72, 0x30c, 0x159), (0x72, 0x30f, 0x211), (0x72, 0x311, 0x213), (0x72, 0x323, 0x1e5b), (0x72, 0x327, 0x157),
+    (0x72, 0x331, 0x1e5f), (0x73, 0x301, 0x15b), (0x73, 0x302, 0x15d), (0x73, 0x307, 0x1e61), (0x73, 0x30c, 0x161),
+    (0x73, 0x323, 0x1e63), (0x73, 0x326, 0x219), (0x73, 0x327, 0x15f), (0x74, 0x307, 0x1e6b), (0x74, 0x308, 0x1e97),
+    (0x74, 0x30c, 0x165), (0x74, 0x323, 0x1e6d), (0x74, 0x326, 0x21b), (0x74, 0x327, 0x163), (0x74, 0x32d, 0x1e71),
+    (0x74, 0x331, 0x1e6f), (0x75, 0x300, 0xf9), (0x75, 0x301, 0xfa), (0x75, 0x302, 0xfb), (0x75, 0x303, 0x169),
+    (0x75, 0x304, 0x16b), (0x75, 0x306, 0x16d), (0x75, 0x308, 0xfc), (0x75, 0x309, 0x1ee7), (0x75, 0x30a, 0x16f),
+    (0x75, 0x30b, 0x171), (0x75, 0x30c, 0x1d4), (0x75, 0x30f, 0x215), (0x75, 0x311, 0x217), (0x75, 0x31b, 0x1b0),
+    (0x75, 0x323, 0x1ee5), (0x75, 0x324, 0x1e73), (0x75, 0x328, 0x173), (0x75, 0x32d, 0x1e77), (0x75, 0x330, 0x1e75),
+    (0x76, 0x303, 0x1e7d), (0x76, 0x323, 0x1e7f), (0x77, 0x300, 0x1e81), (0x77, 0x301, 0x1e83), (0x77, 0x302, 0x175),
+    (0x77, 0x307, 0x1e87), (0x77, 0x308, 0x1e85), (0x77, 0x30a, 0x1e98), (0x77, 0x323, 0x1e89), (0x78, 0x307, 0x1e8b),
+    (0x78, 0x308, 0x1e8d), (0x79, 0x300, 0x1ef3), (0x79, 0x301, 0xfd), (0x79, 0x302, 0x177), (0x79, 0x303, 0x1ef9),
+    (0x79, 0x304, 0x233), (0x79, 0x307, 0x1e8f), (0x79, 0x308, 0xff), (0x79, 0x309, 0x1ef7), (0x79, 0x30a, 0x1e99),
+    (0x79, 0x323, 0x1ef5), (0x7a, 0x301, 0x17a), (0x7a, 0x302, 0x1e91), (0x7a, 0x307, 0x17c), (0x7a, 0x30c, 0x17e),
+    (0x7a, 0x323, 0x1e93), (0x7a, 0x331, 0x1e95), (0xa8, 0x300, 0x1fed), (0xa8, 0x301, 0x385), (0xa8, 0x342, 0x1fc1),
+    (0xc2, 0x300, 0x1ea6), (0xc2, 0x301, 0x1ea4), (0xc2, 0x303, 0x1eaa), (0xc2, 0x309, 0x1ea8), (0xc4, 0x304, 0x1de),
+    (0xc5, 0x301, 0x1fa), (0xc6, 0x301, 0x1fc), (0xc6, 0x304, 0x1e2), (0xc7, 0x301, 0x1e08), (0xca, 0x300, 0x1ec0),
+    (0xca, 0x301, 0x1ebe), (0xca, 0x303, 0x1ec4), (0xca, 0x309, 0x1ec2), (0xcf, 0x301, 0x1e2e), (0xd4, 0x300, 0x1ed2),
+    (0xd4, 0x301, 0x1ed0), (0xd4, 0x303, 0x1ed6), (0xd4, 0x309, 0x1ed4), (0xd5, 0x301, 0x1e4c), (0xd5, 0x304, 0x22c),
+    (0xd5, 0x308, 0x1e4e), (0xd6, 0x304, 0x22a), (0xd8, 0x301, 0x1fe), (0xdc, 0x300, 0x1db), (0xdc, 0x301, 0x1d7),
+    (0xdc, 0x304, 0x1d5), (0xdc, 0x30c, 0x1d9), (0xe2, 0x300, 0x1ea7), (0xe2, 0x301, 0x1ea5), (0xe2, 0x303, 0x1eab),
+    (0xe2, 0x309, 0x1ea9), (0xe4, 0x304, 0x1df), (0xe5, 0x301, 0x1fb), (0xe6, 0x301, 0x1fd), (0xe6, 0x304, 0x1e3),
+    (0xe7, 0x301, 0x1e09), (0xea, 0x300, 0x1ec1), (0xea, 0x301, 0x1ebf), (0xea, 0x303, 0x1ec5), (0xea, 0x309, 0x1ec3),
+    (0xef, 0x301, 0x1e2f), (0xf4, 0x300, 0x1ed3), (0xf4, 0x301, 0x1ed1), (0xf4, 0x303, 0x1ed7), (0xf4, 0x309, 0x1ed5),
+    (0xf5, 0x301, 0x1e4d), (0xf5, 0x304, 0x22d), (0xf5, 0x308, 0x1e4f), (0xf6, 0x304, 0x22b), (0xf8, 0x301, 0x1ff),
+    (0xfc, 0x300, 0x1dc), (0xfc, 0x301, 0x1d8), (0xfc, 0x304, 0x1d6), (0xfc, 0x30c, 0x1da), (0x102, 0x300, 0x1eb0),
+    (0x102, 0x301, 0x1eae), (0x102, 0x303, 0x1eb4), (0x102, 0x309, 0x1eb2), (0x103, 0x300, 0x1eb1), (0x103, 0x301, 0x1eaf),
+    (0x103, 0x303, 0x1eb5), (0x103, 0x309, 0x1eb3), (0x112, 0x300, 0x1e14), (0x112, 0x301, 0x1e16), (0x113, 0x300, 0x1e15),
+    (0x113, 0x301, 0x1e17), (0x14c, 0x300, 0x1e50), (0x14c, 0x301, 0x1e52), (0x14d, 0x300, 0x1e51), (0x14d, 0x301, 0x1e53),
+    (0x15a, 0x307, 0x1e64), (0x15b, 0x307, 0x1e65), (0x160, 0x307, 0x1e66), (0x161, 0x307, 0x1e67), (0x168, 0x301, 0x1e78),
+    (0x169, 0x301, 0x1e79), (0x16a, 0x308, 0x1e7a), (0x16b, 0x308, 0x1e7b), (0x17f, 0x307, 0x1e9b), (0x1a0, 0x300, 0x1edc),
+    (0x1a0, 0x301, 0x1eda), (0x1a0, 0x303, 0x1ee0), (0x1a0, 0x309, 0x1ede), (0x1a0, 0x323, 0x1ee2), (0x1a1, 0x300, 0x1edd),
+    (0x1a1, 0x301, 0x1edb), (0x1a1, 0x303, 0x1ee1), (0x1a1, 0x309, 0x1edf), (0x1a1, 0x323, 0x1ee3), (0x1af, 0x300, 0x1eea),
+    (0x1af, 0x301, 0x1ee8), (0x1af, 0x303, 0x1eee), (0x1af, 0x309, 0x1eec), (0x1af, 0x323, 0x1ef0), (0x1b0, 0x300, 0x1eeb),
+    (0x1b0, 0x301, 0x1ee9), (0x1b0, 0x303, 0x1eef), (0x1b0, 0x309, 0x1eed), (0x1b0, 0x323, 0x1ef1), (0x1b7, 0x30c, 0x1ee),
+    (0x1ea, 0x304, 0x1ec), (0x1eb, 0x304, 0x1ed), (0x226, 0x304, 0x1e0), (0x227, 0x304, 0x1e1), (0x228, 0x306, 0x1e1c),
+    (0x229, 0x306, 0x1e1d), (0x22e, 0x304, 0x230), (0x22f, 0x304, 0x231), (0x292, 0x30c, 0x1ef), (0x391, 0x300, 0x1fba),
+    (0x391, 0x301, 0x386), (0x391, 0x304, 0x1fb9), (0x391, 0x306, 0x1fb8), (0x391, 0x313, 0x1f08), (0x391, 0x314, 0x1f09),
+    (0x391, 0x345, 0x1fbc), (0x395, 0x300, 0x1fc8), (0x395, 0x301, 0x388), (0x395, 0x313, 0x1f18), (0x395, 0x314, 0x1f19),
+    (0x397, 0x300, 0x1fca), (0x397, 0x301, 0x389), (0x397, 0x313, 0x1f28), (0x397, 0x314, 0x1f29), (0x397, 0x345, 0x1fcc),
+    (0x399, 0x300, 0x1fda), (0x399, 0x301, 0x38a), (0x399, 0x304, 0x1fd9), (0x399, 0x306, 0x1fd8), (0x399, 0x308, 0x3aa),
+    (0x399, 0x313, 0x1f38), (0x399, 0x314, 0x1f39), (0x39f, 0x300, 0x1ff8), (0x39f, 0x301, 0x38c), (0x39f, 0x313, 0x1f48),
+    (0x39f, 0x314, 0x1f49), (0x3a1, 0x314, 0x1fec), (0x3a5, 0x300, 0x1fea), (0x3a5, 0x301, 0x38e), (0x3a5, 0x304, 0x1fe9),
+    (0x3a5, 0x306, 0x1fe8), (0x3a5, 0x308, 0x3ab), (0x3a5, 0x314, 0x1f59), (0x3a9, 0x300, 0x1ffa), (0x3a9, 0x301, 0x38f),
+    (0x3a9, 0x313, 0x1f68), (0x3a9, 0x314, 0x1f69), (0x3a9, 0x345, 0x1ffc), (0x3ac, 0x345, 0x1fb4), (0x3ae, 0x345, 0x1fc4),
+    (0x3b1, 0x300, 0x1f70), (0x3b1, 0x301, 0x3ac), (0x3b1, 0x304, 0x1fb1), (0x3b1, 0x306, 0x1fb0), (0x3b1, 0x313, 0x1f00),
+    (0x3b1, 0x314, 0x1f01), (0x3b1, 0x342, 0x1fb6), (0x3b1, 0x345, 0x1fb3), (0x3b5, 0x300, 0x1f72), (0x3b5, 0x301, 0x3ad),
+    (0x3b5, 0x313, 0x1f10), (0x3b5, 0x314, 0x1f11), (0x3b7, 0x300, 0x1f74), (0x3b7, 0x301, 0x3ae), (0x3b7, 0x313, 0x1f20),
+    (0x3b7, 0x314, 0x1f21), (0x3b7, 0x342, 0x1fc6), (0x3b7, 0x345, 0x1fc3), (0x3b9, 0x300, 0x1f76), (0x3b9, 0x301, 0x3af),
+    (0x3b9, 0x304, 0x1fd1), (0x3b9, 0x306, 0x1fd0), (0x3b9, 0x308, 0x3ca), (0x3b9, 0x313, 0x1f30), (0x3b9, 0x314, 0x1f31),
+    (0x3b9, 0x342, 0x1fd6), (0x3bf, 0x300, 0x1f78), (0x3bf, 0x301, 0x3cc), (0x3bf, 0x313, 0x1f40), (0x3bf, 0x314, 0x1f41),
+    (0x3c1, 0x313, 0x1fe4), (0x3c1, 0x314, 0x1fe5), (0x3c5, 0x300, 0x1f7a), (0x3c5, 0x301, 0x3cd), (0x3c5, 0x304, 0x1fe1),
+    (0x3c5, 0x306, 0x1fe0), (0x3c5, 0x308, 0x3cb), (0x3c5, 0x313, 0x1f50), (0x3c5, 0x314, 0x1f51), (0x3c5, 0x342, 0x1fe6),
+    (0x3c9, 0x300, 0x1f7c), (0x3c9, 0x301, 0x3ce), (0x3c9, 0x313, 0x1f60), (0x3c9, 0x314, 0x1f61), (0x3c9, 0x342, 0x1ff6),
+    (0x3c9, 0x345, 0x1ff3), (0x3ca, 0x300, 0x1fd2), (0x3ca, 0x301, 0x390), (0x3ca, 0x342, 0x1fd7), (0x3cb, 0x300, 0x1fe2),
+    (0x3cb, 0x301, 0x3b0), (0x3cb, 0x342, 0x1fe7), (0x3ce, 0x345, 0x1ff4), (0x3d2, 0x301, 0x3d3), (0x3d2, 0x308, 0x3d4),
+    (0x406, 0x308, 0x407), (0x410, 0x306, 0x4d0), (0x410, 0x308, 0x4d2), (0x413, 0x301, 0x403), (0x415, 0x300, 0x400),
+    (0x415, 0x306, 0x4d6), (0x415, 0x308, 0x401), (0x416, 0x306, 0x4c1), (0x416, 0x308, 0x4dc), (0x417, 0x308, 0x4de),
+    (0x418, 0x300, 0x40d), (0x418, 0x304, 0x4e2), (0x418, 0x306, 0x419), (0x418, 0x308, 0x4e4), (0x41a, 0x301, 0x40c),
+    (0x41e, 0x308, 0x4e6), (0x423, 0x304, 0x4ee), (0x423, 0x306, 0x40e), (0x423, 0x308, 0x4f0), (0x423, 0x30b, 0x4f2),
+    (0x427, 0x308, 0x4f4), (0x42b, 0x308, 0x4f8), (0x42d, 0x308, 0x4ec), (0x430, 0x306, 0x4d1), (0x430, 0x308, 0x4d3),
+    (0x433, 0x301, 0x453), (0x435, 0x300, 0x450), (0x435, 0x306, 0x4d7), (0x435, 0x308, 0x451), (0x436, 0x306, 0x4c2),
+    (0x436, 0x308, 0x4dd), (0x437, 0x308, 0x4df), (0x438, 0x300, 0x45d), (0x438, 0x304, 0x4e3), (0x438, 0x306, 0x439),
+    (0x438, 0x308, 0x4e5), (0x43a, 0x301, 0x45c), (0x43e, 0x308, 0x4e7), (0x443, 0x304, 0x4ef), (0x443, 0x306, 0x45e),
+    (0x443, 0x308, 0x4f1), (0x443, 0x30b, 0x4f3), (0x447, 0x308, 0x4f5), (0x44b, 0x308, 0x4f9), (0x44d, 0x308, 0x4ed),
+    (0x456, 0x308, 0x457), (0x474, 0x30f, 0x476), (0x475, 0x30f, 0x477), (0x4d8, 0x308, 0x4da), (0x4d9, 0x308, 0x4db),
+    (0x4e8, 0x308, 0x4ea), (0x4e9, 0x308, 0x4eb), (0x627, 0x653, 0x622), (0x627, 0x654, 0x623), (0x627, 0x655, 0x625),
+    (0x648, 0x654, 0x624), (0x64a, 0x654, 0x626), (0x6c1, 0x654, 0x6c2), (0x6d2, 0x654, 0x6d3), (0x6d5, 0x654, 0x6c0),
+    (0x928, 0x93c, 0x929), (0x930, 0x93c, 0x931), (0x933, 0x93c, 0x934), (0x9c7, 0x9be, 0x9cb), (0x9c7, 0x9d7, 0x9cc),
+    (0xb47, 0xb3e, 0xb4b), (0xb47, 0xb56, 0xb48), (0xb47, 0xb57, 0xb4c), (0xb92, 0xbd7, 0xb94), (0xbc6, 0xbbe, 0xbca),
+    (0xbc6, 0xbd7, 0xbcc), (0xbc7, 0xbbe, 0xbcb), (0xc46, 0xc56, 0xc48), (0xcbf, 0xcd5, 0xcc0), (0xcc6, 0xcc2, 0xcca),
+    (0xcc6, 0xcd5, 0xcc7), (0xcc6, 0xcd6, 0xcc8), (0xcca, 0xcd5, 0xccb), (0xd46, 0xd3e, 0xd4a), (0xd46, 0xd57, 0xd4c),
+    (0xd47, 0xd3e, 0xd4b), (0xdd9, 0xdca, 0xdda), (0xdd9, 0xdcf, 0xddc), (0xdd9, 0xddf, 0xdde), (0xddc, 0xdca, 0xddd),
+    (0x1025, 0x102e, 0x1026), (0x1b05, 0x1b35, 0x1b06), (0x1b07, 0x1b35, 0x1b08), (0x1b09, 0x1b35, 0x1b0a), (0x1b0b, 0x1b35, 0x1b0c),
+    (0x1b0d, 0x1b35, 0x1b0e), (0x1b11, 0x1b35, 0x1b12), (0x1b3a, 0x1b35, 0x1b3b), (0x1b3c, 0x1b35, 0x1b3d), (0x1b3e, 0x1b35, 0x1b40),
+    (0x1b3f, 0x1b35, 0x1b41), (0x1b42, 0x1b35, 0x1b43), (0x1e36, 0x304, 0x1e38), (0x1e37, 0x304, 0x1e39), (0x1e5a, 0x304, 0x1e5c),
+    (0x1e5b, 0x304, 0x1e5d), (0x1e62, 0x307, 0x1e68), (0x1e63, 0x307, 0x1e69), (0x1ea0, 0x302, 0x1eac), (0x1ea0, 0x306, 0x1eb6),
+    (0x1ea1, 0x302, 0x1ead), (0x1ea1, 0x306, 0x1eb7), (0x1eb8, 0x302, 0x1ec6), (0x1eb9, 0x302, 0x1ec7), (0x1ecc, 0x302, 0x1ed8),
+    (0x1ecd, 0x302, 0x1ed9), (0x1f00, 0x300, 0x1f02), (0x1f00, 0x301, 0x1f04), (0x1f00, 0x342, 0x1f06), (0x1f00, 0x345, 0x1f80),
+    (0x1f01, 0x300, 0x1f03), (0x1f01, 0x301, 0x1f05), (0x1f01, 0x342, 0x1f07), (0x1f01, 0x345, 0x1f81), (0x1f02, 0x345, 0x1f82),
+    (0x1f03, 0x345, 0x1f83), (0x1f04, 0x345, 0x1f84), (0x1f05, 0x345, 0x1f85), (0x1f06, 0x345, 0x1f86), (0x1f07, 0x345, 0x1f87),
+    (0x1f08, 0x300, 0x1f0a), (0x1f08, 0x301, 0x1f0c), (0x1f08, 0x342, 0x1f0e), (0x1f08, 0x345, 0x1f88), (0x1f09, 0x300, 0x1f0b),
+    (0x1f09, 0x301, 0x1f0d), (0x1f09, 0x342, 0x1f0f), (0x1f09, 0x345, 0x1f89), (0x1f0a, 0x345, 0x1f8a), (0x1f0b, 0x345, 0x1f8b),
+    (0x1f0c, 0x345, 0x1f8c), (0x1f0d, 0x345, 0x1f8d), (0x1f0e, 0x345, 0x1f8e), (0x1f0f, 0x345, 0x1f8f), (0x1f10, 0x300, 0x1f12),
+    (0x1f10, 0x301, 0x1f14), (0x1f11, 0x300, 0x1f13), (0x1f11, 0x301, 0x1f15), (0x1f18, 0x300, 0x1f1a), (0x1f18, 0x301, 0x1f1c),
+    (0x1f19, 0x300, 0x1f1b), (0x1f19, 0x301, 0x1f1d), (0x1f20, 0x300, 0x1f22), (0x1f20, 0x301, 0x1f24), (0x1f20, 0x342, 0x1f26),
+    (0x1f20, 0x345, 0x1f90), (0x1f21, 0x300, 0x1f23), (0x1f21, 0x301, 0x1f25), (0x1f21, 0x342, 0x1f27), (0x1f21, 0x345, 0x1f91),
+    (0x1f22, 0x345, 0x1f92), (0x1f23, 0x345, 0x1f93), (0x1f24, 0x345, 0x1f94), (0x1f25, 0x345, 0x1f95), (0x1f26, 0x345, 0x1f96),
+    (0x1f27, 0x345, 0x1f97), (0x1f28, 0x300, 0x1f2a), (0x1f28, 0x301, 0x1f2c), (0x1f28, 0x342, 0x1f2e), (0x1f28, 0x345, 0x1f98),
+    (0x1f29, 0x300, 0x1f2b), (0x1f29, 0x301, 0x1f2d), (0x1f29, 0x342, 0x1f2f), (0x1f29, 0x345, 0x1f99), (0x1f2a, 0x345, 0x1f9a),
+    (0x1f2b, 0x345, 0x1f9b), (0x1f2c, 0x345, 0x1f9c), (0x1f2d, 0x345, 0x1f9d), (0x1f2e, 0x345, 0x1f9e), (0x1f2f, 0x345, 0x1f9f),
+    (0x1f30, 0x300, 0x1f32), (0x1f30, 0x301, 0x1f34), (0x1f30, 0x342, 0x1f36), (0x1f31, 0x300, 0x1f33), (0x1f31, 0x301, 0x1f35),
+    (0x1f31, 0x342, 0x1f37), (0x1f38, 0x300, 0x1f3a), (0x1f38, 0x301, 0x1f3c), (0x1f38, 0x342, 0x1f3e), (0x1f39, 0x300, 0x1f3b),
+    (0x1f39, 0x301, 0x1f3d), (0x1f39, 0x342, 0x1f3f), (0x1f40, 0x300, 0x1f42), (0x1f40, 0x301, 0x1f44), (0x1f41, 0x300, 0x1f43),
+    (0x1f41, 0x301, 0x1f45), (0x1f48, 0x300, 0x1f4a), (0x1f48, 0x301, 0x1f4c), (0x1f49, 0x300, 0x1f4b), (0x1f49, 0x301, 0x1f4d),
+    (0x1f50, 0x300, 0x1f52), (0x1f50, 0x301, 0x1f54), (0x1f50, 0x342, 0x1f56), (0x1f51, 0x300, 0x1f53), (0x1f51, 0x301, 0x1f55),
+    (0x1f51, 0x342, 0x1f57), (0x1f59, 0x300, 0x1f5b), (0x1f59, 0x301, 0x1f5d), (0x1f59, 0x342, 0x1f5f), (0x1f60, 0x300, 0x1f62),
+    (0x1f60, 0x301, 0x1f64), (0x1f60, 0x342, 0x1f66), (0x1f60, 0x345, 0x1fa0), (0x1f61, 0x300, 0x1f63), (0x1f61, 0x301, 0x1f65),
+    (0x1f61, 0x342, 0x1f67), (0x1f61, 0x345, 0x1fa1), (0x1f62, 0x345, 0x1fa2), (0x1f63, 0x345, 0x1fa3), (0x1f64, 0x345, 0x1fa4),
+    (0x1f65, 0x345, 0x1fa5), (0x1f66, 0x345, 0x1fa6), (0x1f67, 0x345, 0x1fa7), (0x1f68, 0x300, 0x1f6a), (0x1f68, 0x301, 0x1f6c),
+    (0x1f68, 0x342, 0x1f6e), (0x1f68, 0x345, 0x1fa8), (0x1f69, 0x300, 0x1f6b), (0x1f69, 0x301, 0x1f6d), (0x1f69, 0x342, 0x1f6f),
+    (0x1f69, 0x345, 0x1fa9), (0x1f6a, 0x345, 0x1faa), (0x1f6b, 0x345, 0x1fab), (0x1f6c, 0x345, 0x1fac), (0x1f6d, 0x345, 0x1fad),
+    (0x1f6e, 0x345, 0x1fae), (0x1f6f, 0x345, 0x1faf), (0x1f70, 0x345, 0x1fb2), (0x1f74, 0x345, 0x1fc2), (0x1f7c, 0x345, 0x1ff2),
+    (0x1fb6, 0x345, 0x1fb7), (0x1fbf, 0x300, 0x1fcd), (0x1fbf, 0x301, 0x1fce), (0x1fbf, 0x342, 0x1fcf), (0x1fc6, 0x345, 0x1fc7),
+    (0x1ff6, 0x345, 0x1ff7), (0x1ffe, 0x300, 0x1fdd), (0x1ffe, 0x301, 0x1fde), (0x1ffe, 0x342, 0x1fdf), (0x2190, 0x338, 0x219a),
+    (0x2192, 0x338, 0x219b), (0x2194, 0x338, 0x21ae), (0x21d0, 0x338, 0x21cd), (0x21d2, 0x338, 0x21cf), (0x21d4, 0x338, 0x21ce),
+    (0x2203, 0x338, 0x2204), (0x2208, 0x338, 0x2209), (0x220b, 0x338, 0x220c), (0x2223, 0x338, 0x2224), (0x2225, 0x338, 0x2226),
+    (0x223c, 0x338, 0x2241), (0x2243, 0x338, 0x2244), (0x2245, 0x338, 0x2247), (0x2248, 0x338, 0x2249), (0x224d, 0x338, 0x226d),
+    (0x2261, 0x338, 0x2262), (0x2264, 0x338, 0x2270), (0x2265, 0x338, 0x2271), (0x2272, 0x338, 0x2274), (0x2273, 0x338, 0x2275),
+    (0x2276, 0x338, 0x2278), (0x2277, 0x338, 0x2279), (0x227a, 0x338, 0x2280), (0x227b, 0x338, 0x2281), (0x227c, 0x338, 0x22e0),
+    (0x227d, 0x338, 0x22e1), (0x2282, 0x338, 0x2284), (0x2283, 0x338, 0x2285), (0x2286, 0x338, 0x2288), (0x2287, 0x338, 0x2289),
+    (0x2291, 0x338, 0x22e2), (0x2292, 0x338, 0x22e3), (0x22a2, 0x338, 0x22ac), (0x22a8, 0x338, 0x22ad), (0x22a9, 0x338, 0x22ae),
+    (0x22ab, 0x338, 0x22af), (0x22b2, 0x338, 0x22ea), (0x22b3, 0x338, 0x22eb), (0x22b4, 0x338, 0x22ec), (0x22b5, 0x338, 0x22ed),
+    (0x3046, 0x3099, 0x3094), (0x304b, 0x3099, 0x304c), (0x304d, 0x3099, 0x304e), (0x304f, 0x3099, 0x3050), (0x3051, 0x3099, 0x3052),
+    (0x3053, 0x3099, 0x3054), (0x3055, 0x3099, 0x3056), (0x3057, 0x3099, 0x3058), (0x3059, 0x3099, 0x305a), (0x305b, 0x3099, 0x305c),
+    (0x305d, 0x3099, 0x305e), (0x305f, 0x3099, 0x3060), (0x3061, 0x3099, 0x3062), (0x3064, 0x3099, 0x3065), (0x3066, 0x3099, 0x3067),
+    (0x3068, 0x3099, 0x3069), (0x306f, 0x3099, 0x3070), (0x306f, 0x309a, 0x3071), (0x3072, 0x3099, 0x3073), (0x3072, 0x309a, 0x3074),
+    (0x3075, 0x3099, 0x3076), (0x3075, 0x309a, 0x3077), (0x3078, 0x3099, 0x3079), (0x3078, 0x309a, 0x307a), (0x307b, 0x3099, 0x307c),
+    (0x307b, 0x309a, 0x307d), (0x309d, 0x3099, 0x309e), (0x30a6, 0x3099, 0x30f4), (0x30ab, 0x3099, 0x30ac), (0x30ad, 0x3099, 0x30ae),
+    (0x30af, 0x3099, 0x30b0), (0x30b1, 0x3099, 0x30b2), (0x30b3, 0x3099, 0x30b4), (0x30b5, 0x3099, 0x30b6), (0x30b7, 0x3099, 0x30b8),
+    (0x30b9, 0x3099, 0x30ba), (0x30bb, 0x3099, 0x30bc), (0x30bd, 0x3099, 0x30be), (0x30bf, 0x3099, 0x30c0), (0x30c1, 0x3099, 0x30c2),
+    (0x30c4, 0x3099, 0x30c5), (0x30c6, 0x3099, 0x30c7), (0x30c8, 0x3099, 0x30c9), (0x30cf, 0x3099, 0x30d0), (0x30cf, 0x309a, 0x30d1),
+    (0x30d2, 0x3099, 0x30d3), (0x30d2, 0x309a, 0x30d4), (0x30d5, 0x3099, 0x30d6), (0x30d5, 0x309a, 0x30d7), (0x30d8, 0x3099, 0x30d9),
+    (0x30d8, 0x309a, 0x30da), (0x30db, 0x3099, 0x30dc), (0x30db, 0x309a, 0x30dd), (0x30ef, 0x3099, 0x30f7), (0x30f0, 0x3099, 0x30f8),
+    (0x30f1, 0x3099, 0x30f9), (0x30f2, 0x3099, 0x30fa), (0x30fd, 0x3099, 0x30fe), (0x11099, 0x110ba, 0x1109a), (0x1109b, 0x110ba, 0x1109c),
+    (0x110a5, 0x110ba, 0x110ab), (0x11131, 0x11127, 0x1112e), (0x11132, 0x11127, 0x1112f), (0x11347, 0x1133e, 0x1134b), (0x11347, 0x11357, 0x1134c),
+    (0x114b9, 0x114b0, 0x114bc), (0x114b9, 0x114ba, 0x114bb), (0x114b9, 0x114bd, 0x114be), (0x115b8, 0x115af, 0x115ba), (0x115b9, 0x115af, 0x115bb),
+    (0x11935, 0x11930, 0x11938),
+];
+
+/// Grapheme cluster break classes of the characters other than CR, LF, Hangul jamos & emojis, approximated from their
+/// general categories, by ranges of code points
+pub(crate) const GRAPHEME_CLASSES: &[(u32, u32, GraphemeClass)] = &[
+    (0x0, 0x9, GraphemeClass::Control), (0xb, 0xc, GraphemeClass::Control), (0xe, 0x1f, GraphemeClass::Control), (0x7f, 0x9f, GraphemeClass::Control),
+    (0xad, 0xad, GraphemeClass::Control), (0x300, 0x36f, GraphemeClass::Extend), (0x483, 0x489, GraphemeClass::Extend), (0x591, 0x5bd, GraphemeClass::Extend),
+    (0x5bf, 0x5bf, GraphemeClass::Extend), (0x5c1, 0x5c2, GraphemeClass::Extend), (0x5c4, 0x5c5, GraphemeClass::Extend), (0x5c7, 0x5c7, GraphemeClass::Extend),
+    (0x600, 0x605, GraphemeClass::Prepend), (0x610, 0x61a, GraphemeClass::Extend), (0x61c, 0x61c, GraphemeClass::Control), (0x64b, 0x65f, GraphemeClass::Extend),
+    (0x670, 0x670, GraphemeClass::Extend), (0x6d6, 0x6dc, GraphemeClass::Extend), (0x6dd, 0x6dd, GraphemeClass::Prepend), (0x6df, 0x6e4, GraphemeClass::Extend),
+    (0x6e7, 0x6e8, GraphemeClass::Extend), (0x6ea, 0x6ed, GraphemeClass::Extend), (0x70f, 0x70f, GraphemeClass::Prepend), (0x711, 0x711, GraphemeClass::Extend),
+    (0x730, 0x74a, GraphemeClass::Extend), (0x7a6, 0x7b0, GraphemeClass::Extend), (0x7eb, 0x7f3, GraphemeClass::Extend), (0x7fd, 0x7fd, GraphemeClass::Extend),
+    (0x816, 0x819, GraphemeClass::Extend), (0x81b, 0x823, GraphemeClass::Extend), (0x825, 0x827, GraphemeClass::Extend), (0x829, 0x82d, GraphemeClass::Extend),
+    (0x859, 0x85b, GraphemeClass::Extend), (0x890, 0x891, GraphemeClass::Control), (0x898, 0x89f, GraphemeClass::Extend), (0x8ca, 0x8e1, GraphemeClass::Extend),
+    (0x8e2, 0x8e2, GraphemeClass::Prepend), (0x8e3, 0x902, GraphemeClass::Extend), (0x903, 0x903, GraphemeClass::SpacingMark), (0x93a, 0x93a, GraphemeClass::Extend),
+    (0x93b, 0x93b, GraphemeClass::SpacingMark), (0x93c, 0x93c, GraphemeClass::Extend), (0x93e, 0x940, GraphemeClass::SpacingMark), (0x941, 0x948, GraphemeClass::Extend),
+    (0x949, 0x94c, GraphemeClass::SpacingMark), (0x94d, 0x94d, GraphemeClass::Extend), (0x94e, 0x94f, GraphemeClass::SpacingMark), (0x951, 0x957, GraphemeClass::Extend),
+    (0x962, 0x963, GraphemeClass::Extend), (0x981, 0x981, GraphemeClass::Extend), (0x982, 0x983, GraphemeClass::SpacingMark), (0x9bc, 0x9bc, GraphemeClass::Extend),
+    (0x9be, 0x9c0, GraphemeClass::SpacingMark), (0x9c1, 0x9c4, GraphemeClass::Extend), (0x9c7, 0x9c8, GraphemeClass::SpacingMark), (0x9cb, 0x9cc, GraphemeClass::SpacingMark),
+    (0x9cd, 0x9cd, GraphemeClass::Extend), (0x9d7, 0x9d7, GraphemeClass::SpacingMark), (0x9e2, 0x9e3, GraphemeClass::Extend), (0x9fe, 0x9fe, GraphemeClass::Extend),
+    (0xa01, 0xa02, GraphemeClass::Extend), (0xa03, 0xa03, GraphemeClass::SpacingMark), (0xa3c, 0xa3c, GraphemeClass::Extend), (0xa3e, 0xa40, GraphemeClass::SpacingMark),
+    (0xa41, 0xa42, GraphemeClass::Extend), (0xa47, 0xa48, GraphemeClass::Extend), (0xa4b, 0xa4d, GraphemeClass::Extend), (0xa51, 0xa51, GraphemeClass::Extend),
+    (0xa70, 0xa71, GraphemeClass::Extend), (0xa75, 0xa75, GraphemeClass::Extend), (0xa81, 0xa82, GraphemeClass::Extend), (0xa83, 0xa83, GraphemeClass::SpacingMark),
+    (0xabc, 0xabc, GraphemeClass::Extend), (0xabe, 0xac0, GraphemeClass::SpacingMark), (0xac1, 0xac5, GraphemeClass::Extend), (0xac7, 0xac8, GraphemeClass::Extend),
+    (0xac9, 0xac9, GraphemeClass::SpacingMark), (0xacb, 0xacc, GraphemeClass::SpacingMark), (0xacd, 0xacd, GraphemeClass::Extend), (0xae2, 0xae3, GraphemeClass::Extend),
+    (0xafa, 0xaff, GraphemeClass::Extend), (0xb01, 0xb01, GraphemeClass::Extend), (0xb02, 0xb03, GraphemeClass::SpacingMark), (0xb3c, 0xb3c, GraphemeClass::Extend),
+    (0xb3e, 0xb3e, GraphemeClass::SpacingMark), (0xb3f, 0xb3f, GraphemeClass::Extend), (0xb40, 0xb40, GraphemeClass::SpacingMark), (0xb41, 0xb44, GraphemeClass::Extend),
+    (0xb47, 0xb48, GraphemeClass::SpacingMark), (0xb4b, 0xb4c, GraphemeClass::SpacingMark), (0xb4d, 0xb4d, GraphemeClass::Extend), (0xb55, 0xb56, GraphemeClass::Extend),
+    (0xb57, 0xb57, GraphemeClass::SpacingMark), (0xb62, 0xb63, GraphemeClass::Extend), (0xb82, 0xb82, GraphemeClass::Extend), (0xbbe, 0xbbf, GraphemeClass::SpacingMark),
+    (0xbc0, 0xbc0, GraphemeClass::Extend), (0xbc1, 0xbc2, GraphemeClass::SpacingMark), (0xbc6, 0xbc8, GraphemeClass::SpacingMark), (0xbca, 0xbcc, GraphemeClass::SpacingMark),
+    (0xbcd, 0xbcd, GraphemeClass::Extend), (0xbd7, 0xbd7, GraphemeClass::SpacingMark), (0xc00, 0xc00, GraphemeClass::Extend), (0xc01, 0xc03, GraphemeClass::SpacingMark),
+    (0xc04, 0xc04, GraphemeClass::Extend), (0xc3c, 0xc3c, GraphemeClass::Extend), (0xc3e, 0xc40, GraphemeClass::Extend), (0xc41, 0xc44, GraphemeClass::SpacingMark),
+    (0xc46, 0xc48, GraphemeClass::Extend), (0xc4a, 0xc4d, GraphemeClass::Extend), (0xc55, 0xc56, GraphemeClass::Extend), (0xc62, 0xc63, GraphemeClass::Extend),
+    (0xc81, 0xc81, GraphemeClass::Extend), (0xc82, 0xc83, GraphemeClass::SpacingMark), (0xcbc, 0xcbc, GraphemeClass::Extend), (0xcbe, 0xcbe, GraphemeClass::SpacingMark),
+    (0xcbf, 0xcbf, GraphemeClass::Extend), (0xcc0, 0xcc4, GraphemeClass::SpacingMark), (0xcc6, 0xcc6, GraphemeClass::Extend), (0xcc7, 0xcc8, GraphemeClass::SpacingMark),
+    (0xcca, 0xccb, GraphemeClass::SpacingMark), (0xccc, 0xccd, GraphemeClass::Extend), (0xcd5, 0xcd6, GraphemeClass::SpacingMark), (0xce2, 0xce3, GraphemeClass::Extend),
+    (0xd00, 0xd01, GraphemeClass::Extend), (0xd02, 0xd03, GraphemeClass::SpacingMark), (0xd3b, 0xd3c, GraphemeClass::Extend), (0xd3e, 0xd40, GraphemeClass::SpacingMark),
+    (0xd41, 0xd44, GraphemeClass::Extend), (0xd46, 0xd48, GraphemeClass::SpacingMark), (0xd4a, 0xd4c, GraphemeClass::SpacingMark), (0xd4d, 0xd4d, GraphemeClass::Extend),
+    (0xd57, 0xd57, GraphemeClass::SpacingMark), (0xd62, 0xd63, GraphemeClass::Extend), (0xd81, 0xd81, GraphemeClass::Extend), (0xd82, 0xd83, GraphemeClass::SpacingMark),
+    (0xdca, 0xdca, GraphemeClass::Extend), (0xdcf, 0xdd1, GraphemeClass::SpacingMark), (0xdd2, 0xdd4, GraphemeClass::Extend), (0xdd6, 0xdd6, GraphemeClass::Extend),
+    (0xdd8, 0xddf, GraphemeClass::SpacingMark), (0xdf2, 0xdf3, GraphemeClass::SpacingMark), (0xe31, 0xe31, GraphemeClass::Extend), (0xe34, 0xe3a, GraphemeClass::Extend),
+    (0xe47, 0xe4e, GraphemeClass::Extend), (0xeb1, 0xeb1, GraphemeClass::Extend), (0xeb4, 0xebc, GraphemeClass::Extend), (0xec8, 0xecd, GraphemeClass::Extend),
+    (0xf18, 0xf19, GraphemeClass::Extend), (0xf35, 0xf35, GraphemeClass::Extend), (0xf37, 0xf37, GraphemeClass::Extend), (0xf39, 0xf39, GraphemeClass::Extend),
+    (0xf3e, 0xf3f, GraphemeClass::SpacingMark), (0xf71, 0xf7e, GraphemeClass::Extend), (0xf7f, 0xf7f, GraphemeClass::SpacingMark), (0xf80, 0xf84, GraphemeClass::Extend),
+    (0xf86, 0xf87, GraphemeClass::Extend), (0xf8d, 0xf97, GraphemeClass::Extend), (0xf99, 0xfbc, GraphemeClass::Extend), (0xfc6, 0xfc6, GraphemeClass::Extend),
+    (0x102b, 0x102c, GraphemeClass::SpacingMark), (0x102d, 0x1030, GraphemeClass::Extend), (0x1031, 0x1031, GraphemeClass::SpacingMark), (0x1032, 0x1037, GraphemeClass::Extend),
+    (0x1038, 0x1038, GraphemeClass::SpacingMark), (0x1039, 0x103a, GraphemeClass::Extend), (0x103b, 0x103c, GraphemeClass::SpacingMark), (0x103d, 0x103e, GraphemeClass::Extend),
+    (0x1056, 0x1057, GraphemeClass::SpacingMark), (0x1058, 0x1059, GraphemeClass::Extend), (0x105e, 0x1060, GraphemeClass::Extend), (0x1062, 0x1064, GraphemeClass::SpacingMark),
+    (0x1067, 0x106d, GraphemeClass::SpacingMark), (0x1071, 0x1074, GraphemeClass::Extend), (0x1082, 0x1082, GraphemeClass::Extend), (0x1083, 0x1084, GraphemeClass::SpacingMark),
+    (0x1085, 0x1086, GraphemeClass::Extend), (0x1087, 0x108c, GraphemeClass::SpacingMark), (0x108d, 0x108d, GraphemeClass::Extend), (0x108f, 0x108f, GraphemeClass::SpacingMark),
+    (0x109a, 0x109c, GraphemeClass::SpacingMark), (0x109d, 0x109d, GraphemeClass::Extend), (0x135d, 0x135f, GraphemeClass::Extend), (0x1712, 0x1714, GraphemeClass::Extend),
+    (0x1715, 0x1715, GraphemeClass::SpacingMark), (0x1732, 0x1733, GraphemeClass::Extend), (0x1734, 0x1734, GraphemeClass::SpacingMark), (0x1752, 0x1753, GraphemeClass::Extend),
+    (0x1772, 0x1773, GraphemeClass::Extend), (0x17b4, 0x17b5, GraphemeClass::Extend), (0x17b6, 0x17b6, GraphemeClass::SpacingMark), (0x17b7, 0x17bd, GraphemeClass::Extend),
+    (0x17be, 0x17c5, GraphemeClass::SpacingMark), (0x17c6, 0x17c6, GraphemeClass::Extend), (0x17c7, 0x17c8, GraphemeClass::SpacingMark), (0x17c9, 0x17d3, GraphemeClass::Extend),
+    (0x17dd, 0x17dd, GraphemeClass::Extend), (0x180b, 0x180d, GraphemeClass::Extend), (0x180e, 0x180e, GraphemeClass::Control), (0x180f, 0x180f, GraphemeClass::Extend),
+    (0x1885, 0x1886, GraphemeClass::Extend), (0x18a9, 0x18a9, GraphemeClass::Extend), (0x1920, 0x1922, GraphemeClass::Extend), (0x1923, 0x1926, GraphemeClass::SpacingMark),
+    (0x1927, 0x1928, GraphemeClass::Extend), (0x1929, 0x192b, GraphemeClass::SpacingMark), (0x1930, 0x1931, GraphemeClass::SpacingMark), (0x1932, 0x1932, GraphemeClass::Extend),
+    (0x1933, 0x1938, GraphemeClass::SpacingMark), (0x1939, 0x193b, GraphemeClass::Extend), (0x1a17, 0x1a18, GraphemeClass::Extend), (0x1a19, 0x1a1a, GraphemeClass::SpacingMark),
+    (0x1a1b, 0x1a1b, GraphemeClass::Extend), (0x1a55, 0x1a55, GraphemeClass::SpacingMark), (0x1a56, 0x1a56, GraphemeClass::Extend), (0x1a57, 0x1a57, GraphemeClass::SpacingMark),
+    (0x1a58, 0x1a5e, GraphemeClass::Extend), (0x1a60, 0x1a60, GraphemeClass::Extend), (0x1a61, 0x1a61, GraphemeClass::SpacingMark), (0x1a62, 0x1a62, GraphemeClass::Extend),
+    (0x1a63, 0x1a64, GraphemeClass::SpacingMark), (0x1a65, 0x1a6c, GraphemeClass::Extend), (0x1a6d, 0x1a72, GraphemeClass::SpacingMark), (0x1a73, 0x1a7c, GraphemeClass::Extend),
+    (0x1a7f, 0x1a7f, GraphemeClass::Extend), (0x1ab0, 0x1ace, GraphemeClass::Extend), (0x1b00, 0x1b03, GraphemeClass::Extend), (0x1b04, 0x1b04, GraphemeClass::SpacingMark),
+    (0x1b34, 0x1b34, GraphemeClass::Extend), (0x1b35, 0x1b35, GraphemeClass::SpacingMark), (0x1b36, 0x1b3a, GraphemeClass::Extend), (0x1b3b, 0x1b3b, GraphemeClass::SpacingMark),
+    (0x1b3c, 0x1b3c, GraphemeClass::Extend), (0x1b3d, 0x1b41, GraphemeClass::SpacingMark), (0x1b42, 0x1b42, GraphemeClass::Extend), (0x1b43, 0x1b44, GraphemeClass::SpacingMark),
+    (0x1b6b, 0x1b73, GraphemeClass::Extend), (0x1b80, 0x1b81, GraphemeClass::Extend), (0x1b82, 0x1b82, GraphemeClass::SpacingMark), (0x1ba1, 0x1ba1, GraphemeClass::SpacingMark),
+    (0x1ba2, 0x1ba5, GraphemeClass::Extend), (0x1ba6, 0x1ba7, GraphemeClass::SpacingMark), (0x1ba8, 0x1ba9, GraphemeClass::Extend), (0x1baa, 0x1baa, GraphemeClass::SpacingMark),
+    (0x1bab, 0x1bad, GraphemeClass::Extend), (0x1be6, 0x1be6, GraphemeClass::Extend), (0x1be7, 0x1be7, GraphemeClass::SpacingMark), (0x1be8, 0x1be9, GraphemeClass::Extend),
+    (0x1bea, 0x1bec, GraphemeClass::SpacingMark), (0x1bed, 0x1bed, GraphemeClass::Extend), (0x1bee, 0x1bee, GraphemeClass::SpacingMark), (0x1bef, 0x1bf1, GraphemeClass::Extend),
+    (0x1bf2, 0x1bf3, GraphemeClass::SpacingMark), (0x1c24, 0x1c2b, GraphemeClass::SpacingMark), (0x1c2c, 0x1c33, GraphemeClass::Extend), (0x1c34, 0x1c35, GraphemeClass::SpacingMark),
+    (0x1c36, 0x1c37, GraphemeClass::Extend), (0x1cd0, 0x1cd2, GraphemeClass::Extend), (0x1cd4, 0x1ce0, GraphemeClass::Extend), (0x1ce1, 0x1ce1, GraphemeClass::SpacingMark),
+    (0x1ce2, 0x1ce8, GraphemeClass::Extend), (0x1ced, 0x1ced, GraphemeClass::Extend), (0x1cf4, 0x1cf4, GraphemeClass::Extend), (0x1cf7, 0x1cf7, GraphemeClass::SpacingMark),
+    (0x1cf8, 0x1cf9, GraphemeClass::Extend), (0x1dc0, 0x1dff, GraphemeClass::Extend), (0x200b, 0x200b, GraphemeClass::Control), (0x200c, 0x200c, GraphemeClass::Extend),
+    (0x200d, 0x200d, GraphemeClass::Zwj), (0x200e, 0x200f, GraphemeClass::Control), (0x2028, 0x202e, GraphemeClass::Control), (0x2060, 0x2064, GraphemeClass::Control),
+    (0x2066, 0x206f, GraphemeClass::Control), (0x20d0, 0x20f0, GraphemeClass::Extend), (0x2cef, 0x2cf1, GraphemeClass::Extend), (0x2d7f, 0x2d7f, GraphemeClass::Extend),
+    (0x2de0, 0x2dff, GraphemeClass::Extend), (0x302a, 0x302d, GraphemeClass::Extend), (0x302e, 0x302f, GraphemeClass::SpacingMark), (0x3099, 0x309a, GraphemeClass::Extend),
+    (0xa66f, 0xa672, GraphemeClass::Extend), (0xa674, 0xa67d, GraphemeClass::Extend), (0xa69e, 0xa69f, GraphemeClass::Extend), (0xa6f0, 0xa6f1, GraphemeClass::Extend),
+    (0xa802, 0xa802, GraphemeClass::Extend), (0xa806, 0xa806, GraphemeClass::Extend), (0xa80b, 0xa80b, GraphemeClass::Extend), (0xa823, 0xa824, GraphemeClass::SpacingMark),
+    (0xa825, 0xa826, GraphemeClass::Extend), (0xa827, 0xa827, GraphemeClass::SpacingMark), (0xa82c, 0xa82c, GraphemeClass::Extend), (0xa880, 0xa881, GraphemeClass::SpacingMark),
+    (0xa8b4, 0xa8c3, GraphemeClass::SpacingMark), (0xa8c4, 0xa8c5, GraphemeClass::Extend), (0xa8e0, 0xa8f1, GraphemeClass::Extend), (0xa8ff, 0xa8ff, GraphemeClass::Extend),
+    (0xa926, 0xa92d, GraphemeClass::Extend), (0xa947, 0xa951, GraphemeClass::Extend), (0xa952, 0xa953, GraphemeClass::SpacingMark), (0xa980, 0xa982, GraphemeClass::Extend),
+    (0xa983, 0xa983, GraphemeClass::SpacingMark), (0xa9b3, 0xa9b3, GraphemeClass::Extend), (0xa9b4, 0xa9b5, GraphemeClass::SpacingMark), (0xa9b6, 0xa9b9, GraphemeClass::Extend),
+    (0xa9ba, 0xa9bb, GraphemeClass::SpacingMark), (0xa9bc, 0xa9bd, GraphemeClass::Extend), (0xa9be, 0xa9c0, GraphemeClass::SpacingMark), (0xa9e5, 0xa9e5, GraphemeClass::Extend),
+    (0xaa29, 0xaa2e, GraphemeClass::Extend), (0xaa2f, 0xaa30, GraphemeClass::SpacingMark), (0xaa31, 0xaa32, GraphemeClass::Extend), (0xaa33, 0xaa34, GraphemeClass::SpacingMark),
+    (0xaa35, 0xaa36, GraphemeClass::Extend), (0xaa43, 0xaa43, GraphemeClass::Extend), (0xaa4c, 0xaa4c, GraphemeClass::Extend), (0xaa4d, 0xaa4d, GraphemeClass::SpacingMark),
+    (0xaa7b, 0xaa7b, GraphemeClass::SpacingMark), (0xaa7c, 0xaa7c, GraphemeClass::Extend), (0xaa7d, 0xaa7d, GraphemeClass::SpacingMark), (0xaab0, 0xaab0, GraphemeClass::Extend),
+    (0xaab2, 0xaab4, GraphemeClass::Extend), (0xaab7, 0xaab8, GraphemeClass::Extend), (0xaabe, 0xaabf, GraphemeClass::Extend), (0xaac1, 0xaac1, GraphemeClass::Extend),
+    (0xaaeb, 0xaaeb, GraphemeClass::SpacingMark), (0xaaec, 0xaaed, GraphemeClass::Extend), (0xaaee, 0xaaef, GraphemeClass::SpacingMark), (0xaaf5, 0xaaf5, GraphemeClass::SpacingMark),
+    (0xaaf6, 0xaaf6, GraphemeClass::Extend), (0xabe3, 0xabe4, GraphemeClass::SpacingMark), (0xabe5, 0xabe5, GraphemeClass::Extend), (0xabe6, 0xabe7, GraphemeClass::SpacingMark),
+    (0xabe8, 0xabe8, GraphemeClass::Extend), (0xabe9, 0xabea, GraphemeClass::SpacingMark), (0xabec, 0xabec, GraphemeClass::SpacingMark), (0xabed, 0xabed, GraphemeClass::Extend),
+    (0xfb1e, 0xfb1e, GraphemeClass::Extend), (0xfe00, 0xfe0f, GraphemeClass::Extend), (0xfe20, 0xfe2f, GraphemeClass::Extend), (0xfeff, 0xfeff, GraphemeClass::Control),
+    (0xff9e, 0xff9f, GraphemeClass::Extend), (0xfff9, 0xfffb, GraphemeClass::Control), (0x101fd, 0x101fd, GraphemeClass::Extend), (0x102e0, 0x102e0, GraphemeClass::Extend),
+    (0x10376, 0x1037a, GraphemeClass::Extend), (0x10a01, 0x10a03, GraphemeClass::Extend), (0x10a05, 0x10a06, GraphemeClass::Extend), (0x10a0c, 0x10a0f, GraphemeClass::Extend),
+    (0x10a38, 0x10a3a, GraphemeClass::Extend), (0x10a3f, 0x10a3f, GraphemeClass::Extend), (0x10ae5, 0x10ae6, GraphemeClass::Extend), (0x10d24, 0x10d27, GraphemeClass::Extend),
+    (0x10eab, 0x10eac, GraphemeClass::Extend), (0x10f46, 0x10f50, GraphemeClass::Extend), (0x10f82, 0x10f85, GraphemeClass::Extend), (0x11000, 0x11000, GraphemeClass::SpacingMark),
+    (0x11001, 0x11001, GraphemeClass::Extend), (0x11002, 0x11002, GraphemeClass::SpacingMark), (0x11038, 0x11046, GraphemeClass::Extend), (0x11070, 0x11070, GraphemeClass::Extend),
+    (0x11073, 0x11074, GraphemeClass::Extend), (0x1107f, 0x11081, GraphemeClass::Extend), (0x11082, 0x11082, GraphemeClass::SpacingMark), (0x110b0, 0x110b2, GraphemeClass::SpacingMark),
+    (0x110b3, 0x110b6, GraphemeClass::Extend), (0x110b7, 0x110b8, GraphemeClass::SpacingMark), (0x110b9, 0x110ba, GraphemeClass::Extend), (0x110bd, 0x110bd, GraphemeClass::Prepend),
+    (0x110c2, 0x110c2, GraphemeClass::Extend), (0x110cd, 0x110cd, GraphemeClass::Prepend), (0x11100, 0x11102, GraphemeClass::Extend), (0x11127, 0x1112b, GraphemeClass::Extend),
+    (0x1112c, 0x1112c, GraphemeClass::SpacingMark), (0x1112d, 0x11134, GraphemeClass::Extend), (0x11145, 0x11146, GraphemeClass::SpacingMark), (0x11173, 0x11173, GraphemeClass::Extend),
+    (0x11180, 0x11181, GraphemeClass::Extend), (0x11182, 0x11182, GraphemeClass::SpacingMark), (0x111b3, 0x111b5, GraphemeClass::SpacingMark), (0x111b6, 0x111be, GraphemeClass::Extend),
+    (0x111bf, 0x111c0, GraphemeClass::SpacingMark), (0x111c9, 0x111cc, GraphemeClass::Extend), (0x111ce, 0x111ce, GraphemeClass::SpacingMark), (0x111cf, 0x111cf, GraphemeClass::Extend),
+    (0x1122c, 0x1122e, GraphemeClass::SpacingMark), (0x1122f, 0x11231, GraphemeClass::Extend), (0x11232, 0x11233, GraphemeClass::SpacingMark), (0x11234, 0x11234, GraphemeClass::Extend),
+    (0x11235, 0x11235, GraphemeClass::SpacingMark), (0x11236, 0x11237, GraphemeClass::Extend), (0x1123e, 0x1123e, GraphemeClass::Extend), (0x112df, 0x112df, GraphemeClass::Extend),
+    (0x112e0, 0x112e2, GraphemeClass::SpacingMark), (0x112e3, 0x112ea, GraphemeClass::Extend), (0x11300, 0x11301, GraphemeClass::Extend), (0x11302, 0x11303, GraphemeClass::SpacingMark),
+    (0x1133b, 0x1133c, GraphemeClass::Extend), (0x1133e, 0x1133f, GraphemeClass::SpacingMark), (0x11340, 0x11340, GraphemeClass::Extend), (0x11341, 0x11344, GraphemeClass::SpacingMark),
+    (0x11347, 0x11348, GraphemeClass::SpacingMark), (0x1134b, 0x1134d, GraphemeClass::SpacingMark), (0x11357, 0x11357, GraphemeClass::SpacingMark), (0x11362, 0x11363, GraphemeClass::SpacingMark),
+    (0x11366, 0x1136c, GraphemeClass::Extend), (0x11370, 0x11374, GraphemeClass::Extend), (0x11435, 0x11437, GraphemeClass::SpacingMark), (0x11438, 0x1143f, GraphemeClass::Extend),
+    (0x11440, 0x11441, GraphemeClass::SpacingMark), (0x11442, 0x11444, GraphemeClass::Extend), (0x11445, 0x11445, GraphemeClass::SpacingMark), (0x11446, 0x11446, GraphemeClass::Extend),
+    (0x1145e, 0x1145e, GraphemeClass::Extend), (0x114b0, 0x114b2, GraphemeClass::SpacingMark), (0x114b3, 0x114b8, GraphemeClass::Extend), (0x114b9, 0x114b9, GraphemeClass::SpacingMark),
+    (0x114ba, 0x114ba, GraphemeClass::Extend), (0x114bb, 0x114be, GraphemeClass::SpacingMark), (0x114bf, 0x114c0, GraphemeClass::Extend), (0x114c1, 0x114c1, GraphemeClass::SpacingMark),
+    (0x114c2, 0x114c3, GraphemeClass::Extend), (0x115af, 0x115b1, GraphemeClass::SpacingMark), (0x115b2, 0x115b5, GraphemeClass::Extend), (0x115b8, 0x115bb, GraphemeClass::SpacingMark),
+    (0x115bc, 0x115bd, GraphemeClass::Extend), (0x115be, 0x115be, GraphemeClass::SpacingMark), (0x115bf, 0x115c0, GraphemeClass::Extend), (0x115dc, 0x115dd, GraphemeClass::Extend),
+    (0x11630, 0x11632, GraphemeClass::SpacingMark), (0x11633, 0x1163a, GraphemeClass::Extend), (0x1163b, 0x1163c, GraphemeClass::SpacingMark), (0x1163d, 0x1163d, GraphemeClass::Extend),
+    (0x1163e, 0x1163e, GraphemeClass::SpacingMark), (0x1163f, 0x11640, GraphemeClass::Extend), (0x116ab, 0x116ab, GraphemeClass::Extend), (0x116ac, 0x116ac, GraphemeClass::SpacingMark),
+    (0x116ad, 0x116ad, GraphemeClass::Extend), (0x116ae, 0x116af, GraphemeClass::SpacingMark), (0x116b0, 0x116b5, GraphemeClass::Extend), (0x116b6, 0x116b6, GraphemeClass::SpacingMark),
+    (0x116b7, 0x116b7, GraphemeClass::Extend), (0x1171d, 0x1171f, GraphemeClass::Extend), (0x11720, 0x11721, GraphemeClass::SpacingMark), (0x11722, 0x11725, GraphemeClass::Extend),
+    (0x11726, 0x11726, GraphemeClass::SpacingMark), (0x11727, 0x1172b, GraphemeClass::Extend), (0x1182c, 0x1182e, GraphemeClass::SpacingMark), (0x1182f, 0x11837, GraphemeClass::Extend),
+    (0x11838, 0x11838, GraphemeClass::SpacingMark), (0x11839, 0x1183a, GraphemeClass::Extend), (0x11930, 0x11935, GraphemeClass::SpacingMark), (0x11937, 0x11938, GraphemeClass::SpacingMark),
+    (0x1193b, 0x1193c, GraphemeClass::Extend), (0x1193d, 0x1193d, GraphemeClass::SpacingMark), (0x1193e, 0x1193e, GraphemeClass::Extend), (0x11940, 0x11940, GraphemeClass::SpacingMark),
+    (0x11942, 0x11942, GraphemeClass::SpacingMark), (0x11943, 0x11943, GraphemeClass::Extend), (0x119d1, 0x119d3, GraphemeClass::SpacingMark), (0x119d4, 0x119d7, GraphemeClass::Extend),
+    (0x119da, 0x119db, GraphemeClass::Extend), (0x119dc, 0x119df, GraphemeClass::SpacingMark), (0x119e0, 0x119e0, GraphemeClass::Extend), (0x119e4, 0x119e4, GraphemeClass::SpacingMark),
+    (0x11a01, 0x11a0a, GraphemeClass::Extend), (0x11a33, 0x11a38, GraphemeClass::Extend), (0x11a39, 0x11a39, GraphemeClass::SpacingMark), (0x11a3b, 0x11a3e, GraphemeClass::Extend),
+    (0x11a47, 0x11a47, GraphemeClass::Extend), (0x11a51, 0x11a56, GraphemeClass::Extend), (0x11a57, 0x11a58, GraphemeClass::SpacingMark), (0x11a59, 0x11a5b, GraphemeClass::Extend),
+    (0x11a8a, 0x11a96, GraphemeClass::Extend), (0x11a97, 0x11a97, GraphemeClass::SpacingMark), (0x11a98, 0x11a99, GraphemeClass::Extend), (0x11c2f, 0x11c2f, GraphemeClass::SpacingMark),
+    (0x11c30, 0x11c36, GraphemeClass::Extend), (0x11c38, 0x11c3d, GraphemeClass::Extend), (0x11c3e, 0x11c3e, GraphemeClass::SpacingMark), (0x11c3f, 0x11c3f, GraphemeClass::Extend),
+    (0x11c92, 0x11ca7, GraphemeClass::Extend), (0x11ca9, 0x11ca9, GraphemeClass::SpacingMark), (0x11caa, 0x11cb0, GraphemeClass::Extend), (0x11cb1, 0x11cb1, GraphemeClass::SpacingMark),
+    (0x11cb2, 0x11cb3, GraphemeClass::Extend), (0x11cb4, 0x11cb4, GraphemeClass::SpacingMark), (0x11cb5, 0x11cb6, GraphemeClass::Extend), (0x11d31, 0x11d36, GraphemeClass::Extend),
+    (0x11d3a, 0x11d3a, GraphemeClass::Extend), (0x11d3c, 0x11d3d, GraphemeClass::Extend), (0x11d3f, 0x11d45, GraphemeClass::Extend), (0x11d47, 0x11d47, GraphemeClass::Extend),
+    (0x11d8a, 0x11d8e, GraphemeClass::SpacingMark), (0x11d90, 0x11d91, GraphemeClass::Extend), (0x11d93, 0x11d94, GraphemeClass::SpacingMark), (0x11d95, 0x11d95, GraphemeClass::Extend),
+    (0x11d96, 0x11d96, GraphemeClass::SpacingMark), (0x11d97, 0x11d97, GraphemeClass::Extend), (0x11ef3, 0x11ef4, GraphemeClass::Extend), (0x11ef5, 0x11ef6, GraphemeClass::SpacingMark),
+    (0x13430, 0x13438, GraphemeClass::Control), (0x16af0, 0x16af4, GraphemeClass::Extend), (0x16b30, 0x16b36, GraphemeClass::Extend), (0x16f4f, 0x16f4f, GraphemeClass::Extend),
+    (0x16f51, 0x16f87, GraphemeClass::SpacingMark), (0x16f8f, 0x16f92, GraphemeClass::Extend), (0x16fe4, 0x16fe4, GraphemeClass::Extend), (0x16ff0, 0x16ff1, GraphemeClass::SpacingMark),
+    (0x1bc9d, 0x1bc9e, GraphemeClass::Extend), (0x1bca0, 0x1bca3, GraphemeClass::Control), (0x1cf00, 0x1cf2d, GraphemeClass::Extend), (0x1cf30, 0x1cf46, GraphemeClass::Extend),
+    (0x1d165, 0x1d166, GraphemeClass::SpacingMark), (0x1d167, 0x1d169, GraphemeClass::Extend), (0x1d16d, 0x1d172, GraphemeClass::SpacingMark), (0x1d173, 0x1d17a, GraphemeClass::Control),
+    (0x1d17b, 0x1d182, GraphemeClass::Extend), (0x1d185, 0x1d18b, GraphemeClass::Extend), (0x1d1aa, 0x1d1ad, GraphemeClass::Extend), (0x1d242, 0x1d244, GraphemeClass::Extend),
+    (0x1da00, 0x1da36, GraphemeClass::Extend), (0x1da3b, 0x1da6c, GraphemeClass::Extend), (0x1da75, 0x1da75, GraphemeClass::Extend), (0x1da84, 0x1da84, GraphemeClass::Extend),
+    (0x1da9b, 0x1da9f, GraphemeClass::Extend), (0x1daa1, 0x1daaf, GraphemeClass::Extend), (0x1e000, 0x1e006, GraphemeClass::Extend), (0x1e008, 0x1e018, GraphemeClass::Extend),
+    (0x1e01b, 0x1e021, GraphemeClass::Extend), (0x1e023, 0x1e024, GraphemeClass::Extend), (0x1e026, 0x1e02a, GraphemeClass::Extend), (0x1e130, 0x1e136, GraphemeClass::Extend),
+    (0x1e2ae, 0x1e2ae, GraphemeClass::Extend), (0x1e2ec, 0x1e2ef, GraphemeClass::Extend), (0x1e8d0, 0x1e8d6, GraphemeClass::Extend), (0x1e944, 0x1e94a, GraphemeClass::Extend),
+    (0x1f3fb, 0x1f3ff, GraphemeClass::Extend), (0xe0001, 0xe0001, GraphemeClass::Control), (0xe0020, 0xe007f, GraphemeClass::Extend), (0xe0100, 0xe01ef, GraphemeClass::Extend),
+];
@@ -0,0 +1,168 @@
+//! Declarative validation of parsed form data held in the cache, before it is posted to the Kuroco form APIs.
+//! Errors are inserted into the cache (under form.errors by default), for templates to render them next to their fields.
+
+#[cfg(feature = "regex")]
+use regex::Regex;
+use serde_json::{Value, json};
+
+use crate::{DataCache, error::JsonDataCacheError};
+
+/// Default path of the validation errors, see FormValidator::errors_path
+pub const DEFAULT_ERRORS_PATH: &str = "form.errors";
+
+/// Empty values only fail Required (and RequiredWith), so that optional fields are checked only when filled
+#[derive(Debug, Clone)]
+pub enum Rule {
+    /// Not missing, null, blank or an empty array
+    Required,
+    /// Required when the other field is filled
+    RequiredWith(String),
+    /// String values must match the regex
+    #[cfg(feature = "regex")]
+    Pattern(Regex),
+    /// Count of characters of strings, or of items of arrays
+    Length { min: Option<usize>, max: Option<usize> },
+    /// Numbers, or strings parsed as numbers
+    Range { min: Option<f64>, max: Option<f64> },
+    /// Same value as the other field, like a password confirmation
+    SameAs(String),
+}
+
+impl Rule {
+    /// Identifier of the rule in errors
+    fn name(&self) -> &'static str {
+        match self {
+            Rule::Required => "required",
+            Rule::RequiredWith(_) => "required_with",
+            #[cfg(feature = "regex")]
+            Rule::Pattern(_) => "pattern",
+            Rule::Length { .. } => "length",
+            Rule::Range { .. } => "range",
+            Rule::SameAs(_) => "same_as",
+        }
+    }
+
+    /// Checks the value of a field, other fields of the form being read through get_field
+    fn is_valid<'a>(&self, value: Option<&'a Value>, get_field: impl Fn(&str) -> Option<&'a Value>) -> bool {
+        let value = value.filter(|value| !is_empty(value));
+        match (self, value) {
+            (Rule::Required, value) => value.is_some(),
+            (Rule::RequiredWith(other_field), value) => value.is_some() || get_field(other_field).is_none_or(is_empty),
+            (_, None) => true,
+            #[cfg(feature = "regex")]
+            (Rule::Pattern(regex), Some(value)) => value.as_str().is_some_and(|value| regex.is_match(value)),
+            (Rule::Length { min, max }, Some(value)) => {
+                let length = match value {
+                    Value::String(value) => value.chars().count(),
+                    Value::Array(value) => value.len(),
+                    _ => return false,
+                };
+                min.is_none_or(|min| length >= min) && max.is_none_or(|max| length <= max)
+            },
+            (Rule::Range { min, max }, Some(value)) => {
+                let number = match value {
+                    Value::Number(number) => number.as_f64(),
+                    Value::String(value) => value.trim().parse::<f64>().ok().filter(|number| number.is_finite()),
+                    _ => None,
+                };
+                number.is_some_and(|number| min.is_none_or(|min| number >= min) && max.is_none_or(|max| number <= max))
+            },
+            (Rule::SameAs(other_field), Some(value)) => get_field(other_field) == Some(value),
+        }
+    }
+}
+
+fn is_empty(value: &Value) -> bool {
+    match value {
+        Value::Null => true,
+        Value::String(value) => value.trim().is_empty(),
+        Value::Array(value) => value.is_empty(),
+        _ => false,
+    }
+}
+
+/// A failed rule
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationError {
+    pub field: String,
+    pub rule: &'static str,
+    pub message: String
+}
+
+/// Rules of the fields of a form, checked in declaration order
+#[derive(Debug, Clone)]
+pub struct FormValidator {
+    rules: Vec<(String, Rule, String)>, // Field path (relative to the form data), rule & error message
+    errors_path: String
+}
+
+impl Default for FormValidator {
+    fn default() -> Self {
+        Self {
+            rules: Vec::new(),
+            errors_path: DEFAULT_ERRORS_PATH.to_string()
+        }
+    }
+}
+
+impl FormValidator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a rule of the field (a path relative to the form data, like address.zip), failing with message
+    pub fn rule(mut self, field: &str, rule: Rule, message: &str) -> Self {
+        self.rules.push((field.to_string(), rule, message.to_string()));
+        self
+    }
+
+    /// Same as rule, compiling the pattern
+    #[cfg(feature = "regex")]
+    pub fn pattern(self, field: &str, pattern: &str, message: &str) -> Result<Self, JsonDataCacheError> {
+        let regex = Regex::new(pattern).map_err(|_| format!("Invalid regex {pattern}"))?;
+        Ok(self.rule(field, Rule::Pattern(regex), message))
+    }
+
+    pub fn errors_path(mut self, errors_path: &str) -> Self {
+        self.errors_path = errors_path.to_string();
+        self
+    }
+
+    /// Checks every rule against the form data, returning the failed ones (all of them, not only the first of each field)
+    pub fn validate(&self, data_cache: &DataCache, data_path: &str) -> Vec<ValidationError> {
+        let get_field = |field: &str| data_cache.get(&format!("{data_path}.{field}"));
+        self.rules.iter()
+            .filter(|(field, rule, _)| !rule.is_valid(get_field(field), get_field))
+            .map(|(field, rule, message)| ValidationError {
+                field: field.clone(),
+                rule: rule.name(),
+                message: message.clone()
+            })
+            .collect()
+    }
+}
+
+impl DataCache {
+    /// Validates the form data at data_path, replacing the errors of previous validations :
+    /// errors_path.{field} holds the list of {"rule", "message"} of the field, and errors_path is an empty object if the form is valid
+    /// Returns the errors
+    pub fn validate_form(&mut self, data_path: &str, validator: &FormValidator) -> Result<Vec<ValidationError>, JsonDataCacheError> {
+        let errors = validator.validate(self, data_path);
+        let mut errors_value = json!({});
+        for error in &errors {
+            // Nested fields get nested errors, like errors_path.address.zip
+            let field_errors = error.field.split('.').fold(&mut errors_value, |parent, key| {
+                if !parent.is_object() {
+                    *parent = json!({});
+                }
+                parent.as_object_mut().unwrap().entry(key).or_insert(Value::Null)
+            });
+            if !field_errors.is_array() {
+                *field_errors = json!([]);
+            }
+            field_errors.as_array_mut().unwrap().push(json!({"rule": error.rule, "message": error.message}));
+        }
+        self.try_replace(&validator.errors_path, errors_value)?;
+        Ok(errors)
+    }
+}
@@ -0,0 +1,51 @@
+//! Vary-by enforcement: the paths whose values differ between requests (the user, the geolocation...) are declared dynamic,
+//! and a render declares which of them its output depends on, like vary_by: ["user.segment", "geo.country"], for its
+//! output to be cached per combination of their values. Renders through replace_with_vary_by track what they read (see
+//! the reads module) and fail if a dynamic path outside of vary_by was read, as caching that output would serve
+//! personalized content to other users.
+
+#[cfg(feature = "replace-engine")]
+use std::io;
+
+use crate::{DataCache, path_pattern::PathPattern};
+#[cfg(feature = "replace-engine")]
+use crate::error::JsonDataCacheError;
+
+#[derive(Debug, Default)]
+pub(crate) struct DataCacheVary {
+    pub(crate) dynamic: Vec<PathPattern>,
+}
+
+impl DataCache {
+    /// Declares the paths matching pattern dynamic, their values differing between requests
+    pub fn declare_dynamic(&mut self, pattern: &str) {
+        self.vary.dynamic.push(PathPattern::from(pattern));
+    }
+
+    /// The read paths which are dynamic (or contain dynamic paths) without being covered by a path of vary_by
+    /// Example: with "user.*" dynamic & vary_by ["user.segment"], "user.name" & "user" are undeclared, "site.name" is not
+    pub fn undeclared_reads(&self, vary_by: &[&str], reads: &[String]) -> Vec<String> {
+        let vary_by: Vec<PathPattern> = vary_by.iter().map(|path| PathPattern::from(*path)).collect();
+        reads.iter()
+            .filter(|path| self.vary.dynamic.iter().any(|dynamic| dynamic.overlaps(path)))
+            .filter(|path| !vary_by.iter().any(|declared| declared.covers(path)))
+            .cloned()
+            .collect()
+    }
+
+    /// Same as replace_with_data_cache, failing if the render read dynamic paths (see declare_dynamic) not covered by
+    /// vary_by. The output is written before the check: it must not be cached (nor sent) when this fails
+    #[cfg(feature = "replace-engine")]
+    pub fn replace_with_vary_by<R, W>(&mut self, vary_by: &[&str], reader: R, writer: W) -> Result<(), JsonDataCacheError>
+    where
+        R: io::Read,
+        W: io::Write,
+    {
+        let reads = self.replace_with_read_tracking(reader, writer)?;
+        let undeclared = self.undeclared_reads(vary_by, &reads);
+        if !undeclared.is_empty() {
+            return Err(format!("Render read dynamic paths missing from vary_by: {}", undeclared.join(", ")).into());
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,66 @@
+use std::collections::HashMap;
+
+use serde_json::Value;
+
+use crate::{DataCache, error::JsonDataCacheError};
+
+/// Versions of modified paths. Each modification takes the next value of a single counter
+/// The version of a subtree is the last modification of its path or one of its descendants (subtree version),
+/// or of one of its ancestors (replacing it along with them)
+#[derive(Debug, Default)]
+pub(crate) struct DataCacheVersions {
+    counter: u64,
+    modified: HashMap<String, u64>, // Last modification of exactly this path
+    subtrees: HashMap<String, u64> // Last modification of this path or one of its descendants
+}
+
+/// Path and its ancestors, up to the root (empty path)
+fn path_and_ancestors(path: &str) -> impl Iterator<Item = &str> {
+    let mut end = Some(path.len());
+    std::iter::from_fn(move || {
+        let current = end?;
+        end = match path[..current].rfind('.') {
+            Some(dot_idx) => Some(dot_idx),
+            None if current > 0 => Some(0),
+            None => None,
+        };
+        Some(&path[..current])
+    })
+}
+
+impl DataCacheVersions {
+    pub(crate) fn bump(&mut self, path: &str) {
+        self.counter += 1;
+        self.modified.insert(path.to_string(), self.counter);
+        for subtree_path in path_and_ancestors(path) {
+            self.subtrees.insert(subtree_path.to_string(), self.counter);
+        }
+    }
+
+    fn get(&self, path: &str) -> u64 {
+        let subtree_version = self.subtrees.get(path).copied().unwrap_or_default();
+        path_and_ancestors(path)
+            .skip(1)
+            .filter_map(|ancestor| self.modified.get(ancestor).copied())
+            .fold(subtree_version, u64::max)
+    }
+}
+
+impl DataCache {
+    /// Version of the subtree at path: increases each time path, one of its descendants or one of its ancestors is modified
+    /// 0 if it has never been modified
+    pub fn version(&self, path: &str) -> u64 {
+        self.versions.get(path)
+    }
+
+    /// Inserts (see insert) only if the version of path is still expected_version, as read by the caller before computing value
+    /// Returns the new version, or an error on conflict, letting concurrent updaters reconcile instead of overwriting each other
+    pub fn insert_if_version(&mut self, path: &str, value: Value, expected_version: u64) -> Result<u64, JsonDataCacheError> {
+        let version = self.version(path);
+        if version != expected_version {
+            return Err(format!("Version conflict on {path} : expected {expected_version}, found {version}").into());
+        }
+        self.try_insert(path, value)?;
+        Ok(self.version(path))
+    }
+}
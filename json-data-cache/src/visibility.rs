@@ -0,0 +1,194 @@
+//! Role based visibility: rules set by set_visibility gate the keys matching a path pattern (see PathPattern) behind
+//! requirements over the claims of the current user, held at the claims path of the cache ("user" by default).
+//! Keys whose requirement is not met are moved out of the tree, so that get, replacements and every other read of the cache
+//! behave as if they were missing, and moved back once the claims meet it.
+//! Hidden array items are replaced by null, keeping the indexes of the other items. Inserts below a hidden key replace
+//! its hidden value.
+
+use std::{collections::HashMap, fmt};
+
+use serde_json::Value;
+
+use crate::{DataCache, computed::paths_overlap, consent::DEFAULT_CONSENT_PATH, path_pattern::PathPattern};
+
+/// Default path of the claims of the current user, see set_claims_path
+pub const DEFAULT_CLAIMS_PATH: &str = "user";
+
+/// Requirement of a visibility rule over the claims
+#[derive(Debug, Clone, PartialEq)]
+pub enum Visibility {
+    /// Any claims, like a logged in member
+    Authenticated,
+    /// One of the roles in the roles array of the claims
+    AnyRole(Vec<String>),
+    /// The claim has this value, like {"plan": "premium"}
+    Claim(String, Value),
+    /// The purpose is granted by the consent flags of the visitor (see the consent module), whatever the claims
+    Consent(String),
+}
+
+impl Visibility {
+    pub fn any_role(roles: &[&str]) -> Self {
+        Visibility::AnyRole(roles.iter().map(|role| role.to_string()).collect())
+    }
+
+    fn is_met(&self, claims: Option<&Value>, consent: Option<&Value>) -> bool {
+        let claims = claims.filter(|claims| !claims.is_null());
+        match self {
+            Visibility::Authenticated => claims.is_some(),
+            Visibility::AnyRole(roles) => claims.and_then(|claims| claims.get("roles")).and_then(Value::as_array).is_some_and(|claimed| {
+                claimed.iter().filter_map(Value::as_str).any(|claimed| roles.iter().any(|role| role == claimed))
+            }),
+            Visibility::Claim(name, value) => claims.and_then(|claims| claims.get(name)) == Some(value),
+            Visibility::Consent(purpose) => consent.and_then(|consent| consent.get(purpose)).and_then(Value::as_bool).unwrap_or(false),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub(crate) struct DataCacheVisibility {
+    rules: Vec<(String, PathPattern, Visibility)>,
+    claims_path: String,
+    pub(crate) consent_path: String,
+    hidden: HashMap<String, (String, Value)>, // Hidden values by path, with the pattern of the rule hiding them
+}
+
+impl Default for DataCacheVisibility {
+    fn default() -> Self {
+        Self {
+            rules: Vec::new(),
+            claims_path: DEFAULT_CLAIMS_PATH.to_string(),
+            consent_path: DEFAULT_CONSENT_PATH.to_string(),
+            hidden: HashMap::new(),
+        }
+    }
+}
+
+// Hidden values are left out, so that debug output does not leak them
+impl fmt::Debug for DataCacheVisibility {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let rules: Vec<(&str, &Visibility)> = self.rules.iter().map(|(pattern, _, visibility)| (pattern.as_str(), visibility)).collect();
+        let mut hidden: Vec<&str> = self.hidden.keys().map(String::as_str).collect();
+        hidden.sort_unstable();
+        f.debug_struct("DataCacheVisibility")
+            .field("rules", &rules)
+            .field("claims_path", &self.claims_path)
+            .field("consent_path", &self.consent_path)
+            .field("hidden", &hidden)
+            .finish()
+    }
+}
+
+impl DataCacheVisibility {
+    /// No rule can move values out of the tree or back into it
+    pub(crate) fn is_empty(&self) -> bool {
+        self.rules.is_empty() && self.hidden.is_empty()
+    }
+
+    /// Forgets the values hidden at & below path, about to be replaced by null
+    pub(crate) fn forget(&mut self, path: &str) {
+        self.hidden.retain(|hidden_path, _| !paths_overlap(hidden_path, path) || hidden_path.len() < path.len());
+    }
+}
+
+/// Removes the node at path from the tree (an array item being replaced by null)
+fn take_node(root: &mut Value, path: &str) -> Option<Value> {
+    let (parent_path, key) = path.rsplit_once('.').unwrap_or(("", path));
+    let parent = if parent_path.is_empty() { Some(root) } else { root.pointer_mut(&DataCache::target_to_pointer(parent_path)) };
+    match parent? {
+        Value::Object(o) => o.remove(key),
+        Value::Array(a) => a.get_mut(key.parse::<usize>().ok()?).map(Value::take),
+        _ => None,
+    }
+}
+
+/// Puts the node back at path, if its parent still exists
+fn restore_node(root: &mut Value, path: &str, value: Value) -> bool {
+    let (parent_path, key) = path.rsplit_once('.').unwrap_or(("", path));
+    let parent = if parent_path.is_empty() { Some(root) } else { root.pointer_mut(&DataCache::target_to_pointer(parent_path)) };
+    match parent {
+        Some(Value::Object(o)) => {
+            o.insert(key.to_string(), value);
+            true
+        },
+        Some(Value::Array(a)) => match key.parse::<usize>().ok().and_then(|idx| a.get_mut(idx)) {
+            Some(item) => {
+                *item = value;
+                true
+            },
+            None => false,
+        },
+        _ => false,
+    }
+}
+
+impl DataCache {
+    /// Hides the keys matching pattern unless the claims meet the visibility, replacing any rule previously set for the pattern
+    /// Example: set_visibility("articles.*.body", Visibility::any_role(&["member", "staff"]))
+    pub fn set_visibility(&mut self, pattern: &str, visibility: Visibility) {
+        self.visibility.rules.retain(|(existing, _, _)| existing != pattern);
+        self.visibility.rules.push((pattern.to_string(), PathPattern::from(pattern), visibility));
+        self.on_after_insert();
+    }
+
+    /// Returns false if no rule was set for pattern. The keys it hid are restored
+    pub fn remove_visibility(&mut self, pattern: &str) -> bool {
+        let count = self.visibility.rules.len();
+        self.visibility.rules.retain(|(existing, _, _)| existing != pattern);
+        self.on_after_insert();
+        count != self.visibility.rules.len()
+    }
+
+    /// Sets the path of the claims of the current user (DEFAULT_CLAIMS_PATH by default)
+    pub fn set_claims_path(&mut self, claims_path: &str) {
+        self.visibility.claims_path = claims_path.to_string();
+        self.on_after_insert();
+    }
+
+    /// Paths currently hidden by visibility rules, sorted
+    pub fn hidden_paths(&self) -> Vec<&str> {
+        let mut paths: Vec<&str> = self.visibility.hidden.keys().map(String::as_str).collect();
+        paths.sort_unstable();
+        paths
+    }
+
+    /// Restores the hidden keys whose rule is now met (or removed), and hides the keys of the tree whose rule is not
+    pub(crate) fn apply_visibility(&mut self) {
+        if self.visibility.rules.is_empty() && self.visibility.hidden.is_empty() {
+            return;
+        }
+        let claims = self.get(&self.visibility.claims_path);
+        let consent = self.get(&self.visibility.consent_path);
+        let unmet: Vec<(String, PathPattern)> = self.visibility.rules.iter()
+            .filter(|(_, _, visibility)| !visibility.is_met(claims, consent))
+            .map(|(pattern, path_pattern, _)| (pattern.clone(), path_pattern.clone()))
+            .collect();
+
+        // Shallowest first, so that hidden descendants are restored into their restored parents
+        let mut restored: Vec<String> = self.visibility.hidden.iter()
+            .filter(|(_, (pattern, _))| !unmet.iter().any(|(unmet_pattern, _)| unmet_pattern == pattern))
+            .map(|(path, _)| path.clone())
+            .collect();
+        restored.sort_by_key(|path| path.matches('.').count());
+        for path in restored {
+            let (_, value) = self.visibility.hidden.remove(&path).unwrap();
+            if restore_node(&mut self.root, &path, value) {
+                self.mark_dirty(&path);
+            }
+        }
+
+        for (pattern, path_pattern) in &unmet {
+            for path in path_pattern.matching_paths(&self.root) {
+                // Null placeholders of hidden array items stay as they are
+                let is_placeholder = self.visibility.hidden.contains_key(&path) && Self::lookup(&self.root, &path).is_some_and(Value::is_null);
+                if is_placeholder || paths_overlap(&path, &self.visibility.claims_path) || paths_overlap(&path, &self.visibility.consent_path) {
+                    continue;
+                }
+                if let Some(value) = take_node(&mut self.root, &path) {
+                    self.visibility.hidden.insert(path.clone(), (pattern.clone(), value));
+                    self.mark_dirty(&path);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,220 @@
+//! io::Write utilities for the outputs of replacements
+
+use std::io::{self, Write};
+
+use crate::{base64, crc32c::Crc32c, sha256::Sha256};
+
+/// Writes everything to each of its sinks, for example the response body, a hashing writer for the ETag & a ByteCounter,
+/// so that large bodies are replaced once instead of being read again after replacement
+/// A failing sink fails the whole write, the previous sinks having already received the bytes
+#[derive(Default)]
+pub struct TeeWriter<'a> {
+    sinks: Vec<&'a mut dyn Write>,
+}
+
+impl<'a> TeeWriter<'a> {
+    pub fn new(sinks: Vec<&'a mut dyn Write>) -> Self {
+        Self { sinks }
+    }
+
+    pub fn push(&mut self, sink: &'a mut dyn Write) {
+        self.sinks.push(sink);
+    }
+}
+
+impl Write for TeeWriter<'_> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        for sink in self.sinks.iter_mut() {
+            sink.write_all(buf)?;
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        for sink in self.sinks.iter_mut() {
+            sink.flush()?;
+        }
+        Ok(())
+    }
+}
+
+/// Discards what it is written, counting the bytes (for a Content-Length header for example)
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ByteCounter {
+    pub count: u64,
+}
+
+impl Write for ByteCounter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.count += buf.len() as u64;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Default size of the chunks of a ChunkedWriter
+pub const DEFAULT_CHUNK_SIZE: usize = 8 * 1024;
+
+/// Frames what it is written with the HTTP/1.1 chunked transfer encoding, for a response whose length is unknown until the
+/// end of the replacement, written to a raw socket (along with a "Transfer-Encoding: chunked" header)
+/// Writes are buffered up to the chunk size, flushes sending the buffered bytes as a chunk right away (see FlushPolicy).
+/// finish must be called to terminate the body: a dropped writer leaves it truncated, as the client would see it
+pub struct ChunkedWriter<W: Write> {
+    inner: W,
+    chunk_size: usize,
+    buffer: Vec<u8>,
+}
+
+impl<W: Write> ChunkedWriter<W> {
+    pub fn new(inner: W) -> Self {
+        Self::with_chunk_size(inner, DEFAULT_CHUNK_SIZE)
+    }
+
+    /// Chunks hold up to chunk_size bytes (at least 1), but for larger writes which are sent as a single chunk
+    pub fn with_chunk_size(inner: W, chunk_size: usize) -> Self {
+        let chunk_size = chunk_size.max(1);
+        Self { inner, chunk_size, buffer: Vec::with_capacity(chunk_size) }
+    }
+
+    /// Writes a chunk, empty ones being skipped as they would terminate the body
+    fn write_chunk(inner: &mut W, data: &[u8]) -> io::Result<()> {
+        if data.is_empty() {
+            return Ok(());
+        }
+        write!(inner, "{:X}\r\n", data.len())?;
+        inner.write_all(data)?;
+        inner.write_all(b"\r\n")
+    }
+
+    fn write_buffer(&mut self) -> io::Result<()> {
+        Self::write_chunk(&mut self.inner, &self.buffer)?;
+        self.buffer.clear();
+        Ok(())
+    }
+
+    /// Writes the buffered bytes & the last chunk (without trailers), returning the flushed inner writer
+    pub fn finish(self) -> io::Result<W> {
+        self.finish_with_trailers(&[])
+    }
+
+    /// Same as finish, sending the trailer fields after the last chunk (declared beforehand in a Trailer header)
+    /// Example: finish_with_trailers(&[("Digest", &digest.header_value())]) with the digest of a DigestWriter
+    pub fn finish_with_trailers(mut self, trailers: &[(&str, &str)]) -> io::Result<W> {
+        self.write_buffer()?;
+        self.inner.write_all(b"0\r\n")?;
+        for (name, value) in trailers {
+            if [name, value].iter().any(|s| s.contains(['\r', '\n'])) {
+                return Err(io::Error::new(io::ErrorKind::InvalidInput, format!("Invalid trailer {name}")));
+            }
+            write!(self.inner, "{name}: {value}\r\n")?;
+        }
+        self.inner.write_all(b"\r\n")?;
+        self.inner.flush()?;
+        Ok(self.inner)
+    }
+}
+
+impl<W: Write> Write for ChunkedWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if self.buffer.is_empty() && buf.len() >= self.chunk_size {
+            Self::write_chunk(&mut self.inner, buf)?;
+            return Ok(buf.len());
+        }
+        let len = buf.len().min(self.chunk_size - self.buffer.len());
+        self.buffer.extend_from_slice(&buf[..len]);
+        if self.buffer.len() >= self.chunk_size {
+            self.write_buffer()?;
+        }
+        Ok(len)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.write_buffer()?;
+        self.inner.flush()
+    }
+}
+
+/// Algorithm of a DigestWriter
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DigestAlgorithm {
+    Crc32c,
+    Sha256,
+}
+
+#[derive(Debug, Clone)]
+enum Hasher {
+    Crc32c(Crc32c),
+    Sha256(Sha256),
+}
+
+/// Digest of the bytes written to a DigestWriter
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BodyDigest {
+    pub algorithm: DigestAlgorithm,
+    pub bytes: Vec<u8>, // Big endian for CRC-32C
+}
+
+impl BodyDigest {
+    /// Value of a Digest header or trailer (RFC 3230), like "sha-256=47DEQpj8HBSa+/TImW+5JCeuQeRkm5NMpJWZG3hSuFU="
+    pub fn header_value(&self) -> String {
+        let name = match self.algorithm {
+            DigestAlgorithm::Crc32c => "crc32c",
+            DigestAlgorithm::Sha256 => "sha-256",
+        };
+        format!("{name}={}", base64::encode(&self.bytes))
+    }
+
+    /// Lowercase hexadecimal digest, like for an ETag
+    pub fn to_hex(&self) -> String {
+        self.bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+    }
+}
+
+/// Computes the digest of what it writes to the inner writer on the fly, for a Digest trailer (see
+/// ChunkedWriter::finish_with_trailers) without reading the replaced body again
+pub struct DigestWriter<W: Write> {
+    inner: W,
+    hasher: Hasher,
+}
+
+impl<W: Write> DigestWriter<W> {
+    pub fn new(inner: W, algorithm: DigestAlgorithm) -> Self {
+        let hasher = match algorithm {
+            DigestAlgorithm::Crc32c => Hasher::Crc32c(Crc32c::default()),
+            DigestAlgorithm::Sha256 => Hasher::Sha256(Sha256::default()),
+        };
+        Self { inner, hasher }
+    }
+
+    /// Digest of the bytes written so far
+    pub fn digest(&self) -> BodyDigest {
+        match self.hasher.clone() {
+            Hasher::Crc32c(hasher) => BodyDigest { algorithm: DigestAlgorithm::Crc32c, bytes: hasher.finalize().to_be_bytes().to_vec() },
+            Hasher::Sha256(hasher) => BodyDigest { algorithm: DigestAlgorithm::Sha256, bytes: hasher.finalize().to_vec() },
+        }
+    }
+
+    /// The inner writer & the digest of everything written
+    pub fn finish(self) -> (W, BodyDigest) {
+        let digest = self.digest();
+        (self.inner, digest)
+    }
+}
+
+impl<W: Write> Write for DigestWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let written = self.inner.write(buf)?;
+        match &mut self.hasher {
+            Hasher::Crc32c(hasher) => hasher.update(&buf[..written]),
+            Hasher::Sha256(hasher) => hasher.update(&buf[..written]),
+        }
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
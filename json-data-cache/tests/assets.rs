@@ -0,0 +1,35 @@
+#![cfg(feature = "replace-engine")]
+
+use json_data_cache::{DataCache, DataCacheOptions};
+use serde_json::json;
+
+#[test]
+fn asset_manifest_test() {
+    let mut data_cache = DataCache::new(DataCacheOptions::default());
+    let manifest = json!({
+        "main.css": "main.3f2a1b.css",
+        "src/main.ts": {"file": "assets/main.4889e940.js", "css": ["assets/main.1c2d.css"]},
+        "logo.svg": "https://cdn.example.com/logo.9a8b.svg"
+    });
+    assert_eq!(data_cache.load_asset_manifest(&manifest, "/static/").unwrap(), 3);
+    assert_eq!(data_cache.asset_url("main.css"), Some("/static/main.3f2a1b.css"));
+    assert_eq!(data_cache.asset_url("src/main.ts"), Some("/static/assets/main.4889e940.js"));
+    assert_eq!(data_cache.asset_url("logo.svg"), Some("https://cdn.example.com/logo.9a8b.svg"));
+    assert_eq!(data_cache.asset_url("missing.css"), None);
+
+    let template = br#"<link href="{$asset:main.css}"><script src="{$asset:src/main.ts}"></script>"#;
+    let mut output = Vec::new();
+    assert!(data_cache.replace_with_data_cache(template.as_slice(), &mut output).is_ok());
+    assert_eq!(output, br#"<link href="/static/main.3f2a1b.css"><script src="/static/assets/main.4889e940.js"></script>"#);
+
+    // A new build replaces the URLs, dropping the assets it does not have anymore
+    assert_eq!(data_cache.load_asset_manifest(&json!({"main.css": "main.77e0.css"}), "").unwrap(), 1);
+    assert_eq!(data_cache.asset_url("main.css"), Some("main.77e0.css"));
+    assert_eq!(data_cache.asset_url("src/main.ts"), None);
+    let mut output = Vec::new();
+    assert!(data_cache.replace_with_data_cache(b"{$asset:main.css}".as_slice(), &mut output).is_ok());
+    assert_eq!(output, b"main.77e0.css");
+
+    assert!(data_cache.load_asset_manifest(&json!(["main.css"]), "").is_err());
+    assert!(data_cache.load_asset_manifest(&json!({"main.css": {"css": []}}), "").is_err());
+}
@@ -0,0 +1,59 @@
+use json_data_cache::{DataCache, DataCacheOptions, bind::CacheBind, coercion::Date};
+#[cfg(not(feature = "derive"))]
+use json_data_cache_derive::CacheBind;
+use serde_json::json;
+
+#[derive(Debug, PartialEq, CacheBind)]
+struct Author {
+    name: String,
+    #[cache(path = "profile.url")]
+    url: Option<String>,
+}
+
+#[derive(Debug, PartialEq, CacheBind)]
+#[cache(prefix = "request")]
+struct RequestContext {
+    #[cache(path = "user.id")]
+    user_id: u64,
+    locale: Option<String>,
+    #[cache(default)]
+    is_preview: bool,
+    published: Date,
+    #[cache(nested)]
+    author: Author,
+    #[cache(skip)]
+    computed: Vec<String>,
+}
+
+#[test]
+fn cache_bind_test() {
+    let mut data_cache = DataCache::new(DataCacheOptions::default());
+    data_cache.insert("request", json!({
+        "user": {"id": 42},
+        "published": "2024-05-01",
+        "author": {"name": "Taro", "profile": {"url": "https://example.com"}}
+    }));
+    let context = RequestContext::load_from_cache(&data_cache).unwrap();
+    assert_eq!(context, RequestContext {
+        user_id: 42,
+        locale: None,
+        is_preview: false,
+        published: Date { year: 2024, month: 5, day: 1 },
+        author: Author { name: "Taro".to_string(), url: Some("https://example.com".to_string()) },
+        computed: Vec::new(),
+    });
+
+    let mut copy = DataCache::new(DataCacheOptions::default());
+    assert!(context.store_to_cache(&mut copy, "copy").is_ok());
+    assert_eq!(copy.get("copy.user.id"), Some(&json!(42)));
+    assert_eq!(copy.get("copy.published"), Some(&json!("2024-05-01")));
+    assert_eq!(copy.get("copy.author.profile.url"), Some(&json!("https://example.com")));
+    assert_eq!(copy.get("copy.locale"), Some(&json!(null)));
+    assert_eq!(copy.get("copy.computed"), None);
+    assert_eq!(RequestContext::load_from_cache_at(&copy, "copy").unwrap(), context);
+
+    // Missing & unconvertible required values fail
+    data_cache.insert("request.user.id", json!("abc"));
+    assert!(RequestContext::load_from_cache(&data_cache).is_err());
+    assert!(Author::load_from_cache(&data_cache).is_err());
+}
@@ -0,0 +1,84 @@
+#![cfg(feature = "replace-engine")]
+
+use std::time::Duration;
+
+use json_data_cache::{BuildProgress, BuildStep, DataCacheOptions, bundle::{Bundle, BundleBuilder, BundleManager}};
+use serde_json::json;
+
+#[test]
+fn bundle_test() {
+    let bytes = BundleBuilder::new()
+        .template("page", b"<h1>{$site.name}</h1><p>{$i18n.ja.greeting}</p>")
+        .fragment("nav", b"<nav>{$site.name}</nav>")
+        .catalog("ja", json!({"greeting": "こんにちは"}))
+        .schema("articles", json!({"type": "array", "items": {"type": "object"}}))
+        .data("site", json!({"name": "Kuroco"}))
+        .data("", json!({"articles": []}))
+        .to_bytes()
+        .unwrap();
+    assert_eq!(bytes.len() % 512, 0);
+
+    let mut bundle = Bundle::load(&bytes).unwrap();
+    assert_eq!(bundle.manifest["version"], 1);
+    assert_eq!(bundle.templates.ids().collect::<Vec<_>>(), ["page"]);
+    let mut output = Vec::new();
+    bundle.templates.render("page", &mut bundle.data_cache, &mut output).unwrap();
+    assert_eq!(String::from_utf8(output).unwrap(), "<h1>Kuroco</h1><p>こんにちは</p>");
+    assert!(bundle.templates.render("missing", &mut bundle.data_cache, Vec::new()).is_err());
+    assert_eq!(bundle.data_cache.render_fragment_cached("nav", &["site.name"]).unwrap(), b"<nav>Kuroco</nav>");
+    assert_eq!(bundle.data_cache.schema("articles.0"), Some(&json!({"type": "object"})));
+    assert_eq!(bundle.data_cache.get("articles"), Some(&json!([])));
+
+    let bytes = BundleBuilder::new().catalog("en", json!({"greeting": "Hello"})).catalog_path("messages").to_bytes().unwrap();
+    let bundle = Bundle::load(&bytes).unwrap();
+    assert!(bundle.templates.is_empty());
+    assert_eq!(bundle.data_cache.get("messages.en.greeting"), Some(&json!("Hello")));
+
+    let bytes = BundleBuilder::new().assets(json!({"main.css": "main.3f2a1b.css"}), "/static").to_bytes().unwrap();
+    let bundle = Bundle::load(&bytes).unwrap();
+    assert_eq!(bundle.data_cache.asset_url("main.css"), Some("/static/main.3f2a1b.css"));
+}
+
+#[test]
+fn bundle_invalid_test() {
+    assert!(Bundle::load(b"").is_err()); // No manifest
+    let mut bytes = BundleBuilder::new().data("site", json!({})).to_bytes().unwrap();
+    bytes[0] = b'x'; // Checksum mismatch
+    assert!(Bundle::load(&bytes).is_err());
+    let bytes = BundleBuilder::new().data("site", json!({})).to_bytes().unwrap();
+    assert!(Bundle::load(&bytes[..1000]).is_err()); // Truncated
+}
+
+#[test]
+fn bundle_manager_test() {
+    let bundle_of = |version: &str| BundleBuilder::new()
+        .template("page", b"<p>{$site.version}</p>")
+        .data("site", json!({"version": version}))
+        .to_bytes()
+        .unwrap();
+    let render = |bundle: &Bundle| {
+        let mut output = Vec::new();
+        bundle.render("page", &mut output).unwrap();
+        String::from_utf8(output).unwrap()
+    };
+
+    let mut manager = BundleManager::new();
+    assert!(manager.current().is_none());
+    assert_eq!(manager.build_step(Duration::ZERO).unwrap(), BuildProgress::Done);
+    let in_flight = manager.load(&bundle_of("v1"), DataCacheOptions::default()).unwrap();
+    assert_eq!(manager.generation(), 1);
+
+    manager.stage(&bundle_of("v2")).unwrap();
+    assert!(manager.is_pending());
+    assert_eq!(manager.build_step(Duration::ZERO).unwrap(), BuildProgress::Pending(BuildStep::CollectPatterns));
+    assert_eq!(render(&manager.current().unwrap()), "<p>v1</p>"); // Not swapped in until prepared
+    while manager.build_step(Duration::ZERO).unwrap() != BuildProgress::Done {}
+    assert!(!manager.is_pending());
+    assert_eq!(manager.generation(), 2);
+    assert_eq!(render(&manager.current().unwrap()), "<p>v2</p>");
+    assert_eq!(render(&in_flight), "<p>v1</p>"); // Renders started before the swap keep their version
+
+    assert!(manager.stage(b"not a bundle").is_err());
+    assert!(!manager.is_pending());
+    assert_eq!(render(&manager.current().unwrap()), "<p>v2</p>");
+}
@@ -0,0 +1,39 @@
+use json_data_cache::{CachePath, DataCache, DataCacheOptions, path};
+use serde_json::json;
+
+#[test]
+fn cache_path_test() {
+    let list = CachePath::root().join("content").join("list");
+    let first_title = list.join(0).join("title");
+    assert_eq!(first_title.as_str(), "content.list.0.title");
+    assert_eq!(path!("content"."list"[0]."title"), first_title);
+    assert_eq!(path!("content"), CachePath::parse("content").unwrap());
+    assert_eq!(first_title.to_string(), "content.list.0.title");
+    assert_eq!(first_title.segments().collect::<Vec<_>>(), ["content", "list", "0", "title"]);
+    assert_eq!(first_title.last(), Some("title"));
+    assert_eq!(first_title.parent().unwrap().parent(), Some(list.clone()));
+    assert_eq!(path!("content").parent(), Some(CachePath::root()));
+    assert_eq!(CachePath::root().parent(), None);
+    assert!(first_title.starts_with(&list));
+    assert!(!path!("contents").starts_with(&path!("content")));
+    assert_eq!(list.append().as_str(), "content.list.");
+    assert_eq!(list.append().join(1).as_str(), "content.list.1");
+
+    assert!(CachePath::parse("content..list").is_err());
+    assert!(".content".parse::<CachePath>().is_err());
+    assert_eq!(CachePath::parse("content.list.").unwrap().as_str(), "content.list.");
+
+    // Keys are single segments
+    assert!(list.try_join("a.b").is_err());
+    assert!(list.try_join("").is_err());
+    assert_eq!(list.try_join("title").unwrap().as_str(), "content.list.title");
+    assert!(std::panic::catch_unwind(|| CachePath::root().join("content.list")).is_err());
+
+    // Accepted by APIs taking paths
+    let mut data_cache = DataCache::new(DataCacheOptions::default());
+    data_cache.insert(&list, json!([]));
+    data_cache.insert(&list.append(), json!({"title": "Hello"}));
+    assert_eq!(data_cache.get(&first_title), Some(&json!("Hello")));
+    let idx = 0;
+    assert_eq!(data_cache.get(&path!("content"."list"[idx]."title")), Some(&json!("Hello")));
+}
@@ -0,0 +1,55 @@
+use json_data_cache::{DataCache, DataCacheOptions, coercion::Date};
+use serde_json::{Value, json};
+
+#[test]
+fn get_as_test() {
+    let mut data_cache = DataCache::new(DataCacheOptions::default());
+    data_cache.insert("member", json!({
+        "active": "1",
+        "newsletter": 0,
+        "points": "120",
+        "rate": " 1.5 ",
+        "code": 42,
+        "birthday": "2023-01-02",
+        "updated_at": "2024-02-29T10:00:00+09:00",
+        "created_at": 1_700_000_000,
+        "tags": [{"id": "7"}],
+        "nickname": null
+    }));
+    // Without schema, only values of the requested JSON type are read
+    assert_eq!(data_cache.get_as::<bool>("member.active").map_err(|e| e.msg.contains("member.active")), Err(true));
+    assert_eq!(data_cache.get_as::<i64>("member.code").unwrap(), Some(42));
+    assert_eq!(data_cache.get_as::<Date>("member.birthday").unwrap(), Some(Date { year: 2023, month: 1, day: 2 }));
+    assert_eq!(data_cache.get_as::<String>("member.nickname").unwrap(), None);
+    assert_eq!(data_cache.get_as::<String>("member.missing").unwrap(), None);
+
+    data_cache.register_schema("member", json!({
+        "type": "object",
+        "properties": {
+            "active": {"type": "boolean"},
+            "newsletter": {"type": "boolean"},
+            "points": {"type": "integer"},
+            "rate": {"type": "number"},
+            "code": {"type": "string"},
+            "updated_at": {"type": "string", "format": "date-time"},
+            "created_at": {"type": "integer", "format": "date-time"},
+            "tags": {"type": "array", "items": {"properties": {"id": {"type": "integer"}}}}
+        }
+    }));
+    assert_eq!(data_cache.schema("member.tags.0.id"), Some(&json!({"type": "integer"})));
+    assert_eq!(data_cache.get_as::<bool>("member.active").unwrap(), Some(true));
+    assert_eq!(data_cache.get_as::<bool>("member.newsletter").unwrap(), Some(false));
+    assert_eq!(data_cache.get_as::<i64>("member.points").unwrap(), Some(120));
+    assert_eq!(data_cache.get_as::<f64>("member.rate").unwrap(), Some(1.5));
+    assert_eq!(data_cache.get_as::<String>("member.code").unwrap(), Some("42".to_string()));
+    assert_eq!(data_cache.get_as::<u64>("member.tags.0.id").unwrap(), Some(7));
+    assert_eq!(data_cache.get_as::<Date>("member.updated_at").unwrap().map(|date| date.to_string()), Some("2024-02-29".to_string()));
+    assert_eq!(data_cache.get_as::<Date>("member.created_at").unwrap(), Some(Date { year: 2023, month: 11, day: 14 }));
+    assert!(data_cache.get_as::<Date>("member.points").is_err());
+    assert_eq!(Date::parse("2023-02-29"), None);
+
+    // Customized conversion
+    data_cache.set_coercion::<bool, _>(|value: &Value, _| value.as_str().map(|s| s == "Y"));
+    assert_eq!(data_cache.get_as::<bool>("member.active").unwrap(), Some(false));
+    assert_eq!(data_cache.get_as::<Value>("member.tags.0").unwrap(), Some(json!({"id": "7"})));
+}
@@ -0,0 +1,106 @@
+use json_data_cache::{DataCache, DataCacheOptions, compression::{compress, decompress}};
+use serde_json::json;
+
+#[test]
+fn compress_test() {
+    for data in [&b""[..], b"a", b"abcd", b"abcabcabcabcabcabc", "記事本文。".repeat(200).as_bytes()] {
+        let compressed = compress(data);
+        assert_eq!(decompress(&compressed).unwrap(), data);
+    }
+    let text = "Lorem ipsum dolor sit amet, consectetur adipiscing elit. ".repeat(100);
+    let compressed = compress(text.as_bytes());
+    assert!(compressed.len() < text.len() / 10);
+
+    assert!(decompress(&compressed[..compressed.len() - 1]).is_err());
+    assert!(decompress(&[10, 2, b'a', b'b', 5, 0]).is_err()); // Offset before the start
+}
+
+#[test]
+fn compress_strings_test() {
+    let body = "<p>本文です。</p>".repeat(100);
+    let mut data_cache = DataCache::new(DataCacheOptions::default());
+    data_cache.insert("articles", json!({"a1": {"title": "First", "body": body}}));
+    data_cache.compress_strings("articles", 256);
+    data_cache.insert("articles.a2", json!({"title": "Second", "body": format!("{body}!")}));
+
+    let stats = data_cache.compression_stats();
+    assert_eq!(stats.count, 2);
+    assert_eq!(stats.original_bytes, body.len() * 2 + 1);
+    assert!(stats.compressed_bytes < body.len() / 4);
+
+    // Reads of their path & string values are decompressed, short strings are left as is
+    assert_eq!(data_cache.get("articles.a1.body"), Some(&json!(body)));
+    assert_eq!(data_cache.get("articles.a2.body"), Some(&json!(format!("{body}!"))));
+    assert_eq!(data_cache.get("articles.a2.title"), Some(&json!("Second")));
+    assert_eq!(data_cache.as_string_values_map().get("articles.a1.body"), Some(&body));
+
+    // Replaced & removed values are forgotten
+    data_cache.insert("articles.a1.body", json!("Short"));
+    assert_eq!(data_cache.get("articles.a1.body"), Some(&json!("Short")));
+    data_cache.insert("articles.a2.body", json!(null));
+    assert_eq!(data_cache.get("articles.a2.body"), Some(&json!(null)));
+    assert_eq!(data_cache.compression_stats().count, 0);
+
+    #[cfg(feature = "serializer")]
+    {
+        data_cache.insert("articles.a1.body", json!(body));
+        assert_eq!(data_cache.compression_stats().count, 1);
+        assert_eq!(data_cache.string_values_view().get("articles.a1.body"), Some(&body));
+    }
+
+    #[cfg(feature = "replace-engine")]
+    {
+        let mut writer = Vec::new();
+        assert!(data_cache.replace_with_data_cache(b"{$articles.a1.body}|{$$articles.a1}".as_slice(), &mut writer).is_ok());
+        assert_eq!(String::from_utf8(writer).unwrap(), format!(r#"{body}|{{\"title\":\"First\",\"body\":\"{body}\"}}"#));
+    }
+}
+
+#[test]
+fn compressed_reads_test() {
+    let body = "A long article body. ".repeat(20);
+    let mut data_cache = DataCache::new(DataCacheOptions::default());
+    data_cache.compress_strings("article", 100);
+    data_cache.insert("article", json!({"body": body}));
+    data_cache.insert("default_body", json!("default"));
+    data_cache.insert_fallback("shown", &["article.body", "default_body"]).unwrap();
+
+    // Compressed strings are values for fallback chains & ancestors
+    assert_eq!(data_cache.get("shown"), Some(&json!(body)));
+    assert_eq!(data_cache.get("article"), Some(&json!({"body": body})));
+    assert_eq!(data_cache.get_many(&["article", "article.body"]), [Some(&json!({"body": body})), Some(&json!(body))]);
+
+    #[cfg(feature = "serializer")]
+    {
+        let article = json!({"body": body}).to_string();
+        assert_eq!(data_cache.string_values_view().get("article"), Some(&article));
+        // Recomputed after an insert, still decompressed
+        data_cache.insert("article.title", json!("Title"));
+        assert_eq!(data_cache.string_values_view().get("article"), Some(&json!({"body": body, "title": "Title"}).to_string()));
+        assert_eq!(data_cache.string_values_view().get("shown"), Some(&body));
+    }
+    #[cfg(feature = "replace-engine")]
+    {
+        let mut writer = Vec::new();
+        assert!(data_cache.replace_with_data_cache(b"{$shown}".as_slice(), &mut writer).is_ok());
+        assert_eq!(String::from_utf8(writer).unwrap(), body);
+    }
+}
+
+#[test]
+fn persist_compressed_test() {
+    use json_data_cache::store::{CacheStore, MemoryCacheStore};
+
+    let body = "Article body. ".repeat(50);
+    let mut data_cache = DataCache::new(DataCacheOptions::default());
+    data_cache.compress_strings("", 100);
+    data_cache.insert("article", json!({"body": body, "id": 1}));
+    assert_eq!(data_cache.compression_stats().count, 1);
+
+    let mut store = MemoryCacheStore::default();
+    assert!(data_cache.persist_to(&mut store, "all", "", None).is_ok());
+    assert!(data_cache.persist_to(&mut store, "article", "article", None).is_ok());
+    let expected = json!({"article": {"body": body, "id": 1}});
+    assert_eq!(serde_json::from_slice::<serde_json::Value>(&store.get("all").unwrap().unwrap()).unwrap(), expected);
+    assert_eq!(serde_json::from_slice::<serde_json::Value>(&store.get("article").unwrap().unwrap()).unwrap(), expected["article"]);
+}
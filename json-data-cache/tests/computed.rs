@@ -0,0 +1,73 @@
+use json_data_cache::{DataCache, DataCacheOptions};
+use serde_json::json;
+
+#[test]
+fn computed_test() {
+    let mut data_cache = DataCache::new(DataCacheOptions::default());
+    data_cache.insert("cart.items", json!([{"price": 100}, {"price": "250"}, {"price": 50.5}]));
+    data_cache.insert("tax_rate", json!(0.1));
+
+    assert!(data_cache.define_computed("cart.total", "sum(cart.items.*.price)").is_ok());
+    assert!(data_cache.define_computed("cart.count", "count(cart.items.*)").is_ok());
+    assert!(data_cache.define_computed("cart.total_with_tax", "cart.total * (1 + tax_rate)").is_ok());
+    assert!(data_cache.define_computed("cart.cheapest", "min(cart.items.*.price, 40)").is_ok());
+    assert_eq!(data_cache.get("cart.total"), Some(&json!(400.5)));
+    assert_eq!(data_cache.get("cart.count"), Some(&json!(3)));
+    assert_eq!(data_cache.get("cart.total_with_tax"), Some(&json!(440.55)));
+    assert_eq!(data_cache.get("cart.cheapest"), Some(&json!(40)));
+
+    // Dependencies are recomputed, including chained computed paths
+    data_cache.insert("cart.items.", json!({"price": 99.5}));
+    assert_eq!(data_cache.get("cart.total"), Some(&json!(500)));
+    assert_eq!(data_cache.get("cart.count"), Some(&json!(4)));
+    data_cache.merge(json!({"tax_rate": 0.2}));
+    assert_eq!(data_cache.get("cart.total_with_tax"), Some(&json!(600)));
+
+    // Missing or non numeric values produce null
+    assert!(data_cache.define_computed("ratio", "cart.total / unknown").is_ok());
+    assert_eq!(data_cache.get("ratio"), Some(&json!(null)));
+
+    assert!(data_cache.define_computed("invalid", "sum(cart.items.*.price").is_err());
+    assert!(data_cache.define_computed("unknown_function", "median(cart.items.*.price)").is_err());
+    assert!(data_cache.define_computed("cart.total", "cart.total + 1").is_err());
+
+    #[cfg(feature = "replace-engine")]
+    {
+        let mut writer = Vec::new();
+        assert!(data_cache.replace_with_data_cache("{$cart.total}".as_bytes(), &mut writer).is_ok());
+        assert_eq!(&String::from_utf8(writer).unwrap(), "500");
+    }
+}
+
+#[test]
+fn computed_lazy_test() {
+    let mut data_cache = DataCache::new(DataCacheOptions::default());
+    data_cache.insert("a", json!(1));
+    data_cache.define_computed("b", "a * 2").unwrap();
+    data_cache.define_computed("c", "b + 1").unwrap();
+    // Computed on read, the tree getting the values once prepared. Every getter agrees before that
+    assert_eq!(data_cache.get("c"), Some(&json!(3)));
+    assert_eq!(data_cache.get_many(&["c"]), [Some(&json!(3))]);
+    assert_eq!(data_cache.as_string_values_map().get("c"), Some(&String::from("3")));
+    assert_eq!(data_cache.get_subtree_map("").get("c"), Some(&&json!(3)));
+    assert_eq!(data_cache.get_all("c"), [(String::from("c"), &json!(3))]);
+    data_cache.insert("a", json!(5));
+    assert_eq!(data_cache.get("c"), Some(&json!(11)));
+    assert_eq!(data_cache.get_many(&["c"]), [Some(&json!(11))]);
+    assert_eq!(data_cache.as_string_values_map().get("c"), Some(&String::from("11")));
+    data_cache.prepare_computed();
+    assert_eq!(data_cache.as_string_values_map().get("c"), Some(&String::from("11")));
+    assert_eq!(data_cache.get_all("c"), [(String::from("c"), &json!(11))]);
+
+    // Computed paths defined later are used too, without cycles
+    data_cache.define_computed("d", "e + 1").unwrap();
+    data_cache.define_computed("e", "c * 2").unwrap();
+    assert_eq!(data_cache.get("d"), Some(&json!(23)));
+    assert!(data_cache.define_computed("c", "d - 1").is_err());
+
+    #[cfg(feature = "serializer")]
+    {
+        data_cache.insert("a", json!(6));
+        assert_eq!(data_cache.string_values_view().get("d"), Some(&String::from("27")));
+    }
+}
@@ -0,0 +1,57 @@
+use json_data_cache::{DataCache, DataCacheOptions, consent::parse_consent};
+use serde_json::json;
+
+#[test]
+fn parse_consent_test() {
+    assert_eq!(parse_consent("analytics:1,ads:0"), json!({"analytics": true, "ads": false}));
+    assert_eq!(
+        parse_consent("%7Bstamp:%27abc%3D%3D%27%2Cnecessary:true%2Cstatistics:false%2Cutc:1700000000%7D"),
+        json!({"necessary": true, "statistics": false})
+    );
+    assert_eq!(parse_consent(r#"{"personalization": true}"#), json!({"personalization": true}));
+    assert_eq!(parse_consent("analytics|personalization"), json!({"analytics": true, "personalization": true}));
+    assert_eq!(parse_consent(""), json!({}));
+}
+
+#[test]
+fn consent_test() {
+    let mut data_cache = DataCache::new(DataCacheOptions::default());
+    data_cache.insert("tracking", json!({"ga_id": "G-123", "site": "main"}));
+    data_cache.insert("recommendations", json!(["a", "b"]));
+    data_cache.require_consent("tracking.ga_id", "analytics");
+    data_cache.require_consent("recommendations", "personalization");
+    assert_eq!(data_cache.get("tracking"), Some(&json!({"site": "main"})));
+    assert_eq!(data_cache.get("recommendations"), None);
+    assert!(!format!("{data_cache:?}").contains("G-123"));
+
+    assert_eq!(data_cache.load_consent("theme=dark; cmp=analytics:1,personalization:0", "cmp"), json!({"analytics": true, "personalization": false}));
+    assert!(data_cache.has_consent("analytics"));
+    assert!(!data_cache.has_consent("personalization"));
+    assert_eq!(data_cache.get("tracking.ga_id"), Some(&json!("G-123")));
+    assert_eq!(data_cache.get("recommendations"), None);
+
+    // Without the cookie, consent is absent
+    assert_eq!(data_cache.load_consent("theme=dark", "cmp"), json!({}));
+    assert_eq!(data_cache.get("tracking.ga_id"), None);
+    assert_eq!(data_cache.hidden_paths(), ["recommendations", "tracking.ga_id"]);
+
+    data_cache.set_consent_path("cmp");
+    data_cache.insert("cmp.personalization", json!(true));
+    assert_eq!(data_cache.get("recommendations"), Some(&json!(["a", "b"])));
+}
+
+#[cfg(feature = "replace-engine")]
+#[test]
+fn consent_replace_test() {
+    let mut data_cache = DataCache::new(DataCacheOptions::default());
+    data_cache.insert("tracking.ga_id", json!("G-123"));
+    data_cache.require_consent("tracking.ga_id", "analytics");
+    let replace = |data_cache: &mut DataCache| {
+        let mut writer = Vec::new();
+        data_cache.replace_with_data_cache("id={$tracking.ga_id}".as_bytes(), &mut writer).unwrap();
+        String::from_utf8(writer).unwrap()
+    };
+    assert_eq!(replace(&mut data_cache), "id={$tracking.ga_id}");
+    data_cache.load_consent("cmp=analytics:true", "cmp");
+    assert_eq!(replace(&mut data_cache), "id=G-123");
+}
@@ -0,0 +1,83 @@
+use json_data_cache::{DataCache, DataCacheOptions, crdt::{Crdt, GCounter, LwwRegister, OrSet}};
+use serde_json::json;
+
+#[test]
+fn crdt_g_counter_test() {
+    let mut tokyo = DataCache::new(DataCacheOptions::default());
+    let mut osaka = DataCache::new(DataCacheOptions::default());
+
+    let mut counter = GCounter::default();
+    counter.increment("tokyo", 3);
+    assert!(tokyo.merge_crdt("stats.views", &Crdt::GCounter(counter)).is_ok());
+    let mut counter = GCounter::default();
+    counter.increment("osaka", 2);
+    assert!(osaka.merge_crdt("stats.views", &Crdt::GCounter(counter)).is_ok());
+
+    // Exchange states, through their JSON encoding
+    let tokyo_state = tokyo.get_crdt("stats.views").unwrap().to_state();
+    let osaka_state = osaka.get_crdt("stats.views").unwrap().to_state();
+    assert!(tokyo.merge_crdt("stats.views", &Crdt::from_state(&osaka_state).unwrap()).is_ok());
+    assert!(osaka.merge_crdt("stats.views", &Crdt::from_state(&tokyo_state).unwrap()).is_ok());
+    assert_eq!(tokyo.get("stats.views"), Some(&json!(5)));
+    assert_eq!(osaka.get("stats.views"), Some(&json!(5)));
+
+    // Merging the same state again is idempotent
+    assert!(tokyo.merge_crdt("stats.views", &Crdt::from_state(&osaka_state).unwrap()).is_ok());
+    assert_eq!(tokyo.get("stats.views"), Some(&json!(5)));
+
+    // Type mismatch
+    let register = Crdt::LwwRegister(LwwRegister::new(json!("x"), 1, "tokyo"));
+    assert!(tokyo.merge_crdt("stats.views", &register).is_err());
+    assert!(Crdt::from_state(&json!({"type": "unknown"})).is_err());
+}
+
+#[test]
+fn crdt_lww_register_test() {
+    let mut data_cache = DataCache::new(DataCacheOptions::default());
+    let older = Crdt::LwwRegister(LwwRegister::new(json!({"title": "Old", "draft": true}), 10, "tokyo"));
+    let newer = Crdt::LwwRegister(LwwRegister::new(json!({"title": "New"}), 20, "osaka"));
+    assert!(data_cache.merge_crdt("banner", &newer).is_ok());
+    assert!(data_cache.merge_crdt("banner", &older).is_ok());
+    // The newest value replaces the whole object
+    assert_eq!(data_cache.get("banner"), Some(&json!({"title": "New"})));
+
+    // Same timestamp : the greatest node id wins, in any order
+    let a = Crdt::LwwRegister(LwwRegister::new(json!("a"), 30, "node-a"));
+    let b = Crdt::LwwRegister(LwwRegister::new(json!("b"), 30, "node-b"));
+    let mut ab = a.clone();
+    ab.merge(&b).unwrap();
+    let mut ba = b.clone();
+    ba.merge(&a).unwrap();
+    assert_eq!(ab, ba);
+    assert_eq!(ab.value(), json!("b"));
+}
+
+#[test]
+fn crdt_or_set_test() {
+    let mut tokyo_set = OrSet::default();
+    tokyo_set.add(&json!("id-1"), "tokyo:1");
+    tokyo_set.add(&json!("id-2"), "tokyo:2");
+    let mut osaka_set = tokyo_set.clone();
+
+    // Concurrent removal & re-addition : the addition wins
+    tokyo_set.remove(&json!("id-1"));
+    osaka_set.add(&json!("id-1"), "osaka:1");
+    osaka_set.add(&json!("id-3"), "osaka:2");
+    osaka_set.remove(&json!("id-2"));
+
+    let mut data_cache = DataCache::new(DataCacheOptions::default());
+    assert!(data_cache.merge_crdt("seen", &Crdt::OrSet(tokyo_set.clone())).is_ok());
+    assert_eq!(data_cache.get("seen"), Some(&json!(["id-2"])));
+    assert!(data_cache.merge_crdt("seen", &Crdt::OrSet(osaka_set.clone())).is_ok());
+    assert_eq!(data_cache.get("seen"), Some(&json!(["id-1", "id-3"])));
+
+    let Some(Crdt::OrSet(merged)) = data_cache.get_crdt("seen") else { panic!() };
+    assert!(merged.contains(&json!("id-1")));
+    assert!(!merged.contains(&json!("id-2")));
+    let decoded = Crdt::from_state(&data_cache.get_crdt("seen").unwrap().to_state()).unwrap();
+    assert_eq!(&decoded, data_cache.get_crdt("seen").unwrap());
+
+    // Invalidation drops the state
+    assert!(data_cache.invalidate("seen"));
+    assert!(data_cache.get_crdt("seen").is_none());
+}
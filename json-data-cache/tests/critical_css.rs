@@ -0,0 +1,69 @@
+use std::io::Write;
+
+use json_data_cache::{DataCache, DataCacheOptions, critical_css::{CriticalCssInjector, DEFAULT_CRITICAL_CSS_PATH}};
+use serde_json::json;
+
+const PAGE: &str = "<!DOCTYPE html><html><head><meta charset=\"utf-8\"><link rel=\"stylesheet\" href=\"/main.css\" media=\"screen\">\
+<!-- <link rel=\"stylesheet\" href=\"/commented.css\"> --><script>document.write('<link rel=\"stylesheet\" href=\"/js.css\">')</script>\
+<link rel=\"icon\" href=\"/favicon.ico\"></head><body><link rel=\"stylesheet\" href=\"/body.css\"></body></html>";
+
+const INJECTED: &str = "<!DOCTYPE html><html><head><style nonce=\"abc\">h1{color:red}<\\/style></style><meta charset=\"utf-8\">\
+<link rel=\"preload\" href=\"/main.css\" as=\"style\" media=\"screen\" onload=\"this.onload=null;this.rel='stylesheet'\">\
+<noscript><link rel=\"stylesheet\" href=\"/main.css\" media=\"screen\"></noscript>\
+<!-- <link rel=\"stylesheet\" href=\"/commented.css\"> --><script>document.write('<link rel=\"stylesheet\" href=\"/js.css\">')</script>\
+<link rel=\"icon\" href=\"/favicon.ico\"></head><body><link rel=\"stylesheet\" href=\"/body.css\"></body></html>";
+
+#[test]
+fn critical_css_injector_test() {
+    let mut injector = CriticalCssInjector::new(Vec::new(), "h1{color:red}</style>").nonce("abc");
+    injector.write_all(PAGE.as_bytes()).unwrap();
+    assert!(injector.is_injected());
+    assert_eq!(String::from_utf8(injector.finish().unwrap()).unwrap(), INJECTED);
+
+    // Chunks splitting tags & closing sequences
+    for chunk_len in [1, 2, 3, 7, 64] {
+        let mut injector = CriticalCssInjector::new(Vec::new(), "h1{color:red}</style>").nonce("abc");
+        for chunk in PAGE.as_bytes().chunks(chunk_len) {
+            injector.write_all(chunk).unwrap();
+        }
+        assert_eq!(String::from_utf8(injector.finish().unwrap()).unwrap(), INJECTED, "{chunk_len}");
+    }
+
+    let mut injector = CriticalCssInjector::new(Vec::new(), "");
+    injector.write_all(PAGE.as_bytes()).unwrap();
+    assert!(!injector.is_injected());
+    assert_eq!(injector.finish().unwrap(), PAGE.as_bytes());
+
+    // Without head
+    let mut injector = CriticalCssInjector::new(Vec::new(), "p{margin:0}");
+    injector.write_all(b"<html><body><p>x</p></body>").unwrap();
+    assert_eq!(injector.finish().unwrap(), b"<html><style>p{margin:0}</style><body><p>x</p></body>");
+}
+
+#[test]
+fn critical_css_map_test() {
+    let mut data_cache = DataCache::new(DataCacheOptions::default());
+    assert_eq!(data_cache.critical_css(DEFAULT_CRITICAL_CSS_PATH, &["/"]).unwrap(), None);
+    data_cache.insert(DEFAULT_CRITICAL_CSS_PATH, json!({"/": "body{margin:0}", "article": "h1{font-size:2em}", "/news/1.html": null, "bad": 1}));
+    assert_eq!(data_cache.critical_css(DEFAULT_CRITICAL_CSS_PATH, &["/", "article"]).unwrap(), Some("body{margin:0}"));
+    assert_eq!(data_cache.critical_css(DEFAULT_CRITICAL_CSS_PATH, &["/news/1.html", "article"]).unwrap(), Some("h1{font-size:2em}"));
+    assert_eq!(data_cache.critical_css(DEFAULT_CRITICAL_CSS_PATH, &["/about"]).unwrap(), None);
+    assert!(data_cache.critical_css(DEFAULT_CRITICAL_CSS_PATH, &["bad"]).is_err());
+}
+
+#[cfg(feature = "replace-engine")]
+#[test]
+fn critical_css_replace_test() {
+    let mut data_cache = DataCache::new(DataCacheOptions::default());
+    data_cache.insert(DEFAULT_CRITICAL_CSS_PATH, json!({"article": "h1{font-size:2em}"}));
+    data_cache.insert("title", json!("Hello"));
+    let css = data_cache.critical_css(DEFAULT_CRITICAL_CSS_PATH, &["/news/1", "article"]).unwrap().unwrap_or_default().to_string();
+    let mut injector = CriticalCssInjector::new(Vec::new(), &css);
+    let template = b"<head><link rel=stylesheet href=/main.css><title>{$title}</title></head>";
+    data_cache.replace_with_data_cache(template.as_slice(), &mut injector).unwrap();
+    assert_eq!(
+        String::from_utf8(injector.finish().unwrap()).unwrap(),
+        "<head><style>h1{font-size:2em}</style><link rel=\"preload\" href=\"/main.css\" as=\"style\" onload=\"this.onload=null;this.rel='stylesheet'\">\
+<noscript><link rel=stylesheet href=/main.css></noscript><title>Hello</title></head>"
+    );
+}
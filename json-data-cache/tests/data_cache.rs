@@ -1,6 +1,7 @@
+#[cfg(feature = "replace-engine")]
 use std::io::BufWriter;
 
-use json_data_cache::{DataCache, DataCacheOptions};
+use json_data_cache::{DataCache, DataCacheOptions, path_pattern::PathPattern};
 use serde_json::{Value, json};
 
 #[test]
@@ -126,19 +127,22 @@ fn data_cache() {
         "non_object" // Unaffected
     ])));
 
-    // Test replacements
-    for (input, replacement) in [
-        ("{$basic_key.nested_key}", "nested_value"),
-        ("{$a.b}", r#"{"c":"my_c_value","d":"my_d_value","e":"my_e_value"}"#),
-        ("{$$basic_key.nested_key}", r#"nested_value"#),
-        ("{$$a.b}", r#"{\"c\":\"my_c_value\",\"d\":\"my_d_value\",\"e\":\"my_e_value\"}"#)
-    ] {
-        let reader = String::from(input);
-        let mut writer = BufWriter::new(Vec::new());
-        let data_cache_ref = &mut data_cache;
-        assert!(data_cache_ref.replace_with_data_cache(reader.as_bytes(), &mut writer).is_ok());
-        let writer_string = String::from_utf8(writer.buffer().to_vec()).unwrap();
-        assert_eq!(&writer_string, replacement);
+    #[cfg(feature = "replace-engine")]
+    {
+        // Test replacements
+        for (input, replacement) in [
+            ("{$basic_key.nested_key}", "nested_value"),
+            ("{$a.b}", r#"{"c":"my_c_value","d":"my_d_value","e":"my_e_value"}"#),
+            ("{$$basic_key.nested_key}", r#"nested_value"#),
+            ("{$$a.b}", r#"{\"c\":\"my_c_value\",\"d\":\"my_d_value\",\"e\":\"my_e_value\"}"#)
+        ] {
+            let reader = String::from(input);
+            let mut writer = BufWriter::new(Vec::new());
+            let data_cache_ref = &mut data_cache;
+            assert!(data_cache_ref.replace_with_data_cache(reader.as_bytes(), &mut writer).is_ok());
+            let writer_string = String::from_utf8(writer.buffer().to_vec()).unwrap();
+            assert_eq!(&writer_string, replacement);
+        }
     }
 }
 
@@ -177,8 +181,8 @@ fn data_cache_get_list_test() {
         }),
     ]));
     assert_eq!(data_cache.get_list("list.*"), data_cache.get("list")
-        .map(|v| v.as_array().unwrap().iter().collect())
-        .unwrap_or(Vec::new())); // Technically this is a shortcut
+        .map(|v| v.as_array().unwrap().iter().collect::<Vec<&Value>>())
+        .unwrap_or_default()); // Technically this is a shortcut
     
     assert_eq!(data_cache.get_list("list.*.id"), Vec::from([
         &json!(1),
@@ -196,4 +200,164 @@ fn data_cache_get_list_test() {
     // For now, only one wildcard is supported
     assert_eq!(data_cache.get_list("list.*.*"), Vec::<&Value>::new());
     assert_eq!(data_cache.get_list("list*"), Vec::<&Value>::new());
-}
\ No newline at end of file
+}
+
+#[test]
+fn data_cache_get_all_test() {
+    let mut data_cache = DataCache::new(DataCacheOptions::default());
+    data_cache.insert("items", json!([{"title": "a", "tags": [{"title": "t"}]}, {"id": 2}, {"title": "c"}]));
+    data_cache.insert("pages", json!({"top": {"title": "Top"}, "about": {"meta": {"title": "About"}}}));
+
+    let paths = |pattern: &str| -> Vec<(String, Value)> {
+        data_cache.get_all(pattern).into_iter().map(|(path, value)| (path, value.clone())).collect()
+    };
+    assert_eq!(paths("items.*.title"), [("items.0.title".to_string(), json!("a")), ("items.2.title".to_string(), json!("c"))]);
+    assert_eq!(paths("pages.*.title"), [("pages.top.title".to_string(), json!("Top"))]);
+    assert_eq!(paths("pages.**.title"), [("pages.top.title".to_string(), json!("Top")), ("pages.about.meta.title".to_string(), json!("About"))]);
+    assert_eq!(paths("items.**.title").len(), 3);
+    assert_eq!(paths("**.title").len(), 5);
+    assert_eq!(paths("items.1"), [("items.1".to_string(), json!({"id": 2}))]);
+    assert_eq!(paths("items.*.missing"), []);
+    assert_eq!(paths("*").len(), 2);
+    assert_eq!(paths("**").len(), 16); // Every node but the root
+
+    let pattern = PathPattern::from("content.**.title");
+    assert!(pattern.is_match("content.title") && pattern.is_match("content.news.0.title"));
+    assert!(!pattern.is_match("content.news") && !pattern.is_match("other.title"));
+    assert!(pattern.covers("content.news.title.text") && !pattern.covers("content.news"));
+    assert!(pattern.overlaps("content.news") && !pattern.overlaps("other"));
+    assert!(pattern.has_wildcards() && !PathPattern::from("content.title").has_wildcards());
+}
+
+#[test]
+fn max_depth() {
+    let nested = |depth: usize| (0..depth).fold(json!("leaf"), |value, _| Value::Array(Vec::from([value])));
+
+    let mut data_cache = DataCache::new(DataCacheOptions {
+        max_depth: Some(8),
+        ..Default::default()
+    });
+    assert!(data_cache.try_insert("a.b", nested(6)).is_ok());
+    assert!(data_cache.try_insert("a.b", nested(7)).is_err());
+    assert!(data_cache.try_insert("a.b.c.d.e.f.g.h.i", json!(1)).is_err());
+    assert!(data_cache.try_merge(json!({"c": nested(8)})).is_err());
+    data_cache.insert("d", nested(10)); // Ignored
+    assert_eq!(data_cache.get("d"), None);
+    assert_eq!(data_cache.get("a.b.0.0.0.0.0.0"), Some(&json!("leaf")));
+
+    // The default limit protects against pathological inputs
+    let mut data_cache = DataCache::new(DataCacheOptions::default());
+    assert!(data_cache.try_insert("deep", nested(2_000)).is_err());
+    data_cache.root["deep"] = nested(2_000);
+    #[cfg(feature = "replace-engine")]
+    assert!(data_cache.replace_with_data_cache("{$deep}".as_bytes(), Vec::new()).is_err());
+
+    // Views serialize the tree without recursing, so a value set directly in root does not overflow the stack either
+    #[cfg(feature = "serializer")]
+    {
+        data_cache.root["deep"] = nested(4_000);
+        let leaf_path = format!("deep{}", ".0".repeat(4_000));
+        assert_eq!(data_cache.keys_with_prefix("deep").len(), 4_001);
+        assert_eq!(data_cache.string_values_view().get(&leaf_path), Some(&"leaf".to_string()));
+        assert_eq!(data_cache.serialized_range("deep.0").map(|(value, _)| value.len()), Some(2 * 3_999 + "\"leaf\"".len()));
+    }
+    // Dropping the value would recurse through it as well
+    let mut deep = data_cache.root["deep"].take();
+    while let Value::Array(mut a) = deep {
+        deep = a.pop().unwrap_or_default();
+    }
+}
+
+#[test]
+fn data_cache_try_replace_test() {
+    let mut data_cache = DataCache::new(DataCacheOptions {
+        max_depth: Some(4),
+        ..Default::default()
+    });
+    data_cache.insert("a", json!({"b": 1, "c": 2}));
+    assert!(data_cache.try_replace("a", json!({"d": 3})).is_ok());
+    assert_eq!(data_cache.get("a"), Some(&json!({"d": 3})));
+    data_cache.insert("a", json!({"e": 4}));
+    assert_eq!(data_cache.get("a"), Some(&json!({"d": 3, "e": 4})));
+
+    // A rejected value leaves the previous one untouched
+    assert!(data_cache.try_replace("a", json!({"f": {"g": {"h": {"i": 1}}}})).is_err());
+    assert_eq!(data_cache.get("a"), Some(&json!({"d": 3, "e": 4})));
+}
+
+#[test]
+fn data_cache_get_many_test() {
+    let mut data_cache = DataCache::new(DataCacheOptions::default());
+    data_cache.insert("content", json!({"title": "Hello", "tags": ["a", "b"], "author": {"name": "Taro"}}));
+    data_cache.insert("seo.description", json!("Description"));
+    assert!(data_cache.insert_ref("headline", "content.title").is_ok());
+
+    let targets = ["content.title", "content.tags.1", "content.tags.01", "content.author.name", "content.missing.name", "seo.description", "headline", ""];
+    let expected: Vec<Option<&Value>> = targets.iter().map(|target| data_cache.get(target)).collect();
+    assert_eq!(data_cache.get_many(&targets), expected);
+    assert_eq!(data_cache.get_many(&["content.tags.1", "content.tags.0"]), [Some(&json!("b")), Some(&json!("a"))]);
+
+    let map = data_cache.get_subtree_map("content");
+    let mut keys: Vec<&str> = map.keys().map(String::as_str).collect();
+    keys.sort_unstable();
+    assert_eq!(keys, ["content.author", "content.author.name", "content.tags", "content.tags.0", "content.tags.1", "content.title"]);
+    assert_eq!(map.get("content.author.name"), Some(&&json!("Taro")));
+    assert_eq!(data_cache.get_subtree_map("").len(), 9);
+    assert!(data_cache.get_subtree_map("missing").is_empty());
+}
+
+#[test]
+fn data_cache_get_segments_test() {
+    let mut data_cache = DataCache::new(DataCacheOptions::default());
+    data_cache.insert("links", json!({"a/b": "slash", "c~1": "tilde", "list": [10, 20]}));
+    // Segments are keys as is, without pointer escapes
+    assert_eq!(data_cache.get("links.a/b"), Some(&json!("slash")));
+    assert_eq!(data_cache.get("links.c~1"), Some(&json!("tilde")));
+    assert_eq!(data_cache.get("links.list.1"), Some(&json!(20)));
+    assert_eq!(data_cache.get("links.list.01"), None);
+    assert_eq!(data_cache.get("links.list.+1"), None);
+    assert_eq!(data_cache.get_list("links.list.*"), [&json!(10), &json!(20)]);
+}
+
+#[test]
+fn data_cache_remove_test() {
+    let mut data_cache = DataCache::new(DataCacheOptions::default());
+    data_cache.insert("news", json!({"items": [{"id": 1}, {"id": 2}, {"id": 3}], "meta": {"page": {"index": 1}}}));
+
+    assert_eq!(data_cache.remove("news.items.0"), Some(json!({"id": 1})));
+    assert_eq!(data_cache.get("news.items.0.id"), Some(&json!(2))); // Later items are shifted
+    assert_eq!(data_cache.remove("news.items.5"), None);
+    assert_eq!(data_cache.remove("news.missing.id"), None);
+
+    // Without pruning, empty parents are kept
+    assert_eq!(data_cache.remove("news.meta.page.index"), Some(json!(1)));
+    assert_eq!(data_cache.get("news.meta"), Some(&json!({"page": {}})));
+    data_cache.insert("news.meta.page.index", json!(2));
+    assert_eq!(data_cache.remove_pruned("news.meta.page.index"), Some(json!(2)));
+    assert_eq!(data_cache.get("news.meta"), None);
+    assert_eq!(data_cache.remove_pruned("news.items.1"), Some(json!({"id": 3})));
+    assert_eq!(data_cache.remove_pruned("news.items.0"), Some(json!({"id": 2})));
+    assert_eq!(data_cache.root, json!({}));
+
+    data_cache.insert("a", json!(1));
+    assert_eq!(data_cache.remove(""), Some(json!({"a": 1})));
+    assert_eq!(data_cache.root, json!({}));
+}
+
+#[cfg(feature = "replace-engine")]
+#[test]
+fn data_cache_remove_replace_test() {
+    let mut data_cache = DataCache::new(DataCacheOptions::default());
+    data_cache.insert("title", json!("Hello"));
+    data_cache.insert("list", json!(["a", "b"]));
+    let mut output = Vec::new();
+    data_cache.replace_with_data_cache("{$title} {$list.0}".as_bytes(), &mut output).unwrap();
+    assert_eq!(output, b"Hello a");
+
+    // The serialized data is rebuilt without the removed values
+    data_cache.remove("title");
+    data_cache.remove("list.0");
+    let mut output = Vec::new();
+    data_cache.replace_with_data_cache("{$title} {$list.0}".as_bytes(), &mut output).unwrap();
+    assert_eq!(output, b"{$title} b");
+}
@@ -0,0 +1,61 @@
+use json_data_cache::{DataCache, DataCacheOptions, decisions::DecisionLog};
+use serde_json::json;
+
+#[test]
+fn decision_log_buffer_test() {
+    let mut data_cache = DataCache::new(DataCacheOptions::default());
+    data_cache.log_decision("flag", "ignored", json!(true)).unwrap();
+    assert!(data_cache.drain_decisions().is_empty());
+
+    data_cache.set_decision_log(DecisionLog::buffered().request_id("req-1"));
+    data_cache.log_decision("experiment", "checkout", json!("B")).unwrap();
+    data_cache.log_decision("redirect", "/old", json!({"to": "/new", "status": 301})).unwrap();
+    assert_eq!(data_cache.drain_decisions(), vec![
+        json!({"kind": "experiment", "name": "checkout", "detail": "B", "request_id": "req-1"}),
+        json!({"kind": "redirect", "name": "/old", "detail": {"to": "/new", "status": 301}, "request_id": "req-1"})
+    ]);
+    assert!(data_cache.drain_decisions().is_empty());
+    assert!(data_cache.take_decision_log().is_some());
+    data_cache.log_decision("flag", "ignored", json!(true)).unwrap();
+    assert!(data_cache.drain_decisions().is_empty());
+}
+
+#[cfg(feature = "replace-engine")]
+#[test]
+fn decision_log_placeholders_test() {
+    use std::{cell::RefCell, rc::Rc};
+
+    let entries = Rc::new(RefCell::new(Vec::new()));
+    let mut data_cache = DataCache::new(DataCacheOptions::default());
+    data_cache.insert("name", json!("Emma"));
+    data_cache.insert("city", json!("Tokyo"));
+    let sink = entries.clone();
+    data_cache.set_decision_log(DecisionLog::to_callback(move |entry| sink.borrow_mut().push(entry.clone())).log_placeholders(true));
+
+    let mut output = Vec::new();
+    data_cache.replace_with_data_cache(b"{$name} {$missing} {$name} {$city}".as_slice(), &mut output).unwrap();
+    assert_eq!(output, b"Emma {$missing} Emma Tokyo");
+    assert_eq!(*entries.borrow(), vec![
+        json!({"kind": "placeholder", "name": "{$name}", "detail": {"count": 2}}),
+        json!({"kind": "placeholder", "name": "{$city}", "detail": {"count": 1}})
+    ]);
+}
+
+#[cfg(feature = "replace-engine")]
+#[test]
+fn decision_log_no_rebuild_test() {
+    let mut data_cache = DataCache::new(DataCacheOptions::default());
+    data_cache.insert("name", json!("Emma"));
+    data_cache.set_decision_log(DecisionLog::buffered().log_placeholders(true));
+
+    let mut builds = 0;
+    data_cache.prepare_with_yield(|| builds += 1).unwrap();
+    for _ in 0..2 {
+        let mut output = Vec::new();
+        data_cache.replace_with_data_cache(b"{$name}".as_slice(), &mut output).unwrap();
+        assert_eq!(output, b"Emma");
+        assert_eq!(data_cache.drain_decisions().len(), 1);
+        data_cache.prepare_with_yield(|| builds += 1).unwrap();
+    }
+    assert_eq!(builds, 1);
+}
@@ -0,0 +1,64 @@
+use std::{rc::Rc, time::{Duration, SystemTime}};
+
+use json_data_cache::{DataCache, DataCacheOptions, degraded::DegradedMode, runtime::ManualClock};
+use serde_json::json;
+
+#[test]
+fn degrade_and_recover_test() {
+    let since = SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+    let mut data_cache = DataCache::new(DataCacheOptions { clock: Some(Rc::new(ManualClock::new(since))), ..Default::default() });
+    data_cache.declare_dynamic("user.name");
+    data_cache.insert("nav", json!([{"title": "News", "href": "/news"}]));
+    data_cache.insert("recommendations", json!({"top": ["a", "b"], "sidebar": ["c"]}));
+    data_cache.insert("user.name", json!("Taro"));
+    data_cache.insert("site.name", json!("Kuroco"));
+    data_cache.set_degraded_mode(DegradedMode::new()
+        .fallback("nav", json!([{"title": "Home", "href": "/"}]))
+        .disable("recommendations.*")
+        .disable_dynamic(true));
+    assert!(data_cache.degraded_status().is_none());
+    assert!(data_cache.recover().is_ok()); // Not degraded
+
+    assert!(data_cache.degrade("origin-down").is_ok());
+    assert_eq!(data_cache.get("nav.0.title"), Some(&json!("Home")));
+    assert_eq!(data_cache.get("recommendations"), Some(&json!({"top": "", "sidebar": ""})));
+    assert_eq!(data_cache.get("user.name"), Some(&json!("")));
+    assert_eq!(data_cache.get("site.name"), Some(&json!("Kuroco")));
+    assert_eq!(data_cache.get("degraded.reason"), Some(&json!("origin-down")));
+    let status = data_cache.degraded_status().unwrap();
+    assert_eq!(status.since, since);
+    assert_eq!(status.substituted, ["nav"]);
+    assert_eq!(status.disabled, ["recommendations.sidebar", "recommendations.top", "user.name"]);
+    assert_eq!(status.header_value(), "origin-down; substituted=1; disabled=3");
+
+    assert!(data_cache.degrade("api timeout").is_ok()); // Only updates the reason
+    assert_eq!(data_cache.get("degraded.reason"), Some(&json!("api timeout")));
+    assert_eq!(data_cache.degraded_status().unwrap().header_value(), "apitimeout; substituted=1; disabled=3");
+
+    assert!(data_cache.recover().is_ok());
+    assert!(data_cache.degraded_status().is_none());
+    assert_eq!(data_cache.get("nav.0.title"), Some(&json!("News")));
+    assert_eq!(data_cache.get("recommendations.top"), Some(&json!(["a", "b"])));
+    assert_eq!(data_cache.get("user.name"), Some(&json!("Taro")));
+    assert_eq!(data_cache.get("degraded"), Some(&json!(null)));
+}
+
+#[cfg(feature = "replace-engine")]
+#[test]
+fn degraded_replace_test() {
+    let mut data_cache = DataCache::new(DataCacheOptions::default());
+    data_cache.insert("menu", json!("Live menu"));
+    data_cache.insert("ads", json!("Ad"));
+    data_cache.set_degraded_mode(DegradedMode::new().fallback("menu", json!("Static menu")).disable("ads").status_path("status"));
+    let template = b"{$menu}|{$ads}|{$status.reason}";
+
+    assert!(data_cache.degrade("maintenance").is_ok());
+    let mut output = Vec::new();
+    assert!(data_cache.replace_with_data_cache(template.as_slice(), &mut output).is_ok());
+    assert_eq!(output, b"Static menu||maintenance");
+
+    assert!(data_cache.recover().is_ok());
+    let mut output = Vec::new();
+    assert!(data_cache.replace_with_data_cache(template.as_slice(), &mut output).is_ok());
+    assert_eq!(output, b"Live menu|Ad|{$status.reason}"); // Unknown once recovered
+}
@@ -0,0 +1,53 @@
+use json_data_cache::delta::{DELTA_MAGIC, apply_delta, encode_delta};
+
+fn page(title: &str, greeting: &str) -> Vec<u8> {
+    let items: String = (0..50).map(|idx| format!("<li class=\"item\"><a href=\"/news/{idx}\">{greeting} {idx}</a></li>\n")).collect();
+    format!("<html><head><title>{title}</title></head><body><ul>\n{items}</ul></body></html>").into_bytes()
+}
+
+#[test]
+fn delta_test() {
+    let ja = page("ニュース", "こんにちは");
+    let en = page("News", "Hello");
+    let delta = encode_delta(&ja, &en);
+    assert!(delta.starts_with(DELTA_MAGIC));
+    assert!(delta.len() * 5 < en.len(), "{} bytes for {}", delta.len(), en.len());
+    assert_eq!(apply_delta(&ja, &delta).unwrap(), en);
+    assert_eq!(apply_delta(&en, &encode_delta(&en, &ja)).unwrap(), ja);
+
+    for (base, target) in [
+        (&b""[..], &b""[..]),
+        (b"", b"only added"),
+        (b"only removed", b""),
+        (b"same bytes, copied whole", b"same bytes, copied whole"),
+        (b"short", b"shorter"),
+    ] {
+        assert_eq!(apply_delta(base, &encode_delta(base, target)).unwrap(), target);
+    }
+
+    // Pseudo random bytes, mostly added
+    let mut state = 7u32;
+    let noise: Vec<u8> = (0..2000).map(|_| {
+        state = state.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+        (state >> 16) as u8
+    }).collect();
+    let mut mixed = noise[500..1500].to_vec();
+    mixed.extend_from_slice(&en);
+    assert_eq!(apply_delta(&noise, &encode_delta(&noise, &mixed)).unwrap(), mixed);
+}
+
+#[test]
+fn delta_error_test() {
+    let ja = page("ニュース", "こんにちは");
+    let en = page("News", "Hello");
+    let delta = encode_delta(&ja, &en);
+    assert!(apply_delta(&en, &delta).unwrap_err().msg.contains("another base"));
+    assert!(apply_delta(&ja, b"not a delta").is_err());
+    for len in [4, 8, delta.len() / 2, delta.len() - 1] {
+        assert!(apply_delta(&ja, &delta[..len]).is_err(), "{len}");
+    }
+    let mut corrupted = delta.clone();
+    let last = corrupted.len() - 1;
+    corrupted[last] ^= 1;
+    assert!(apply_delta(&ja, &corrupted).is_err());
+}
@@ -0,0 +1,78 @@
+#![cfg(feature = "replace-engine")]
+
+use std::fs;
+
+use json_data_cache::{DataCache, DataCacheOptions, bundle::{Bundle, BundleBuilder}, export::{ExportRoute, ExportedPage, route_file_path}};
+use serde_json::json;
+
+fn site_bundle() -> Bundle {
+    let bytes = BundleBuilder::new()
+        .template("home", b"<h1>{$site.name}</h1>")
+        .template("article", b"<title>{$article.title} - {$site.name}</title>")
+        .template("feed", b"<rss>{$site.name}</rss>")
+        .data("site", json!({"name": "Kuroco"}))
+        .to_bytes()
+        .unwrap();
+    let mut bundle = Bundle::load(&bytes).unwrap();
+    bundle.data_cache.prepare().unwrap();
+    bundle
+}
+
+#[test]
+fn route_file_path_test() {
+    for (route, file) in [
+        ("/", "index.html"),
+        ("/news/1", "news/1/index.html"),
+        ("/news/1/", "news/1/index.html"),
+        ("/feed.xml", "feed.xml"),
+        ("/v1.0/", "v1.0/index.html"),
+    ] {
+        assert_eq!(route_file_path(route).unwrap(), file, "{route}");
+    }
+    for route in ["", "news", "/news/../secret", "/news//1", "/search?q=1"] {
+        assert!(route_file_path(route).is_err(), "{route}");
+    }
+}
+
+#[test]
+fn export_test() {
+    let bundle = site_bundle();
+    let routes = ExportRoute::from_json(&json!([
+        {"path": "/", "template": "home"},
+        {"path": "/news/1", "template": "article", "data": {"article": {"title": "Hello"}}},
+        {"path": "/news/2", "template": "article", "data": {"article": {"title": "Again"}, "site": {"name": "Override"}}},
+        {"path": "/feed.xml", "template": "feed"},
+    ])).unwrap();
+    assert!(ExportRoute::from_json(&json!([{"path": "/"}])).unwrap_err().msg.contains("template"));
+
+    let dir = std::env::temp_dir().join(format!("json-data-cache-export-{}", std::process::id()));
+    let pages = bundle.export_to_dir(&routes, &dir).unwrap();
+    assert_eq!(pages[1], ExportedPage { route: String::from("/news/1"), file: String::from("news/1/index.html"), len: 29 });
+    assert_eq!(fs::read_to_string(dir.join("index.html")).unwrap(), "<h1>Kuroco</h1>");
+    assert_eq!(fs::read_to_string(dir.join("news/1/index.html")).unwrap(), "<title>Hello - Kuroco</title>");
+    assert_eq!(fs::read_to_string(dir.join("news/2/index.html")).unwrap(), "<title>Again - Override</title>");
+    assert_eq!(fs::read_to_string(dir.join("feed.xml")).unwrap(), "<rss>Kuroco</rss>");
+    fs::remove_dir_all(dir).unwrap();
+
+    // Same pages as the edge renders
+    let mut request_cache = DataCache::new(DataCacheOptions::default());
+    request_cache.insert("article", json!({"title": "Hello"}));
+    let mut output = Vec::new();
+    bundle.render_with("article", &mut request_cache, &mut output).unwrap();
+    assert_eq!(output, b"<title>Hello - Kuroco</title>");
+
+    let mut archive = Vec::new();
+    let pages = bundle.export_to_tar(&routes, &mut archive).unwrap();
+    assert_eq!(pages.iter().map(|page| page.file.as_str()).collect::<Vec<_>>(), ["index.html", "news/1/index.html", "news/2/index.html", "feed.xml"]);
+    assert_eq!(archive.len(), 512 * (4 * 2 + 2));
+    assert_eq!(&archive[..10], b"index.html");
+    assert_eq!(&archive[512..527], b"<h1>Kuroco</h1>");
+
+    for routes in [
+        Vec::from([ExportRoute::new("/", "missing", json!(null))]),
+        Vec::from([ExportRoute::new("/", "home", json!([1]))]),
+        Vec::from([ExportRoute::new("/a", "home", json!(null)), ExportRoute::new("/a/", "home", json!(null))]),
+    ] {
+        assert!(bundle.export(&routes, |_, _| Ok(())).is_err(), "{routes:?}");
+    }
+}
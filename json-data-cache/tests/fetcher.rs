@@ -0,0 +1,173 @@
+use std::{cell::RefCell, rc::Rc, time::Duration};
+
+use json_data_cache::{DataCache, DataCacheOptions, error::JsonDataCacheError, fetcher::{DataSource, FetchRequest, FetchResponse, Fetcher, HttpClient}};
+use serde_json::json;
+
+#[test]
+fn fetcher_test() {
+    let requests = Rc::new(RefCell::new(Vec::new()));
+    let client_requests = requests.clone();
+    let mut fetcher = Fetcher::new(move |request: &FetchRequest| {
+        client_requests.borrow_mut().push(request.url.clone());
+        let body = match request.url.as_str() {
+            "https://origin/member?id=a%2Fb%20c" => json!({"name": "my_name", "rank": 1}),
+            "https://origin/settings" => json!({"lang": "ja"}),
+            "https://origin/broken" => return Ok(FetchResponse { status: 500, body: Vec::new() }),
+            _ => json!({"id": request.url}),
+        };
+        Ok(FetchResponse { status: 200, body: serde_json::to_vec(&body).unwrap() })
+    });
+    fetcher.add_source(DataSource::new("member", "https://origin/member?id={$request.member_id}").header("Authorization", "token"));
+    fetcher.add_source(DataSource::new("settings", "https://origin/settings"));
+    fetcher.add_source(DataSource::new("settings_copy", "https://origin/settings").ttl(Duration::from_secs(60)));
+    fetcher.add_source(DataSource::new("broken", "https://origin/broken"));
+    assert_eq!(fetcher.sources()[0].dependencies(), ["request.member_id"]);
+
+    let mut data_cache = DataCache::new(DataCacheOptions::default());
+    assert!(fetcher.ensure(&mut data_cache, "member.name").is_err()); // Placeholder without value
+    data_cache.insert("request.member_id", json!("a/b c"));
+    assert!(fetcher.ensure(&mut data_cache, "member.name").unwrap());
+    assert_eq!(data_cache.get("member.name"), Some(&json!("my_name")));
+    assert!(!fetcher.ensure(&mut data_cache, "member").unwrap()); // Already fetched
+
+    // Sources sharing the same request are fetched once
+    assert!(fetcher.ensure(&mut data_cache, "settings").unwrap());
+    assert!(fetcher.ensure(&mut data_cache, "settings_copy").unwrap());
+    assert_eq!(data_cache.get("settings_copy.lang"), Some(&json!("ja")));
+    assert_eq!(requests.borrow().len(), 2);
+
+    // A new placeholder value changes the request, and the new response replaces the previous one
+    data_cache.insert("request.member_id", json!("other"));
+    assert!(fetcher.ensure(&mut data_cache, "member").unwrap());
+    assert_eq!(data_cache.get("member"), Some(&json!({"id": "https://origin/member?id=other"})));
+
+    assert!(fetcher.ensure(&mut data_cache, "broken").is_err());
+    assert!(fetcher.ensure(&mut data_cache, "unknown").is_err());
+    assert_eq!(*requests.borrow(), [
+        "https://origin/member?id=a%2Fb%20c",
+        "https://origin/settings",
+        "https://origin/member?id=other",
+        "https://origin/broken",
+    ]);
+}
+
+#[test]
+fn fetch_plan_test() {
+    struct BatchClient {
+        batches: Vec<Vec<String>>,
+    }
+    impl HttpClient for BatchClient {
+        fn fetch(&mut self, request: &FetchRequest) -> Result<FetchResponse, JsonDataCacheError> {
+            let body = match request.url.as_str() {
+                "https://origin/member" => json!({"id": 7, "segment": "gold"}),
+                "https://origin/campaigns" => json!({"current": "spring"}),
+                _ => json!({"url": request.url}),
+            };
+            Ok(FetchResponse { status: 200, body: serde_json::to_vec(&body).unwrap() })
+        }
+
+        fn fetch_all(&mut self, requests: &[FetchRequest]) -> Vec<Result<FetchResponse, JsonDataCacheError>> {
+            self.batches.push(requests.iter().map(|request| request.url.clone()).collect());
+            requests.iter().map(|request| self.fetch(request)).collect()
+        }
+    }
+
+    let mut fetcher = Fetcher::new(BatchClient { batches: Vec::new() });
+    fetcher.add_source(DataSource::new("recommendations", "https://origin/recommendations?member={$member.id}&campaign={$campaign.current}"));
+    fetcher.add_source(DataSource::new("history", "https://origin/history?member={$member.id}"));
+    fetcher.add_source(DataSource::new("member", "https://origin/member"));
+    fetcher.add_source(DataSource::new("campaign", "https://origin/campaigns"));
+    fetcher.add_source(DataSource::new("unused", "https://origin/unused"));
+
+    let plan = fetcher.plan(&["recommendations", "history"]).unwrap();
+    assert_eq!(plan.stages, [Vec::from(["member", "campaign"]), Vec::from(["recommendations", "history"])]);
+
+    let mut data_cache = DataCache::new(DataCacheOptions::default());
+    assert_eq!(fetcher.ensure_all(&mut data_cache, &["recommendations", "history"]).unwrap(), 4);
+    assert_eq!(data_cache.get("recommendations.url"), Some(&json!("https://origin/recommendations?member=7&campaign=spring")));
+    assert_eq!(data_cache.get("history.url"), Some(&json!("https://origin/history?member=7")));
+    // Up to date : nothing fetched again
+    assert_eq!(fetcher.ensure_all(&mut data_cache, &["recommendations"]).unwrap(), 0);
+    assert_eq!(fetcher.client().batches, [
+        Vec::from(["https://origin/member", "https://origin/campaigns"]),
+        Vec::from(["https://origin/recommendations?member=7&campaign=spring", "https://origin/history?member=7"]),
+    ]);
+
+    fetcher.add_source(DataSource::new("a", "https://origin/a?b={$b.id}"));
+    fetcher.add_source(DataSource::new("b", "https://origin/b?a={$a.id}"));
+    assert!(fetcher.plan(&["a"]).is_err());
+    assert!(fetcher.plan(&["unknown"]).is_err());
+}
+
+#[test]
+fn fetcher_invalidate_test() {
+    let fetch_count = Rc::new(RefCell::new(0));
+    let client_fetch_count = fetch_count.clone();
+    let mut fetcher = Fetcher::new(move |_: &FetchRequest| {
+        *client_fetch_count.borrow_mut() += 1;
+        Ok(FetchResponse { status: 200, body: Vec::from(*br#"{"title": "Title"}"#) })
+    });
+    fetcher.add_source(DataSource::new("content", "https://origin/content"));
+
+    let mut data_cache = DataCache::new(DataCacheOptions::default());
+    assert!(fetcher.ensure(&mut data_cache, "content").unwrap());
+    assert!(!fetcher.ensure(&mut data_cache, "content").unwrap());
+    // Evicted by a content update webhook
+    data_cache.invalidate("content");
+    fetcher.invalidate("content");
+    assert!(fetcher.ensure(&mut data_cache, "content.title").unwrap());
+    assert_eq!(data_cache.get("content.title"), Some(&json!("Title")));
+    assert_eq!(*fetch_count.borrow(), 2);
+}
+
+#[test]
+fn circuit_breaker_test() {
+    use std::time::SystemTime;
+
+    use json_data_cache::{fetcher::{CircuitBreakerPolicy, CircuitState}, runtime::ManualClock};
+
+    let is_up = Rc::new(RefCell::new(true));
+    let client_is_up = is_up.clone();
+    let requests = Rc::new(RefCell::new(0));
+    let client_requests = requests.clone();
+    let policy = CircuitBreakerPolicy::new(2, Duration::from_secs(30)).health_path("health");
+    let mut fetcher = Fetcher::new(move |_: &FetchRequest| {
+        *client_requests.borrow_mut() += 1;
+        let status = if *client_is_up.borrow() { 200 } else { 503 };
+        Ok(FetchResponse { status, body: br#"{"items": [1]}"#.to_vec() })
+    }).circuit_breaker(policy);
+    fetcher.add_source(DataSource::new("news", "https://origin/news").ttl(Duration::from_secs(10)));
+    fetcher.add_source(DataSource::new("banner", "https://origin/banner").fallback(json!({"html": ""})));
+
+    let clock = Rc::new(ManualClock::new(SystemTime::UNIX_EPOCH + Duration::from_secs(1_000_000)));
+    let mut data_cache = DataCache::new(DataCacheOptions { clock: Some(clock.clone()), ..Default::default() });
+    assert!(fetcher.ensure(&mut data_cache, "news").unwrap());
+    assert_eq!(data_cache.get("health.news.state"), Some(&json!("closed")));
+
+    *is_up.borrow_mut() = false;
+    clock.advance(Duration::from_secs(10));
+    assert!(fetcher.ensure(&mut data_cache, "news").is_err());
+    assert!(fetcher.ensure(&mut data_cache, "news").is_err());
+    assert!(matches!(fetcher.health("news").unwrap().state, CircuitState::Open { .. }));
+    assert_eq!(data_cache.get("health.news.failure_rate"), Some(&json!(2.0 / 3.0)));
+    // Open : no request, the stale value is kept & sources without value use their fallback
+    assert!(!fetcher.ensure(&mut data_cache, "news").unwrap());
+    assert_eq!(data_cache.get("news.items"), Some(&json!([1])));
+    assert_eq!(*requests.borrow(), 3);
+    assert!(fetcher.ensure(&mut data_cache, "banner").is_err());
+    assert!(fetcher.ensure(&mut data_cache, "banner").is_err());
+    assert!(fetcher.ensure(&mut data_cache, "banner").unwrap());
+    assert_eq!(data_cache.get("banner"), Some(&json!({"html": ""})));
+    assert_eq!(*requests.borrow(), 5);
+
+    // Half-open after the cooldown : a failed trial opens the circuit again, a successful one closes it
+    clock.advance(Duration::from_secs(30));
+    assert!(fetcher.ensure(&mut data_cache, "news").is_err());
+    assert!(!fetcher.ensure(&mut data_cache, "news").unwrap());
+    assert_eq!(*requests.borrow(), 6);
+    clock.advance(Duration::from_secs(30));
+    *is_up.borrow_mut() = true;
+    assert!(fetcher.ensure(&mut data_cache, "news").unwrap());
+    assert_eq!(fetcher.health("news").unwrap().state, CircuitState::Closed);
+    assert_eq!(data_cache.get("health.news.state"), Some(&json!("closed")));
+}
@@ -0,0 +1,41 @@
+#![cfg(feature = "ffi")]
+
+use std::ptr;
+
+use json_data_cache::ffi::{
+    JSON_DATA_CACHE_ERR_JSON, JSON_DATA_CACHE_ERR_NOT_FOUND, JSON_DATA_CACHE_ERR_REJECTED, JSON_DATA_CACHE_OK, JsonDataCacheBuffer,
+    json_data_cache_buffer_free, json_data_cache_free, json_data_cache_get, json_data_cache_insert, json_data_cache_new,
+    json_data_cache_replace,
+};
+
+fn buffer_string(buffer: &JsonDataCacheBuffer) -> String {
+    String::from_utf8(unsafe { std::slice::from_raw_parts(buffer.data, buffer.len) }.to_vec()).unwrap()
+}
+
+#[test]
+fn ffi_test() {
+    let cache = json_data_cache_new();
+    let path = "a.b";
+    let json = r#"{"c": "my_c_value"}"#;
+    unsafe {
+        assert_eq!(json_data_cache_insert(cache, path.as_ptr(), path.len(), json.as_ptr(), json.len()), JSON_DATA_CACHE_OK);
+        assert_eq!(json_data_cache_insert(cache, path.as_ptr(), path.len(), "{".as_ptr(), 1), JSON_DATA_CACHE_ERR_JSON);
+        let deep_path = "a.".repeat(300) + "b";
+        assert_eq!(json_data_cache_insert(cache, deep_path.as_ptr(), deep_path.len(), "1".as_ptr(), 1), JSON_DATA_CACHE_ERR_REJECTED);
+
+        let mut out = JsonDataCacheBuffer { data: ptr::null_mut(), len: 0 };
+        assert_eq!(json_data_cache_get(cache, path.as_ptr(), path.len(), &mut out), JSON_DATA_CACHE_OK);
+        assert_eq!(buffer_string(&out), r#"{"c":"my_c_value"}"#);
+        json_data_cache_buffer_free(&mut out);
+        assert!(out.data.is_null());
+
+        assert_eq!(json_data_cache_get(cache, "x".as_ptr(), 1, &mut out), JSON_DATA_CACHE_ERR_NOT_FOUND);
+
+        let input = "value: {$a.b.c}";
+        assert_eq!(json_data_cache_replace(cache, input.as_ptr(), input.len(), &mut out), JSON_DATA_CACHE_OK);
+        assert_eq!(buffer_string(&out), "value: my_c_value");
+        json_data_cache_buffer_free(&mut out);
+
+        json_data_cache_free(cache);
+    }
+}
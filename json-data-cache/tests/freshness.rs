@@ -0,0 +1,39 @@
+use std::{cell::RefCell, rc::Rc, time::{Duration, SystemTime}};
+
+use json_data_cache::{DataCache, DataCacheOptions, Freshness, FreshnessMeta};
+use serde_json::json;
+
+#[test]
+fn freshness_test() {
+    let mut data_cache = DataCache::new(DataCacheOptions::default());
+    data_cache.insert("content", json!({"list": [1, 2], "title": "Title"}));
+    data_cache.insert("menu", json!(["home"]));
+    data_cache.insert("user", json!({"name": "my_name"}));
+
+    let now = SystemTime::now();
+    let minute = Duration::from_secs(60);
+    data_cache.set_freshness("content", FreshnessMeta { fresh_until: now - minute, stale_until: now + minute });
+    data_cache.set_freshness("menu", FreshnessMeta { fresh_until: now + minute, stale_until: now + minute * 2 });
+    data_cache.set_freshness("user", FreshnessMeta { fresh_until: now - minute * 2, stale_until: now - minute });
+
+    let revalidated = Rc::new(RefCell::new(Vec::new()));
+    let hook_revalidated = revalidated.clone();
+    data_cache.set_revalidate_hook(move |path| hook_revalidated.borrow_mut().push(path.to_string()));
+
+    assert_eq!(data_cache.get_with_freshness("menu.0"), Some((&json!("home"), Freshness::Fresh)));
+    assert_eq!(data_cache.get_with_freshness("user.name"), Some((&json!("my_name"), Freshness::Expired)));
+    assert_eq!(data_cache.get_with_freshness("content.title"), Some((&json!("Title"), Freshness::Stale)));
+    assert_eq!(data_cache.get_with_freshness("content.list.1"), Some((&json!(2), Freshness::Stale)));
+    assert_eq!(data_cache.get_with_freshness("content.unknown"), None);
+    // Revalidation is requested once for the subtree carrying the metadata
+    assert_eq!(*revalidated.borrow(), ["content"]);
+    assert_eq!(data_cache.freshness_meta("content.list").map(|(path, _)| path), Some("content"));
+
+    // Once revalidated, the subtree is fresh again, and could be revalidated later on
+    data_cache.insert("content.title", json!("New title"));
+    data_cache.set_freshness("content", FreshnessMeta { fresh_until: now + minute, stale_until: now + minute * 2 });
+    assert_eq!(data_cache.get_with_freshness("content.title"), Some((&json!("New title"), Freshness::Fresh)));
+    data_cache.set_freshness("content", FreshnessMeta { fresh_until: now - minute, stale_until: now + minute });
+    data_cache.get_with_freshness("content.title");
+    assert_eq!(*revalidated.borrow(), ["content", "content"]);
+}
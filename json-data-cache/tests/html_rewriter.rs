@@ -0,0 +1,40 @@
+use std::io::Write;
+
+use json_data_cache::html_rewriter::{HtmlRewriter, RewriteRule};
+
+fn rewrite(rules: &[RewriteRule], html: &str, chunk_len: usize) -> String {
+    let mut rewriter = HtmlRewriter::new(Vec::new(), rules.to_vec());
+    for chunk in html.as_bytes().chunks(chunk_len) {
+        rewriter.write_all(chunk).unwrap();
+    }
+    String::from_utf8(rewriter.finish().unwrap()).unwrap()
+}
+
+#[test]
+fn html_rewriter_test() {
+    let rules = [
+        RewriteRule::new("aside").remove(),
+        RewriteRule::new("script").unless("type", "json").remove(),
+        RewriteRule::new("section").when("class", "wide").rename("div"),
+        RewriteRule::new("img").rename("x-img").set_attribute("layout", "fill"),
+        RewriteRule::new("*").remove_attribute("on*"),
+        RewriteRule::new("a").set_attribute("rel", "noopener"),
+    ];
+    for (html, rewritten) in [
+        ("<p>keep</p>", "<p>keep</p>"),
+        ("a<aside>x<aside>nested</aside><b>y</b></aside>b", "ab"),
+        ("<script>if (a < b) { x('</p>') }</script><script type=\"application/json\">{\"a\": \"<b>\"}</script>", "<script type=\"application/json\">{\"a\": \"<b>\"}</script>"),
+        ("<section class=\"wide\"><section>in</section></section>", "<div class=\"wide\"><section>in</section></div>"),
+        ("<img src='/a.png' alt=\"\">", "<x-img src=\"/a.png\" alt layout=\"fill\"></x-img>"),
+        ("<button onclick=\"go()\" onmouseover='x' type=button>Go</button>", "<button type=\"button\">Go</button>"),
+        ("<a href=\"/x\" rel=\"external\">x</a>", "<a href=\"/x\" rel=\"noopener\">x</a>"),
+        ("<a title=\"x > y\" onclick='f(\">\")'>z</a>", "<a title=\"x > y\" rel=\"noopener\">z</a>"),
+        ("<!-- <aside>kept</aside> --><aside><!-- removed --></aside>", "<!-- <aside>kept</aside> -->"),
+        ("<textarea><aside>text</aside></textarea>", "<textarea><aside>text</aside></textarea>"),
+        ("a < b <!DOCTYPE html>", "a < b <!DOCTYPE html>"),
+    ] {
+        for chunk_len in [1, 3, 1024] {
+            assert_eq!(rewrite(&rules, html, chunk_len), rewritten, "{html} in chunks of {chunk_len}");
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use json_data_cache::hydration::{PatchOp, diff_html, patch_to_json};
+use serde_json::json;
+
+#[test]
+fn diff_html_test() {
+    let shell = br#"<!DOCTYPE html><html><body><header id="header"><a class="login" href="/login">Log in</a></header>
+<main><p>Hello <b>guest</b></p><ul><li>A</li></ul><img src="/a.png"></main><script>var user = null;</script></body></html>"#;
+    let personalized = br#"<!DOCTYPE html><html><body><header id="header"><a class="account" href="/account" data-user>Taro</a></header>
+<main><p>Hello <b>Taro</b></p><ul><li>A</li><li>B</li></ul><img src="/a.png"></main><script>var user = {"id":1};</script></body></html>"#;
+    let patch = diff_html(shell, personalized).unwrap();
+    let selector = |selector: &str| selector.to_string();
+    assert_eq!(patch, [
+        PatchOp::SetAttribute { selector: selector("#header > a:nth-child(1)"), name: "class".to_string(), value: "account".to_string() },
+        PatchOp::SetAttribute { selector: selector("#header > a:nth-child(1)"), name: "href".to_string(), value: "/account".to_string() },
+        PatchOp::SetAttribute { selector: selector("#header > a:nth-child(1)"), name: "data-user".to_string(), value: String::new() },
+        PatchOp::SetText { selector: selector("#header > a:nth-child(1)"), text: "Taro".to_string() },
+        PatchOp::SetText { selector: selector("html > body:nth-child(1) > main:nth-child(2) > p:nth-child(1) > b:nth-child(1)"), text: "Taro".to_string() },
+        PatchOp::SetHtml { selector: selector("html > body:nth-child(1) > main:nth-child(2) > ul:nth-child(2)"), html: "<li>A</li><li>B</li>".to_string() },
+        PatchOp::SetText { selector: selector("html > body:nth-child(1) > script:nth-child(3)"), text: r#"var user = {"id":1};"#.to_string() },
+    ]);
+    assert_eq!(patch_to_json(&patch[3..4]), json!([["text", "#header > a:nth-child(1)", "Taro"]]));
+
+    // Mixed text & elements, attribute removal, fragments
+    let patch = diff_html(br#"<div class="x">Hi <b>a</b></div>"#, br#"<div>Bye <b>a</b></div>"#).unwrap();
+    assert_eq!(patch, [
+        PatchOp::RemoveAttribute { selector: selector(":scope > div:nth-child(1)"), name: "class".to_string() },
+        PatchOp::SetHtml { selector: selector(":scope > div:nth-child(1)"), html: "Bye <b>a</b>".to_string() },
+    ]);
+    assert!(diff_html(b"<p>same</p>", b"<p>same</p>").unwrap().is_empty());
+    assert!(diff_html(b"<p>a</p>", b"<div>a</div>").is_err());
+}
+
+#[cfg(feature = "replace-engine")]
+#[test]
+fn render_patch_test() {
+    use json_data_cache::{DataCache, DataCacheOptions, hydration::render_patch};
+
+    let mut shell_cache = DataCache::new(DataCacheOptions::default());
+    shell_cache.insert("user.name", json!("guest"));
+    let mut personalized_cache = DataCache::new(DataCacheOptions::default());
+    personalized_cache.insert("user.name", json!("Taro"));
+    let hydration_patch = render_patch(br#"<nav><span id="name">{$user.name}</span></nav>"#, &mut shell_cache, &mut personalized_cache).unwrap();
+    assert_eq!(hydration_patch.shell, br#"<nav><span id="name">guest</span></nav>"#);
+    assert_eq!(hydration_patch.patch, [PatchOp::SetText { selector: "#name".to_string(), text: "Taro".to_string() }]);
+}
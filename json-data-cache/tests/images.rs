@@ -0,0 +1,37 @@
+use std::io::Write;
+
+use json_data_cache::{DataCache, DataCacheOptions, html_rewriter::HtmlRewriter, images::{DEFAULT_IMAGE_METADATA_PATH, ImageRules}};
+use serde_json::json;
+
+fn rewrite(rules: &ImageRules, html: &str) -> String {
+    let mut rewriter = HtmlRewriter::new(Vec::new(), Vec::from([rules.rewrite_rule()]));
+    for chunk in html.as_bytes().chunks(7) {
+        rewriter.write_all(chunk).unwrap();
+    }
+    String::from_utf8(rewriter.finish().unwrap()).unwrap()
+}
+
+#[test]
+fn image_rules_test() {
+    let mut data_cache = DataCache::new(DataCacheOptions::default());
+    data_cache.insert(DEFAULT_IMAGE_METADATA_PATH, json!({"/img/top.jpg": {"width": 1200, "height": 630}}));
+    let rules = data_cache.image_rules(DEFAULT_IMAGE_METADATA_PATH).unwrap().lazy_loading().async_decoding();
+
+    for (html, rewritten) in [
+        ("<img src=\"/img/top.jpg\" alt=\"Top\">", "<img src=\"/img/top.jpg\" alt=\"Top\" loading=\"lazy\" decoding=\"async\" width=\"1200\" height=\"630\">"),
+        ("<img src=\"/img/top.jpg?w=2\">", "<img src=\"/img/top.jpg?w=2\" loading=\"lazy\" decoding=\"async\" width=\"1200\" height=\"630\">"),
+        ("<img src=\"/img/top.jpg\" width=\"600\" loading=\"eager\" decoding=\"sync\">", "<img src=\"/img/top.jpg\" width=\"600\" loading=\"eager\" decoding=\"sync\">"),
+        ("<img src=\"/a.png\" fetchpriority=\"high\">", "<img src=\"/a.png\" fetchpriority=\"high\" decoding=\"async\">"),
+        ("<p><iframe src=\"/img/top.jpg\"></iframe></p>", "<p><iframe src=\"/img/top.jpg\"></iframe></p>"),
+    ] {
+        assert_eq!(rewrite(&rules, html), rewritten, "{html}");
+    }
+
+    // Dimensions only
+    let rules = ImageRules::default().dimension("/a.png", 10, 20);
+    assert_eq!(rewrite(&rules, "<img src='/a.png'><img src='/b.png'>"), "<img src=\"/a.png\" width=\"10\" height=\"20\"><img src='/b.png'>");
+
+    assert_eq!(data_cache.image_rules("missing").unwrap(), ImageRules::default());
+    data_cache.insert("invalid", json!({"/a.png": {"width": 10}}));
+    assert!(data_cache.image_rules("invalid").unwrap_err().msg.contains("/a.png"));
+}
@@ -0,0 +1,53 @@
+#![cfg(feature = "replace-engine")]
+
+use json_data_cache::{DataCache, DataCacheOptions, incremental::{CompiledTemplate, OutputPatch}};
+use serde_json::json;
+
+fn replace(data_cache: &mut DataCache, template: &[u8]) -> Vec<u8> {
+    let mut output = Vec::new();
+    data_cache.replace_with_data_cache(template, &mut output).unwrap();
+    output
+}
+
+#[test]
+fn rerender_dirty_test() {
+    let template = b"<ul><li>{$sales.today}</li><li>{$$sales.label}</li><li>{$visits}</li></ul>{$missing} \\{$visits} {$} {$a {$visits}".to_vec();
+    let mut data_cache = DataCache::new(DataCacheOptions { escape_placeholders: true, ..Default::default() });
+    data_cache.insert("sales", json!({"today": 120, "label": "Sales"}));
+    data_cache.insert("visits", json!(5));
+    let compiled = CompiledTemplate::compile(template.clone());
+    assert_eq!(compiled.keys().collect::<Vec<_>>(), ["sales.today", "sales.label", "visits", "missing", "visits", "visits"]);
+
+    let mut rendered = data_cache.render_compiled(&compiled).unwrap();
+    assert_eq!(rendered.output(), replace(&mut data_cache, &template));
+    assert!(data_cache.rerender_dirty(&compiled, &mut rendered).unwrap().is_empty());
+
+    // Only the modified keys are rendered again
+    data_cache.insert("sales.today", json!(1500));
+    data_cache.insert("sales.label", json!("Sales"));
+    let patches = data_cache.rerender_dirty(&compiled, &mut rendered).unwrap();
+    assert_eq!(patches, [OutputPatch { range: 8..11, bytes: b"1500".to_vec() }]);
+    assert_eq!(rendered.output(), replace(&mut data_cache, &template));
+
+    // Keys without value yet, several patches
+    data_cache.insert("missing", json!("now set"));
+    data_cache.insert("visits", json!(10));
+    let previous = rendered.output().to_vec();
+    let patches = data_cache.rerender_dirty(&compiled, &mut rendered).unwrap();
+    assert_eq!(patches.len(), 3);
+    let mut patched = previous;
+    for patch in patches.iter().rev() {
+        patched.splice(patch.range.clone(), patch.bytes.iter().copied());
+    }
+    assert_eq!(patched, replace(&mut data_cache, &template));
+    assert_eq!(rendered.output(), patched);
+
+    // Replacing an ancestor dirties its descendants
+    data_cache.insert("sales", json!({"today": 7}));
+    data_cache.rerender_dirty(&compiled, &mut rendered).unwrap();
+    assert_eq!(rendered.into_output(), replace(&mut data_cache, &template));
+
+    let other = CompiledTemplate::compile(b"{$visits}".to_vec());
+    let mut rendered = data_cache.render_compiled(&other).unwrap();
+    assert!(data_cache.rerender_dirty(&compiled, &mut rendered).is_err());
+}
@@ -0,0 +1,45 @@
+use std::{cell::RefCell, rc::Rc, time::{Duration, SystemTime}};
+
+use json_data_cache::{DataCache, DataCacheOptions, FreshnessMeta};
+use serde_json::json;
+
+#[test]
+fn invalidation_test() {
+    let mut data_cache = DataCache::new(DataCacheOptions::default());
+    data_cache.insert("content", json!({"news": [{"id": 1}, {"id": 2}], "topics": {"id": 3}}));
+    data_cache.insert("menu", json!(["home"]));
+    let now = SystemTime::now();
+    data_cache.set_freshness("content.news", FreshnessMeta { fresh_until: now + Duration::from_secs(60), stale_until: now + Duration::from_secs(60) });
+
+    let invalidated = Rc::new(RefCell::new(Vec::new()));
+    let listener_invalidated = invalidated.clone();
+    data_cache.add_invalidation_listener(move |path: &str| listener_invalidated.borrow_mut().push(path.to_string()));
+
+    assert!(data_cache.invalidate("content.news"));
+    assert_eq!(data_cache.get("content"), Some(&json!({"topics": {"id": 3}})));
+    assert!(data_cache.freshness_meta("content.news").is_none());
+    assert!(!data_cache.invalidate("content.news")); // Nothing left to evict
+    assert!(data_cache.invalidate("menu.0"));
+    assert_eq!(data_cache.get("menu"), Some(&json!([])));
+    assert_eq!(*invalidated.borrow(), ["content.news", "menu.0"]);
+
+    #[cfg(feature = "replace-engine")]
+    {
+        let mut writer = Vec::new();
+        assert!(data_cache.replace_with_data_cache("{$content.topics.id}".as_bytes(), &mut writer).is_ok());
+        assert!(data_cache.invalidate("content.topics"));
+        let mut writer = Vec::new();
+        assert!(data_cache.replace_with_data_cache("{$content.topics.id}".as_bytes(), &mut writer).is_ok());
+        assert_eq!(&String::from_utf8(writer).unwrap(), "{$content.topics.id}");
+    }
+    #[cfg(feature = "serializer")]
+    {
+        data_cache.string_values_view();
+        data_cache.invalidate("content");
+        let expected = data_cache.as_string_values_map();
+        assert_eq!(data_cache.string_values_view(), &expected);
+    }
+
+    assert!(data_cache.invalidate(""));
+    assert_eq!(data_cache.root, json!({}));
+}
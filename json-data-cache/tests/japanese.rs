@@ -0,0 +1,33 @@
+use json_data_cache::japanese::{char_count, normalize_width, strip_furigana, to_hiragana, to_katakana, truncate_chars};
+
+#[test]
+fn normalize_width_test() {
+    assert_eq!(normalize_width("ＡＢＣ１２３　ｶﾞｷﾞ"), "ABC123 ガギ");
+    assert_eq!(normalize_width("ﾊﾟﾝﾌﾚｯﾄ｢ｳﾞｧｲｵﾘﾝ｣"), "パンフレット「ヴァイオリン」");
+    assert_eq!(normalize_width("ｱﾞﾟ"), "ア゛゜"); // Marks without a voiced kana are kept
+    assert_eq!(normalize_width("全角カナ＆ｶﾅ！"), "全角カナ&カナ!");
+}
+
+#[test]
+fn kana_test() {
+    assert_eq!(to_katakana("ひらがなゔ、カナ"), "ヒラガナヴ、カナ");
+    assert_eq!(to_hiragana("カタカナー、ヴ、ひら"), "かたかなー、ゔ、ひら");
+}
+
+#[test]
+fn truncate_chars_test() {
+    assert_eq!(truncate_chars("東京都の天気予報", 5, "…"), "東京都の…");
+    assert_eq!(truncate_chars("東京都の天気予報", 8, "…"), "東京都の天気予報");
+    assert_eq!(truncate_chars("東京都の天気予報", 5, ""), "東京都の天");
+    assert_eq!(truncate_chars("abcdef", 1, "..."), "...");
+    // Combining marks stay with their character
+    assert_eq!(char_count("か\u{3099}き"), 2);
+    assert_eq!(truncate_chars("か\u{3099}き\u{3099}く", 1, ""), "か\u{3099}");
+}
+
+#[test]
+fn strip_furigana_test() {
+    assert_eq!(strip_furigana("<ruby>漢字<rt>かんじ</rt></ruby>と｜東京《とうきょう》"), "漢字と東京");
+    assert_eq!(strip_furigana("<RUBY><rb>明日</rb><rp>(</rp><RT>あした</RT><rp>)</rp></RUBY>"), "明日");
+    assert_eq!(strip_furigana("<b>太字</b>と《閉じない"), "<b>太字</b>と《閉じない");
+}
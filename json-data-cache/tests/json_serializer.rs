@@ -1,4 +1,6 @@
-use json_data_cache::json_serializer::JsonSerializer;
+#![cfg(feature = "serializer")]
+
+use json_data_cache::json_serializer::{JsonSerializer, RangeTrace, SerializerOptions};
 use serde_json::json;
 
 #[test]
@@ -45,7 +47,7 @@ fn serializer_test() {
     ] {
         assert!(serialized.key_values.contains_key(key));
         let range = serialized.key_values.get(key).unwrap();
-        let serialized_string = String::from_utf8((&serialized.data[range.start..range.end]).to_vec());
+        let serialized_string = String::from_utf8(serialized.data[range.start..range.end].to_vec());
         assert!(serialized_string.is_ok());
         let serialized_value = serialized_string.unwrap();
         assert_eq!(expected, &serialized_value);
@@ -74,9 +76,81 @@ fn serializer_test() {
     ] {
         assert!(double_serialized.key_values.contains_key(key));
         let range = double_serialized.key_values.get(key).unwrap();
-        let double_serialized_string = String::from_utf8((&double_serialized.data[range.start..range.end]).to_vec());
+        let double_serialized_string = String::from_utf8(double_serialized.data[range.start..range.end].to_vec());
         assert!(double_serialized_string.is_ok());
         let double_serialized_value = double_serialized_string.unwrap();
         assert_eq!(expected, &double_serialized_value);
     }
-}
\ No newline at end of file
+}
+
+#[test]
+fn try_serialize_test() {
+    let value = json!({"a": [[1, 2], {"b": [3]}]});
+    assert!(JsonSerializer::try_serialize(&value, true, 4).is_ok());
+    assert!(JsonSerializer::try_serialize(&value, true, 3).is_err());
+}
+
+#[test]
+fn serializer_trace_test() {
+    let value = json!({"a": ["x", 1]});
+    let options = SerializerOptions {
+        double_serialize: true,
+        trace: true,
+        verify: true,
+        ..Default::default()
+    };
+    let (serialized, double_serialized, trace) = JsonSerializer::serialize_with(&value, &options);
+    let range = |key: &str, start, end, double_serialized| RangeTrace { key: key.to_string(), start, end, double_serialized };
+    // Children ranges are computed before their parents
+    assert_eq!(trace, [
+        range("a.0", 7, 8, false),
+        range("a.0", 10, 11, true),
+        range("a.1", 10, 11, false),
+        range("a.1", 14, 15, true),
+        range("a", 5, 12, false),
+        range("a", 7, 16, true),
+    ]);
+    assert_eq!(&serialized.data[5..12], br#"["x",1]"#);
+    assert_eq!(&double_serialized.unwrap().data[7..16], br#"[\"x\",1]"#);
+
+    let (_, double_serialized, trace) = JsonSerializer::serialize_with(&value, &SerializerOptions::default());
+    assert!(double_serialized.is_none());
+    assert!(trace.is_empty());
+}
+
+#[test]
+fn serializer_escapes_test() {
+    let value = json!({
+        "日本語": "こんにちは 🌏",
+        "quote\"key": "say \"hi\"",
+        "escapes": ["back\\slash", "new\nline\ttab", "\u{0007}bell", "</script>"],
+        "nested": {"emoji 🎉": {"deep": "\"\\\""}},
+        "empty": ["", {}, []]
+    });
+    let options = SerializerOptions {
+        double_serialize: true,
+        verify: true, // Panics if any range is off
+        ..Default::default()
+    };
+    let (serialized, double_serialized, _) = JsonSerializer::serialize_with(&value, &options);
+    let double_serialized = double_serialized.unwrap();
+    macro_rules! slice {
+        ($serialized:expr, $key:expr) => {{
+            let range = $serialized.key_values.get($key).unwrap();
+            String::from_utf8($serialized.data[range.start..range.end].to_vec()).unwrap()
+        }};
+    }
+
+    assert_eq!(slice!(serialized, "日本語"), "こんにちは 🌏");
+    assert_eq!(slice!(serialized, "quote\"key"), r#"say \"hi\""#);
+    assert_eq!(slice!(serialized, "escapes.1"), r#"new\nline\ttab"#);
+    assert_eq!(slice!(serialized, "escapes.2"), r#"\u0007bell"#);
+    assert_eq!(slice!(serialized, "nested.emoji 🎉.deep"), r#"\"\\\""#);
+    assert_eq!(slice!(serialized, "empty.0"), "");
+    assert_eq!(slice!(serialized, "empty"), r#"["",{},[]]"#);
+    assert_eq!(slice!(double_serialized, "日本語"), "こんにちは 🌏");
+    assert_eq!(slice!(double_serialized, "quote\"key"), r#"say \\\"hi\\\""#);
+    assert_eq!(slice!(double_serialized, "escapes.0"), r#"back\\\\slash"#);
+    assert_eq!(slice!(double_serialized, "empty"), r#"[\"\",{},[]]"#);
+    assert_eq!(serialized.data, value.to_string().as_bytes());
+}
@@ -0,0 +1,63 @@
+use std::io::Write;
+
+use json_data_cache::{html_rewriter::HtmlRewriter, links::LinkRules};
+
+#[test]
+fn link_rules_test() {
+    let links = LinkRules::default()
+        .swap_domain("www.example.com", "staging.example.com")
+        .prefix_locale("ja")
+        .append_parameter("utm_source", "news letter");
+    for (url, is_link, rewritten) in [
+        ("https://WWW.example.com/news?id=1", false, Some("https://staging.example.com/news?id=1")),
+        ("//www.example.com:8080/a.js", false, Some("//staging.example.com:8080/a.js")),
+        ("https://user@www.example.com", false, Some("https://user@staging.example.com")),
+        ("https://cdn.example.com/a.png", false, None),
+        ("/about?q=https://www.example.com", false, None),
+        ("/news/1", false, None), // Only links get locale prefixes & parameters
+        ("/news/1#top", true, Some("/ja/news/1?utm_source=news%20letter#top")),
+        ("/ja/news?p=2", true, Some("/ja/news?p=2&amp;utm_source=news%20letter")),
+        ("/japan", true, Some("/ja/japan?utm_source=news%20letter")),
+        ("/?utm_source=x", true, Some("/ja/?utm_source=x")),
+        ("/?a=1&amp;utm_source=x", true, Some("/ja/?a=1&amp;utm_source=x")),
+        ("relative?", true, Some("relative?utm_source=news%20letter")),
+        ("https://www.example.com", true, Some("https://staging.example.com?utm_source=news%20letter")),
+        ("#top", true, None),
+        ("mailto:info@example.com", true, None),
+        ("JavaScript:void(0)", true, None),
+        ("", true, None),
+    ] {
+        assert_eq!(links.rewrite(url, is_link).as_deref(), rewritten, "{url}");
+    }
+}
+
+#[test]
+fn link_rewriter_test() {
+    let links = LinkRules::default().swap_domain("example.com", "staging.example.com").prefix_locale("en");
+    let html = "<a href=\"/news\" class=\"x\">News</a><img src='https://example.com/a.png' alt=\"\"><link rel=\"stylesheet\" href=\"/main.css\">\
+<script>location.href = \"/news\"</script><a href=\"#top\">Top</a>";
+    let mut rewriter = HtmlRewriter::new(Vec::new(), Vec::from([links.rewrite_rule()]));
+    for chunk in html.as_bytes().chunks(5) {
+        rewriter.write_all(chunk).unwrap();
+    }
+    assert_eq!(
+        String::from_utf8(rewriter.finish().unwrap()).unwrap(),
+        "<a href=\"/en/news\" class=\"x\">News</a><img src=\"https://staging.example.com/a.png\" alt><link rel=\"stylesheet\" href=\"/main.css\">\
+<script>location.href = \"/news\"</script><a href=\"#top\">Top</a>"
+    );
+}
+
+#[cfg(feature = "replace-engine")]
+#[test]
+fn replace_with_links_test() {
+    use json_data_cache::{DataCache, DataCacheOptions};
+    use serde_json::json;
+
+    let mut data_cache = DataCache::new(DataCacheOptions::default());
+    data_cache.insert("campaign", json!({"id": 42}));
+    data_cache.insert("path", json!("/news/1"));
+    let links = LinkRules::default().append_parameter_from("cid", "campaign.id").append_parameter_from("missing", "none");
+    let output = data_cache.replace_with_links(&links, "<a href=\"{$path}\">x</a>".as_bytes(), Vec::new()).unwrap();
+    assert_eq!(output, b"<a href=\"/news/1?cid=42\">x</a>");
+    assert!(!links.is_empty());
+}
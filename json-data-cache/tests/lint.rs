@@ -0,0 +1,58 @@
+use json_data_cache::{DataCache, DataCacheOptions, lint::{LintDiagnostic, LintIssue, LintSource, TemplatePlaceholder, find_placeholders, lint_template, schema_at}};
+use serde_json::json;
+
+fn diagnostic(line: usize, column: usize, key: &str, issue: LintIssue) -> LintDiagnostic {
+    LintDiagnostic { line, column, key: key.to_string(), issue }
+}
+
+#[test]
+fn find_placeholders_test() {
+    let placeholders = find_placeholders("{$a} {$$b.c}\n{$} {$ d} {$e");
+    assert_eq!(placeholders, [
+        TemplatePlaceholder { line: 1, column: 1, key: "a", is_double: false },
+        TemplatePlaceholder { line: 1, column: 6, key: "b.c", is_double: true },
+    ]);
+}
+
+#[test]
+fn lint_template_test() {
+    let template = "<h1>{$title}</h1>\n{$items.0.name} {$missing} {$date|date}\n{$price}";
+    let mut data_cache = DataCache::new(DataCacheOptions::default());
+    data_cache.insert("title", json!("Title"));
+    data_cache.insert("items", json!([{"name": "A"}]));
+    data_cache.insert("price", json!("free"));
+    #[cfg(feature = "replace-engine")]
+    data_cache.set_renderer("price", json_data_cache::renderers::grouped_number("", "円", ","));
+
+    #[cfg_attr(not(feature = "replace-engine"), allow(unused_mut))]
+    let mut expected = Vec::from([
+        diagnostic(2, 17, "missing", LintIssue::UnknownPath),
+        diagnostic(2, 28, "date", LintIssue::UnsupportedFilter(String::from("date"))),
+    ]);
+    #[cfg(feature = "replace-engine")]
+    expected.push(diagnostic(3, 1, "price", LintIssue::RejectedByRenderer("string")));
+    assert_eq!(lint_template(template, LintSource::Cache(&data_cache)), expected);
+    assert_eq!(expected[0].to_string(), "2:17: unknown path missing");
+
+    let schema = json!({"type": "object", "properties": {
+        "title": {"type": "string"},
+        "price": {"type": "integer"},
+        "items": {"type": "array", "items": {"type": "object", "properties": {"name": {"type": "string"}}}}
+    }});
+    assert_eq!(schema_at(&schema, "items.3.name"), Some(&json!({"type": "string"})));
+    assert_eq!(schema_at(&schema, "items.first"), None);
+    assert_eq!(lint_template(template, LintSource::Schema(&schema)), expected[..2]);
+}
+
+#[cfg(feature = "replace-engine")]
+#[test]
+fn lint_safe_redirect_test() {
+    let template = "{$query.next|safe_redirect} {$query.missing|safe_redirect} {$title|safe_redirect}";
+    let mut data_cache = DataCache::new(DataCacheOptions { safe_redirect_keys: Vec::from([String::from("query")]), ..Default::default() });
+    data_cache.insert("query", json!({"next": "/mypage"}));
+    data_cache.insert("title", json!("Title"));
+    assert_eq!(lint_template(template, LintSource::Cache(&data_cache)), [
+        diagnostic(1, 29, "query.missing", LintIssue::UnknownPath),
+        diagnostic(1, 60, "title", LintIssue::UnsupportedFilter(String::from("safe_redirect"))),
+    ]);
+}
@@ -0,0 +1,41 @@
+use json_data_cache::minify::minify;
+
+#[test]
+fn minify_test() {
+    for (html, minified) in [
+        ("<p>  Hello \t world  </p>", "<p> Hello world </p>"),
+        ("<ul>\n    <li>a</li>\n    <li>b</li>\n</ul>\n", "<ul>\n<li>a</li>\n<li>b</li>\n</ul>\n"),
+        ("<div   class=\"a  b\"\n  id='x'  >text</div>", "<div class=\"a  b\"\nid='x'>text</div>"),
+        ("<pre>  keep\n    this  </pre>  <p>  x</p>", "<pre>  keep\n    this  </pre> <p> x</p>"),
+        ("<TEXTAREA>  a  </textarea >  b", "<TEXTAREA>  a  </textarea> b"),
+        ("<script>const s = `a\n    b`;  </script>  x", "<script>const s = `a\n    b`;  </script> x"),
+        ("<style>\n  a  { color : red }  /*  note  */ b::after { content: \"  x \\\"  y\" }\n</style>", "<style>\na { color : red } /*  note  */ b::after { content: \"  x \\\"  y\" }\n</style>"),
+        ("<!--  keep   comment -->  x", "<!--  keep   comment --> x"),
+        ("a  <  b", "a < b"),
+        ("<pre><pre>  x  </pre>  y", "<pre><pre>  x  </pre> y"),
+        ("<p>no change</p>", "<p>no change</p>"),
+    ] {
+        assert_eq!(String::from_utf8(minify(html.as_bytes())).unwrap(), minified, "{html}");
+    }
+}
+
+#[cfg(feature = "replace-engine")]
+#[test]
+fn replace_minify_test() {
+    use json_data_cache::{DataCache, DataCacheOptions};
+    use serde_json::json;
+
+    let mut data_cache = DataCache::new(DataCacheOptions { minify: true, ..Default::default() });
+    data_cache.insert("title", json!("Two  spaces"));
+    data_cache.insert("code", json!("fn  main() {}"));
+    let template = "<h1>  {$title}  </h1>\n\n  <pre>  {$code}  </pre>\n  <p title=\"  {$title}\">  x  </p>";
+    let mut output = Vec::new();
+    data_cache.replace_with_data_cache(template.as_bytes(), &mut output).unwrap();
+    assert_eq!(String::from_utf8(output).unwrap(), "<h1> Two  spaces </h1>\n<pre>  fn  main() {}  </pre>\n<p title=\"  Two  spaces\"> x </p>");
+
+    // Streamed in chunks, the state being kept between them
+    let template = format!("{}<pre>  a  </pre>  {{$title}}", "<p>  x  </p>".repeat(10_000));
+    let mut output = Vec::new();
+    data_cache.replace_with_data_cache(template.as_bytes(), &mut output).unwrap();
+    assert_eq!(String::from_utf8(output).unwrap(), format!("{}<pre>  a  </pre> Two  spaces", "<p> x </p>".repeat(10_000)));
+}
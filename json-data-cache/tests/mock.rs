@@ -0,0 +1,64 @@
+use json_data_cache::{DataCache, mock::MockGenerator};
+use serde_json::{Value, json};
+
+fn schema() -> Value {
+    json!({
+        "type": "object",
+        "properties": {
+            "author": {"type": "string", "format": "name"},
+            "contents": {
+                "type": "array",
+                "minItems": 3,
+                "maxItems": 3,
+                "items": {
+                    "type": "object",
+                    "properties": {
+                        "id": {"type": "integer", "minimum": 1, "maximum": 100},
+                        "subject": {"type": "string", "format": "title", "locale": "ja"},
+                        "date": {"type": "string", "format": "date"},
+                        "image": {"type": "string", "format": "image_url"},
+                        "status": {"enum": ["draft", "published"]},
+                        "kind": {"const": "news"},
+                        "price": {"type": "number", "minimum": 1.5, "maximum": 2.5},
+                        "visible": {"type": "boolean"}
+                    }
+                }
+            }
+        }
+    })
+}
+
+#[test]
+fn mock_test() {
+    let data_cache = DataCache::from_schema_mock(&schema(), 42).unwrap();
+    assert_eq!(data_cache.root, DataCache::from_schema_mock(&schema(), 42).unwrap().root);
+    assert_ne!(data_cache.root, DataCache::from_schema_mock(&schema(), 43).unwrap().root);
+
+    assert!(data_cache.get("author").unwrap().as_str().unwrap().contains(' '));
+    assert_eq!(data_cache.get("contents").unwrap().as_array().unwrap().len(), 3);
+    let id = data_cache.get("contents.0.id").unwrap().as_i64().unwrap();
+    assert!((1..=100).contains(&id));
+    let subject = data_cache.get("contents.1.subject").unwrap().as_str().unwrap();
+    assert!(subject.ends_with('。'));
+    let date = data_cache.get("contents.2.date").unwrap().as_str().unwrap();
+    assert_eq!(date.len(), 10);
+    assert!(date.starts_with("202"));
+    assert!(data_cache.get("contents.0.image").unwrap().as_str().unwrap().starts_with("https://"));
+    assert!(["draft", "published"].contains(&data_cache.get("contents.0.status").unwrap().as_str().unwrap()));
+    assert_eq!(data_cache.get("contents.0.kind"), Some(&json!("news")));
+    let price = data_cache.get("contents.0.price").unwrap().as_f64().unwrap();
+    assert!((1.5..=2.5).contains(&price));
+    assert!(data_cache.get("contents.0.visible").unwrap().is_boolean());
+
+    assert!(DataCache::from_schema_mock(&json!({"type": "string"}), 1).is_err());
+    assert!(MockGenerator::new(1).generate(&json!({"type": "unknown"})).is_err());
+    // Dates are valid calendar dates
+    let mut generator = MockGenerator::new(7);
+    for _ in 0..200 {
+        let date = generator.generate(&json!({"format": "date-time"})).unwrap();
+        let date = date.as_str().unwrap();
+        let month: u32 = date[5..7].parse().unwrap();
+        let day: u32 = date[8..10].parse().unwrap();
+        assert!((1..=12).contains(&month) && (1..=31).contains(&day));
+    }
+}
@@ -0,0 +1,292 @@
+use std::time::Duration;
+
+use json_data_cache::{DataCache, DataCacheOptions, error::JsonDataCacheError, fetcher::{FetchRequest, FetchResponse, Fetcher}, pipeline::{Condition, Pipeline, PipelineIo, PipelineOutcome, RetryPolicy}};
+use serde_json::json;
+
+const CONFIG: &str = r#"{"steps": [
+    {"match_route": {"from": "request.path", "into": "route", "required": true, "routes": [
+        {"pattern": "/news/:id", "name": "news"}, {"pattern": "/files/*", "name": "files"}
+    ]}},
+    {"fetch": {"path": "news", "url": "https://origin/news/{$route.params.id}", "headers": {"X-Api-Key": "key"}}},
+    {"transform": {"into": "page.title", "from": "news.title"}},
+    {"transform": {"into": "page.total", "expr": "news.price * news.count"}},
+    {"validate": {"data": "form", "halt_on_errors": true, "rules": [
+        {"field": "email", "rule": "required", "message": "Required"},
+        {"field": "age", "rule": "range", "min": 0, "max": 150, "message": "Invalid age"}
+    ]}}
+]}"#;
+
+fn new_fetcher() -> Fetcher<impl FnMut(&FetchRequest) -> Result<FetchResponse, JsonDataCacheError>> {
+    Fetcher::new(|request: &FetchRequest| {
+        assert_eq!(request.headers, [(String::from("X-Api-Key"), String::from("key"))]);
+        let body = json!({"title": request.url, "price": 3, "count": 2});
+        Ok(FetchResponse { status: 200, body: serde_json::to_vec(&body).unwrap() })
+    })
+}
+
+#[test]
+fn pipeline_test() {
+    let pipeline = Pipeline::from_json(CONFIG).unwrap();
+    assert_eq!(pipeline.steps().len(), 5);
+    let mut fetcher = new_fetcher();
+    let mut output = Vec::new();
+    let mut template = "".as_bytes();
+    let mut io = PipelineIo::new(&mut fetcher, &mut template, &mut output);
+
+    let mut data_cache = DataCache::new(DataCacheOptions::default());
+    data_cache.insert("request.path", json!("/news/12?page=2"));
+    data_cache.insert("form", json!({"email": "a@example.com", "age": "20"}));
+    assert_eq!(pipeline.run(&mut data_cache, &mut io).unwrap(), PipelineOutcome::Completed);
+    assert_eq!(data_cache.get("route"), Some(&json!({"name": "news", "params": {"id": "12"}})));
+    assert_eq!(data_cache.get("page.title"), Some(&json!("https://origin/news/12")));
+    assert_eq!(data_cache.get("page.total"), Some(&json!(6)));
+
+    // An unmatched required route halts the pipeline
+    let mut data_cache = DataCache::new(DataCacheOptions::default());
+    data_cache.insert("request.path", json!("/news/12/comments"));
+    assert_eq!(pipeline.run(&mut data_cache, &mut io).unwrap(), PipelineOutcome::Halted { step: 0 });
+    assert_eq!(data_cache.get("route"), Some(&json!(null)));
+
+    // The files route has no id parameter for the url of the fetch step, so the run fails there
+    let mut data_cache = DataCache::new(DataCacheOptions::default());
+    data_cache.insert("request.path", json!("/files/a/b.pdf"));
+    assert!(pipeline.run(&mut data_cache, &mut io).is_err());
+    assert_eq!(data_cache.get("route"), Some(&json!({"name": "files", "params": {"*": "a/b.pdf"}})));
+}
+
+#[test]
+fn pipeline_validate_test() {
+    let pipeline = Pipeline::from_json(r#"{"steps": [
+        {"validate": {"data": "form", "errors": "errors", "halt_on_errors": true, "rules": [
+            {"field": "email", "rule": "required", "message": "Required"},
+            {"field": "age", "rule": "range", "min": 0, "max": 150, "message": "Invalid age"}
+        ]}},
+        {"transform": {"into": "valid", "from": "form.email"}}
+    ]}"#).unwrap();
+    let mut fetcher = new_fetcher();
+    let mut output = Vec::new();
+    let mut template = "".as_bytes();
+    let mut io = PipelineIo::new(&mut fetcher, &mut template, &mut output);
+
+    let mut data_cache = DataCache::new(DataCacheOptions::default());
+    data_cache.insert("form", json!({"age": "200"}));
+    assert_eq!(pipeline.run(&mut data_cache, &mut io).unwrap(), PipelineOutcome::Halted { step: 0 });
+    assert!(data_cache.get("errors.email").is_some());
+    assert!(data_cache.get("errors.age").is_some());
+    assert_eq!(data_cache.get("valid"), None);
+}
+
+#[test]
+fn pipeline_config_errors_test() {
+    assert!(Pipeline::from_json("[]").is_err());
+    assert!(Pipeline::from_json(r#"{"steps": [{"unknown": {}}]}"#).is_err());
+    assert!(Pipeline::from_json(r#"{"steps": [{"fetch": {"path": "a"}}]}"#).is_err());
+    assert!(Pipeline::from_json(r#"{"steps": [{"transform": {"into": "a", "from": "b", "expr": "1"}}]}"#).is_err());
+    assert!(Pipeline::from_json(r#"{"steps": [{"validate": {"data": "form", "rules": [{"field": "a", "rule": "unknown"}]}}]}"#).is_err());
+    assert!(Pipeline::from_json(r#"{"steps": [{"match_route": {"from": "a", "into": "b", "routes": []}, "fetch": {}}]}"#).is_err());
+    assert!(Pipeline::from_json(r#"{"steps": [{"when": "a"}]}"#).is_err());
+    assert!(Pipeline::from_json(r#"{"steps": [{"transform": {"into": "a", "from": "b"}, "when": "a == b"}]}"#).is_err()); // b is not JSON
+    assert!(Pipeline::from_json(r#"{"steps": [{"transform": {"into": "a", "from": "b"}, "on_error": "ignore"}]}"#).is_err());
+}
+
+#[test]
+fn pipeline_condition_test() {
+    let mut data_cache = DataCache::new(DataCacheOptions::default());
+    data_cache.insert("route", json!({"name": "news", "params": {}}));
+    data_cache.insert("cart", json!([{"price": 60}, {"price": "50"}]));
+    data_cache.insert("flags", json!({"on": true, "off": 0, "empty": ""}));
+    let is_met = |condition: &str| Condition::parse(condition).unwrap().is_met(&data_cache);
+    assert!(is_met(r#"route.name == "news""#));
+    assert!(is_met(r#"route.name != "files""#));
+    assert!(is_met("missing == null"));
+    assert!(is_met("flags.on && !flags.off && !flags.empty && !missing && !route.params"));
+    assert!(is_met("sum(cart.*.price) >= 110"));
+    assert!(!is_met("sum(cart.*.price) > 110"));
+    assert!(!is_met("missing < 1")); // Not a number
+    assert!(is_met("flags.off || cart.0.price * 2 < 200 && flags.on"));
+    assert!(!is_met("flags.off || flags.empty"));
+
+    // Operators inside of string literals are part of them
+    let mut data_cache = DataCache::new(DataCacheOptions::default());
+    data_cache.insert("q", json!("a||b"));
+    data_cache.insert("op", json!("x != \"y && z\""));
+    let is_met = |condition: &str| Condition::parse(condition).unwrap().is_met(&data_cache);
+    assert!(is_met(r#"q == "a||b""#));
+    assert!(is_met(r#"q != "a&&b" && q == "a||b""#));
+    assert!(!is_met(r#"q == "a" || q == "b""#));
+    assert!(is_met(r#"op == "x != \"y && z\"""#));
+}
+
+#[test]
+fn pipeline_retry_test() {
+    let mut attempts = 0;
+    let mut fetcher = Fetcher::new(|_: &FetchRequest| {
+        attempts += 1;
+        Ok(FetchResponse { status: if attempts < 3 { 503 } else { 200 }, body: br#"{"title": "News"}"#.to_vec() })
+    });
+    let pipeline = Pipeline::from_json(r#"{"steps": [
+        {"fetch": {"path": "news", "url": "https://origin/news"}, "retry": {"attempts": 3, "backoff_ms": 10, "factor": 2}},
+        {"fetch": {"path": "other", "url": "https://origin/other"}, "when": "!news"}
+    ]}"#).unwrap();
+    assert_eq!(pipeline.steps()[0].1.retry, Some(RetryPolicy { attempts: 3, backoff: Duration::from_millis(10), factor: 2 }));
+    let mut waits = Vec::new();
+    let mut sleep = |duration| waits.push(duration);
+    let mut output = Vec::new();
+    let mut template = "".as_bytes();
+    let mut io = PipelineIo::new(&mut fetcher, &mut template, &mut output).sleep(&mut sleep);
+
+    let mut data_cache = DataCache::new(DataCacheOptions::default());
+    assert_eq!(pipeline.run(&mut data_cache, &mut io).unwrap(), PipelineOutcome::Completed);
+    assert_eq!(data_cache.get("news.title"), Some(&json!("News")));
+    assert_eq!(waits, [Duration::from_millis(10), Duration::from_millis(20)]);
+    // The second step was skipped
+    assert_eq!(attempts, 3);
+}
+
+#[test]
+fn pipeline_pass_through_test() {
+    let mut fetcher = Fetcher::new(|_: &FetchRequest| Ok(FetchResponse { status: 500, body: Vec::new() }));
+    let pipeline = Pipeline::from_json(r#"{"steps": [
+        {"fetch": {"path": "news", "url": "https://origin/news"}, "on_error": "pass_through", "retry": {"attempts": 2}},
+        {"transform": {"into": "title", "from": "news.title"}}
+    ], "error_path": "error"}"#).unwrap();
+    let mut output = Vec::new();
+    let mut template = "".as_bytes();
+    let mut io = PipelineIo::new(&mut fetcher, &mut template, &mut output);
+
+    let mut data_cache = DataCache::new(DataCacheOptions::default());
+    data_cache.insert("title", json!("Title"));
+    assert_eq!(pipeline.run(&mut data_cache, &mut io).unwrap(), PipelineOutcome::Completed);
+    assert_eq!(data_cache.get("error.step"), Some(&json!(0)));
+    assert!(data_cache.get("error.message").and_then(|message| message.as_str()).is_some_and(|message| message.contains("500")));
+    assert_eq!(data_cache.get("title"), Some(&json!(null)));
+}
+
+#[test]
+fn pipeline_explain_test() {
+    let pipeline = Pipeline::from_json(CONFIG).unwrap();
+    let fetcher = new_fetcher();
+    let mut data_cache = DataCache::new(DataCacheOptions::default());
+    data_cache.insert("request.path", json!("/news/12"));
+    data_cache.insert("form", json!({"age": "20"}));
+
+    let trace = pipeline.explain(&data_cache, &fetcher);
+    assert_eq!(trace.outcome, Some(PipelineOutcome::Halted { step: 4 }));
+    assert_eq!(trace.steps.len(), 5);
+    assert_eq!(trace.steps[0].detail, json!({"path": "/news/12", "matched": "news", "params": {"id": "12"}, "routes": [
+        {"pattern": "/news/:id", "name": "news", "matched": true}
+    ]}));
+    // The request is rendered from the matched route, without fetching it
+    assert_eq!(trace.steps[1].detail, json!({"path": "news", "url": "https://origin/news/12", "headers": ["X-Api-Key"]}));
+    assert_eq!(trace.steps[2].detail, json!({"into": "page.title", "value": null}));
+    assert_eq!(trace.steps[4].detail, json!({"data": "form", "errors": [{"field": "email", "rule": "required", "message": "Required"}]}));
+    assert_eq!(trace.to_json()["outcome"], json!({"kind": "halted", "step": 4}));
+    // Without side effects
+    assert_eq!(data_cache.get("route"), None);
+    assert_eq!(data_cache.get("form.errors"), None);
+    // Routes are tried in order, an unmatched required one halting the run
+    data_cache.insert("request.path", json!("/about"));
+    let trace = pipeline.explain(&data_cache, &fetcher);
+    assert_eq!(trace.outcome, Some(PipelineOutcome::Halted { step: 0 }));
+    assert_eq!(trace.steps[0].detail["routes"], json!([
+        {"pattern": "/news/:id", "name": "news", "matched": false},
+        {"pattern": "/files/*", "name": "files", "matched": false}
+    ]));
+
+    let pipeline = Pipeline::from_json(r#"{"steps": [
+        {"transform": {"into": "a", "from": "b"}, "when": "flag && count > 1 || !flag"},
+        {"fetch": {"path": "news", "url": "https://origin/{$missing}"}, "on_error": "pass_through"},
+        {"fetch": {"path": "news", "url": "https://origin/{$missing}"}}
+    ]}"#).unwrap();
+    data_cache.insert("flag", json!(true));
+    data_cache.insert("count", json!(1));
+    let trace = pipeline.explain(&data_cache, &fetcher);
+    assert!(trace.steps[0].skipped);
+    assert_eq!(trace.steps[0].condition, Some(json!({"met": false, "clauses": [
+        {"clause": "flag", "group": 0, "met": true, "value": true},
+        {"clause": "count > 1", "group": 0, "met": false, "value": [1.0, 1.0]},
+        {"clause": "!flag", "group": 1, "met": false, "value": true}
+    ]})));
+    assert!(trace.steps[1].error.is_some());
+    assert_eq!(trace.outcome, None);
+    assert_eq!(trace.to_json()["outcome"], json!({"kind": "aborted"}));
+}
+
+#[test]
+fn pipeline_explain_options_test() {
+    let pipeline = Pipeline::from_json(r#"{"steps": [{"transform": {"into": "a.b.c", "from": "b"}}]}"#).unwrap();
+    let fetcher = new_fetcher();
+    let mut data_cache = DataCache::new(DataCacheOptions { max_depth: Some(3), ..Default::default() });
+    data_cache.insert("b", json!({"x": 1}));
+
+    // The dry run has the options of the cache, rejecting the same values as run
+    let trace = pipeline.explain(&data_cache, &fetcher);
+    assert!(trace.steps[0].error.as_ref().is_some_and(|error| error.contains("depth")), "{:?}", trace.steps[0].error);
+}
+
+#[cfg(feature = "replace-engine")]
+#[test]
+fn pipeline_on_error_test() {
+    let mut fetcher = Fetcher::new(|_: &FetchRequest| Ok(FetchResponse { status: 500, body: Vec::new() }));
+    let pipeline = Pipeline::from_json(r#"{"steps": [
+        {"fetch": {"path": "news", "url": "https://origin/news"}, "on_error": {"fallback_template": "templates.fallback"}, "when": "!offline"},
+        {"fetch": {"path": "news", "url": "https://origin/news"}, "on_error": {"error_page": "templates.error"}},
+        {"render": {}}
+    ]}"#).unwrap();
+    let mut run = |offline: bool| {
+        let mut output = Vec::new();
+        let mut template = "{$news}".as_bytes();
+        let mut io = PipelineIo::new(&mut fetcher, &mut template, &mut output);
+        let mut data_cache = DataCache::new(DataCacheOptions::default());
+        data_cache.insert("offline", json!(offline));
+        data_cache.insert("templates", json!({"fallback": "<p>Unavailable</p>", "error": "<p>Error at step {$pipeline.error.step}</p>"}));
+        let outcome = pipeline.run(&mut data_cache, &mut io).unwrap();
+        (outcome, String::from_utf8(output).unwrap())
+    };
+    assert_eq!(run(false), (PipelineOutcome::Recovered { step: 0 }, String::from("<p>Unavailable</p>")));
+    assert_eq!(run(true), (PipelineOutcome::Failed { step: 1 }, String::from("<p>Error at step 1</p>")));
+}
+
+#[cfg(feature = "replace-engine")]
+#[test]
+fn pipeline_render_test() {
+    let pipeline = Pipeline::from_json(r#"{"steps": [
+        {"transform": {"into": "title", "from": "news.title"}},
+        {"render": {}},
+        {"render": {"template_path": "templates.footer"}}
+    ]}"#).unwrap();
+    let mut fetcher = new_fetcher();
+    let mut output = Vec::new();
+    let mut template = "<h1>{$title}</h1>".as_bytes();
+    let mut io = PipelineIo::new(&mut fetcher, &mut template, &mut output);
+
+    let mut data_cache = DataCache::new(DataCacheOptions::default());
+    data_cache.insert("news.title", json!("First"));
+    data_cache.insert("templates.footer", json!("<p>{$title}</p>"));
+    data_cache.insert("templates.header", json!("<p>{$subtitle}</p>"));
+    let trace = Pipeline::from_json(r#"{"steps": [{"render": {"template_path": "templates.header"}}]}"#).unwrap().explain(&data_cache, io.fetcher);
+    assert_eq!(trace.steps[0].detail, json!({"template_path": "templates.header", "issues": ["1:4: unknown path subtitle"]}));
+    assert_eq!(pipeline.run(&mut data_cache, &mut io).unwrap(), PipelineOutcome::Completed);
+    assert_eq!(String::from_utf8(output).unwrap(), "<h1>First</h1><p>First</p>");
+}
+
+#[cfg(feature = "replace-engine")]
+#[test]
+fn pipeline_render_vary_by_test() {
+    let pipeline = Pipeline::from_json(r#"{"steps": [
+        {"render": {"template_path": "templates.page", "vary_by": ["user.segment"]}}
+    ]}"#).unwrap();
+    let mut fetcher = new_fetcher();
+    let mut output = Vec::new();
+    let mut template = "".as_bytes();
+    let mut io = PipelineIo::new(&mut fetcher, &mut template, &mut output);
+
+    let mut data_cache = DataCache::new(DataCacheOptions::default());
+    data_cache.declare_dynamic("user");
+    data_cache.insert("user", json!({"segment": "gold", "name": "Taro"}));
+    data_cache.insert("templates.page", json!("<p>{$user.segment}</p>"));
+    assert_eq!(pipeline.run(&mut data_cache, &mut io).unwrap(), PipelineOutcome::Completed);
+    data_cache.insert("templates.page", json!("<p>{$user.name}</p>"));
+    assert!(pipeline.run(&mut data_cache, &mut io).unwrap_err().msg.contains("user.name"));
+    assert!(Pipeline::from_json(r#"{"steps": [{"render": {"vary_by": "user.segment"}}]}"#).is_err());
+}
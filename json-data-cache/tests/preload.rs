@@ -0,0 +1,43 @@
+use std::io::Write;
+
+use json_data_cache::{DataCache, DataCacheOptions, preload::{PreloadAsset, PreloadExtractor, PreloadRule, link_header}};
+use serde_json::json;
+
+#[test]
+fn preload_extractor_test() {
+    let html = br#"<html><head><link rel="stylesheet" href="/main.css"><link rel=preload as=font href='/font.woff2'>
+<link rel="icon" href="/favicon.ico"></head><body><img src="/hero.jpg" fetchpriority="high"><img src="/other.jpg">
+<script src="/app.js" data-critical></script><link rel="stylesheet" href="/main.css"></body></html>"#;
+    let mut rules = PreloadRule::defaults();
+    rules.push(PreloadRule::new("script", "src", "script").when("data-critical", ""));
+
+    // Tags split across chunks
+    for chunk_size in [1, 7, html.len()] {
+        let mut extractor = PreloadExtractor::new(Vec::new(), rules.clone());
+        for chunk in html.chunks(chunk_size) {
+            extractor.write_all(chunk).unwrap();
+        }
+        let (output, assets) = extractor.into_parts();
+        assert_eq!(output, html);
+        let urls: Vec<&str> = assets.iter().map(|asset| asset.url.as_str()).collect();
+        assert_eq!(urls, ["/main.css", "/font.woff2", "/hero.jpg", "/app.js"]);
+        assert_eq!(
+            link_header(&assets[..2]),
+            "</main.css>; rel=preload; as=style, </font.woff2>; rel=preload; as=font; crossorigin"
+        );
+    }
+}
+
+#[test]
+fn preload_tags_test() {
+    let mut data_cache = DataCache::new(DataCacheOptions::default());
+    data_cache.insert("preload", json!([{"url": "/main.css", "as": "style"}, {"url": "/a.woff2?v=\"1\"", "as": "font"}]));
+    assert_eq!(data_cache.preload_assets("preload").unwrap()[0], PreloadAsset { url: "/main.css".to_string(), as_type: "style".to_string() });
+    assert_eq!(
+        data_cache.preload_tags("preload").unwrap(),
+        r#"<link rel="preload" href="/main.css" as="style"><link rel="preload" href="/a.woff2?v=&quot;1&quot;" as="font" crossorigin>"#
+    );
+    assert_eq!(data_cache.preload_tags("missing").unwrap(), "");
+    data_cache.insert("bad", json!([{"url": "/x"}]));
+    assert!(data_cache.preload_tags("bad").is_err());
+}
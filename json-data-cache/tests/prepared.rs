@@ -0,0 +1,68 @@
+#![cfg(feature = "serializer")]
+
+use json_data_cache::{DataCache, DataCacheOptions};
+use serde_json::json;
+
+#[test]
+fn prepared_bytes_test() {
+    let mut data_cache = DataCache::new(DataCacheOptions::default());
+    data_cache.insert("site", json!({"name": "Diverta \"Edge\"", "menu": ["Top", "News"]}));
+    data_cache.insert("_tmp.draft", json!("Left out"));
+    let bytes = data_cache.to_prepared_bytes().unwrap();
+
+    let restored = DataCache::from_prepared_bytes(&bytes, DataCacheOptions::default()).unwrap();
+    assert_eq!(restored.root, json!({"site": {"name": "Diverta \"Edge\"", "menu": ["Top", "News"]}}));
+    assert_eq!(restored.serialized_range("site.menu.1").map(|(bytes, _)| bytes), Some(b"News".as_slice()));
+    assert_eq!(restored.to_prepared_bytes().unwrap().len(), bytes.len());
+
+    #[cfg(feature = "replace-engine")]
+    {
+        let mut restored = restored;
+        let template = "{$site.name} {$site.menu.0} {$$site.menu}";
+        let (mut expected, mut writer) = (Vec::new(), Vec::new());
+        assert!(data_cache.replace_with_data_cache(template.as_bytes(), &mut expected).is_ok());
+        assert!(restored.replace_with_data_cache(template.as_bytes(), &mut writer).is_ok());
+        assert_eq!(writer, expected);
+        // Replaced by the restored DFA, the automaton is not built
+        assert_eq!(restored.automaton_stats().map(|stats| stats.build_time), Some(std::time::Duration::ZERO));
+
+        // Inserts discard the snapshot like any serialized data
+        restored.insert("site.menu.", json!("Contact"));
+        writer.clear();
+        assert!(restored.replace_with_data_cache(b"{$site.menu.2}".as_slice(), &mut writer).is_ok());
+        assert_eq!(writer, b"Contact");
+    }
+
+    assert!(DataCache::from_prepared_bytes(b"JSON", DataCacheOptions::default()).is_err());
+    assert!(DataCache::from_prepared_bytes(b"JDCP1", DataCacheOptions::default()).is_err()); // Previous format
+    assert!(DataCache::from_prepared_bytes(&bytes[..bytes.len() - 3], DataCacheOptions::default()).is_err());
+    let mut trailing = bytes.clone();
+    trailing.push(0);
+    assert!(DataCache::from_prepared_bytes(&trailing, DataCacheOptions::default()).is_err());
+}
+
+#[cfg(feature = "replace-engine")]
+#[test]
+fn prepared_automaton_test() {
+    let mut data_cache = DataCache::new(DataCacheOptions::default());
+    data_cache.insert("site", json!({"name": "Kuroco", "名前": "クロコ"}));
+    data_cache.insert("site.name_long", json!("Kuroco Edge"));
+    data_cache.insert_bytes("banner", b"<b>Raw</b>".to_vec());
+    data_cache.set_static_keys(&["site"]);
+    let template = "{$site.name}|{$site.name_long}|{$site.名前}|{$banner}|{$missing}|{{$site.name}}";
+    let mut expected = Vec::new();
+    assert!(data_cache.replace_with_data_cache(template.as_bytes(), &mut expected).is_ok());
+    assert_eq!(String::from_utf8(expected.clone()).unwrap(), "Kuroco|Kuroco Edge|クロコ|<b>Raw</b>|{$missing}|{Kuroco}");
+
+    // Static keys & raw values are replaced by the DFA too
+    let mut restored = DataCache::from_prepared_bytes(&data_cache.to_prepared_bytes().unwrap(), DataCacheOptions::default()).unwrap();
+    let mut writer = Vec::new();
+    assert!(restored.replace_with_data_cache(template.as_bytes(), &mut writer).is_ok());
+    assert_eq!(writer, expected);
+
+    // Placeholders spanning chunks of the streaming pass
+    let template = "{$site.name_long}-".repeat(10_000);
+    writer.clear();
+    assert!(restored.replace_with_data_cache(template.as_bytes(), &mut writer).is_ok());
+    assert_eq!(writer, "Kuroco Edge-".repeat(10_000).as_bytes());
+}
@@ -0,0 +1,63 @@
+use std::{rc::Rc, time::{Duration, SystemTime}};
+
+use json_data_cache::{DataCache, DataCacheOptions, preview::PreviewCodec, runtime::ManualClock};
+use serde_json::json;
+
+fn at(seconds: u64) -> SystemTime {
+    SystemTime::UNIX_EPOCH + Duration::from_secs(seconds)
+}
+
+#[test]
+fn preview_token_test() {
+    let codec = PreviewCodec::new(b"secret");
+    let token = codec.issue(&json!({"member_id": 7}), at(2_000)).unwrap();
+    assert_eq!(codec.verify(&token, at(1_000)).unwrap(), json!({"member_id": 7, "exp": 2_000}));
+    assert!(codec.verify(&token, at(2_000)).is_err()); // Expired
+    assert!(PreviewCodec::new(b"other").verify(&token, at(1_000)).is_err());
+    let (payload, signature) = token.split_once('.').unwrap();
+    assert!(codec.verify(&format!("{payload}x.{signature}"), at(1_000)).is_err());
+    assert!(codec.verify("garbage", at(1_000)).is_err());
+    assert!(codec.issue(&json!("claims"), at(2_000)).is_err());
+
+    assert_eq!(codec.find_token("page=2&preview_token=abc", "preview_token=def"), Some("abc"));
+    assert_eq!(codec.find_token("page=2", "theme=dark; preview_token=def"), Some("def"));
+    assert_eq!(codec.find_token("preview_token=", ""), None);
+    let codec = codec.param_name("pt").cookie_name("__preview");
+    assert_eq!(codec.find_token("preview_token=abc&pt=ghi", "__preview=def"), Some("ghi"));
+    assert_eq!(codec.find_token("preview_token=abc", "__preview=def"), Some("def"));
+}
+
+#[test]
+fn preview_mode_test() {
+    let codec = PreviewCodec::new(b"secret");
+    let clock = Rc::new(ManualClock::new(at(1_000)));
+    let new_cache = || {
+        let mut data_cache = DataCache::new(DataCacheOptions { clock: Some(clock.clone()), ..Default::default() });
+        data_cache.insert("news", json!([{"id": 1}, {"id": 2, "open_date": 5_000}]));
+        data_cache.insert("page", json!({"title": "Published", "banner": "Sale"}));
+        data_cache.insert("draft", json!({"page": {"title": "Draft", "banner": null}}));
+        data_cache
+    };
+
+    let mut data_cache = new_cache();
+    assert_eq!(data_cache.enter_preview_from_request(&codec, "page=1", "", "draft").unwrap(), None);
+    assert!(!data_cache.is_preview());
+    assert_eq!(data_cache.published_items("news").len(), 1);
+
+    let token = codec.issue(&json!({}), at(2_000)).unwrap();
+    let query = format!("preview_token={token}");
+    assert_eq!(data_cache.enter_preview_from_request(&codec, &query, "", "draft").unwrap(), Some(json!({"exp": 2_000})));
+    assert!(data_cache.is_preview());
+    assert_eq!(data_cache.get("page"), Some(&json!({"title": "Draft"})));
+    assert_eq!(data_cache.published_items("news").len(), 2);
+    assert!(data_cache.is_published("news.1"));
+    assert_eq!(data_cache.filter_published("news").unwrap(), 0);
+    assert_eq!(data_cache.next_schedule_change("news"), None);
+
+    // Expired or tampered tokens leave the cache untouched
+    clock.advance(Duration::from_secs(1_000));
+    let mut data_cache = new_cache();
+    assert!(data_cache.enter_preview(&codec, &token, "draft").is_err());
+    assert!(!data_cache.is_preview());
+    assert_eq!(data_cache.get("page.title"), Some(&json!("Published")));
+}
@@ -0,0 +1,37 @@
+use std::io::Write;
+
+use json_data_cache::{html_rewriter::HtmlRewriter, profiles::TransformProfile};
+
+const PAGE: &str = "<html lang=\"ja\"><head><script src=\"/app.js\"></script><script type=\"application/ld+json\">{}</script>\
+<link rel=\"stylesheet\" href=\"/main.css\"></head><body onload=\"init()\"><img src=\"/a.jpg\" width=\"800\" height=\"600\" loading=\"lazy\">\
+<p style=\"color: red\">Text</p><iframe src=\"/map\"></iframe></body></html>";
+
+const AMP_PAGE: &str = "<html lang=\"ja\" amp><head><script type=\"application/ld+json\">{}</script></head><body>\
+<amp-img src=\"/a.jpg\" width=\"800\" height=\"600\" layout=\"responsive\"></amp-img><p>Text</p><amp-iframe src=\"/map\"></amp-iframe></body></html>";
+
+#[test]
+fn amp_profile_test() {
+    let mut rewriter = HtmlRewriter::new(Vec::new(), TransformProfile::amp().rules);
+    rewriter.write_all(PAGE.as_bytes()).unwrap();
+    assert_eq!(String::from_utf8(rewriter.finish().unwrap()).unwrap(), AMP_PAGE);
+}
+
+#[cfg(feature = "replace-engine")]
+#[test]
+fn replace_with_profile_test() {
+    use json_data_cache::{DataCache, DataCacheOptions, html_rewriter::RewriteRule};
+    use serde_json::json;
+
+    let mut data_cache = DataCache::new(DataCacheOptions::default());
+    data_cache.insert("title", json!("Hello"));
+    data_cache.register_profile(TransformProfile::amp());
+    data_cache.register_profile(TransformProfile::new("text").rule(RewriteRule::new("img").remove()));
+    assert!(data_cache.profile("amp").is_some());
+    let template = b"<h1 onclick=\"x()\">{$title}</h1><img src=\"/a.png\">";
+
+    let output = data_cache.replace_with_profile("amp", template.as_slice(), Vec::new()).unwrap();
+    assert_eq!(output, b"<h1>Hello</h1><amp-img src=\"/a.png\"></amp-img>");
+    let output = data_cache.replace_with_profile("text", template.as_slice(), Vec::new()).unwrap();
+    assert_eq!(output, b"<h1 onclick=\"x()\">Hello</h1>");
+    assert!(data_cache.replace_with_profile("missing", template.as_slice(), Vec::new()).is_err());
+}
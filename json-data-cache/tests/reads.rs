@@ -0,0 +1,56 @@
+use json_data_cache::{DataCache, DataCacheOptions};
+use serde_json::json;
+
+#[test]
+fn reads_test() {
+    let mut data_cache = DataCache::new(DataCacheOptions::default());
+    data_cache.insert("cart", json!([{"price": 3, "count": 2}, {"price": 5, "count": 1}]));
+    data_cache.insert("user.name", json!("Taro"));
+
+    data_cache.get("user.name"); // Not tracked yet
+    data_cache.track_reads();
+    data_cache.get("user.name");
+    data_cache.get_many(&["user.name", "missing"]);
+    data_cache.get_list("cart.*.price");
+    assert_eq!(data_cache.take_reads(), ["cart.0.price", "cart.1.price", "missing", "user.name"]);
+    assert!(data_cache.take_reads().is_empty());
+    data_cache.get("user.name");
+    assert!(data_cache.take_reads().is_empty()); // Not tracking anymore
+
+    data_cache.track_reads();
+    data_cache.define_computed("total", "sum(cart.*.price) + cart.0.count").unwrap();
+    assert!(data_cache.take_reads().is_empty()); // Computed on first read
+    data_cache.track_reads();
+    data_cache.get("total");
+    assert_eq!(data_cache.take_reads(), ["cart.0.count", "cart.0.price", "cart.1.price", "total"]);
+}
+
+#[test]
+fn read_coverage_test() {
+    let mut data_cache = DataCache::new(DataCacheOptions::default());
+    data_cache.insert("page", json!({"title": "Top", "tags": [], "seo": {"description": "..."}}));
+    data_cache.insert("_tmp.draft", json!("scratch"));
+    let reads = [String::from("page.title"), String::from("page.seo"), String::from("missing")];
+    let coverage = data_cache.read_coverage(&reads);
+    assert_eq!(coverage.read, ["page.seo.description", "page.title"]);
+    assert_eq!(coverage.unread, ["page.tags"]);
+    assert!((coverage.ratio() - 2.0 / 3.0).abs() < f64::EPSILON);
+    assert_eq!(DataCache::new(DataCacheOptions::default()).read_coverage(&reads).ratio(), 1.0);
+}
+
+#[cfg(feature = "replace-engine")]
+#[test]
+fn replace_with_read_tracking_test() {
+    let mut data_cache = DataCache::new(DataCacheOptions::default());
+    data_cache.insert("page", json!({"title": "Top", "body": "Hello", "author": {"name": "Taro"}}));
+    let mut output = Vec::new();
+    let reads = data_cache.replace_with_read_tracking(b"<h1>{$page.title}</h1>{$$page.author}{$missing}".as_slice(), &mut output).unwrap();
+    assert_eq!(output, br#"<h1>Top</h1>{\"name\":\"Taro\"}{$missing}"#);
+    assert_eq!(reads, ["page.author", "page.title"]);
+    assert_eq!(data_cache.read_coverage(&reads).unread, ["page.body"]);
+
+    // Caches borrowed by replace_with_caches track their own reads
+    data_cache.track_reads();
+    assert!(DataCache::replace_with_caches(&[&data_cache], b"{$page.body}".as_slice(), Vec::new()).is_ok());
+    assert_eq!(data_cache.take_reads(), ["page.body"]);
+}
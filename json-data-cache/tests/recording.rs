@@ -0,0 +1,38 @@
+use json_data_cache::{DataCache, DataCacheOptions, recording::{Recorder, replay}};
+use serde_json::json;
+
+#[test]
+fn recording_test() {
+    let mut data_cache = DataCache::new(DataCacheOptions::default());
+    data_cache.insert("site.name", json!("Kuroco"));
+    let mut recorder = Recorder::new(data_cache, Vec::new()).unwrap();
+    recorder.insert("content", json!({"subject": "Hello"})).unwrap();
+    recorder.merge(json!({"user": {"name": "Taro"}})).unwrap();
+    recorder.insert_bulk(Vec::from([(String::from("tags"), json!(["a"]))])).unwrap();
+    assert_eq!(recorder.get("content.subject").unwrap(), Some(&json!("Hello")));
+    assert_eq!(recorder.get("missing").unwrap(), None);
+    #[cfg(feature = "replace-engine")]
+    {
+        let mut output = Vec::new();
+        recorder.replace_with_data_cache(b"{$site.name} : {$content.subject} {$user.name}".as_slice(), &mut output).unwrap();
+        assert_eq!(output, b"Kuroco : Hello Taro");
+    }
+    let (data_cache, log) = recorder.into_parts();
+
+    let report = replay(DataCacheOptions::default(), log.as_slice()).unwrap();
+    assert_eq!(report.data_cache.root, data_cache.root);
+    assert!(report.mismatches.is_empty());
+    #[cfg(feature = "replace-engine")]
+    assert_eq!(report.renders, [b"Kuroco : Hello Taro"]);
+
+    // A log edited to reproduce a different state
+    let log = String::from_utf8(log).unwrap().replace(r#""subject":"Hello""#, r#""subject":"Bye""#);
+    let report = replay(DataCacheOptions::default(), log.as_bytes()).unwrap();
+    #[cfg(feature = "replace-engine")]
+    assert_eq!(report.mismatches.len(), 2);
+    #[cfg(not(feature = "replace-engine"))]
+    assert_eq!(report.mismatches.len(), 1);
+    assert!(report.mismatches[0].starts_with("Line 5 : get content.subject"));
+
+    assert!(replay(DataCacheOptions::default(), b"{\"op\": \"unknown\"}".as_slice()).is_err());
+}
@@ -0,0 +1,77 @@
+use json_data_cache::{DataCache, DataCacheOptions, redirect::{DEFAULT_REDIRECT_ALLOWLIST_PATH, DEFAULT_REDIRECT_FALLBACK, RedirectAllowlist}};
+use serde_json::json;
+
+#[test]
+fn redirect_allowlist_test() {
+    let allowlist = RedirectAllowlist::from_value(&json!({"hosts": ["example.com", "*.Example.jp"], "fallback": "/home"})).unwrap();
+    assert_eq!(allowlist.schemes, ["https", "http"]);
+    for target in [
+        "/mypage?tab=1",
+        "  /news  ",
+        "https://example.com",
+        "HTTP://EXAMPLE.COM:8080/a?b#c",
+        "//example.com/path",
+        "https://www.example.jp/",
+        "https://a.b.example.jp",
+        "https://example.com./",
+    ] {
+        assert!(allowlist.validate(target).is_ok(), "{target}");
+        assert_eq!(allowlist.safe_target(target), target.trim());
+    }
+    for (target, reason) in [
+        ("https://evil.com/", "host evil.com"),
+        ("https://example.jp/", "host example.jp"), // Subdomains only
+        ("https://evilexample.com", "host evilexample.com"),
+        ("https://example.com.evil.com", "host example.com.evil.com"),
+        ("https://example.com@evil.com", "credentials"),
+        ("//evil.com", "host evil.com"),
+        ("/\\evil.com", "Invalid"),
+        ("/\t/evil.com", "Invalid"),
+        ("javascript:alert(1)", "scheme javascript"),
+        ("ftp://example.com", "scheme ftp"),
+        ("https:example.com", "no host"),
+        ("mypage", "root-relative"),
+        ("", "Invalid"),
+    ] {
+        let e = allowlist.validate(target).unwrap_err();
+        assert!(e.msg.contains(reason), "{target}: {}", e.msg);
+        assert_eq!(allowlist.safe_target(target), "/home");
+    }
+
+    assert!(RedirectAllowlist::from_value(&json!({"hosts": "example.com"})).is_err());
+    assert!(RedirectAllowlist::from_value(&json!({"hosts": [], "fallback": 1})).is_err());
+    let https_only = RedirectAllowlist::from_value(&json!({"hosts": ["example.com"], "schemes": ["https"]})).unwrap();
+    assert!(https_only.validate("http://example.com").is_err());
+    assert_eq!(https_only.fallback, DEFAULT_REDIRECT_FALLBACK);
+}
+
+#[test]
+fn validate_redirect_target_test() {
+    let mut data_cache = DataCache::new(DataCacheOptions::default());
+    assert!(data_cache.validate_redirect_target("/", DEFAULT_REDIRECT_ALLOWLIST_PATH).unwrap_err().msg.contains("No redirect allowlist"));
+    assert_eq!(data_cache.safe_redirect_target("https://example.com", DEFAULT_REDIRECT_ALLOWLIST_PATH), "/"); // Fails closed
+
+    data_cache.insert(DEFAULT_REDIRECT_ALLOWLIST_PATH, json!({"hosts": ["example.com"]}));
+    assert!(data_cache.validate_redirect_target("https://example.com/a", DEFAULT_REDIRECT_ALLOWLIST_PATH).is_ok());
+    assert!(data_cache.validate_redirect_target("https://evil.com/a", DEFAULT_REDIRECT_ALLOWLIST_PATH).is_err());
+    assert_eq!(data_cache.safe_redirect_target("https://evil.com/a", DEFAULT_REDIRECT_ALLOWLIST_PATH), "/");
+}
+
+#[cfg(feature = "replace-engine")]
+#[test]
+fn safe_redirect_filter_test() {
+    let mut data_cache = DataCache::new(DataCacheOptions { safe_redirect_keys: Vec::from([String::from("query")]), ..Default::default() });
+    data_cache.insert(DEFAULT_REDIRECT_ALLOWLIST_PATH, json!({"hosts": ["example.com"], "fallback": "/top"}));
+    data_cache.insert("query", json!({"next": "https://example.com/mypage", "back": "https://evil.com/"}));
+    data_cache.insert("other", json!("https://evil.com/"));
+    let template = "{$query.next|safe_redirect} {$query.back|safe_redirect} {$query.back} {$other|safe_redirect}";
+    let mut output = Vec::new();
+    data_cache.replace_with_data_cache(template.as_bytes(), &mut output).unwrap();
+    assert_eq!(String::from_utf8(output).unwrap(), "https://example.com/mypage /top https://evil.com/ {$other|safe_redirect}");
+
+    // The allowlist is read again once modified
+    data_cache.insert(DEFAULT_REDIRECT_ALLOWLIST_PATH, json!({"hosts": ["evil.com"]}));
+    let mut output = Vec::new();
+    data_cache.replace_with_data_cache(template.as_bytes(), &mut output).unwrap();
+    assert_eq!(String::from_utf8(output).unwrap(), "/top https://evil.com/ https://evil.com/ {$other|safe_redirect}");
+}
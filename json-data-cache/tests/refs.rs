@@ -0,0 +1,89 @@
+use json_data_cache::{DataCache, DataCacheOptions};
+use serde_json::json;
+
+#[test]
+fn refs_test() {
+    let mut data_cache = DataCache::new(DataCacheOptions::default());
+    data_cache.insert("content.details", json!({"subject": "Hello", "tags": ["a", "b"]}));
+    data_cache.insert("seo.description", json!("Description"));
+
+    assert!(data_cache.insert_ref("seo.title", "content.details.subject").is_ok());
+    assert!(data_cache.insert_ref("page", "content.details").is_ok());
+    assert!(data_cache.insert_ref("headline", "seo.title").is_ok()); // Reference to a reference
+    assert_eq!(data_cache.get_ref("seo.title"), Some("content.details.subject"));
+    assert_eq!(data_cache.get("seo.title"), Some(&json!("Hello")));
+    assert_eq!(data_cache.get("page.tags.1"), Some(&json!("b")));
+    assert_eq!(data_cache.get("headline"), Some(&json!("Hello")));
+    assert_eq!(data_cache.get_list("page.tags.*"), [&json!("a"), &json!("b")]);
+
+    // References follow updates of their target
+    data_cache.insert("content.details.subject", json!("World"));
+    assert_eq!(data_cache.get("seo.title"), Some(&json!("World")));
+    let map = data_cache.as_string_values_map();
+    assert_eq!(map.get("seo.title").map(|s| s.as_str()), Some("World"));
+    assert_eq!(map.get("page.tags").map(|s| s.as_str()), Some(r#"["a","b"]"#));
+    assert_eq!(map.get("seo.description").map(|s| s.as_str()), Some("Description"));
+
+    // Cycles are rejected
+    assert!(data_cache.insert_ref("content.details.subject", "headline").is_err());
+    assert!(data_cache.insert_ref("loop", "loop.child").is_err());
+    assert_eq!(data_cache.get("headline"), Some(&json!("World")));
+
+    #[cfg(feature = "serializer")]
+    {
+        let expected = data_cache.as_string_values_map();
+        assert_eq!(data_cache.string_values_view(), &expected);
+        data_cache.insert("content.details.tags.", json!("c"));
+        let expected = data_cache.as_string_values_map();
+        assert_eq!(data_cache.string_values_view(), &expected);
+        assert_eq!(data_cache.keys_with_prefix("page.tags."), ["page.tags.0", "page.tags.1", "page.tags.2"]);
+    }
+
+    #[cfg(feature = "replace-engine")]
+    {
+        let mut writer = Vec::new();
+        let input = "{$seo.title} / {$headline} / {$page.tags.2} / {$$page.tags}";
+        assert!(data_cache.replace_with_data_cache(input.as_bytes(), &mut writer).is_ok());
+        assert_eq!(&String::from_utf8(writer).unwrap(), r#"World / World / c / [\"a\",\"b\",\"c\"]"#);
+    }
+}
+
+#[test]
+fn fallbacks_test() {
+    let mut data_cache = DataCache::new(DataCacheOptions::default());
+    data_cache.insert("user.pref.locale", json!(null));
+    data_cache.insert("site.default_locale", json!("ja"));
+    data_cache.insert("site.default_address", json!({"country": "JP"}));
+    assert!(data_cache.insert_fallback("locale", &["user.pref.locale", "geo.locale", "site.default_locale"]).is_ok());
+    assert!(data_cache.insert_fallback("address", &["user.address", "site.default_address"]).is_ok());
+    assert_eq!(data_cache.get_fallback("locale").map(<[String]>::len), Some(3));
+    assert_eq!(data_cache.get_with_fallback(&["user.pref.locale", "geo.locale", "site.default_locale"]), Some(&json!("ja")));
+    assert_eq!(data_cache.get_with_fallback(&["user.pref.locale", "geo.locale"]), None);
+    assert_eq!(data_cache.get("locale"), Some(&json!("ja")));
+    assert_eq!(data_cache.get("address.country"), Some(&json!("JP")));
+
+    data_cache.insert("geo.locale", json!("en"));
+    assert_eq!(data_cache.get("locale"), Some(&json!("en")));
+    assert_eq!(data_cache.as_string_values_map().get("locale").map(|s| s.as_str()), Some("en"));
+
+    assert!(data_cache.insert_fallback("empty", &[]).is_err());
+    assert!(data_cache.insert_fallback("loop", &["site", "loop.child"]).is_err());
+
+    #[cfg(feature = "serializer")]
+    {
+        let expected = data_cache.as_string_values_map();
+        assert_eq!(data_cache.string_values_view(), &expected);
+        data_cache.insert("user.pref.locale", json!("fr"));
+        let expected = data_cache.as_string_values_map();
+        assert_eq!(data_cache.string_values_view(), &expected);
+        assert_eq!(expected.get("locale").map(|s| s.as_str()), Some("fr"));
+    }
+
+    #[cfg(feature = "replace-engine")]
+    {
+        data_cache.insert("user.address.country", json!("FR"));
+        let mut writer = Vec::new();
+        assert!(data_cache.replace_with_data_cache(b"{$locale} {$address.country}".as_slice(), &mut writer).is_ok());
+        assert_eq!(writer, b"fr FR");
+    }
+}
@@ -0,0 +1,76 @@
+use json_data_cache::{DataCache, DataCacheOptions, render_context::{RenderContext, RenderContextPaths, parse_timezone}};
+use serde_json::json;
+
+#[test]
+fn render_context_test() {
+    let mut data_cache = DataCache::new(DataCacheOptions::default());
+    assert_eq!(data_cache.render_context(), &RenderContext::default());
+
+    data_cache.insert("locale", json!("ja_JP"));
+    data_cache.insert("timezone", json!("Asia/Tokyo"));
+    data_cache.insert("currency", json!("jpy"));
+    data_cache.insert("device", json!("Mobile"));
+    let context = RenderContext { locale: String::from("ja-JP"), timezone_offset_minutes: 540, currency: String::from("JPY"), device: String::from("mobile") };
+    assert_eq!(data_cache.render_context(), &context);
+
+    // Derived again after inserts
+    data_cache.insert("timezone", json!("not a zone"));
+    assert_eq!(data_cache.render_context().timezone_offset_minutes, 0);
+
+    let english = RenderContext { locale: String::from("en-US"), ..Default::default() };
+    data_cache.override_render_context(Some(english.clone()));
+    data_cache.insert("locale", json!("fr"));
+    assert_eq!(data_cache.render_context(), &english);
+    data_cache.override_render_context(None);
+    assert_eq!(data_cache.render_context().locale, "fr");
+
+    data_cache.set_render_context_paths(RenderContextPaths { locale: String::from("user.lang"), ..Default::default() });
+    assert_eq!(data_cache.render_context().locale, "en");
+}
+
+#[test]
+fn parse_timezone_test() {
+    assert_eq!(parse_timezone(&json!(540)), Some(540));
+    assert_eq!(parse_timezone(&json!("+09:00")), Some(540));
+    assert_eq!(parse_timezone(&json!("-05:30")), Some(-330));
+    assert_eq!(parse_timezone(&json!("+02")), Some(120));
+    assert_eq!(parse_timezone(&json!("utc")), Some(0));
+    assert_eq!(parse_timezone(&json!("Z")), Some(0));
+    assert_eq!(parse_timezone(&json!("+25:00")), None);
+    assert_eq!(parse_timezone(&json!("Europe/Paris")), None); // Daylight saving time
+    assert_eq!(parse_timezone(&json!(100_000)), None);
+}
+
+#[cfg(feature = "replace-engine")]
+#[test]
+fn context_renderers_test() {
+    use json_data_cache::renderers::{localized_datetime, localized_number, localized_price, localized_text};
+
+    let render = |data_cache: &mut DataCache, template: &str| {
+        let mut output = Vec::new();
+        data_cache.replace_with_data_cache(template.as_bytes(), &mut output).unwrap();
+        String::from_utf8(output).unwrap()
+    };
+    let mut data_cache = DataCache::new(DataCacheOptions::default());
+    data_cache.insert("item", json!({"price": 1234.5, "views": 1234567, "published_at": "2024-12-31T20:30:00Z", "label": {"ja": "新着", "en": "New"}}));
+    data_cache.insert("locale", json!("ja-JP"));
+    data_cache.insert("timezone", json!(540));
+    data_cache.insert("currency", json!("JPY"));
+    data_cache.set_context_renderer("item.price", localized_price());
+    data_cache.set_context_renderer("item.views", localized_number());
+    data_cache.set_context_renderer("item.published_at", localized_datetime());
+    data_cache.set_context_renderer("item.label", localized_text());
+    let template = "{$item.price} {$item.views} {$item.published_at} {$item.label}";
+    assert_eq!(render(&mut data_cache, template), "1,234円 1,234,567 2025/01/01 05:30 新着"); // JPY has no fraction digits
+
+    let mut output = Vec::new();
+    let german = RenderContext { locale: String::from("de-DE"), timezone_offset_minutes: 60, currency: String::from("EUR"), ..Default::default() };
+    data_cache.replace_with_context(german, template.as_bytes(), &mut output).unwrap();
+    assert_eq!(String::from_utf8(output).unwrap(), "1.234,50\u{a0}€ 1.234.567 31.12.2024 21:30 {\"ja\":\"新着\",\"en\":\"New\"}");
+    // The context of the cache is back
+    assert_eq!(render(&mut data_cache, template), "1,234円 1,234,567 2025/01/01 05:30 新着");
+
+    data_cache.insert("locale", json!("en-US"));
+    data_cache.insert("currency", json!("USD"));
+    assert_eq!(render(&mut data_cache, template), "$1,234.50 1,234,567 01/01/2025 05:30 New");
+}
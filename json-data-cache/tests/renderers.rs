@@ -0,0 +1,58 @@
+#![cfg(feature = "replace-engine")]
+
+use json_data_cache::{DataCache, DataCacheOptions, japanese::{strip_furigana, truncate_chars}, renderers::{datetime_at_offset, grouped_number, text, truncated}};
+use serde_json::{Value, json};
+
+fn render(data_cache: &mut DataCache, template: &str) -> String {
+    let mut output = Vec::new();
+    data_cache.replace_with_data_cache(template.as_bytes(), &mut output).unwrap();
+    String::from_utf8(output).unwrap()
+}
+
+#[test]
+fn renderers_test() {
+    let mut data_cache = DataCache::new(DataCacheOptions::default());
+    data_cache.insert("items", json!([{"price": 1234}, {"price": -1234567.5}, {"price": "980"}, {"price": null}]));
+    data_cache.insert("published_at", json!("2024-12-31T20:30:00Z"));
+    data_cache.insert("updated_at", json!(1_700_000_000));
+    data_cache.set_renderer("items.*.price", grouped_number("", "円", ","));
+    data_cache.set_renderer("published_at", datetime_at_offset(9 * 60));
+    data_cache.set_renderer("updated_at", datetime_at_offset(-5 * 60));
+    assert_eq!(
+        render(&mut data_cache, "{$items.0.price} {$items.1.price} {$items.2.price} {$items.3.price} {$published_at} {$updated_at}"),
+        "1,234円 -1,234,567.5円 980円 null 2025-01-01 05:30 2023-11-14 17:13"
+    );
+    // The tree & doubly serialized values keep the raw values
+    assert_eq!(data_cache.get("items.0.price"), Some(&json!(1234)));
+    assert_eq!(render(&mut data_cache, "{$$items.0}"), r#"{\"price\":1234}"#);
+
+    data_cache.set_renderer("items.*.price", |value: &Value| value.as_i64().map(|price| format!("¥{price}")));
+    assert_eq!(render(&mut data_cache, "{$items.0.price} {$items.2.price}"), "¥1234 980");
+    assert!(data_cache.remove_renderer("items.*.price"));
+    assert!(!data_cache.remove_renderer("items.*.price"));
+    assert_eq!(render(&mut data_cache, "{$items.0.price}"), "1234");
+
+    // Identical rendered values are stored once
+    data_cache.remove_renderer("published_at");
+    data_cache.remove_renderer("updated_at");
+    data_cache.insert("tags", json!(["sale", "sale", "new", "sale"]));
+    data_cache.set_renderer("tags.*", |value: &Value| value.as_str().map(str::to_uppercase));
+    assert_eq!(render(&mut data_cache, "{$tags.0} {$tags.1} {$tags.2} {$tags.3}"), "SALE SALE NEW SALE");
+    assert_eq!(data_cache.automaton_stats().unwrap().rendered_bytes, "SALENEW".len());
+}
+
+#[test]
+fn text_renderer_test() {
+    let mut data_cache = DataCache::new(DataCacheOptions::default());
+    data_cache.insert("news", json!([{"title": "<ruby>東京<rt>とうきょう</rt></ruby>の天気予報"}, {"title": 1}]));
+    data_cache.set_renderer("news.*.title", text(|title| truncate_chars(&strip_furigana(title), 5, "…")));
+    assert_eq!(render(&mut data_cache, "{$news.0.title} {$news.1.title}"), "東京の天… 1");
+}
+
+#[test]
+fn truncated_renderer_test() {
+    let mut data_cache = DataCache::new(DataCacheOptions::default());
+    data_cache.insert("comments", json!([{"body": "Nice 👍🏽👍🏽👍🏽"}, {"body": "Short"}]));
+    data_cache.set_renderer("comments.*.body", truncated(7, "…"));
+    assert_eq!(render(&mut data_cache, "{$comments.0.body} {$comments.1.body}"), "Nice 👍🏽… Short");
+}
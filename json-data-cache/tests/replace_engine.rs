@@ -0,0 +1,495 @@
+#![cfg(feature = "replace-engine")]
+
+use std::{rc::Rc, time::{Duration, SystemTime}};
+
+use json_data_cache::{
+    AhoCorasickKind, AuditMarkers, BUDGET_DEGRADED_REASON, DataCache, DataCacheOptions, FlushPolicy, MatchKind, OnBudgetExceeded,
+    PlaceholderMap, RENDER_BUDGET_EXCEEDED, RenderBudget, runtime::ManualClock,
+};
+use serde_json::json;
+
+#[test]
+fn data_cache_serialize_only_test() {
+    let mut data_cache = DataCache::new(DataCacheOptions {
+        serialize_only: Vec::from([String::from("content.*"), String::from("user.name")]),
+        serialize_exclude: Vec::from([String::from("content.secret")]),
+        ..Default::default()
+    });
+
+    data_cache.insert("content", json!({"title": "my_title", "list": [{"id": 1}], "secret": "hidden"}));
+    data_cache.insert("user", json!({"name": "my_name", "email": "my_email"}));
+
+    for (input, replacement) in [
+        ("{$content.title}", "my_title"),
+        ("{$content.list.0.id}", "1"),
+        ("{$$content.title}", "my_title"),
+        ("{$user.name}", "my_name"),
+        ("{$content}", "{$content}"), // Only children of content are included
+        ("{$content.secret}", "{$content.secret}"),
+        ("{$user.email}", "{$user.email}"),
+    ] {
+        let mut writer = Vec::new();
+        assert!(data_cache.replace_with_data_cache(input.as_bytes(), &mut writer).is_ok());
+        assert_eq!(&String::from_utf8(writer).unwrap(), replacement);
+    }
+
+    // Serialized ranges & string values are restricted to the same keys
+    assert_eq!(data_cache.serialized_range("content.title").map(|(value, _)| value), Some("my_title".as_bytes()));
+    assert_eq!(data_cache.serialized_range("content.secret"), None);
+    assert_eq!(data_cache.serialized_range("user.email"), None);
+    assert_eq!(data_cache.keys_with_prefix("user"), ["user.name"]);
+    assert_eq!(data_cache.as_str_values().count(), 5);
+    let mut keys: Vec<String> = data_cache.as_string_values_map().into_keys().collect();
+    keys.sort();
+    assert_eq!(keys, ["content.list", "content.list.0", "content.list.0.id", "content.title", "user.name"]);
+    let mut view_keys: Vec<&String> = data_cache.string_values_view().keys().collect();
+    view_keys.sort();
+    assert_eq!(view_keys, keys.iter().collect::<Vec<_>>());
+    data_cache.insert("user.email", json!("other_email"));
+    assert_eq!(data_cache.string_values_view().get("user.email"), None);
+}
+
+#[test]
+fn data_cache_automaton_stats_test() {
+    let mut data_cache = DataCache::new(DataCacheOptions {
+        max_patterns: Some(4),
+        ..Default::default()
+    });
+
+    data_cache.insert("a", json!({"b": 1}));
+    assert!(data_cache.automaton_stats().is_none());
+    assert!(data_cache.replace_with_data_cache("{$a.b}".as_bytes(), Vec::new()).is_ok());
+    let stats = data_cache.automaton_stats().unwrap();
+    assert_eq!(stats.pattern_count, 4); // a & a.b, serialized and double serialized
+    assert!(stats.heap_bytes > 0);
+
+    data_cache.insert("c", json!("too many"));
+    assert!(data_cache.automaton_stats().is_none());
+    assert!(data_cache.replace_with_data_cache("{$a.b}".as_bytes(), Vec::new()).is_err());
+}
+
+#[test]
+fn data_cache_interned_values_test() {
+    let mut data_cache = DataCache::new(DataCacheOptions::default());
+    for key in ["a", "b", "c"] {
+        data_cache.insert(key, json!("published"));
+    }
+    data_cache.insert("d", json!("draft"));
+    data_cache.prepare().unwrap();
+    let stats = data_cache.automaton_stats().unwrap();
+    assert_eq!(stats.pattern_count, 8);
+    assert_eq!(stats.rendered_bytes, 0); // Without renderers, values are only sliced out of the serialized data
+    // The 3 "published" share one slot, along with their doubly serialized form (the same bytes without quotes to escape)
+    assert_eq!(stats.value_bytes, "published".len() + "draft".len());
+
+    let mut output = Vec::new();
+    data_cache.replace_with_data_cache(b"{$a} {$b} {$$c} {$d}".as_slice(), &mut output).unwrap();
+    assert_eq!(output, b"published published published draft");
+}
+
+#[test]
+fn data_cache_ac_options_test() {
+    let mut data_cache = DataCache::new(DataCacheOptions {
+        ac_kind: Some(AhoCorasickKind::DFA),
+        ac_prefilter: Some(false),
+        ac_match_kind: Some(MatchKind::LeftmostLongest),
+        ..Default::default()
+    });
+    data_cache.insert("user", json!({"name": "my_name"}));
+
+    let mut writer = Vec::new();
+    assert!(data_cache.replace_with_data_cache("Hello {$user.name} ! {$$user}".as_bytes(), &mut writer).is_ok());
+    assert_eq!(&String::from_utf8(writer).unwrap(), r#"Hello my_name ! {\"name\":\"my_name\"}"#);
+}
+
+#[test]
+fn data_cache_prepare_test() {
+    let mut data_cache = DataCache::new(DataCacheOptions::default());
+    data_cache.insert("a", json!("my_a_value"));
+
+    let mut yield_count = 0;
+    assert!(data_cache.prepare_with_yield(|| yield_count += 1).is_ok());
+    assert_eq!(yield_count, 1);
+    assert!(data_cache.automaton_stats().is_some());
+
+    // Already built : nothing to do
+    assert!(data_cache.prepare_with_yield(|| yield_count += 1).is_ok());
+    assert_eq!(yield_count, 1);
+
+    let mut writer = Vec::new();
+    assert!(data_cache.replace_with_data_cache("{$a}".as_bytes(), &mut writer).is_ok());
+    assert_eq!(&String::from_utf8(writer).unwrap(), "my_a_value");
+}
+
+#[test]
+fn data_cache_raw_bytes_test() {
+    let mut data_cache = DataCache::new(DataCacheOptions::default());
+    data_cache.insert("title", json!("Title"));
+    data_cache.insert("fragment", json!("json value"));
+    data_cache.insert_bytes("fragment", Vec::from([0x1f, 0x8b, 0xff, 0x00]));
+    data_cache.insert_bytes("logo", Vec::from(*b"\x89PNG"));
+
+    // Raw values stay outside of the JSON tree
+    assert_eq!(data_cache.get("logo"), None);
+    assert_eq!(data_cache.get("fragment"), Some(&json!("json value")));
+    assert_eq!(data_cache.get_bytes("logo"), Some(&b"\x89PNG"[..]));
+
+    let mut writer = Vec::new();
+    assert!(data_cache.replace_with_data_cache("{$title}:{$fragment}:{$logo}:{$$fragment}".as_bytes(), &mut writer).is_ok());
+    assert_eq!(writer, b"Title:\x1f\x8b\xff\x00:\x89PNG:json value");
+
+    assert_eq!(data_cache.remove_bytes("fragment"), Some(Vec::from([0x1f, 0x8b, 0xff, 0x00])));
+    let mut writer = Vec::new();
+    assert!(data_cache.replace_with_data_cache("{$fragment}:{$logo}".as_bytes(), &mut writer).is_ok());
+    assert_eq!(writer, b"json value:\x89PNG");
+}
+
+#[test]
+fn data_cache_fragment_test() {
+    let mut data_cache = DataCache::new(DataCacheOptions::default());
+    data_cache.insert("menu", json!({"home": "Home", "news": "News"}));
+    data_cache.register_fragment("nav", Vec::from(*b"<a>{$menu.home}</a><a>{$menu.news}</a>"));
+
+    assert_eq!(data_cache.render_fragment_cached("nav", &["menu"]).unwrap(), b"<a>Home</a><a>News</a>");
+
+    // Unrelated modifications reuse the cached output, without building the automaton again
+    data_cache.insert("user.name", json!("my_name"));
+    assert_eq!(data_cache.render_fragment_cached("nav", &["menu"]).unwrap(), b"<a>Home</a><a>News</a>");
+    assert!(data_cache.automaton_stats().is_none());
+
+    // Same value : dependency is dirty but the fingerprint is unchanged
+    data_cache.insert("menu.home", json!("Home"));
+    assert_eq!(data_cache.render_fragment_cached("nav", &["menu"]).unwrap(), b"<a>Home</a><a>News</a>");
+    assert!(data_cache.automaton_stats().is_none());
+
+    data_cache.insert("menu.home", json!("Top"));
+    assert_eq!(data_cache.render_fragment_cached("nav", &["menu"]).unwrap(), b"<a>Top</a><a>News</a>");
+    assert!(data_cache.automaton_stats().is_some());
+
+    assert!(data_cache.render_fragment_cached("unknown", &["menu"]).is_err());
+}
+
+#[test]
+fn data_cache_replace_progress_test() {
+    use json_data_cache::{CancellationToken, ReplaceProgress};
+
+    let mut data_cache = DataCache::new(DataCacheOptions::default());
+    data_cache.insert("name", json!("world"));
+    let template = "Hello {$name} ! ".repeat(100_000);
+
+    let mut last_progress = None;
+    let mut output = Vec::new();
+    let result = data_cache.replace_with_data_cache_progress(template.as_bytes(), &mut output, |progress: &ReplaceProgress| {
+        last_progress = Some(*progress);
+    }, None);
+    assert!(result.is_ok());
+    let last_progress = last_progress.unwrap();
+    assert_eq!(last_progress.bytes_in, template.len() as u64);
+    assert_eq!(last_progress.bytes_out, output.len() as u64);
+    assert_eq!(output, "Hello world ! ".repeat(100_000).as_bytes());
+
+    // Cancelled after the first write
+    let cancellation = CancellationToken::new();
+    let mut writes = 0;
+    let mut output = Vec::new();
+    let result = data_cache.replace_with_data_cache_progress(template.as_bytes(), &mut output, |_: &ReplaceProgress| {
+        writes += 1;
+        cancellation.cancel();
+    }, Some(&cancellation.clone()));
+    assert!(result.is_err_and(|e| e.msg.contains("cancelled")));
+    assert_eq!(writes, 1);
+    assert!(output.len() < template.len());
+}
+
+#[test]
+fn data_cache_replace_range_test() {
+    let mut data_cache = DataCache::new(DataCacheOptions::default());
+    data_cache.insert("title", json!("タイトル"));
+    data_cache.insert("id", json!(7));
+    let template = b"<h1>{$title}</h1>{$id}<p>{$missing} {$$title}</p>";
+    let mut full = Vec::new();
+    data_cache.replace_with_data_cache(template.as_slice(), &mut full).unwrap();
+
+    let map = data_cache.placeholder_map(template).unwrap();
+    assert_eq!(map.output_len, full.len());
+    assert_eq!(map.placeholders.len(), 3);
+    assert_eq!(&full[map.placeholders[0].output.clone()], "タイトル".as_bytes());
+    for start in 0..=full.len() {
+        for end in start..=full.len() {
+            let mut partial = Vec::new();
+            assert!(data_cache.replace_range(template, start..end, &map, &mut partial).is_ok());
+            assert_eq!(partial, &full[start..end]);
+        }
+    }
+    assert!(data_cache.replace_range(template, 0..full.len() + 1, &map, Vec::new()).is_err());
+    assert!(data_cache.replace_range(b"<h1>{$title}</h1>", 0..4, &map, Vec::new()).is_err());
+
+    // Maps are stored along with their template, their fingerprint not depending on the process
+    let stored = serde_json::to_string(&map.to_json()).unwrap();
+    let loaded = PlaceholderMap::from_json(&serde_json::from_str(&stored).unwrap()).unwrap();
+    assert_eq!(loaded, map);
+    let mut other_cache = DataCache::new(DataCacheOptions::default());
+    other_cache.insert("title", json!("タイトル"));
+    other_cache.insert("id", json!(7));
+    assert_eq!(other_cache.placeholder_map(template).unwrap(), map);
+    let mut partial = Vec::new();
+    assert!(other_cache.replace_range(template, 3..20, &loaded, &mut partial).is_ok());
+    assert_eq!(partial, &full[3..20]);
+    assert!(PlaceholderMap::from_json(&json!({"template_len": 1, "output_len": 1, "fingerprint": 0, "placeholders": [[2, 1, 0, 0]]})).is_err());
+
+    // The output changed since the map was computed
+    data_cache.insert("id", json!(8));
+    assert!(data_cache.replace_range(template, 0..4, &map, Vec::new()).is_err());
+
+    // Static keys & escapes are located like they are replaced
+    let mut data_cache = DataCache::new(DataCacheOptions { escape_placeholders: true, ..Default::default() });
+    data_cache.insert("site.name", json!("Kuroco"));
+    data_cache.insert("title", json!("Title"));
+    data_cache.set_static_keys(&["site"]);
+    let template = br"{$title} {$site.name} \{$title}";
+    let mut full = Vec::new();
+    data_cache.replace_with_data_cache(template.as_slice(), &mut full).unwrap();
+    assert_eq!(full, b"Title Kuroco {$title}");
+    let map = data_cache.placeholder_map(template).unwrap();
+    assert_eq!(map.output_len, full.len());
+    let mut partial = Vec::new();
+    assert!(data_cache.replace_range(template, 2..full.len(), &map, &mut partial).is_ok());
+    assert_eq!(partial, &full[2..]);
+}
+
+#[test]
+fn data_cache_build_step_test() {
+    use json_data_cache::{BuildProgress, BuildStep};
+    use std::{rc::Rc, time::{Duration, SystemTime}};
+    use json_data_cache::runtime::ManualClock;
+
+    // The manual clock does not advance during steps, so a zero budget is always spent after one step
+    let mut data_cache = DataCache::new(DataCacheOptions {
+        clock: Some(Rc::new(ManualClock::new(SystemTime::UNIX_EPOCH))),
+        ..Default::default()
+    });
+    data_cache.insert("title", json!("Hello"));
+    assert_eq!(data_cache.build_step(Duration::ZERO).unwrap(), BuildProgress::Pending(BuildStep::CollectPatterns));
+    assert_eq!(data_cache.build_step(Duration::ZERO).unwrap(), BuildProgress::Pending(BuildStep::BuildAutomaton));
+    assert!(data_cache.automaton_stats().is_none());
+    assert_eq!(data_cache.build_step(Duration::ZERO).unwrap(), BuildProgress::Done);
+    assert_eq!(data_cache.automaton_stats().unwrap().pattern_count, 2);
+    assert_eq!(data_cache.build_step(Duration::ZERO).unwrap(), BuildProgress::Done);
+
+    // Inserts restart the construction, which a large enough budget completes at once
+    data_cache.insert("subtitle", json!("World"));
+    data_cache.build_step(Duration::ZERO).unwrap();
+    data_cache.insert("footer", json!("!"));
+    assert_eq!(data_cache.build_step(Duration::from_secs(1)).unwrap(), BuildProgress::Done);
+    let mut writer = Vec::new();
+    assert!(data_cache.replace_with_data_cache(b"{$title} {$subtitle}{$footer}".as_slice(), &mut writer).is_ok());
+    assert_eq!(writer, b"Hello World!");
+}
+
+#[test]
+fn data_cache_replace_with_caches_test() {
+    let mut page_cache = DataCache::new(DataCacheOptions::default());
+    page_cache.insert("title", json!("Page"));
+    page_cache.insert("body", json!("{$site.name}"));
+    let mut site_cache = DataCache::new(DataCacheOptions::default());
+    site_cache.insert("title", json!("Site"));
+    site_cache.insert("site", json!({"name": "Kuroco", "lang": "ja"}));
+    site_cache.set_static_keys(&["site"]);
+
+    let input = b"{$title}|{$$title}|{$body}|{$site.name}|{$site.lang}|{$missing}".as_slice();
+    let mut writer = Vec::new();
+    assert!(DataCache::replace_with_caches(&[&page_cache, &site_cache], input, &mut writer).is_err());
+    page_cache.prepare().unwrap();
+    site_cache.prepare().unwrap();
+
+    // Earlier caches take precedence, replaced values are not replaced again
+    assert!(DataCache::replace_with_caches(&[&page_cache, &site_cache], input, &mut writer).is_ok());
+    assert_eq!(String::from_utf8(writer).unwrap(), "Page|Page|{$site.name}|Kuroco|ja|{$missing}");
+    let mut writer = Vec::new();
+    assert!(DataCache::replace_with_caches(&[&site_cache, &page_cache], input, &mut writer).is_ok());
+    assert_eq!(String::from_utf8(writer).unwrap(), "Site|Site|{$site.name}|Kuroco|ja|{$missing}");
+    let mut writer = Vec::new();
+    assert!(DataCache::replace_with_caches(&[], input, &mut writer).is_ok());
+    assert_eq!(writer, input);
+}
+
+#[test]
+fn data_cache_ambiguous_keys_test() {
+    let mut data_cache = DataCache::new(DataCacheOptions::default());
+    data_cache.insert("user", json!("User"));
+    data_cache.insert("user_name", json!("Name"));
+    data_cache.insert("a", json!("A"));
+    data_cache.insert("a}b", json!("B"));
+    let ambiguous = data_cache.detect_ambiguous_keys().unwrap();
+    assert_eq!(ambiguous, [(String::from("{$$a}"), String::from("{$$a}b}")), (String::from("{$a}"), String::from("{$a}b}"))]);
+
+    // The longest pattern wins by default, the first one to end with MatchKind::Standard
+    let input = "{$user}{$user_name}|{$a}b}";
+    let mut writer = Vec::new();
+    assert!(data_cache.replace_with_data_cache(input.as_bytes(), &mut writer).is_ok());
+    assert_eq!(String::from_utf8(writer).unwrap(), "UserName|B");
+    let mut data_cache = DataCache::new(DataCacheOptions { ac_match_kind: Some(MatchKind::Standard), ..Default::default() });
+    data_cache.insert("a", json!("A"));
+    data_cache.insert("a}b", json!("B"));
+    let mut writer = Vec::new();
+    assert!(data_cache.replace_with_data_cache(input.as_bytes(), &mut writer).is_ok());
+    assert_eq!(String::from_utf8(writer).unwrap(), "{$user}{$user_name}|Ab}");
+}
+
+#[test]
+fn data_cache_escape_placeholders_test() {
+    let input = r"{$title} \{$title} \{$$title} \{$missing} \{ \{$title}";
+    let mut data_cache = DataCache::new(DataCacheOptions::default());
+    data_cache.insert("title", json!("Title"));
+    let mut writer = Vec::new();
+    assert!(data_cache.replace_with_data_cache(input.as_bytes(), &mut writer).is_ok());
+    assert_eq!(String::from_utf8(writer).unwrap(), r"Title \Title \Title \{$missing} \{ \Title");
+
+    let mut data_cache = DataCache::new(DataCacheOptions { escape_placeholders: true, ..Default::default() });
+    data_cache.insert("title", json!("Title"));
+    let mut writer = Vec::new();
+    assert!(data_cache.replace_with_data_cache(input.as_bytes(), &mut writer).is_ok());
+    assert_eq!(String::from_utf8(writer).unwrap(), r"Title {$title} {$$title} {$missing} \{ {$title}");
+
+    data_cache.prepare().unwrap();
+    let mut writer = Vec::new();
+    assert!(DataCache::replace_with_caches(&[&data_cache], input.as_bytes(), &mut writer).is_ok());
+    assert_eq!(String::from_utf8(writer).unwrap(), r"Title {$title} {$$title} {$missing} \{ {$title}");
+}
+
+#[test]
+fn data_cache_audit_markers_test() {
+    let mut data_cache = DataCache::new(DataCacheOptions { audit_markers: Some(AuditMarkers::html_comments()), ..Default::default() });
+    data_cache.insert("user", json!({"name": "Taro"}));
+    let mut writer = Vec::new();
+    assert!(data_cache.replace_with_data_cache(b"<p>{$user.name}</p>{$$user} {$missing}".as_slice(), &mut writer).is_ok());
+    assert_eq!(
+        String::from_utf8(writer).unwrap(),
+        r#"<p><!--kv:user.name-->Taro<!--/kv--></p><!--kv:user-->{\"name\":\"Taro\"}<!--/kv--> {$missing}"#
+    );
+
+    let markers = AuditMarkers { open: String::from("[{key}|"), close: String::from("]") };
+    let mut data_cache = DataCache::new(DataCacheOptions { audit_markers: Some(markers), ..Default::default() });
+    data_cache.insert("a", json!(1));
+    data_cache.prepare().unwrap();
+    let mut writer = Vec::new();
+    assert!(DataCache::replace_with_caches(&[&data_cache], b"{$a}".as_slice(), &mut writer).is_ok());
+    assert_eq!(writer, b"[a|1]");
+}
+
+#[test]
+fn data_cache_source_map_test() {
+    use json_data_cache::SourceMapEntry;
+
+    let mut data_cache = DataCache::new(DataCacheOptions::default());
+    data_cache.insert("user", json!({"name": "Taro", "id": 12}));
+    let template = b"<p>{$user.name}</p>{$user.id}{$missing}{$$user.name}";
+    let mut output = Vec::new();
+    let source_map = data_cache.replace_with_source_map(template.as_slice(), &mut output).unwrap();
+    assert_eq!(output, b"<p>Taro</p>12{$missing}Taro");
+    assert_eq!(source_map.output_len, output.len());
+    assert_eq!(source_map.entries, [
+        SourceMapEntry { output: 3..7, key: String::from("user.name") },
+        SourceMapEntry { output: 11..13, key: String::from("user.id") },
+        SourceMapEntry { output: 23..27, key: String::from("user.name") },
+    ]);
+    assert_eq!(source_map.replaced_bytes(), 10);
+    assert!((source_map.coverage() - 10.0 / 27.0).abs() < f64::EPSILON);
+    assert_eq!(source_map.to_json()["entries"][1], json!({"start": 11, "end": 13, "key": "user.id"}));
+
+    let source_map = data_cache.replace_with_source_map(b"".as_slice(), Vec::new()).unwrap();
+    assert_eq!(source_map.coverage(), 0.0);
+}
+
+/// Records the length of the output at each flush
+#[derive(Default)]
+struct FlushRecorder {
+    output: Vec<u8>,
+    flushes: Vec<usize>,
+}
+
+impl std::io::Write for FlushRecorder {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.output.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.flushes.push(self.output.len());
+        Ok(())
+    }
+}
+
+#[test]
+fn data_cache_flush_policy_test() {
+    let template = b"<p>{$a}</p><p>{$b}</p>";
+    for (flush_policy, flushes) in [
+        (FlushPolicy::Never, vec![]),
+        (FlushPolicy::AtEnd, vec![22]),
+        (FlushPolicy::AfterPlaceholder, vec![7, 18, 22]),
+        (FlushPolicy::AfterBytes(10), vec![14, 22]),
+    ] {
+        let mut data_cache = DataCache::new(DataCacheOptions { flush_policy, ..Default::default() });
+        data_cache.insert("a", json!("1234"));
+        data_cache.insert("b", json!("5678"));
+        let mut writer = FlushRecorder::default();
+        assert!(data_cache.replace_with_data_cache(template.as_slice(), &mut writer).is_ok());
+        assert_eq!(writer.output, b"<p>1234</p><p>5678</p>");
+        assert_eq!(writer.flushes, flushes, "{flush_policy:?}");
+
+        data_cache.prepare().unwrap();
+        let mut writer = FlushRecorder::default();
+        assert!(DataCache::replace_with_caches(&[&data_cache], template.as_slice(), &mut writer).is_ok());
+        assert_eq!(writer.flushes, flushes, "{flush_policy:?}");
+    }
+}
+
+/// Reads a few bytes at a time, each read taking 10 ms of the clock
+struct SlowReader<'a> {
+    input: &'a [u8],
+    clock: Rc<ManualClock>,
+}
+
+impl std::io::Read for SlowReader<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        self.clock.advance(Duration::from_millis(10));
+        let len = buf.len().min(self.input.len()).min(6);
+        buf[..len].copy_from_slice(&self.input[..len]);
+        self.input = &self.input[len..];
+        Ok(len)
+    }
+}
+
+#[test]
+fn data_cache_render_budget_test() {
+    let template = "<p>{$a}</p>".repeat(10);
+    let expected = "<p>value</p>".repeat(10);
+    for on_exceeded in [OnBudgetExceeded::Abort, OnBudgetExceeded::Degrade] {
+        let clock = Rc::new(ManualClock::new(SystemTime::UNIX_EPOCH));
+        let mut data_cache = DataCache::new(DataCacheOptions {
+            clock: Some(clock.clone()),
+            render_budget: Some(RenderBudget::new(Duration::from_millis(55)).on_exceeded(on_exceeded)),
+            ..Default::default()
+        });
+        data_cache.insert("a", json!("value"));
+
+        let mut output = Vec::new();
+        let reader = SlowReader { input: template.as_bytes(), clock: clock.clone() };
+        let error = data_cache.replace_with_data_cache(reader, &mut output).unwrap_err();
+        assert!(error.msg.starts_with(RENDER_BUDGET_EXCEEDED), "{}", error.msg);
+        assert!(!output.is_empty() && output.len() < expected.len());
+        assert!(expected.as_bytes().starts_with(&output), "{}", String::from_utf8_lossy(&output));
+        let reason = data_cache.degraded_status().map(|status| status.reason.as_str());
+        assert_eq!(reason, (on_exceeded == OnBudgetExceeded::Degrade).then_some(BUDGET_DEGRADED_REASON));
+
+        // Within the budget
+        let mut output = Vec::new();
+        assert!(data_cache.replace_with_data_cache(template.as_bytes(), &mut output).is_ok());
+        assert_eq!(output, expected.as_bytes());
+
+        data_cache.prepare().unwrap();
+        let reader = SlowReader { input: template.as_bytes(), clock: clock.clone() };
+        let error = DataCache::replace_with_caches(&[&data_cache], reader, Vec::new()).unwrap_err();
+        assert!(error.msg.starts_with(RENDER_BUDGET_EXCEEDED), "{}", error.msg);
+    }
+}
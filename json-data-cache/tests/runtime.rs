@@ -0,0 +1,76 @@
+use std::{rc::Rc, time::{Duration, SystemTime}};
+
+use json_data_cache::{
+    DataCache, DataCacheOptions, Freshness, FreshnessMeta,
+    runtime::{Clock, ManualClock, Rng, SeededRng},
+    security_headers::SecurityHeadersBuilder,
+    store::{CacheStore, MemoryCacheStore}
+};
+use serde_json::json;
+
+#[test]
+fn manual_clock_test() {
+    let start = SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+    let clock = Rc::new(ManualClock::new(start));
+    let mut data_cache = DataCache::new(DataCacheOptions {
+        clock: Some(clock.clone()),
+        ..Default::default()
+    });
+    data_cache.insert("news", json!(["a"]));
+    data_cache.set_freshness("news", FreshnessMeta {
+        fresh_until: start + Duration::from_secs(60),
+        stale_until: start + Duration::from_secs(120)
+    });
+    assert_eq!(data_cache.get_with_freshness("news").unwrap().1, Freshness::Fresh);
+    clock.advance(Duration::from_secs(60));
+    assert_eq!(data_cache.get_with_freshness("news").unwrap().1, Freshness::Stale);
+    clock.advance(Duration::from_secs(60));
+    assert_eq!(data_cache.get_with_freshness("news").unwrap().1, Freshness::Expired);
+    assert_eq!(data_cache.clock().monotonic(), Duration::from_secs(120));
+
+    clock.set(start);
+    assert_eq!(data_cache.get_with_freshness("news").unwrap().1, Freshness::Fresh);
+    assert_eq!(clock.monotonic(), Duration::from_secs(120));
+
+    let mut store = MemoryCacheStore::default().with_clock(clock.clone());
+    store.put("key", b"value", Some(Duration::from_secs(10))).unwrap();
+    clock.advance(Duration::from_secs(9));
+    assert_eq!(store.get("key").unwrap().as_deref(), Some(b"value".as_slice()));
+    clock.advance(Duration::from_secs(1));
+    assert_eq!(store.get("key").unwrap(), None);
+}
+
+#[test]
+fn seeded_rng_test() {
+    let mut bytes = [0; 11];
+    SeededRng::new(7).fill_bytes(&mut bytes);
+    let mut same_seed_bytes = [0; 11];
+    SeededRng::new(7).fill_bytes(&mut same_seed_bytes);
+    assert_eq!(bytes, same_seed_bytes);
+    assert_ne!(SeededRng::new(7).next_u64(), SeededRng::new(8).next_u64());
+
+    let nonce = |seed| {
+        let mut data_cache = DataCache::new(DataCacheOptions {
+            rng: Some(Rc::new(SeededRng::new(seed))),
+            ..Default::default()
+        });
+        data_cache.insert("policy", json!({"csp": {"script-src": ["'nonce'"]}}));
+        SecurityHeadersBuilder::new("policy").build(&mut data_cache).unwrap().nonce
+    };
+    assert_eq!(nonce(1), nonce(1));
+    assert_ne!(nonce(1), nonce(2));
+    assert_eq!(nonce(1).len(), 22);
+}
+
+#[cfg(unix)]
+#[test]
+fn platform_rng_test() {
+    let data_cache = DataCache::new(DataCacheOptions::default());
+    let rng = data_cache.rng().unwrap();
+    let mut bytes = [0; 32];
+    rng.fill_bytes(&mut bytes);
+    let mut other_bytes = [0; 32];
+    rng.fill_bytes(&mut other_bytes);
+    assert_ne!(bytes, other_bytes);
+    assert_ne!(bytes, [0; 32]);
+}
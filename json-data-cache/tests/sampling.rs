@@ -0,0 +1,69 @@
+use std::{rc::Rc, time::{Duration, SystemTime}};
+
+use json_data_cache::{DataCache, DataCacheOptions, runtime::{Clock, ManualClock}, sampling::Rotation};
+use serde_json::{Value, json};
+
+#[test]
+fn sampling_test() {
+    let mut data_cache = DataCache::new(DataCacheOptions::default());
+    data_cache.insert("articles", json!([1, 2, 3, 4, 5, 6, 7, 8]));
+    data_cache.insert("user.id", json!(42));
+
+    let shuffled: Vec<Value> = data_cache.shuffle("articles", "user.id").into_iter().cloned().collect();
+    assert_eq!(shuffled.len(), 8);
+    let mut sorted = shuffled.clone();
+    sorted.sort_by_key(|item| item.as_u64());
+    assert_eq!(sorted, (1..=8).map(Value::from).collect::<Vec<Value>>());
+    // Stable for the same seed, a sample being a prefix of the shuffle
+    assert_eq!(data_cache.shuffle("articles", "user.id").into_iter().cloned().collect::<Vec<Value>>(), shuffled);
+    assert_eq!(data_cache.sample("articles", 3, "user.id").into_iter().cloned().collect::<Vec<Value>>(), shuffled[..3]);
+    assert_eq!(data_cache.sample("articles", 20, "user.id").len(), 8);
+    assert!(data_cache.sample("missing", 3, "user.id").is_empty());
+
+    // Seeds are different between users
+    let orders: Vec<Vec<Value>> = (0..10).map(|id| {
+        data_cache.insert("user.id", json!(id));
+        data_cache.shuffle("articles", "user.id").into_iter().cloned().collect()
+    }).collect();
+    assert!(orders.iter().any(|order| *order != orders[0]));
+
+    assert_eq!(data_cache.sample_into("related", "articles", 2, "user.id").unwrap(), 2);
+    assert_eq!(data_cache.get("related"), Some(&Value::Array(orders[9][..2].to_vec())));
+    assert!(data_cache.sample_into("related", "user", 2, "user.id").is_err());
+}
+
+#[test]
+fn rotation_test() {
+    let clock = Rc::new(ManualClock::new(SystemTime::UNIX_EPOCH + Duration::from_secs(36_000)));
+    let mut data_cache = DataCache::new(DataCacheOptions { clock: Some(clock.clone()), ..Default::default() });
+    data_cache.insert("banners", json!([
+        {"id": "sale", "weight": 3},
+        {"id": "new", "weight": 1},
+        {"id": "off", "weight": 0},
+        {"id": "broken", "weight": "heavy"}
+    ]));
+    let rotation = Rotation::new(Duration::from_secs(3_600));
+    assert_eq!(rotation.next_change(clock.now()), SystemTime::UNIX_EPOCH + Duration::from_secs(39_600));
+
+    // Reproducible within a window
+    let picked = data_cache.rotate("banners", "hero", &rotation).unwrap().unwrap();
+    assert_eq!(data_cache.get("hero"), data_cache.get(&format!("banners.{picked}")));
+    clock.advance(Duration::from_secs(3_599));
+    assert_eq!(data_cache.rotate("banners", "hero", &rotation).unwrap(), Some(picked));
+
+    // Weighted over windows, never picking weightless items
+    let mut counts = [0; 4];
+    for _ in 0..400 {
+        clock.advance(Duration::from_secs(3_600));
+        counts[data_cache.rotate("banners", "hero", &rotation).unwrap().unwrap()] += 1;
+    }
+    assert_eq!(counts[2] + counts[3], 0);
+    assert!(counts[0] > counts[1] * 2, "{counts:?}");
+
+    let rotation = rotation.weight_key("priority").seed_path("user.id");
+    assert!(data_cache.rotate("banners", "hero", &rotation).unwrap().is_some()); // Without priorities, all weigh 1
+    data_cache.insert("empty", json!([{"priority": 0}]));
+    assert_eq!(data_cache.rotate("empty", "hero", &rotation).unwrap(), None);
+    assert_eq!(data_cache.get("hero"), Some(&json!(null)));
+    assert!(data_cache.rotate("missing", "hero", &rotation).is_err());
+}
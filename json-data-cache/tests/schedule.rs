@@ -0,0 +1,56 @@
+use std::{rc::Rc, time::{Duration, SystemTime}};
+
+use json_data_cache::{DataCache, DataCacheOptions, pipeline::Condition, runtime::ManualClock, schedule::is_published};
+use serde_json::json;
+
+// 2024-01-01T00:00:00Z
+const NEW_YEAR: u64 = 1_704_067_200;
+
+#[test]
+fn is_published_test() {
+    let now = SystemTime::UNIX_EPOCH + Duration::from_secs(NEW_YEAR);
+    assert!(is_published(&json!({"title": "Always"}), now));
+    assert!(is_published(&json!({"open_date": "", "close_date": null}), now));
+    assert!(is_published(&json!({"open_date": "2024-01-01T09:00:00+09:00"}), now)); // Open date included
+    assert!(!is_published(&json!({"open_date": "2024-01-01T00:00:01Z"}), now));
+    assert!(!is_published(&json!({"close_date": "2024-01-01"}), now)); // Close date excluded
+    assert!(is_published(&json!({"open_date": "2023-12-31", "close_date": NEW_YEAR + 1}), now));
+    assert!(!is_published(&json!({"open_date": "someday"}), now)); // Unreadable dates unpublish
+    assert!(!is_published(&json!({"close_date": true}), now));
+}
+
+#[test]
+fn schedule_test() {
+    let clock = Rc::new(ManualClock::new(SystemTime::UNIX_EPOCH + Duration::from_secs(NEW_YEAR)));
+    let mut data_cache = DataCache::new(DataCacheOptions { clock: Some(clock.clone()), ..Default::default() });
+    data_cache.insert("news", json!([
+        {"id": 1},
+        {"id": 2, "open_date": "2024-01-01T01:00:00Z"},
+        {"id": 3, "close_date": "2024-01-01T02:00:00Z"},
+        {"id": 4, "close_date": "2023-12-01"}
+    ]));
+    let ids = |data_cache: &DataCache| -> Vec<i64> {
+        data_cache.published_items("news").iter().map(|item| item["id"].as_i64().unwrap()).collect()
+    };
+    assert_eq!(ids(&data_cache), [1, 3]);
+    assert!(data_cache.is_published("news.0"));
+    assert!(!data_cache.is_published("news.1"));
+    assert!(!data_cache.is_published("missing"));
+    assert_eq!(data_cache.next_schedule_change("news"), Some(SystemTime::UNIX_EPOCH + Duration::from_secs(NEW_YEAR + 3600)));
+
+    // The cached response is unchanged, only the clock moves
+    clock.advance(Duration::from_secs(3600));
+    assert_eq!(ids(&data_cache), [1, 2, 3]);
+    assert_eq!(data_cache.next_schedule_change("news"), Some(SystemTime::UNIX_EPOCH + Duration::from_secs(NEW_YEAR + 7200)));
+    clock.advance(Duration::from_secs(3600));
+    assert_eq!(ids(&data_cache), [1, 2]);
+    assert_eq!(data_cache.next_schedule_change("news"), None);
+
+    let is_met = |data_cache: &DataCache, condition: &str| Condition::parse(condition).unwrap().is_met(data_cache);
+    assert!(is_met(&data_cache, "published(news.1) && !published(news.2)"));
+
+    assert_eq!(data_cache.filter_published("news").unwrap(), 2);
+    assert_eq!(data_cache.get("news"), Some(&json!([{"id": 1}, {"id": 2, "open_date": "2024-01-01T01:00:00Z"}])));
+    assert_eq!(data_cache.filter_published("news").unwrap(), 0);
+    assert!(data_cache.filter_published("news.0").is_err());
+}
@@ -0,0 +1,38 @@
+use json_data_cache::{DataCache, DataCacheOptions, scoring::{BotScorer, Heuristic}};
+use serde_json::json;
+
+fn scorer() -> BotScorer {
+    BotScorer::new()
+        .heuristic("user_agent", Heuristic::bot_user_agent("headers.user-agent"), 0.6)
+        .heuristic("no_accept_language", Heuristic::Missing("headers.accept-language".to_string()), 0.3)
+        .heuristic("honeypot", Heuristic::Present("form.website".to_string()), 1.0)
+        .heuristic("fast_submit", Heuristic::LessThan("form.elapsed_ms".to_string(), 1500.0), 0.4)
+        .heuristic("geo", Heuristic::OneOf("geo.country".to_string(), vec![json!("XX")]), 0.2)
+        .heuristic("has_session", Heuristic::Present("cookies.session".to_string()), -0.5)
+}
+
+#[test]
+fn scoring_test() {
+    let mut data_cache = DataCache::new(DataCacheOptions::default());
+    data_cache.insert("headers", json!({"user-agent": "Mozilla/5.0 (compatible; Googlebot/2.1)"}));
+    data_cache.insert("form", json!({"website": "", "elapsed_ms": "800"}));
+    let bot_score = data_cache.score_bot(&scorer()).unwrap();
+    assert_eq!(bot_score.matched, ["user_agent", "no_accept_language", "fast_submit"]);
+    assert_eq!(bot_score.score, 1.0); // Capped
+    assert!(bot_score.is_bot);
+    assert_eq!(data_cache.get("bot.is_bot"), Some(&json!(true)));
+    assert_eq!(data_cache.get("bot.matched.2"), Some(&json!("fast_submit")));
+
+    let mut data_cache = DataCache::new(DataCacheOptions::default());
+    data_cache.insert("headers", json!({"user-agent": "Mozilla/5.0 (Windows NT 10.0) Chrome/120", "accept-language": "ja"}));
+    data_cache.insert("form.elapsed_ms", json!(5000));
+    data_cache.insert("geo.country", json!("XX"));
+    data_cache.insert("cookies.session", json!("abc"));
+    let bot_score = data_cache.score_bot(&scorer().output_path("request.bot")).unwrap();
+    assert_eq!(bot_score.matched, ["geo", "has_session"]);
+    assert_eq!(bot_score.score, 0.0);
+    assert_eq!(data_cache.get("request.bot.is_bot"), Some(&json!(false)));
+
+    let bot_score = scorer().threshold(0.2).heuristic("geo_again", Heuristic::OneOf("geo.country".to_string(), vec![json!("XX")]), 0.5).score(&data_cache);
+    assert!(bot_score.is_bot);
+}
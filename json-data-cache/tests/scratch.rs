@@ -0,0 +1,32 @@
+use json_data_cache::{DataCache, DataCacheOptions, SCRATCH_KEY};
+use serde_json::json;
+
+#[test]
+fn scratch_test() {
+    let mut data_cache = DataCache::new(DataCacheOptions::default());
+    data_cache.insert("cart.total", json!(1200));
+    data_cache.insert(&format!("{SCRATCH_KEY}.subtotals"), json!([1000, 200]));
+    data_cache.insert("_tmpx", json!("not scratch"));
+    assert_eq!(data_cache.get("_tmp.subtotals.1"), Some(&json!(200)));
+
+    let map = data_cache.as_string_values_map();
+    assert!(map.keys().all(|key| !key.starts_with("_tmp.") && key != "_tmp"));
+    assert_eq!(map.get("_tmpx").map(|s| s.as_str()), Some("not scratch"));
+    assert!(!data_cache.to_string().contains("subtotals"));
+
+    #[cfg(feature = "serializer")]
+    {
+        let expected = data_cache.as_string_values_map();
+        assert_eq!(data_cache.string_values_view(), &expected);
+        data_cache.insert("_tmp.step", json!(2));
+        assert_eq!(data_cache.string_values_view(), &expected);
+        assert_eq!(data_cache.keys_with_prefix("_tmp"), ["_tmpx"]);
+    }
+
+    #[cfg(feature = "replace-engine")]
+    {
+        let mut output = Vec::new();
+        data_cache.replace_with_data_cache(b"{$cart.total} {$_tmp.step} {$$_tmp} {$_tmpx}".as_slice(), &mut output).unwrap();
+        assert_eq!(output, b"1200 {$_tmp.step} {$$_tmp} not scratch");
+    }
+}
@@ -0,0 +1,39 @@
+use json_data_cache::{DataCache, DataCacheOptions, search::SearchIndex};
+use serde_json::json;
+
+fn hit_paths(index: &SearchIndex, query: &str) -> Vec<String> {
+    index.search(query).into_iter().map(|hit| hit.path).collect()
+}
+
+#[test]
+fn search_test() {
+    let mut data_cache = DataCache::new(DataCacheOptions::default());
+    data_cache.insert("news", json!([
+        {"title": "Edge caching", "body": "Caching at the edge, caching everywhere", "id": 1},
+        {"title": "Release notes", "body": "New edge functions", "tags": ["Release"]},
+        {"title": "東京のイベント", "body": "イベント情報"}
+    ]));
+    let mut index = SearchIndex::new(&["news.*"]);
+    assert_eq!(index.rebuild(&data_cache), 3);
+
+    assert_eq!(hit_paths(&index, "edge"), ["news.0", "news.1"]);
+    assert_eq!(hit_paths(&index, "CACHING"), ["news.0"]);
+    assert_eq!(hit_paths(&index, "edge release"), ["news.1"]);
+    assert!(hit_paths(&index, "edge missing").is_empty());
+    assert!(hit_paths(&index, " ,").is_empty());
+    // Whole Japanese runs only without bigrams
+    assert_eq!(hit_paths(&index, "イベント情報"), ["news.2"]);
+    assert!(hit_paths(&index, "イベント").is_empty());
+
+    let mut index = SearchIndex::new(&["news.*.title", "news.*.body"]).bigrams(true);
+    index.rebuild(&data_cache);
+    assert_eq!(hit_paths(&index, "イベント"), ["news.2.body", "news.2.title"]);
+    assert_eq!(hit_paths(&index, "東京"), ["news.2.title"]);
+    let hits = index.search("caching");
+    assert_eq!(hits[0].path, "news.0.body");
+    assert!(hits[0].score > hits[1].score);
+
+    data_cache.insert("news", json!(null));
+    assert_eq!(index.rebuild(&data_cache), 0);
+    assert!(index.search("edge").is_empty());
+}
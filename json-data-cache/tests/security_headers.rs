@@ -0,0 +1,61 @@
+use json_data_cache::{DataCache, DataCacheOptions, security_headers::SecurityHeadersBuilder};
+use serde_json::json;
+
+#[test]
+fn security_headers_test() {
+    let mut data_cache = DataCache::new(DataCacheOptions::default());
+    data_cache.insert("security", json!({
+        "csp": {"default-src": ["'self'"], "script-src": ["'self'", "'nonce'"], "upgrade-insecure-requests": true},
+        "hsts": {"max_age": 31536000, "include_subdomains": true},
+        "referrer_policy": "strict-origin-when-cross-origin",
+        "permissions_policy": {"camera": [], "geolocation": ["self", "https://maps.example.com"], "fullscreen": ["*"]},
+        "unknown": 1
+    }));
+    let security_headers = SecurityHeadersBuilder::new("security").nonce("abc123").build(&mut data_cache).unwrap();
+    assert_eq!(security_headers.headers, [
+        ("Content-Security-Policy", "default-src 'self'; script-src 'self' 'nonce-abc123'; upgrade-insecure-requests".to_string()),
+        ("Strict-Transport-Security", "max-age=31536000; includeSubDomains".to_string()),
+        ("Referrer-Policy", "strict-origin-when-cross-origin".to_string()),
+        ("Permissions-Policy", "camera=(), geolocation=(self \"https://maps.example.com\"), fullscreen=*".to_string()),
+    ]);
+    assert_eq!(data_cache.get("csp_nonce"), Some(&json!("abc123")));
+
+    let html = br#"<head><SCRIPT>a()</SCRIPT><script src="/x.js"></script><script nonce="keep">b()</script><style>p{}</style><span>script</span></head>"#;
+    assert_eq!(
+        String::from_utf8(security_headers.inject_nonces(html)).unwrap(),
+        r#"<head><script nonce="abc123">a()</SCRIPT><script src="/x.js"></script><script nonce="keep">b()</script><style nonce="abc123">p{}</style><span>script</span></head>"#
+    );
+    // Attributes are parsed: data-src is not a src, data-nonce not a nonce, and a quoted > does not end the tag
+    let html = br#"<script data-src="/lazy.js">c()</script><style data-nonce="x">q{}</style><script data-cond="a > b">d()</script>"#;
+    assert_eq!(
+        String::from_utf8(security_headers.inject_nonces(html)).unwrap(),
+        r#"<script data-src="/lazy.js" nonce="abc123">c()</script><style data-nonce="x" nonce="abc123">q{}</style><script data-cond="a > b" nonce="abc123">d()</script>"#
+    );
+    // The content of scripts is not parsed as tags
+    let html = br#"<script>document.write('<script src="/x.js"></scr' + 'ipt>')</script>"#;
+    assert_eq!(
+        String::from_utf8(security_headers.inject_nonces(html)).unwrap(),
+        r#"<script nonce="abc123">document.write('<script src="/x.js"></scr' + 'ipt>')</script>"#
+    );
+
+    // Generated nonces differ on each build
+    let first = SecurityHeadersBuilder::new("security").build(&mut data_cache).unwrap().nonce;
+    let second = SecurityHeadersBuilder::new("security").build(&mut data_cache).unwrap().nonce;
+    assert_eq!(first.len(), 22);
+    assert_ne!(first, second);
+
+    assert!(SecurityHeadersBuilder::new("missing").build(&mut data_cache).is_err());
+    data_cache.insert("bad", json!({"hsts": {"preload": true}}));
+    assert!(SecurityHeadersBuilder::new("bad").build(&mut data_cache).is_err());
+}
+
+#[cfg(feature = "replace-engine")]
+#[test]
+fn security_headers_template_test() {
+    let mut data_cache = DataCache::new(DataCacheOptions::default());
+    data_cache.insert("security.csp.script-src", json!(["'nonce'"]));
+    SecurityHeadersBuilder::new("security").nonce("n0nce").build(&mut data_cache).unwrap();
+    let mut output = Vec::new();
+    data_cache.replace_with_data_cache(br#"<script nonce="{$csp_nonce}">"#.as_slice(), &mut output).unwrap();
+    assert_eq!(output, br#"<script nonce="n0nce">"#);
+}
@@ -0,0 +1,73 @@
+use std::rc::Rc;
+
+use json_data_cache::{DataCache, DataCacheOptions, runtime::SeededRng, session::{COOKIE_SIZE_LIMIT, SessionCodec}};
+use serde_json::json;
+
+#[test]
+fn session_test() {
+    let codec = SessionCodec::new("session", "k1", b"secret");
+    let mut data_cache = DataCache::new(DataCacheOptions::default());
+    data_cache.insert("session", json!({"cart": [1, 2]}));
+
+    let cookie = data_cache.save_session(&codec, "session").unwrap();
+    // HMAC-SHA256 signature, as computed by other implementations
+    assert_eq!(cookie.value, "k1.s.eyJjYXJ0IjpbMSwyXX0.8pd3yJiLxPcnDVF6J2ckYDbd-0nxBTzTy9B5IAgdJCU");
+    assert_eq!(cookie.size, "session=".len() + cookie.value.len());
+    assert_eq!(cookie.remaining, COOKIE_SIZE_LIMIT - cookie.size);
+
+    let mut next_request = DataCache::new(DataCacheOptions::default());
+    next_request.insert("session", json!({"cart": [9], "user": "x"}));
+    assert!(next_request.restore_session(&codec, "session", &cookie.value).is_ok());
+    assert_eq!(next_request.get("session"), Some(&json!({"cart": [1, 2]})));
+
+    // Tampering
+    let tampered = cookie.value.replace("eyJjYXJ0IjpbMSwyXX0", "eyJjYXJ0IjpbMSwzXX0");
+    assert!(next_request.restore_session(&codec, "session", &tampered).is_err());
+    assert!(codec.decode("k1.s.e30").is_err());
+    assert!(SessionCodec::new("session", "k1", b"other").decode(&cookie.value).is_err());
+    assert_eq!(next_request.get("session"), Some(&json!({"cart": [1, 2]})));
+}
+
+#[test]
+fn session_encrypted_test() {
+    let codec = SessionCodec::new("session", "k1", b"secret");
+    let value = json!({"user": {"id": 42, "name": "山田 太郎"}, "long": "x".repeat(100)});
+    let rng = SeededRng::new(7);
+    let cookie = codec.encode_encrypted(&value, &rng).unwrap();
+    assert!(!cookie.value.contains("eyJ")); // Not readable as base64 JSON
+    assert_eq!(codec.decode(&cookie.value).unwrap(), value);
+    // Each encoding draws a new nonce, changing the ciphertext
+    assert_ne!(codec.encode_encrypted(&value, &rng).unwrap().value, cookie.value);
+
+    // With the rng of the options
+    let mut data_cache = DataCache::new(DataCacheOptions { rng: Some(Rc::new(SeededRng::new(1))), ..Default::default() });
+    data_cache.insert("session", value.clone());
+    let saved = data_cache.save_session_encrypted(&codec, "session").unwrap();
+    assert_ne!(saved.value, data_cache.save_session_encrypted(&codec, "session").unwrap().value);
+    assert_eq!(codec.decode(&saved.value).unwrap(), value);
+
+    let mut tampered = cookie.value.into_bytes();
+    tampered[10] = if tampered[10] == b'A' { b'B' } else { b'A' };
+    assert!(codec.decode(str::from_utf8(&tampered).unwrap()).is_err());
+}
+
+#[test]
+fn session_rotation_and_size_test() {
+    let old_codec = SessionCodec::new("session", "k1", b"old secret");
+    let old_cookie = old_codec.encode(&json!({"a": 1})).unwrap();
+
+    let codec = old_codec.rotate("k2", b"new secret");
+    let new_cookie = codec.encode(&json!({"a": 1})).unwrap();
+    assert!(new_cookie.value.starts_with("k2."));
+    assert_eq!(codec.decode(&old_cookie.value).unwrap(), json!({"a": 1}));
+
+    let codec = codec.retire("k1").unwrap();
+    assert!(codec.decode(&old_cookie.value).is_err());
+    assert!(codec.decode(&new_cookie.value).is_ok());
+    // The last key signs new cookies
+    assert!(codec.clone().retire("k2").is_err());
+
+    // Base64 grows the payload by a third : 3100 bytes of JSON do not fit
+    assert!(codec.encode(&json!("x".repeat(2900))).is_ok());
+    assert!(codec.encode(&json!("x".repeat(3100))).is_err());
+}
@@ -0,0 +1,82 @@
+#![cfg(feature = "replace-engine")]
+
+use json_data_cache::{DataCache, DataCacheOptions};
+use serde_json::json;
+
+fn replace(data_cache: &mut DataCache, input: &str) -> String {
+    let mut writer = Vec::new();
+    data_cache.replace_with_data_cache(input.as_bytes(), &mut writer).unwrap();
+    String::from_utf8(writer).unwrap()
+}
+
+#[test]
+fn static_keys_test() {
+    let mut data_cache = DataCache::new(DataCacheOptions::default());
+    data_cache.insert("site", json!({"name": "Kuroco", "nav": ["Home", "News"]}));
+    data_cache.insert("article", json!({"title": "First"}));
+    data_cache.set_static_keys(&["site"]);
+    assert_eq!(data_cache.static_keys(), ["site"]);
+    assert!(!data_cache.is_static_automaton_built());
+
+    let input = "{$site.name} {$article.title} {$$site.nav} {$site.nav.1}";
+    assert_eq!(replace(&mut data_cache, input), r#"Kuroco First [\"Home\",\"News\"] News"#);
+    assert!(data_cache.is_static_automaton_built());
+    // The automaton of the tree leaves the static keys out
+    let dynamic_patterns = data_cache.automaton_stats().unwrap().pattern_count;
+
+    // Inserts of other keys only rebuild the automaton of the tree, values containing placeholders are not replaced again
+    data_cache.insert("article.title", json!("{$site.name} news"));
+    assert!(data_cache.is_static_automaton_built());
+    assert_eq!(replace(&mut data_cache, input), r#"Kuroco {$site.name} news [\"Home\",\"News\"] News"#);
+    assert_eq!(data_cache.automaton_stats().unwrap().pattern_count, dynamic_patterns);
+
+    // Inserts of static keys rebuild both
+    data_cache.insert("site.name", json!("Diverta"));
+    assert!(!data_cache.is_static_automaton_built());
+    assert_eq!(replace(&mut data_cache, "{$site.name} {$site}"), r#"Diverta {"name":"Diverta","nav":["Home","News"]}"#);
+    assert!(data_cache.is_static_automaton_built());
+
+    data_cache.insert("article", json!(1));
+
+    data_cache.set_static_keys(&[]);
+    assert!(!data_cache.is_static_automaton_built());
+    assert_eq!(replace(&mut data_cache, "{$site.name} {$article}"), "Diverta 1");
+}
+
+#[test]
+fn static_keys_chunks_test() {
+    let mut data_cache = DataCache::new(DataCacheOptions::default());
+    data_cache.insert("site.name", json!("Kuroco"));
+    data_cache.insert("title", json!("Title"));
+    data_cache.set_static_keys(&["site"]);
+
+    // Placeholders across the chunks of the streaming pass
+    let input = "{$title}-{$site.name}|".repeat(10_000);
+    let expected = "Title-Kuroco|".repeat(10_000);
+    assert_eq!(replace(&mut data_cache, &input), expected);
+}
+
+#[test]
+fn static_keys_refs_test() {
+    let mut data_cache = DataCache::new(DataCacheOptions::default());
+    data_cache.insert("site.name", json!("Kuroco"));
+    data_cache.insert("content.title", json!("T1"));
+    data_cache.insert_ref("site.title", "content.title").unwrap();
+    data_cache.insert_ref("content.site_name", "site.name").unwrap();
+    data_cache.insert_fallback("site.headline", &["content.headline", "site.name"]).unwrap();
+    data_cache.set_static_keys(&["site"]);
+
+    // References in both directions, between static & dynamic keys
+    let input = "{$site.title} {$content.site_name} {$site.headline}";
+    assert_eq!(replace(&mut data_cache, input), "T1 Kuroco Kuroco");
+    assert!(data_cache.is_static_automaton_built());
+
+    // Inserts of dynamic keys do not rebuild the static automaton, references to them are replaced by the dynamic one
+    data_cache.insert("content.title", json!("T2"));
+    data_cache.insert("content.headline", json!("Breaking"));
+    assert!(data_cache.is_static_automaton_built());
+    assert_eq!(replace(&mut data_cache, input), "T2 Kuroco Breaking");
+
+    data_cache.insert("site.name", json!("Diverta"));
+    assert_eq!(replace(&mut data_cache, input), "T2 Diverta Breaking");
+}
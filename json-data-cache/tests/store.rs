@@ -0,0 +1,55 @@
+use std::{env, fs, process, time::Duration};
+
+use json_data_cache::{DataCache, DataCacheOptions, store::{CacheStore, FileCacheStore, MemoryCacheStore}};
+use serde_json::json;
+
+fn check_store(store: &mut dyn CacheStore) {
+    let mut data_cache = DataCache::new(DataCacheOptions::default());
+    data_cache.insert("content", json!({"title": "Title", "list": [1, 2]}));
+    assert!(data_cache.persist_to(store, "page/1:content", "content", None).is_ok());
+    assert!(data_cache.persist_to(store, "whole", "", Some(Duration::from_secs(60))).is_ok());
+    assert!(data_cache.persist_to(store, "unknown", "unknown", None).is_err());
+
+    let mut hydrated = DataCache::new(DataCacheOptions::default());
+    assert!(hydrated.hydrate_from(store, "page/1:content", "page").unwrap());
+    assert_eq!(hydrated.get("page.list.1"), Some(&json!(2)));
+    assert!(hydrated.hydrate_from(store, "whole", "").unwrap());
+    assert_eq!(hydrated.get("content.title"), Some(&json!("Title")));
+    assert!(!hydrated.hydrate_from(store, "missing", "").unwrap());
+
+    assert!(store.put("invalid", b"{not json", None).is_ok());
+    assert!(hydrated.hydrate_from(store, "invalid", "x").is_err());
+
+    // Expired entries are missing
+    assert!(store.put("expired", b"1", Some(Duration::ZERO)).is_ok());
+    assert_eq!(store.get("expired").unwrap(), None);
+
+    assert!(store.delete("whole").is_ok());
+    assert!(store.delete("whole").is_ok());
+    assert_eq!(store.get("whole").unwrap(), None);
+}
+
+#[test]
+fn memory_store_test() {
+    check_store(&mut MemoryCacheStore::default());
+}
+
+#[test]
+fn file_store_test() {
+    let directory = env::temp_dir().join(format!("json-data-cache-store-{}", process::id()));
+    let mut store = FileCacheStore::new(&directory).unwrap();
+    check_store(&mut store);
+    let _ = fs::remove_dir_all(directory);
+}
+
+#[test]
+fn scratch_not_persisted_test() {
+    let mut data_cache = DataCache::new(DataCacheOptions::default());
+    data_cache.insert("page.title", json!("Hello"));
+    data_cache.insert("_tmp.total", json!(3));
+    let mut store = MemoryCacheStore::default();
+    data_cache.persist_to(&mut store, "root", "", None).unwrap();
+    assert_eq!(store.get("root").unwrap().as_deref(), Some(br#"{"page":{"title":"Hello"}}"#.as_slice()));
+    assert!(data_cache.persist_to(&mut store, "tmp", "_tmp.total", None).is_err());
+    assert_eq!(data_cache.get("_tmp.total"), Some(&json!(3)));
+}
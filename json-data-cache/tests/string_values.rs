@@ -0,0 +1,105 @@
+#![cfg(feature = "serializer")]
+
+use std::{borrow::Cow, collections::HashMap};
+
+use json_data_cache::{DataCache, DataCacheOptions, RangeMeta};
+use serde_json::json;
+
+#[test]
+fn data_cache_string_values_view_test() {
+    let mut data_cache = DataCache::new(DataCacheOptions::default());
+
+    data_cache.insert("a.b", json!({"c": "my_c_value", "d": 12.5, "e": [true, null, "quoted \"value\""]}));
+    data_cache.insert("list", json!([{"id": 1}, {"id": 2}]));
+    let expected = data_cache.as_string_values_map();
+    assert_eq!(data_cache.string_values_view(), &expected);
+
+    // Only the modified subtrees are recomputed, and the result must stay identical to the full map
+    data_cache.insert("a.b.c", json!({"nested": "value"}));
+    data_cache.insert("new_key", json!("new_value"));
+    let expected = data_cache.as_string_values_map();
+    assert_eq!(data_cache.string_values_view(), &expected);
+    assert_eq!(data_cache.string_values_view().get("a.b.c"), Some(&String::from(r#"{"nested":"value"}"#)));
+    assert_eq!(data_cache.string_values_view().get("a.b.e.2"), Some(&String::from(r#"quoted "value""#)));
+
+    data_cache.merge(json!({"list": null, "merged": {"x": 1}}));
+    let expected = data_cache.as_string_values_map();
+    assert_eq!(data_cache.string_values_view(), &expected);
+    assert_eq!(data_cache.string_values_view().get("list.0.id"), None);
+
+    // Serialized data built for replacements is reused
+    #[cfg(feature = "replace-engine")]
+    {
+        let mut writer = Vec::new();
+        assert!(data_cache.replace_with_data_cache("{$merged.x}".as_bytes(), &mut writer).is_ok());
+        data_cache.insert("merged.y", json!(2));
+        let expected = data_cache.as_string_values_map();
+        assert_eq!(data_cache.string_values_view(), &expected);
+    }
+}
+
+#[test]
+fn data_cache_as_str_values_test() {
+    let mut data_cache = DataCache::new(DataCacheOptions::default());
+
+    data_cache.insert("a.b", json!({"c": "plain", "d": 12.5, "e": [true, null, "quoted \"value\""]}));
+    data_cache.insert("empty", json!(""));
+
+    let str_values: HashMap<&str, Cow<str>> = data_cache.as_str_values().collect();
+    let expected = data_cache.as_string_values_map();
+    assert_eq!(str_values.len(), expected.len());
+    for (key, value) in &expected {
+        assert_eq!(str_values.get(key.as_str()).map(|v| v.as_ref()), Some(value.as_str()));
+    }
+
+    // Only values that needed unescaping are allocated
+    assert!(matches!(str_values.get("a.b.c"), Some(Cow::Borrowed("plain"))));
+    assert!(matches!(str_values.get("a.b"), Some(Cow::Borrowed(_))));
+    assert!(matches!(str_values.get("a.b.e.2"), Some(Cow::Owned(_))));
+}
+
+#[test]
+fn data_cache_keys_with_prefix_test() {
+    let mut data_cache = DataCache::new(DataCacheOptions::default());
+
+    data_cache.insert("content.list", json!([{"id": 1}, {"id": 2}]));
+    data_cache.insert("content.lists", json!("not in list"));
+    data_cache.insert("experiments", json!({"a": true}));
+
+    assert_eq!(data_cache.keys_with_prefix("content.list."), Vec::from([
+        "content.list.0",
+        "content.list.0.id",
+        "content.list.1",
+        "content.list.1.id",
+    ]));
+    assert_eq!(data_cache.keys_with_prefix("experiments"), Vec::from(["experiments", "experiments.a"]));
+    assert_eq!(data_cache.keys_with_prefix("unknown"), Vec::<&str>::new());
+
+    // Index is rebuilt after an insert
+    data_cache.insert("experiments.b", json!(false));
+    assert_eq!(data_cache.keys_with_prefix("experiments."), Vec::from(["experiments.a", "experiments.b"]));
+}
+
+#[test]
+fn data_cache_serialized_range_test() {
+    let mut data_cache = DataCache::new(DataCacheOptions::default());
+    data_cache.insert("a", json!({"s": "plain", "q": "say \"hi\"", "n": 12}));
+
+    let (bytes, meta) = data_cache.serialized_range("a.s").unwrap();
+    assert_eq!(bytes, b"plain");
+    assert_eq!(meta, RangeMeta { start: meta.start, end: meta.start + 5, is_string: true, has_escapes: false });
+    let (bytes, meta) = data_cache.serialized_range("a.q").unwrap();
+    assert_eq!(bytes, br#"say \"hi\""#);
+    assert!(meta.is_string && meta.has_escapes);
+    let (bytes, meta) = data_cache.serialized_range("a.n").unwrap();
+    assert_eq!(bytes, b"12");
+    assert!(!meta.is_string && !meta.has_escapes);
+    let (bytes, meta) = data_cache.serialized_range("a").unwrap();
+    assert_eq!(bytes, br#"{"s":"plain","q":"say \"hi\"","n":12}"#);
+    assert!(!meta.is_string);
+    assert!(data_cache.serialized_range("a.unknown").is_none());
+
+    // Serialized data is rebuilt after an insert
+    data_cache.insert("a.n", json!(13));
+    assert_eq!(data_cache.serialized_range("a.n").unwrap().0, b"13");
+}
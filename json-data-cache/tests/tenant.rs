@@ -0,0 +1,91 @@
+use json_data_cache::{DataCacheOptions, tenant::{TenantCache, TenantOptions}};
+use serde_json::json;
+
+#[test]
+fn tenant_cache_test() {
+    let mut tenants = TenantCache::new().max_tenants(2);
+    tenants.add_tenant("site_a", TenantOptions::default()).unwrap();
+    tenants.add_tenant("site_b", TenantOptions { max_bytes: Some(40), ..Default::default() }).unwrap();
+    assert!(tenants.add_tenant("site_c", TenantOptions::default()).is_err());
+    assert!(tenants.add_tenant("site_a", TenantOptions::default()).is_ok()); // Replacing does not count
+    assert!(tenants.add_tenant("site.d", TenantOptions::default()).is_err());
+    let mut ids: Vec<&str> = tenants.tenant_ids().collect();
+    ids.sort();
+    assert_eq!(ids, ["site_a", "site_b"]);
+
+    tenants.insert("site_a.news.title", json!("A news")).unwrap();
+    tenants.insert("site_b.news.title", json!("B news")).unwrap();
+    assert_eq!(tenants.get("site_a.news.title"), Some(&json!("A news")));
+    assert_eq!(tenants.get("site_b"), Some(&json!({"news": {"title": "B news"}})));
+    assert_eq!(tenants.tenant("site_a").unwrap().get("news.title"), Some(&json!("A news")));
+    assert!(tenants.insert("site_c.news", json!(1)).is_err());
+    assert!(tenants.insert("site_a", json!(1)).is_err());
+
+    // Limits
+    assert_eq!(tenants.tenant_bytes("site_b"), Some(27));
+    assert!(tenants.insert("site_b.news.body", json!("Too long for the limit")).is_err());
+    assert_eq!(tenants.get("site_b.news.body"), None);
+    tenants.insert("site_b.news.id", json!(1)).unwrap();
+
+    assert!(tenants.remove_tenant("site_b"));
+    assert!(!tenants.remove_tenant("site_b"));
+    assert_eq!(tenants.get("site_b.news.title"), None);
+}
+
+#[test]
+fn tenant_bytes_test() {
+    let mut tenants = TenantCache::new();
+    tenants.add_tenant("site_a", TenantOptions { max_bytes: Some(1_000), ..Default::default() }).unwrap();
+    let measured = |tenants: &TenantCache| serde_json::to_vec(tenants.get("site_a").unwrap()).unwrap().len();
+
+    // The size is tracked across inserts merging, replacing, creating & pushing values
+    let inserts = [
+        ("site_a.news", json!({"title": "A news", "tags": ["a"]})),
+        ("site_a.news", json!({"body": "Body", "author": {"name": "Taro"}})),
+        ("site_a.news.author.name", json!("Hanako")),
+        ("site_a.news.tags.", json!("b")),
+        ("site_a.news.title", json!(null)),
+        ("site_a.items", json!([{"id": 1}, {"id": 2}])),
+        ("site_a.items.id", json!([3, 4])),
+        ("site_a.seo.meta.description", json!("Description \"quoted\"")),
+        ("site_a.news.body.text", json!("Text")),
+    ];
+    for (path, value) in inserts {
+        tenants.insert(path, value).unwrap();
+        assert_eq!(tenants.tenant_bytes("site_a"), Some(measured(&tenants)), "{path}");
+    }
+    let size = measured(&tenants);
+    let error = tenants.insert("site_a.news.body", json!("x".repeat(1_000))).unwrap_err();
+    assert!(error.to_string().contains("over its 1000 bytes limit"));
+    assert_eq!(tenants.tenant_bytes("site_a"), Some(size));
+
+    tenants.tenant_mut("site_a").unwrap().insert("other", json!("Not tracked"));
+    assert_eq!(tenants.tenant_bytes("site_a"), Some(measured(&tenants)));
+    tenants.insert("site_a.news.id", json!(1)).unwrap();
+    assert_eq!(tenants.tenant_bytes("site_a"), Some(measured(&tenants)));
+}
+
+#[test]
+fn tenant_options_test() {
+    let mut tenants = TenantCache::new();
+    tenants.add_tenant("site_a", TenantOptions { cache: DataCacheOptions { max_depth: Some(2), ..Default::default() }, max_bytes: None }).unwrap();
+    tenants.add_tenant("site_b", TenantOptions::default()).unwrap();
+    assert!(tenants.insert("site_a.a.b.c", json!(1)).is_err());
+    tenants.insert("site_b.a.b.c", json!(1)).unwrap();
+}
+
+#[cfg(feature = "replace-engine")]
+#[test]
+fn tenant_isolation_test() {
+    let mut tenants = TenantCache::new();
+    tenants.add_tenant("site_a", TenantOptions::default()).unwrap();
+    tenants.add_tenant("site_b", TenantOptions::default()).unwrap();
+    tenants.insert("site_a.title", json!("A")).unwrap();
+    tenants.insert("site_b.title", json!("B")).unwrap();
+    tenants.insert("site_b.secret", json!("B secret")).unwrap();
+
+    let mut output = Vec::new();
+    tenants.replace("site_a", "{$title} {$secret} {$site_b.secret}".as_bytes(), &mut output).unwrap();
+    assert_eq!(String::from_utf8(output).unwrap(), "A {$secret} {$site_b.secret}");
+    assert!(tenants.replace("site_c", "".as_bytes(), Vec::new()).is_err());
+}
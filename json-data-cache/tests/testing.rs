@@ -0,0 +1,33 @@
+#![cfg(feature = "replace-engine")]
+
+use std::fs;
+
+use json_data_cache::testing::{GoldenTest, Redaction, fixture_cache};
+
+#[test]
+fn golden_test() {
+    let dir = std::env::temp_dir().join(format!("json-data-cache-golden-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("data.json"), r#"{"title": "Hello", "csp_nonce": "r4nd0m", "updated": "2024-05-01T10:00:00"}"#).unwrap();
+    fs::write(dir.join("page.golden.html"), "<h1>Hello</h1>\n<script nonce=\"[NONCE]\"></script>\n<p>[DATE]</p>").unwrap();
+    let template = b"<h1>{$title}</h1>\n<script nonce=\"{$csp_nonce}\"></script>\n<p>{$updated}</p>";
+
+    let mut data_cache = fixture_cache(dir.join("data.json")).unwrap();
+    let golden_test = GoldenTest::new()
+        .redact(Redaction::between("nonce=\"", "\"", "[NONCE]"))
+        .redact(Redaction::between("<p>", "</p>", "[DATE]"));
+    golden_test.assert(&mut data_cache, template, dir.join("page.golden.html"));
+
+    data_cache.insert("title", serde_json::json!("Bye"));
+    let error = golden_test.check(&mut data_cache, template, dir.join("page.golden.html")).unwrap_err();
+    assert!(error.msg.contains("on line 1\n  expected: <h1>Hello</h1>\n  actual:   <h1>Bye</h1>"));
+    assert!(golden_test.check(&mut data_cache, template, dir.join("missing.html")).is_err());
+    assert!(fixture_cache(dir.join("missing.json")).is_err());
+
+    #[cfg(feature = "regex")]
+    {
+        let redaction = Redaction::regex(r"\d{4}-\d{2}-\d{2}T[\d:]+", "[DATE]").unwrap();
+        assert_eq!(redaction.apply(b"at 2024-05-01T10:00:00 !"), b"at [DATE] !");
+    }
+    fs::remove_dir_all(dir).unwrap();
+}
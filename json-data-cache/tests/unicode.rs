@@ -0,0 +1,44 @@
+use json_data_cache::{DataCache, DataCacheOptions, unicode::{Normalization, graphemes, normalize, truncate}};
+use serde_json::json;
+
+#[test]
+fn normalize_test() {
+    assert_eq!(normalize("e\u{301}", Normalization::Nfc), "é");
+    assert_eq!(normalize("\u{1e0b}\u{323}", Normalization::Nfc), "\u{1e0d}\u{307}"); // Marks reordered by class
+    assert_eq!(normalize("か\u{3099}", Normalization::Nfc), "が");
+    assert_eq!(normalize("\u{1100}\u{1161}\u{11a8}", Normalization::Nfc), "각");
+    assert_eq!(normalize("\u{212b}", Normalization::Nfc), "Å"); // Singleton decompositions
+    assert_eq!(normalize("ｶﾞＡ①ﬁ", Normalization::Nfc), "ｶﾞＡ①ﬁ");
+    assert_eq!(normalize("ｶﾞＡ①ﬁ", Normalization::Nfkc), "ガA1fi");
+    assert!(matches!(normalize("ascii", Normalization::Nfkc), std::borrow::Cow::Borrowed(_)));
+}
+
+#[test]
+fn graphemes_test() {
+    let text = "🇯🇵👍🏽e\u{301}👨\u{200d}👩\u{200d}👧\r\n각\u{1100}\u{1161}";
+    assert_eq!(
+        graphemes(text).collect::<Vec<&str>>(),
+        ["🇯🇵", "👍🏽", "e\u{301}", "👨\u{200d}👩\u{200d}👧", "\r\n", "각", "\u{1100}\u{1161}"]
+    );
+    assert_eq!(graphemes("🇯🇵🇺").count(), 2);
+    assert_eq!(graphemes("").count(), 0);
+
+    assert_eq!(truncate("👨\u{200d}👩\u{200d}👧 family", 3, "…"), "👨\u{200d}👩\u{200d}👧 …");
+    assert_eq!(truncate("👍🏽👍🏽", 2, "…"), "👍🏽👍🏽");
+    assert_eq!(truncate("e\u{301}e\u{301}e\u{301}", 2, ""), "e\u{301}e\u{301}");
+}
+
+#[test]
+fn normalization_option_test() {
+    let mut data_cache = DataCache::new(DataCacheOptions { normalization: Some(Normalization::Nfc), ..Default::default() });
+    data_cache.insert("comment", json!({"body": "Cafe\u{301}", "tags": ["e\u{301}"], "likes": 2}));
+    assert_eq!(data_cache.get("comment"), Some(&json!({"body": "Café", "tags": ["é"], "likes": 2})));
+    data_cache.merge(json!({"title": "ﾊﾟﾝ"}));
+    assert_eq!(data_cache.get("title"), Some(&json!("ﾊﾟﾝ")));
+    data_cache.insert_bulk(vec![(String::from("name"), json!("A\u{30a}"))]);
+    assert_eq!(data_cache.get("name"), Some(&json!("Å")));
+
+    let mut data_cache = DataCache::new(DataCacheOptions { normalization: Some(Normalization::Nfkc), ..Default::default() });
+    data_cache.merge(json!({"title": "ﾊﾟﾝ"}));
+    assert_eq!(data_cache.get("title"), Some(&json!("パン")));
+}
@@ -0,0 +1,55 @@
+use json_data_cache::{DataCache, DataCacheOptions, validation::{FormValidator, Rule}};
+use serde_json::json;
+
+fn validator() -> FormValidator {
+    let validator = FormValidator::new()
+        .rule("name", Rule::Required, "Name is required")
+        .rule("name", Rule::Length { min: None, max: Some(5) }, "Name is too long")
+        .rule("age", Rule::Range { min: Some(18.0), max: Some(120.0) }, "Invalid age")
+        .rule("password_confirmation", Rule::SameAs("password".to_string()), "Passwords differ")
+        .rule("address.zip", Rule::RequiredWith("address.city".to_string()), "Zip is required with a city");
+    #[cfg(feature = "regex")]
+    let validator = validator.pattern("email", r"^[^@\s]+@[^@\s]+$", "Invalid email").unwrap();
+    validator
+}
+
+#[test]
+fn validation_test() {
+    let mut data_cache = DataCache::new(DataCacheOptions::default());
+    data_cache.insert("form.data", json!({
+        "name": "  ",
+        "age": "17",
+        "email": "",
+        "password": "secret",
+        "password_confirmation": "Secret",
+        "address": {"city": "Tokyo"}
+    }));
+    let errors = data_cache.validate_form("form.data", &validator()).unwrap();
+    let failed: Vec<(&str, &str)> = errors.iter().map(|error| (error.field.as_str(), error.rule)).collect();
+    // Empty optional fields (email) are not checked
+    assert_eq!(failed, [("name", "required"), ("age", "range"), ("password_confirmation", "same_as"), ("address.zip", "required_with")]);
+    assert_eq!(data_cache.get("form.errors.name.0"), Some(&json!({"rule": "required", "message": "Name is required"})));
+    assert_eq!(data_cache.get("form.errors.address.zip.0.message"), Some(&json!("Zip is required with a city")));
+
+    data_cache.insert("form.data", json!({
+        "name": "Yamada Taro",
+        "age": 30,
+        "email": "not an email",
+        "password_confirmation": "secret",
+        "address": {"city": "Tokyo", "zip": "100-0001"}
+    }));
+    let errors = data_cache.validate_form("form.data", &validator()).unwrap();
+    let failed: Vec<&str> = errors.iter().map(|error| error.field.as_str()).collect();
+    #[cfg(feature = "regex")]
+    assert_eq!(failed, ["name", "email"]);
+    #[cfg(not(feature = "regex"))]
+    assert_eq!(failed, ["name"]);
+    // Previous errors are replaced
+    assert!(data_cache.get("form.errors.age").is_none());
+    assert_eq!(data_cache.get("form.errors.name.0.rule"), Some(&json!("length")));
+
+    data_cache.insert("form.data", json!({"name": "Taro", "email": "taro@example.com"}));
+    let errors = data_cache.validate_form("form.data", &validator().errors_path("contact.errors")).unwrap();
+    assert!(errors.is_empty());
+    assert_eq!(data_cache.get("contact.errors"), Some(&json!({})));
+}
@@ -0,0 +1,48 @@
+#![cfg(feature = "replace-engine")]
+
+use json_data_cache::{DataCache, DataCacheOptions, renderers::grouped_number};
+use serde_json::json;
+
+fn replace(data_cache: &mut DataCache, variant: Option<&str>, input: &str) -> String {
+    let mut writer = Vec::new();
+    match variant {
+        Some(variant) => data_cache.replace_with_variant(variant, input.as_bytes(), &mut writer).unwrap(),
+        None => data_cache.replace_with_data_cache(input.as_bytes(), &mut writer).unwrap(),
+    }
+    String::from_utf8(writer).unwrap()
+}
+
+#[test]
+fn variants_test() {
+    let mut data_cache = DataCache::new(DataCacheOptions::default());
+    data_cache.insert("title", json!("タイトル"));
+    data_cache.insert("price", json!(1200));
+    data_cache.insert("footer", json!("© Diverta"));
+    data_cache.insert("variants.en", json!({"title": "Title", "lang": "en"}));
+    data_cache.insert("variants.usd", json!({"price": 8.5}));
+    data_cache.define_variant("en", "variants.en");
+    data_cache.define_variant("usd", "variants.usd");
+
+    let input = "{$title} {$price} {$footer} {$lang}";
+    assert_eq!(replace(&mut data_cache, None, input), "タイトル 1200 © Diverta {$lang}");
+    assert_eq!(replace(&mut data_cache, Some("en"), input), "Title 1200 © Diverta en");
+    assert_eq!(replace(&mut data_cache, Some("usd"), input), "タイトル 8.5 © Diverta {$lang}");
+    assert_eq!(replace(&mut data_cache, Some("en"), "{$$title}"), "Title");
+
+    // Variant automata follow inserts
+    data_cache.insert("variants.en.footer", json!("© Diverta Inc."));
+    data_cache.insert("title", json!("新タイトル"));
+    assert_eq!(replace(&mut data_cache, Some("en"), input), "Title 1200 © Diverta Inc. en");
+    assert_eq!(replace(&mut data_cache, None, input), "新タイトル 1200 © Diverta {$lang}");
+
+    // Renderers of the base keys apply to overlaid values
+    data_cache.set_renderer("price", grouped_number("$", "", ","));
+    data_cache.insert("variants.usd.price", json!(1999));
+    assert_eq!(replace(&mut data_cache, Some("usd"), "{$price}"), "$1,999");
+
+    let mut writer = Vec::new();
+    assert!(data_cache.replace_with_variant("fr", b"{$title}".as_slice(), &mut writer).is_err());
+    assert!(data_cache.remove_variant("en"));
+    assert!(!data_cache.remove_variant("en"));
+    assert!(data_cache.replace_with_variant("en", b"{$title}".as_slice(), &mut writer).is_err());
+}
@@ -0,0 +1,50 @@
+use json_data_cache::{DataCache, DataCacheOptions};
+use serde_json::json;
+
+#[test]
+fn vary_reads_test() {
+    let mut data_cache = DataCache::new(DataCacheOptions::default());
+    data_cache.declare_dynamic("user.*");
+    data_cache.declare_dynamic("geo.country");
+    data_cache.insert("user", json!({"segment": "gold", "name": "Taro"}));
+    data_cache.insert("geo", json!({"country": "JP", "region": "Asia"}));
+    data_cache.insert("site.name", json!("Kuroco"));
+
+    data_cache.get("site.name"); // Not tracked yet
+    data_cache.track_reads();
+    data_cache.get("user.segment");
+    data_cache.get("geo");
+    data_cache.get("geo.region");
+    data_cache.get("user.segment");
+    let reads = data_cache.take_reads();
+    assert_eq!(reads, ["geo", "geo.region", "user.segment"]);
+    assert!(data_cache.take_reads().is_empty());
+    data_cache.get("user.name");
+    assert!(data_cache.take_reads().is_empty()); // Not tracking anymore
+
+    // geo holds geo.country, geo.region is static
+    assert_eq!(data_cache.undeclared_reads(&["user.segment"], &reads), ["geo"]);
+    assert!(data_cache.undeclared_reads(&["user.segment", "geo"], &reads).is_empty());
+    let reads = [String::from("user"), String::from("user.name.first"), String::from("site.name")];
+    assert_eq!(data_cache.undeclared_reads(&["user.segment"], &reads), ["user", "user.name.first"]);
+    assert_eq!(data_cache.undeclared_reads(&["user.*"], &reads), ["user"]);
+}
+
+#[cfg(feature = "replace-engine")]
+#[test]
+fn replace_with_vary_by_test() {
+    let mut data_cache = DataCache::new(DataCacheOptions::default());
+    data_cache.declare_dynamic("user");
+    data_cache.insert("user", json!({"segment": "gold", "name": "Taro"}));
+    data_cache.insert("site.name", json!("Kuroco"));
+
+    let mut output = Vec::new();
+    assert!(data_cache.replace_with_vary_by(&["user.segment"], b"{$site.name} {$user.segment}".as_slice(), &mut output).is_ok());
+    assert_eq!(output, b"Kuroco gold");
+
+    for template in ["Hello {$user.name}", "{$$user}"] {
+        let error = data_cache.replace_with_vary_by(&["user.segment"], template.as_bytes(), Vec::new()).unwrap_err();
+        assert!(error.msg.contains("missing from vary_by"), "{template}");
+    }
+    assert!(data_cache.replace_with_vary_by(&["user"], b"{$$user}".as_slice(), Vec::new()).is_ok());
+}
@@ -0,0 +1,53 @@
+use json_data_cache::{DataCache, DataCacheOptions};
+use serde_json::json;
+
+#[test]
+fn versions_test() {
+    let mut data_cache = DataCache::new(DataCacheOptions::default());
+    assert_eq!(data_cache.version("content"), 0);
+
+    data_cache.insert("content.details.subject", json!("Hello"));
+    let subject_version = data_cache.version("content.details.subject");
+    assert!(subject_version > 0);
+    // Ancestors include the modification of their descendants
+    assert_eq!(data_cache.version("content.details"), subject_version);
+    assert_eq!(data_cache.version("content"), subject_version);
+    assert_eq!(data_cache.version("menu"), 0);
+
+    // Siblings are unaffected
+    data_cache.insert("content.details.body", json!("Body"));
+    assert_eq!(data_cache.version("content.details.subject"), subject_version);
+    assert!(data_cache.version("content.details") > subject_version);
+
+    // Replacing an ancestor modifies its descendants
+    data_cache.insert("content", json!({"details": {"subject": "World"}}));
+    assert!(data_cache.version("content.details.subject") > subject_version);
+    assert_eq!(data_cache.version("content.details.subject"), data_cache.version("content"));
+
+    // Merging into the root modifies every inserted key only
+    let menu_version = data_cache.version("menu");
+    data_cache.merge(json!({"content": {"details": {"subject": "Merged"}}}));
+    assert_eq!(data_cache.version("menu"), menu_version);
+    assert!(data_cache.version("content.details.subject") > subject_version);
+}
+
+#[test]
+fn insert_if_version_test() {
+    let mut data_cache = DataCache::new(DataCacheOptions::default());
+    data_cache.insert("cart.items", json!([]));
+    let version = data_cache.version("cart");
+    assert_eq!(data_cache.version("cart.total"), 0);
+
+    let new_version = data_cache.insert_if_version("cart.total", json!(0), 0).unwrap();
+    assert!(new_version > version);
+    assert_eq!(data_cache.version("cart"), new_version);
+
+    // A concurrent update of cart happened since version was read
+    let result = data_cache.insert_if_version("cart", json!({"items": [1]}), version);
+    assert!(result.is_err());
+    assert_eq!(data_cache.get("cart.items"), Some(&json!([])));
+
+    let cart_version = data_cache.version("cart");
+    assert!(data_cache.insert_if_version("cart", json!({"items": [1]}), cart_version).is_ok());
+    assert_eq!(data_cache.get("cart.items"), Some(&json!([1])));
+}
@@ -0,0 +1,79 @@
+use json_data_cache::{DataCache, DataCacheOptions, visibility::Visibility};
+use serde_json::json;
+
+fn new_cache() -> DataCache {
+    let mut data_cache = DataCache::new(DataCacheOptions::default());
+    data_cache.insert("articles", json!([
+        {"title": "Open", "body": "Open body"},
+        {"title": "Members", "body": "Members body"}
+    ]));
+    data_cache.insert("files", json!(["public.pdf", "private.pdf"]));
+    data_cache.insert("premium", json!({"video": "https://video"}));
+    data_cache.set_visibility("articles.*.body", Visibility::any_role(&["member", "staff"]));
+    data_cache.set_visibility("files.1", Visibility::Authenticated);
+    data_cache
+}
+
+#[test]
+fn visibility_test() {
+    let mut data_cache = new_cache();
+    // Anonymous
+    assert_eq!(data_cache.get("articles.1.body"), None);
+    assert_eq!(data_cache.get("articles.1"), Some(&json!({"title": "Members"})));
+    assert_eq!(data_cache.get("files"), Some(&json!(["public.pdf", null])));
+    assert_eq!(data_cache.hidden_paths(), ["articles.0.body", "articles.1.body", "files.1"]);
+
+    // Inserts of hidden content are hidden too
+    data_cache.insert("articles.body", json!(["New open body", "New members body"]));
+    assert_eq!(data_cache.get("articles.0"), Some(&json!({"title": "Open"})));
+
+    data_cache.insert("user", json!({"id": 1, "roles": ["guest"]}));
+    assert_eq!(data_cache.get("articles.0.body"), None);
+    assert_eq!(data_cache.get("files.1"), Some(&json!("private.pdf")));
+    assert_eq!(data_cache.hidden_paths(), ["articles.0.body", "articles.1.body"]);
+
+    data_cache.insert("user", json!({"id": 1, "roles": ["member"]}));
+    assert_eq!(data_cache.get("articles.0.body"), Some(&json!("New open body")));
+    assert_eq!(data_cache.get("articles.1.body"), Some(&json!("New members body")));
+    assert!(data_cache.hidden_paths().is_empty());
+
+    // Claims removed on logout hide the keys again
+    data_cache.insert("user", json!(null));
+    assert_eq!(data_cache.get("files.1"), Some(&json!(null)));
+    assert_eq!(data_cache.hidden_paths().len(), 3);
+
+    // Values replaced by null are forgotten
+    data_cache.insert("articles", json!(null));
+    assert_eq!(data_cache.hidden_paths(), ["files.1"]);
+
+    assert!(data_cache.remove_visibility("files.1"));
+    assert!(!data_cache.remove_visibility("files.1"));
+    assert_eq!(data_cache.get("files.1"), Some(&json!("private.pdf")));
+}
+
+#[test]
+fn visibility_claims_test() {
+    let mut data_cache = new_cache();
+    data_cache.set_visibility("premium", Visibility::Claim(String::from("plan"), json!("premium")));
+    data_cache.set_claims_path("session.member");
+    data_cache.insert("session.member", json!({"plan": "free"}));
+    assert_eq!(data_cache.get("premium"), None);
+    assert_eq!(data_cache.get("files.1"), Some(&json!("private.pdf")));
+    data_cache.insert("session.member.plan", json!("premium"));
+    assert_eq!(data_cache.get("premium.video"), Some(&json!("https://video")));
+}
+
+#[cfg(feature = "replace-engine")]
+#[test]
+fn visibility_replace_test() {
+    let mut data_cache = new_cache();
+    let replace = |data_cache: &mut DataCache, input: &str| {
+        let mut writer = Vec::new();
+        data_cache.replace_with_data_cache(input.as_bytes(), &mut writer).unwrap();
+        String::from_utf8(writer).unwrap()
+    };
+    let template = "{$articles.1.title}: {$articles.1.body} {$$articles.1}";
+    assert_eq!(replace(&mut data_cache, template), r#"Members: {$articles.1.body} {\"title\":\"Members\"}"#);
+    data_cache.insert("user.roles", json!(["staff"]));
+    assert_eq!(replace(&mut data_cache, template), r#"Members: Members body {\"title\":\"Members\",\"body\":\"Members body\"}"#);
+}
@@ -0,0 +1,91 @@
+use std::io::Write;
+
+use json_data_cache::writers::{ByteCounter, TeeWriter};
+
+#[test]
+fn tee_writer_test() {
+    let mut body = Vec::new();
+    let mut copy = Vec::new();
+    let mut counter = ByteCounter::default();
+    {
+        let mut tee = TeeWriter::new(Vec::from([&mut body as &mut dyn Write, &mut copy]));
+        tee.push(&mut counter);
+        tee.write_all(b"hello ").unwrap();
+        tee.write_all("世界".as_bytes()).unwrap();
+        tee.flush().unwrap();
+    }
+    assert_eq!(body, "hello 世界".as_bytes());
+    assert_eq!(copy, body);
+    assert_eq!(counter.count, body.len() as u64);
+}
+
+#[cfg(feature = "replace-engine")]
+#[test]
+fn tee_writer_replacement_test() {
+    use json_data_cache::{DataCache, DataCacheOptions};
+    use serde_json::json;
+
+    let mut data_cache = DataCache::new(DataCacheOptions::default());
+    data_cache.insert("title", json!("Hello"));
+    let mut body = Vec::new();
+    let mut counter = ByteCounter::default();
+    let tee = TeeWriter::new(Vec::from([&mut body as &mut dyn Write, &mut counter]));
+    assert!(data_cache.replace_with_data_cache(b"<h1>{$title}</h1>".as_slice(), tee).is_ok());
+    assert_eq!(body, b"<h1>Hello</h1>");
+    assert_eq!(counter.count, 14);
+}
+
+#[test]
+fn chunked_writer_test() {
+    use json_data_cache::writers::ChunkedWriter;
+
+    let mut writer = ChunkedWriter::with_chunk_size(Vec::new(), 4);
+    writer.write_all(b"ab").unwrap();
+    writer.write_all(b"").unwrap();
+    writer.write_all(b"cdef").unwrap();
+    writer.flush().unwrap();
+    writer.flush().unwrap();
+    writer.write_all(b"0123456789abcdef!").unwrap();
+    assert_eq!(writer.finish().unwrap(), b"4\r\nabcd\r\n2\r\nef\r\n11\r\n0123456789abcdef!\r\n0\r\n\r\n");
+
+    assert_eq!(ChunkedWriter::new(Vec::new()).finish().unwrap(), b"0\r\n\r\n");
+}
+
+#[cfg(feature = "replace-engine")]
+#[test]
+fn chunked_writer_replacement_test() {
+    use json_data_cache::{DataCache, DataCacheOptions, FlushPolicy, writers::ChunkedWriter};
+    use serde_json::json;
+
+    let mut data_cache = DataCache::new(DataCacheOptions { flush_policy: FlushPolicy::AfterPlaceholder, ..Default::default() });
+    data_cache.insert("title", json!("Hello"));
+    let mut writer = ChunkedWriter::new(Vec::new());
+    assert!(data_cache.replace_with_data_cache(b"<h1>{$title}</h1>".as_slice(), &mut writer).is_ok());
+    assert_eq!(writer.finish().unwrap(), b"9\r\n<h1>Hello\r\n5\r\n</h1>\r\n0\r\n\r\n");
+}
+
+#[test]
+fn digest_writer_test() {
+    use json_data_cache::writers::{ChunkedWriter, DigestAlgorithm, DigestWriter};
+
+    let mut writer = DigestWriter::new(Vec::new(), DigestAlgorithm::Crc32c);
+    writer.write_all(b"12345").unwrap();
+    writer.write_all(b"6789").unwrap();
+    let (body, digest) = writer.finish();
+    assert_eq!(body, b"123456789");
+    assert_eq!(digest.to_hex(), "e3069283");
+    assert_eq!(digest.header_value(), "crc32c=4waSgw==");
+
+    let writer = DigestWriter::new(Vec::new(), DigestAlgorithm::Sha256);
+    assert_eq!(writer.digest().header_value(), "sha-256=47DEQpj8HBSa+/TImW+5JCeuQeRkm5NMpJWZG3hSuFU=");
+
+    // Digest of the body, sent as a trailer of its chunked framing
+    let mut writer = DigestWriter::new(ChunkedWriter::new(Vec::new()), DigestAlgorithm::Sha256);
+    writer.write_all(b"abc").unwrap();
+    assert_eq!(writer.digest().to_hex(), "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad");
+    let (chunked, digest) = writer.finish();
+    let output = chunked.finish_with_trailers(&[("Digest", &digest.header_value())]).unwrap();
+    assert_eq!(output, b"3\r\nabc\r\n0\r\nDigest: sha-256=ungWv48Bz+pBQUDeXa4iI7ADYaOWF3qctBD/YfIAFa0=\r\n\r\n");
+
+    assert!(ChunkedWriter::new(Vec::new()).finish_with_trailers(&[("X-Split", "a\r\nb")]).is_err());
+}
@@ -0,0 +1,206 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    env,
+    fs::{self, File},
+    io::{self, BufReader, BufWriter, Write},
+    path::Path,
+    process::ExitCode,
+};
+
+use json_data_cache::{DataCache, DataCacheOptions, bundle::Bundle, export::ExportRoute, lint::{LintSource, find_placeholders, lint_template}};
+use serde_json::Value;
+
+const USAGE: &str = "Usage: kuroco-edge-cache <command> [options]
+
+Commands:
+  render --data <data.json> --template <page.html> [--output <file>]
+      Replaces {$key} and {$$key} placeholders of the template, exactly as the edge does
+  get --data <data.json> <path>
+      Prints the value at path (strings are printed raw, other values as JSON)
+  diff <left.json> <right.json>
+      Lists keys whose values differ between two data files. Exits with 1 if any
+  validate-template --data <data.json> --template <page.html>
+      Lists placeholders of the template that have no value in data. Exits with 1 if any
+  lint-template (--data <data.json> | --schema <schema.json>) --template <page.html>
+      Lists placeholders of unknown paths and unsupported filters. Exits with 1 if any
+  flatten --data <data.json>
+      Prints all keys and their string values, as a sorted JSON object
+  mock --schema <schema.json> [--seed <n>]
+      Prints fake data matching the schema, always the same for a given seed (0 by default)
+  export --bundle <site.kebundle> --routes <routes.json> (--out-dir <dir> | --tar <file>)
+      Pre-renders the routes exactly as the edge does, as files of the directory or entries of a tar archive";
+
+/// Command line arguments, split between --name value options and positional arguments
+struct Args {
+    options: BTreeMap<String, String>,
+    positional: Vec<String>,
+}
+
+impl Args {
+    fn parse(args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut options = BTreeMap::new();
+        let mut positional = Vec::new();
+        let mut args = args.peekable();
+        while let Some(arg) = args.next() {
+            match arg.strip_prefix("--") {
+                Some(name) => {
+                    let value = args.next().ok_or(format!("Missing value for option --{name}"))?;
+                    options.insert(name.to_string(), value);
+                },
+                None => positional.push(arg),
+            }
+        }
+        Ok(Self { options, positional })
+    }
+
+    fn option(&self, name: &str) -> Result<&str, String> {
+        self.options.get(name).map(|v| v.as_str()).ok_or(format!("Missing option --{name}"))
+    }
+
+    fn positional(&self, idx: usize, name: &str) -> Result<&str, String> {
+        self.positional.get(idx).map(|v| v.as_str()).ok_or(format!("Missing argument <{name}>"))
+    }
+}
+
+fn read_json(path: &str) -> Result<Value, String> {
+    let file = File::open(path).map_err(|e| format!("Unable to open {path} : {e}"))?;
+    serde_json::from_reader(BufReader::new(file)).map_err(|e| format!("Invalid JSON in {path} : {e}"))
+}
+
+fn load_cache(path: &str) -> Result<DataCache, String> {
+    let data = read_json(path)?;
+    if !data.is_object() {
+        return Err(format!("{path} must contain a JSON object"));
+    }
+    let mut data_cache = DataCache::new(DataCacheOptions::default());
+    data_cache.try_merge(data).map_err(|e| format!("Unable to load {path} : {e}"))?;
+    Ok(data_cache)
+}
+
+fn render(args: &Args) -> Result<ExitCode, String> {
+    let mut data_cache = load_cache(args.option("data")?)?;
+    let template_path = args.option("template")?;
+    let template = File::open(template_path).map_err(|e| format!("Unable to open {template_path} : {e}"))?;
+    let writer: Box<dyn Write> = match args.options.get("output") {
+        Some(output) => Box::new(File::create(output).map_err(|e| format!("Unable to create {output} : {e}"))?),
+        None => Box::new(io::stdout().lock()),
+    };
+    let mut writer = BufWriter::new(writer);
+    data_cache.replace_with_data_cache(BufReader::new(template), &mut writer).map_err(|e| e.to_string())?;
+    writer.flush().map_err(|e| e.to_string())?;
+    Ok(ExitCode::SUCCESS)
+}
+
+fn get(args: &Args) -> Result<ExitCode, String> {
+    let data_cache = load_cache(args.option("data")?)?;
+    let path = args.positional(0, "path")?;
+    match data_cache.get(path) {
+        Some(Value::String(value)) => println!("{value}"),
+        Some(value) => println!("{value}"),
+        None => return Err(format!("No value at {path}")),
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+fn diff(args: &Args) -> Result<ExitCode, String> {
+    let left = load_cache(args.positional(0, "left.json")?)?.as_string_values_map();
+    let right = load_cache(args.positional(1, "right.json")?)?.as_string_values_map();
+    let keys: BTreeSet<&String> = left.keys().chain(right.keys()).collect();
+    let mut differs = false;
+    for key in keys {
+        match (left.get(key), right.get(key)) {
+            (Some(l), None) => println!("- {key}: {l}"),
+            (None, Some(r)) => println!("+ {key}: {r}"),
+            (Some(l), Some(r)) if l != r => println!("~ {key}: {l} => {r}"),
+            _ => continue,
+        }
+        differs = true;
+    }
+    Ok(if differs { ExitCode::FAILURE } else { ExitCode::SUCCESS })
+}
+
+fn validate_template(args: &Args) -> Result<ExitCode, String> {
+    let keys = load_cache(args.option("data")?)?.as_string_values_map();
+    let template_path = args.option("template")?;
+    let template = fs::read_to_string(template_path).map_err(|e| format!("Unable to read {template_path} : {e}"))?;
+    let mut valid = true;
+    for placeholder in find_placeholders(&template) {
+        if !keys.contains_key(placeholder.key) {
+            println!("{template_path}:{}:{}: no value for {}", placeholder.line, placeholder.column, placeholder.key);
+            valid = false;
+        }
+    }
+    Ok(if valid { ExitCode::SUCCESS } else { ExitCode::FAILURE })
+}
+
+fn lint_template_command(args: &Args) -> Result<ExitCode, String> {
+    let template_path = args.option("template")?;
+    let template = fs::read_to_string(template_path).map_err(|e| format!("Unable to read {template_path} : {e}"))?;
+    let diagnostics = match args.options.get("schema") {
+        Some(schema_path) => lint_template(&template, LintSource::Schema(&read_json(schema_path)?)),
+        None => lint_template(&template, LintSource::Cache(&load_cache(args.option("data")?)?)),
+    };
+    for diagnostic in &diagnostics {
+        println!("{template_path}:{diagnostic}");
+    }
+    Ok(if diagnostics.is_empty() { ExitCode::SUCCESS } else { ExitCode::FAILURE })
+}
+
+fn flatten(args: &Args) -> Result<ExitCode, String> {
+    let values: BTreeMap<String, String> = load_cache(args.option("data")?)?.as_string_values_map().into_iter().collect();
+    println!("{}", serde_json::to_string_pretty(&values).map_err(|e| e.to_string())?);
+    Ok(ExitCode::SUCCESS)
+}
+
+fn mock(args: &Args) -> Result<ExitCode, String> {
+    let schema = read_json(args.option("schema")?)?;
+    let seed = match args.options.get("seed") {
+        Some(seed) => seed.parse().map_err(|_| format!("Invalid seed {seed}"))?,
+        None => 0,
+    };
+    let data_cache = DataCache::from_schema_mock(&schema, seed).map_err(|e| e.to_string())?;
+    println!("{}", serde_json::to_string_pretty(&data_cache.root).map_err(|e| e.to_string())?);
+    Ok(ExitCode::SUCCESS)
+}
+
+fn export(args: &Args) -> Result<ExitCode, String> {
+    let bundle_path = args.option("bundle")?;
+    let bytes = fs::read(bundle_path).map_err(|e| format!("Unable to read {bundle_path} : {e}"))?;
+    let mut bundle = Bundle::load(&bytes).map_err(|e| e.to_string())?;
+    bundle.data_cache.prepare().map_err(|e| e.to_string())?;
+    let routes = ExportRoute::from_json(&read_json(args.option("routes")?)?).map_err(|e| e.to_string())?;
+    let pages = match args.options.get("tar") {
+        Some(tar_path) => {
+            let file = File::create(tar_path).map_err(|e| format!("Unable to create {tar_path} : {e}"))?;
+            bundle.export_to_tar(&routes, BufWriter::new(file))
+        },
+        None => bundle.export_to_dir(&routes, Path::new(args.option("out-dir")?)),
+    }.map_err(|e| e.to_string())?;
+    for page in pages {
+        println!("{} => {} ({} bytes)", page.route, page.file, page.len);
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+fn main() -> ExitCode {
+    let mut args = env::args().skip(1);
+    let command = args.next().unwrap_or_default();
+    let result = Args::parse(args).and_then(|args| match command.as_str() {
+        "render" => render(&args),
+        "get" => get(&args),
+        "diff" => diff(&args),
+        "validate-template" => validate_template(&args),
+        "lint-template" => lint_template_command(&args),
+        "flatten" => flatten(&args),
+        "mock" => mock(&args),
+        "export" => export(&args),
+        _ => Err(USAGE.to_string()),
+    });
+    match result {
+        Ok(code) => code,
+        Err(e) => {
+            eprintln!("{e}");
+            ExitCode::from(2)
+        },
+    }
+}
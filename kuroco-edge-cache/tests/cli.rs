@@ -0,0 +1,77 @@
+use std::{fs, path::PathBuf, process::Command};
+
+use json_data_cache::bundle::BundleBuilder;
+use serde_json::json;
+
+fn fixture_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("kuroco-edge-cache-{}-{name}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("data.json"), r#"{"content": {"subject": "Hello", "list": [1, 2]}}"#).unwrap();
+    fs::write(dir.join("other.json"), r#"{"content": {"subject": "Bye", "list": [1, 2]}, "added": true}"#).unwrap();
+    fs::write(dir.join("schema.json"), r#"{"type": "object", "properties": {"name": {"format": "name"}, "ids": {"type": "array", "items": {"type": "integer"}}}}"#).unwrap();
+    fs::write(dir.join("page.html"), "<h1>{$content.subject}</h1>\n<p>{$$content.list} {$unknown.key}</p>").unwrap();
+    dir
+}
+
+fn run(dir: &PathBuf, args: &[&str]) -> (Option<i32>, String) {
+    let output = Command::new(env!("CARGO_BIN_EXE_kuroco-edge-cache"))
+        .current_dir(dir)
+        .args(args)
+        .output()
+        .unwrap();
+    (output.status.code(), String::from_utf8(output.stdout).unwrap())
+}
+
+#[test]
+fn cli_test() {
+    let dir = fixture_dir("cli");
+
+    assert_eq!(
+        run(&dir, &["render", "--data", "data.json", "--template", "page.html"]),
+        (Some(0), String::from("<h1>Hello</h1>\n<p>[1,2] {$unknown.key}</p>"))
+    );
+    assert_eq!(run(&dir, &["get", "--data", "data.json", "content.subject"]), (Some(0), String::from("Hello\n")));
+    assert_eq!(run(&dir, &["get", "--data", "data.json", "content.list"]), (Some(0), String::from("[1,2]\n")));
+    assert_eq!(
+        run(&dir, &["validate-template", "--data", "data.json", "--template", "page.html"]),
+        (Some(1), String::from("page.html:2:21: no value for unknown.key\n"))
+    );
+    assert_eq!(
+        run(&dir, &["lint-template", "--data", "data.json", "--template", "page.html"]),
+        (Some(1), String::from("page.html:2:21: unknown path unknown.key\n"))
+    );
+    assert_eq!(
+        run(&dir, &["lint-template", "--schema", "schema.json", "--template", "page.html"]),
+        (Some(1), String::from("page.html:1:5: unknown path content.subject\npage.html:2:4: unknown path content.list\npage.html:2:21: unknown path unknown.key\n"))
+    );
+    assert_eq!(
+        run(&dir, &["diff", "data.json", "other.json"]),
+        (Some(1), String::from("+ added: true\n~ content: {\"subject\":\"Hello\",\"list\":[1,2]} => {\"subject\":\"Bye\",\"list\":[1,2]}\n~ content.subject: Hello => Bye\n"))
+    );
+    let (code, flattened) = run(&dir, &["flatten", "--data", "data.json"]);
+    assert_eq!(code, Some(0));
+    assert_eq!(serde_json::from_str::<serde_json::Value>(&flattened).unwrap()["content.list.1"], "2");
+    let (code, mocked) = run(&dir, &["mock", "--schema", "schema.json", "--seed", "3"]);
+    assert_eq!(code, Some(0));
+    let mocked: serde_json::Value = serde_json::from_str(&mocked).unwrap();
+    assert!(mocked["name"].is_string() && mocked["ids"].is_array());
+    assert_eq!(run(&dir, &["mock", "--schema", "schema.json", "--seed", "3"]).1, serde_json::to_string_pretty(&mocked).unwrap() + "\n");
+    assert_eq!(run(&dir, &["unknown"]).0, Some(2));
+
+    let bundle = BundleBuilder::new()
+        .template("article", b"<h1>{$article.title}</h1>{$site.name}")
+        .data("site", json!({"name": "Kuroco"}))
+        .to_bytes()
+        .unwrap();
+    fs::write(dir.join("site.kebundle"), bundle).unwrap();
+    fs::write(dir.join("routes.json"), r#"[{"path": "/news/1", "template": "article", "data": {"article": {"title": "Hello"}}}]"#).unwrap();
+    assert_eq!(
+        run(&dir, &["export", "--bundle", "site.kebundle", "--routes", "routes.json", "--out-dir", "out"]),
+        (Some(0), String::from("/news/1 => news/1/index.html (20 bytes)\n"))
+    );
+    assert_eq!(fs::read_to_string(dir.join("out/news/1/index.html")).unwrap(), "<h1>Hello</h1>Kuroco");
+    assert_eq!(run(&dir, &["export", "--bundle", "site.kebundle", "--routes", "routes.json", "--tar", "out.tar"]).0, Some(0));
+    assert_eq!(fs::metadata(dir.join("out.tar")).unwrap().len(), 512 * 4);
+
+    fs::remove_dir_all(dir).unwrap();
+}